@@ -0,0 +1,97 @@
+use crate::{
+	event, rusty_node::assets::events, AccountId, AssetId, Balance, Client, Error, Hash, Signer,
+};
+
+impl Client {
+	/// Create the asset `id`, administered by `admin`.
+	pub async fn create_asset(
+		&self,
+		signer: &Signer,
+		id: AssetId,
+		admin: AccountId,
+		min_balance: Balance,
+	) -> Result<events::Created, Error> {
+		let events = self
+			.api
+			.tx()
+			.assets()
+			.create(id, admin.into(), min_balance)
+			.sign_and_submit_then_watch(signer)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// Create the sufficient asset `id`, owned by `owner`, without reserving a deposit, as an
+	/// operator of the runtime.
+	pub async fn force_create_asset(
+		&self,
+		operator: &Signer,
+		id: AssetId,
+		owner: AccountId,
+		min_balance: Balance,
+	) -> Result<events::ForceCreated, Error> {
+		let events = self
+			.api
+			.tx()
+			.assets()
+			.force_create(id, owner.into(), true, min_balance)
+			.sign_and_submit_then_watch(operator)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// Mint `amount` of the asset `id` to `beneficiary`, as its issuer.
+	pub async fn mint_asset(
+		&self,
+		signer: &Signer,
+		id: AssetId,
+		beneficiary: AccountId,
+		amount: Balance,
+	) -> Result<events::Issued, Error> {
+		let events = self
+			.api
+			.tx()
+			.assets()
+			.mint(id, beneficiary.into(), amount)
+			.sign_and_submit_then_watch(signer)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// Transfer `amount` of the asset `id` to `target`.
+	pub async fn transfer_asset(
+		&self,
+		signer: &Signer,
+		id: AssetId,
+		target: AccountId,
+		amount: Balance,
+	) -> Result<events::Transferred, Error> {
+		let events = self
+			.api
+			.tx()
+			.assets()
+			.transfer(id, target.into(), amount)
+			.sign_and_submit_then_watch(signer)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// The balance of `who` in the asset `id`, at the block `at`, or at the best block.
+	pub async fn asset_balance(
+		&self,
+		id: AssetId,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> Result<Balance, Error> {
+		Ok(self.api.storage().assets().account(id, who, at).await?.balance)
+	}
+}
+
@@ -0,0 +1,82 @@
+//! A typed client of the node, generated with subxt from the metadata of its runtime.
+//!
+//! [`rusty_node`] holds the calls, events, storage items and constants of every pallet, as
+//! generated from `metadata/rusty-node.scale`. [`Client`] wraps the ones services integrating
+//! the tutorials need:
+//! - [`Client::create_asset`], [`Client::force_create_asset`], [`Client::mint_asset`] and
+//!   [`Client::transfer_asset`] over the assets pallet
+//! - [`Client::create_class`], [`Client::mint_nft`] and [`Client::transfer_nft`] over the uniques
+//!   pallet
+//! - [`Client::set_code`], upgrading the runtime through the sudo pallet, [`Client::sudo`],
+//!   dispatching any call as root, and [`Client::set_operators`], appointing the operators allowed
+//!   to dispatch the privileged calls
+//!
+//! Each submits its extrinsic, waits for it to be finalized, and returns the event it deposited.
+//! The metadata has to be regenerated from a running node after each change of the runtime:
+//!
+//! ```sh
+//! subxt metadata --url http://127.0.0.1:9933 -f bytes > client/metadata/rusty-node.scale
+//! ```
+
+mod assets;
+mod nfts;
+mod system;
+
+use subxt::{sp_core::sr25519, ClientBuilder, DefaultConfig, DefaultExtra, Event, PairSigner};
+
+pub use rusty_node_primitives::{AssetId, ClassId, InstanceId};
+pub use subxt::{
+	self,
+	sp_core::{crypto::AccountId32 as AccountId, H256 as Hash},
+	Error, TransactionEvents,
+};
+
+/// The identifiers of the runtime are the ones of its primitives, rather than the copies subxt
+/// would generate, so that the client cannot drift from the runtime.
+#[subxt::subxt(runtime_metadata_path = "metadata/rusty-node.scale")]
+pub mod rusty_node {
+	#[subxt(substitute_type = "rusty_node_primitives::ids::AssetId")]
+	use ::rusty_node_primitives::AssetId;
+	#[subxt(substitute_type = "rusty_node_primitives::ids::ClassId")]
+	use ::rusty_node_primitives::ClassId;
+	#[subxt(substitute_type = "rusty_node_primitives::ids::InstanceId")]
+	use ::rusty_node_primitives::InstanceId;
+}
+
+/// The runtime API generated from the metadata.
+pub type Api = rusty_node::RuntimeApi<DefaultConfig, DefaultExtra<DefaultConfig>>;
+
+/// The signer of the extrinsics, from an sr25519 key pair.
+pub type Signer = PairSigner<DefaultConfig, DefaultExtra<DefaultConfig>, sr25519::Pair>;
+
+pub type Balance = u128;
+
+/// A connection to a node.
+pub struct Client {
+	api: Api,
+}
+
+impl Client {
+	/// Connect to the node at `url`.
+	pub async fn new(url: &str) -> Result<Self, Error> {
+		let client = ClientBuilder::new().set_url(url).build().await?;
+		Ok(Self { api: client.to_runtime_api() })
+	}
+
+	/// The generated API, for the calls and queries the client does not wrap.
+	pub fn api(&self) -> &Api {
+		&self.api
+	}
+
+	/// The hash of the last block finalized by the node.
+	pub async fn finalized_head(&self) -> Result<Hash, Error> {
+		self.api.client.rpc().finalized_head().await
+	}
+}
+
+/// The first event `E` of a finalized extrinsic.
+fn event<E: Event>(events: TransactionEvents<DefaultConfig>) -> Result<E, Error> {
+	events
+		.find_first_event::<E>()?
+		.ok_or_else(|| Error::Other(format!("no {}::{} event", E::PALLET, E::EVENT)))
+}
@@ -0,0 +1,76 @@
+use crate::{
+	event, rusty_node::uniques::events, AccountId, ClassId, Client, Error, Hash, InstanceId,
+	Signer,
+};
+
+impl Client {
+	/// Create the class of nonfungible items `class`, administered by `admin`.
+	pub async fn create_class(
+		&self,
+		signer: &Signer,
+		class: ClassId,
+		admin: AccountId,
+	) -> Result<events::Created, Error> {
+		let events = self
+			.api
+			.tx()
+			.uniques()
+			.create(class, admin.into())
+			.sign_and_submit_then_watch(signer)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// Mint the item `instance` of `class` to `owner`, as the issuer of the class.
+	pub async fn mint_nft(
+		&self,
+		signer: &Signer,
+		class: ClassId,
+		instance: InstanceId,
+		owner: AccountId,
+	) -> Result<events::Issued, Error> {
+		let events = self
+			.api
+			.tx()
+			.uniques()
+			.mint(class, instance, owner.into())
+			.sign_and_submit_then_watch(signer)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// Transfer the item `instance` of `class` to `dest`.
+	pub async fn transfer_nft(
+		&self,
+		signer: &Signer,
+		class: ClassId,
+		instance: InstanceId,
+		dest: AccountId,
+	) -> Result<events::Transferred, Error> {
+		let events = self
+			.api
+			.tx()
+			.uniques()
+			.transfer(class, instance, dest.into())
+			.sign_and_submit_then_watch(signer)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// The owner of the item `instance` of `class`, at the block `at`, or at the best block.
+	pub async fn nft_owner(
+		&self,
+		class: ClassId,
+		instance: InstanceId,
+		at: Option<Hash>,
+	) -> Result<Option<AccountId>, Error> {
+		let details = self.api.storage().uniques().asset(class, instance, at).await?;
+		Ok(details.map(|details| details.owner))
+	}
+}
@@ -0,0 +1,64 @@
+use crate::{
+	event,
+	rusty_node::{
+		runtime_types::{frame_system::pallet::Call as SystemCall, rusty_node_runtime::Call},
+		sudo,
+		system::events,
+	},
+	AccountId, Client, Error, Signer,
+};
+use codec::Encode;
+use subxt::sp_core::hashing::twox_128;
+
+/// The storage key of the `Operators` of the runtime, a `parameter_types` storage item.
+const OPERATORS_KEY: &[u8] = b":Operators:";
+
+impl Client {
+	/// Dispatch `call` as root, signed by the sudo key.
+	pub async fn sudo(&self, sudo: &Signer, call: Call) -> Result<sudo::events::Sudid, Error> {
+		let events = self
+			.api
+			.tx()
+			.sudo()
+			.sudo(call)
+			.sign_and_submit_then_watch(sudo)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// Appoint `operators` to dispatch the privileged calls of the pallets, as the sudo key.
+	pub async fn set_operators(
+		&self,
+		sudo: &Signer,
+		operators: Vec<AccountId>,
+	) -> Result<sudo::events::Sudid, Error> {
+		let items = vec![(twox_128(OPERATORS_KEY).to_vec(), operators.encode())];
+		self.sudo(sudo, Call::System(SystemCall::set_storage { items })).await
+	}
+
+	/// Upgrade the runtime to `code`, as the sudo key.
+	pub async fn set_code(
+		&self,
+		sudo: &Signer,
+		code: Vec<u8>,
+	) -> Result<events::CodeUpdated, Error> {
+		let call = Call::System(SystemCall::set_code { code });
+		let events = self
+			.api
+			.tx()
+			.sudo()
+			.sudo_unchecked_weight(call, 0)
+			.sign_and_submit_then_watch(sudo)
+			.await?
+			.wait_for_finalized_success()
+			.await?;
+		event(events)
+	}
+
+	/// The `spec_version` of the runtime at the best block.
+	pub async fn spec_version(&self) -> Result<u32, Error> {
+		Ok(self.api.client.rpc().runtime_version(None).await?.spec_version)
+	}
+}
@@ -0,0 +1,167 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A faucet of an asset of the assets pallet, showing how ink! contracts use the pallets of the
+//! runtime through its chain extension.
+//!
+//! The faucet is made the issuer of its asset, with `assets.setTeam`, and mints a fixed drip to
+//! whoever asks for it, once per block. Its owner can also give out the assets the faucet holds.
+
+use ink_lang as ink;
+
+#[ink::contract(env = rusty_node_extension::RustyEnvironment)]
+mod asset_faucet {
+	use rusty_node_extension::{AssetId, ExtensionError};
+
+	#[ink(storage)]
+	pub struct AssetFaucet {
+		/// The account that instantiated the faucet.
+		owner: AccountId,
+		/// The asset dripped.
+		asset_id: AssetId,
+		/// The amount minted by each drip.
+		drip: Balance,
+		/// The block of the last drip.
+		last_drip: Option<BlockNumber>,
+	}
+
+	/// Some of the asset has been dripped.
+	#[ink(event)]
+	pub struct Dripped {
+		#[ink(topic)]
+		to: AccountId,
+		amount: Balance,
+	}
+
+	#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+	pub enum Error {
+		/// Only the owner of the faucet can withdraw from it.
+		NotOwner,
+		/// The faucet already dripped in this block.
+		AlreadyDripped,
+		/// The runtime rejected the call, e.g. as the faucet is not the issuer of the asset.
+		Runtime(ExtensionError),
+	}
+
+	impl From<ExtensionError> for Error {
+		fn from(error: ExtensionError) -> Self {
+			Self::Runtime(error)
+		}
+	}
+
+	impl AssetFaucet {
+		/// A faucet minting `drip` of the asset `asset_id`.
+		#[ink(constructor)]
+		pub fn new(asset_id: AssetId, drip: Balance) -> Self {
+			Self { owner: Self::env().caller(), asset_id, drip, last_drip: None }
+		}
+
+		/// The amount of the asset the faucet holds.
+		#[ink(message)]
+		pub fn reserve(&self) -> Balance {
+			self.env().extension().balance_of(self.asset_id, self.env().account_id())
+		}
+
+		/// The total supply of the asset.
+		#[ink(message)]
+		pub fn total_supply(&self) -> Balance {
+			self.env().extension().total_supply(self.asset_id)
+		}
+
+		/// Mint the drip to the caller.
+		#[ink(message)]
+		pub fn drip(&mut self) -> Result<(), Error> {
+			let now = self.env().block_number();
+			if self.last_drip == Some(now) {
+				return Err(Error::AlreadyDripped)
+			}
+
+			let caller = self.env().caller();
+			self.env().extension().mint(self.asset_id, caller, self.drip)?;
+			self.last_drip = Some(now);
+
+			self.env().emit_event(Dripped { to: caller, amount: self.drip });
+
+			Ok(())
+		}
+
+		/// Give `amount` of the asset the faucet holds to `to`.
+		#[ink(message)]
+		pub fn withdraw(&mut self, to: AccountId, amount: Balance) -> Result<(), Error> {
+			if self.env().caller() != self.owner {
+				return Err(Error::NotOwner)
+			}
+
+			self.env().extension().transfer(self.asset_id, to, amount)?;
+
+			Ok(())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use ink_env::test::{self, DefaultAccounts};
+		use ink_lang as ink;
+		use rusty_node_extension::{
+			mock::{mock_call, mock_query},
+			RustyEnvironment,
+		};
+		use scale::Decode;
+
+		const ASSET_ID: AssetId = 7;
+
+		fn accounts() -> DefaultAccounts<RustyEnvironment> {
+			test::default_accounts::<RustyEnvironment>()
+		}
+
+		/// A faucet dripping 100, instantiated by Alice.
+		fn faucet() -> AssetFaucet {
+			test::set_caller::<RustyEnvironment>(accounts().alice);
+			AssetFaucet::new(ASSET_ID, 100)
+		}
+
+		#[ink::test]
+		fn reserve_is_the_balance_of_the_contract() {
+			let faucet = faucet();
+			let contract = test::callee::<RustyEnvironment>();
+			mock_query(0x0001_0001, (ASSET_ID, contract), 1_000u128);
+
+			assert_eq!(faucet.reserve(), 1_000);
+		}
+
+		#[ink::test]
+		fn drip_mints_to_the_caller() {
+			let mut faucet = faucet();
+			test::set_caller::<RustyEnvironment>(accounts().bob);
+			mock_call(0x0001_0004, (ASSET_ID, accounts().bob, 100u128), 0);
+
+			assert_eq!(faucet.drip(), Ok(()));
+			assert_eq!(faucet.drip(), Err(Error::AlreadyDripped));
+
+			let events = test::recorded_events().collect::<Vec<_>>();
+			assert_eq!(events.len(), 1);
+			let event = <Event as Decode>::decode(&mut &events[0].data[..]).unwrap();
+			assert!(matches!(event, Event::Dripped(Dripped { amount: 100, .. })));
+		}
+
+		#[ink::test]
+		fn drip_reports_rejected_mints() {
+			let mut faucet = faucet();
+			mock_call(0x0001_0004, (ASSET_ID, accounts().alice, 100u128), 1);
+
+			assert_eq!(faucet.drip(), Err(Error::Runtime(ExtensionError::Failed)));
+			assert_eq!(test::recorded_events().count(), 0);
+		}
+
+		#[ink::test]
+		fn only_the_owner_withdraws() {
+			let mut faucet = faucet();
+			mock_call(0x0001_0003, (ASSET_ID, accounts().charlie, 50u128), 0);
+
+			assert_eq!(faucet.withdraw(accounts().charlie, 50), Ok(()));
+			test::set_caller::<RustyEnvironment>(accounts().bob);
+			assert_eq!(faucet.withdraw(accounts().charlie, 50), Err(Error::NotOwner));
+		}
+	}
+}
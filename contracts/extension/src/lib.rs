@@ -0,0 +1,108 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! The chain extension of the rusty node runtime, as seen by ink! contracts.
+//!
+//! Contracts running on the node declare [`RustyEnvironment`] as their environment, e.g. with
+//! `#[ink::contract(env = rusty_node_extension::RustyEnvironment)]`, and call the functions of
+//! [`RuntimeExtension`] with `self.env().extension()`. The ids of the functions match the ones the
+//! `chain_extension` module of the runtime dispatches.
+
+use ink_env::{DefaultEnvironment, Environment};
+use ink_lang as ink;
+
+pub type AccountId = <DefaultEnvironment as Environment>::AccountId;
+pub type AssetId = u32;
+pub type Balance = <DefaultEnvironment as Environment>::Balance;
+pub type ClassId = u32;
+pub type InstanceId = u32;
+
+#[cfg(feature = "std")]
+pub mod mock;
+
+/// The functions the runtime exposes to contracts.
+#[ink::chain_extension]
+pub trait RuntimeExtension {
+	type ErrorCode = ExtensionError;
+
+	/// The balance of `who` in the asset `asset_id`.
+	#[ink(extension = 0x0001_0001, handle_status = false, returns_result = false)]
+	fn balance_of(asset_id: AssetId, who: AccountId) -> Balance;
+
+	/// The total supply of the asset `asset_id`.
+	#[ink(extension = 0x0001_0002, handle_status = false, returns_result = false)]
+	fn total_supply(asset_id: AssetId) -> Balance;
+
+	/// Transfer `amount` of the asset `asset_id` from the contract to `to`.
+	#[ink(extension = 0x0001_0003, returns_result = false)]
+	fn transfer(asset_id: AssetId, to: AccountId, amount: Balance);
+
+	/// Mint `amount` of the asset `asset_id` to `to`, the contract being the issuer of the asset.
+	#[ink(extension = 0x0001_0004, returns_result = false)]
+	fn mint(asset_id: AssetId, to: AccountId, amount: Balance);
+
+	/// The amount of the asset `asset_id` that `owner` approved `delegate` to transfer.
+	#[ink(extension = 0x0001_0005, handle_status = false, returns_result = false)]
+	fn allowance(asset_id: AssetId, owner: AccountId, delegate: AccountId) -> Balance;
+
+	/// Approve `delegate` to transfer `amount` of the asset `asset_id` of the contract, on top of
+	/// what it is already approved to. The approval deposit is reserved from the contract.
+	#[ink(extension = 0x0001_0006, returns_result = false)]
+	fn approve(asset_id: AssetId, delegate: AccountId, amount: Balance);
+
+	/// Cancel the approval of `delegate` to transfer the asset `asset_id` of the contract.
+	#[ink(extension = 0x0001_0007, returns_result = false)]
+	fn cancel_approval(asset_id: AssetId, delegate: AccountId);
+
+	/// Transfer `amount` of the asset `asset_id` from `owner` to `to`, out of what `owner` approved
+	/// the contract to transfer.
+	#[ink(extension = 0x0001_0008, returns_result = false)]
+	fn transfer_from(asset_id: AssetId, owner: AccountId, to: AccountId, amount: Balance);
+
+	/// The owner of the item `instance` of the class `class`, if it exists.
+	#[ink(extension = 0x0002_0001, handle_status = false, returns_result = false)]
+	fn owner(class: ClassId, instance: InstanceId) -> Option<AccountId>;
+
+	/// Whether `who` is approved to transfer the item `instance` of the class `class`.
+	#[ink(extension = 0x0002_0002, handle_status = false, returns_result = false)]
+	fn is_approved(class: ClassId, instance: InstanceId, who: AccountId) -> bool;
+
+	/// Transfer the item `instance` of the class `class` to `to`, the contract owning it or being
+	/// approved to transfer it.
+	#[ink(extension = 0x0002_0003, returns_result = false)]
+	fn transfer_item(class: ClassId, instance: InstanceId, to: AccountId);
+}
+
+/// The status of a failed call of the runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ExtensionError {
+	/// The runtime rejected the call, which was reverted.
+	Failed,
+}
+
+impl ink_env::chain_extension::FromStatusCode for ExtensionError {
+	fn from_status_code(status_code: u32) -> Result<(), Self> {
+		match status_code {
+			0 => Ok(()),
+			1 => Err(Self::Failed),
+			_ => panic!("unknown status code of the runtime"),
+		}
+	}
+}
+
+/// The environment of contracts running on the rusty node: the default one, extended with
+/// [`RuntimeExtension`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum RustyEnvironment {}
+
+impl Environment for RustyEnvironment {
+	const MAX_EVENT_TOPICS: usize = <DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+	type AccountId = AccountId;
+	type Balance = Balance;
+	type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+	type ChainExtension = RuntimeExtension;
+	type Hash = <DefaultEnvironment as Environment>::Hash;
+	type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+}
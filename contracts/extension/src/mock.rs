@@ -0,0 +1,45 @@
+//! Mocks of the functions of the chain extension, for the off-chain tests of contracts.
+
+use ink_env::test::{register_chain_extension, ChainExtension};
+use scale::Encode;
+
+/// The function `func_id` of the chain extension, checking it is called with `input`, and
+/// returning `output` with the status `status`.
+pub struct MockFunction {
+	pub func_id: u32,
+	pub input: Vec<u8>,
+	pub output: Vec<u8>,
+	pub status: u32,
+}
+
+impl ChainExtension for MockFunction {
+	fn func_id(&self) -> u32 {
+		self.func_id
+	}
+
+	fn call(&mut self, input: &[u8], output: &mut Vec<u8>) -> u32 {
+		assert_eq!(input, &self.input[..], "unexpected input of function {:#x}", self.func_id);
+		output.extend_from_slice(&self.output);
+		self.status
+	}
+}
+
+/// Register the function `func_id`, returning `output` when called with `input`.
+pub fn mock_query(func_id: u32, input: impl Encode, output: impl Encode) {
+	register_chain_extension(MockFunction {
+		func_id,
+		input: input.encode(),
+		output: output.encode(),
+		status: 0,
+	});
+}
+
+/// Register the function `func_id`, returning the status `status` when called with `input`.
+pub fn mock_call(func_id: u32, input: impl Encode, status: u32) {
+	register_chain_extension(MockFunction {
+		func_id,
+		input: input.encode(),
+		output: Vec::new(),
+		status,
+	});
+}
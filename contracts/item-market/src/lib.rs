@@ -0,0 +1,270 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A marketplace of the items of the uniques pallet, showing how ink! contracts trade them
+//! through the chain extension of the runtime.
+//!
+//! A seller approves the marketplace to transfer an item, with `uniques.approveTransfer`, and
+//! lists it at a price in the native currency. The item stays with its seller until a buyer pays
+//! the price: the marketplace then transfers the item to the buyer, as its approved delegate, and
+//! pays the seller. The item is never escrowed, so a listing goes stale when the seller transfers
+//! the item or revokes the approval, and buying it fails.
+
+use ink_lang as ink;
+
+#[ink::contract(env = rusty_node_extension::RustyEnvironment)]
+mod item_market {
+	use ink_storage::{traits::SpreadAllocate, Mapping};
+	use rusty_node_extension::{ClassId, ExtensionError, InstanceId};
+
+	#[ink(storage)]
+	#[derive(SpreadAllocate)]
+	pub struct ItemMarket {
+		/// The seller and price of each listed item.
+		listings: Mapping<(ClassId, InstanceId), (AccountId, Balance)>,
+	}
+
+	/// An item has been listed.
+	#[ink(event)]
+	pub struct Listed {
+		class: ClassId,
+		instance: InstanceId,
+		#[ink(topic)]
+		seller: AccountId,
+		price: Balance,
+	}
+
+	/// An item has been withdrawn from sale.
+	#[ink(event)]
+	pub struct Unlisted {
+		class: ClassId,
+		instance: InstanceId,
+	}
+
+	/// An item has been sold.
+	#[ink(event)]
+	pub struct Sold {
+		class: ClassId,
+		instance: InstanceId,
+		#[ink(topic)]
+		seller: AccountId,
+		#[ink(topic)]
+		buyer: AccountId,
+		price: Balance,
+	}
+
+	#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+	pub enum Error {
+		/// The caller does not own the item.
+		NotOwner,
+		/// The marketplace is not approved to transfer the item.
+		NotApproved,
+		/// The item is not listed.
+		NotListed,
+		/// The value of the call is not the price of the item.
+		WrongPrice,
+		/// The runtime rejected the transfer of the item, e.g. as the listing is stale.
+		Runtime(ExtensionError),
+	}
+
+	impl From<ExtensionError> for Error {
+		fn from(error: ExtensionError) -> Self {
+			Self::Runtime(error)
+		}
+	}
+
+	impl ItemMarket {
+		/// A marketplace without listings.
+		#[ink(constructor)]
+		pub fn new() -> Self {
+			ink_lang::utils::initialize_contract(|_| {})
+		}
+
+		/// The price of a listed item.
+		#[ink(message)]
+		pub fn price(&self, class: ClassId, instance: InstanceId) -> Option<Balance> {
+			self.listings.get(&(class, instance)).map(|(_, price)| price)
+		}
+
+		/// List an item of the caller at `price`, the marketplace being approved to transfer it.
+		#[ink(message)]
+		pub fn list(
+			&mut self,
+			class: ClassId,
+			instance: InstanceId,
+			price: Balance,
+		) -> Result<(), Error> {
+			let seller = self.env().caller();
+			if self.env().extension().owner(class, instance) != Some(seller) {
+				return Err(Error::NotOwner)
+			}
+			if !self.env().extension().is_approved(class, instance, self.env().account_id()) {
+				return Err(Error::NotApproved)
+			}
+
+			self.listings.insert(&(class, instance), &(seller, price));
+
+			self.env().emit_event(Listed { class, instance, seller, price });
+
+			Ok(())
+		}
+
+		/// Withdraw an item of the caller from sale.
+		#[ink(message)]
+		pub fn unlist(&mut self, class: ClassId, instance: InstanceId) -> Result<(), Error> {
+			let (seller, _) = self.listings.get(&(class, instance)).ok_or(Error::NotListed)?;
+			if self.env().caller() != seller {
+				return Err(Error::NotOwner)
+			}
+
+			self.listings.remove(&(class, instance));
+
+			self.env().emit_event(Unlisted { class, instance });
+
+			Ok(())
+		}
+
+		/// Buy a listed item, paying its price as the value of the call.
+		///
+		/// The value is refunded if the item cannot be bought.
+		#[ink(message, payable)]
+		pub fn buy(&mut self, class: ClassId, instance: InstanceId) -> Result<(), Error> {
+			let result = self.try_buy(class, instance);
+			if result.is_err() {
+				let (buyer, value) = (self.env().caller(), self.env().transferred_value());
+				let refund = self.env().transfer(buyer, value);
+				assert!(refund.is_ok(), "the buyer could not be refunded");
+			}
+			result
+		}
+
+		fn try_buy(&mut self, class: ClassId, instance: InstanceId) -> Result<(), Error> {
+			let (seller, price) = self.listings.get(&(class, instance)).ok_or(Error::NotListed)?;
+			if self.env().transferred_value() != price {
+				return Err(Error::WrongPrice)
+			}
+
+			let buyer = self.env().caller();
+			self.env().extension().transfer_item(class, instance, buyer)?;
+			self.listings.remove(&(class, instance));
+			// Trapping reverts the transfer of the item along with the call.
+			let paid = self.env().transfer(seller, price);
+			assert!(paid.is_ok(), "the seller could not be paid");
+
+			self.env().emit_event(Sold { class, instance, seller, buyer, price });
+
+			Ok(())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use ink_env::test::{self, DefaultAccounts};
+		use ink_lang as ink;
+		use rusty_node_extension::{
+			mock::{mock_call, mock_query},
+			RustyEnvironment,
+		};
+
+		const CLASS: ClassId = 3;
+		const INSTANCE: InstanceId = 7;
+
+		fn accounts() -> DefaultAccounts<RustyEnvironment> {
+			test::default_accounts::<RustyEnvironment>()
+		}
+
+		fn balance(who: AccountId) -> Balance {
+			test::get_account_balance::<RustyEnvironment>(who).unwrap()
+		}
+
+		/// Have `owner` own the item, and approve `approved` to transfer it.
+		fn mock_item(owner: AccountId, approved: AccountId) {
+			mock_query(0x0002_0001, (CLASS, INSTANCE), Some(owner));
+			mock_query(0x0002_0002, (CLASS, INSTANCE, approved), true);
+		}
+
+		/// A marketplace where Alice listed the item at 100.
+		fn market() -> ItemMarket {
+			let mut market = ItemMarket::new();
+			mock_item(accounts().alice, test::callee::<RustyEnvironment>());
+			test::set_caller::<RustyEnvironment>(accounts().alice);
+			assert_eq!(market.list(CLASS, INSTANCE, 100), Ok(()));
+			market
+		}
+
+		/// Have Bob call `buy` with `value`, as if he transferred it to the marketplace.
+		fn buy(market: &mut ItemMarket, value: Balance) -> Result<(), Error> {
+			let contract = test::callee::<RustyEnvironment>();
+			test::set_caller::<RustyEnvironment>(accounts().bob);
+			test::set_account_balance::<RustyEnvironment>(contract, balance(contract) + value);
+			test::set_value_transferred::<RustyEnvironment>(value);
+			market.buy(CLASS, INSTANCE)
+		}
+
+		#[ink::test]
+		fn list_requires_the_owner_and_the_approval() {
+			let mut market = ItemMarket::new();
+			let contract = test::callee::<RustyEnvironment>();
+			test::set_caller::<RustyEnvironment>(accounts().alice);
+
+			mock_item(accounts().bob, contract);
+			assert_eq!(market.list(CLASS, INSTANCE, 100), Err(Error::NotOwner));
+
+			mock_item(accounts().alice, accounts().charlie);
+			mock_query(0x0002_0002, (CLASS, INSTANCE, contract), false);
+			assert_eq!(market.list(CLASS, INSTANCE, 100), Err(Error::NotApproved));
+
+			mock_item(accounts().alice, contract);
+			assert_eq!(market.list(CLASS, INSTANCE, 100), Ok(()));
+			assert_eq!(market.price(CLASS, INSTANCE), Some(100));
+		}
+
+		#[ink::test]
+		fn only_the_seller_unlists() {
+			let mut market = market();
+
+			test::set_caller::<RustyEnvironment>(accounts().bob);
+			assert_eq!(market.unlist(CLASS, INSTANCE), Err(Error::NotOwner));
+
+			test::set_caller::<RustyEnvironment>(accounts().alice);
+			assert_eq!(market.unlist(CLASS, INSTANCE), Ok(()));
+			assert_eq!(market.price(CLASS, INSTANCE), None);
+		}
+
+		#[ink::test]
+		fn buy_transfers_the_item_and_pays_the_seller() {
+			let mut market = market();
+			let seller_balance = balance(accounts().alice);
+			mock_call(0x0002_0003, (CLASS, INSTANCE, accounts().bob), 0);
+
+			assert_eq!(buy(&mut market, 100), Ok(()));
+
+			assert_eq!(balance(accounts().alice), seller_balance + 100);
+			assert_eq!(market.price(CLASS, INSTANCE), None);
+			assert_eq!(test::recorded_events().count(), 2);
+		}
+
+		#[ink::test]
+		fn buy_refunds_a_wrong_price() {
+			let mut market = market();
+			let buyer_balance = balance(accounts().bob);
+
+			assert_eq!(buy(&mut market, 50), Err(Error::WrongPrice));
+
+			assert_eq!(balance(accounts().bob), buyer_balance + 50);
+			assert_eq!(market.price(CLASS, INSTANCE), Some(100));
+		}
+
+		#[ink::test]
+		fn buy_refunds_a_stale_listing() {
+			let mut market = market();
+			let buyer_balance = balance(accounts().bob);
+			mock_call(0x0002_0003, (CLASS, INSTANCE, accounts().bob), 1);
+
+			assert_eq!(buy(&mut market, 100), Err(Error::Runtime(ExtensionError::Failed)));
+
+			assert_eq!(balance(accounts().bob), buyer_balance + 100);
+		}
+	}
+}
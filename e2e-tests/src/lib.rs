@@ -0,0 +1,98 @@
+//! End-to-end tests of the node: the tests spawn a development node, submit extrinsics to it with
+//! [`rusty_node_client`], and check the events and storage of the finalized blocks.
+//!
+//! The node binary is built beforehand, with `cargo build --release`, and found in the target
+//! directory of the workspace unless the `RUSTY_NODE` environment variable gives its path.
+
+#[cfg(test)]
+mod tests;
+
+use rusty_node_client::{Client, Error, Signer};
+use sp_keyring::AccountKeyring;
+use std::{
+	env,
+	process::{Child, Command, Stdio},
+	sync::atomic::{AtomicU16, Ordering},
+	time::Duration,
+};
+
+/// The environment variable overriding the path of the node binary.
+pub const RUSTY_NODE: &str = "RUSTY_NODE";
+
+/// How long to wait for a spawned node to serve its websocket.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The next ports of spawned nodes, so tests running concurrently don't share a node.
+static NEXT_PORT: AtomicU16 = AtomicU16::new(30_000);
+
+/// A development node, killed when dropped.
+pub struct TestNode {
+	process: Child,
+	client: Client,
+}
+
+impl TestNode {
+	/// Spawn a development node on a temporary database, and connect to it.
+	pub async fn spawn() -> Result<Self, Error> {
+		let path = env::var(RUSTY_NODE).unwrap_or_else(|_| {
+			concat!(env!("CARGO_MANIFEST_DIR"), "/../target/release/rusty-node").into()
+		});
+		let port = NEXT_PORT.fetch_add(3, Ordering::SeqCst);
+		let ws_port = port + 2;
+		let process = Command::new(&path)
+			.args(["--dev", "--tmp"])
+			.arg(format!("--port={}", port))
+			.arg(format!("--rpc-port={}", port + 1))
+			.arg(format!("--ws-port={}", ws_port))
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.spawn()
+			.unwrap_or_else(|e| panic!("{} could not be spawned: {}", path, e));
+
+		let url = format!("ws://127.0.0.1:{}", ws_port);
+		let mut waited = Duration::ZERO;
+		loop {
+			match Client::new(&url).await {
+				Ok(client) => return Ok(Self { process, client }),
+				Err(_) if waited < STARTUP_TIMEOUT => {
+					tokio::time::sleep(Duration::from_millis(500)).await;
+					waited += Duration::from_millis(500);
+				},
+				Err(e) => return Err(e),
+			}
+		}
+	}
+
+	pub fn client(&self) -> &Client {
+		&self.client
+	}
+}
+
+impl Drop for TestNode {
+	fn drop(&mut self) {
+		let _ = self.process.kill();
+	}
+}
+
+/// The signer of a well-known development account.
+pub fn signer(account: AccountKeyring) -> Signer {
+	Signer::new(account.pair())
+}
+
+/// The environment variable overriding the path of the runtime to upgrade to.
+pub const UPGRADE_WASM: &str = "RUSTY_NODE_UPGRADE_WASM";
+
+/// The code of the runtime built with the `upgrade-test` feature, which the upgrade tests set.
+pub fn upgrade_code() -> Vec<u8> {
+	let path = env::var(UPGRADE_WASM).unwrap_or_else(|_| {
+		concat!(
+			env!("CARGO_MANIFEST_DIR"),
+			"/../target/upgrade/release/wbuild/rusty-node-runtime/",
+			"rusty_node_runtime.compact.compressed.wasm"
+		)
+		.into()
+	});
+	std::fs::read(&path).unwrap_or_else(|e| {
+		panic!("{} could not be read, build it with the upgrade-test feature: {}", path, e)
+	})
+}
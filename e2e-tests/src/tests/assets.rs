@@ -0,0 +1,29 @@
+use crate::{signer, TestNode};
+use rusty_node_client::AssetId;
+use sp_keyring::AccountKeyring::{Alice, Bob};
+
+/// An asset not created at genesis.
+const ASSET_ID: AssetId = AssetId(100);
+
+#[tokio::test]
+async fn create_mint_and_transfer_an_asset() {
+	let node = TestNode::spawn().await.unwrap();
+	let client = node.client();
+	let alice = signer(Alice);
+
+	let created = client.create_asset(&alice, ASSET_ID, Alice.to_account_id(), 1).await.unwrap();
+	assert_eq!(created.asset_id, ASSET_ID);
+	assert_eq!(created.owner, Alice.to_account_id());
+
+	let issued = client.mint_asset(&alice, ASSET_ID, Alice.to_account_id(), 1_000).await.unwrap();
+	assert_eq!((issued.owner, issued.total_supply), (Alice.to_account_id(), 1_000));
+
+	let transferred =
+		client.transfer_asset(&alice, ASSET_ID, Bob.to_account_id(), 400).await.unwrap();
+	assert_eq!(transferred.from, Alice.to_account_id());
+	assert_eq!(transferred.to, Bob.to_account_id());
+	assert_eq!(transferred.amount, 400);
+
+	assert_eq!(client.asset_balance(ASSET_ID, Alice.to_account_id(), None).await.unwrap(), 600);
+	assert_eq!(client.asset_balance(ASSET_ID, Bob.to_account_id(), None).await.unwrap(), 400);
+}
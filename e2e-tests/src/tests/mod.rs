@@ -0,0 +1,4 @@
+mod assets;
+mod nfts;
+mod privileged;
+mod upgrade;
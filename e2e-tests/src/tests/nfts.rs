@@ -0,0 +1,27 @@
+use crate::{signer, TestNode};
+use rusty_node_client::{ClassId, InstanceId};
+use sp_keyring::AccountKeyring::{Alice, Bob};
+
+/// A class not created at genesis.
+const CLASS: ClassId = ClassId(100);
+const INSTANCE: InstanceId = InstanceId(7);
+
+#[tokio::test]
+async fn create_mint_and_transfer_an_nft() {
+	let node = TestNode::spawn().await.unwrap();
+	let client = node.client();
+	let alice = signer(Alice);
+
+	let created = client.create_class(&alice, CLASS, Alice.to_account_id()).await.unwrap();
+	assert_eq!(created.class, CLASS);
+
+	let issued = client.mint_nft(&alice, CLASS, INSTANCE, Alice.to_account_id()).await.unwrap();
+	assert_eq!((issued.instance, issued.owner), (INSTANCE, Alice.to_account_id()));
+
+	let transferred =
+		client.transfer_nft(&alice, CLASS, INSTANCE, Bob.to_account_id()).await.unwrap();
+	assert_eq!(transferred.from, Alice.to_account_id());
+	assert_eq!(transferred.to, Bob.to_account_id());
+
+	assert_eq!(client.nft_owner(CLASS, INSTANCE, None).await.unwrap(), Some(Bob.to_account_id()));
+}
@@ -0,0 +1,47 @@
+use crate::{signer, TestNode};
+use rusty_node_client::{
+	rusty_node::runtime_types::{
+		pallet_assets::pallet::Call as AssetsCall, rusty_node_runtime::Call,
+	},
+	AssetId,
+};
+use sp_keyring::AccountKeyring::{Alice, Bob, Charlie};
+
+/// Assets not created at genesis, forced into existence by sudo and by an operator.
+const SUDO_ASSET_ID: AssetId = AssetId(100);
+const OPERATOR_ASSET_ID: AssetId = AssetId(101);
+
+#[tokio::test]
+async fn sudo_forces_the_creation_of_an_asset() {
+	let node = TestNode::spawn().await.unwrap();
+	let client = node.client();
+
+	let call = Call::Assets(AssetsCall::force_create {
+		id: SUDO_ASSET_ID,
+		owner: Charlie.to_account_id().into(),
+		is_sufficient: true,
+		min_balance: 1,
+	});
+	let sudid = client.sudo(&signer(Alice), call).await.unwrap();
+	assert!(sudid.sudo_result.is_ok());
+
+	// Charlie owns the asset, and mints it as its issuer.
+	client.mint_asset(&signer(Charlie), SUDO_ASSET_ID, Bob.to_account_id(), 10).await.unwrap();
+	assert_eq!(client.asset_balance(SUDO_ASSET_ID, Bob.to_account_id(), None).await.unwrap(), 10);
+}
+
+#[tokio::test]
+async fn operators_force_the_creation_of_assets() {
+	let node = TestNode::spawn().await.unwrap();
+	let client = node.client();
+	let bob = signer(Bob);
+	let charlie = Charlie.to_account_id();
+
+	assert!(client.force_create_asset(&bob, OPERATOR_ASSET_ID, charlie.clone(), 1).await.is_err());
+
+	client.set_operators(&signer(Alice), vec![Bob.to_account_id()]).await.unwrap();
+
+	let created =
+		client.force_create_asset(&bob, OPERATOR_ASSET_ID, charlie.clone(), 1).await.unwrap();
+	assert_eq!((created.asset_id, created.owner), (OPERATOR_ASSET_ID, charlie));
+}
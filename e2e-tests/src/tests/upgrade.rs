@@ -0,0 +1,33 @@
+use crate::{signer, upgrade_code, TestNode};
+use rusty_node_client::subxt::sp_core::storage::StorageKey;
+use sp_keyring::AccountKeyring::{Alice, Bob};
+use std::time::Duration;
+
+/// The key the migration of the upgraded runtime records its `spec_version` under.
+const UPGRADED_KEY: &[u8] = b":rusty-node:upgraded";
+
+#[tokio::test]
+async fn set_code_upgrades_the_runtime_and_migrates_the_storage() {
+	let code = upgrade_code();
+	let node = TestNode::spawn().await.unwrap();
+	let client = node.client();
+	let alice = signer(Alice);
+	let spec_version = client.spec_version().await.unwrap();
+
+	client.set_code(&alice, code).await.unwrap();
+
+	// The new runtime runs from the block after the upgrade.
+	while client.spec_version().await.unwrap() == spec_version {
+		tokio::time::sleep(Duration::from_secs(1)).await;
+	}
+	assert_eq!(client.spec_version().await.unwrap(), spec_version + 1);
+
+	let rpc = client.api().client.rpc();
+	let mark = rpc.storage(&StorageKey(UPGRADED_KEY.to_vec()), None).await.unwrap();
+	assert_eq!(mark.map(|data| data.0), Some((spec_version + 1).to_le_bytes().to_vec()));
+
+	// The pallets still dispatch their calls.
+	client.create_asset(&alice, 100, Alice.to_account_id(), 1).await.unwrap();
+	client.mint_asset(&alice, 100, Bob.to_account_id(), 10).await.unwrap();
+	assert_eq!(client.asset_balance(100, Bob.to_account_id(), None).await.unwrap(), 10);
+}
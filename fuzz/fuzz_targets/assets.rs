@@ -0,0 +1,80 @@
+//! Random sequences of asset transfers, mints, burns and OTC swaps.
+//!
+//! Checks the supply of the native currency and of each asset is conserved, and the invariants
+//! of the OTC swap pallet, after each call.
+
+#![no_main]
+
+use libfuzzer_sys::{
+	arbitrary::{self, Arbitrary},
+	fuzz_target,
+};
+use rusty_node_fuzz::*;
+
+#[derive(Arbitrary, Debug)]
+enum Action {
+	Transfer { from: u8, to: u8, asset: u8, amount: u32 },
+	Mint { caller: u8, to: u8, asset: u8, amount: u32 },
+	Burn { caller: u8, from: u8, asset: u8, amount: u32 },
+	CreateOffer {
+		maker: u8,
+		give_asset: u8,
+		give_amount: u32,
+		want_amount: u32,
+		counterparty: Option<u8>,
+		expiry: u8,
+	},
+	TakeOffer { taker: u8, offer_id: u8 },
+	CloseOffer { caller: u8, offer_id: u8 },
+	NextBlock,
+}
+
+fn apply(action: Action) {
+	// Calls failing is expected: only the state they leave matters.
+	let _ = match action {
+		Action::Transfer { from, to, asset: index, amount } => Assets::transfer(
+			Origin::signed(account(from)),
+			asset(index),
+			account(to),
+			amount.into(),
+		),
+		Action::Mint { caller, to, asset: index, amount } =>
+			Assets::mint(Origin::signed(account(caller)), asset(index), account(to), amount.into()),
+		Action::Burn { caller, from, asset: index, amount } => Assets::burn(
+			Origin::signed(account(caller)),
+			asset(index),
+			account(from),
+			amount.into(),
+		),
+		Action::CreateOffer { maker, give_asset, give_amount, want_amount, counterparty, expiry } =>
+			OtcSwap::create_offer(
+				Origin::signed(account(maker)),
+				asset(give_asset),
+				give_amount.into(),
+				asset(give_asset.wrapping_add(1)),
+				want_amount.into(),
+				counterparty.map(account),
+				System::block_number() + u64::from(expiry),
+			),
+		Action::TakeOffer { taker, offer_id } =>
+			OtcSwap::take_offer(Origin::signed(account(taker)), offer_id.into()),
+		Action::CloseOffer { caller, offer_id } =>
+			OtcSwap::close_offer(Origin::signed(account(caller)), offer_id.into()),
+		Action::NextBlock => {
+			System::set_block_number(System::block_number() + 1);
+			Ok(())
+		},
+	};
+}
+
+fuzz_target!(|actions: Vec<Action>| {
+	new_test_ext().execute_with(|| {
+		for action in actions {
+			apply(action);
+
+			check_balances();
+			check_assets();
+			OtcSwap::try_state().unwrap();
+		}
+	})
+});
@@ -0,0 +1,68 @@
+//! Random sequences of nonfungible item mints, transfers and burns, and kitty trades.
+//!
+//! Checks the supply of the native currency is conserved, items are indexed by their owner, and
+//! the invariants of the kitties pallet, after each call.
+
+#![no_main]
+
+use libfuzzer_sys::{
+	arbitrary::{self, Arbitrary},
+	fuzz_target,
+};
+use rusty_node_fuzz::*;
+
+#[derive(Arbitrary, Debug)]
+enum Action {
+	Mint { caller: u8, instance: u8, owner: u8 },
+	Transfer { caller: u8, instance: u8, to: u8 },
+	Burn { caller: u8, instance: u8 },
+	CreateKitty { owner: u8 },
+	BreedKitty { owner: u8, parent_1: u8, parent_2: u8 },
+	TransferKitty { from: u8, to: u8, kitty_id: u8 },
+	SetPrice { owner: u8, kitty_id: u8, price: Option<u16> },
+	BuyKitty { buyer: u8, kitty_id: u8, max_price: u16 },
+	NextBlock,
+}
+
+fn apply(action: Action) {
+	// Calls failing is expected: only the state they leave matters.
+	let _ = match action {
+		Action::Mint { caller, instance, owner } =>
+			Uniques::mint(Origin::signed(account(caller)), CLASS, instance.into(), account(owner)),
+		Action::Transfer { caller, instance, to } =>
+			Uniques::transfer(Origin::signed(account(caller)), CLASS, instance.into(), account(to)),
+		Action::Burn { caller, instance } =>
+			Uniques::burn(Origin::signed(account(caller)), CLASS, instance.into(), None),
+		Action::CreateKitty { owner } => Kitties::create_kitty(Origin::signed(account(owner))),
+		Action::BreedKitty { owner, parent_1, parent_2 } => Kitties::breed_kitty(
+			Origin::signed(account(owner)),
+			parent_1.into(),
+			parent_2.into(),
+		),
+		Action::TransferKitty { from, to, kitty_id } =>
+			Kitties::transfer(Origin::signed(account(from)), account(to), kitty_id.into()),
+		Action::SetPrice { owner, kitty_id, price } => Kitties::set_price(
+			Origin::signed(account(owner)),
+			kitty_id.into(),
+			price.map(Into::into),
+		),
+		Action::BuyKitty { buyer, kitty_id, max_price } =>
+			Kitties::buy_kitty(Origin::signed(account(buyer)), kitty_id.into(), max_price.into()),
+		Action::NextBlock => {
+			System::set_block_number(System::block_number() + 1);
+			Ok(())
+		},
+	};
+}
+
+fuzz_target!(|actions: Vec<Action>| {
+	new_test_ext().execute_with(|| {
+		for action in actions {
+			apply(action);
+
+			check_balances();
+			check_uniques();
+			Kitties::try_state().unwrap();
+		}
+	})
+});
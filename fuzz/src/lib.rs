@@ -0,0 +1,256 @@
+//! Mock runtime and invariants shared by the fuzz targets.
+//!
+//! The targets run random sequences of calls, from a handful of accounts, against [`Runtime`]:
+//! failing calls are expected, but the storage must stay consistent after each of them. The
+//! property tests run shorter sequences against it with proptest, which shrinks the failing ones,
+//! and also check the effect of each successful call.
+
+use frame_support::{
+	parameter_types,
+	traits::{
+		tokens::{
+			fungibles::Inspect as _,
+			nonfungibles::{Inspect as _, InspectEnumerable as _},
+		},
+		Currency, Randomness,
+	},
+	PalletId,
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
+};
+use test_utils::ExtBuilder;
+
+#[cfg(test)]
+mod tests;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+/// The accounts calls are made from, funded in every asset.
+pub const ACCOUNTS: [u64; 4] = [1, 2, 3, 4];
+
+/// The assets created at genesis, owned by the first account.
+pub const ASSETS: [u32; 2] = [0, 1];
+
+/// The class of nonfungible items, owned by the first account.
+pub const CLASS: u32 = 0;
+
+const INITIAL_BALANCE: u64 = 1_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
+		OtcSwap: pallet_otc_swap::{Pallet, Call, Storage, Event<T>},
+		Kitties: pallet_kitties::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: u64 = 0;
+	pub const ApprovalDeposit: u64 = 0;
+	pub const MetadataDepositBase: u64 = 0;
+	pub const MetadataDepositPerByte: u64 = 0;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Runtime {
+	type ApprovalDeposit = ApprovalDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = u32;
+	type Balance = u64;
+	type Currency = Balances;
+	type Event = Event;
+	type Extra = ();
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Freezer = ();
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ClassDeposit: u64 = 0;
+	pub const InstanceDeposit: u64 = 0;
+	pub const AttributeDepositBase: u64 = 0;
+	pub const DepositPerByte: u64 = 0;
+	pub const KeyLimit: u32 = 32;
+	pub const ValueLimit: u32 = 64;
+}
+
+impl pallet_uniques::Config for Runtime {
+	type AttributeDepositBase = AttributeDepositBase;
+	type ClassDeposit = ClassDeposit;
+	type ClassId = u32;
+	type Currency = Balances;
+	type DepositPerByte = DepositPerByte;
+	type Event = Event;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type InstanceDeposit = InstanceDeposit;
+	type InstanceId = u32;
+	type KeyLimit = KeyLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type StringLimit = StringLimit;
+	type ValueLimit = ValueLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const OtcSwapPalletId: PalletId = PalletId(*b"py/otcsw");
+	/// No fee is charged on trades, so that balances only move between the traders.
+	pub const TradeFee: Perbill = Perbill::from_percent(0);
+	pub const FeeCollector: u64 = 0;
+}
+
+impl pallet_otc_swap::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type FeeCollector = FeeCollector;
+	type OnFeeCharged = ();
+	type PalletId = OtcSwapPalletId;
+	type TradeFee = TradeFee;
+	type WeightInfo = ();
+}
+
+/// Randomness derived from the subject and the block number, so runs are reproducible.
+pub struct FuzzRandomness;
+
+impl Randomness<H256, u64> for FuzzRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block_number = System::block_number();
+		let seed = [subject, &block_number.to_le_bytes()[..]].concat();
+		(H256::from(sp_io::hashing::blake2_256(&seed)), block_number)
+	}
+}
+
+parameter_types! {
+	pub const MaxKittiesOwned: u32 = 8;
+}
+
+impl pallet_kitties::Config for Runtime {
+	type Currency = Balances;
+	type Event = Event;
+	type FeeCollector = FeeCollector;
+	type MaxKittiesOwned = MaxKittiesOwned;
+	type OnFeeCharged = ();
+	type Pauses = ();
+	type Randomness = FuzzRandomness;
+	type TradeFee = TradeFee;
+	type WeightInfo = ();
+}
+
+/// Build the externalities the fuzz targets start from.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut builder = ExtBuilder::<Runtime>::default()
+		.balances(ACCOUNTS.iter().map(|who| (*who, INITIAL_BALANCE)).collect())
+		.class(CLASS, ACCOUNTS[0]);
+	for asset_id in ASSETS {
+		builder = builder.asset(
+			asset_id,
+			ACCOUNTS[0],
+			ACCOUNTS.iter().map(|who| (*who, INITIAL_BALANCE)).collect(),
+		);
+	}
+	builder.build()
+}
+
+/// The account at `index`, wrapping around.
+pub fn account(index: u8) -> u64 {
+	ACCOUNTS[index as usize % ACCOUNTS.len()]
+}
+
+/// The asset at `index`, wrapping around.
+pub fn asset(index: u8) -> u32 {
+	ASSETS[index as usize % ASSETS.len()]
+}
+
+/// Check the native currency is conserved: its total issuance is what the accounts hold.
+pub fn check_balances() {
+	let held = frame_system::Account::<Runtime>::iter_values()
+		.fold(0u64, |total, account| total + account.data.free + account.data.reserved);
+	assert_eq!(Balances::total_issuance(), held, "native issuance not matching the accounts");
+}
+
+/// Check the supply of each asset is what the accounts hold, escrow included.
+pub fn check_assets() {
+	let holders = ACCOUNTS.iter().copied().chain([OtcSwap::account_id()]);
+	for asset_id in ASSETS {
+		let held =
+			holders.clone().fold(0u64, |total, who| total + Assets::balance(asset_id, &who));
+		assert_eq!(
+			Assets::total_issuance(asset_id),
+			held,
+			"asset supply not matching the accounts"
+		);
+	}
+}
+
+/// Check each item of the class is owned, and listed in its owner's items.
+pub fn check_uniques() {
+	for instance in Uniques::instances(&CLASS) {
+		let owner = Uniques::owner(&CLASS, &instance).expect("items are owned");
+		assert!(
+			Uniques::owned_in_class(&CLASS, &owner).any(|owned| owned == instance),
+			"item missing from its owner's"
+		);
+	}
+}
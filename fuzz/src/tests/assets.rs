@@ -0,0 +1,116 @@
+use crate::*;
+use frame_support::{dispatch::DispatchResult, traits::tokens::fungibles::Inspect};
+use proptest::prelude::*;
+
+#[derive(Clone, Debug)]
+enum Op {
+	Mint { caller: u8, to: u8, asset: u8, amount: u64 },
+	Burn { caller: u8, from: u8, asset: u8, amount: u64 },
+	Transfer { from: u8, to: u8, asset: u8, amount: u64 },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+	let amount = || 0..=2 * INITIAL_BALANCE;
+	prop_oneof![
+		(any::<u8>(), any::<u8>(), any::<u8>(), amount())
+			.prop_map(|(caller, to, asset, amount)| Op::Mint { caller, to, asset, amount }),
+		(any::<u8>(), any::<u8>(), any::<u8>(), amount())
+			.prop_map(|(caller, from, asset, amount)| Op::Burn { caller, from, asset, amount }),
+		(any::<u8>(), any::<u8>(), any::<u8>(), amount())
+			.prop_map(|(from, to, asset, amount)| Op::Transfer { from, to, asset, amount }),
+	]
+}
+
+fn assets_event(result: DispatchResult) -> Option<pallet_assets::Event<Runtime>> {
+	result.ok()?;
+	System::events().into_iter().rev().find_map(|record| match record.event {
+		Event::Assets(event) => Some(event),
+		_ => None,
+	})
+}
+
+/// Apply `op`, and check a successful one moved the balances and the supply as its event reports.
+fn apply(op: Op) {
+	System::reset_events();
+
+	match op {
+		Op::Mint { caller, to, asset: index, amount } => {
+			let (asset_id, owner) = (asset(index), account(to));
+			let supply = Assets::total_issuance(asset_id);
+			let balance = Assets::balance(asset_id, &owner);
+
+			let result = Assets::mint(Origin::signed(account(caller)), asset_id, owner, amount);
+
+			if let Some(event) = assets_event(result) {
+				assert_eq!(
+					event,
+					pallet_assets::Event::Issued { asset_id, owner, total_supply: amount }
+				);
+				assert_eq!(Assets::total_issuance(asset_id), supply + amount);
+				assert_eq!(Assets::balance(asset_id, &owner), balance + amount);
+			}
+		},
+		Op::Burn { caller, from, asset: index, amount } => {
+			let (asset_id, owner) = (asset(index), account(from));
+			let supply = Assets::total_issuance(asset_id);
+			let balance = Assets::balance(asset_id, &owner);
+
+			let result = Assets::burn(Origin::signed(account(caller)), asset_id, owner, amount);
+
+			if let Some(event) = assets_event(result) {
+				let burned = balance - Assets::balance(asset_id, &owner);
+				assert!(burned <= amount, "burned more than requested");
+				assert_eq!(
+					event,
+					pallet_assets::Event::Burned { asset_id, owner, balance: burned }
+				);
+				assert_eq!(Assets::total_issuance(asset_id), supply - burned);
+			}
+		},
+		Op::Transfer { from, to, asset: index, amount } => {
+			let (asset_id, from, to) = (asset(index), account(from), account(to));
+			let supply = Assets::total_issuance(asset_id);
+			let from_balance = Assets::balance(asset_id, &from);
+			let to_balance = Assets::balance(asset_id, &to);
+
+			let result = Assets::transfer(Origin::signed(from), asset_id, to, amount);
+
+			assert_eq!(Assets::total_issuance(asset_id), supply, "transfers mint or burn");
+			if from == to {
+				assert_eq!(Assets::balance(asset_id, &from), from_balance);
+			} else if let Some(event) = assets_event(result) {
+				let moved = from_balance - Assets::balance(asset_id, &from);
+				assert_eq!(Assets::balance(asset_id, &to), to_balance + moved);
+				assert_eq!(
+					event,
+					pallet_assets::Event::Transferred { asset_id, from, to, amount: moved }
+				);
+			}
+		},
+	}
+}
+
+/// Check no account holds more of an asset than its supply.
+fn check_asset_balances() {
+	for asset_id in ASSETS {
+		let supply = Assets::total_issuance(asset_id);
+		for who in ACCOUNTS {
+			assert!(Assets::balance(asset_id, &who) <= supply, "balance exceeding the supply");
+		}
+	}
+}
+
+proptest! {
+	#[test]
+	fn assets_are_conserved(ops in prop::collection::vec(op(), 1..50)) {
+		new_test_ext().execute_with(|| {
+			for op in ops {
+				apply(op);
+
+				check_balances();
+				check_assets();
+				check_asset_balances();
+			}
+		})
+	}
+}
@@ -0,0 +1,121 @@
+use crate::*;
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::tokens::nonfungibles::{Inspect, InspectEnumerable},
+};
+use proptest::prelude::*;
+
+#[derive(Clone, Debug)]
+enum Op {
+	Mint { caller: u8, instance: u8, owner: u8 },
+	Transfer { caller: u8, instance: u8, to: u8 },
+	Burn { caller: u8, instance: u8 },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+	// Few instances, so calls often target existing items.
+	let instance = || 0..8u8;
+	prop_oneof![
+		(any::<u8>(), instance(), any::<u8>())
+			.prop_map(|(caller, instance, owner)| Op::Mint { caller, instance, owner }),
+		(any::<u8>(), instance(), any::<u8>())
+			.prop_map(|(caller, instance, to)| Op::Transfer { caller, instance, to }),
+		(any::<u8>(), instance()).prop_map(|(caller, instance)| Op::Burn { caller, instance }),
+	]
+}
+
+fn uniques_event(result: DispatchResult) -> Option<pallet_uniques::Event<Runtime>> {
+	result.ok()?;
+	System::events().into_iter().rev().find_map(|record| match record.event {
+		Event::Uniques(event) => Some(event),
+		_ => None,
+	})
+}
+
+/// The number of items of the class.
+fn supply() -> usize {
+	Uniques::instances(&CLASS).count()
+}
+
+/// The number of items of the class owned by `who`.
+fn owned(who: u64) -> usize {
+	Uniques::owned_in_class(&CLASS, &who).count()
+}
+
+/// Apply `op`, and check a successful one changed the items as its event reports.
+fn apply(op: Op) {
+	System::reset_events();
+
+	match op {
+		Op::Mint { caller, instance, owner } => {
+			let (instance, owner) = (instance.into(), account(owner));
+			let (supply_before, owned_before) = (supply(), owned(owner));
+
+			let result = Uniques::mint(Origin::signed(account(caller)), CLASS, instance, owner);
+
+			if let Some(event) = uniques_event(result) {
+				assert_eq!(event, pallet_uniques::Event::Issued { class: CLASS, instance, owner });
+				assert_eq!(Uniques::owner(&CLASS, &instance), Some(owner));
+				assert_eq!(supply(), supply_before + 1);
+				assert_eq!(owned(owner), owned_before + 1);
+			}
+		},
+		Op::Transfer { caller, instance, to } => {
+			let (instance, to) = (instance.into(), account(to));
+			let from = Uniques::owner(&CLASS, &instance);
+			let supply_before = supply();
+			let owned_before = from.map(|from| (owned(from), owned(to)));
+
+			let result = Uniques::transfer(Origin::signed(account(caller)), CLASS, instance, to);
+
+			assert_eq!(supply(), supply_before, "transfers mint or burn items");
+			if let (Some(event), Some(from), Some((from_owned, to_owned))) =
+				(uniques_event(result), from, owned_before)
+			{
+				assert_eq!(
+					event,
+					pallet_uniques::Event::Transferred { class: CLASS, instance, from, to }
+				);
+				assert_eq!(Uniques::owner(&CLASS, &instance), Some(to));
+				if from != to {
+					assert_eq!(owned(from), from_owned - 1);
+					assert_eq!(owned(to), to_owned + 1);
+				}
+			}
+		},
+		Op::Burn { caller, instance } => {
+			let instance = instance.into();
+			let owner = Uniques::owner(&CLASS, &instance);
+			let supply_before = supply();
+
+			let result = Uniques::burn(Origin::signed(account(caller)), CLASS, instance, None);
+
+			if let (Some(event), Some(owner)) = (uniques_event(result), owner) {
+				assert_eq!(event, pallet_uniques::Event::Burned { class: CLASS, instance, owner });
+				assert_eq!(Uniques::owner(&CLASS, &instance), None);
+				assert_eq!(supply(), supply_before - 1);
+			}
+		},
+	}
+}
+
+/// Check the accounts own, all together, as many items as the class holds.
+fn check_owned_items() {
+	let owned = ACCOUNTS.iter().map(|who| owned(*who)).sum::<usize>();
+	assert_eq!(owned, supply(), "owned items not matching the class");
+}
+
+proptest! {
+	#[test]
+	fn items_are_conserved(ops in prop::collection::vec(op(), 1..50)) {
+		new_test_ext().execute_with(|| {
+			for op in ops {
+				apply(op);
+
+				check_balances();
+				check_uniques();
+				check_owned_items();
+			}
+		})
+	}
+}
@@ -1,8 +1,11 @@
 use rusty_node_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	opaque::SessionKeys, AccountId, AssetId, AssetsConfig, AuraConfig, Balance, BalancesConfig,
+	ClubConfig, CouncilConfig, ExplorerCountersConfig, GenesisConfig, GrandpaConfig,
+	KittiesConfig, PriceFetchConfig, SessionConfig, Signature, Stablecoin, SudoConfig,
+	SystemConfig, WrappedNative, DEMO_ASSET_ID, STABLE_ASSET_ID, WASM_BINARY,
+	WRAPPED_NATIVE_ASSET_ID,
 };
-use sc_service::ChainType;
+use sc_service::{ChainType, Properties};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{sr25519, Pair, Public};
 use sp_finality_grandpa::AuthorityId as GrandpaId;
@@ -31,87 +34,162 @@ where
 	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
-/// Generate an Aura authority key.
-pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
-	(get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
+/// Generate the account and the Aura and Grandpa keys of an authority.
+pub fn authority_keys_from_seed(s: &str) -> (AccountId, AuraId, GrandpaId) {
+	(
+		get_account_id_from_seed::<sr25519::Public>(s),
+		get_from_seed::<AuraId>(s),
+		get_from_seed::<GrandpaId>(s),
+	)
 }
 
-pub fn development_config() -> Result<ChainSpec, String> {
-	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+/// The well-known development accounts, `//Alice` to `//Ferdie` and their stashes.
+pub fn dev_accounts() -> Vec<AccountId> {
+	["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"]
+		.iter()
+		.flat_map(|name| [name.to_string(), format!("{}//stash", name)])
+		.map(|seed| get_account_id_from_seed::<sr25519::Public>(&seed))
+		.collect()
+}
 
-	Ok(ChainSpec::from_genesis(
-		// Name
-		"Development",
-		// ID
-		"dev",
-		ChainType::Development,
-		move || {
-			testnet_genesis(
-				wasm_binary,
-				// Initial PoA authorities
-				vec![authority_keys_from_seed("Alice")],
-				// Sudo account
-				get_account_id_from_seed::<sr25519::Public>("Alice"),
-				// Pre-funded accounts
-				vec![
-					get_account_id_from_seed::<sr25519::Public>("Alice"),
-					get_account_id_from_seed::<sr25519::Public>("Bob"),
-					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-				],
-				true,
-			)
-		},
-		// Bootnodes
-		vec![],
-		// Telemetry
-		None,
-		// Protocol ID
-		None,
-		// Properties
-		None,
-		// Extensions
-		None,
-	))
+/// The token symbol, decimals and address format polkadot-js displays.
+fn chain_properties() -> Properties {
+	let mut properties = Properties::new();
+	properties.insert("tokenSymbol".into(), "UNIT".into());
+	properties.insert("tokenDecimals".into(), 12.into());
+	properties.insert("ss58Format".into(), 42.into());
+	properties
+}
+
+/// The named genesis presets, each seeding a chain with its own authorities, accounts, assets
+/// and kitties. They are selected by id with `--chain`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Preset {
+	/// A single authority, Alice, and every pallet seeded with demo state.
+	Development,
+	/// Two authorities, Alice and Bob, and demo assets.
+	LocalTestnet,
+	/// Three authorities and only the funded accounts, closer to a fresh public network.
+	Testnet,
 }
 
-pub fn local_testnet_config() -> Result<ChainSpec, String> {
+impl Preset {
+	/// The preset of a chain id.
+	pub fn from_id(id: &str) -> Option<Self> {
+		match id {
+			"dev" => Some(Self::Development),
+			"" | "local" => Some(Self::LocalTestnet),
+			"testnet" => Some(Self::Testnet),
+			_ => None,
+		}
+	}
+
+	fn name(self) -> &'static str {
+		match self {
+			Self::Development => "Development",
+			Self::LocalTestnet => "Local Testnet",
+			Self::Testnet => "Testnet",
+		}
+	}
+
+	fn id(self) -> &'static str {
+		match self {
+			Self::Development => "dev",
+			Self::LocalTestnet => "local_testnet",
+			Self::Testnet => "testnet",
+		}
+	}
+
+	fn chain_type(self) -> ChainType {
+		match self {
+			Self::Development => ChainType::Development,
+			Self::LocalTestnet => ChainType::Local,
+			Self::Testnet => ChainType::Live,
+		}
+	}
+
+	/// The seeds of the initial PoA authorities.
+	fn authorities(self) -> &'static [&'static str] {
+		match self {
+			Self::Development => &["Alice"],
+			Self::LocalTestnet => &["Alice", "Bob"],
+			Self::Testnet => &["Alice", "Bob", "Charlie"],
+		}
+	}
+
+	fn genesis(self, wasm_binary: &[u8]) -> GenesisConfig {
+		let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+		let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+		let seeded = self != Self::Testnet;
+
+		testnet_genesis(
+			wasm_binary,
+			// Initial PoA authorities
+			self.authorities().iter().map(|seed| authority_keys_from_seed(seed)).collect(),
+			// Sudo account
+			alice.clone(),
+			// Pre-funded accounts
+			dev_accounts(),
+			// Price authorities
+			if self == Self::Development { vec![alice.clone()] } else { vec![] },
+			// Demo assets
+			if seeded { demo_assets(&alice) } else { vec![] },
+			// Kitties
+			if self == Self::Development { demo_kitties(&[&alice, &bob]) } else { vec![] },
+			// Club members
+			if self == Self::Development { vec![alice, bob] } else { vec![] },
+			true,
+		)
+	}
+}
+
+/// An asset created at genesis, on top of the wrapped native and stable ones.
+pub struct GenesisAsset {
+	pub id: AssetId,
+	pub owner: AccountId,
+	pub name: &'static str,
+	pub symbol: &'static str,
+	pub decimals: u8,
+	/// The accounts holding the asset, and their balance.
+	pub holders: Vec<(AccountId, Balance)>,
+}
+
+/// A demo token, held by every development account.
+fn demo_assets(owner: &AccountId) -> Vec<GenesisAsset> {
+	vec![GenesisAsset {
+		id: DEMO_ASSET_ID,
+		owner: owner.clone(),
+		name: "Demo",
+		symbol: "DEMO",
+		decimals: 12,
+		holders: dev_accounts().into_iter().map(|who| (who, 1 << 50)).collect(),
+	}]
+}
+
+/// Two kitties for each owner, with DNA derived from the owner and the kitty index.
+fn demo_kitties(owners: &[&AccountId]) -> Vec<(AccountId, [u8; 16])> {
+	owners
+		.iter()
+		.flat_map(|owner| {
+			(0u8..2).map(move |index| {
+				let dna = sp_core::hashing::blake2_128(&[owner.as_ref(), &[index]].concat());
+				((*owner).clone(), dna)
+			})
+		})
+		.collect()
+}
+
+/// The chain spec of a genesis preset.
+pub fn preset_config(preset: Preset) -> Result<ChainSpec, String> {
 	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
 
 	Ok(ChainSpec::from_genesis(
 		// Name
-		"Local Testnet",
+		preset.name(),
 		// ID
-		"local_testnet",
-		ChainType::Local,
-		move || {
-			testnet_genesis(
-				wasm_binary,
-				// Initial PoA authorities
-				vec![
-					authority_keys_from_seed("Alice"),
-					authority_keys_from_seed("Bob"),
-				],
-				// Sudo account
-				get_account_id_from_seed::<sr25519::Public>("Alice"),
-				// Pre-funded accounts
-				vec![
-					get_account_id_from_seed::<sr25519::Public>("Alice"),
-					get_account_id_from_seed::<sr25519::Public>("Bob"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie"),
-					get_account_id_from_seed::<sr25519::Public>("Dave"),
-					get_account_id_from_seed::<sr25519::Public>("Eve"),
-					get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
-				],
-				true,
-			)
-		},
+		preset.id(),
+		preset.chain_type(),
+		move || preset.genesis(wasm_binary),
 		// Bootnodes
 		vec![],
 		// Telemetry
@@ -119,18 +197,23 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 		// Protocol ID
 		None,
 		// Properties
-		None,
+		Some(chain_properties()),
 		// Extensions
 		None,
 	))
 }
 
 /// Configure initial storage state for FRAME modules.
+#[allow(clippy::too_many_arguments)]
 fn testnet_genesis(
 	wasm_binary: &[u8],
-	initial_authorities: Vec<(AuraId, GrandpaId)>,
+	initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	price_authorities: Vec<AccountId>,
+	assets: Vec<GenesisAsset>,
+	kitties: Vec<(AccountId, [u8; 16])>,
+	club_members: Vec<AccountId>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -142,16 +225,58 @@ fn testnet_genesis(
 			// Configure endowed accounts with initial balance of 1 << 60.
 			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
 		},
-		aura: AuraConfig {
-			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect(),
-		},
-		grandpa: GrandpaConfig {
-			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect(),
-		},
+		// The session pallet sets the Aura and Grandpa authorities from the keys of the validators.
+		aura: AuraConfig { authorities: vec![] },
+		grandpa: GrandpaConfig { authorities: vec![] },
 		sudo: SudoConfig {
 			// Assign network admin rights.
 			key: root_key,
 		},
 		transaction_payment: Default::default(),
+		assets: AssetsConfig {
+			// The assets minted by the wrapped-native and stablecoin pallets, then the preset ones.
+			assets: [
+				(WRAPPED_NATIVE_ASSET_ID, WrappedNative::account_id(), true, 1),
+				(STABLE_ASSET_ID, Stablecoin::account_id(), true, 1),
+			]
+			.into_iter()
+			.chain(assets.iter().map(|asset| (asset.id, asset.owner.clone(), true, 1)))
+			.collect(),
+			metadata: [
+				(WRAPPED_NATIVE_ASSET_ID, "Wrapped Unit", "WUNIT", 12),
+				(STABLE_ASSET_ID, "Stable Unit", "SUNIT", 12),
+			]
+			.into_iter()
+			.chain(assets.iter().map(|asset| (asset.id, asset.name, asset.symbol, asset.decimals)))
+			.map(|(id, name, symbol, decimals)| (id, name.into(), symbol.into(), decimals))
+			.collect(),
+			accounts: assets
+				.iter()
+				.flat_map(|asset| {
+					let id = asset.id;
+					asset.holders.iter().map(move |(who, balance)| (id, who.clone(), *balance))
+				})
+				.collect(),
+		},
+		club: ClubConfig { members: club_members },
+		council: CouncilConfig {
+			// The authorities form the council, which passes the admin operations by two thirds.
+			phantom: Default::default(),
+			members: initial_authorities.iter().map(|(who, ..)| who.clone()).collect(),
+		},
+		explorer_counters: ExplorerCountersConfig {
+			assets: [WRAPPED_NATIVE_ASSET_ID, STABLE_ASSET_ID]
+				.into_iter()
+				.chain(assets.iter().map(|asset| asset.id))
+				.collect(),
+		},
+		kitties: KittiesConfig { kitties },
+		price_fetch: PriceFetchConfig { authorities: price_authorities },
+		session: SessionConfig {
+			keys: initial_authorities
+				.into_iter()
+				.map(|(who, aura, grandpa)| (who.clone(), who, SessionKeys { aura, grandpa }))
+				.collect(),
+		},
 	}
 }
@@ -33,12 +33,9 @@ impl SubstrateCli for Cli {
 	}
 
 	fn load_spec(&self, id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
-		Ok(match id {
-			"dev" => Box::new(chain_spec::development_config()?),
-			"" | "local" => Box::new(chain_spec::local_testnet_config()?),
-			path => Box::new(chain_spec::ChainSpec::from_json_file(
-				std::path::PathBuf::from(path),
-			)?),
+		Ok(match chain_spec::Preset::from_id(id) {
+			Some(preset) => Box::new(chain_spec::preset_config(preset)?),
+			None => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(id))?),
 		})
 	}
 
@@ -131,6 +128,25 @@ pub fn run() -> sc_cli::Result<()> {
 						.into(),
 				)
 			},
+		#[cfg(feature = "try-runtime")]
+		Some(Subcommand::TryRuntime(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				// The command only needs a task manager to run, none of the components of
+				// `new_partial`.
+				let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
+				let task_manager =
+					sc_service::TaskManager::new(config.tokio_handle.clone(), registry)
+						.map_err(|e| sc_cli::Error::Service(sc_service::Error::Prometheus(e)))?;
+				Ok((cmd.run::<Block, service::ExecutorDispatch>(config), task_manager))
+			})
+		},
+		#[cfg(not(feature = "try-runtime"))]
+		Some(Subcommand::TryRuntime) => Err(
+			"TryRuntime wasn't enabled when building the node. You can enable it with \
+			 `--features try-runtime`."
+				.into(),
+		),
 		None => {
 			let runner = cli.create_runner(&cli.run)?;
 			runner.run_node_until_exit(|config| async move {
@@ -0,0 +1,103 @@
+//! Prometheus metrics of the activity of the tutorial pallets.
+//!
+//! [`PalletMetrics`] registers its metrics in the registry of the node, and [`run`] updates them
+//! from the events of each new best block, as the node imports them.
+
+use codec::Decode;
+use futures::StreamExt;
+use rusty_node_runtime::{opaque::Block, Balance, Event, Hash};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sp_core::{hashing::twox_128, storage::StorageKey};
+use sp_runtime::generic::BlockId;
+use std::sync::Arc;
+use substrate_prometheus_endpoint::{
+	register, Counter, CounterVec, Gauge, Opts, PrometheusError, Registry, U64,
+};
+
+type EventRecord = frame_system::EventRecord<Event, Hash>;
+
+/// The metrics of the tutorial pallets.
+pub struct PalletMetrics {
+	assets_created: Counter<U64>,
+	nft_mints: Gauge<U64>,
+	marketplace_volume: CounterVec<U64>,
+}
+
+impl PalletMetrics {
+	/// Register the metrics in `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			assets_created: register(
+				Counter::new("rusty_node_assets_created_total", "Number of assets created")?,
+				registry,
+			)?,
+			nft_mints: register(
+				Gauge::new(
+					"rusty_node_nft_mints_per_block",
+					"Number of NFTs, and kitties, minted in the last best block",
+				)?,
+				registry,
+			)?,
+			marketplace_volume: register(
+				CounterVec::new(
+					Opts::new(
+						"rusty_node_marketplace_volume_total",
+						"Volume of the sales on each marketplace, in units of their currency",
+					),
+					&["marketplace"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	/// Update the metrics from the events of a block.
+	fn record(&self, events: &[EventRecord]) {
+		let mut nft_mints = 0;
+		for record in events {
+			match &record.event {
+				Event::Assets(
+					pallet_assets::Event::Created { .. } |
+					pallet_assets::Event::ForceCreated { .. },
+				) => self.assets_created.inc(),
+				Event::Uniques(pallet_uniques::Event::Issued { .. }) |
+				Event::Kitties(pallet_kitties::Event::Created { .. }) => nft_mints += 1,
+				Event::Kitties(pallet_kitties::Event::Sold { price, .. }) =>
+					self.sale("kitties", *price),
+				Event::DutchAuction(pallet_dutch_auction::Event::Sold { price, .. }) =>
+					self.sale("dutch_auction", *price),
+				_ => {},
+			}
+		}
+		self.nft_mints.set(nft_mints);
+	}
+
+	fn sale(&self, marketplace: &str, price: Balance) {
+		let price = u64::try_from(price).unwrap_or(u64::MAX);
+		self.marketplace_volume.with_label_values(&[marketplace]).inc_by(price);
+	}
+}
+
+/// Update `metrics` from the events of each new best block `client` imports.
+pub async fn run<C, B>(client: Arc<C>, metrics: PalletMetrics)
+where
+	C: BlockchainEvents<Block> + StorageProvider<Block, B>,
+	B: Backend<Block>,
+{
+	let key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+	let mut imports = client.import_notification_stream();
+
+	while let Some(notification) = imports.next().await {
+		if !notification.is_new_best {
+			continue
+		}
+
+		let events = client
+			.storage(&BlockId::Hash(notification.hash), &key)
+			.ok()
+			.flatten()
+			.and_then(|data| Vec::<EventRecord>::decode(&mut &data.0[..]).ok())
+			.unwrap_or_default();
+		metrics.record(&events);
+	}
+}
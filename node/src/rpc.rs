@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use rusty_node_runtime::{opaque::Block, AccountId, Balance, Index};
+use rusty_node_runtime::{opaque::Block, AccountId, AssetId, Balance, ClassId, Index, InstanceId};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -29,13 +29,29 @@ pub fn create_full<C, P>(deps: FullDeps<C, P>) -> jsonrpc_core::IoHandler<sc_rpc
 where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+	C: sc_client_api::ProofProvider<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: rusty_node_runtime_api::AssetsApi<Block, AssetId, AccountId, Balance>,
+	C::Api: rusty_node_runtime_api::NftsApi<Block, ClassId, InstanceId, AccountId, Balance>,
+	C::Api: rusty_node_runtime_api::MarketplaceApi<
+		Block,
+		AccountId,
+		AssetId,
+		Balance,
+		ClassId,
+		InstanceId,
+	>,
+	C::Api: rusty_node_runtime_api::ProofsApi<Block, AssetId, ClassId, InstanceId, AccountId>,
 	P: TransactionPool + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+	use rusty_node_rpc::{
+		Assets, AssetsApiServer, Marketplace, MarketplaceApiServer, Nfts, NftsApiServer, Proofs,
+		ProofsApiServer,
+	};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
 	let mut io = jsonrpc_core::IoHandler::default();
@@ -55,10 +71,11 @@ where
 		client.clone(),
 	)));
 
-	// Extend this RPC with a custom API by using the following syntax.
-	// `YourRpcStruct` should have a reference to a client, which is needed
-	// to call into the runtime.
-	// `io.extend_with(YourRpcTrait::to_delegate(YourRpcStruct::new(ReferenceToClient, ...)));`
+	// The custom queries of the workspace pallets.
+	io.extend_with(AssetsApiServer::to_delegate(Assets::<_, Block, Balance>::new(client.clone())));
+	io.extend_with(NftsApiServer::to_delegate(Nfts::<_, Block, Balance>::new(client.clone())));
+	io.extend_with(MarketplaceApiServer::to_delegate(Marketplace::<_, Block>::new(client.clone())));
+	io.extend_with(ProofsApiServer::to_delegate(Proofs::<_, Block>::new(client)));
 
 	io
 }
@@ -6,10 +6,11 @@ use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 pub use sc_executor::NativeElseWasmExecutor;
 use sc_finality_grandpa::SharedVoterState;
 use sc_keystore::LocalKeystore;
-use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
+use sc_service::{error::Error as ServiceError, ChainType, Configuration, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sp_consensus::SlotData;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sp_keystore::SyncCryptoStore;
 use std::{sync::Arc, time::Duration};
 
 // Our native executor instance.
@@ -182,6 +183,16 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		};
 	}
 
+	if config.chain_spec.chain_type() == ChainType::Development {
+		// Let offchain workers sign prices as Alice, the dev chain price authority.
+		SyncCryptoStore::sr25519_generate_new(
+			&*keystore_container.sync_keystore(),
+			pallet_price_fetch::KEY_TYPE,
+			Some("//Alice"),
+		)
+		.map_err(|e| ServiceError::Other(format!("Error inserting the price key: {}", e)))?;
+	}
+
 	config.network.extra_sets.push(sc_finality_grandpa::grandpa_peers_set_config());
 	let warp_sync = Arc::new(sc_finality_grandpa::warp_proof::NetworkProvider::new(
 		backend.clone(),
@@ -244,6 +255,15 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		telemetry: telemetry.as_mut(),
 	})?;
 
+	if let Some(registry) = prometheus_registry.as_ref() {
+		let metrics = crate::metrics::PalletMetrics::register(registry)?;
+		task_manager.spawn_handle().spawn(
+			"pallet-metrics",
+			None,
+			crate::metrics::run(client.clone(), metrics),
+		);
+	}
+
 	if role.is_authority() {
 		let proposer_factory = sc_basic_authorship::ProposerFactory::new(
 			task_manager.spawn_handle(),
@@ -0,0 +1,62 @@
+//! Benchmarks of the attestation pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+
+fn new_schema<T: Config>(issuer: &T::AccountId) -> SchemaId {
+	let schema_id = Pallet::<T>::next_schema_id();
+	let hash = T::Hashing::hash_of(&schema_id);
+	Pallet::<T>::create_schema(RawOrigin::Signed(issuer.clone()).into(), hash).unwrap();
+	schema_id
+}
+
+benchmarks! {
+	create_schema {
+		let issuer: T::AccountId = whitelisted_caller();
+		let hash = T::Hashing::hash_of(&0u32);
+	}: _(RawOrigin::Signed(issuer.clone()), hash)
+	verify {
+		assert_eq!(Pallet::<T>::schema(0).map(|schema| schema.issuer), Some(issuer));
+	}
+
+	issue {
+		let issuer: T::AccountId = whitelisted_caller();
+		let subject: T::AccountId = account("subject", 0, 0);
+		let schema_id = new_schema::<T>(&issuer);
+		let payload = T::Hashing::hash_of(&subject);
+		// Issuing a new credential over a revoked one.
+		Pallet::<T>::issue(
+			RawOrigin::Signed(issuer.clone()).into(),
+			schema_id,
+			subject.clone(),
+			payload,
+			None,
+		)?;
+		Pallet::<T>::revoke(RawOrigin::Signed(issuer.clone()).into(), schema_id, subject.clone())?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
+	}: _(RawOrigin::Signed(issuer), schema_id, subject.clone(), payload, Some(expires_at))
+	verify {
+		assert!(Pallet::<T>::verify(schema_id, &subject, &payload));
+	}
+
+	revoke {
+		let issuer: T::AccountId = whitelisted_caller();
+		let subject: T::AccountId = account("subject", 0, 0);
+		let schema_id = new_schema::<T>(&issuer);
+		let payload = T::Hashing::hash_of(&subject);
+		Pallet::<T>::issue(
+			RawOrigin::Signed(issuer.clone()).into(),
+			schema_id,
+			subject.clone(),
+			payload,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(issuer), schema_id, subject.clone())
+	verify {
+		assert!(Pallet::<T>::valid_credential(schema_id, &subject).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
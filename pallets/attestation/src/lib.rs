@@ -0,0 +1,258 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Verifiable credentials, attested on chain.
+///
+/// An issuer publishes a schema, the hash of its off-chain definition, then issues credentials of
+/// that schema to subjects: each credential is the hash of the claims it attests, optionally
+/// expiring. Credentials cannot be transferred, and an issuer can revoke the credentials it issued.
+/// A revoked credential stays on chain, so verifiers can tell it apart from one never issued.
+///
+/// Other pallets check credentials through [`CredentialVerifier`], e.g. to restrict a call to
+/// accounts holding a valid credential of a given schema.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use types::SchemaId;
+
+/// Check the credentials held by an account.
+pub trait CredentialVerifier<AccountId> {
+	/// Whether `subject` holds a valid credential of `schema_id`.
+	fn has_credential(schema_id: SchemaId, subject: &AccountId) -> bool;
+}
+
+/// No account ever holds a credential.
+impl<AccountId> CredentialVerifier<AccountId> for () {
+	fn has_credential(_schema_id: SchemaId, _subject: &AccountId) -> bool {
+		false
+	}
+}
+
+impl<T: Config> CredentialVerifier<T::AccountId> for Pallet<T> {
+	fn has_credential(schema_id: SchemaId, subject: &T::AccountId) -> bool {
+		Self::valid_credential(schema_id, subject).is_some()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next schema will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_schema_id)]
+	pub type NextSchemaId<T> = StorageValue<_, SchemaId, ValueQuery>;
+
+	/// The published schemas.
+	#[pallet::storage]
+	#[pallet::getter(fn schema)]
+	pub type Schemas<T: Config> = StorageMap<_, Twox64Concat, SchemaId, SchemaOf<T>>;
+
+	/// The credential of each schema held by each subject, revoked or not.
+	#[pallet::storage]
+	#[pallet::getter(fn credential)]
+	pub type Credentials<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SchemaId,
+		Blake2_128Concat,
+		T::AccountId,
+		CredentialOf<T>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A schema has been published.
+		SchemaCreated { schema_id: SchemaId, issuer: T::AccountId, hash: T::Hash },
+		/// A credential has been issued.
+		CredentialIssued {
+			schema_id: SchemaId,
+			subject: T::AccountId,
+			payload: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		},
+		/// A credential has been revoked.
+		CredentialRevoked { schema_id: SchemaId, subject: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The schema does not exist.
+		UnknownSchema,
+		/// The caller is not the issuer of the schema.
+		NotIssuer,
+		/// The subject already holds a valid credential of the schema.
+		AlreadyIssued,
+		/// The subject holds no credential of the schema.
+		UnknownCredential,
+		/// The credential is already revoked.
+		AlreadyRevoked,
+		/// The expiry block is already in the past.
+		ExpiryInThePast,
+		/// There is no schema id left.
+		NoAvailableSchemaId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Publish a schema, the caller being its issuer.
+		#[pallet::weight(T::WeightInfo::create_schema())]
+		pub fn create_schema(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+
+			let schema_id = Self::next_schema_id();
+			let next_id = schema_id.checked_add(1).ok_or(Error::<T>::NoAvailableSchemaId)?;
+
+			Schemas::<T>::insert(schema_id, Schema { issuer: issuer.clone(), hash });
+			NextSchemaId::<T>::put(next_id);
+
+			Self::deposit_event(Event::SchemaCreated { schema_id, issuer, hash });
+
+			Ok(())
+		}
+
+		/// Issue a credential of a schema to `subject`.
+		///
+		/// A subject holds a single credential of each schema: a new one can only be issued once
+		/// the previous one is revoked or expired.
+		#[pallet::weight(T::WeightInfo::issue())]
+		pub fn issue(
+			origin: OriginFor<T>,
+			schema_id: SchemaId,
+			subject: T::AccountId,
+			payload: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+
+			Self::ensure_issuer(schema_id, &issuer)?;
+			if let Some(expires_at) = expires_at {
+				ensure!(
+					expires_at > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::ExpiryInThePast
+				);
+			}
+			ensure!(
+				Self::valid_credential(schema_id, &subject).is_none(),
+				Error::<T>::AlreadyIssued
+			);
+
+			Credentials::<T>::insert(
+				schema_id,
+				&subject,
+				Credential { issuer, payload, expires_at, revoked: false },
+			);
+
+			Self::deposit_event(Event::CredentialIssued {
+				schema_id,
+				subject,
+				payload,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Revoke the credential of a schema held by `subject`.
+		#[pallet::weight(T::WeightInfo::revoke())]
+		pub fn revoke(
+			origin: OriginFor<T>,
+			schema_id: SchemaId,
+			subject: T::AccountId,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+
+			Self::ensure_issuer(schema_id, &issuer)?;
+			Credentials::<T>::try_mutate(schema_id, &subject, |maybe_credential| -> DispatchResult {
+				let credential = maybe_credential.as_mut().ok_or(Error::<T>::UnknownCredential)?;
+				ensure!(!credential.revoked, Error::<T>::AlreadyRevoked);
+				credential.revoked = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CredentialRevoked { schema_id, subject });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The credential of `schema_id` held by `subject`, if neither revoked nor expired.
+		pub fn valid_credential(
+			schema_id: SchemaId,
+			subject: &T::AccountId,
+		) -> Option<CredentialOf<T>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::credential(schema_id, subject).filter(|credential| {
+				!credential.revoked && credential.expires_at.map_or(true, |expiry| now < expiry)
+			})
+		}
+
+		/// Whether `subject` holds a valid credential of `schema_id` attesting `payload`.
+		pub fn verify(schema_id: SchemaId, subject: &T::AccountId, payload: &T::Hash) -> bool {
+			Self::valid_credential(schema_id, subject)
+				.map_or(false, |credential| &credential.payload == payload)
+		}
+
+		fn ensure_issuer(schema_id: SchemaId, who: &T::AccountId) -> DispatchResult {
+			let schema = Self::schema(schema_id).ok_or(Error::<T>::UnknownSchema)?;
+			ensure!(schema.issuer == *who, Error::<T>::NotIssuer);
+			Ok(())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let next_id = Self::next_schema_id();
+			ensure!(Schemas::<T>::iter_keys().all(|id| id < next_id), "schema id not allocated");
+			for (schema_id, _, credential) in Credentials::<T>::iter() {
+				let schema = Self::schema(schema_id).ok_or("credential of an unknown schema")?;
+				ensure!(credential.issuer == schema.issuer, "credential of another issuer");
+			}
+
+			Ok(())
+		}
+	}
+}
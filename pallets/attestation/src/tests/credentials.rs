@@ -0,0 +1,90 @@
+use super::mock::*;
+use crate::{CredentialVerifier, Error, Event as AttestationEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+const SCHEMA: H256 = H256::repeat_byte(1);
+const PAYLOAD: H256 = H256::repeat_byte(2);
+
+fn create_schema() {
+	assert_ok!(Attestation::create_schema(Origin::signed(ISSUER), SCHEMA));
+}
+
+fn has_credential(subject: u64) -> bool {
+	<Attestation as CredentialVerifier<u64>>::has_credential(0, &subject)
+}
+
+#[test]
+fn only_the_schema_issuer_issues() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Attestation::issue(Origin::signed(ISSUER), 0, ALICE, PAYLOAD, None),
+			Error::<Test>::UnknownSchema
+		);
+		create_schema();
+		System::assert_last_event(
+			AttestationEvent::SchemaCreated { schema_id: 0, issuer: ISSUER, hash: SCHEMA }.into(),
+		);
+
+		assert_noop!(
+			Attestation::issue(Origin::signed(BOB), 0, ALICE, PAYLOAD, None),
+			Error::<Test>::NotIssuer
+		);
+		assert_noop!(
+			Attestation::issue(Origin::signed(ISSUER), 0, ALICE, PAYLOAD, Some(1)),
+			Error::<Test>::ExpiryInThePast
+		);
+		assert_ok!(Attestation::issue(Origin::signed(ISSUER), 0, ALICE, PAYLOAD, None));
+		assert_noop!(
+			Attestation::issue(Origin::signed(ISSUER), 0, ALICE, PAYLOAD, None),
+			Error::<Test>::AlreadyIssued
+		);
+
+		assert!(has_credential(ALICE));
+		assert!(!has_credential(BOB));
+		assert!(Attestation::verify(0, &ALICE, &PAYLOAD));
+		assert!(!Attestation::verify(0, &ALICE, &SCHEMA));
+	})
+}
+
+#[test]
+fn revoked_credentials_are_no_longer_valid() {
+	new_test_ext().execute_with(|| {
+		create_schema();
+		assert_ok!(Attestation::issue(Origin::signed(ISSUER), 0, ALICE, PAYLOAD, None));
+
+		assert_noop!(Attestation::revoke(Origin::signed(BOB), 0, ALICE), Error::<Test>::NotIssuer);
+		assert_noop!(
+			Attestation::revoke(Origin::signed(ISSUER), 0, BOB),
+			Error::<Test>::UnknownCredential
+		);
+		assert_ok!(Attestation::revoke(Origin::signed(ISSUER), 0, ALICE));
+		System::assert_last_event(
+			AttestationEvent::CredentialRevoked { schema_id: 0, subject: ALICE }.into(),
+		);
+		assert_noop!(
+			Attestation::revoke(Origin::signed(ISSUER), 0, ALICE),
+			Error::<Test>::AlreadyRevoked
+		);
+
+		assert!(!has_credential(ALICE));
+		assert!(Attestation::credential(0, ALICE).unwrap().revoked);
+
+		// A new credential can be issued once the previous one is revoked.
+		assert_ok!(Attestation::issue(Origin::signed(ISSUER), 0, ALICE, PAYLOAD, None));
+		assert!(has_credential(ALICE));
+	})
+}
+
+#[test]
+fn credentials_expire() {
+	new_test_ext().execute_with(|| {
+		create_schema();
+		assert_ok!(Attestation::issue(Origin::signed(ISSUER), 0, ALICE, PAYLOAD, Some(10)));
+		assert!(has_credential(ALICE));
+
+		System::set_block_number(10);
+		assert!(!has_credential(ALICE));
+		assert!(!Attestation::verify(0, &ALICE, &PAYLOAD));
+	})
+}
@@ -0,0 +1,34 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+pub type SchemaId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type SchemaOf<T> = Schema<AccountIdOf<T>, <T as frame_system::Config>::Hash>;
+pub type CredentialOf<T> = Credential<
+	AccountIdOf<T>,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// The shape of the credentials an issuer issues.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Schema<AccountId, Hash> {
+	/// The only account allowed to issue credentials of the schema.
+	pub issuer: AccountId,
+	/// The hash of the off-chain schema definition.
+	pub hash: Hash,
+}
+
+/// A credential issued to a subject.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Credential<AccountId, Hash, BlockNumber> {
+	/// The issuer of the credential.
+	pub issuer: AccountId,
+	/// The hash of the off-chain claims the credential attests.
+	pub payload: Hash,
+	/// The block from which the credential is no longer valid, if any.
+	pub expires_at: Option<BlockNumber>,
+	/// Whether the issuer revoked the credential.
+	pub revoked: bool,
+}
@@ -0,0 +1,60 @@
+//! Weights for pallet_attestation
+//!
+//! Estimated from the storage accesses of each call, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_attestation --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/attestation/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_attestation.
+pub trait WeightInfo {
+	fn create_schema() -> Weight;
+	fn issue() -> Weight;
+	fn revoke() -> Weight;
+}
+
+/// Weights for pallet_attestation using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_schema() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn issue() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn revoke() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_schema() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn issue() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -0,0 +1,44 @@
+//! Benchmarks of the batch-utility pallet.
+//!
+//! Only the overhead of the batch is measured: the weight of its calls is added on top of it.
+
+use super::*;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+fn remarks<T: Config>(c: u32) -> Vec<<T as Config>::Call>
+where
+	<T as Config>::Call: From<frame_system::Call<T>>,
+{
+	(0..c).map(|_| frame_system::Call::<T>::remark { remark: Vec::new() }.into()).collect()
+}
+
+benchmarks! {
+	where_clause { where <T as Config>::Call: From<frame_system::Call<T>> }
+
+	batch {
+		let c in 0 .. T::MaxCalls::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let calls = remarks::<T>(c);
+	}: _(RawOrigin::Signed(caller), calls)
+	verify {
+		frame_system::Pallet::<T>::assert_last_event(
+			<T as Config>::Event::from(Event::BatchCompleted).into(),
+		);
+	}
+
+	batch_all {
+		let c in 0 .. T::MaxCalls::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let calls = remarks::<T>(c);
+	}: _(RawOrigin::Signed(caller), calls)
+	verify {
+		frame_system::Pallet::<T>::assert_last_event(
+			<T as Config>::Event::from(Event::BatchCompleted).into(),
+		);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,164 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Dispatch a batch of calls from a single extrinsic.
+///
+/// Every call is dispatched with the origin of the batch, and its result is reported by an event
+/// carrying its index in the batch. `batch` stops at the first failing call and keeps the effects
+/// of the calls before it; `batch_all` reverts them all.
+///
+/// The batch is charged upfront for the weight of all its calls. Calls that are not executed, or
+/// that used less than their declared weight, are refunded through the post dispatch info.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo, UnfilteredDispatchable},
+		pallet_prelude::*,
+		transactional,
+		weights::extract_actual_weight,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The calls that can be batched.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ UnfilteredDispatchable<Origin = Self::Origin>
+			+ IsType<<Self as frame_system::Config>::Call>;
+
+		/// The maximum number of calls in a batch.
+		#[pallet::constant]
+		type MaxCalls: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event {
+		/// The call at `index` in the batch succeeded.
+		ItemCompleted { index: u32 },
+		/// The call at `index` in the batch failed; the calls after it were not executed.
+		ItemFailed { index: u32, error: DispatchError },
+		/// All the calls of the batch succeeded.
+		BatchCompleted,
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The batch holds more than `MaxCalls` calls.
+		TooManyCalls,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispatch `calls` in order, stopping at the first failure.
+		///
+		/// The batch itself succeeds even when one of its calls fails, so the calls before the
+		/// failing one keep their effects.
+		#[pallet::weight(
+			T::WeightInfo::batch(calls.len() as u32)
+				.saturating_add(Pallet::<T>::calls_weight(calls))
+		)]
+		pub fn batch(
+			origin: OriginFor<T>,
+			calls: Vec<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(calls.len() as u32 <= T::MaxCalls::get(), Error::<T>::TooManyCalls);
+
+			let mut weight = T::WeightInfo::batch(calls.len() as u32);
+			for (index, call) in calls.into_iter().enumerate() {
+				let index = index as u32;
+				let info = call.get_dispatch_info();
+				let result = Self::dispatch_item(origin.clone(), call);
+				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+
+				if let Err(e) = result {
+					Self::deposit_event(Event::ItemFailed { index, error: e.error });
+					return Ok(Some(weight).into())
+				}
+				Self::deposit_event(Event::ItemCompleted { index });
+			}
+			Self::deposit_event(Event::BatchCompleted);
+
+			Ok(Some(weight).into())
+		}
+
+		/// Dispatch `calls` in order, reverting all of them if any fails.
+		#[pallet::weight(
+			T::WeightInfo::batch_all(calls.len() as u32)
+				.saturating_add(Pallet::<T>::calls_weight(calls))
+		)]
+		#[transactional]
+		pub fn batch_all(
+			origin: OriginFor<T>,
+			calls: Vec<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(calls.len() as u32 <= T::MaxCalls::get(), Error::<T>::TooManyCalls);
+
+			let mut weight = T::WeightInfo::batch_all(calls.len() as u32);
+			for (index, call) in calls.into_iter().enumerate() {
+				let index = index as u32;
+				let info = call.get_dispatch_info();
+				let result = Self::dispatch_item(origin.clone(), call);
+				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+
+				result.map_err(|mut e| {
+					e.post_info = Some(weight).into();
+					e
+				})?;
+				Self::deposit_event(Event::ItemCompleted { index });
+			}
+			Self::deposit_event(Event::BatchCompleted);
+
+			Ok(Some(weight).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The weight of a batch: the sum of the weights of its calls.
+		pub fn calls_weight(calls: &[<T as Config>::Call]) -> Weight {
+			calls
+				.iter()
+				.map(|call| call.get_dispatch_info().weight)
+				.fold(0, |total: Weight, weight| total.saturating_add(weight))
+		}
+
+		/// Dispatch a call of a batch.
+		///
+		/// Root bypasses the call filter, as it would when dispatching the call directly.
+		fn dispatch_item(
+			origin: OriginFor<T>,
+			call: <T as Config>::Call,
+		) -> DispatchResultWithPostInfo {
+			if ensure_root(origin.clone()).is_ok() {
+				call.dispatch_bypass_filter(origin)
+			} else {
+				call.dispatch(origin)
+			}
+		}
+	}
+}
@@ -0,0 +1,79 @@
+use super::mock::*;
+use crate::{Error, Event as BatchEvent, WeightInfo};
+use frame_support::{assert_noop, assert_ok, weights::GetDispatchInfo};
+
+fn transfer(dest: u64, value: u64) -> Call {
+	Call::Balances(pallet_balances::Call::transfer { dest, value })
+}
+
+fn transfer_weight() -> u64 {
+	transfer(BOB, 10).get_dispatch_info().weight
+}
+
+#[test]
+fn batch_stops_at_the_first_failure() {
+	new_test_ext().execute_with(|| {
+		let calls = vec![transfer(BOB, 10), transfer(BOB, 1_000), transfer(CHARLIE, 10)];
+
+		let post_info = BatchUtility::batch(Origin::signed(ALICE), calls).unwrap();
+
+		assert_eq!(Balances::free_balance(BOB), 110);
+		assert_eq!(Balances::free_balance(CHARLIE), 100);
+		System::assert_has_event(BatchEvent::ItemCompleted { index: 0 }.into());
+		System::assert_last_event(
+			BatchEvent::ItemFailed {
+				index: 1,
+				error: pallet_balances::Error::<Test>::InsufficientBalance.into(),
+			}
+			.into(),
+		);
+		// The call that was not executed is refunded.
+		let weight = <() as WeightInfo>::batch(3) + 2 * transfer_weight();
+		assert_eq!(post_info.actual_weight, Some(weight));
+	})
+}
+
+#[test]
+fn batch_reports_every_call() {
+	new_test_ext().execute_with(|| {
+		let calls = vec![transfer(BOB, 10), transfer(CHARLIE, 10)];
+
+		assert_ok!(BatchUtility::batch(Origin::signed(ALICE), calls));
+
+		assert_eq!(Balances::free_balance(ALICE), 80);
+		System::assert_has_event(BatchEvent::ItemCompleted { index: 0 }.into());
+		System::assert_has_event(BatchEvent::ItemCompleted { index: 1 }.into());
+		System::assert_last_event(BatchEvent::BatchCompleted.into());
+	})
+}
+
+#[test]
+fn batch_all_reverts_every_call() {
+	new_test_ext().execute_with(|| {
+		let calls = vec![transfer(BOB, 10), transfer(BOB, 1_000), transfer(CHARLIE, 10)];
+
+		let error = BatchUtility::batch_all(Origin::signed(ALICE), calls).unwrap_err();
+
+		assert_eq!(error.error, pallet_balances::Error::<Test>::InsufficientBalance.into());
+		let weight = <() as WeightInfo>::batch_all(3) + 2 * transfer_weight();
+		assert_eq!(error.post_info.actual_weight, Some(weight));
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Balances::free_balance(BOB), 100);
+	})
+}
+
+#[test]
+fn batches_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let calls = vec![transfer(BOB, 1); 4];
+
+		assert_noop!(
+			BatchUtility::batch(Origin::signed(ALICE), calls.clone()),
+			Error::<Test>::TooManyCalls
+		);
+		assert_noop!(
+			BatchUtility::batch_all(Origin::signed(ALICE), calls),
+			Error::<Test>::TooManyCalls
+		);
+	})
+}
@@ -0,0 +1,45 @@
+//! Weights for pallet_batch_utility
+//!
+//! Estimated from the storage accesses of each call, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_batch_utility --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/batch-utility/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_batch_utility.
+pub trait WeightInfo {
+	fn batch(c: u32) -> Weight;
+	fn batch_all(c: u32) -> Weight;
+}
+
+/// Weights for pallet_batch_utility using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn batch(c: u32) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((4_000_000 as Weight).saturating_mul(c as Weight))
+	}
+	fn batch_all(c: u32) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((4_500_000 as Weight).saturating_mul(c as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn batch(c: u32) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((4_000_000 as Weight).saturating_mul(c as Weight))
+	}
+	fn batch_all(c: u32) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((4_500_000 as Weight).saturating_mul(c as Weight))
+	}
+}
@@ -0,0 +1,102 @@
+//! Benchmarks of the bounty pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{
+	tokens::fungibles::{Create, Mutate},
+	Currency,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, One};
+
+const ASSET_ID: u32 = 1_000;
+const AMOUNT: u32 = 1_000_000;
+
+/// Fund `funder` and have it post a bounty expiring at block `expiry`.
+fn new_bounty<T: Config>(funder: &T::AccountId, expiry: T::BlockNumber) -> BountyId
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	T::Assets::create(ASSET_ID.into(), funder.clone(), true, One::one()).unwrap();
+	T::Assets::mint_into(ASSET_ID.into(), funder, AMOUNT.into()).unwrap();
+	let bounty_id = Pallet::<T>::next_bounty_id();
+	Pallet::<T>::post_bounty(
+		RawOrigin::Signed(funder.clone()).into(),
+		ASSET_ID.into(),
+		AMOUNT.into(),
+		T::Hashing::hash_of(&bounty_id),
+		expiry,
+	)
+	.unwrap();
+	bounty_id
+}
+
+/// Fund `hunter` for the submission deposit and have it submit work for `bounty_id`.
+fn submit<T: Config>(bounty_id: BountyId, hunter: &T::AccountId) {
+	T::Currency::make_free_balance_be(hunter, T::SubmissionDeposit::get() * 10u32.into());
+	let work = T::Hashing::hash_of(hunter);
+	Pallet::<T>::submit_work(RawOrigin::Signed(hunter.clone()).into(), bounty_id, work).unwrap();
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	post_bounty {
+		let funder: T::AccountId = whitelisted_caller();
+		T::Assets::create(ASSET_ID.into(), funder.clone(), true, One::one())?;
+		T::Assets::mint_into(ASSET_ID.into(), &funder, AMOUNT.into())?;
+		let description = T::Hashing::hash_of(&funder);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+	}: _(RawOrigin::Signed(funder), ASSET_ID.into(), AMOUNT.into(), description, expiry)
+	verify {
+		assert!(Pallet::<T>::bounty(0).is_some());
+	}
+
+	submit_work {
+		let funder: T::AccountId = account("funder", 0, 0);
+		let hunter: T::AccountId = whitelisted_caller();
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		let bounty_id = new_bounty::<T>(&funder, expiry);
+		T::Currency::make_free_balance_be(&hunter, T::SubmissionDeposit::get() * 10u32.into());
+		let work = T::Hashing::hash_of(&hunter);
+	}: _(RawOrigin::Signed(hunter.clone()), bounty_id, work)
+	verify {
+		assert_eq!(Pallet::<T>::submission(bounty_id, hunter).map(|s| s.work), Some(work));
+	}
+
+	award {
+		let s in 1 .. T::MaxSubmissions::get();
+		let funder: T::AccountId = whitelisted_caller();
+		let hunter: T::AccountId = account("hunter", 0, 0);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		let bounty_id = new_bounty::<T>(&funder, expiry);
+		for i in 0 .. s {
+			submit::<T>(bounty_id, &account("hunter", i, 0));
+		}
+	}: _(RawOrigin::Signed(funder), bounty_id, hunter)
+	verify {
+		assert!(Pallet::<T>::bounty(bounty_id).is_none());
+		assert_eq!(Pallet::<T>::submission_count(bounty_id), 0);
+	}
+
+	refund {
+		let s in 0 .. T::MaxSubmissions::get();
+		let funder: T::AccountId = account("funder", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let expiry = frame_system::Pallet::<T>::block_number() + One::one();
+		let bounty_id = new_bounty::<T>(&funder, expiry);
+		for i in 0 .. s {
+			submit::<T>(bounty_id, &account("hunter", i, 0));
+		}
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert!(Pallet::<T>::bounty(bounty_id).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
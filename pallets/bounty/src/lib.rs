@@ -0,0 +1,330 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Post bounties paid in a fungible asset and award them to hunters.
+///
+/// The bounty amount is escrowed in the pallet account when the bounty is posted. Hunters submit
+/// the hash of their work, and either the funder or the arbiter origin awards the escrow to one of
+/// them. A bounty that has not been awarded before its expiry can be refunded to its funder.
+///
+/// Each submission reserves a deposit from its hunter, refunded when the bounty closes, and a
+/// bounty takes at most `MaxSubmissions` of them, so that closing it has a bounded cost.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{tokens::fungibles, ReservableCurrency},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::StorageDeposits;
+	use sp_runtime::traits::{AccountIdConversion, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets bounties are paid with.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The native currency, used for submission deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from a hunter for each submission, refunded when the bounty closes.
+		#[pallet::constant]
+		type SubmissionDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// The maximum number of submissions to a bounty.
+		#[pallet::constant]
+		type MaxSubmissions: Get<u32>;
+
+		/// The origin allowed to award any bounty in place of its funder.
+		type ArbiterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next bounty will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_bounty_id)]
+	pub type NextBountyId<T> = StorageValue<_, BountyId, ValueQuery>;
+
+	/// The open bounties.
+	#[pallet::storage]
+	#[pallet::getter(fn bounty)]
+	pub type Bounties<T: Config> = StorageMap<_, Twox64Concat, BountyId, BountyOf<T>>;
+
+	/// The work submitted by each hunter of a bounty, with the deposit reserved for it.
+	#[pallet::storage]
+	#[pallet::getter(fn submission)]
+	pub type Submissions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyId,
+		Blake2_128Concat,
+		T::AccountId,
+		SubmissionOf<T>,
+	>;
+
+	/// The number of submissions to each bounty.
+	#[pallet::storage]
+	#[pallet::getter(fn submission_count)]
+	pub type SubmissionCount<T> = StorageMap<_, Twox64Concat, BountyId, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new bounty has been posted.
+		Posted {
+			bounty_id: BountyId,
+			funder: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			description: T::Hash,
+		},
+		/// A hunter submitted work for a bounty.
+		WorkSubmitted { bounty_id: BountyId, hunter: T::AccountId, work: T::Hash },
+		/// A bounty has been awarded to a hunter.
+		Awarded { bounty_id: BountyId, hunter: T::AccountId, amount: BalanceOf<T> },
+		/// An expired bounty has been refunded to its funder.
+		Refunded { bounty_id: BountyId, funder: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The bounty does not exist.
+		UnknownBounty,
+		/// The bounty amount must be non zero.
+		ZeroAmount,
+		/// The expiry block is already in the past.
+		ExpiryInThePast,
+		/// The bounty has expired.
+		Expired,
+		/// The bounty has not expired yet.
+		NotExpired,
+		/// The caller is neither the funder nor the arbiter.
+		NoPermission,
+		/// The hunter did not submit any work for this bounty.
+		NoSubmission,
+		/// There is no bounty id left.
+		NoAvailableBountyId,
+		/// The bounty already has the maximum number of submissions.
+		TooManySubmissions,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Post a bounty, escrowing `amount` of `asset_id` until it is awarded or refunded.
+		#[pallet::weight(T::WeightInfo::post_bounty())]
+		pub fn post_bounty(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			description: T::Hash,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let funder = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ExpiryInThePast
+			);
+
+			let bounty_id = Self::next_bounty_id();
+			let next_id = bounty_id.checked_add(1).ok_or(Error::<T>::NoAvailableBountyId)?;
+
+			T::Assets::transfer(asset_id, &funder, &Self::account_id(), amount, false)?;
+
+			Bounties::<T>::insert(
+				bounty_id,
+				Bounty { funder: funder.clone(), asset_id, amount, description, expiry },
+			);
+			NextBountyId::<T>::put(next_id);
+
+			Self::deposit_event(Event::Posted { bounty_id, funder, asset_id, amount, description });
+
+			Ok(())
+		}
+
+		/// Submit the hash of some work for an open bounty.
+		///
+		/// Submitting again replaces the previous work hash, keeping its deposit. A first
+		/// submission reserves the `SubmissionDeposit`.
+		#[pallet::weight(T::WeightInfo::submit_work())]
+		pub fn submit_work(origin: OriginFor<T>, bounty_id: BountyId, work: T::Hash) -> DispatchResult {
+			let hunter = ensure_signed(origin)?;
+
+			let bounty = Self::bounty(bounty_id).ok_or(Error::<T>::UnknownBounty)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < bounty.expiry,
+				Error::<T>::Expired
+			);
+
+			if let Some(mut submission) = Self::submission(bounty_id, &hunter) {
+				submission.work = work;
+				Submissions::<T>::insert(bounty_id, &hunter, submission);
+			} else {
+				let count = Self::submission_count(bounty_id);
+				ensure!(count < T::MaxSubmissions::get(), Error::<T>::TooManySubmissions);
+
+				let deposit = T::Currency::reserve_deposit(&hunter, T::SubmissionDeposit::get())?;
+				Submissions::<T>::insert(bounty_id, &hunter, Submission { work, deposit });
+				SubmissionCount::<T>::insert(bounty_id, count + 1);
+			}
+
+			Self::deposit_event(Event::WorkSubmitted { bounty_id, hunter, work });
+
+			Ok(())
+		}
+
+		/// Award the escrowed amount to a hunter that submitted work.
+		///
+		/// Can be called by the funder or by the arbiter origin. Refunds the weight of the
+		/// submissions the bounty was assumed to hold.
+		#[pallet::weight(T::WeightInfo::award(T::MaxSubmissions::get()))]
+		pub fn award(
+			origin: OriginFor<T>,
+			bounty_id: BountyId,
+			hunter: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let bounty = Self::bounty(bounty_id).ok_or(Error::<T>::UnknownBounty)?;
+
+			if let Err(origin) = T::ArbiterOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(who == bounty.funder, Error::<T>::NoPermission);
+			}
+			ensure!(Submissions::<T>::contains_key(bounty_id, &hunter), Error::<T>::NoSubmission);
+
+			T::Assets::transfer(bounty.asset_id, &Self::account_id(), &hunter, bounty.amount, false)?;
+			let submissions = Self::close(bounty_id);
+
+			Self::deposit_event(Event::Awarded { bounty_id, hunter, amount: bounty.amount });
+
+			Ok(Some(T::WeightInfo::award(submissions)).into())
+		}
+
+		/// Give the escrow of an expired bounty back to its funder.
+		///
+		/// Anyone can trigger the refund once the bounty has expired. Refunds the weight of the
+		/// submissions the bounty was assumed to hold.
+		#[pallet::weight(T::WeightInfo::refund(T::MaxSubmissions::get()))]
+		pub fn refund(origin: OriginFor<T>, bounty_id: BountyId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let bounty = Self::bounty(bounty_id).ok_or(Error::<T>::UnknownBounty)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= bounty.expiry,
+				Error::<T>::NotExpired
+			);
+
+			T::Assets::transfer(
+				bounty.asset_id,
+				&Self::account_id(),
+				&bounty.funder,
+				bounty.amount,
+				false,
+			)?;
+			let submissions = Self::close(bounty_id);
+
+			Self::deposit_event(Event::Refunded {
+				bounty_id,
+				funder: bounty.funder,
+				amount: bounty.amount,
+			});
+
+			Ok(Some(T::WeightInfo::refund(submissions)).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding escrowed bounties.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Remove a bounty and its submissions, refunding their deposits, and return the number of
+		/// submissions removed.
+		fn close(bounty_id: BountyId) -> u32 {
+			Bounties::<T>::remove(bounty_id);
+			SubmissionCount::<T>::remove(bounty_id);
+
+			let mut removed = 0;
+			for (hunter, submission) in Submissions::<T>::drain_prefix(bounty_id) {
+				T::Currency::refund_deposit(&hunter, submission.deposit);
+				removed += 1;
+			}
+			removed
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use rusty_node_support::escrow::ensure_escrowed;
+
+			let next_id = Self::next_bounty_id();
+			ensure!(Bounties::<T>::iter_keys().all(|id| id < next_id), "bounty id not allocated");
+			ensure!(
+				Submissions::<T>::iter_keys().all(|(id, _)| Bounties::<T>::contains_key(id)),
+				"submission to a closed bounty"
+			);
+			ensure!(
+				SubmissionCount::<T>::iter().all(|(id, count)| {
+					Submissions::<T>::iter_prefix(id).count() as u32 == count
+				}),
+				"submission count out of sync"
+			);
+
+			// The escrow account holds the reward of every open bounty.
+			let account = Self::account_id();
+			ensure_escrowed::<_, T::Assets>(
+				&account,
+				Bounties::<T>::iter_values().map(|bounty| (bounty.asset_id, bounty.amount)),
+				"bounties not escrowed",
+			)?;
+
+			Ok(())
+		}
+	}
+}
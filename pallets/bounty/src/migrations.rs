@@ -0,0 +1,58 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 records the deposit of each submission, and counts the submissions of each bounty.
+///
+/// The submissions made before version 2 reserved no deposit, so they record none.
+pub mod v2 {
+	use super::*;
+	use crate::{Submission, SubmissionCount, SubmissionOf, Submissions};
+	use sp_runtime::traits::Zero;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = 0;
+		Submissions::<T>::translate::<T::Hash, _>(|bounty_id, _hunter, work| {
+			SubmissionCount::<T>::mutate(bounty_id, |count| *count += 1);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+			let submission: SubmissionOf<T> = Submission { work, deposit: Zero::zero() };
+			Some(submission)
+		});
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		weight.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
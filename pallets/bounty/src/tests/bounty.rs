@@ -0,0 +1,143 @@
+use super::mock::*;
+use crate::{Error, Event as BountyEvent, WeightInfo};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+fn post_bounty() {
+	assert_ok!(Bounty::post_bounty(
+		Origin::signed(ALICE),
+		ASSET_ID,
+		100,
+		H256::repeat_byte(1),
+		10
+	));
+}
+
+#[test]
+fn post_bounty_escrows_the_amount() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 900);
+		assert_eq!(Assets::balance(ASSET_ID, Bounty::account_id()), 100);
+		assert_eq!(Bounty::next_bounty_id(), 1);
+	})
+}
+
+#[test]
+fn post_bounty_rejects_past_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Bounty::post_bounty(Origin::signed(ALICE), ASSET_ID, 100, H256::zero(), 1),
+			Error::<Test>::ExpiryInThePast
+		);
+	})
+}
+
+#[test]
+fn funder_awards_a_hunter() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+		assert_ok!(Bounty::submit_work(Origin::signed(BOB), 0, H256::repeat_byte(2)));
+
+		assert_ok!(Bounty::award(Origin::signed(ALICE), 0, BOB));
+
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+		assert!(Bounty::bounty(0).is_none());
+		assert!(Bounty::submission(0, BOB).is_none());
+		System::assert_last_event(
+			BountyEvent::Awarded { bounty_id: 0, hunter: BOB, amount: 100 }.into(),
+		);
+	})
+}
+
+#[test]
+fn arbiter_can_award() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+		assert_ok!(Bounty::submit_work(Origin::signed(BOB), 0, H256::repeat_byte(2)));
+
+		assert_noop!(
+			Bounty::award(Origin::signed(CHARLIE), 0, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Bounty::award(Origin::signed(ARBITER), 0, BOB));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+	})
+}
+
+#[test]
+fn award_requires_a_submission() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+
+		assert_noop!(Bounty::award(Origin::signed(ALICE), 0, BOB), Error::<Test>::NoSubmission);
+	})
+}
+
+#[test]
+fn expired_bounty_is_refunded() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+
+		assert_noop!(Bounty::refund(Origin::signed(BOB), 0), Error::<Test>::NotExpired);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Bounty::submit_work(Origin::signed(BOB), 0, H256::zero()),
+			Error::<Test>::Expired
+		);
+		assert_ok!(Bounty::refund(Origin::signed(BOB), 0));
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000);
+	})
+}
+
+#[test]
+fn submissions_reserve_a_deposit_refunded_on_award() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+		assert_ok!(Bounty::submit_work(Origin::signed(BOB), 0, H256::repeat_byte(2)));
+		assert_ok!(Bounty::submit_work(Origin::signed(BOB), 0, H256::repeat_byte(3)));
+		assert_ok!(Bounty::submit_work(Origin::signed(CHARLIE), 0, H256::repeat_byte(4)));
+
+		assert_eq!(Balances::reserved_balance(BOB), 5);
+		assert_eq!(Bounty::submission(0, BOB).map(|s| s.work), Some(H256::repeat_byte(3)));
+		assert_eq!(Bounty::submission_count(0), 2);
+
+		let post_info = Bounty::award(Origin::signed(ALICE), 0, BOB).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::award(2)));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Bounty::submission_count(0), 0);
+	})
+}
+
+#[test]
+fn submissions_are_bounded() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+		assert_ok!(Bounty::submit_work(Origin::signed(BOB), 0, H256::repeat_byte(2)));
+		assert_ok!(Bounty::submit_work(Origin::signed(CHARLIE), 0, H256::repeat_byte(3)));
+
+		assert_noop!(
+			Bounty::submit_work(Origin::signed(ALICE), 0, H256::repeat_byte(4)),
+			Error::<Test>::TooManySubmissions
+		);
+		// Replacing a submission takes no new slot.
+		assert_ok!(Bounty::submit_work(Origin::signed(BOB), 0, H256::repeat_byte(5)));
+	})
+}
+
+#[test]
+fn refund_returns_the_submission_deposits() {
+	new_test_ext().execute_with(|| {
+		post_bounty();
+		assert_ok!(Bounty::submit_work(Origin::signed(BOB), 0, H256::repeat_byte(2)));
+
+		System::set_block_number(10);
+		let post_info = Bounty::refund(Origin::signed(CHARLIE), 0).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::refund(1)));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert!(Bounty::submission(0, BOB).is_none());
+	})
+}
@@ -0,0 +1,44 @@
+use super::mock::*;
+use crate::migrations;
+use frame_support::{
+	assert_ok,
+	storage::migration::put_storage_value,
+	traits::{GetStorageVersion, StorageVersion},
+	Hashable,
+};
+use sp_core::H256;
+
+#[test]
+fn migrate_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Bounty>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(Bounty::on_chain_storage_version(), Bounty::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_records_the_submission_deposits_and_counts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bounty::post_bounty(
+			Origin::signed(ALICE),
+			ASSET_ID,
+			100,
+			H256::repeat_byte(1),
+			10
+		));
+		// Version 1 kept only the work hash of a submission, and no count.
+		let key = [0u32.twox_64_concat(), BOB.blake2_128_concat()].concat();
+		put_storage_value(b"Bounty", b"Submissions", &key, H256::repeat_byte(2));
+		StorageVersion::new(1).put::<Bounty>();
+
+		migrations::migrate::<Test>();
+
+		let submission = Bounty::submission(0, BOB).unwrap();
+		assert_eq!((submission.work, submission.deposit), (H256::repeat_byte(2), 0));
+		assert_eq!(Bounty::submission_count(0), 1);
+		assert_eq!(Bounty::on_chain_storage_version(), 2);
+	})
+}
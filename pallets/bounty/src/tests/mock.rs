@@ -0,0 +1,133 @@
+use crate as pallet_bounty;
+use frame_support::{ord_parameter_types, parameter_types, PalletId};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use test_utils::ExtBuilder;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const ARBITER: u64 = 4;
+
+pub const ASSET_ID: u32 = 0;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Bounty: pallet_bounty::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type AccountData = pallet_balances::AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: u64 = 0;
+	pub const ApprovalDeposit: u64 = 0;
+	pub const MetadataDepositBase: u64 = 0;
+	pub const MetadataDepositPerByte: u64 = 0;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ApprovalDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = u32;
+	type Balance = u64;
+	type Currency = Balances;
+	type Event = Event;
+	type Extra = ();
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Freezer = ();
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const BountyPalletId: PalletId = PalletId(*b"py/bount");
+	pub const SubmissionDeposit: u64 = 5;
+	pub const MaxSubmissions: u32 = 2;
+}
+
+ord_parameter_types! {
+	pub const Arbiter: u64 = ARBITER;
+}
+
+impl pallet_bounty::Config for Test {
+	type ArbiterOrigin = frame_system::EnsureSignedBy<Arbiter, u64>;
+	type Assets = Assets;
+	type Currency = Balances;
+	type Event = Event;
+	type MaxSubmissions = MaxSubmissions;
+	type PalletId = BountyPalletId;
+	type SubmissionDeposit = SubmissionDeposit;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Test>::default()
+		.balances(vec![(ALICE, 100), (BOB, 100), (CHARLIE, 100)])
+		.asset(ASSET_ID, ALICE, vec![(ALICE, 1_000)])
+		.build()
+}
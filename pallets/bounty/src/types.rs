@@ -0,0 +1,43 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{tokens::fungibles::Inspect, Currency};
+use scale_info::TypeInfo;
+
+pub type BountyId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type DepositBalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type BountyOf<T> = Bounty<
+	AccountIdOf<T>,
+	AssetIdOf<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub type SubmissionOf<T> = Submission<<T as frame_system::Config>::Hash, DepositBalanceOf<T>>;
+
+/// An open bounty.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Bounty<AccountId, AssetId, Balance, Hash, BlockNumber> {
+	/// The account that posted and funded the bounty.
+	pub funder: AccountId,
+	/// The asset the bounty is paid in.
+	pub asset_id: AssetId,
+	/// The escrowed amount.
+	pub amount: Balance,
+	/// The hash of the bounty description, stored off-chain.
+	pub description: Hash,
+	/// The block from which the bounty can no longer receive work and can be refunded.
+	pub expiry: BlockNumber,
+}
+
+/// The work a hunter submitted for a bounty.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Submission<Hash, Balance> {
+	/// The hash of the work, stored off-chain.
+	pub work: Hash,
+	/// The deposit reserved from the hunter, refunded when the bounty closes.
+	pub deposit: Balance,
+}
@@ -0,0 +1,83 @@
+//! Weights for pallet_bounty
+//!
+//! Estimated from the storage accesses of each call, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_bounty --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/bounty/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_bounty.
+pub trait WeightInfo {
+	fn post_bounty() -> Weight;
+	fn submit_work() -> Weight;
+	fn award(s: u32) -> Weight;
+	fn refund(s: u32) -> Weight;
+}
+
+/// Weights for pallet_bounty using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn post_bounty() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn submit_work() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn award(s: u32) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+	}
+	fn refund(s: u32) -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn post_bounty() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn submit_work() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn award(s: u32) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+	}
+	fn refund(s: u32) -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+	}
+}
@@ -0,0 +1,102 @@
+//! Benchmarks of the club pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	traits::{Currency, Get, ReservableCurrency},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+fn funded<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, T::JoinDeposit::get() * 10u32.into());
+}
+
+/// Replace the members of the club by `m` accounts, plus `caller` if any.
+fn set_members<T: Config>(m: u32, caller: Option<&T::AccountId>) -> Vec<T::AccountId> {
+	let mut members: Vec<T::AccountId> = (0..m).map(|i| account("member", i, 0)).collect();
+	members.extend(caller.cloned());
+	members.sort();
+	Members::<T>::put(BoundedVec::try_from(members.clone()).unwrap());
+	members
+}
+
+/// Have `who` apply to join the club.
+fn new_candidate<T: Config>(who: &T::AccountId) {
+	funded::<T>(who);
+	Pallet::<T>::apply(RawOrigin::Signed(who.clone()).into()).unwrap();
+}
+
+benchmarks! {
+	apply {
+		let candidate: T::AccountId = whitelisted_caller();
+		funded::<T>(&candidate);
+	}: _(RawOrigin::Signed(candidate.clone()))
+	verify {
+		assert!(Pallet::<T>::application(candidate).is_some());
+	}
+
+	withdraw_application {
+		let candidate: T::AccountId = whitelisted_caller();
+		new_candidate::<T>(&candidate);
+	}: _(RawOrigin::Signed(candidate.clone()))
+	verify {
+		assert!(Pallet::<T>::application(candidate).is_none());
+	}
+
+	// The last sponsor needed, making the candidate join a club one member short of full.
+	sponsor {
+		let m in T::SponsorsNeeded::get() .. T::MaxMembers::get() - 1;
+		let sponsor: T::AccountId = whitelisted_caller();
+		let members = set_members::<T>(m - 1, Some(&sponsor));
+		let candidate: T::AccountId = account("candidate", 0, 0);
+		new_candidate::<T>(&candidate);
+		for other in members.iter().filter(|other| **other != sponsor) {
+			if Pallet::<T>::application(&candidate).map_or(0, |a| a.sponsors.len() as u32) + 1 ==
+				T::SponsorsNeeded::get()
+			{
+				break
+			}
+			Pallet::<T>::sponsor(RawOrigin::Signed(other.clone()).into(), candidate.clone())?;
+		}
+	}: _(RawOrigin::Signed(sponsor), candidate.clone())
+	verify {
+		assert!(Pallet::<T>::is_member(&candidate));
+	}
+
+	leave {
+		let m in 1 .. T::MaxMembers::get();
+		let member: T::AccountId = whitelisted_caller();
+		set_members::<T>(m - 1, Some(&member));
+		funded::<T>(&member);
+		T::Currency::reserve(&member, T::JoinDeposit::get())?;
+		Deposits::<T>::insert(&member, T::JoinDeposit::get());
+	}: _(RawOrigin::Signed(member.clone()))
+	verify {
+		assert!(!Pallet::<T>::is_member(&member));
+	}
+
+	// The vote reaching the majority, kicking the member out of a full club.
+	vote_kick {
+		let voter: T::AccountId = whitelisted_caller();
+		let members = set_members::<T>(T::MaxMembers::get() - 1, Some(&voter));
+		let member = members.iter().find(|member| **member != voter).cloned().unwrap();
+		funded::<T>(&member);
+		T::Currency::reserve(&member, T::JoinDeposit::get())?;
+		Deposits::<T>::insert(&member, T::JoinDeposit::get());
+		let needed = (members.len() - 1) / 2 + 1;
+		let votes: Vec<T::AccountId> = members
+			.iter()
+			.filter(|other| **other != voter && **other != member)
+			.take(needed - 1)
+			.cloned()
+			.collect();
+		KickVotes::<T>::insert(&member, BoundedVec::try_from(votes).unwrap());
+	}: _(RawOrigin::Signed(voter), member.clone())
+	verify {
+		assert!(!Pallet::<T>::is_member(&member));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,353 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A club with a bounded set of members.
+///
+/// Anyone can apply to join by reserving a deposit. Once enough members sponsored a candidate,
+/// they join the club, and their deposit stays reserved until they leave. Members can vote to
+/// kick one another: when a strict majority of the other members agree, the member is removed and
+/// their deposit slashed.
+///
+/// [`EnsureMember`] lets other pallets restrict calls to members of the club, e.g. by plugging it
+/// in as the origin allowed to mint.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Imbalance, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::StorageDeposits;
+	use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The native currency, used for join deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from candidates, and kept from members until they leave.
+		#[pallet::constant]
+		type JoinDeposit: Get<BalanceOf<Self>>;
+
+		/// The number of members that must sponsor a candidate for them to join.
+		#[pallet::constant]
+		type SponsorsNeeded: Get<u32>;
+
+		/// The maximum number of members.
+		#[pallet::constant]
+		type MaxMembers: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The members of the club, sorted.
+	#[pallet::storage]
+	#[pallet::getter(fn members)]
+	pub type Members<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxMembers>, ValueQuery>;
+
+	/// The deposit reserved from each member. Genesis members have none.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit)]
+	pub type Deposits<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+	/// The pending applications.
+	#[pallet::storage]
+	#[pallet::getter(fn application)]
+	pub type Applications<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ApplicationOf<T>>;
+
+	/// The members that voted to kick each member.
+	#[pallet::storage]
+	#[pallet::getter(fn kick_votes)]
+	pub type KickVotes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxMembers>,
+		ValueQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub members: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { members: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			let mut members = self.members.clone();
+			members.sort();
+			members.dedup();
+			let members: BoundedVec<_, _> = members.try_into().expect("too many genesis members");
+			Members::<T>::put(members);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account applied to join the club.
+		Applied { candidate: T::AccountId, deposit: BalanceOf<T> },
+		/// An application has been withdrawn.
+		ApplicationWithdrawn { candidate: T::AccountId },
+		/// A member sponsored a candidate.
+		Sponsored { candidate: T::AccountId, sponsor: T::AccountId },
+		/// A candidate joined the club.
+		Joined { member: T::AccountId },
+		/// A member left the club.
+		Left { member: T::AccountId },
+		/// A member voted to kick another member.
+		KickVoted { member: T::AccountId, voter: T::AccountId },
+		/// A member has been kicked out of the club, and their deposit slashed.
+		Kicked { member: T::AccountId, slashed: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not a member.
+		NotMember,
+		/// The account is already a member.
+		AlreadyMember,
+		/// The account already applied.
+		AlreadyApplied,
+		/// The account has not applied.
+		NotApplied,
+		/// The member already sponsored the candidate.
+		AlreadySponsored,
+		/// The member already voted to kick the target.
+		AlreadyVoted,
+		/// Members cannot vote to kick themselves.
+		SelfKick,
+		/// The club is full.
+		TooManyMembers,
+		/// The account cannot afford the join deposit.
+		InsufficientDeposit,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Apply to join the club, reserving the join deposit.
+		#[pallet::weight(T::WeightInfo::apply())]
+		pub fn apply(origin: OriginFor<T>) -> DispatchResult {
+			let candidate = ensure_signed(origin)?;
+
+			ensure!(!Self::is_member(&candidate), Error::<T>::AlreadyMember);
+			ensure!(!Applications::<T>::contains_key(&candidate), Error::<T>::AlreadyApplied);
+
+			let deposit = T::Currency::reserve_deposit(&candidate, T::JoinDeposit::get())
+				.map_err(|_| Error::<T>::InsufficientDeposit)?;
+			Applications::<T>::insert(
+				&candidate,
+				Application { deposit, sponsors: Default::default() },
+			);
+
+			Self::deposit_event(Event::Applied { candidate, deposit });
+
+			Ok(())
+		}
+
+		/// Withdraw an application, getting the deposit back.
+		#[pallet::weight(T::WeightInfo::withdraw_application())]
+		pub fn withdraw_application(origin: OriginFor<T>) -> DispatchResult {
+			let candidate = ensure_signed(origin)?;
+
+			let application = Applications::<T>::take(&candidate).ok_or(Error::<T>::NotApplied)?;
+			T::Currency::refund_deposit(&candidate, application.deposit);
+
+			Self::deposit_event(Event::ApplicationWithdrawn { candidate });
+
+			Ok(())
+		}
+
+		/// Sponsor a candidate. The last sponsor needed makes them join the club.
+		#[pallet::weight(T::WeightInfo::sponsor(T::MaxMembers::get()))]
+		pub fn sponsor(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(Self::is_member(&sponsor), Error::<T>::NotMember);
+			let mut application = Self::application(&candidate).ok_or(Error::<T>::NotApplied)?;
+			ensure!(!application.sponsors.contains(&sponsor), Error::<T>::AlreadySponsored);
+
+			// There is always room for one more sponsor while the application is pending.
+			application
+				.sponsors
+				.try_push(sponsor.clone())
+				.map_err(|_| Error::<T>::AlreadySponsored)?;
+
+			let joins = application.sponsors.len() as u32 >= T::SponsorsNeeded::get();
+			if joins {
+				Members::<T>::try_mutate(|members| -> DispatchResult {
+					let index = members.binary_search(&candidate).unwrap_or_else(|index| index);
+					members
+						.try_insert(index, candidate.clone())
+						.map_err(|_| Error::<T>::TooManyMembers)?;
+					Ok(())
+				})?;
+				Deposits::<T>::insert(&candidate, application.deposit);
+				Applications::<T>::remove(&candidate);
+			} else {
+				Applications::<T>::insert(&candidate, application);
+			}
+
+			Self::deposit_event(Event::Sponsored { candidate: candidate.clone(), sponsor });
+			if joins {
+				Self::deposit_event(Event::Joined { member: candidate });
+			}
+
+			Ok(())
+		}
+
+		/// Leave the club, getting the deposit back.
+		#[pallet::weight(T::WeightInfo::leave(T::MaxMembers::get()))]
+		pub fn leave(origin: OriginFor<T>) -> DispatchResult {
+			let member = ensure_signed(origin)?;
+
+			Self::remove_member(&member)?;
+			if let Some(deposit) = Deposits::<T>::take(&member) {
+				T::Currency::refund_deposit(&member, deposit);
+			}
+
+			Self::deposit_event(Event::Left { member });
+
+			Ok(())
+		}
+
+		/// Vote to kick a member out of the club.
+		///
+		/// The member is kicked as soon as a strict majority of the other members voted so.
+		#[pallet::weight(T::WeightInfo::vote_kick())]
+		pub fn vote_kick(origin: OriginFor<T>, member: T::AccountId) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			ensure!(voter != member, Error::<T>::SelfKick);
+			let members = Self::members();
+			ensure!(members.binary_search(&voter).is_ok(), Error::<T>::NotMember);
+			ensure!(members.binary_search(&member).is_ok(), Error::<T>::NotMember);
+
+			let mut votes = Self::kick_votes(&member);
+			// Votes of former members no longer count.
+			votes.retain(|voter| members.binary_search(voter).is_ok());
+			ensure!(!votes.contains(&voter), Error::<T>::AlreadyVoted);
+			votes.try_push(voter.clone()).map_err(|_| Error::<T>::TooManyMembers)?;
+
+			Self::deposit_event(Event::KickVoted { member: member.clone(), voter });
+
+			if votes.len() * 2 > members.len() - 1 {
+				Self::remove_member(&member)?;
+				let deposit = Deposits::<T>::take(&member).unwrap_or_default();
+				let slashed = T::Currency::slash_deposit(&member, deposit);
+
+				Self::deposit_event(Event::Kicked { member, slashed: slashed.peek() });
+			} else {
+				KickVotes::<T>::insert(&member, votes);
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `who` is a member of the club.
+		pub fn is_member(who: &T::AccountId) -> bool {
+			Self::members().binary_search(who).is_ok()
+		}
+
+		/// Remove a member and the votes to kick them.
+		fn remove_member(member: &T::AccountId) -> DispatchResult {
+			Members::<T>::try_mutate(|members| -> DispatchResult {
+				let index = members.binary_search(member).map_err(|_| Error::<T>::NotMember)?;
+				members.remove(index);
+				Ok(())
+			})?;
+			KickVotes::<T>::remove(member);
+			Ok(())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let members = Self::members();
+			ensure!(members.windows(2).all(|pair| pair[0] < pair[1]), "members not sorted");
+			ensure!(
+				Deposits::<T>::iter_keys().all(|who| members.binary_search(&who).is_ok()),
+				"deposit of a non-member"
+			);
+			ensure!(
+				Applications::<T>::iter_keys().all(|who| members.binary_search(&who).is_err()),
+				"application of a member"
+			);
+			ensure!(
+				KickVotes::<T>::iter_keys().all(|who| members.binary_search(&who).is_ok()),
+				"votes to kick a non-member"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Ensure the origin is a signed member of the club, yielding their account.
+pub struct EnsureMember<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> frame_support::traits::EnsureOrigin<T::Origin> for EnsureMember<T> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::Origin) -> Result<Self::Success, T::Origin> {
+		o.into().and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who) if Pallet::<T>::is_member(&who) => Ok(who),
+			r => Err(T::Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		let member = Pallet::<T>::members().first().cloned().expect("the club has no member");
+		T::Origin::from(frame_system::RawOrigin::Signed(member))
+	}
+}
@@ -0,0 +1,98 @@
+use super::mock::*;
+use crate::{EnsureMember, Error, Event as ClubEvent};
+use frame_support::{assert_noop, assert_ok, traits::EnsureOrigin};
+
+fn join(candidate: u64) {
+	assert_ok!(Club::apply(Origin::signed(candidate)));
+	assert_ok!(Club::sponsor(Origin::signed(ALICE), candidate));
+	assert_ok!(Club::sponsor(Origin::signed(BOB), candidate));
+}
+
+#[test]
+fn candidates_join_once_sponsored() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Club::members().into_inner(), vec![ALICE, BOB]);
+
+		assert_noop!(Club::apply(Origin::signed(ALICE)), Error::<Test>::AlreadyMember);
+		assert_noop!(Club::apply(Origin::signed(DAVE)), Error::<Test>::InsufficientDeposit);
+		assert_ok!(Club::apply(Origin::signed(CHARLIE)));
+		assert_noop!(Club::apply(Origin::signed(CHARLIE)), Error::<Test>::AlreadyApplied);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 10);
+
+		assert_noop!(Club::sponsor(Origin::signed(DAVE), CHARLIE), Error::<Test>::NotMember);
+		assert_noop!(Club::sponsor(Origin::signed(ALICE), DAVE), Error::<Test>::NotApplied);
+		assert_ok!(Club::sponsor(Origin::signed(ALICE), CHARLIE));
+		assert_noop!(
+			Club::sponsor(Origin::signed(ALICE), CHARLIE),
+			Error::<Test>::AlreadySponsored
+		);
+		assert!(!Club::is_member(&CHARLIE));
+
+		assert_ok!(Club::sponsor(Origin::signed(BOB), CHARLIE));
+		System::assert_last_event(ClubEvent::Joined { member: CHARLIE }.into());
+		assert_eq!(Club::members().into_inner(), vec![ALICE, BOB, CHARLIE]);
+		assert!(Club::application(CHARLIE).is_none());
+		assert_eq!(Club::deposit(CHARLIE), Some(10));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 10);
+	})
+}
+
+#[test]
+fn deposits_are_returned_on_withdrawal_and_leave() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::apply(Origin::signed(CHARLIE)));
+		assert_ok!(Club::withdraw_application(Origin::signed(CHARLIE)));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_noop!(
+			Club::withdraw_application(Origin::signed(CHARLIE)),
+			Error::<Test>::NotApplied
+		);
+
+		join(CHARLIE);
+		assert_ok!(Club::leave(Origin::signed(CHARLIE)));
+		System::assert_last_event(ClubEvent::Left { member: CHARLIE }.into());
+		assert_eq!(Club::members().into_inner(), vec![ALICE, BOB]);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_noop!(Club::leave(Origin::signed(CHARLIE)), Error::<Test>::NotMember);
+	})
+}
+
+#[test]
+fn a_majority_of_the_other_members_kicks() {
+	new_test_ext().execute_with(|| {
+		join(CHARLIE);
+
+		assert_noop!(Club::vote_kick(Origin::signed(ALICE), ALICE), Error::<Test>::SelfKick);
+		assert_noop!(Club::vote_kick(Origin::signed(DAVE), CHARLIE), Error::<Test>::NotMember);
+		assert_noop!(Club::vote_kick(Origin::signed(ALICE), DAVE), Error::<Test>::NotMember);
+
+		assert_ok!(Club::vote_kick(Origin::signed(ALICE), CHARLIE));
+		assert_noop!(
+			Club::vote_kick(Origin::signed(ALICE), CHARLIE),
+			Error::<Test>::AlreadyVoted
+		);
+		assert!(Club::is_member(&CHARLIE));
+
+		assert_ok!(Club::vote_kick(Origin::signed(BOB), CHARLIE));
+		System::assert_last_event(ClubEvent::Kicked { member: CHARLIE, slashed: 10 }.into());
+		assert!(!Club::is_member(&CHARLIE));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), 90);
+		assert!(Club::kick_votes(CHARLIE).is_empty());
+	})
+}
+
+#[test]
+fn ensure_member_only_lets_members_through() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(EnsureMember::<Test>::ensure_origin(Origin::signed(ALICE)).ok(), Some(ALICE));
+		assert!(EnsureMember::<Test>::ensure_origin(Origin::signed(CHARLIE)).is_err());
+		assert!(EnsureMember::<Test>::ensure_origin(Origin::root()).is_err());
+
+		join(CHARLIE);
+		assert_eq!(
+			EnsureMember::<Test>::ensure_origin(Origin::signed(CHARLIE)).ok(),
+			Some(CHARLIE)
+		);
+	})
+}
@@ -0,0 +1,37 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{Currency, Get},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type ApplicationOf<T> =
+	Application<AccountIdOf<T>, BalanceOf<T>, <T as Config>::SponsorsNeeded>;
+
+/// An application to join the club.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+#[scale_info(skip_type_params(SponsorsNeeded))]
+pub struct Application<AccountId, Balance, SponsorsNeeded>
+where
+	AccountId: Clone + Eq + core::fmt::Debug,
+	Balance: Clone + Eq + core::fmt::Debug,
+	SponsorsNeeded: Get<u32>,
+{
+	/// The deposit reserved from the candidate.
+	pub deposit: Balance,
+	/// The members that sponsored the candidate so far.
+	pub sponsors: BoundedVec<AccountId, SponsorsNeeded>,
+}
@@ -0,0 +1,86 @@
+//! Weights for pallet_club
+//!
+//! Estimated from the storage accesses of each call, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_club --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/club/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_club.
+pub trait WeightInfo {
+	fn apply() -> Weight;
+	fn withdraw_application() -> Weight;
+	fn sponsor(m: u32) -> Weight;
+	fn leave(m: u32) -> Weight;
+	fn vote_kick() -> Weight;
+}
+
+/// Weights for pallet_club using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn apply() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn withdraw_application() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn sponsor(m: u32) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn leave(m: u32) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn vote_kick() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn apply() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn withdraw_application() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn sponsor(m: u32) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn leave(m: u32) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn vote_kick() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+}
@@ -0,0 +1,92 @@
+//! Benchmarks of the donation-matching pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::tokens::fungibles::{Create, Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+
+const ASSET_ID: u32 = 1_000;
+const AMOUNT: u32 = 1_000_000;
+
+/// Create the asset of the benchmarks, and fund `holders` with it.
+fn create_asset<T: Config>(holders: &[&T::AccountId])
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	T::Assets::create(ASSET_ID.into(), account("owner", 0, 0), true, One::one()).unwrap();
+	for who in holders {
+		T::Assets::mint_into(ASSET_ID.into(), who, AMOUNT.into()).unwrap();
+	}
+}
+
+/// Have `sponsor` create a campaign open for 10 blocks from now.
+fn new_campaign<T: Config>(sponsor: &T::AccountId) -> (CampaignId, T::BlockNumber) {
+	let campaign_id = Pallet::<T>::next_campaign_id();
+	let start = frame_system::Pallet::<T>::block_number();
+	let end = start + 10u32.into();
+	Pallet::<T>::create_campaign(
+		RawOrigin::Signed(sponsor.clone()).into(),
+		account("cause", 0, 0),
+		ASSET_ID.into(),
+		(AMOUNT / 2).into(),
+		start,
+		end,
+	)
+	.unwrap();
+	(campaign_id, end)
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	create_campaign {
+		let sponsor: T::AccountId = whitelisted_caller();
+		create_asset::<T>(&[&sponsor]);
+		let start = frame_system::Pallet::<T>::block_number();
+		let end = start + 10u32.into();
+	}: _(
+		RawOrigin::Signed(sponsor),
+		account("cause", 0, 0),
+		ASSET_ID.into(),
+		AMOUNT.into(),
+		start,
+		end
+	)
+	verify {
+		assert!(Pallet::<T>::campaign(0).is_some());
+	}
+
+	donate {
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		let donor: T::AccountId = whitelisted_caller();
+		create_asset::<T>(&[&sponsor, &donor]);
+		let (campaign_id, _) = new_campaign::<T>(&sponsor);
+	}: _(RawOrigin::Signed(donor), campaign_id, (AMOUNT / 4).into())
+	verify {
+		assert_eq!(
+			Pallet::<T>::campaign(campaign_id).map(|campaign| campaign.matched),
+			Some((AMOUNT / 4).into())
+		);
+	}
+
+	// Both the cause and the sponsor are paid.
+	close_campaign {
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		let donor: T::AccountId = account("donor", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		create_asset::<T>(&[&sponsor, &donor]);
+		let (campaign_id, end) = new_campaign::<T>(&sponsor);
+		Pallet::<T>::donate(RawOrigin::Signed(donor).into(), campaign_id, (AMOUNT / 4).into())?;
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: _(RawOrigin::Signed(caller), campaign_id)
+	verify {
+		assert!(Pallet::<T>::campaign(campaign_id).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,268 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Match donations to a cause, one for one, from a sponsor's pool.
+///
+/// A sponsor escrows a matching pool for a cause and a donation window. Every donation made during
+/// the window is escrowed alongside the pool and matched with the same amount from it, until the
+/// pool runs out. Once the window is over, anyone can close the campaign: the cause receives the
+/// donations and their match, and the sponsor gets back what is left of the pool.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::fungibles, transactional, PalletId};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets donations are made in.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next campaign will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_campaign_id)]
+	pub type NextCampaignId<T> = StorageValue<_, CampaignId, ValueQuery>;
+
+	/// The campaigns that have not been closed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn campaign)]
+	pub type Campaigns<T: Config> = StorageMap<_, Twox64Concat, CampaignId, CampaignOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A matching campaign has been created.
+		CampaignCreated {
+			campaign_id: CampaignId,
+			sponsor: T::AccountId,
+			cause: T::AccountId,
+			pool: BalanceOf<T>,
+		},
+		/// A donation has been made, and matched.
+		Donated {
+			campaign_id: CampaignId,
+			donor: T::AccountId,
+			amount: BalanceOf<T>,
+			matched: BalanceOf<T>,
+		},
+		/// A campaign has been closed and paid out.
+		CampaignClosed {
+			campaign_id: CampaignId,
+			payout: BalanceOf<T>,
+			refund: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The campaign does not exist.
+		UnknownCampaign,
+		/// The amount must be non zero.
+		ZeroAmount,
+		/// The donation window must end after it starts, in the future.
+		InvalidWindow,
+		/// The donation window is not open.
+		WindowClosed,
+		/// The donation window is not over yet.
+		CampaignOngoing,
+		/// There is no campaign id left.
+		NoAvailableCampaignId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow a matching `pool` for donations to `cause` between blocks `start` and `end`.
+		#[pallet::weight(T::WeightInfo::create_campaign())]
+		pub fn create_campaign(
+			origin: OriginFor<T>,
+			cause: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			pool: BalanceOf<T>,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(!pool.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				start < end && end > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidWindow
+			);
+
+			let campaign_id = Self::next_campaign_id();
+			let next_id = campaign_id.checked_add(1).ok_or(Error::<T>::NoAvailableCampaignId)?;
+
+			T::Assets::transfer(asset_id, &sponsor, &Self::account_id(), pool, false)?;
+
+			Campaigns::<T>::insert(
+				campaign_id,
+				Campaign {
+					sponsor: sponsor.clone(),
+					cause: cause.clone(),
+					asset_id,
+					pool,
+					donated: Zero::zero(),
+					matched: Zero::zero(),
+					start,
+					end,
+				},
+			);
+			NextCampaignId::<T>::put(next_id);
+
+			Self::deposit_event(Event::CampaignCreated {
+				campaign_id,
+				sponsor,
+				cause,
+				pool,
+			});
+
+			Ok(())
+		}
+
+		/// Donate `amount` to the cause of a campaign, matched from its pool.
+		#[pallet::weight(T::WeightInfo::donate())]
+		pub fn donate(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let donor = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut campaign = Self::campaign(campaign_id).ok_or(Error::<T>::UnknownCampaign)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(campaign.start <= now && now < campaign.end, Error::<T>::WindowClosed);
+
+			T::Assets::transfer(campaign.asset_id, &donor, &Self::account_id(), amount, false)?;
+
+			let matched = amount.min(campaign.pool);
+			campaign.pool -= matched;
+			campaign.donated = campaign.donated.saturating_add(amount);
+			campaign.matched = campaign.matched.saturating_add(matched);
+			Campaigns::<T>::insert(campaign_id, campaign);
+
+			Self::deposit_event(Event::Donated { campaign_id, donor, amount, matched });
+
+			Ok(())
+		}
+
+		/// Close a campaign once its window is over: pay out the cause and refund the sponsor.
+		#[pallet::weight(T::WeightInfo::close_campaign())]
+		#[transactional]
+		pub fn close_campaign(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let campaign = Self::campaign(campaign_id).ok_or(Error::<T>::UnknownCampaign)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= campaign.end,
+				Error::<T>::CampaignOngoing
+			);
+
+			let pallet_account = Self::account_id();
+			let payout = campaign.donated.saturating_add(campaign.matched);
+			if !payout.is_zero() {
+				T::Assets::transfer(
+					campaign.asset_id,
+					&pallet_account,
+					&campaign.cause,
+					payout,
+					false,
+				)?;
+			}
+			if !campaign.pool.is_zero() {
+				T::Assets::transfer(
+					campaign.asset_id,
+					&pallet_account,
+					&campaign.sponsor,
+					campaign.pool,
+					false,
+				)?;
+			}
+			Campaigns::<T>::remove(campaign_id);
+
+			Self::deposit_event(Event::CampaignClosed {
+				campaign_id,
+				payout,
+				refund: campaign.pool,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding matching pools and donations.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use rusty_node_support::escrow::ensure_escrowed;
+
+			let next_id = Self::next_campaign_id();
+			for (campaign_id, campaign) in Campaigns::<T>::iter() {
+				ensure!(campaign_id < next_id, "campaign id not allocated");
+				ensure!(campaign.start < campaign.end, "campaign ending before it starts");
+				ensure!(campaign.matched <= campaign.donated, "more matched than donated");
+			}
+
+			// The escrow account holds the pool, donations and matches of every open campaign.
+			let account = Self::account_id();
+			let held = Campaigns::<T>::iter_values().map(|campaign| {
+				let held =
+					campaign.pool.saturating_add(campaign.donated).saturating_add(campaign.matched);
+				(campaign.asset_id, held)
+			});
+			ensure_escrowed::<_, T::Assets>(&account, held, "campaigns not escrowed")?;
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,109 @@
+use super::mock::*;
+use crate::{Error, Event as MatchingEvent};
+use frame_support::{assert_noop, assert_ok};
+
+fn create_campaign() {
+	assert_ok!(DonationMatching::create_campaign(
+		Origin::signed(ALICE),
+		CHARLIE,
+		ASSET_ID,
+		100,
+		2,
+		10
+	));
+}
+
+#[test]
+fn create_campaign_escrows_the_pool() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			DonationMatching::create_campaign(Origin::signed(ALICE), CHARLIE, ASSET_ID, 0, 2, 10),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			DonationMatching::create_campaign(Origin::signed(ALICE), CHARLIE, ASSET_ID, 100, 10, 2),
+			Error::<Test>::InvalidWindow
+		);
+		assert_noop!(
+			DonationMatching::create_campaign(Origin::signed(ALICE), CHARLIE, ASSET_ID, 100, 0, 1),
+			Error::<Test>::InvalidWindow
+		);
+		create_campaign();
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 900);
+		assert_eq!(Assets::balance(ASSET_ID, DonationMatching::account_id()), 100);
+		System::assert_last_event(
+			MatchingEvent::CampaignCreated {
+				campaign_id: 0,
+				sponsor: ALICE,
+				cause: CHARLIE,
+				pool: 100,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn donations_are_matched_within_the_window() {
+	new_test_ext().execute_with(|| {
+		create_campaign();
+
+		assert_noop!(
+			DonationMatching::donate(Origin::signed(BOB), 0, 10),
+			Error::<Test>::WindowClosed
+		);
+		System::set_block_number(2);
+		assert_noop!(
+			DonationMatching::donate(Origin::signed(BOB), 0, 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_ok!(DonationMatching::donate(Origin::signed(BOB), 0, 60));
+		System::assert_last_event(
+			MatchingEvent::Donated { campaign_id: 0, donor: BOB, amount: 60, matched: 60 }.into(),
+		);
+
+		// Only what is left of the pool is matched.
+		assert_ok!(DonationMatching::donate(Origin::signed(BOB), 0, 60));
+		System::assert_last_event(
+			MatchingEvent::Donated { campaign_id: 0, donor: BOB, amount: 60, matched: 40 }.into(),
+		);
+
+		let campaign = DonationMatching::campaign(0).unwrap();
+		assert_eq!((campaign.pool, campaign.donated, campaign.matched), (0, 120, 100));
+
+		System::set_block_number(10);
+		assert_noop!(
+			DonationMatching::donate(Origin::signed(BOB), 0, 10),
+			Error::<Test>::WindowClosed
+		);
+	})
+}
+
+#[test]
+fn close_campaign_pays_the_cause_and_refunds_the_sponsor() {
+	new_test_ext().execute_with(|| {
+		create_campaign();
+		System::set_block_number(2);
+		assert_ok!(DonationMatching::donate(Origin::signed(BOB), 0, 30));
+
+		assert_noop!(
+			DonationMatching::close_campaign(Origin::signed(BOB), 0),
+			Error::<Test>::CampaignOngoing
+		);
+		System::set_block_number(10);
+		assert_ok!(DonationMatching::close_campaign(Origin::signed(BOB), 0));
+
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), 60);
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 970);
+		assert_eq!(Assets::balance(ASSET_ID, DonationMatching::account_id()), 0);
+		assert!(DonationMatching::campaign(0).is_none());
+		System::assert_last_event(
+			MatchingEvent::CampaignClosed { campaign_id: 0, payout: 60, refund: 70 }.into(),
+		);
+		assert_noop!(
+			DonationMatching::close_campaign(Origin::signed(BOB), 0),
+			Error::<Test>::UnknownCampaign
+		);
+	})
+}
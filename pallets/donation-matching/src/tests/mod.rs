@@ -0,0 +1,3 @@
+pub mod mock;
+
+mod campaigns;
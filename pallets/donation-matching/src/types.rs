@@ -0,0 +1,33 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type CampaignId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type CampaignOf<T> =
+	Campaign<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// A matching campaign for a cause.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Campaign<AccountId, AssetId, Balance, BlockNumber> {
+	/// The account that funded the matching pool.
+	pub sponsor: AccountId,
+	/// The account donations go to.
+	pub cause: AccountId,
+	/// The asset donations are made in.
+	pub asset_id: AssetId,
+	/// What is left of the matching pool.
+	pub pool: Balance,
+	/// The total donated so far.
+	pub donated: Balance,
+	/// The total matched so far.
+	pub matched: Balance,
+	/// The first block donations are matched at.
+	pub start: BlockNumber,
+	/// The block from which donations are no longer accepted, and the campaign can be closed.
+	pub end: BlockNumber,
+}
@@ -0,0 +1,60 @@
+//! Weights for pallet_donation_matching
+//!
+//! Estimated from the storage accesses of each call, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_donation_matching --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/donation-matching/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_donation_matching.
+pub trait WeightInfo {
+	fn create_campaign() -> Weight;
+	fn donate() -> Weight;
+	fn close_campaign() -> Weight;
+}
+
+/// Weights for pallet_donation_matching using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_campaign() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn donate() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn close_campaign() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_campaign() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn donate() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn close_campaign() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+}
@@ -0,0 +1,97 @@
+//! Benchmarks of the dutch-auction pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{
+	tokens::nonfungibles::{Create, Mutate},
+	Currency,
+};
+use frame_system::RawOrigin;
+
+const CLASS_ID: u32 = 1_000;
+const INSTANCE_ID: u32 = 0;
+const PRICE: u32 = 1_000;
+
+fn funded<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, T::Currency::minimum_balance() * 1_000_000u32.into());
+}
+
+/// Mint the item of the benchmarks to `owner`.
+fn mint_item<T: Config>(owner: &T::AccountId)
+where
+	T::Items: Create<T::AccountId> + Mutate<T::AccountId>,
+	T::ClassId: From<u32>,
+	T::InstanceId: From<u32>,
+{
+	funded::<T>(owner);
+	T::Items::create_class(&CLASS_ID.into(), owner, owner).unwrap();
+	T::Items::mint_into(&CLASS_ID.into(), &INSTANCE_ID.into(), owner).unwrap();
+}
+
+/// Have `seller` auction the item of the benchmarks for the native currency.
+fn new_auction<T: Config>(seller: &T::AccountId)
+where
+	T::Items: Create<T::AccountId> + Mutate<T::AccountId>,
+	T::ClassId: From<u32>,
+	T::InstanceId: From<u32>,
+{
+	mint_item::<T>(seller);
+	Pallet::<T>::create_auction(
+		RawOrigin::Signed(seller.clone()).into(),
+		CLASS_ID.into(),
+		INSTANCE_ID.into(),
+		Funds::Native,
+		PRICE.into(),
+		(PRICE / 2).into(),
+		1u32.into(),
+	)
+	.unwrap();
+}
+
+benchmarks! {
+	where_clause { where
+		T::Items: Create<T::AccountId> + Mutate<T::AccountId>,
+		T::ClassId: From<u32>,
+		T::InstanceId: From<u32>,
+	}
+
+	create_auction {
+		let seller: T::AccountId = whitelisted_caller();
+		mint_item::<T>(&seller);
+	}: _(
+		RawOrigin::Signed(seller),
+		CLASS_ID.into(),
+		INSTANCE_ID.into(),
+		Funds::Native,
+		PRICE.into(),
+		(PRICE / 2).into(),
+		1u32.into()
+	)
+	verify {
+		assert!(Pallet::<T>::auction(T::ClassId::from(CLASS_ID), T::InstanceId::from(INSTANCE_ID))
+			.is_some());
+	}
+
+	// Royalties, if the policy sets any for the class, are not part of the measure.
+	buy {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		new_auction::<T>(&seller);
+		funded::<T>(&buyer);
+	}: _(RawOrigin::Signed(buyer), CLASS_ID.into(), INSTANCE_ID.into(), PRICE.into())
+	verify {
+		assert!(Pallet::<T>::auction(T::ClassId::from(CLASS_ID), T::InstanceId::from(INSTANCE_ID))
+			.is_none());
+	}
+
+	cancel_auction {
+		let seller: T::AccountId = whitelisted_caller();
+		new_auction::<T>(&seller);
+	}: _(RawOrigin::Signed(seller), CLASS_ID.into(), INSTANCE_ID.into())
+	verify {
+		assert!(Pallet::<T>::auction(T::ClassId::from(CLASS_ID), T::InstanceId::from(INSTANCE_ID))
+			.is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,351 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Sell nonfungible items in descending-price, or Dutch, auctions.
+///
+/// A seller escrows an item and sets a start price, a floor price and the amount the price
+/// decreases by every block. The first buyer to accept the current price wins the item. Payment is
+/// made either in the native currency or in a fungible asset, and the royalty owed on the item,
+/// as computed by the runtime's `RoyaltyPolicy`, is paid out of it.
+///
+/// Buyers are charged the `TradeFee` on the price, out of what the seller receives, and the fee is
+/// reported to `OnFeeCharged`, so that referrers get their share.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			tokens::{fungibles, nonfungibles},
+			Currency,
+			ExistenceRequirement::AllowDeath,
+		},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_royalty_registry::RoyaltyPolicy;
+	use rusty_node_support::{
+		fees::{charge_fee, charge_native_fee},
+		FeeKind, OnFeeCharged, OnNativeFeeCharged,
+	};
+	use sp_runtime::{
+		traits::{AccountIdConversion, Saturating, UniqueSaturatedInto, Zero},
+		Perbill,
+	};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The native currency items can be paid with.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The fungible assets items can be paid with.
+		type Assets: fungibles::Transfer<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// Identifier of an item class.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its class.
+		type InstanceId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The nonfungible items auctioned.
+		type Items: nonfungibles::Transfer<
+			Self::AccountId,
+			ClassId = Self::ClassId,
+			InstanceId = Self::InstanceId,
+		>;
+
+		/// The royalties owed on sales.
+		type Royalties: RoyaltyPolicy<Self::AccountId, Self::ClassId, BalanceOf<Self>>;
+
+		/// The share of the price charged as a fee.
+		#[pallet::constant]
+		type TradeFee: Get<Perbill>;
+
+		/// The account collecting the fees, e.g. the treasury.
+		type FeeCollector: Get<Self::AccountId>;
+
+		/// Shares the fees with the referrers of the buyers paying them.
+		type OnFeeCharged: OnFeeCharged<Self::AccountId, AssetIdOf<Self>, BalanceOf<Self>> +
+			OnNativeFeeCharged<Self::AccountId, BalanceOf<Self>>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The running auction of each escrowed item.
+	#[pallet::storage]
+	#[pallet::getter(fn auction)]
+	pub type Auctions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		AuctionOf<T>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An item has been put up for auction.
+		AuctionCreated {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			seller: T::AccountId,
+			funds: FundsOf<T>,
+			start_price: BalanceOf<T>,
+			floor_price: BalanceOf<T>,
+		},
+		/// An item has been bought at the current price.
+		Sold {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			royalty: BalanceOf<T>,
+		},
+		/// An auction has been canceled and its item given back to the seller.
+		AuctionCanceled { class: T::ClassId, instance: T::InstanceId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The item does not exist.
+		UnknownItem,
+		/// The caller does not own the item.
+		NotOwner,
+		/// The item is not auctioned.
+		NotAuctioned,
+		/// The floor price must not exceed the start price.
+		FloorAboveStart,
+		/// The current price exceeds the price the buyer is willing to pay.
+		PriceTooHigh,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow an item and auction it, starting at `start_price` and decreasing by `decrement`
+		/// every block down to `floor_price`.
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			instance: T::InstanceId,
+			funds: FundsOf<T>,
+			start_price: BalanceOf<T>,
+			floor_price: BalanceOf<T>,
+			decrement: BalanceOf<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+
+			let owner = <T::Items as nonfungibles::Inspect<T::AccountId>>::owner(&class, &instance)
+				.ok_or(Error::<T>::UnknownItem)?;
+			ensure!(owner == seller, Error::<T>::NotOwner);
+			ensure!(floor_price <= start_price, Error::<T>::FloorAboveStart);
+
+			T::Items::transfer(&class, &instance, &Self::account_id())?;
+
+			Auctions::<T>::insert(
+				&class,
+				&instance,
+				Auction {
+					seller: seller.clone(),
+					funds,
+					start_price,
+					floor_price,
+					decrement,
+					started_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::AuctionCreated {
+				class,
+				instance,
+				seller,
+				funds,
+				start_price,
+				floor_price,
+			});
+
+			Ok(())
+		}
+
+		/// Buy an item at its current price, if it does not exceed `max_price`.
+		#[pallet::weight(T::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			instance: T::InstanceId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			let auction = Auctions::<T>::get(&class, &instance).ok_or(Error::<T>::NotAuctioned)?;
+			let price = Self::current_price(&auction);
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+			let mut royalty = Zero::zero();
+			if let Some((beneficiary, amount)) = T::Royalties::royalty(&class, price) {
+				royalty = amount.min(price);
+				Self::pay(auction.funds, &buyer, &beneficiary, royalty)?;
+			}
+			let fee = T::TradeFee::get().mul_floor(price).min(price.saturating_sub(royalty));
+			Self::charge_trade_fee(auction.funds, &buyer, fee)?;
+			Self::pay(
+				auction.funds,
+				&buyer,
+				&auction.seller,
+				price.saturating_sub(royalty).saturating_sub(fee),
+			)?;
+			T::Items::transfer(&class, &instance, &buyer)?;
+			Auctions::<T>::remove(&class, &instance);
+
+			Self::deposit_event(Event::Sold {
+				class,
+				instance,
+				seller: auction.seller,
+				buyer,
+				price,
+				royalty,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel an auction and get the item back.
+		#[pallet::weight(T::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			instance: T::InstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let auction = Auctions::<T>::get(&class, &instance).ok_or(Error::<T>::NotAuctioned)?;
+			ensure!(auction.seller == who, Error::<T>::NotOwner);
+
+			T::Items::transfer(&class, &instance, &who)?;
+			Auctions::<T>::remove(&class, &instance);
+
+			Self::deposit_event(Event::AuctionCanceled { class, instance });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding auctioned items.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// The price an auctioned item can be bought at now.
+		pub fn current_price(auction: &AuctionOf<T>) -> BalanceOf<T> {
+			let elapsed: u32 = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(auction.started_at)
+				.unique_saturated_into();
+			auction
+				.start_price
+				.saturating_sub(auction.decrement.saturating_mul(elapsed.into()))
+				.max(auction.floor_price)
+		}
+
+		fn pay(
+			funds: FundsOf<T>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(())
+			}
+			match funds {
+				Funds::Native => T::Currency::transfer(from, to, amount, AllowDeath),
+				Funds::Asset(asset_id) =>
+					T::Assets::transfer(asset_id, from, to, amount, false).map(|_| ()),
+			}
+		}
+
+		/// Charge the buyer of an item the fee, in the funds of its auction.
+		fn charge_trade_fee(
+			funds: FundsOf<T>,
+			buyer: &T::AccountId,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let collector = T::FeeCollector::get();
+			match funds {
+				Funds::Native => charge_native_fee::<_, T::Currency, T::OnFeeCharged>(
+					FeeKind::Marketplace,
+					buyer,
+					fee,
+					&collector,
+				),
+				Funds::Asset(asset_id) => charge_fee::<_, T::Assets, T::OnFeeCharged>(
+					FeeKind::Marketplace,
+					buyer,
+					asset_id,
+					fee,
+					&collector,
+				),
+			}
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let account = Self::account_id();
+			for (class, instance, auction) in Auctions::<T>::iter() {
+				ensure!(auction.floor_price <= auction.start_price, "floor above the start price");
+				ensure!(
+					<T::Items as nonfungibles::Inspect<T::AccountId>>::owner(&class, &instance) ==
+						Some(account.clone()),
+					"auctioned item not escrowed"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
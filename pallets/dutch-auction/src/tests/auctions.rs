@@ -0,0 +1,138 @@
+use super::mock::*;
+use crate::{Error, Event as AuctionEvent, Funds};
+use frame_support::{assert_noop, assert_ok, traits::tokens::nonfungibles::Inspect};
+use sp_runtime::Perbill;
+
+fn create_auction(funds: Funds<u32>) {
+	assert_ok!(DutchAuction::create_auction(
+		Origin::signed(ALICE),
+		CLASS_ID,
+		INSTANCE_ID,
+		funds,
+		50,
+		20,
+		5
+	));
+}
+
+fn current_price() -> u64 {
+	DutchAuction::current_price(&DutchAuction::auction(CLASS_ID, INSTANCE_ID).unwrap())
+}
+
+#[test]
+fn create_auction_escrows_the_item() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			DutchAuction::create_auction(
+				Origin::signed(BOB),
+				CLASS_ID,
+				INSTANCE_ID,
+				Funds::Native,
+				50,
+				20,
+				5
+			),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			DutchAuction::create_auction(
+				Origin::signed(ALICE),
+				CLASS_ID,
+				INSTANCE_ID,
+				Funds::Native,
+				20,
+				50,
+				5
+			),
+			Error::<Test>::FloorAboveStart
+		);
+		create_auction(Funds::Native);
+
+		assert_eq!(Uniques::owner(&CLASS_ID, &INSTANCE_ID), Some(DutchAuction::account_id()));
+	})
+}
+
+#[test]
+fn price_decreases_down_to_the_floor() {
+	new_test_ext().execute_with(|| {
+		create_auction(Funds::Native);
+		assert_eq!(current_price(), 50);
+
+		System::set_block_number(4);
+		assert_eq!(current_price(), 35);
+
+		System::set_block_number(100);
+		assert_eq!(current_price(), 20);
+	})
+}
+
+#[test]
+fn first_buyer_at_the_current_price_wins() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RoyaltyRegistry::set_royalty(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			CHARLIE,
+			Perbill::from_percent(10)
+		));
+		create_auction(Funds::Native);
+		System::set_block_number(3);
+
+		assert_noop!(
+			DutchAuction::buy(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 30),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_ok!(DutchAuction::buy(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 40));
+
+		assert_eq!(Uniques::owner(&CLASS_ID, &INSTANCE_ID), Some(BOB));
+		assert_eq!(Balances::free_balance(BOB), 60);
+		assert_eq!(Balances::free_balance(ALICE), 136);
+		assert_eq!(Balances::free_balance(CHARLIE), 4);
+		System::assert_last_event(
+			AuctionEvent::Sold {
+				class: CLASS_ID,
+				instance: INSTANCE_ID,
+				seller: ALICE,
+				buyer: BOB,
+				price: 40,
+				royalty: 4,
+			}
+			.into(),
+		);
+		assert_noop!(
+			DutchAuction::buy(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 40),
+			Error::<Test>::NotAuctioned
+		);
+	})
+}
+
+#[test]
+fn items_can_be_paid_with_an_asset() {
+	new_test_ext().execute_with(|| {
+		create_auction(Funds::Asset(ASSET_ID));
+
+		assert_ok!(DutchAuction::buy(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 50));
+
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 950);
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 50);
+		assert_eq!(Balances::free_balance(BOB), 100);
+	})
+}
+
+#[test]
+fn seller_can_cancel() {
+	new_test_ext().execute_with(|| {
+		create_auction(Funds::Native);
+
+		assert_noop!(
+			DutchAuction::cancel_auction(Origin::signed(BOB), CLASS_ID, INSTANCE_ID),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(DutchAuction::cancel_auction(Origin::signed(ALICE), CLASS_ID, INSTANCE_ID));
+
+		assert_eq!(Uniques::owner(&CLASS_ID, &INSTANCE_ID), Some(ALICE));
+		System::assert_last_event(
+			AuctionEvent::AuctionCanceled { class: CLASS_ID, instance: INSTANCE_ID }.into(),
+		);
+	})
+}
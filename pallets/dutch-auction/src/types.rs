@@ -0,0 +1,37 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{tokens::fungibles::Inspect, Currency};
+use scale_info::TypeInfo;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type FundsOf<T> = Funds<AssetIdOf<T>>;
+pub type AuctionOf<T> =
+	Auction<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// The kind of funds an item is paid with.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum Funds<AssetId> {
+	/// The native currency.
+	Native,
+	/// A fungible asset.
+	Asset(AssetId),
+}
+
+/// A descending-price auction of an escrowed item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Auction<AccountId, AssetId, Balance, BlockNumber> {
+	/// The account selling the item.
+	pub seller: AccountId,
+	/// The kind of funds the item is paid with.
+	pub funds: Funds<AssetId>,
+	/// The price when the auction starts.
+	pub start_price: Balance,
+	/// The price the auction never goes below.
+	pub floor_price: Balance,
+	/// The amount the price decreases by every block.
+	pub decrement: Balance,
+	/// The block the auction started at.
+	pub started_at: BlockNumber,
+}
@@ -0,0 +1,41 @@
+//! Benchmarks of the explorer-counters pallet.
+//!
+//! The events of the runtime are not known to the pallet: `on_finalize` is benchmarked by reading
+//! back the holders of `n` assets, which is what grows with the events of the block, and
+//! `observe_events` by observing `e` events which change nothing.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::Get;
+
+benchmarks! {
+	where_clause { where
+		T::AssetId: From<u32>,
+	}
+
+	// `n` assets had their holders change in the block.
+	on_finalize {
+		let n in 0 .. T::MaxAssetsPerBlock::get();
+		let mut counters = Totals::<T>::get();
+	}: {
+		Pallet::<T>::refresh_holders(&mut counters, (0..n).map(Into::into));
+		Totals::<T>::put(counters);
+	}
+
+	// `e` events were deposited in the block.
+	observe_events {
+		let e in 0 .. T::MaxEventsPerBlock::get();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let sender: T::AccountId = account("sender", 0, 0);
+		for _ in 0..e {
+			frame_system::Pallet::<T>::deposit_event(frame_system::Event::<T>::Remarked {
+				sender: sender.clone(),
+				hash: Default::default(),
+			});
+		}
+	}: {
+		Pallet::<T>::count_events();
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
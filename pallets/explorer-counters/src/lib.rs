@@ -0,0 +1,330 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Aggregate counters of the chain, for block explorers.
+///
+/// The counters are kept up to date from the events of each block, at the end of the block:
+/// [`Observe`] tells what an event of the runtime changes in them, so that the pallets emitting
+/// the events, such as assets or uniques, need no hook of their own. The pallet should come last
+/// in the runtime, so that the events of the hooks of the other pallets are counted.
+///
+/// `on_initialize` reserves the weight of observing `MaxEventsPerBlock` events. A block with more
+/// events is charged for the rest once they are observed.
+///
+/// The holders of an asset are read back from the runtime whenever an event may have changed
+/// them, for at most `MaxAssetsPerBlock` assets per block: the holders of the assets past it are
+/// updated the next time they change. The transfers are counted per session, and start from zero
+/// at each new session.
+///
+/// The creation of assets and items is timestamped with the time of the block they are created
+/// in, so that explorers show their wall-clock age, until they are destroyed. The items of a class
+/// are counted, so that they go along with it when the class is destroyed.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use types::{Counters, MomentOf, Observation};
+pub use weights::WeightInfo;
+
+/// What the events of the runtime change in the counters.
+pub trait Observe<Event, AssetId, ClassId, InstanceId> {
+	/// What `event` changes in the counters, if anything.
+	fn observe(event: &Event) -> Option<Observation<AssetId, ClassId, InstanceId>>;
+
+	/// The number of accounts holding `asset`.
+	fn holders(asset: &AssetId) -> u32;
+}
+
+/// No event changes the counters.
+impl<Event, AssetId, ClassId, InstanceId> Observe<Event, AssetId, ClassId, InstanceId> for () {
+	fn observe(_event: &Event) -> Option<Observation<AssetId, ClassId, InstanceId>> {
+		None
+	}
+
+	fn holders(_asset: &AssetId) -> u32 {
+		0
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{
+		types::{Counters, MomentOf, Observation},
+		weights::WeightInfo,
+		Observe,
+	};
+	use frame_support::{pallet_prelude::*, traits::Time};
+	use frame_system::pallet_prelude::*;
+	use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The identifier of a fungible asset.
+		type AssetId: Member + Parameter + MaxEncodedLen + MaybeSerializeDeserialize + Copy + Ord;
+
+		/// The identifier of a class of nonfungible items.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The identifier of a nonfungible item within its class.
+		type InstanceId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Tells what the events of the runtime change in the counters.
+		type Observer: Observe<
+			<Self as frame_system::Config>::Event,
+			Self::AssetId,
+			Self::ClassId,
+			Self::InstanceId,
+		>;
+
+		/// The source of the creation timestamps.
+		type Time: Time;
+
+		/// The index of the current session.
+		type CurrentSession: Get<u32>;
+
+		/// The maximum number of assets whose holders are read back in a block.
+		#[pallet::constant]
+		type MaxAssetsPerBlock: Get<u32>;
+
+		/// The number of events of a block whose observation `on_initialize` reserves weight for.
+		#[pallet::constant]
+		type MaxEventsPerBlock: Get<u32>;
+
+		/// Weight information for the hooks of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The aggregate counters of the chain.
+	#[pallet::storage]
+	#[pallet::getter(fn counters)]
+	pub type Totals<T: Config> = StorageValue<_, Counters, ValueQuery>;
+
+	/// The number of accounts holding each asset, as last read back.
+	#[pallet::storage]
+	#[pallet::getter(fn holders)]
+	pub type Holders<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u32, ValueQuery>;
+
+	/// When each asset was created.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_created_at)]
+	pub type AssetCreatedAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, MomentOf<T>, OptionQuery>;
+
+	/// When each nonfungible item in existence was created, by class.
+	#[pallet::storage]
+	#[pallet::getter(fn item_created_at)]
+	pub type ItemCreatedAt<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		MomentOf<T>,
+		OptionQuery,
+	>;
+
+	/// The number of items of each class whose creation is timestamped.
+	#[pallet::storage]
+	#[pallet::getter(fn class_items)]
+	pub type ClassItems<T: Config> = StorageMap<_, Blake2_128Concat, T::ClassId, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The creation of an asset has been timestamped.
+		AssetTimestamped { asset_id: T::AssetId, created_at: MomentOf<T> },
+		/// The creation of a nonfungible item has been timestamped.
+		ItemTimestamped { class: T::ClassId, instance: T::InstanceId, created_at: MomentOf<T> },
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The assets created at genesis, which emit no event. Their creation is timestamped with
+		/// the time of genesis.
+		pub assets: Vec<T::AssetId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { assets: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			let assets: BTreeSet<_> = self.assets.iter().copied().collect();
+			for asset in &assets {
+				AssetCreatedAt::<T>::insert(asset, T::Time::now());
+			}
+			let mut counters = Counters {
+				assets_created: assets.len() as u64,
+				session: T::CurrentSession::get(),
+				..Default::default()
+			};
+			Pallet::<T>::refresh_holders(&mut counters, assets.into_iter());
+			Totals::<T>::put(counters);
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			T::WeightInfo::on_finalize(T::MaxAssetsPerBlock::get())
+				.saturating_add(T::WeightInfo::observe_events(T::MaxEventsPerBlock::get()))
+		}
+
+		fn on_finalize(_now: T::BlockNumber) {
+			Self::count_events();
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Update the counters with the events of the block.
+		pub(crate) fn count_events() {
+			let mut counters = Totals::<T>::get();
+			let session = T::CurrentSession::get();
+			if counters.session != session {
+				counters.session = session;
+				counters.session_transfers = 0;
+			}
+
+			let now = T::Time::now();
+			let mut touched = BTreeSet::new();
+			let mut writes = 0;
+			let events = frame_system::Pallet::<T>::events();
+			let observed = events.len() as u32;
+			for record in events {
+				match T::Observer::observe(&record.event) {
+					Some(Observation::AssetCreated(asset_id)) => {
+						counters.assets_created = counters.assets_created.saturating_add(1);
+						AssetCreatedAt::<T>::insert(&asset_id, now);
+						writes += 1;
+						Self::deposit_event(Event::AssetTimestamped { asset_id, created_at: now });
+					},
+					Some(Observation::AssetDestroyed(asset_id)) => {
+						AssetCreatedAt::<T>::remove(&asset_id);
+						writes += 1;
+						touched.insert(asset_id);
+					},
+					Some(Observation::Holdings(asset)) => {
+						touched.insert(asset);
+					},
+					Some(Observation::Transfer(asset)) => {
+						counters.session_transfers = counters.session_transfers.saturating_add(1);
+						touched.extend(asset);
+					},
+					Some(Observation::ItemIssued(item)) => {
+						counters.items = counters.items.saturating_add(1);
+						if let Some((class, instance)) = item {
+							ItemCreatedAt::<T>::insert(&class, &instance, now);
+							ClassItems::<T>::mutate(&class, |items| {
+								*items = items.saturating_add(1)
+							});
+							writes += 2;
+							Self::deposit_event(Event::ItemTimestamped {
+								class,
+								instance,
+								created_at: now,
+							});
+						}
+					},
+					Some(Observation::ItemBurned(item)) => {
+						counters.items = counters.items.saturating_sub(1);
+						if let Some((class, instance)) = item {
+							if ItemCreatedAt::<T>::take(&class, &instance).is_some() {
+								ClassItems::<T>::mutate_exists(&class, |items| {
+									*items = items
+										.and_then(|items| items.checked_sub(1))
+										.filter(|items| *items > 0);
+								});
+							}
+							writes += 2;
+						}
+					},
+					Some(Observation::ClassDestroyed(class)) => {
+						// The items of the class go with it, without an event of their own.
+						let items = ClassItems::<T>::take(&class);
+						counters.items = counters.items.saturating_sub(items as u64);
+						ItemCreatedAt::<T>::remove_prefix(&class, None);
+						writes += items as u64 + 1;
+					},
+					None => {},
+				}
+			}
+			// One write for each timestamp, as many as the creations the block paid for, and the
+			// observation of the events `on_initialize` did not reserve weight for.
+			let reserved = T::WeightInfo::observe_events(T::MaxEventsPerBlock::get());
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::DbWeight::get().writes(writes).saturating_add(
+					T::WeightInfo::observe_events(observed).saturating_sub(reserved),
+				),
+				DispatchClass::Mandatory,
+			);
+
+			let max = T::MaxAssetsPerBlock::get() as usize;
+			Self::refresh_holders(&mut counters, touched.into_iter().take(max));
+			Totals::<T>::put(counters);
+		}
+
+		/// Read back the holders of `assets`, and apply the change to the total of `counters`.
+		pub(crate) fn refresh_holders(
+			counters: &mut Counters,
+			assets: impl Iterator<Item = T::AssetId>,
+		) {
+			for asset in assets {
+				let holders = T::Observer::holders(&asset);
+				let previous = Holders::<T>::mutate_exists(&asset, |stored| {
+					let previous = stored.unwrap_or_default();
+					*stored = if holders == 0 { None } else { Some(holders) };
+					previous
+				});
+				counters.holders =
+					counters.holders.saturating_add(holders as u64).saturating_sub(previous as u64);
+			}
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let holders: u64 = Holders::<T>::iter_values().map(|holders| holders as u64).sum();
+			ensure!(Totals::<T>::get().holders == holders, "holders total out of sync");
+			ensure!(
+				ClassItems::<T>::iter().all(|(class, items)| {
+					ItemCreatedAt::<T>::iter_prefix(&class).count() == items as usize
+				}),
+				"class items out of sync"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,67 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 keys the creation times of the items by class, then instance, and counts the items
+/// of each class, so that they can be dropped along with their class.
+pub mod v2 {
+	use super::*;
+	use crate::{ClassItems, ItemCreatedAt, MomentOf};
+	use frame_support::{
+		storage::migration::storage_key_iter, traits::PalletInfoAccess, Blake2_128Concat,
+	};
+	use sp_std::prelude::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		// Version 1 stored them under the same name, keyed by the pair of ids.
+		let items: Vec<_> = storage_key_iter::<
+			(T::ClassId, T::InstanceId),
+			MomentOf<T>,
+			Blake2_128Concat,
+		>(Pallet::<T>::name().as_bytes(), b"ItemCreatedAt")
+		.drain()
+		.collect();
+
+		let migrated = items.len() as Weight;
+		for ((class, instance), created_at) in items {
+			ItemCreatedAt::<T>::insert(&class, &instance, created_at);
+			ClassItems::<T>::mutate(&class, |items| *items = items.saturating_add(1));
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(migrated * 2, migrated * 3 + 1)
+	}
+}
@@ -0,0 +1,149 @@
+use super::mock::*;
+use crate::Counters;
+use frame_support::{assert_ok, traits::OnFinalize, weights::DispatchClass};
+use pallet_uniques::DestroyWitness;
+use test_utils::ExtBuilder;
+
+fn create_asset(id: u32) {
+	assert_ok!(Assets::force_create(Origin::root(), id, ALICE, true, 1));
+}
+
+#[test]
+fn assets_and_their_holders_are_counted() {
+	new_test_ext().execute_with(|| {
+		create_asset(0);
+		assert_ok!(Assets::mint(Origin::signed(ALICE), 0, BOB, 10));
+		assert_ok!(Assets::mint(Origin::signed(ALICE), 0, CHARLIE, 10));
+		next_block();
+
+		let counters = ExplorerCounters::counters();
+		assert_eq!(counters.assets_created, 1);
+		assert_eq!(counters.holders, 2);
+		assert_eq!(ExplorerCounters::holders(0), 2);
+
+		assert_ok!(Assets::burn(Origin::signed(ALICE), 0, BOB, 10));
+		next_block();
+
+		assert_eq!(ExplorerCounters::counters().holders, 1);
+	});
+}
+
+#[test]
+fn transfers_are_counted_per_session() {
+	new_test_ext().execute_with(|| {
+		create_asset(0);
+		assert_ok!(Assets::mint(Origin::signed(ALICE), 0, BOB, 10));
+		assert_ok!(Assets::transfer(Origin::signed(BOB), 0, CHARLIE, 5));
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), BOB, 10));
+		next_block();
+
+		let counters = ExplorerCounters::counters();
+		assert_eq!((counters.session, counters.session_transfers), (0, 2));
+		assert_eq!(counters.holders, 2);
+
+		CurrentSession::set(1);
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), BOB, 10));
+		next_block();
+
+		let counters = ExplorerCounters::counters();
+		assert_eq!((counters.session, counters.session_transfers), (1, 1));
+	});
+}
+
+#[test]
+fn items_are_counted() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), CLASS_ID, ALICE, true));
+		assert_ok!(Uniques::mint(Origin::signed(ALICE), CLASS_ID, 0, BOB));
+		assert_ok!(Uniques::mint(Origin::signed(ALICE), CLASS_ID, 1, BOB));
+		next_block();
+		assert_eq!(ExplorerCounters::counters().items, 2);
+
+		assert_ok!(Uniques::burn(Origin::signed(BOB), CLASS_ID, 0, None));
+		assert_ok!(Uniques::transfer(Origin::signed(BOB), CLASS_ID, 1, CHARLIE));
+		next_block();
+
+		let counters = ExplorerCounters::counters();
+		assert_eq!(counters.items, 1);
+		assert_eq!(counters.session_transfers, 1);
+	});
+}
+
+#[test]
+fn destroyed_classes_take_their_items_along() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), CLASS_ID, ALICE, true));
+		assert_ok!(Uniques::force_create(Origin::root(), CLASS_ID + 1, ALICE, true));
+		for instance in 0..2 {
+			assert_ok!(Uniques::mint(Origin::signed(ALICE), CLASS_ID, instance, BOB));
+		}
+		assert_ok!(Uniques::mint(Origin::signed(ALICE), CLASS_ID + 1, 0, BOB));
+		next_block();
+		assert_eq!(ExplorerCounters::counters().items, 3);
+		assert_eq!(ExplorerCounters::class_items(CLASS_ID), 2);
+
+		let witness = DestroyWitness { instances: 2, instance_metadatas: 0, attributes: 0 };
+		assert_ok!(Uniques::destroy(Origin::signed(ALICE), CLASS_ID, witness));
+		next_block();
+
+		assert_eq!(ExplorerCounters::counters().items, 1);
+		assert_eq!(ExplorerCounters::class_items(CLASS_ID), 0);
+		assert_eq!(ExplorerCounters::item_created_at(CLASS_ID, 0), None);
+		assert!(ExplorerCounters::item_created_at(CLASS_ID + 1, 0).is_some());
+	});
+}
+
+#[test]
+fn events_past_max_events_per_block_are_weighed() {
+	new_test_ext().execute_with(|| {
+		let mandatory = || *System::block_weight().get(DispatchClass::Mandatory);
+
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), BOB, 10));
+		let before = mandatory();
+		ExplorerCounters::on_finalize(System::block_number());
+		assert_eq!(mandatory(), before);
+
+		System::reset_events();
+		for _ in 0..=MaxEventsPerBlock::get() {
+			assert_ok!(Balances::transfer(Origin::signed(ALICE), BOB, 1));
+		}
+		let before = mandatory();
+		ExplorerCounters::on_finalize(System::block_number());
+		assert!(mandatory() > before);
+	});
+}
+
+#[test]
+fn holders_are_read_back_for_at_most_max_assets_per_block() {
+	new_test_ext().execute_with(|| {
+		for id in 0..3 {
+			create_asset(id);
+			assert_ok!(Assets::mint(Origin::signed(ALICE), id, BOB, 10));
+		}
+		next_block();
+
+		let counters = ExplorerCounters::counters();
+		assert_eq!(counters.assets_created, 3);
+		assert_eq!(counters.holders, 2);
+		assert_eq!(ExplorerCounters::holders(2), 0);
+
+		// The third asset is read back the next time its holders change.
+		assert_ok!(Assets::mint(Origin::signed(ALICE), 2, CHARLIE, 10));
+		next_block();
+
+		assert_eq!(ExplorerCounters::counters().holders, 4);
+	});
+}
+
+#[test]
+fn genesis_assets_are_counted() {
+	ExtBuilder::<Test>::default()
+		.genesis(crate::GenesisConfig::<Test> { assets: vec![0, 1, 1] })
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				ExplorerCounters::counters(),
+				Counters { assets_created: 2, ..Default::default() }
+			);
+		});
+}
@@ -0,0 +1,39 @@
+use super::mock::*;
+use crate::migrations;
+use frame_support::{
+	storage::migration::put_storage_value,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	Hashable,
+};
+
+#[test]
+fn runtime_upgrade_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<ExplorerCounters>();
+
+		<ExplorerCounters as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(
+			ExplorerCounters::on_chain_storage_version(),
+			ExplorerCounters::current_storage_version()
+		);
+	})
+}
+
+#[test]
+fn migrate_keys_the_item_timestamps_by_class() {
+	new_test_ext().execute_with(|| {
+		// Version 1 keyed the creation times by the pair of ids.
+		for instance in 0..2u32 {
+			let key = (CLASS_ID, instance).blake2_128_concat();
+			put_storage_value(b"ExplorerCounters", b"ItemCreatedAt", &key, 6_000u64);
+		}
+		StorageVersion::new(1).put::<ExplorerCounters>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(ExplorerCounters::item_created_at(CLASS_ID, 1), Some(6_000));
+		assert_eq!(ExplorerCounters::class_items(CLASS_ID), 2);
+		assert_eq!(ExplorerCounters::on_chain_storage_version(), 2);
+	})
+}
@@ -0,0 +1,217 @@
+use crate::{self as pallet_explorer_counters, Observation, Observe};
+use frame_support::{
+	parameter_types,
+	traits::{OnFinalize, OnInitialize},
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use test_utils::ExtBuilder;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+
+pub const CLASS_ID: u32 = 0;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
+		ExplorerCounters: pallet_explorer_counters::{Pallet, Storage, Config<T>, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type AccountData = pallet_balances::AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: u64 = 0;
+	pub const ApprovalDeposit: u64 = 0;
+	pub const MetadataDepositBase: u64 = 0;
+	pub const MetadataDepositPerByte: u64 = 0;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ApprovalDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = u32;
+	type Balance = u64;
+	type Currency = Balances;
+	type Event = Event;
+	type Extra = ();
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Freezer = ();
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type MinimumPeriod = MinimumPeriod;
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ClassDeposit: u64 = 0;
+	pub const InstanceDeposit: u64 = 0;
+	pub const AttributeDepositBase: u64 = 0;
+	pub const DepositPerByte: u64 = 0;
+	pub const KeyLimit: u32 = 32;
+	pub const ValueLimit: u32 = 64;
+}
+
+impl pallet_uniques::Config for Test {
+	type AttributeDepositBase = AttributeDepositBase;
+	type ClassDeposit = ClassDeposit;
+	type ClassId = u32;
+	type Currency = Balances;
+	type DepositPerByte = DepositPerByte;
+	type Event = Event;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type InstanceDeposit = InstanceDeposit;
+	type InstanceId = u32;
+	type KeyLimit = KeyLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type StringLimit = StringLimit;
+	type ValueLimit = ValueLimit;
+	type WeightInfo = ();
+}
+
+/// Count the events of the assets, uniques and balances pallets, and the holders of an asset
+/// among the test accounts.
+pub struct TestObserver;
+
+impl Observe<Event, u32, u32, u32> for TestObserver {
+	fn observe(event: &Event) -> Option<Observation<u32, u32, u32>> {
+		match event {
+			Event::Assets(
+				pallet_assets::Event::Created { asset_id, .. } |
+				pallet_assets::Event::ForceCreated { asset_id, .. },
+			) => Some(Observation::AssetCreated(*asset_id)),
+			Event::Assets(
+				pallet_assets::Event::Issued { asset_id, .. } |
+				pallet_assets::Event::Burned { asset_id, .. },
+			) => Some(Observation::Holdings(*asset_id)),
+			Event::Assets(pallet_assets::Event::Destroyed { asset_id }) =>
+				Some(Observation::AssetDestroyed(*asset_id)),
+			Event::Assets(pallet_assets::Event::Transferred { asset_id, .. }) =>
+				Some(Observation::Transfer(Some(*asset_id))),
+			Event::Uniques(pallet_uniques::Event::Issued { class, instance, .. }) =>
+				Some(Observation::ItemIssued(Some((*class, *instance)))),
+			Event::Uniques(pallet_uniques::Event::Burned { class, instance, .. }) =>
+				Some(Observation::ItemBurned(Some((*class, *instance)))),
+			Event::Uniques(pallet_uniques::Event::Destroyed { class }) =>
+				Some(Observation::ClassDestroyed(*class)),
+			Event::Uniques(pallet_uniques::Event::Transferred { .. }) |
+			Event::Balances(pallet_balances::Event::Transfer { .. }) =>
+				Some(Observation::Transfer(None)),
+			_ => None,
+		}
+	}
+
+	fn holders(asset: &u32) -> u32 {
+		[ALICE, BOB, CHARLIE].iter().filter(|who| Assets::balance(*asset, **who) > 0).count() as u32
+	}
+}
+
+parameter_types! {
+	pub static CurrentSession: u32 = 0;
+	pub const MaxAssetsPerBlock: u32 = 2;
+	pub const MaxEventsPerBlock: u32 = 10;
+}
+
+impl pallet_explorer_counters::Config for Test {
+	type AssetId = u32;
+	type ClassId = u32;
+	type CurrentSession = CurrentSession;
+	type Event = Event;
+	type InstanceId = u32;
+	type MaxAssetsPerBlock = MaxAssetsPerBlock;
+	type MaxEventsPerBlock = MaxEventsPerBlock;
+	type Observer = TestObserver;
+	type Time = Timestamp;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Test>::default()
+		.balances(vec![(ALICE, 100), (BOB, 100), (CHARLIE, 100)])
+		.build()
+}
+
+/// Finalize the current block, then start the next one, with no events, as the executive does.
+pub fn next_block() {
+	let now = System::block_number();
+	ExplorerCounters::on_finalize(now);
+	System::reset_events();
+	System::set_block_number(now + 1);
+	ExplorerCounters::on_initialize(now + 1);
+}
@@ -0,0 +1,5 @@
+pub mod mock;
+
+mod counters;
+mod migrations;
+mod timestamps;
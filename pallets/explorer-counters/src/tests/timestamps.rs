@@ -0,0 +1,68 @@
+use super::mock::*;
+use crate::Event as ExplorerEvent;
+use frame_support::{
+	assert_ok,
+	traits::{tokens::fungibles::Destroy, OnFinalize},
+};
+use test_utils::ExtBuilder;
+
+#[test]
+fn asset_creation_is_timestamped() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(6_000);
+		assert_ok!(Assets::force_create(Origin::root(), 0, ALICE, true, 1));
+		ExplorerCounters::on_finalize(System::block_number());
+
+		System::assert_last_event(
+			ExplorerEvent::AssetTimestamped { asset_id: 0, created_at: 6_000 }.into(),
+		);
+		assert_eq!(ExplorerCounters::asset_created_at(0), Some(6_000));
+		assert_eq!(ExplorerCounters::asset_created_at(1), None);
+	});
+}
+
+#[test]
+fn destroyed_assets_take_their_timestamp_and_holders_along() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(Assets::mint(Origin::signed(ALICE), 0, BOB, 10));
+		next_block();
+		assert!(ExplorerCounters::asset_created_at(0).is_some());
+		assert_eq!(ExplorerCounters::counters().holders, 1);
+
+		let witness = Assets::get_destroy_witness(&0).unwrap();
+		assert_ok!(Assets::destroy(Origin::signed(ALICE), 0, witness));
+		next_block();
+
+		assert_eq!(ExplorerCounters::asset_created_at(0), None);
+		assert_eq!(ExplorerCounters::holders(0), 0);
+		assert_eq!(ExplorerCounters::counters().holders, 0);
+	});
+}
+
+#[test]
+fn item_creation_is_timestamped_until_burnt() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(6_000);
+		assert_ok!(Uniques::force_create(Origin::root(), CLASS_ID, ALICE, true));
+		assert_ok!(Uniques::mint(Origin::signed(ALICE), CLASS_ID, 0, BOB));
+		next_block();
+
+		assert_eq!(ExplorerCounters::item_created_at(CLASS_ID, 0), Some(6_000));
+
+		assert_ok!(Uniques::burn(Origin::signed(BOB), CLASS_ID, 0, None));
+		next_block();
+
+		assert_eq!(ExplorerCounters::item_created_at(CLASS_ID, 0), None);
+	});
+}
+
+#[test]
+fn genesis_assets_are_timestamped_at_genesis() {
+	ExtBuilder::<Test>::default()
+		.genesis(crate::GenesisConfig::<Test> { assets: vec![0] })
+		.build()
+		.execute_with(|| {
+			assert_eq!(ExplorerCounters::asset_created_at(0), Some(0));
+		});
+}
@@ -0,0 +1,40 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Time;
+use scale_info::TypeInfo;
+
+pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+/// The aggregate counters of the chain.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Counters {
+	/// The number of assets created since genesis.
+	pub assets_created: u64,
+	/// The number of nonfungible items in existence.
+	pub items: u64,
+	/// The number of accounts holding each asset, summed over the assets.
+	pub holders: u64,
+	/// The index of the session the transfers are counted in.
+	pub session: u32,
+	/// The number of transfers of the session.
+	pub session_transfers: u64,
+}
+
+/// What an event of the runtime changes in the counters.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Observation<AssetId, ClassId, InstanceId> {
+	/// The asset was created.
+	AssetCreated(AssetId),
+	/// The asset was destroyed, and its creation timestamp and holders with it.
+	AssetDestroyed(AssetId),
+	/// The accounts holding the asset may have changed, e.g. it was minted or burnt.
+	Holdings(AssetId),
+	/// A transfer, of the asset if it is a fungible one, whose holders may have changed.
+	Transfer(Option<AssetId>),
+	/// A nonfungible item was minted, whose creation is timestamped if it has an id.
+	ItemIssued(Option<(ClassId, InstanceId)>),
+	/// A nonfungible item was burnt, and its creation timestamp with it if it has an id.
+	ItemBurned(Option<(ClassId, InstanceId)>),
+	/// A class was destroyed, and the items left in it with it.
+	ClassDestroyed(ClassId),
+}
@@ -0,0 +1,55 @@
+//! Weights for pallet_explorer_counters
+//!
+//! Estimated from the storage accesses of each hook, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_explorer_counters --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/explorer-counters/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_explorer_counters.
+pub trait WeightInfo {
+	fn on_finalize(n: u32) -> Weight;
+	fn observe_events(e: u32) -> Weight;
+}
+
+/// Weights for pallet_explorer_counters using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn on_finalize(n: u32) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((8_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn observe_events(e: u32) -> Weight {
+		(2_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn on_finalize(n: u32) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((8_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn observe_events(e: u32) -> Weight {
+		(2_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
+}
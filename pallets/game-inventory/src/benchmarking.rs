@@ -0,0 +1,119 @@
+//! Benchmarks of the game-inventory pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	traits::{
+		tokens::{fungibles, nonfungibles},
+		Currency, Get,
+	},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+use sp_std::prelude::*;
+
+const FIRST_ASSET_ID: u32 = 1_000;
+const CLASS_ID: u32 = 1_000;
+const AMOUNT: u32 = 1_000;
+
+/// Create `n` resources, funding `player` with each, and the class of crafted items.
+fn setup<T: Config>(n: u32, player: &T::AccountId) -> Vec<AssetIdOf<T>>
+where
+	T: pallet_balances::Config,
+	T::Resources: fungibles::Create<T::AccountId>,
+	T::Items: nonfungibles::Create<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+	T::ClassId: From<u32>,
+{
+	let game: T::AccountId = account("game", 0, 0);
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		&game,
+		T::ExistentialDeposit::get() * 1_000_000u32.into(),
+	);
+	<T::Items as nonfungibles::Create<_>>::create_class(&CLASS_ID.into(), &game, &game).unwrap();
+	resource_ids::<T>(n)
+		.into_iter()
+		.map(|id| {
+			<T::Resources as fungibles::Create<_>>::create(id, game.clone(), true, One::one())
+				.unwrap();
+			<T::Resources as fungibles::Mutate<_>>::mint_into(id, player, AMOUNT.into()).unwrap();
+			id
+		})
+		.collect()
+}
+
+/// The ids of the first `n` resources, whether they exist or not.
+fn resource_ids<T: Config>(n: u32) -> Vec<AssetIdOf<T>>
+where
+	AssetIdOf<T>: From<u32>,
+{
+	(FIRST_ASSET_ID..FIRST_ASSET_ID + n).map(Into::into).collect()
+}
+
+fn ingredients<T: Config>(
+	resources: Vec<AssetIdOf<T>>,
+) -> BoundedVec<(AssetIdOf<T>, BalanceOf<T>), T::MaxIngredients> {
+	let ingredients: Vec<_> = resources.into_iter().map(|id| (id, One::one())).collect();
+	ingredients.try_into().unwrap()
+}
+
+/// Add a recipe burning one unit of each of `resources` as recipe 0.
+fn insert_recipe<T: Config>(resources: Vec<AssetIdOf<T>>)
+where
+	T::ClassId: From<u32>,
+{
+	Recipes::<T>::insert(
+		0,
+		Recipe { ingredients: ingredients::<T>(resources), output: CLASS_ID.into() },
+	);
+}
+
+benchmarks! {
+	where_clause { where
+		T: pallet_balances::Config,
+		T::Resources: fungibles::Create<T::AccountId>,
+		T::Items: nonfungibles::Create<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+		T::ClassId: From<u32>,
+	}
+
+	// Registering the last kind, the others being scanned for duplicates.
+	register_resource {
+		let k in 0 .. T::MaxResourceKinds::get() - 1;
+		ResourceKinds::<T>::put(BoundedVec::try_from(resource_ids::<T>(k)).unwrap());
+		let asset_id: AssetIdOf<T> = (FIRST_ASSET_ID + k).into();
+		let origin = T::RecipeOrigin::successful_origin();
+	}: _<T::Origin>(origin, asset_id)
+	verify {
+		assert!(Pallet::<T>::resource_kinds().contains(&asset_id));
+	}
+
+	add_recipe {
+		let i in 1 .. T::MaxIngredients::get();
+		let ingredients = ingredients::<T>(resource_ids::<T>(i));
+		let origin = T::RecipeOrigin::successful_origin();
+	}: _<T::Origin>(origin, ingredients, CLASS_ID.into())
+	verify {
+		assert!(Pallet::<T>::recipe(0).is_some());
+	}
+
+	remove_recipe {
+		insert_recipe::<T>(resource_ids::<T>(T::MaxIngredients::get()));
+		let origin = T::RecipeOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert!(Pallet::<T>::recipe(0).is_none());
+	}
+
+	craft {
+		let i in 1 .. T::MaxIngredients::get();
+		let player: T::AccountId = whitelisted_caller();
+		insert_recipe::<T>(setup::<T>(i, &player));
+	}: _(RawOrigin::Signed(player.clone()), 0)
+	verify {
+		assert_eq!(Pallet::<T>::inventory(&player).items.len(), 1);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
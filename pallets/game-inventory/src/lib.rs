@@ -0,0 +1,272 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A game inventory over fungible resources and nonfungible items.
+///
+/// Resources are fungible assets, items are nonfungible instances; the pallet only relies on the
+/// `fungibles` and `nonfungibles` traits, so both pallets are plugged in through the runtime
+/// configuration. The recipe origin registers the resource kinds that make up an inventory, and
+/// the recipes turning them into items: crafting burns the ingredients from the player and mints
+/// a new item of the recipe's class to them.
+///
+/// `inventory` gathers what a player holds in both pallets into a single view.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::tokens::{fungibles, nonfungibles},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Zero;
+	use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to register resources and manage recipes.
+		type RecipeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The fungible assets resources are.
+		type Resources: fungibles::Mutate<Self::AccountId>;
+
+		/// Identifier of an item class.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its class.
+		type InstanceId: Member + Parameter + MaxEncodedLen + Copy + From<u32>;
+
+		/// The nonfungible items crafted.
+		///
+		/// Crafted classes should be dedicated to this pallet, since it picks instance ids on its
+		/// own.
+		type Items: nonfungibles::Mutate<
+				Self::AccountId,
+				ClassId = Self::ClassId,
+				InstanceId = Self::InstanceId,
+			> + nonfungibles::InspectEnumerable<
+				Self::AccountId,
+				ClassId = Self::ClassId,
+				InstanceId = Self::InstanceId,
+			>;
+
+		/// The maximum number of resource kinds an inventory is made of.
+		#[pallet::constant]
+		type MaxResourceKinds: Get<u32>;
+
+		/// The maximum number of ingredients of a recipe.
+		#[pallet::constant]
+		type MaxIngredients: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The resource kinds listed in inventories.
+	#[pallet::storage]
+	#[pallet::getter(fn resource_kinds)]
+	pub type ResourceKinds<T: Config> =
+		StorageValue<_, BoundedVec<AssetIdOf<T>, T::MaxResourceKinds>, ValueQuery>;
+
+	/// The id the next recipe will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_recipe_id)]
+	pub type NextRecipeId<T> = StorageValue<_, RecipeId, ValueQuery>;
+
+	/// The recipes players can craft.
+	#[pallet::storage]
+	#[pallet::getter(fn recipe)]
+	pub type Recipes<T: Config> = StorageMap<_, Twox64Concat, RecipeId, RecipeOf<T>>;
+
+	/// The instance id the next crafted item will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_instance_id)]
+	pub type NextInstanceId<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A resource kind is now listed in inventories.
+		ResourceRegistered { asset_id: AssetIdOf<T> },
+		/// A recipe has been added.
+		RecipeAdded { recipe_id: RecipeId, output: T::ClassId },
+		/// A recipe has been removed.
+		RecipeRemoved { recipe_id: RecipeId },
+		/// A player crafted an item.
+		Crafted {
+			recipe_id: RecipeId,
+			player: T::AccountId,
+			class: T::ClassId,
+			instance: T::InstanceId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The resource kind is already registered.
+		AlreadyRegistered,
+		/// Too many resource kinds are registered.
+		TooManyResourceKinds,
+		/// A recipe needs at least one ingredient, each in a non zero amount.
+		InvalidIngredients,
+		/// The recipe does not exist.
+		UnknownRecipe,
+		/// There is no id left.
+		NoAvailableId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List a resource kind in inventories.
+		#[pallet::weight(T::WeightInfo::register_resource(T::MaxResourceKinds::get()))]
+		pub fn register_resource(origin: OriginFor<T>, asset_id: AssetIdOf<T>) -> DispatchResult {
+			T::RecipeOrigin::ensure_origin(origin)?;
+
+			ResourceKinds::<T>::try_mutate(|kinds| -> DispatchResult {
+				ensure!(!kinds.contains(&asset_id), Error::<T>::AlreadyRegistered);
+				kinds.try_push(asset_id).map_err(|_| Error::<T>::TooManyResourceKinds)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ResourceRegistered { asset_id });
+
+			Ok(())
+		}
+
+		/// Add a recipe burning `ingredients` to craft an item of the `output` class.
+		#[pallet::weight(T::WeightInfo::add_recipe(ingredients.len() as u32))]
+		pub fn add_recipe(
+			origin: OriginFor<T>,
+			ingredients: BoundedVec<(AssetIdOf<T>, BalanceOf<T>), T::MaxIngredients>,
+			output: T::ClassId,
+		) -> DispatchResult {
+			T::RecipeOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!ingredients.is_empty() && ingredients.iter().all(|(_, amount)| !amount.is_zero()),
+				Error::<T>::InvalidIngredients
+			);
+			let recipe_id = Self::next_recipe_id();
+			let next_id = recipe_id.checked_add(1).ok_or(Error::<T>::NoAvailableId)?;
+
+			Recipes::<T>::insert(recipe_id, Recipe { ingredients, output });
+			NextRecipeId::<T>::put(next_id);
+
+			Self::deposit_event(Event::RecipeAdded { recipe_id, output });
+
+			Ok(())
+		}
+
+		/// Remove a recipe.
+		#[pallet::weight(T::WeightInfo::remove_recipe())]
+		pub fn remove_recipe(origin: OriginFor<T>, recipe_id: RecipeId) -> DispatchResult {
+			T::RecipeOrigin::ensure_origin(origin)?;
+
+			ensure!(Recipes::<T>::contains_key(recipe_id), Error::<T>::UnknownRecipe);
+			Recipes::<T>::remove(recipe_id);
+
+			Self::deposit_event(Event::RecipeRemoved { recipe_id });
+
+			Ok(())
+		}
+
+		/// Burn the ingredients of a recipe to craft its item.
+		///
+		/// Refunds the weight of the ingredients the recipe was assumed to burn.
+		#[pallet::weight(T::WeightInfo::craft(T::MaxIngredients::get()))]
+		#[transactional]
+		pub fn craft(origin: OriginFor<T>, recipe_id: RecipeId) -> DispatchResultWithPostInfo {
+			let player = ensure_signed(origin)?;
+
+			let recipe = Self::recipe(recipe_id).ok_or(Error::<T>::UnknownRecipe)?;
+			let ingredients = recipe.ingredients.len() as u32;
+			for (asset_id, amount) in recipe.ingredients {
+				T::Resources::burn_from(asset_id, &player, amount)?;
+			}
+
+			let id = Self::next_instance_id();
+			let next_id = id.checked_add(1).ok_or(Error::<T>::NoAvailableId)?;
+			let class = recipe.output;
+			let instance = T::InstanceId::from(id);
+			T::Items::mint_into(&class, &instance, &player)?;
+			NextInstanceId::<T>::put(next_id);
+
+			Self::deposit_event(Event::Crafted { recipe_id, player, class, instance });
+
+			Ok(Some(T::WeightInfo::craft(ingredients)).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// What `who` holds of the registered resources, and all their items.
+		pub fn inventory(who: &T::AccountId) -> InventoryOf<T> {
+			let resources = Self::resource_kinds()
+				.into_iter()
+				.map(|asset_id| {
+					(asset_id, <T::Resources as fungibles::Inspect<_>>::balance(asset_id, who))
+				})
+				.filter(|(_, balance)| !balance.is_zero())
+				.collect();
+			let items = <T::Items as nonfungibles::InspectEnumerable<_>>::owned(who).collect();
+
+			Inventory { resources, items }
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let kinds = Self::resource_kinds();
+			ensure!(
+				kinds.iter().enumerate().all(|(i, kind)| !kinds[..i].contains(kind)),
+				"resource kind registered twice"
+			);
+			let next_id = Self::next_recipe_id();
+			for (recipe_id, recipe) in Recipes::<T>::iter() {
+				ensure!(recipe_id < next_id, "recipe id not allocated");
+				ensure!(
+					!recipe.ingredients.is_empty() &&
+						recipe.ingredients.iter().all(|(_, amount)| !amount.is_zero()),
+					"recipe without ingredients"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
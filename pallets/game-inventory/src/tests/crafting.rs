@@ -0,0 +1,111 @@
+use super::mock::*;
+use crate::{Error, Event as InventoryEvent, Inventory, WeightInfo};
+use frame_support::{assert_noop, assert_ok, traits::tokens::nonfungibles::Inspect};
+
+fn add_sword_recipe() {
+	assert_ok!(GameInventory::add_recipe(
+		Origin::root(),
+		vec![(WOOD, 10), (IRON, 30)].try_into().unwrap(),
+		SWORDS
+	));
+}
+
+#[test]
+fn only_the_recipe_origin_manages_recipes() {
+	new_test_ext().execute_with(|| {
+		assert!(GameInventory::add_recipe(
+			Origin::signed(ALICE),
+			vec![(WOOD, 10)].try_into().unwrap(),
+			SWORDS
+		)
+		.is_err());
+		assert_noop!(
+			GameInventory::add_recipe(Origin::root(), vec![].try_into().unwrap(), SWORDS),
+			Error::<Test>::InvalidIngredients
+		);
+		assert_noop!(
+			GameInventory::add_recipe(Origin::root(), vec![(WOOD, 0)].try_into().unwrap(), SWORDS),
+			Error::<Test>::InvalidIngredients
+		);
+		add_sword_recipe();
+		System::assert_last_event(
+			InventoryEvent::RecipeAdded { recipe_id: 0, output: SWORDS }.into(),
+		);
+
+		assert!(GameInventory::remove_recipe(Origin::signed(ALICE), 0).is_err());
+		assert_ok!(GameInventory::remove_recipe(Origin::root(), 0));
+		assert_noop!(
+			GameInventory::remove_recipe(Origin::root(), 0),
+			Error::<Test>::UnknownRecipe
+		);
+	})
+}
+
+#[test]
+fn crafting_burns_resources_and_mints_an_item() {
+	new_test_ext().execute_with(|| {
+		add_sword_recipe();
+
+		assert_noop!(GameInventory::craft(Origin::signed(ALICE), 1), Error::<Test>::UnknownRecipe);
+		assert_ok!(GameInventory::craft(Origin::signed(ALICE), 0));
+
+		assert_eq!(Assets::balance(WOOD, ALICE), 90);
+		assert_eq!(Assets::balance(IRON, ALICE), 70);
+		assert_eq!(Uniques::owner(&SWORDS, &0), Some(ALICE));
+		System::assert_last_event(
+			InventoryEvent::Crafted { recipe_id: 0, player: ALICE, class: SWORDS, instance: 0 }
+				.into(),
+		);
+
+		// Nothing is burned when a single ingredient is missing.
+		assert_ok!(GameInventory::craft(Origin::signed(ALICE), 0));
+		assert_ok!(GameInventory::craft(Origin::signed(ALICE), 0));
+		assert!(GameInventory::craft(Origin::signed(ALICE), 0).is_err());
+		assert_eq!(Assets::balance(WOOD, ALICE), 70);
+		assert_eq!(Assets::balance(IRON, ALICE), 10);
+	})
+}
+
+#[test]
+fn crafting_refunds_the_weight_of_missing_ingredients() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(GameInventory::add_recipe(
+			Origin::root(),
+			vec![(WOOD, 10)].try_into().unwrap(),
+			SWORDS
+		));
+
+		let post_info = GameInventory::craft(Origin::signed(ALICE), 0).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::craft(1)));
+	})
+}
+
+#[test]
+fn inventory_lists_resources_and_items() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			GameInventory::inventory(&ALICE),
+			Inventory { resources: vec![], items: vec![] }
+		);
+
+		assert_ok!(GameInventory::register_resource(Origin::root(), WOOD));
+		assert_noop!(
+			GameInventory::register_resource(Origin::root(), WOOD),
+			Error::<Test>::AlreadyRegistered
+		);
+		assert_ok!(GameInventory::register_resource(Origin::root(), IRON));
+		assert_noop!(
+			GameInventory::register_resource(Origin::root(), 2),
+			Error::<Test>::TooManyResourceKinds
+		);
+
+		add_sword_recipe();
+		assert_ok!(GameInventory::craft(Origin::signed(ALICE), 0));
+
+		assert_eq!(
+			GameInventory::inventory(&ALICE),
+			Inventory { resources: vec![(WOOD, 90), (IRON, 70)], items: vec![(SWORDS, 0)] }
+		);
+		assert_eq!(GameInventory::inventory(&BOB), Inventory { resources: vec![], items: vec![] });
+	})
+}
@@ -0,0 +1,56 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{tokens::fungibles::Inspect, Get},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+pub type RecipeId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Resources as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Resources as Inspect<AccountIdOf<T>>>::Balance;
+pub type RecipeOf<T> = Recipe<
+	AssetIdOf<T>,
+	BalanceOf<T>,
+	<T as Config>::ClassId,
+	<T as Config>::MaxIngredients,
+>;
+pub type InventoryOf<T> =
+	Inventory<AssetIdOf<T>, BalanceOf<T>, <T as Config>::ClassId, <T as Config>::InstanceId>;
+
+/// A way to turn resources into an item.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(AssetId: MaxEncodedLen, Balance: MaxEncodedLen, ClassId: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxIngredients))]
+pub struct Recipe<AssetId, Balance, ClassId, MaxIngredients>
+where
+	AssetId: Clone + Eq + core::fmt::Debug,
+	Balance: Clone + Eq + core::fmt::Debug,
+	ClassId: Clone + Eq + core::fmt::Debug,
+	MaxIngredients: Get<u32>,
+{
+	/// The resources burned by crafting, and how much of each.
+	pub ingredients: BoundedVec<(AssetId, Balance), MaxIngredients>,
+	/// The class of the crafted item.
+	pub output: ClassId,
+}
+
+/// Everything a player holds: their resources of the registered kinds, and their items.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct Inventory<AssetId, Balance, ClassId, InstanceId> {
+	/// The non zero balances of registered resources.
+	pub resources: Vec<(AssetId, Balance)>,
+	pub items: Vec<(ClassId, InstanceId)>,
+}
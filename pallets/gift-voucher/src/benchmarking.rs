@@ -0,0 +1,246 @@
+//! Benchmarks of the gift-voucher pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	sp_std::{prelude::*, vec},
+	traits::{
+		tokens::fungibles::{Create, Mutate},
+		Currency, Get, Hooks,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use rusty_node_support::OnUnlock;
+use sp_core::{crypto::KeyTypeId, sr25519};
+use sp_runtime::{
+	traits::{Hash, IdentifyAccount, One},
+	MultiSignature, MultiSigner,
+};
+
+const ASSET_ID: u32 = 1_000;
+const AMOUNT: u32 = 1_000_000;
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"gvch");
+
+/// The signatures the benchmarks can make, to redeem key-locked vouchers.
+pub trait BenchmarkSignature<Public>: Sized {
+	/// A new voucher key.
+	fn generate_key() -> Public;
+
+	/// Sign `message` with `key`.
+	fn sign(key: &Public, message: &[u8]) -> Self;
+}
+
+impl BenchmarkSignature<MultiSigner> for MultiSignature {
+	fn generate_key() -> MultiSigner {
+		sp_io::crypto::sr25519_generate(KEY_TYPE, None).into()
+	}
+
+	fn sign(key: &MultiSigner, message: &[u8]) -> Self {
+		let key = match key {
+			MultiSigner::Sr25519(key) => key,
+			_ => unreachable!("benchmark keys are sr25519"),
+		};
+		let signature: sr25519::Signature =
+			sp_io::crypto::sr25519_sign(KEY_TYPE, key, message).expect("the key was generated");
+		signature.into()
+	}
+}
+
+#[cfg(test)]
+impl BenchmarkSignature<sp_runtime::testing::UintAuthorityId>
+	for sp_runtime::testing::TestSignature
+{
+	fn generate_key() -> sp_runtime::testing::UintAuthorityId {
+		sp_runtime::testing::UintAuthorityId(100)
+	}
+
+	fn sign(key: &sp_runtime::testing::UintAuthorityId, message: &[u8]) -> Self {
+		Self(key.0, message.to_vec())
+	}
+}
+
+/// The longest code a voucher can be locked with.
+fn code<T: Config>() -> BoundedVec<u8, T::MaxCodeLength> {
+	vec![0; T::MaxCodeLength::get() as usize].try_into().unwrap()
+}
+
+/// Fund `issuer` and have it issue a voucher expiring at block `expiry`.
+fn new_voucher<T: Config>(
+	issuer: &T::AccountId,
+	lock: VoucherLockOf<T>,
+	expiry: T::BlockNumber,
+) -> VoucherId
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	T::Assets::create(ASSET_ID.into(), issuer.clone(), true, One::one()).unwrap();
+	T::Assets::mint_into(ASSET_ID.into(), issuer, AMOUNT.into()).unwrap();
+	let voucher_id = Pallet::<T>::next_voucher_id();
+	Pallet::<T>::issue(
+		RawOrigin::Signed(issuer.clone()).into(),
+		ASSET_ID.into(),
+		AMOUNT.into(),
+		lock,
+		expiry,
+	)
+	.unwrap();
+	voucher_id
+}
+
+fn code_lock<T: Config>() -> VoucherLockOf<T> {
+	VoucherLock::Code(T::Hashing::hash(&code::<T>()[..]))
+}
+
+fn expiry<T: Config>() -> T::BlockNumber {
+	frame_system::Pallet::<T>::block_number() + 100u32.into()
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+		T::OffchainSignature: BenchmarkSignature<T::OffchainPublic>,
+		T::OffchainPublic: Clone,
+	}
+
+	issue {
+		let issuer: T::AccountId = whitelisted_caller();
+		T::Assets::create(ASSET_ID.into(), issuer.clone(), true, One::one())?;
+		T::Assets::mint_into(ASSET_ID.into(), &issuer, AMOUNT.into())?;
+	}: _(RawOrigin::Signed(issuer), ASSET_ID.into(), AMOUNT.into(), code_lock::<T>(), expiry::<T>())
+	verify {
+		assert!(Pallet::<T>::voucher(0).is_some());
+	}
+
+	commit_redemption {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, T::CommitmentDeposit::get() * 10u32.into());
+		let commitment = T::Hashing::hash_of(&(0 as VoucherId, code::<T>(), &caller));
+	}: _(RawOrigin::Signed(caller), commitment)
+	verify {
+		assert!(Pallet::<T>::commitment(commitment).is_some());
+	}
+
+	redeem_with_code {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		let beneficiary: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&beneficiary, T::CommitmentDeposit::get() * 10u32.into());
+		let voucher_id = new_voucher::<T>(&issuer, code_lock::<T>(), expiry::<T>());
+		let commitment = T::Hashing::hash_of(&(voucher_id, code::<T>(), &beneficiary));
+		Pallet::<T>::commit_redemption(RawOrigin::Signed(beneficiary.clone()).into(), commitment)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + One::one(),
+		);
+	}: _(RawOrigin::Signed(beneficiary), voucher_id, code::<T>())
+	verify {
+		assert!(Pallet::<T>::voucher(voucher_id).is_none());
+		assert!(Pallet::<T>::commitment(commitment).is_none());
+	}
+
+	cancel_commitment {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, T::CommitmentDeposit::get() * 10u32.into());
+		let commitment = T::Hashing::hash_of(&(0 as VoucherId, code::<T>(), &caller));
+		Pallet::<T>::commit_redemption(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+	}: _(RawOrigin::Signed(caller), commitment)
+	verify {
+		assert!(Pallet::<T>::commitment(commitment).is_none());
+	}
+
+	redeem_with_signature {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let key = T::OffchainSignature::generate_key();
+		let lock = VoucherLock::Key(key.clone().into_account());
+		let voucher_id = new_voucher::<T>(&issuer, lock, expiry::<T>());
+		let message = Pallet::<T>::signed_message(voucher_id, &beneficiary);
+		let signature = T::OffchainSignature::sign(&key, &message);
+	}: _(RawOrigin::Signed(caller), voucher_id, beneficiary, signature)
+	verify {
+		assert!(Pallet::<T>::voucher(voucher_id).is_none());
+	}
+
+	redeem_unsigned {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let key = T::OffchainSignature::generate_key();
+		let lock = VoucherLock::Key(key.clone().into_account());
+		let voucher_id = new_voucher::<T>(&issuer, lock, expiry::<T>());
+		let message = Pallet::<T>::signed_message(voucher_id, &beneficiary);
+		let signature = T::OffchainSignature::sign(&key, &message);
+	}: _(RawOrigin::None, voucher_id, beneficiary, signature)
+	verify {
+		assert!(Pallet::<T>::voucher(voucher_id).is_none());
+	}
+
+	reclaim {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let expiry = frame_system::Pallet::<T>::block_number() + One::one();
+		let voucher_id = new_voucher::<T>(&issuer, code_lock::<T>(), expiry);
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: _(RawOrigin::Signed(caller), voucher_id)
+	verify {
+		assert!(Pallet::<T>::voucher(voucher_id).is_none());
+	}
+
+	// The voucher goes back to its issuer.
+	expire_voucher {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		let expiry = frame_system::Pallet::<T>::block_number() + One::one();
+		let voucher_id = new_voucher::<T>(&issuer, code_lock::<T>(), expiry);
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: {
+		Pallet::<T>::on_unlock(Expiry::Voucher(voucher_id));
+	}
+	verify {
+		assert!(Pallet::<T>::voucher(voucher_id).is_none());
+	}
+
+	// The commitment is dropped, and its deposit refunded.
+	drop_commitment {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, T::CommitmentDeposit::get() * 10u32.into());
+		let commitment = T::Hashing::hash_of(&(0 as VoucherId, code::<T>(), &caller));
+		Pallet::<T>::commit_redemption(RawOrigin::Signed(caller).into(), commitment)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::CommitmentLifetime::get(),
+		);
+	}: {
+		Pallet::<T>::on_unlock(Expiry::Commitment(commitment));
+	}
+	verify {
+		assert!(Pallet::<T>::commitment(commitment).is_none());
+	}
+
+	// `n` expired vouchers are reclaimed at the end of the block, each expiring at a block of its
+	// own so that their expiries fit in the unlock queue.
+	on_idle {
+		let n in 0 .. 100;
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		T::Assets::create(ASSET_ID.into(), issuer.clone(), true, One::one())?;
+		T::Assets::mint_into(ASSET_ID.into(), &issuer, (AMOUNT * n).into())?;
+		let mut expiry = frame_system::Pallet::<T>::block_number();
+		for _ in 0..n {
+			expiry += One::one();
+			Pallet::<T>::issue(
+				RawOrigin::Signed(issuer.clone()).into(),
+				ASSET_ID.into(),
+				AMOUNT.into(),
+				code_lock::<T>(),
+				expiry,
+			)?;
+		}
+	}: {
+		Pallet::<T>::on_idle(expiry, Weight::max_value());
+	}
+	verify {
+		assert_eq!(Vouchers::<T>::iter().count(), 0);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
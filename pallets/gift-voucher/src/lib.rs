@@ -0,0 +1,577 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Gift vouchers paying out a fungible asset.
+///
+/// An issuer escrows some amount of an asset behind a lock, and hands the secret opening it to
+/// the bearer off-chain. The lock is either the hash of a code or a public key.
+///
+/// A code is redeemed in two steps, so that it cannot be front-run once revealed: the bearer first
+/// commits to the hash of the voucher id, the code and its account, then reveals the code in a
+/// later block. Only the account committed to is paid out, and a copy of the code revealed in the
+/// pool needs a commitment of its own, made too late to redeem first. Each commitment reserves a
+/// deposit from its account, refunded when it is redeemed or cancelled. A commitment left
+/// unredeemed for `CommitmentLifetime` blocks is stale: it no longer redeems, and is dropped with
+/// its deposit refunded.
+///
+/// A key is redeemed by signing the account to pay out to, which only pays out to the account it
+/// names. The signed message is tagged with the pallet and the genesis hash of the chain, so that
+/// it cannot be replayed on another chain nor mistaken for another message of the key. It can also
+/// be redeemed in an unsigned transaction, so that a beneficiary without funds pays no fees.
+/// Signed redemptions are refunded their fee when they pay out: the issuer's escrow stands for the
+/// deposit, as each voucher pays out once, while failed attempts pay.
+///
+/// The expiry of each voucher, and the end of the lifetime of each commitment, are scheduled in the
+/// `Unlocks` queue: a voucher not redeemed by then goes back to its issuer, and a stale commitment
+/// is dropped. An expired voucher that could not be sent back can still be reclaimed by anyone,
+/// and is sent back with the weight left at the end of blocks, a bounded batch at a time.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_std::vec::Vec,
+		storage::StoragePrefixedMap,
+		traits::{tokens::fungibles, ReservableCurrency},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::{
+		pagination::{decode_key, iter_page},
+		OnUnlock, ScheduleUnlock, StorageDeposits,
+	};
+	use sp_runtime::traits::{
+		AccountIdConversion, Hash, IdentifyAccount, SaturatedConversion, Saturating, Verify, Zero,
+	};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets vouchers pay out.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The currency in which commitments reserve their deposit.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved for each commitment to redeem a code-locked voucher, refunded when
+		/// it is redeemed.
+		#[pallet::constant]
+		type CommitmentDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// The number of blocks after which a commitment is stale, and is dropped with its deposit
+		/// refunded.
+		#[pallet::constant]
+		type CommitmentLifetime: Get<Self::BlockNumber>;
+
+		/// A signature made off-chain by the key of a voucher.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key of a voucher.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The maximum length of a voucher code.
+		#[pallet::constant]
+		type MaxCodeLength: Get<u32>;
+
+		/// The priority of unsigned redemptions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// The queue the expiries of the vouchers and commitments are scheduled in, which calls
+		/// back `OnUnlock` of the pallet with them.
+		type Unlocks: ScheduleUnlock<Self::BlockNumber, ExpiryOf<Self>>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next voucher will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_voucher_id)]
+	pub type NextVoucherId<T> = StorageValue<_, VoucherId, ValueQuery>;
+
+	/// The vouchers that have not been redeemed nor reclaimed.
+	#[pallet::storage]
+	#[pallet::getter(fn voucher)]
+	pub type Vouchers<T: Config> = StorageMap<_, Twox64Concat, VoucherId, VoucherOf<T>>;
+
+	/// The last voucher inspected for expiry, which the next cleanup resumes after.
+	#[pallet::storage]
+	#[pallet::getter(fn cleanup_cursor)]
+	pub type CleanupCursor<T> = StorageValue<_, VoucherId>;
+
+	/// The commitments to redeem code-locked vouchers, by their hash.
+	#[pallet::storage]
+	#[pallet::getter(fn commitment)]
+	pub type Commitments<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, CommitmentOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A voucher has been issued.
+		Issued {
+			voucher_id: VoucherId,
+			issuer: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// A voucher has been redeemed.
+		Redeemed { voucher_id: VoucherId, beneficiary: T::AccountId },
+		/// An expired voucher went back to its issuer.
+		Reclaimed { voucher_id: VoucherId },
+		/// A commitment has been cancelled, and its deposit refunded.
+		CommitmentCancelled { commitment: T::Hash },
+		/// A stale commitment has been dropped, and its deposit refunded.
+		CommitmentDropped { commitment: T::Hash },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The voucher does not exist.
+		UnknownVoucher,
+		/// The amount must be non zero.
+		ZeroAmount,
+		/// The expiry block is already in the past.
+		ExpiryInThePast,
+		/// The voucher has expired.
+		Expired,
+		/// The voucher has not expired yet.
+		NotExpired,
+		/// The code or signature does not open the voucher's lock.
+		WrongSecret,
+		/// There is no voucher id left.
+		NoAvailableVoucherId,
+		/// The redemption was not committed to in an earlier block.
+		NotCommitted,
+		/// The commitment has already been made.
+		AlreadyCommitted,
+		/// The commitment was made by another account.
+		NotCommitter,
+		/// The commitment is stale.
+		StaleCommitment,
+		/// Too many expiries are already scheduled at the block.
+		TooManyExpiriesInBlock,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::reclaim_expired(now, remaining_weight)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow `amount` of `asset_id` in a voucher opened by `lock`, until `expiry`.
+		#[pallet::weight(T::WeightInfo::issue())]
+		#[transactional]
+		pub fn issue(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			lock: VoucherLockOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ExpiryInThePast
+			);
+
+			let voucher_id = Self::next_voucher_id();
+			let next_id = voucher_id.checked_add(1).ok_or(Error::<T>::NoAvailableVoucherId)?;
+
+			T::Unlocks::schedule(expiry, Expiry::Voucher(voucher_id))
+				.map_err(|_| Error::<T>::TooManyExpiriesInBlock)?;
+			T::Assets::transfer(asset_id, &issuer, &Self::account_id(), amount, false)?;
+
+			Vouchers::<T>::insert(
+				voucher_id,
+				Voucher { issuer: issuer.clone(), asset_id, amount, lock, expiry },
+			);
+			NextVoucherId::<T>::put(next_id);
+
+			Self::deposit_event(Event::Issued { voucher_id, issuer, asset_id, amount });
+
+			Ok(())
+		}
+
+		/// Commit to redeeming a code-locked voucher, before revealing its code.
+		///
+		/// `commitment` is the hash of the encoded `(voucher_id, code, beneficiary)`, where
+		/// `beneficiary` is the account that will reveal the code. Reserves the
+		/// `CommitmentDeposit` from the caller.
+		#[pallet::weight(T::WeightInfo::commit_redemption())]
+		#[transactional]
+		pub fn commit_redemption(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Commitments::<T>::contains_key(commitment), Error::<T>::AlreadyCommitted);
+			let deposit = T::Currency::reserve_deposit(&who, T::CommitmentDeposit::get())?;
+			let committed = Commitment {
+				who,
+				deposit,
+				committed_at: frame_system::Pallet::<T>::block_number(),
+			};
+			T::Unlocks::schedule(Self::stale_at(&committed), Expiry::Commitment(commitment))
+				.map_err(|_| Error::<T>::TooManyExpiriesInBlock)?;
+			Commitments::<T>::insert(commitment, committed);
+
+			Ok(())
+		}
+
+		/// Redeem a code-locked voucher to the caller by revealing its code.
+		///
+		/// The caller must have committed to the redemption in an earlier block, and less than
+		/// `CommitmentLifetime` blocks ago. The fee is refunded if the voucher pays out, and so is
+		/// the deposit of the commitment.
+		#[pallet::weight(T::WeightInfo::redeem_with_code())]
+		pub fn redeem_with_code(
+			origin: OriginFor<T>,
+			voucher_id: VoucherId,
+			code: BoundedVec<u8, T::MaxCodeLength>,
+		) -> DispatchResultWithPostInfo {
+			let beneficiary = ensure_signed(origin)?;
+
+			let voucher = Self::voucher(voucher_id).ok_or(Error::<T>::UnknownVoucher)?;
+			let code_hash = T::Hashing::hash(&code[..]);
+			ensure!(voucher.lock == VoucherLock::Code(code_hash), Error::<T>::WrongSecret);
+
+			// A commitment made in the block revealing the code could copy it from the pool.
+			let commitment = T::Hashing::hash_of(&(voucher_id, &code, &beneficiary));
+			let committed = Self::commitment(commitment).ok_or(Error::<T>::NotCommitted)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(committed.committed_at < now, Error::<T>::NotCommitted);
+			ensure!(!Self::is_stale(&committed, now), Error::<T>::StaleCommitment);
+
+			Self::pay_out(voucher_id, voucher, beneficiary)?;
+			Self::release(commitment, committed);
+			Ok(Pays::No.into())
+		}
+
+		/// Cancel a commitment of the caller, refunding its deposit.
+		#[pallet::weight(T::WeightInfo::cancel_commitment())]
+		pub fn cancel_commitment(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let committed = Self::commitment(commitment).ok_or(Error::<T>::NotCommitted)?;
+			ensure!(committed.who == who, Error::<T>::NotCommitter);
+
+			Self::release(commitment, committed);
+			Self::deposit_event(Event::CommitmentCancelled { commitment });
+
+			Ok(())
+		}
+
+		/// Redeem a key-locked voucher to `beneficiary`.
+		///
+		/// `signature` must sign the message of `signed_message` with the voucher key.
+		/// Anyone can submit it, e.g. for a beneficiary without funds. The fee is refunded if the
+		/// voucher pays out.
+		#[pallet::weight(T::WeightInfo::redeem_with_signature())]
+		pub fn redeem_with_signature(
+			origin: OriginFor<T>,
+			voucher_id: VoucherId,
+			beneficiary: T::AccountId,
+			signature: T::OffchainSignature,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let voucher = Self::voucher(voucher_id).ok_or(Error::<T>::UnknownVoucher)?;
+			Self::check_signature(voucher_id, &voucher, &beneficiary, &signature)?;
+
+			Self::pay_out(voucher_id, voucher, beneficiary)?;
+			Ok(Pays::No.into())
+		}
+
+		/// Redeem a key-locked voucher to `beneficiary`, in an unsigned transaction.
+		///
+		/// The transaction is validated like `redeem_with_signature`, before it enters the pool.
+		#[pallet::weight(T::WeightInfo::redeem_unsigned())]
+		pub fn redeem_unsigned(
+			origin: OriginFor<T>,
+			voucher_id: VoucherId,
+			beneficiary: T::AccountId,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let voucher = Self::voucher(voucher_id).ok_or(Error::<T>::UnknownVoucher)?;
+			Self::check_signature(voucher_id, &voucher, &beneficiary, &signature)?;
+
+			Self::pay_out(voucher_id, voucher, beneficiary)
+		}
+
+		/// Send an expired voucher back to its issuer.
+		#[pallet::weight(T::WeightInfo::reclaim())]
+		pub fn reclaim(origin: OriginFor<T>, voucher_id: VoucherId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let voucher = Self::voucher(voucher_id).ok_or(Error::<T>::UnknownVoucher)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= voucher.expiry,
+				Error::<T>::NotExpired
+			);
+
+			Self::refund(voucher_id, voucher)
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (voucher_id, beneficiary, signature) = match call {
+				Call::redeem_unsigned { voucher_id, beneficiary, signature } =>
+					(*voucher_id, beneficiary, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			// Redeemed vouchers are removed, so replaying a redemption finds no voucher.
+			let voucher = match Self::voucher(voucher_id) {
+				Some(voucher) => voucher,
+				None => return InvalidTransaction::Stale.into(),
+			};
+			if Self::check_signature(voucher_id, &voucher, beneficiary, signature).is_err() {
+				return InvalidTransaction::BadProof.into()
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			if now >= voucher.expiry {
+				return InvalidTransaction::Stale.into()
+			}
+
+			// A voucher pays out once: the pool keeps a single redemption of each.
+			ValidTransaction::with_tag_prefix("GiftVoucher")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(voucher_id)
+				.longevity(voucher.expiry.saturating_sub(now).saturated_into())
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding escrowed vouchers.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Send a voucher back to its issuer.
+		fn refund(voucher_id: VoucherId, voucher: VoucherOf<T>) -> DispatchResult {
+			T::Assets::transfer(
+				voucher.asset_id,
+				&Self::account_id(),
+				&voucher.issuer,
+				voucher.amount,
+				false,
+			)?;
+			Vouchers::<T>::remove(voucher_id);
+			T::Unlocks::cancel(voucher.expiry, Expiry::Voucher(voucher_id));
+
+			Self::deposit_event(Event::Reclaimed { voucher_id });
+
+			Ok(())
+		}
+
+		/// Remove a commitment and refund its deposit.
+		fn release(commitment: T::Hash, committed: CommitmentOf<T>) {
+			Commitments::<T>::remove(commitment);
+			T::Currency::refund_deposit(&committed.who, committed.deposit);
+			T::Unlocks::cancel(Self::stale_at(&committed), Expiry::Commitment(commitment));
+		}
+
+		/// The block from which a commitment no longer redeems.
+		pub(crate) fn stale_at(committed: &CommitmentOf<T>) -> T::BlockNumber {
+			committed.committed_at.saturating_add(T::CommitmentLifetime::get())
+		}
+
+		/// Whether a commitment no longer redeems at block `now`.
+		fn is_stale(committed: &CommitmentOf<T>, now: T::BlockNumber) -> bool {
+			now >= Self::stale_at(committed)
+		}
+
+		/// Send a voucher back to its issuer, once it expired.
+		///
+		/// Runs from the unlock queue, so a failing refund is not fatal: the voucher is left for a
+		/// later `reclaim`.
+		pub(crate) fn expire_voucher(voucher_id: VoucherId) {
+			if let Some(voucher) = Self::voucher(voucher_id) {
+				if frame_system::Pallet::<T>::block_number() >= voucher.expiry {
+					let _ = Self::refund(voucher_id, voucher);
+				}
+			}
+		}
+
+		/// Reclaim the expired vouchers among as many as `remaining_weight` allows inspecting.
+		///
+		/// Vouchers are inspected in storage order, from where the previous cleanup stopped. Most
+		/// expired vouchers are sent back by the unlock queue already: this sends back those whose
+		/// refund failed then, or whose expiry could not be scheduled when they were migrated.
+		pub fn reclaim_expired(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let base = T::WeightInfo::on_idle(0);
+			let per_voucher = T::WeightInfo::on_idle(1).saturating_sub(base).max(1);
+			let max = remaining_weight.saturating_sub(base) / per_voucher;
+			if max == 0 {
+				return 0
+			}
+
+			let page = iter_page(
+				&Vouchers::<T>::final_prefix(),
+				Self::cleanup_cursor().map(Vouchers::<T>::hashed_key_for),
+				max.min(u32::MAX.into()) as u32,
+				|key, value| {
+					let voucher_id = decode_key::<Twox64Concat, VoucherId>(&mut &key[..])?;
+					Some((voucher_id, VoucherOf::<T>::decode(&mut &value[..]).ok()?))
+				},
+			);
+			// Once the last voucher is inspected, the next cleanup starts over from the first one.
+			let cursor = page.next_key.and(page.items.last().map(|(voucher_id, _)| *voucher_id));
+			CleanupCursor::<T>::set(cursor);
+
+			let expired = page.items.into_iter().filter(|(_, voucher)| now >= voucher.expiry);
+			for (voucher_id, voucher) in expired {
+				// A voucher that cannot be refunded is left for a later `reclaim`.
+				let _ = Self::refund(voucher_id, voucher);
+			}
+
+			T::WeightInfo::on_idle(page.read)
+		}
+
+		/// Drop a commitment and refund its deposit, once it is stale.
+		pub(crate) fn drop_commitment(commitment: T::Hash) {
+			if let Some(committed) = Self::commitment(commitment) {
+				if Self::is_stale(&committed, frame_system::Pallet::<T>::block_number()) {
+					Self::release(commitment, committed);
+					Self::deposit_event(Event::CommitmentDropped { commitment });
+				}
+			}
+		}
+
+		/// The message a voucher key signs to pay out `voucher_id` to `beneficiary`.
+		///
+		/// It is the encoded `(b"gift-voucher", genesis_hash, voucher_id, beneficiary)`.
+		pub fn signed_message(voucher_id: VoucherId, beneficiary: &T::AccountId) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+			(b"gift-voucher", genesis_hash, voucher_id, beneficiary).encode()
+		}
+
+		/// Check that `signature` opens the lock of `voucher`, paying out to `beneficiary`.
+		fn check_signature(
+			voucher_id: VoucherId,
+			voucher: &VoucherOf<T>,
+			beneficiary: &T::AccountId,
+			signature: &T::OffchainSignature,
+		) -> DispatchResult {
+			let key = match &voucher.lock {
+				VoucherLock::Key(key) => key,
+				VoucherLock::Code(_) => return Err(Error::<T>::WrongSecret.into()),
+			};
+			let message = Self::signed_message(voucher_id, beneficiary);
+			ensure!(signature.verify(&message[..], key), Error::<T>::WrongSecret);
+			Ok(())
+		}
+
+		fn pay_out(
+			voucher_id: VoucherId,
+			voucher: VoucherOf<T>,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < voucher.expiry,
+				Error::<T>::Expired
+			);
+
+			T::Assets::transfer(
+				voucher.asset_id,
+				&Self::account_id(),
+				&beneficiary,
+				voucher.amount,
+				false,
+			)?;
+			Vouchers::<T>::remove(voucher_id);
+			T::Unlocks::cancel(voucher.expiry, Expiry::Voucher(voucher_id));
+
+			Self::deposit_event(Event::Redeemed { voucher_id, beneficiary });
+
+			Ok(())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use rusty_node_support::escrow::ensure_escrowed;
+
+			let next_id = Self::next_voucher_id();
+			ensure!(Vouchers::<T>::iter_keys().all(|id| id < next_id), "voucher id not allocated");
+
+			// The escrow account holds the amount of every voucher not redeemed yet.
+			let account = Self::account_id();
+			ensure_escrowed::<_, T::Assets>(
+				&account,
+				Vouchers::<T>::iter_values().map(|voucher| (voucher.asset_id, voucher.amount)),
+				"vouchers not escrowed",
+			)?;
+
+			Ok(())
+		}
+	}
+
+	/// The expiries of the vouchers and commitments, scheduled in `Unlocks`.
+	impl<T: Config> OnUnlock<ExpiryOf<T>> for Pallet<T> {
+		fn unlock_weight(expiry: &ExpiryOf<T>) -> Weight {
+			match expiry {
+				Expiry::Voucher(_) => T::WeightInfo::expire_voucher(),
+				Expiry::Commitment(_) => T::WeightInfo::drop_commitment(),
+			}
+		}
+
+		fn on_unlock(expiry: ExpiryOf<T>) -> Weight {
+			let weight = Self::unlock_weight(&expiry);
+			match expiry {
+				Expiry::Voucher(voucher_id) => Self::expire_voucher(voucher_id),
+				Expiry::Commitment(commitment) => Self::drop_commitment(commitment),
+			}
+			weight
+		}
+	}
+}
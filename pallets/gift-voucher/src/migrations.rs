@@ -0,0 +1,71 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 schedules the expiries of the vouchers and commitments in `Unlocks`. The commitments
+/// are no longer scanned for, and `on_idle` only has the vouchers the queue could not send back
+/// left to scan for, restarting its scan from the first voucher.
+///
+/// A voucher or commitment whose expiry is past, or no longer fits in its block of the queue,
+/// expires right away if it is due. One that is not due is left for `on_idle` and `reclaim`, or
+/// its committer.
+pub mod v2 {
+	use super::*;
+	use crate::{Commitments, Expiry, VoucherId, Vouchers};
+	use frame_support::storage::migration::take_storage_value;
+	use rusty_node_support::unlocks::schedule_or_unlock;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let pallet = Pallet::<T>::name().as_bytes();
+		take_storage_value::<VoucherId>(pallet, b"CleanupCursor", &[]);
+		take_storage_value::<T::Hash>(pallet, b"CommitmentCursor", &[]);
+
+		let mut stored = 0u64;
+		let vouchers = Vouchers::<T>::iter()
+			.map(|(voucher_id, voucher)| (voucher.expiry, Expiry::Voucher(voucher_id)));
+		let commitments = Commitments::<T>::iter().map(|(commitment, committed)| {
+			(
+				Pallet::<T>::stale_at(&committed),
+				Expiry::Commitment(commitment),
+			)
+		});
+		let unlocks = vouchers.chain(commitments).inspect(|_| stored += 1);
+		let weight = schedule_or_unlock::<_, _, T::Unlocks, Pallet<T>>(unlocks, T::DbWeight::get());
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		weight.saturating_add(T::DbWeight::get().reads_writes(stored, 3))
+	}
+}
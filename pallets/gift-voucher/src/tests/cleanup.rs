@@ -0,0 +1,70 @@
+use super::mock::*;
+use crate::{VoucherLock, WeightInfo};
+use frame_support::{assert_ok, traits::Hooks, weights::Weight};
+
+/// Have ALICE issue `n` vouchers expiring at block `expiry`, which the unlock queue then misses.
+fn issue_unscheduled_vouchers(n: u32, expiry: u64) {
+	for _ in 0..n {
+		assert_ok!(GiftVoucher::issue(
+			Origin::signed(ALICE),
+			ASSET_ID,
+			100,
+			VoucherLock::Key(100),
+			expiry
+		));
+	}
+	pallet_unlock_queue::Queue::<Test>::remove(expiry);
+}
+
+/// The weight of inspecting `n` vouchers for expiry.
+fn cleanup_weight(n: u32) -> Weight {
+	<() as WeightInfo>::on_idle(n)
+}
+
+fn open_vouchers() -> usize {
+	crate::Vouchers::<Test>::iter().count()
+}
+
+#[test]
+fn on_idle_reclaims_the_expired_vouchers_the_queue_missed() {
+	new_test_ext().execute_with(|| {
+		issue_unscheduled_vouchers(2, 10);
+		issue_unscheduled_vouchers(1, 20);
+
+		System::set_block_number(10);
+		assert_eq!(GiftVoucher::on_idle(10, Weight::max_value()), cleanup_weight(3));
+
+		assert_eq!(open_vouchers(), 1);
+		assert!(GiftVoucher::voucher(2).is_some());
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 900);
+		assert_eq!(GiftVoucher::cleanup_cursor(), None);
+	})
+}
+
+#[test]
+fn on_idle_resumes_where_it_stopped() {
+	new_test_ext().execute_with(|| {
+		issue_unscheduled_vouchers(3, 10);
+		System::set_block_number(10);
+
+		assert_eq!(GiftVoucher::on_idle(10, cleanup_weight(2)), cleanup_weight(2));
+		assert_eq!(open_vouchers(), 1);
+		assert!(GiftVoucher::cleanup_cursor().is_some());
+
+		assert_eq!(GiftVoucher::on_idle(11, cleanup_weight(2)), cleanup_weight(1));
+		assert_eq!(open_vouchers(), 0);
+		assert_eq!(GiftVoucher::cleanup_cursor(), None);
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000);
+	})
+}
+
+#[test]
+fn on_idle_does_nothing_without_enough_weight() {
+	new_test_ext().execute_with(|| {
+		issue_unscheduled_vouchers(1, 10);
+		System::set_block_number(10);
+
+		assert_eq!(GiftVoucher::on_idle(10, cleanup_weight(0)), 0);
+		assert_eq!(open_vouchers(), 1);
+	})
+}
@@ -0,0 +1,98 @@
+use super::mock::*;
+use crate::{Error, Event as VoucherEvent, Expiry, VoucherLock};
+use frame_support::{assert_noop, assert_ok};
+use rusty_node_support::ScheduleUnlock;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, Hash},
+};
+
+/// Have ALICE issue `n` vouchers expiring at block `expiry`.
+fn issue_vouchers(n: u32, expiry: u64) {
+	for _ in 0..n {
+		assert_ok!(GiftVoucher::issue(
+			Origin::signed(ALICE),
+			ASSET_ID,
+			100,
+			VoucherLock::Key(100),
+			expiry
+		));
+	}
+}
+
+#[test]
+fn expired_vouchers_go_back_to_the_issuer_at_their_expiry() {
+	new_test_ext().execute_with(|| {
+		issue_vouchers(2, 10);
+		issue_vouchers(1, 20);
+		let expiring = vec![Expiry::Voucher(0), Expiry::Voucher(1)];
+		assert_eq!(UnlockQueue::queue(10).into_inner(), expiring);
+
+		run_to_block(9);
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 700);
+
+		run_to_block(10);
+		assert!(GiftVoucher::voucher(0).is_none() && GiftVoucher::voucher(1).is_none());
+		assert!(GiftVoucher::voucher(2).is_some());
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 900);
+		System::assert_has_event(VoucherEvent::Reclaimed { voucher_id: 1 }.into());
+	})
+}
+
+#[test]
+fn redeemed_vouchers_are_no_longer_scheduled() {
+	new_test_ext().execute_with(|| {
+		issue_vouchers(1, 10);
+		let signature = TestSignature(100, GiftVoucher::signed_message(0, &BOB));
+		assert_ok!(GiftVoucher::redeem_with_signature(Origin::signed(BOB), 0, BOB, signature));
+
+		assert!(UnlockQueue::queue(10).is_empty());
+	})
+}
+
+#[test]
+fn issue_fails_when_too_many_expiries_are_in_the_block() {
+	new_test_ext().execute_with(|| {
+		for voucher_id in 100..100 + MaxUnlocksPerBlock::get() {
+			assert_ok!(<UnlockQueue as ScheduleUnlock<_, _>>::schedule(
+				10,
+				Expiry::Voucher(voucher_id)
+			));
+		}
+
+		assert_noop!(
+			GiftVoucher::issue(Origin::signed(ALICE), ASSET_ID, 100, VoucherLock::Key(100), 10),
+			Error::<Test>::TooManyExpiriesInBlock
+		);
+	})
+}
+
+#[test]
+fn stale_commitments_are_dropped() {
+	new_test_ext().execute_with(|| {
+		let stale = BlakeTwo256::hash(b"stale");
+		assert_ok!(GiftVoucher::commit_redemption(Origin::signed(BOB), stale));
+		System::set_block_number(3);
+		let fresh = BlakeTwo256::hash(b"fresh");
+		assert_ok!(GiftVoucher::commit_redemption(Origin::signed(CHARLIE), fresh));
+
+		run_to_block(1 + CommitmentLifetime::get());
+
+		assert!(GiftVoucher::commitment(stale).is_none());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert!(GiftVoucher::commitment(fresh).is_some());
+		assert_eq!(Balances::reserved_balance(CHARLIE), 10);
+		System::assert_has_event(VoucherEvent::CommitmentDropped { commitment: stale }.into());
+	})
+}
+
+#[test]
+fn cancelled_commitments_are_no_longer_scheduled() {
+	new_test_ext().execute_with(|| {
+		let commitment = BlakeTwo256::hash(b"commitment");
+		assert_ok!(GiftVoucher::commit_redemption(Origin::signed(BOB), commitment));
+		assert_ok!(GiftVoucher::cancel_commitment(Origin::signed(BOB), commitment));
+
+		assert!(UnlockQueue::queue(1 + CommitmentLifetime::get()).is_empty());
+	})
+}
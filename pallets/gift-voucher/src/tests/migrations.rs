@@ -0,0 +1,48 @@
+use super::mock::*;
+use crate::{migrations, Expiry, VoucherLock};
+use frame_support::{
+	assert_ok,
+	storage::migration::{have_storage_value, put_storage_value},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+#[test]
+fn runtime_upgrade_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<GiftVoucher>();
+
+		<GiftVoucher as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(GiftVoucher::on_chain_storage_version(), GiftVoucher::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_schedules_the_expiries_in_the_unlock_queue() {
+	new_test_ext().execute_with(|| {
+		for expiry in [5, 10] {
+			assert_ok!(GiftVoucher::issue(
+				Origin::signed(ALICE),
+				ASSET_ID,
+				100,
+				VoucherLock::Key(100),
+				expiry
+			));
+		}
+		// Version 1 scanned for the expired vouchers from a cursor of the pallet.
+		pallet_unlock_queue::Queue::<Test>::remove(5);
+		pallet_unlock_queue::Queue::<Test>::remove(10);
+		put_storage_value(b"GiftVoucher", b"CleanupCursor", &[], 0u32);
+		StorageVersion::new(1).put::<GiftVoucher>();
+		System::set_block_number(5);
+
+		migrations::migrate::<Test>();
+
+		assert!(!have_storage_value(b"GiftVoucher", b"CleanupCursor", &[]));
+		// The voucher past its expiry went back to its issuer right away.
+		assert!(GiftVoucher::voucher(0).is_none());
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 900);
+		assert_eq!(UnlockQueue::queue(10).into_inner(), vec![Expiry::Voucher(1)]);
+		assert_eq!(GiftVoucher::on_chain_storage_version(), 2);
+	})
+}
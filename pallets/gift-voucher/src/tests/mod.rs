@@ -0,0 +1,6 @@
+pub mod mock;
+
+mod cleanup;
+mod expiry;
+mod migrations;
+mod vouchers;
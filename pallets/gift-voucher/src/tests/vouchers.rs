@@ -0,0 +1,295 @@
+use super::mock::*;
+use crate::{Error, Event as VoucherEvent, VoucherLock};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, unsigned::ValidateUnsigned, weights::Pays};
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, Hash},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+};
+
+const VOUCHER_KEY: u64 = 100;
+
+fn issue(lock: VoucherLock<sp_core::H256, u64>) {
+	assert_ok!(GiftVoucher::issue(Origin::signed(ALICE), ASSET_ID, 100, lock, 10));
+}
+
+fn code(code: &[u8]) -> frame_support::BoundedVec<u8, MaxCodeLength> {
+	code.to_vec().try_into().unwrap()
+}
+
+/// Have `who` commit to redeem voucher 0 with `secret`, then move on to the next block.
+fn commit(who: u64, secret: &[u8]) {
+	let commitment = BlakeTwo256::hash_of(&(0u32, code(secret), who));
+	assert_ok!(GiftVoucher::commit_redemption(Origin::signed(who), commitment));
+	System::set_block_number(System::block_number() + 1);
+}
+
+fn signature(beneficiary: u64) -> TestSignature {
+	TestSignature(VOUCHER_KEY, GiftVoucher::signed_message(0, &beneficiary))
+}
+
+#[test]
+fn issue_escrows_the_amount() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			GiftVoucher::issue(
+				Origin::signed(ALICE),
+				ASSET_ID,
+				0,
+				VoucherLock::Key(VOUCHER_KEY),
+				10
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			GiftVoucher::issue(
+				Origin::signed(ALICE),
+				ASSET_ID,
+				100,
+				VoucherLock::Key(VOUCHER_KEY),
+				1
+			),
+			Error::<Test>::ExpiryInThePast
+		);
+		issue(VoucherLock::Key(VOUCHER_KEY));
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 900);
+		assert_eq!(Assets::balance(ASSET_ID, GiftVoucher::account_id()), 100);
+		System::assert_last_event(
+			VoucherEvent::Issued { voucher_id: 0, issuer: ALICE, asset_id: ASSET_ID, amount: 100 }
+				.into(),
+		);
+	})
+}
+
+#[test]
+fn redeem_with_code() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Code(BlakeTwo256::hash(b"happy birthday")));
+		commit(BOB, b"happy birthday");
+
+		assert_noop!(
+			GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"merry christmas")),
+			Error::<Test>::WrongSecret
+		);
+		assert_ok!(GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"happy birthday")));
+
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+		assert!(GiftVoucher::voucher(0).is_none());
+		let commitment = BlakeTwo256::hash_of(&(0u32, code(b"happy birthday"), BOB));
+		assert!(GiftVoucher::commitment(commitment).is_none());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		System::assert_last_event(
+			VoucherEvent::Redeemed { voucher_id: 0, beneficiary: BOB }.into(),
+		);
+	})
+}
+
+#[test]
+fn commitments_reserve_a_deposit() {
+	new_test_ext().execute_with(|| {
+		let commitment = BlakeTwo256::hash_of(&(0u32, code(b"happy birthday"), BOB));
+		assert_ok!(GiftVoucher::commit_redemption(Origin::signed(BOB), commitment));
+		assert_eq!(Balances::reserved_balance(BOB), 10);
+
+		assert_noop!(
+			GiftVoucher::commit_redemption(Origin::signed(CHARLIE), commitment),
+			Error::<Test>::AlreadyCommitted
+		);
+	})
+}
+
+#[test]
+fn cancel_commitment_refunds_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let commitment = BlakeTwo256::hash_of(&(0u32, code(b"happy birthday"), BOB));
+		assert_ok!(GiftVoucher::commit_redemption(Origin::signed(BOB), commitment));
+
+		assert_noop!(
+			GiftVoucher::cancel_commitment(Origin::signed(CHARLIE), commitment),
+			Error::<Test>::NotCommitter
+		);
+		assert_ok!(GiftVoucher::cancel_commitment(Origin::signed(BOB), commitment));
+
+		assert!(GiftVoucher::commitment(commitment).is_none());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		System::assert_last_event(VoucherEvent::CommitmentCancelled { commitment }.into());
+		assert_noop!(
+			GiftVoucher::cancel_commitment(Origin::signed(BOB), commitment),
+			Error::<Test>::NotCommitted
+		);
+	})
+}
+
+#[test]
+fn stale_commitments_do_not_redeem() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Code(BlakeTwo256::hash(b"happy birthday")));
+		commit(BOB, b"happy birthday");
+
+		System::set_block_number(System::block_number() + CommitmentLifetime::get());
+		assert_noop!(
+			GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"happy birthday")),
+			Error::<Test>::StaleCommitment
+		);
+	})
+}
+
+#[test]
+fn revealed_codes_cannot_be_front_run() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Code(BlakeTwo256::hash(b"happy birthday")));
+
+		// The redemption must be committed to beforehand.
+		assert_noop!(
+			GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"happy birthday")),
+			Error::<Test>::NotCommitted
+		);
+		commit(BOB, b"happy birthday");
+
+		// A copy of the code needs a commitment of the copier, which a redemption in the same
+		// block does not accept.
+		assert_noop!(
+			GiftVoucher::redeem_with_code(Origin::signed(CHARLIE), 0, code(b"happy birthday")),
+			Error::<Test>::NotCommitted
+		);
+		let commitment = BlakeTwo256::hash_of(&(0u32, code(b"happy birthday"), CHARLIE));
+		assert_ok!(GiftVoucher::commit_redemption(Origin::signed(CHARLIE), commitment));
+		assert_noop!(
+			GiftVoucher::redeem_with_code(Origin::signed(CHARLIE), 0, code(b"happy birthday")),
+			Error::<Test>::NotCommitted
+		);
+
+		assert_ok!(GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"happy birthday")));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+	})
+}
+
+#[test]
+fn successful_redemptions_are_feeless() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Code(BlakeTwo256::hash(b"happy birthday")));
+		commit(BOB, b"happy birthday");
+
+		let error = GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"merry christmas"))
+			.unwrap_err();
+		assert_eq!(error.post_info.pays_fee, Pays::Yes);
+		let post_info =
+			GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"happy birthday")).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+	})
+}
+
+#[test]
+fn redeem_with_signature() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Key(VOUCHER_KEY));
+		let signature = signature(BOB);
+
+		// The signature only pays out to the account it names.
+		assert_noop!(
+			GiftVoucher::redeem_with_signature(
+				Origin::signed(CHARLIE),
+				0,
+				CHARLIE,
+				signature.clone()
+			),
+			Error::<Test>::WrongSecret
+		);
+		assert_noop!(
+			GiftVoucher::redeem_with_signature(
+				Origin::signed(CHARLIE),
+				0,
+				BOB,
+				TestSignature(BOB, GiftVoucher::signed_message(0, &BOB))
+			),
+			Error::<Test>::WrongSecret
+		);
+		// Nor does a signature of the voucher id and the account without the tag of the chain.
+		assert_noop!(
+			GiftVoucher::redeem_with_signature(
+				Origin::signed(CHARLIE),
+				0,
+				BOB,
+				TestSignature(VOUCHER_KEY, (0u32, BOB).encode())
+			),
+			Error::<Test>::WrongSecret
+		);
+		assert_ok!(GiftVoucher::redeem_with_signature(Origin::signed(CHARLIE), 0, BOB, signature));
+
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+	})
+}
+
+#[test]
+fn redeem_unsigned_is_validated_once() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Key(VOUCHER_KEY));
+		let signature = signature(BOB);
+		let redeem = |beneficiary| crate::Call::redeem_unsigned {
+			voucher_id: 0,
+			beneficiary,
+			signature: signature.clone(),
+		};
+
+		assert_eq!(
+			GiftVoucher::validate_unsigned(TransactionSource::External, &redeem(CHARLIE)),
+			InvalidTransaction::BadProof.into()
+		);
+		let valid = GiftVoucher::validate_unsigned(TransactionSource::External, &redeem(BOB))
+			.expect("the signature opens the voucher");
+		assert_eq!(valid.priority, UnsignedPriority::get());
+		assert_eq!(valid.provides, vec![("GiftVoucher", 0u32).encode()]);
+		assert_eq!(valid.longevity, 9);
+
+		assert_noop!(
+			GiftVoucher::redeem_unsigned(Origin::signed(BOB), 0, BOB, signature.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(GiftVoucher::redeem_unsigned(Origin::none(), 0, BOB, signature.clone()));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+
+		// The redemption cannot be replayed.
+		assert_eq!(
+			GiftVoucher::validate_unsigned(TransactionSource::External, &redeem(BOB)),
+			InvalidTransaction::Stale.into()
+		);
+	})
+}
+
+#[test]
+fn expired_voucher_cannot_be_redeemed_unsigned() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Key(VOUCHER_KEY));
+		let signature = signature(BOB);
+		let call = crate::Call::redeem_unsigned { voucher_id: 0, beneficiary: BOB, signature };
+
+		System::set_block_number(10);
+		assert_eq!(
+			GiftVoucher::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Stale.into()
+		);
+	})
+}
+
+#[test]
+fn expired_vouchers_go_back_to_the_issuer() {
+	new_test_ext().execute_with(|| {
+		issue(VoucherLock::Code(BlakeTwo256::hash(b"happy birthday")));
+		System::set_block_number(8);
+		commit(BOB, b"happy birthday");
+
+		assert_noop!(GiftVoucher::reclaim(Origin::signed(BOB), 0), Error::<Test>::NotExpired);
+
+		System::set_block_number(10);
+		assert_noop!(
+			GiftVoucher::redeem_with_code(Origin::signed(BOB), 0, code(b"happy birthday")),
+			Error::<Test>::Expired
+		);
+		assert_ok!(GiftVoucher::reclaim(Origin::signed(BOB), 0));
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000);
+		System::assert_last_event(VoucherEvent::Reclaimed { voucher_id: 0 }.into());
+	})
+}
@@ -0,0 +1,66 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{tokens::fungibles::Inspect, Currency};
+use scale_info::TypeInfo;
+
+pub type VoucherId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type DepositBalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type ExpiryOf<T> = Expiry<<T as frame_system::Config>::Hash>;
+pub type CommitmentOf<T> =
+	Commitment<AccountIdOf<T>, DepositBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub type VoucherLockOf<T> = VoucherLock<<T as frame_system::Config>::Hash, AccountIdOf<T>>;
+pub type VoucherOf<T> = Voucher<
+	AccountIdOf<T>,
+	AssetIdOf<T>,
+	BalanceOf<T>,
+	VoucherLockOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// What the bearer of a voucher must present to redeem it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum VoucherLock<Hash, AccountId> {
+	/// The preimage of this hash, revealed after committing to it in an earlier block.
+	Code(Hash),
+	/// A signature of the voucher id and the beneficiary, tagged with the chain, by the key of this
+	/// account.
+	Key(AccountId),
+}
+
+/// An issued voucher.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Voucher<AccountId, AssetId, Balance, Lock, BlockNumber> {
+	/// The account that issued and funded the voucher.
+	pub issuer: AccountId,
+	/// The asset the voucher pays out.
+	pub asset_id: AssetId,
+	/// The escrowed amount.
+	pub amount: Balance,
+	pub lock: Lock,
+	/// The block from which the voucher can no longer be redeemed, and can be reclaimed.
+	pub expiry: BlockNumber,
+}
+
+/// A commitment to redeem a code-locked voucher.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Commitment<AccountId, Balance, BlockNumber> {
+	/// The account that made the commitment, and reserved its deposit.
+	pub who: AccountId,
+	/// The deposit reserved from `who`, refunded when the commitment is redeemed.
+	pub deposit: Balance,
+	/// The block at which the commitment was made.
+	pub committed_at: BlockNumber,
+}
+
+/// What the pallet schedules in its `Unlocks` queue.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum Expiry<Hash> {
+	/// The expiry of a voucher, which goes back to its issuer if it was not redeemed.
+	Voucher(VoucherId),
+	/// The end of the lifetime of a commitment, which is dropped if it was not redeemed.
+	Commitment(Hash),
+}
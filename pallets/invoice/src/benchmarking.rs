@@ -0,0 +1,76 @@
+//! Benchmarks of the invoice pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::tokens::fungibles::{Create, Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, One};
+
+const ASSET_ID: u32 = 1_000;
+const AMOUNT: u32 = 1_000_000;
+
+/// Have `payee` request a payment from `payer`, due before block `expiry`.
+fn new_invoice<T: Config>(
+	payee: &T::AccountId,
+	payer: &T::AccountId,
+	expiry: T::BlockNumber,
+) -> InvoiceId
+where
+	AssetIdOf<T>: From<u32>,
+{
+	let invoice_id = Pallet::<T>::next_invoice_id();
+	Pallet::<T>::create_invoice(
+		RawOrigin::Signed(payee.clone()).into(),
+		Some(payer.clone()),
+		ASSET_ID.into(),
+		AMOUNT.into(),
+		T::Hashing::hash_of(&invoice_id),
+		expiry,
+	)
+	.unwrap();
+	invoice_id
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	create_invoice {
+		let payee: T::AccountId = whitelisted_caller();
+		let payer: T::AccountId = account("payer", 0, 0);
+		let memo = T::Hashing::hash_of(&payer);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+	}: _(RawOrigin::Signed(payee), Some(payer), ASSET_ID.into(), AMOUNT.into(), memo, expiry)
+	verify {
+		assert!(Pallet::<T>::invoice(0).is_some());
+	}
+
+	pay_invoice {
+		let payee: T::AccountId = account("payee", 0, 0);
+		let payer: T::AccountId = whitelisted_caller();
+		T::Assets::create(ASSET_ID.into(), payee.clone(), true, One::one())?;
+		T::Assets::mint_into(ASSET_ID.into(), &payer, AMOUNT.into())?;
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		let invoice_id = new_invoice::<T>(&payee, &payer, expiry);
+	}: _(RawOrigin::Signed(payer), invoice_id)
+	verify {
+		assert!(Pallet::<T>::invoice(invoice_id).is_none());
+	}
+
+	// Closing an expired invoice, the caller not being its payee.
+	close_invoice {
+		let payee: T::AccountId = account("payee", 0, 0);
+		let payer: T::AccountId = account("payer", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let expiry = frame_system::Pallet::<T>::block_number() + One::one();
+		let invoice_id = new_invoice::<T>(&payee, &payer, expiry);
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: _(RawOrigin::Signed(caller), invoice_id)
+	verify {
+		assert!(Pallet::<T>::invoice(invoice_id).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
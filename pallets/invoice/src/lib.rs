@@ -0,0 +1,213 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Invoices, settled by id.
+///
+/// A payee creates an invoice for some amount of an asset, tagged with the hash of an off-chain
+/// memo, possibly addressed to a single payer. Paying the invoice transfers the amount straight to
+/// the payee and emits an `InvoicePaid` event carrying the memo, so a merchant can reconcile its
+/// payments from its own invoices instead of scanning raw transfers.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::fungibles};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Zero;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets invoices are paid in.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next invoice will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_invoice_id)]
+	pub type NextInvoiceId<T> = StorageValue<_, InvoiceId, ValueQuery>;
+
+	/// The invoices that have been neither paid nor canceled.
+	#[pallet::storage]
+	#[pallet::getter(fn invoice)]
+	pub type Invoices<T: Config> = StorageMap<_, Twox64Concat, InvoiceId, InvoiceOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An invoice has been created.
+		InvoiceCreated {
+			invoice_id: InvoiceId,
+			payee: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			memo: T::Hash,
+		},
+		/// An invoice has been paid.
+		InvoicePaid {
+			invoice_id: InvoiceId,
+			payee: T::AccountId,
+			payer: T::AccountId,
+			amount: BalanceOf<T>,
+			memo: T::Hash,
+		},
+		/// An invoice has been canceled, or removed after expiring.
+		InvoiceClosed { invoice_id: InvoiceId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The invoice does not exist.
+		UnknownInvoice,
+		/// The amount must be non zero.
+		ZeroAmount,
+		/// The expiry block is already in the past.
+		ExpiryInThePast,
+		/// The invoice has expired.
+		Expired,
+		/// The caller is not allowed to pay or close this invoice.
+		NoPermission,
+		/// There is no invoice id left.
+		NoAvailableInvoiceId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request `amount` of `asset_id`, from `payer` only if given.
+		#[pallet::weight(T::WeightInfo::create_invoice())]
+		pub fn create_invoice(
+			origin: OriginFor<T>,
+			payer: Option<T::AccountId>,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			memo: T::Hash,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let payee = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ExpiryInThePast
+			);
+
+			let invoice_id = Self::next_invoice_id();
+			let next_id = invoice_id.checked_add(1).ok_or(Error::<T>::NoAvailableInvoiceId)?;
+
+			Invoices::<T>::insert(
+				invoice_id,
+				Invoice { payee: payee.clone(), payer, asset_id, amount, memo, expiry },
+			);
+			NextInvoiceId::<T>::put(next_id);
+
+			Self::deposit_event(Event::InvoiceCreated {
+				invoice_id,
+				payee,
+				asset_id,
+				amount,
+				memo,
+			});
+
+			Ok(())
+		}
+
+		/// Pay an invoice.
+		#[pallet::weight(T::WeightInfo::pay_invoice())]
+		pub fn pay_invoice(origin: OriginFor<T>, invoice_id: InvoiceId) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+
+			let invoice = Self::invoice(invoice_id).ok_or(Error::<T>::UnknownInvoice)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < invoice.expiry,
+				Error::<T>::Expired
+			);
+			if let Some(expected) = &invoice.payer {
+				ensure!(*expected == payer, Error::<T>::NoPermission);
+			}
+
+			T::Assets::transfer(invoice.asset_id, &payer, &invoice.payee, invoice.amount, false)?;
+			Invoices::<T>::remove(invoice_id);
+
+			Self::deposit_event(Event::InvoicePaid {
+				invoice_id,
+				payee: invoice.payee,
+				payer,
+				amount: invoice.amount,
+				memo: invoice.memo,
+			});
+
+			Ok(())
+		}
+
+		/// Close an invoice.
+		///
+		/// The payee can cancel an invoice at any time; anyone can close an expired invoice.
+		#[pallet::weight(T::WeightInfo::close_invoice())]
+		pub fn close_invoice(origin: OriginFor<T>, invoice_id: InvoiceId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let invoice = Self::invoice(invoice_id).ok_or(Error::<T>::UnknownInvoice)?;
+			ensure!(
+				who == invoice.payee ||
+					frame_system::Pallet::<T>::block_number() >= invoice.expiry,
+				Error::<T>::NoPermission
+			);
+			Invoices::<T>::remove(invoice_id);
+
+			Self::deposit_event(Event::InvoiceClosed { invoice_id });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let next_id = Self::next_invoice_id();
+			for (invoice_id, invoice) in Invoices::<T>::iter() {
+				ensure!(invoice_id < next_id, "invoice id not allocated");
+				ensure!(!invoice.amount.is_zero(), "invoice of a zero amount");
+			}
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,89 @@
+use super::mock::*;
+use crate::{Error, Event as InvoiceEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+const MEMO: H256 = H256::repeat_byte(7);
+
+fn create_invoice(payer: Option<u64>) {
+	assert_ok!(Invoice::create_invoice(Origin::signed(ALICE), payer, ASSET_ID, 100, MEMO, 10));
+}
+
+#[test]
+fn create_invoice_checks_its_terms() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Invoice::create_invoice(Origin::signed(ALICE), None, ASSET_ID, 0, MEMO, 10),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Invoice::create_invoice(Origin::signed(ALICE), None, ASSET_ID, 100, MEMO, 1),
+			Error::<Test>::ExpiryInThePast
+		);
+		create_invoice(None);
+
+		assert_eq!(Invoice::next_invoice_id(), 1);
+		System::assert_last_event(
+			InvoiceEvent::InvoiceCreated {
+				invoice_id: 0,
+				payee: ALICE,
+				asset_id: ASSET_ID,
+				amount: 100,
+				memo: MEMO,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn pay_invoice_transfers_to_the_payee() {
+	new_test_ext().execute_with(|| {
+		create_invoice(Some(BOB));
+
+		assert_noop!(
+			Invoice::pay_invoice(Origin::signed(CHARLIE), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Invoice::pay_invoice(Origin::signed(BOB), 0));
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_100);
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 900);
+		assert!(Invoice::invoice(0).is_none());
+		System::assert_last_event(
+			InvoiceEvent::InvoicePaid {
+				invoice_id: 0,
+				payee: ALICE,
+				payer: BOB,
+				amount: 100,
+				memo: MEMO,
+			}
+			.into(),
+		);
+		assert_noop!(Invoice::pay_invoice(Origin::signed(BOB), 0), Error::<Test>::UnknownInvoice);
+	})
+}
+
+#[test]
+fn expired_invoices_cannot_be_paid() {
+	new_test_ext().execute_with(|| {
+		create_invoice(None);
+
+		assert_noop!(Invoice::close_invoice(Origin::signed(BOB), 0), Error::<Test>::NoPermission);
+
+		System::set_block_number(10);
+		assert_noop!(Invoice::pay_invoice(Origin::signed(BOB), 0), Error::<Test>::Expired);
+		assert_ok!(Invoice::close_invoice(Origin::signed(BOB), 0));
+		System::assert_last_event(InvoiceEvent::InvoiceClosed { invoice_id: 0 }.into());
+	})
+}
+
+#[test]
+fn payee_can_cancel() {
+	new_test_ext().execute_with(|| {
+		create_invoice(None);
+
+		assert_ok!(Invoice::close_invoice(Origin::signed(ALICE), 0));
+		assert_noop!(Invoice::pay_invoice(Origin::signed(BOB), 0), Error::<Test>::UnknownInvoice);
+	})
+}
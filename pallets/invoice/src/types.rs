@@ -0,0 +1,34 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type InvoiceId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type InvoiceOf<T> = Invoice<
+	AccountIdOf<T>,
+	AssetIdOf<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// A request for payment.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Invoice<AccountId, AssetId, Balance, Hash, BlockNumber> {
+	/// The account to pay.
+	pub payee: AccountId,
+	/// The only account allowed to pay the invoice, if any.
+	pub payer: Option<AccountId>,
+	/// The asset to pay in.
+	pub asset_id: AssetId,
+	/// The amount to pay.
+	pub amount: Balance,
+	/// The hash of the off-chain memo, e.g. the order the invoice is for.
+	pub memo: Hash,
+	/// The block from which the invoice can no longer be paid.
+	pub expiry: BlockNumber,
+}
@@ -0,0 +1,93 @@
+//! Benchmarks of the kitties pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+
+/// Give `owner` a kitty of the first generation with the given DNA.
+fn insert_kitty<T: Config>(owner: &T::AccountId, dna: Dna) -> KittyId {
+	let kitty_id = Pallet::<T>::next_kitty_id();
+	KittiesOwned::<T>::mutate(owner, |owned| owned.try_push(kitty_id)).unwrap();
+	Kitties::<T>::insert(
+		kitty_id,
+		Kitty { dna, gender: Gender::of(&dna), generation: 0, owner: owner.clone(), price: None },
+	);
+	NextKittyId::<T>::put(kitty_id + 1);
+	kitty_id
+}
+
+/// Give `owner` `n` kitties.
+fn insert_kitties<T: Config>(owner: &T::AccountId, n: u32) {
+	for i in 0..n {
+		insert_kitty::<T>(owner, [i as u8; 16]);
+	}
+}
+
+fn price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance() * 100u32.into()
+}
+
+fn funded<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, price::<T>() * 100u32.into());
+}
+
+benchmarks! {
+	create_kitty {
+		let n in 0 .. T::MaxKittiesOwned::get() - 1;
+		let owner: T::AccountId = whitelisted_caller();
+		insert_kitties::<T>(&owner, n);
+	}: _(RawOrigin::Signed(owner.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::kitties_owned(&owner).len() as u32, n + 1);
+	}
+
+	breed_kitty {
+		let n in 0 .. T::MaxKittiesOwned::get() - 3;
+		let owner: T::AccountId = whitelisted_caller();
+		insert_kitties::<T>(&owner, n);
+		let male = insert_kitty::<T>(&owner, [0; 16]);
+		let female = insert_kitty::<T>(&owner, [1; 16]);
+	}: _(RawOrigin::Signed(owner.clone()), male, female)
+	verify {
+		assert_eq!(Pallet::<T>::kitties_owned(&owner).len() as u32, n + 3);
+	}
+
+	// The sender owns `n` kitties, the kitty being the last one, and the recipient one less than
+	// the maximum.
+	transfer {
+		let n in 1 .. T::MaxKittiesOwned::get();
+		let from: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("recipient", 0, 0);
+		insert_kitties::<T>(&from, n - 1);
+		let kitty_id = insert_kitty::<T>(&from, [0; 16]);
+		insert_kitties::<T>(&to, T::MaxKittiesOwned::get() - 1);
+	}: _(RawOrigin::Signed(from), to.clone(), kitty_id)
+	verify {
+		assert_eq!(Pallet::<T>::kitty(kitty_id).map(|kitty| kitty.owner), Some(to));
+	}
+
+	set_price {
+		let owner: T::AccountId = whitelisted_caller();
+		let kitty_id = insert_kitty::<T>(&owner, [0; 16]);
+	}: _(RawOrigin::Signed(owner), kitty_id, Some(price::<T>()))
+	verify {
+		assert_eq!(Pallet::<T>::kitty(kitty_id).and_then(|kitty| kitty.price), Some(price::<T>()));
+	}
+
+	buy_kitty {
+		let n in 1 .. T::MaxKittiesOwned::get();
+		let seller: T::AccountId = account("seller", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		insert_kitties::<T>(&seller, n - 1);
+		let kitty_id = insert_kitty::<T>(&seller, [0; 16]);
+		Pallet::<T>::set_price(RawOrigin::Signed(seller).into(), kitty_id, Some(price::<T>()))?;
+		insert_kitties::<T>(&buyer, T::MaxKittiesOwned::get() - 1);
+		funded::<T>(&buyer);
+	}: _(RawOrigin::Signed(buyer.clone()), kitty_id, price::<T>())
+	verify {
+		assert_eq!(Pallet::<T>::kitty(kitty_id).map(|kitty| kitty.owner), Some(buyer));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
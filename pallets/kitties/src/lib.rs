@@ -0,0 +1,380 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Collectible kitties, the classic Substrate exercise.
+///
+/// Each kitty carries a DNA drawn from the runtime randomness source when it is created. Two
+/// kitties of different genders owned by the same account can be bred: every bit of the child's DNA
+/// is randomly picked from one of its parents, and the child is one generation younger than its
+/// youngest parent.
+///
+/// Owners can put a price on their kitties, for anyone to buy them at. Buyers are charged the
+/// `TradeFee` on the price, out of what the seller receives, and the fee is reported to
+/// `OnFeeCharged`, so that referrers get their share.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement, Randomness},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::{
+		fees::charge_native_fee, FeeKind, OnNativeFeeCharged, Pausable, PauseGuard,
+	};
+	use sp_runtime::{traits::TrailingZeroInput, Perbill};
+	use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency kitties are traded in.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The source of randomness for kitties' DNA: the randomness collective flip pallet, or a
+		/// deterministic source in tests.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// The maximum number of kitties an account can own.
+		#[pallet::constant]
+		type MaxKittiesOwned: Get<u32>;
+
+		/// The share of the price charged as a fee.
+		#[pallet::constant]
+		type TradeFee: Get<Perbill>;
+
+		/// The account collecting the fees, e.g. the treasury.
+		type FeeCollector: Get<Self::AccountId>;
+
+		/// Shares the fees with the referrers of the buyers paying them.
+		type OnFeeCharged: OnNativeFeeCharged<Self::AccountId, BalanceOf<Self>>;
+
+		/// The registry pausing the calls of the pallet.
+		type Pauses: Pausable;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next kitty will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_kitty_id)]
+	pub type NextKittyId<T> = StorageValue<_, KittyId, ValueQuery>;
+
+	/// The kitties.
+	#[pallet::storage]
+	#[pallet::getter(fn kitty)]
+	pub type Kitties<T: Config> = StorageMap<_, Twox64Concat, KittyId, KittyOf<T>>;
+
+	/// The kitties owned by each account.
+	#[pallet::storage]
+	#[pallet::getter(fn kitties_owned)]
+	pub type KittiesOwned<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<KittyId, T::MaxKittiesOwned>,
+		ValueQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The kitties to create, with their owner and DNA.
+		pub kitties: Vec<(T::AccountId, Dna)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { kitties: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (owner, dna) in &self.kitties {
+				Pallet::<T>::mint(owner.clone(), *dna, 0).expect("too many genesis kitties");
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A kitty has been created, or bred.
+		Created { kitty_id: KittyId, owner: T::AccountId, dna: Dna, generation: u32 },
+		/// A kitty has been transferred.
+		Transferred { kitty_id: KittyId, from: T::AccountId, to: T::AccountId },
+		/// The price of a kitty has been set, or removed.
+		PriceSet { kitty_id: KittyId, price: Option<BalanceOf<T>> },
+		/// A kitty has been sold.
+		Sold { kitty_id: KittyId, seller: T::AccountId, buyer: T::AccountId, price: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The kitty does not exist.
+		UnknownKitty,
+		/// The caller does not own the kitty.
+		NotOwner,
+		/// The account owns too many kitties.
+		TooManyOwned,
+		/// Kitties cannot be transferred to, or bought by, their owner.
+		TransferToSelf,
+		/// A kitty cannot be bred with itself.
+		SameParent,
+		/// Only a male and a female can be bred.
+		SameGender,
+		/// The kitty is not for sale.
+		NotForSale,
+		/// The bid is lower than the kitty's price.
+		BidTooLow,
+		/// There is no kitty id left.
+		NoAvailableKittyId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a kitty with random DNA.
+		#[pallet::weight(T::WeightInfo::create_kitty(T::MaxKittiesOwned::get()))]
+		pub fn create_kitty(origin: OriginFor<T>) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			let owner = ensure_signed(origin)?;
+
+			let dna = Self::random_dna(&owner);
+			Self::mint(owner, dna, 0)?;
+
+			Ok(())
+		}
+
+		/// Breed two kitties of the caller into a new one.
+		#[pallet::weight(T::WeightInfo::breed_kitty(T::MaxKittiesOwned::get()))]
+		pub fn breed_kitty(
+			origin: OriginFor<T>,
+			parent_1: KittyId,
+			parent_2: KittyId,
+		) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			let owner = ensure_signed(origin)?;
+
+			ensure!(parent_1 != parent_2, Error::<T>::SameParent);
+			let parent_1 = Self::owned_kitty(parent_1, &owner)?;
+			let parent_2 = Self::owned_kitty(parent_2, &owner)?;
+			ensure!(parent_1.gender != parent_2.gender, Error::<T>::SameGender);
+
+			let dna = Self::breed_dna(&parent_1.dna, &parent_2.dna, &Self::random_dna(&owner));
+			let generation = parent_1.generation.max(parent_2.generation).saturating_add(1);
+			Self::mint(owner, dna, generation)?;
+
+			Ok(())
+		}
+
+		/// Give a kitty to another account.
+		#[pallet::weight(T::WeightInfo::transfer(T::MaxKittiesOwned::get()))]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			kitty_id: KittyId,
+		) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			let from = ensure_signed(origin)?;
+
+			let kitty = Self::owned_kitty(kitty_id, &from)?;
+			Self::do_transfer(kitty_id, kitty, to)?;
+
+			Ok(())
+		}
+
+		/// Put a kitty of the caller up for sale at `price`, or take it off sale with `None`.
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			kitty_id: KittyId,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			let owner = ensure_signed(origin)?;
+
+			let mut kitty = Self::owned_kitty(kitty_id, &owner)?;
+			kitty.price = price;
+			Kitties::<T>::insert(kitty_id, kitty);
+
+			Self::deposit_event(Event::PriceSet { kitty_id, price });
+
+			Ok(())
+		}
+
+		/// Buy a kitty for sale, paying its price as long as it does not exceed `max_price`.
+		///
+		/// The bound protects the buyer from a seller raising the price in the meantime.
+		#[pallet::weight(T::WeightInfo::buy_kitty(T::MaxKittiesOwned::get()))]
+		#[transactional]
+		pub fn buy_kitty(
+			origin: OriginFor<T>,
+			kitty_id: KittyId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			let buyer = ensure_signed(origin)?;
+
+			let kitty = Self::kitty(kitty_id).ok_or(Error::<T>::UnknownKitty)?;
+			let price = kitty.price.ok_or(Error::<T>::NotForSale)?;
+			ensure!(price <= max_price, Error::<T>::BidTooLow);
+			let seller = kitty.owner.clone();
+
+			Self::do_transfer(kitty_id, kitty, buyer.clone())?;
+			let fee = T::TradeFee::get().mul_floor(price);
+			charge_native_fee::<_, T::Currency, T::OnFeeCharged>(
+				FeeKind::Marketplace,
+				&buyer,
+				fee,
+				&T::FeeCollector::get(),
+			)?;
+			T::Currency::transfer(&buyer, &seller, price - fee, ExistenceRequirement::KeepAlive)?;
+
+			Self::deposit_event(Event::Sold { kitty_id, seller, buyer, price });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> PauseGuard for Pallet<T> {
+		type Pauses = T::Pauses;
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// A kitty, as long as it is owned by `owner`.
+		fn owned_kitty(
+			kitty_id: KittyId,
+			owner: &T::AccountId,
+		) -> Result<KittyOf<T>, DispatchError> {
+			let kitty = Self::kitty(kitty_id).ok_or(Error::<T>::UnknownKitty)?;
+			ensure!(kitty.owner == *owner, Error::<T>::NotOwner);
+			Ok(kitty)
+		}
+
+		/// Fresh DNA from the randomness source.
+		///
+		/// The next kitty id is part of the subject, so that kitties created in the same block get
+		/// different DNA.
+		fn random_dna(owner: &T::AccountId) -> Dna {
+			let subject = (owner, Self::next_kitty_id()).encode();
+			let (seed, _) = T::Randomness::random(&subject);
+			Dna::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+				.expect("input is padded with zeroes; qed")
+		}
+
+		/// Combine the DNA of two parents, picking each bit from the first one when the matching
+		/// bit of `selector` is set and from the second one otherwise.
+		pub fn breed_dna(dna_1: &Dna, dna_2: &Dna, selector: &Dna) -> Dna {
+			let mut dna = Dna::default();
+			for (i, gene) in dna.iter_mut().enumerate() {
+				*gene = (dna_1[i] & selector[i]) | (dna_2[i] & !selector[i]);
+			}
+			dna
+		}
+
+		fn mint(owner: T::AccountId, dna: Dna, generation: u32) -> DispatchResult {
+			let kitty_id = Self::next_kitty_id();
+			let next_id = kitty_id.checked_add(1).ok_or(Error::<T>::NoAvailableKittyId)?;
+
+			KittiesOwned::<T>::try_mutate(&owner, |owned| owned.try_push(kitty_id))
+				.map_err(|_| Error::<T>::TooManyOwned)?;
+			let kitty = Kitty {
+				dna,
+				gender: Gender::of(&dna),
+				generation,
+				owner: owner.clone(),
+				price: None,
+			};
+			Kitties::<T>::insert(kitty_id, kitty);
+			NextKittyId::<T>::put(next_id);
+
+			Self::deposit_event(Event::Created { kitty_id, owner, dna, generation });
+
+			Ok(())
+		}
+
+		/// Move a kitty to a new owner, taking it off sale.
+		fn do_transfer(
+			kitty_id: KittyId,
+			mut kitty: KittyOf<T>,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let from = kitty.owner.clone();
+			ensure!(from != to, Error::<T>::TransferToSelf);
+
+			KittiesOwned::<T>::try_mutate(&to, |owned| owned.try_push(kitty_id))
+				.map_err(|_| Error::<T>::TooManyOwned)?;
+			KittiesOwned::<T>::mutate(&from, |owned| owned.retain(|id| *id != kitty_id));
+			kitty.owner = to.clone();
+			kitty.price = None;
+			Kitties::<T>::insert(kitty_id, kitty);
+
+			Self::deposit_event(Event::Transferred { kitty_id, from, to });
+
+			Ok(())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let next_id = Self::next_kitty_id();
+			for (kitty_id, kitty) in Kitties::<T>::iter() {
+				ensure!(kitty_id < next_id, "kitty id not allocated");
+				ensure!(kitty.gender == Gender::of(&kitty.dna), "gender not matching the dna");
+				ensure!(
+					Self::kitties_owned(&kitty.owner).contains(&kitty_id),
+					"kitty missing from its owner's kitties"
+				);
+			}
+			for (owner, owned) in KittiesOwned::<T>::iter() {
+				ensure!(
+					owned
+						.iter()
+						.all(|id| Self::kitty(id).map_or(false, |kitty| kitty.owner == owner)),
+					"owned kitty of another account"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,128 @@
+use super::mock::*;
+use crate::{Error, Event as KittiesEvent, Gender};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Perbill;
+use test_utils::ExtBuilder;
+
+#[test]
+fn create_kitty_draws_random_dna() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+
+		let kitty = Kitties::kitty(1).unwrap();
+		assert_eq!(kitty.dna, [1; 16]);
+		assert_eq!(kitty.gender, Gender::Female);
+		assert_eq!(kitty.generation, 0);
+		assert_eq!(kitty.owner, ALICE);
+		assert_eq!(Kitties::kitties_owned(ALICE).into_inner(), vec![0, 1]);
+		System::assert_last_event(
+			KittiesEvent::Created { kitty_id: 1, owner: ALICE, dna: [1; 16], generation: 0 }
+				.into(),
+		);
+
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+		assert_noop!(Kitties::create_kitty(Origin::signed(ALICE)), Error::<Test>::TooManyOwned);
+	})
+}
+
+#[test]
+fn genesis_kitties_are_created() {
+	ExtBuilder::<Test>::default()
+		.genesis(crate::GenesisConfig::<Test> { kitties: vec![(ALICE, [2; 16]), (BOB, [3; 16])] })
+		.build()
+		.execute_with(|| {
+			let kitty = Kitties::kitty(1).unwrap();
+			assert_eq!(kitty.dna, [3; 16]);
+			assert_eq!(kitty.gender, Gender::Female);
+			assert_eq!(kitty.owner, BOB);
+			assert_eq!(Kitties::kitties_owned(ALICE).into_inner(), vec![0]);
+			assert_eq!(Kitties::next_kitty_id(), 2);
+		})
+}
+
+#[test]
+fn breeding_mixes_parents_dna() {
+	assert_eq!(Kitties::breed_dna(&[0b1100; 16], &[0b1010; 16], &[0b0110; 16]), [0b1100; 16]);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+		assert_ok!(Kitties::create_kitty(Origin::signed(BOB)));
+
+		assert_noop!(Kitties::breed_kitty(Origin::signed(ALICE), 0, 0), Error::<Test>::SameParent);
+		assert_noop!(Kitties::breed_kitty(Origin::signed(ALICE), 0, 2), Error::<Test>::NotOwner);
+
+		// The selector is the child id, 3, repeated.
+		assert_ok!(Kitties::breed_kitty(Origin::signed(ALICE), 1, 0));
+		let child = Kitties::kitty(3).unwrap();
+		assert_eq!(child.dna, [0b01; 16]);
+		assert_eq!(child.generation, 1);
+		assert_eq!(child.owner, ALICE);
+
+		// Kitty 1 and its child are both females.
+		assert_noop!(Kitties::breed_kitty(Origin::signed(ALICE), 1, 3), Error::<Test>::SameGender);
+	})
+}
+
+#[test]
+fn transfer_moves_ownership() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+		assert_ok!(Kitties::set_price(Origin::signed(ALICE), 0, Some(10)));
+
+		assert_noop!(Kitties::transfer(Origin::signed(BOB), BOB, 0), Error::<Test>::NotOwner);
+		assert_noop!(
+			Kitties::transfer(Origin::signed(ALICE), ALICE, 0),
+			Error::<Test>::TransferToSelf
+		);
+		assert_ok!(Kitties::transfer(Origin::signed(ALICE), BOB, 0));
+
+		let kitty = Kitties::kitty(0).unwrap();
+		assert_eq!(kitty.owner, BOB);
+		assert_eq!(kitty.price, None);
+		assert!(Kitties::kitties_owned(ALICE).is_empty());
+		assert_eq!(Kitties::kitties_owned(BOB).into_inner(), vec![0]);
+		System::assert_last_event(
+			KittiesEvent::Transferred { kitty_id: 0, from: ALICE, to: BOB }.into(),
+		);
+	})
+}
+
+#[test]
+fn buy_kitty_charges_the_fee_out_of_the_price() {
+	new_test_ext().execute_with(|| {
+		TradeFee::set(Perbill::from_percent(10));
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+		assert_ok!(Kitties::set_price(Origin::signed(ALICE), 0, Some(30)));
+
+		assert_ok!(Kitties::buy_kitty(Origin::signed(BOB), 0, 30));
+
+		assert_eq!(Balances::free_balance(ALICE), 127);
+		assert_eq!(Balances::free_balance(BOB), 70);
+		assert_eq!(Balances::free_balance(FEE_COLLECTOR), 3);
+	})
+}
+
+#[test]
+fn buy_kitty_pays_the_seller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+		assert_noop!(Kitties::buy_kitty(Origin::signed(BOB), 0, 50), Error::<Test>::NotForSale);
+
+		assert_noop!(
+			Kitties::set_price(Origin::signed(BOB), 0, Some(30)),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(Kitties::set_price(Origin::signed(ALICE), 0, Some(30)));
+		assert_noop!(Kitties::buy_kitty(Origin::signed(BOB), 0, 20), Error::<Test>::BidTooLow);
+
+		assert_ok!(Kitties::buy_kitty(Origin::signed(BOB), 0, 50));
+		assert_eq!(Balances::free_balance(ALICE), 130);
+		assert_eq!(Balances::free_balance(BOB), 70);
+		assert_eq!(Kitties::kitty(0).unwrap().owner, BOB);
+		System::assert_last_event(
+			KittiesEvent::Sold { kitty_id: 0, seller: ALICE, buyer: BOB, price: 30 }.into(),
+		);
+	})
+}
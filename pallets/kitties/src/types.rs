@@ -0,0 +1,45 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Currency;
+use scale_info::TypeInfo;
+
+pub type KittyId = u32;
+
+/// The genes of a kitty.
+pub type Dna = [u8; 16];
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type KittyOf<T> = Kitty<AccountIdOf<T>, BalanceOf<T>>;
+
+/// The gender of a kitty: only two kitties of different genders can breed.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum Gender {
+	Male,
+	Female,
+}
+
+impl Gender {
+	/// The gender encoded in some DNA.
+	pub fn of(dna: &Dna) -> Self {
+		if dna[0] % 2 == 0 {
+			Gender::Male
+		} else {
+			Gender::Female
+		}
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Kitty<AccountId, Balance> {
+	/// The genes of the kitty, which also determine its gender.
+	pub dna: Dna,
+	/// The gender of the kitty, derived from its DNA.
+	pub gender: Gender,
+	/// The number of generations since the kitty's oldest ancestor was created.
+	pub generation: u32,
+	/// The account owning the kitty, the only one that can transfer, price or breed it.
+	pub owner: AccountId,
+	/// The price the kitty can be bought at, if it is for sale.
+	pub price: Option<Balance>,
+}
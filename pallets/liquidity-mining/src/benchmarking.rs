@@ -0,0 +1,109 @@
+//! Benchmarks of the liquidity-mining pallet.
+//!
+//! Staking, unstaking and claiming are measured with rewards pending, so that they are paid out.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::tokens::fungibles::{Create, Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+
+const STAKE_ASSET: u32 = 1_000;
+const REWARD_ASSET: u32 = 1_001;
+const AMOUNT: u32 = 1_000_000;
+
+/// Create both assets of the farm, funding `holders` with each.
+fn create_assets<T: Config>(holders: &[&T::AccountId])
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	let owner: T::AccountId = account("owner", 0, 0);
+	for asset_id in [STAKE_ASSET, REWARD_ASSET] {
+		T::Assets::create(asset_id.into(), owner.clone(), true, One::one()).unwrap();
+		for who in holders {
+			T::Assets::mint_into(asset_id.into(), who, AMOUNT.into()).unwrap();
+		}
+	}
+}
+
+/// Create a funded farm, where `staker` staked 10 blocks ago.
+fn new_farm<T: Config>(staker: &T::AccountId) -> FarmId
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	let funder: T::AccountId = account("funder", 0, 0);
+	create_assets::<T>(&[&funder, staker]);
+	let farm_id = Pallet::<T>::next_farm_id();
+	Pallet::<T>::create_farm(
+		T::FarmOrigin::successful_origin(),
+		STAKE_ASSET.into(),
+		REWARD_ASSET.into(),
+		(AMOUNT / 100).into(),
+	)
+	.unwrap();
+	Pallet::<T>::fund_farm(RawOrigin::Signed(funder).into(), farm_id, AMOUNT.into()).unwrap();
+	Pallet::<T>::stake(RawOrigin::Signed(staker.clone()).into(), farm_id, (AMOUNT / 2).into())
+		.unwrap();
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now + 10u32.into());
+	farm_id
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	create_farm {
+		let origin = T::FarmOrigin::successful_origin();
+	}: _<T::Origin>(origin, STAKE_ASSET.into(), REWARD_ASSET.into(), One::one())
+	verify {
+		assert!(Pallet::<T>::farm(0).is_some());
+	}
+
+	fund_farm {
+		let funder: T::AccountId = whitelisted_caller();
+		let staker: T::AccountId = account("staker", 0, 0);
+		let farm_id = new_farm::<T>(&staker);
+		T::Assets::mint_into(REWARD_ASSET.into(), &funder, AMOUNT.into())?;
+	}: _(RawOrigin::Signed(funder), farm_id, AMOUNT.into())
+	verify {
+		assert!(Pallet::<T>::farm(farm_id).is_some());
+	}
+
+	stake {
+		let staker: T::AccountId = whitelisted_caller();
+		let farm_id = new_farm::<T>(&staker);
+	}: _(RawOrigin::Signed(staker.clone()), farm_id, (AMOUNT / 2).into())
+	verify {
+		assert_eq!(
+			Pallet::<T>::stake_of(farm_id, &staker).map(|stake| stake.amount),
+			Some(AMOUNT.into())
+		);
+	}
+
+	// Unstaking part of the stake, which is kept.
+	unstake {
+		let staker: T::AccountId = whitelisted_caller();
+		let farm_id = new_farm::<T>(&staker);
+	}: _(RawOrigin::Signed(staker.clone()), farm_id, (AMOUNT / 4).into())
+	verify {
+		assert_eq!(
+			Pallet::<T>::stake_of(farm_id, &staker).map(|stake| stake.amount),
+			Some((AMOUNT / 4).into())
+		);
+	}
+
+	claim {
+		let staker: T::AccountId = whitelisted_caller();
+		let farm_id = new_farm::<T>(&staker);
+	}: _(RawOrigin::Signed(staker.clone()), farm_id)
+	verify {
+		assert!(Pallet::<T>::pending_rewards(farm_id, &staker).is_zero());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
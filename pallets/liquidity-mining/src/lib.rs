@@ -0,0 +1,337 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Distribute a reward asset per block across the stakers of a farm asset, e.g. LP tokens.
+///
+/// Each farm keeps an accumulator of the rewards earned by one staked unit since the farm was
+/// created. A staker records the accumulator value when their stake changes (their "reward debt"),
+/// so their pending rewards are always `stake * accumulator - reward_debt`: staking, unstaking and
+/// claiming are O(1) no matter how many stakers the farm has.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::fungibles, transactional, PalletId};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Saturating, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets staked and distributed.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The origin allowed to create farms.
+		type FarmOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The pallet id, used to derive the account holding stakes and rewards.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next farm will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_farm_id)]
+	pub type NextFarmId<T> = StorageValue<_, FarmId, ValueQuery>;
+
+	/// The farms.
+	#[pallet::storage]
+	#[pallet::getter(fn farm)]
+	pub type Farms<T: Config> = StorageMap<_, Twox64Concat, FarmId, FarmOf<T>>;
+
+	/// The stake of each account in each farm.
+	#[pallet::storage]
+	#[pallet::getter(fn stake_of)]
+	pub type Stakes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, FarmId, Blake2_128Concat, T::AccountId, StakeOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A farm has been created.
+		FarmCreated {
+			farm_id: FarmId,
+			stake_asset: AssetIdOf<T>,
+			reward_asset: AssetIdOf<T>,
+			reward_per_block: BalanceOf<T>,
+		},
+		/// Rewards have been added to a farm.
+		FarmFunded { farm_id: FarmId, funder: T::AccountId, amount: BalanceOf<T> },
+		/// An account staked in a farm.
+		Staked { farm_id: FarmId, who: T::AccountId, amount: BalanceOf<T> },
+		/// An account unstaked from a farm.
+		Unstaked { farm_id: FarmId, who: T::AccountId, amount: BalanceOf<T> },
+		/// An account claimed its pending rewards.
+		RewardsClaimed { farm_id: FarmId, who: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The farm does not exist.
+		UnknownFarm,
+		/// The amount must be non zero.
+		ZeroAmount,
+		/// The account does not stake that much.
+		InsufficientStake,
+		/// There is no farm id left.
+		NoAvailableFarmId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a farm distributing `reward_per_block` of `reward_asset` to `stake_asset`
+		/// stakers.
+		///
+		/// The farm distributes nothing until it is funded.
+		#[pallet::weight(T::WeightInfo::create_farm())]
+		pub fn create_farm(
+			origin: OriginFor<T>,
+			stake_asset: AssetIdOf<T>,
+			reward_asset: AssetIdOf<T>,
+			reward_per_block: BalanceOf<T>,
+		) -> DispatchResult {
+			T::FarmOrigin::ensure_origin(origin)?;
+
+			let farm_id = Self::next_farm_id();
+			let next_id = farm_id.checked_add(1).ok_or(Error::<T>::NoAvailableFarmId)?;
+
+			Farms::<T>::insert(
+				farm_id,
+				Farm {
+					stake_asset,
+					reward_asset,
+					reward_per_block,
+					rewards_left: Zero::zero(),
+					total_staked: Zero::zero(),
+					reward_per_share: 0,
+					last_update: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			NextFarmId::<T>::put(next_id);
+
+			Self::deposit_event(Event::FarmCreated {
+				farm_id,
+				stake_asset,
+				reward_asset,
+				reward_per_block,
+			});
+
+			Ok(())
+		}
+
+		/// Add `amount` of the reward asset to the rewards a farm can distribute.
+		#[pallet::weight(T::WeightInfo::fund_farm())]
+		pub fn fund_farm(
+			origin: OriginFor<T>,
+			farm_id: FarmId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let funder = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut farm = Self::updated_farm(farm_id)?;
+
+			T::Assets::transfer(farm.reward_asset, &funder, &Self::account_id(), amount, false)?;
+			farm.rewards_left = farm.rewards_left.saturating_add(amount);
+			Farms::<T>::insert(farm_id, farm);
+
+			Self::deposit_event(Event::FarmFunded { farm_id, funder, amount });
+
+			Ok(())
+		}
+
+		/// Stake `amount` of the farm asset, claiming the pending rewards.
+		#[pallet::weight(T::WeightInfo::stake())]
+		#[transactional]
+		pub fn stake(origin: OriginFor<T>, farm_id: FarmId, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut farm = Self::updated_farm(farm_id)?;
+			let mut stake = Self::stake_of(farm_id, &who).unwrap_or_default();
+
+			T::Assets::transfer(farm.stake_asset, &who, &Self::account_id(), amount, false)?;
+			Self::pay_rewards(farm_id, &farm, &stake, &who)?;
+
+			stake.amount = stake.amount.saturating_add(amount);
+			stake.reward_debt = Self::accrued(&farm, stake.amount);
+			farm.total_staked = farm.total_staked.saturating_add(amount);
+			Stakes::<T>::insert(farm_id, &who, stake);
+			Farms::<T>::insert(farm_id, farm);
+
+			Self::deposit_event(Event::Staked { farm_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Unstake `amount` of the farm asset, claiming the pending rewards.
+		#[pallet::weight(T::WeightInfo::unstake())]
+		#[transactional]
+		pub fn unstake(
+			origin: OriginFor<T>,
+			farm_id: FarmId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut farm = Self::updated_farm(farm_id)?;
+			let mut stake = Self::stake_of(farm_id, &who).unwrap_or_default();
+			ensure!(stake.amount >= amount, Error::<T>::InsufficientStake);
+
+			Self::pay_rewards(farm_id, &farm, &stake, &who)?;
+			T::Assets::transfer(farm.stake_asset, &Self::account_id(), &who, amount, false)?;
+
+			stake.amount -= amount;
+			stake.reward_debt = Self::accrued(&farm, stake.amount);
+			farm.total_staked = farm.total_staked.saturating_sub(amount);
+			if stake.amount.is_zero() {
+				Stakes::<T>::remove(farm_id, &who);
+			} else {
+				Stakes::<T>::insert(farm_id, &who, stake);
+			}
+			Farms::<T>::insert(farm_id, farm);
+
+			Self::deposit_event(Event::Unstaked { farm_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Claim the pending rewards of the caller in a farm.
+		#[pallet::weight(T::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>, farm_id: FarmId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let farm = Self::updated_farm(farm_id)?;
+			let mut stake = Self::stake_of(farm_id, &who).ok_or(Error::<T>::InsufficientStake)?;
+
+			Self::pay_rewards(farm_id, &farm, &stake, &who)?;
+
+			stake.reward_debt = Self::accrued(&farm, stake.amount);
+			Stakes::<T>::insert(farm_id, &who, stake);
+			Farms::<T>::insert(farm_id, farm);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding stakes and rewards.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// The rewards `who` could claim from a farm right now.
+		pub fn pending_rewards(farm_id: FarmId, who: &T::AccountId) -> BalanceOf<T> {
+			match (Self::updated_farm(farm_id), Self::stake_of(farm_id, who)) {
+				(Ok(farm), Some(stake)) => Self::pending(&farm, &stake),
+				_ => Zero::zero(),
+			}
+		}
+
+		/// A farm with its accumulator brought up to the current block.
+		///
+		/// Rewards are emitted only while the farm has stakers and rewards left.
+		fn updated_farm(farm_id: FarmId) -> Result<FarmOf<T>, DispatchError> {
+			let mut farm = Self::farm(farm_id).ok_or(Error::<T>::UnknownFarm)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			if !farm.total_staked.is_zero() {
+				let blocks: u128 = now.saturating_sub(farm.last_update).saturated_into();
+				let reward_per_block: u128 = farm.reward_per_block.saturated_into();
+				let rewards_left: u128 = farm.rewards_left.saturated_into();
+				let emitted = reward_per_block.saturating_mul(blocks).min(rewards_left);
+				let total_staked: u128 = farm.total_staked.saturated_into();
+
+				farm.reward_per_share = farm.reward_per_share.saturating_add(
+					emitted.saturating_mul(REWARD_PER_SHARE_PRECISION) / total_staked,
+				);
+				farm.rewards_left = farm.rewards_left.saturating_sub(emitted.saturated_into());
+			}
+			farm.last_update = now;
+
+			Ok(farm)
+		}
+
+		/// The rewards accrued by `amount` staked units since the creation of the farm.
+		fn accrued(farm: &FarmOf<T>, amount: BalanceOf<T>) -> u128 {
+			let amount: u128 = amount.saturated_into();
+			amount.saturating_mul(farm.reward_per_share) / REWARD_PER_SHARE_PRECISION
+		}
+
+		fn pending(farm: &FarmOf<T>, stake: &StakeOf<T>) -> BalanceOf<T> {
+			Self::accrued(farm, stake.amount).saturating_sub(stake.reward_debt).saturated_into()
+		}
+
+		fn pay_rewards(
+			farm_id: FarmId,
+			farm: &FarmOf<T>,
+			stake: &StakeOf<T>,
+			who: &T::AccountId,
+		) -> DispatchResult {
+			let amount = Self::pending(farm, stake);
+			if !amount.is_zero() {
+				T::Assets::transfer(farm.reward_asset, &Self::account_id(), who, amount, false)?;
+				Self::deposit_event(Event::RewardsClaimed { farm_id, who: who.clone(), amount });
+			}
+			Ok(())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let next_id = Self::next_farm_id();
+			for (farm_id, farm) in Farms::<T>::iter() {
+				ensure!(farm_id < next_id, "farm id not allocated");
+				let staked = Stakes::<T>::iter_prefix_values(farm_id)
+					.fold(BalanceOf::<T>::zero(), |total, stake| {
+						total.saturating_add(stake.amount)
+					});
+				ensure!(staked == farm.total_staked, "total staked not matching the stakes");
+			}
+			ensure!(
+				Stakes::<T>::iter_keys().all(|(farm_id, _)| Farms::<T>::contains_key(farm_id)),
+				"stake in an unknown farm"
+			);
+
+			Ok(())
+		}
+	}
+}
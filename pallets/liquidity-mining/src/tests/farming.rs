@@ -0,0 +1,83 @@
+use super::mock::*;
+use crate::{Error, Event as MiningEvent};
+use frame_support::{assert_noop, assert_ok};
+
+fn create_funded_farm() {
+	assert_ok!(LiquidityMining::create_farm(Origin::root(), LP_TOKEN, REWARD, 10));
+	assert_ok!(LiquidityMining::fund_farm(Origin::signed(ALICE), 0, 500));
+}
+
+#[test]
+fn create_farm_requires_the_farm_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::create_farm(Origin::signed(ALICE), LP_TOKEN, REWARD, 10),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn single_staker_earns_all_rewards() {
+	new_test_ext().execute_with(|| {
+		create_funded_farm();
+		assert_ok!(LiquidityMining::stake(Origin::signed(BOB), 0, 100));
+
+		System::set_block_number(4);
+		assert_eq!(LiquidityMining::pending_rewards(0, &BOB), 30);
+
+		assert_ok!(LiquidityMining::claim(Origin::signed(BOB), 0));
+		assert_eq!(Assets::balance(REWARD, BOB), 1_030);
+		assert_eq!(LiquidityMining::pending_rewards(0, &BOB), 0);
+		System::assert_last_event(
+			MiningEvent::RewardsClaimed { farm_id: 0, who: BOB, amount: 30 }.into(),
+		);
+	})
+}
+
+#[test]
+fn rewards_are_shared_proportionally() {
+	new_test_ext().execute_with(|| {
+		create_funded_farm();
+		assert_ok!(LiquidityMining::stake(Origin::signed(ALICE), 0, 100));
+
+		System::set_block_number(3);
+		assert_ok!(LiquidityMining::stake(Origin::signed(BOB), 0, 300));
+
+		System::set_block_number(5);
+		// 2 blocks alone, then 2 blocks with a quarter of the stake.
+		assert_eq!(LiquidityMining::pending_rewards(0, &ALICE), 25);
+		assert_eq!(LiquidityMining::pending_rewards(0, &BOB), 15);
+	})
+}
+
+#[test]
+fn emission_stops_when_rewards_run_out() {
+	new_test_ext().execute_with(|| {
+		create_funded_farm();
+		assert_ok!(LiquidityMining::stake(Origin::signed(BOB), 0, 100));
+
+		System::set_block_number(200);
+		assert_eq!(LiquidityMining::pending_rewards(0, &BOB), 500);
+	})
+}
+
+#[test]
+fn unstake_returns_stake_and_rewards() {
+	new_test_ext().execute_with(|| {
+		create_funded_farm();
+		assert_ok!(LiquidityMining::stake(Origin::signed(BOB), 0, 100));
+
+		System::set_block_number(3);
+		assert_noop!(
+			LiquidityMining::unstake(Origin::signed(BOB), 0, 101),
+			Error::<Test>::InsufficientStake
+		);
+		assert_ok!(LiquidityMining::unstake(Origin::signed(BOB), 0, 100));
+
+		assert_eq!(Assets::balance(LP_TOKEN, BOB), 1_000);
+		assert_eq!(Assets::balance(REWARD, BOB), 1_020);
+		assert!(LiquidityMining::stake_of(0, BOB).is_none());
+		assert_eq!(LiquidityMining::farm(0).unwrap().total_staked, 0);
+	})
+}
@@ -0,0 +1,44 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type FarmId = u32;
+
+/// The fixed-point precision of `Farm::reward_per_share`.
+pub const REWARD_PER_SHARE_PRECISION: u128 = 1_000_000_000_000;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type FarmOf<T> = Farm<AssetIdOf<T>, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub type StakeOf<T> = Stake<BalanceOf<T>>;
+
+/// A farm rewarding the stakers of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Farm<AssetId, Balance, BlockNumber> {
+	/// The asset staked in the farm.
+	pub stake_asset: AssetId,
+	/// The asset rewards are paid in.
+	pub reward_asset: AssetId,
+	/// The rewards emitted each block, shared between all stakers.
+	pub reward_per_block: Balance,
+	/// The funded rewards not emitted yet.
+	pub rewards_left: Balance,
+	/// The total amount staked in the farm.
+	pub total_staked: Balance,
+	/// The rewards emitted per staked unit since the creation of the farm, scaled by
+	/// `REWARD_PER_SHARE_PRECISION`.
+	pub reward_per_share: u128,
+	/// The block `reward_per_share` was last updated at.
+	pub last_update: BlockNumber,
+}
+
+/// The stake of an account in a farm.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Stake<Balance> {
+	/// The staked amount.
+	pub amount: Balance,
+	/// The rewards accrued by `amount` before it was last changed, already paid or forfeited.
+	pub reward_debt: u128,
+}
@@ -0,0 +1,122 @@
+//! Benchmarks of the loyalty pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	sp_std::prelude::*,
+	traits::{tokens::nonfungibles::Create, Currency, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+
+const CLASS_ID: u32 = 1_000;
+const COST: Points = 100;
+
+/// Register `merchant`, able to issue as many points as needed.
+fn register<T: Config>(merchant: &T::AccountId) {
+	Merchants::<T>::insert(
+		merchant,
+		Merchant { cap: Points::MAX, issued: 0, validity: 100u32.into(), rewards: 0 },
+	);
+}
+
+/// Give `customer` `b` batches of points with `merchant`, expiring one block after another.
+fn insert_batches<T: Config>(merchant: &T::AccountId, customer: &T::AccountId, b: u32) {
+	let now = frame_system::Pallet::<T>::block_number();
+	let batches: Vec<_> = (1..=b)
+		.map(|i| PointsBatch { amount: COST / b as Points, expires_at: now + i.into() })
+		.collect();
+	PointBalances::<T>::insert(merchant, customer, BoundedVec::try_from(batches).unwrap());
+}
+
+benchmarks! {
+	where_clause { where
+		T: pallet_balances::Config,
+		T::Items: Create<T::AccountId>,
+		T::ClassId: From<u32>,
+	}
+
+	register_merchant {
+		let merchant: T::AccountId = account("merchant", 0, 0);
+		let origin = T::MerchantOrigin::successful_origin();
+	}: _<T::Origin>(origin, merchant.clone(), Points::MAX, 100u32.into())
+	verify {
+		assert!(Pallet::<T>::merchant(merchant).is_some());
+	}
+
+	remove_merchant {
+		let r in 0 .. T::MaxRewards::get();
+		let merchant: T::AccountId = account("merchant", 0, 0);
+		register::<T>(&merchant);
+		for reward_id in 0..r {
+			let reward = Reward { cost: COST, kind: RewardKind::Voucher };
+			Rewards::<T>::insert(&merchant, reward_id, reward);
+		}
+		Merchants::<T>::mutate(&merchant, |terms| terms.as_mut().unwrap().rewards = r);
+		let origin = T::MerchantOrigin::successful_origin();
+	}: _<T::Origin>(origin, merchant.clone())
+	verify {
+		assert!(Pallet::<T>::merchant(merchant).is_none());
+	}
+
+	// The new batch expires after all the others.
+	issue_points {
+		let b in 0 .. T::MaxBatches::get() - 1;
+		let merchant: T::AccountId = whitelisted_caller();
+		let customer: T::AccountId = account("customer", 0, 0);
+		register::<T>(&merchant);
+		insert_batches::<T>(&merchant, &customer, b);
+	}: _(RawOrigin::Signed(merchant.clone()), customer.clone(), COST)
+	verify {
+		assert_eq!(Pallet::<T>::batches(merchant, customer).len() as u32, b + 1);
+	}
+
+	add_reward {
+		let merchant: T::AccountId = whitelisted_caller();
+		register::<T>(&merchant);
+	}: _(RawOrigin::Signed(merchant.clone()), COST, RewardKind::Item(CLASS_ID.into()))
+	verify {
+		assert!(Pallet::<T>::reward(merchant, 0).is_some());
+	}
+
+	remove_reward {
+		let merchant: T::AccountId = whitelisted_caller();
+		register::<T>(&merchant);
+		Rewards::<T>::insert(&merchant, 0, Reward { cost: COST, kind: RewardKind::Voucher });
+		Merchants::<T>::mutate(&merchant, |terms| terms.as_mut().unwrap().rewards = 1);
+	}: _(RawOrigin::Signed(merchant.clone()), 0)
+	verify {
+		assert!(Pallet::<T>::reward(merchant, 0).is_none());
+	}
+
+	// Redeeming an item, spending all the batches of the customer.
+	redeem {
+		let b in 1 .. T::MaxBatches::get();
+		let merchant: T::AccountId = account("merchant", 0, 0);
+		let customer: T::AccountId = whitelisted_caller();
+		register::<T>(&merchant);
+		pallet_balances::Pallet::<T>::make_free_balance_be(
+			&merchant,
+			T::ExistentialDeposit::get() * 1_000_000u32.into(),
+		);
+		T::Items::create_class(&CLASS_ID.into(), &merchant, &merchant)?;
+		let cost = COST / b as Points * b as Points;
+		let reward = Reward { cost, kind: RewardKind::Item(CLASS_ID.into()) };
+		Rewards::<T>::insert(&merchant, 0, reward);
+		insert_batches::<T>(&merchant, &customer, b);
+	}: _(RawOrigin::Signed(customer.clone()), merchant.clone(), 0)
+	verify {
+		assert_eq!(Pallet::<T>::points(&merchant, &customer), 0);
+	}
+
+	use_voucher {
+		let merchant: T::AccountId = whitelisted_caller();
+		let holder: T::AccountId = account("customer", 0, 0);
+		Vouchers::<T>::insert(0, Voucher { merchant: merchant.clone(), holder, reward_id: 0 });
+	}: _(RawOrigin::Signed(merchant), 0)
+	verify {
+		assert!(Pallet::<T>::voucher(0).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
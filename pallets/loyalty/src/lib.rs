@@ -0,0 +1,439 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Loyalty programs run by merchants.
+///
+/// Merchants, registered by the merchant origin, issue points to their customers up to a cap.
+/// Points are soulbound: they cannot be transferred, only redeemed with the merchant that issued
+/// them, and they expire after the validity period of that merchant.
+///
+/// Merchants offer rewards, for customers to redeem their points for: either vouchers, which the
+/// merchant honours off-chain and marks as used, or newly minted items. A merchant offers at most
+/// `MaxRewards` of them, so that removing it removes a bounded number of rewards.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::nonfungibles, transactional};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Saturating;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to register merchants.
+		type MerchantOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Identifier of an item class.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its class.
+		type InstanceId: Member + Parameter + MaxEncodedLen + Copy + From<u32>;
+
+		/// The nonfungible items rewards are minted in.
+		///
+		/// Reward classes should be dedicated to this pallet, since it picks instance ids on its
+		/// own.
+		type Items: nonfungibles::Mutate<
+			Self::AccountId,
+			ClassId = Self::ClassId,
+			InstanceId = Self::InstanceId,
+		>;
+
+		/// The maximum number of unexpired batches of points a customer can hold with a merchant.
+		#[pallet::constant]
+		type MaxBatches: Get<u32>;
+
+		/// The maximum number of rewards a merchant can offer.
+		#[pallet::constant]
+		type MaxRewards: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The registered merchants.
+	#[pallet::storage]
+	#[pallet::getter(fn merchant)]
+	pub type Merchants<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, MerchantOf<T>>;
+
+	/// The points each customer holds with each merchant, soonest to expire first.
+	#[pallet::storage]
+	#[pallet::getter(fn batches)]
+	pub type PointBalances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<PointsBatchOf<T>, T::MaxBatches>,
+		ValueQuery,
+	>;
+
+	/// The id the next reward will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_reward_id)]
+	pub type NextRewardId<T> = StorageValue<_, RewardId, ValueQuery>;
+
+	/// The rewards offered by each merchant.
+	#[pallet::storage]
+	#[pallet::getter(fn reward)]
+	pub type Rewards<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, RewardId, RewardOf<T>>;
+
+	/// The id the next voucher will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_voucher_id)]
+	pub type NextVoucherId<T> = StorageValue<_, VoucherId, ValueQuery>;
+
+	/// The vouchers that have not been used yet.
+	#[pallet::storage]
+	#[pallet::getter(fn voucher)]
+	pub type Vouchers<T: Config> = StorageMap<_, Twox64Concat, VoucherId, VoucherOf<T>>;
+
+	/// The instance id the next item reward will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_instance_id)]
+	pub type NextInstanceId<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A merchant has been registered, or its terms updated.
+		MerchantRegistered { merchant: T::AccountId, cap: Points, validity: T::BlockNumber },
+		/// A merchant has been removed.
+		MerchantRemoved { merchant: T::AccountId },
+		/// A merchant issued points to a customer.
+		PointsIssued {
+			merchant: T::AccountId,
+			customer: T::AccountId,
+			amount: Points,
+			expires_at: T::BlockNumber,
+		},
+		/// A merchant offers a new reward.
+		RewardAdded { merchant: T::AccountId, reward_id: RewardId, cost: Points },
+		/// A merchant no longer offers a reward.
+		RewardRemoved { merchant: T::AccountId, reward_id: RewardId },
+		/// A customer redeemed points for a voucher.
+		VoucherRedeemed { merchant: T::AccountId, customer: T::AccountId, voucher_id: VoucherId },
+		/// A customer redeemed points for an item.
+		ItemRedeemed {
+			merchant: T::AccountId,
+			customer: T::AccountId,
+			class: T::ClassId,
+			instance: T::InstanceId,
+		},
+		/// A merchant marked a voucher as used.
+		VoucherUsed { voucher_id: VoucherId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not a registered merchant.
+		NotMerchant,
+		/// The merchant cannot issue that many points.
+		CapExceeded,
+		/// The customer holds too many batches of points with the merchant.
+		TooManyBatches,
+		/// The merchant does not offer this reward.
+		UnknownReward,
+		/// The customer does not hold enough unexpired points.
+		InsufficientPoints,
+		/// The voucher does not exist.
+		UnknownVoucher,
+		/// The voucher was issued by another merchant.
+		NotVoucherMerchant,
+		/// There is no id left.
+		NoAvailableId,
+		/// The merchant offers too many rewards.
+		TooManyRewards,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a merchant, or update its terms.
+		///
+		/// `cap` bounds all the points the merchant ever issued, including those issued before.
+		#[pallet::weight(T::WeightInfo::register_merchant())]
+		pub fn register_merchant(
+			origin: OriginFor<T>,
+			merchant: T::AccountId,
+			cap: Points,
+			validity: T::BlockNumber,
+		) -> DispatchResult {
+			T::MerchantOrigin::ensure_origin(origin)?;
+
+			Merchants::<T>::mutate(&merchant, |maybe_merchant| {
+				let (issued, rewards) =
+					maybe_merchant.as_ref().map_or((0, 0), |m| (m.issued, m.rewards));
+				*maybe_merchant = Some(Merchant { cap, issued, validity, rewards });
+			});
+
+			Self::deposit_event(Event::MerchantRegistered { merchant, cap, validity });
+
+			Ok(())
+		}
+
+		/// Remove a merchant. Its points can no longer be issued nor redeemed.
+		///
+		/// Refunds the weight of the rewards the merchant was assumed to offer.
+		#[pallet::weight(T::WeightInfo::remove_merchant(T::MaxRewards::get()))]
+		pub fn remove_merchant(
+			origin: OriginFor<T>,
+			merchant: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::MerchantOrigin::ensure_origin(origin)?;
+
+			let terms = Merchants::<T>::take(&merchant).ok_or(Error::<T>::NotMerchant)?;
+			Rewards::<T>::remove_prefix(&merchant, None);
+
+			Self::deposit_event(Event::MerchantRemoved { merchant });
+
+			Ok(Some(T::WeightInfo::remove_merchant(terms.rewards)).into())
+		}
+
+		/// Issue points to a customer, valid for the merchant's validity period.
+		///
+		/// Refunds the weight of the batches the customer was assumed to hold.
+		#[pallet::weight(T::WeightInfo::issue_points(T::MaxBatches::get()))]
+		pub fn issue_points(
+			origin: OriginFor<T>,
+			customer: T::AccountId,
+			amount: Points,
+		) -> DispatchResultWithPostInfo {
+			let merchant = ensure_signed(origin)?;
+
+			let mut terms = Self::merchant(&merchant).ok_or(Error::<T>::NotMerchant)?;
+			terms.issued = terms.issued.saturating_add(amount);
+			ensure!(terms.issued <= terms.cap, Error::<T>::CapExceeded);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(terms.validity);
+			let read = PointBalances::<T>::try_mutate(&merchant, &customer, |batches| {
+				let read = batches.len() as u32;
+				batches.retain(|batch| batch.expires_at > now);
+				let index = batches
+					.iter()
+					.position(|batch| batch.expires_at > expires_at)
+					.unwrap_or_else(|| batches.len());
+				batches.try_insert(index, PointsBatch { amount, expires_at }).map(|_| read)
+			})
+			.map_err(|_| Error::<T>::TooManyBatches)?;
+			Merchants::<T>::insert(&merchant, terms);
+
+			Self::deposit_event(Event::PointsIssued { merchant, customer, amount, expires_at });
+
+			Ok(Some(T::WeightInfo::issue_points(read)).into())
+		}
+
+		/// Offer a reward for `cost` points.
+		#[pallet::weight(T::WeightInfo::add_reward())]
+		pub fn add_reward(
+			origin: OriginFor<T>,
+			cost: Points,
+			kind: RewardKind<T::ClassId>,
+		) -> DispatchResult {
+			let merchant = ensure_signed(origin)?;
+
+			let mut terms = Self::merchant(&merchant).ok_or(Error::<T>::NotMerchant)?;
+			ensure!(terms.rewards < T::MaxRewards::get(), Error::<T>::TooManyRewards);
+			let reward_id = Self::next_reward_id();
+			let next_id = reward_id.checked_add(1).ok_or(Error::<T>::NoAvailableId)?;
+
+			terms.rewards += 1;
+			Merchants::<T>::insert(&merchant, terms);
+			Rewards::<T>::insert(&merchant, reward_id, Reward { cost, kind });
+			NextRewardId::<T>::put(next_id);
+
+			Self::deposit_event(Event::RewardAdded { merchant, reward_id, cost });
+
+			Ok(())
+		}
+
+		/// Stop offering a reward.
+		#[pallet::weight(T::WeightInfo::remove_reward())]
+		pub fn remove_reward(origin: OriginFor<T>, reward_id: RewardId) -> DispatchResult {
+			let merchant = ensure_signed(origin)?;
+
+			ensure!(Rewards::<T>::contains_key(&merchant, reward_id), Error::<T>::UnknownReward);
+			Rewards::<T>::remove(&merchant, reward_id);
+			// The merchant of an offered reward is registered.
+			Merchants::<T>::mutate(&merchant, |maybe_merchant| {
+				if let Some(terms) = maybe_merchant {
+					terms.rewards = terms.rewards.saturating_sub(1);
+				}
+			});
+
+			Self::deposit_event(Event::RewardRemoved { merchant, reward_id });
+
+			Ok(())
+		}
+
+		/// Redeem points issued by `merchant` for one of its rewards.
+		///
+		/// The points closest to expiry are spent first. Refunds the weight of the batches the
+		/// customer was assumed to hold.
+		#[pallet::weight(T::WeightInfo::redeem(T::MaxBatches::get()))]
+		#[transactional]
+		pub fn redeem(
+			origin: OriginFor<T>,
+			merchant: T::AccountId,
+			reward_id: RewardId,
+		) -> DispatchResultWithPostInfo {
+			let customer = ensure_signed(origin)?;
+
+			ensure!(Merchants::<T>::contains_key(&merchant), Error::<T>::NotMerchant);
+			let reward = Self::reward(&merchant, reward_id).ok_or(Error::<T>::UnknownReward)?;
+			let read = Self::spend_points(&merchant, &customer, reward.cost)?;
+
+			match reward.kind {
+				RewardKind::Voucher => {
+					let voucher_id = Self::next_voucher_id();
+					let next_id = voucher_id.checked_add(1).ok_or(Error::<T>::NoAvailableId)?;
+					let voucher =
+						Voucher { merchant: merchant.clone(), holder: customer.clone(), reward_id };
+					Vouchers::<T>::insert(voucher_id, voucher);
+					NextVoucherId::<T>::put(next_id);
+
+					Self::deposit_event(Event::VoucherRedeemed { merchant, customer, voucher_id });
+				},
+				RewardKind::Item(class) => {
+					let id = Self::next_instance_id();
+					let next_id = id.checked_add(1).ok_or(Error::<T>::NoAvailableId)?;
+					let instance = T::InstanceId::from(id);
+					T::Items::mint_into(&class, &instance, &customer)?;
+					NextInstanceId::<T>::put(next_id);
+
+					Self::deposit_event(Event::ItemRedeemed {
+						merchant,
+						customer,
+						class,
+						instance,
+					});
+				},
+			}
+
+			Ok(Some(T::WeightInfo::redeem(read)).into())
+		}
+
+		/// Mark a voucher the caller issued as used.
+		#[pallet::weight(T::WeightInfo::use_voucher())]
+		pub fn use_voucher(origin: OriginFor<T>, voucher_id: VoucherId) -> DispatchResult {
+			let merchant = ensure_signed(origin)?;
+
+			let voucher = Self::voucher(voucher_id).ok_or(Error::<T>::UnknownVoucher)?;
+			ensure!(voucher.merchant == merchant, Error::<T>::NotVoucherMerchant);
+			Vouchers::<T>::remove(voucher_id);
+
+			Self::deposit_event(Event::VoucherUsed { voucher_id });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The unexpired points `customer` holds with `merchant`.
+		pub fn points(merchant: &T::AccountId, customer: &T::AccountId) -> Points {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::batches(merchant, customer)
+				.iter()
+				.filter(|batch| batch.expires_at > now)
+				.fold(0, |total, batch| total.saturating_add(batch.amount))
+		}
+
+		/// Spend unexpired points, soonest to expire first, returning the number of batches read.
+		fn spend_points(
+			merchant: &T::AccountId,
+			customer: &T::AccountId,
+			amount: Points,
+		) -> Result<u32, DispatchError> {
+			let now = frame_system::Pallet::<T>::block_number();
+			PointBalances::<T>::try_mutate_exists(
+				merchant,
+				customer,
+				|maybe_batches| -> Result<u32, DispatchError> {
+					let batches = maybe_batches.as_mut().ok_or(Error::<T>::InsufficientPoints)?;
+					let read = batches.len() as u32;
+					batches.retain(|batch| batch.expires_at > now);
+
+					let mut left = amount;
+					for batch in batches.iter_mut() {
+						let spent = left.min(batch.amount);
+						batch.amount -= spent;
+						left -= spent;
+					}
+					ensure!(left == 0, Error::<T>::InsufficientPoints);
+
+					batches.retain(|batch| batch.amount > 0);
+					if batches.is_empty() {
+						*maybe_batches = None;
+					}
+					Ok(read)
+				},
+			)
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			ensure!(
+				Rewards::<T>::iter_keys()
+					.all(|(merchant, _)| Merchants::<T>::contains_key(merchant)),
+				"reward of an unknown merchant"
+			);
+			ensure!(
+				Merchants::<T>::iter().all(|(merchant, terms)| {
+					Rewards::<T>::iter_prefix(&merchant).count() == terms.rewards as usize
+				}),
+				"rewards of a merchant miscounted"
+			);
+			let next_reward_id = Self::next_reward_id();
+			ensure!(
+				Rewards::<T>::iter_keys().all(|(_, reward_id)| reward_id < next_reward_id),
+				"reward id not allocated"
+			);
+			let next_voucher_id = Self::next_voucher_id();
+			ensure!(
+				Vouchers::<T>::iter_keys().all(|voucher_id| voucher_id < next_voucher_id),
+				"voucher id not allocated"
+			);
+
+			Ok(())
+		}
+	}
+}
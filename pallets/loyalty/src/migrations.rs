@@ -0,0 +1,67 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 counts the rewards each merchant offers in its terms.
+///
+/// A merchant already offering more than `MaxRewards` rewards keeps them, but cannot add any until
+/// it offers fewer.
+pub mod v2 {
+	use super::*;
+	use crate::{Merchant, Merchants, Points, Rewards};
+	use codec::{Decode, Encode};
+
+	/// The terms of a merchant, as stored by version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldMerchant<BlockNumber> {
+		pub cap: Points,
+		pub issued: Points,
+		pub validity: BlockNumber,
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		let (mut merchants, mut rewards_read) = (0u64, 0u64);
+		Merchants::<T>::translate::<OldMerchant<T::BlockNumber>, _>(|merchant, old| {
+			let rewards = Rewards::<T>::iter_prefix(&merchant).count() as u32;
+			merchants += 1;
+			rewards_read += rewards as u64;
+			Some(Merchant { cap: old.cap, issued: old.issued, validity: old.validity, rewards })
+		});
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(merchants + rewards_read, merchants + 1)
+	}
+}
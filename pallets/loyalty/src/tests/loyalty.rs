@@ -0,0 +1,164 @@
+use super::mock::*;
+use crate::{Error, Event as LoyaltyEvent, RewardKind, WeightInfo};
+use frame_support::{assert_noop, assert_ok, traits::tokens::nonfungibles::Inspect};
+
+fn register_shop() {
+	assert_ok!(Loyalty::register_merchant(Origin::root(), SHOP, 100, 10));
+}
+
+#[test]
+fn only_registered_merchants_issue_points() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Loyalty::issue_points(Origin::signed(SHOP), ALICE, 10),
+			Error::<Test>::NotMerchant
+		);
+		assert!(Loyalty::register_merchant(Origin::signed(SHOP), SHOP, 100, 10).is_err());
+		register_shop();
+
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 60));
+		System::assert_last_event(
+			LoyaltyEvent::PointsIssued {
+				merchant: SHOP,
+				customer: ALICE,
+				amount: 60,
+				expires_at: 11,
+			}
+			.into(),
+		);
+		assert_eq!(Loyalty::points(&SHOP, &ALICE), 60);
+		assert_eq!(Loyalty::points(&ALICE, &ALICE), 0);
+
+		assert_noop!(
+			Loyalty::issue_points(Origin::signed(SHOP), BOB, 50),
+			Error::<Test>::CapExceeded
+		);
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), BOB, 40));
+		assert_eq!(Loyalty::merchant(SHOP).unwrap().issued, 100);
+	})
+}
+
+#[test]
+fn points_expire() {
+	new_test_ext().execute_with(|| {
+		register_shop();
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 10));
+		System::set_block_number(5);
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 20));
+		assert_noop!(
+			Loyalty::issue_points(Origin::signed(SHOP), ALICE, 30),
+			Error::<Test>::TooManyBatches
+		);
+
+		System::set_block_number(11);
+		assert_eq!(Loyalty::points(&SHOP, &ALICE), 20);
+		// Expired batches are pruned to make room for new ones.
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 30));
+		assert_eq!(Loyalty::points(&SHOP, &ALICE), 50);
+	})
+}
+
+#[test]
+fn unused_weight_is_refunded() {
+	new_test_ext().execute_with(|| {
+		register_shop();
+		assert_ok!(Loyalty::add_reward(Origin::signed(SHOP), 5, RewardKind::Voucher));
+
+		let post_info = Loyalty::issue_points(Origin::signed(SHOP), ALICE, 10).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::issue_points(0)));
+
+		let post_info = Loyalty::redeem(Origin::signed(ALICE), SHOP, 0).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::redeem(1)));
+	})
+}
+
+#[test]
+fn redeem_for_a_voucher() {
+	new_test_ext().execute_with(|| {
+		register_shop();
+		assert_ok!(Loyalty::add_reward(Origin::signed(SHOP), 25, RewardKind::Voucher));
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 10));
+		System::set_block_number(5);
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 20));
+
+		assert_noop!(
+			Loyalty::redeem(Origin::signed(ALICE), SHOP, 1),
+			Error::<Test>::UnknownReward
+		);
+		assert_ok!(Loyalty::redeem(Origin::signed(ALICE), SHOP, 0));
+		System::assert_last_event(
+			LoyaltyEvent::VoucherRedeemed { merchant: SHOP, customer: ALICE, voucher_id: 0 }.into(),
+		);
+		// The oldest batch is spent first.
+		assert_eq!(Loyalty::batches(SHOP, ALICE).len(), 1);
+		assert_eq!(Loyalty::points(&SHOP, &ALICE), 5);
+		assert_noop!(
+			Loyalty::redeem(Origin::signed(ALICE), SHOP, 0),
+			Error::<Test>::InsufficientPoints
+		);
+
+		assert_noop!(
+			Loyalty::use_voucher(Origin::signed(ALICE), 0),
+			Error::<Test>::NotVoucherMerchant
+		);
+		assert_ok!(Loyalty::use_voucher(Origin::signed(SHOP), 0));
+		assert_noop!(Loyalty::use_voucher(Origin::signed(SHOP), 0), Error::<Test>::UnknownVoucher);
+	})
+}
+
+#[test]
+fn redeem_for_an_item() {
+	new_test_ext().execute_with(|| {
+		register_shop();
+		assert_ok!(Loyalty::add_reward(Origin::signed(SHOP), 10, RewardKind::Item(CLASS_ID)));
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 20));
+
+		assert_ok!(Loyalty::redeem(Origin::signed(ALICE), SHOP, 0));
+		assert_ok!(Loyalty::redeem(Origin::signed(ALICE), SHOP, 0));
+		assert_eq!(Uniques::owner(&CLASS_ID, &0), Some(ALICE));
+		assert_eq!(Uniques::owner(&CLASS_ID, &1), Some(ALICE));
+		assert_eq!(Loyalty::points(&SHOP, &ALICE), 0);
+	})
+}
+
+#[test]
+fn removed_merchants_points_cannot_be_redeemed() {
+	new_test_ext().execute_with(|| {
+		register_shop();
+		assert_ok!(Loyalty::add_reward(Origin::signed(SHOP), 10, RewardKind::Voucher));
+		assert_ok!(Loyalty::issue_points(Origin::signed(SHOP), ALICE, 20));
+
+		assert_ok!(Loyalty::remove_merchant(Origin::root(), SHOP));
+		assert_noop!(Loyalty::redeem(Origin::signed(ALICE), SHOP, 0), Error::<Test>::NotMerchant);
+		assert!(Loyalty::reward(SHOP, 0).is_none());
+	})
+}
+
+#[test]
+fn merchants_offer_at_most_max_rewards() {
+	new_test_ext().execute_with(|| {
+		register_shop();
+		for _ in 0..MaxRewards::get() {
+			assert_ok!(Loyalty::add_reward(Origin::signed(SHOP), 10, RewardKind::Voucher));
+		}
+		assert_noop!(
+			Loyalty::add_reward(Origin::signed(SHOP), 10, RewardKind::Voucher),
+			Error::<Test>::TooManyRewards
+		);
+
+		assert_ok!(Loyalty::remove_reward(Origin::signed(SHOP), 0));
+		assert_eq!(Loyalty::merchant(SHOP).unwrap().rewards, 1);
+		assert_ok!(Loyalty::add_reward(Origin::signed(SHOP), 10, RewardKind::Voucher));
+	})
+}
+
+#[test]
+fn remove_merchant_is_weighed_by_its_rewards() {
+	new_test_ext().execute_with(|| {
+		register_shop();
+		assert_ok!(Loyalty::add_reward(Origin::signed(SHOP), 10, RewardKind::Voucher));
+
+		let post_info = Loyalty::remove_merchant(Origin::root(), SHOP).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::remove_merchant(1)));
+	})
+}
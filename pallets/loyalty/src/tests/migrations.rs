@@ -0,0 +1,40 @@
+use super::mock::*;
+use crate::{
+	migrations::{self, v2::OldMerchant},
+	Reward, RewardKind, Rewards,
+};
+use frame_support::{
+	storage::migration::put_storage_value,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	Hashable,
+};
+
+#[test]
+fn runtime_upgrade_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Loyalty>();
+
+		<Loyalty as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(Loyalty::on_chain_storage_version(), Loyalty::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_counts_the_rewards_of_each_merchant() {
+	new_test_ext().execute_with(|| {
+		// Version 1 kept no count of the rewards in the terms of the merchant.
+		let terms = OldMerchant { cap: 100, issued: 0, validity: 10u64 };
+		put_storage_value(b"Loyalty", b"Merchants", &SHOP.blake2_128_concat(), terms);
+		let reward = Reward { cost: 10, kind: RewardKind::Voucher };
+		for reward_id in 0..2 {
+			Rewards::<Test>::insert(SHOP, reward_id, reward.clone());
+		}
+		StorageVersion::new(1).put::<Loyalty>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(Loyalty::merchant(SHOP).unwrap().rewards, 2);
+		assert_eq!(Loyalty::on_chain_storage_version(), 2);
+	})
+}
@@ -0,0 +1,63 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Loyalty points, issued by a merchant and only usable with that merchant.
+pub type Points = u64;
+
+pub type RewardId = u32;
+pub type VoucherId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type MerchantOf<T> = Merchant<<T as frame_system::Config>::BlockNumber>;
+pub type PointsBatchOf<T> = PointsBatch<<T as frame_system::Config>::BlockNumber>;
+pub type RewardOf<T> = Reward<<T as Config>::ClassId>;
+pub type VoucherOf<T> = Voucher<AccountIdOf<T>>;
+
+/// A registered merchant.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Merchant<BlockNumber> {
+	/// The total number of points the merchant can ever issue.
+	pub cap: Points,
+	/// The number of points the merchant issued so far.
+	pub issued: Points,
+	/// The number of blocks issued points are valid for.
+	pub validity: BlockNumber,
+	/// The number of rewards the merchant offers.
+	pub rewards: u32,
+}
+
+/// Points issued at once to a customer.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct PointsBatch<BlockNumber> {
+	pub amount: Points,
+	/// The block from which the points can no longer be redeemed.
+	pub expires_at: BlockNumber,
+}
+
+/// What a customer gets for redeeming a reward.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum RewardKind<ClassId> {
+	/// A voucher the merchant honours off-chain, then marks as used.
+	Voucher,
+	/// A new item of the given class.
+	Item(ClassId),
+}
+
+/// A reward offered by a merchant.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Reward<ClassId> {
+	/// The number of points the reward costs.
+	pub cost: Points,
+	pub kind: RewardKind<ClassId>,
+}
+
+/// A voucher held by a customer.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Voucher<AccountId> {
+	/// The merchant honouring the voucher.
+	pub merchant: AccountId,
+	pub holder: AccountId,
+	/// The reward the voucher was redeemed for.
+	pub reward_id: RewardId,
+}
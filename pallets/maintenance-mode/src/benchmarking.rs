@@ -0,0 +1,24 @@
+//! Benchmarks of the maintenance-mode pallet.
+
+use super::*;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+
+benchmarks! {
+	enter_maintenance_mode {
+		let origin = T::MaintenanceOrigin::successful_origin();
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(Pallet::<T>::maintenance_mode());
+	}
+
+	exit_maintenance_mode {
+		MaintenanceMode::<T>::put(true);
+		let origin = T::MaintenanceOrigin::successful_origin();
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(!Pallet::<T>::maintenance_mode());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
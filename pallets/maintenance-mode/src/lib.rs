@@ -0,0 +1,121 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Switch the runtime to maintenance mode during incidents.
+///
+/// A privileged origin enters maintenance mode, and exits it once the incident is over. While in
+/// maintenance mode, [`MaintenanceFilter`], plugged in as the `BaseCallFilter` of the runtime,
+/// only lets through the calls it is configured to keep, e.g. the ones of system and sudo: all
+/// the user-facing calls are rejected before being dispatched.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to enter and exit maintenance mode.
+		type MaintenanceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// Whether the runtime is in maintenance mode.
+	#[pallet::storage]
+	#[pallet::getter(fn maintenance_mode)]
+	pub type MaintenanceMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event {
+		/// The runtime has entered maintenance mode.
+		MaintenanceModeEntered,
+		/// The runtime has exited maintenance mode.
+		MaintenanceModeExited,
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The runtime is already in maintenance mode.
+		AlreadyInMaintenanceMode,
+		/// The runtime is not in maintenance mode.
+		NotInMaintenanceMode,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// There is no `try_state` to run after the upgrade: the storage is a single flag, with no
+		// invariant to check.
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enter maintenance mode, filtering out the calls the runtime does not keep.
+		#[pallet::weight(T::WeightInfo::enter_maintenance_mode())]
+		pub fn enter_maintenance_mode(origin: OriginFor<T>) -> DispatchResult {
+			T::MaintenanceOrigin::ensure_origin(origin)?;
+
+			ensure!(!Self::maintenance_mode(), Error::<T>::AlreadyInMaintenanceMode);
+			MaintenanceMode::<T>::put(true);
+
+			Self::deposit_event(Event::MaintenanceModeEntered);
+
+			Ok(())
+		}
+
+		/// Exit maintenance mode, letting every call through again.
+		#[pallet::weight(T::WeightInfo::exit_maintenance_mode())]
+		pub fn exit_maintenance_mode(origin: OriginFor<T>) -> DispatchResult {
+			T::MaintenanceOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::maintenance_mode(), Error::<T>::NotInMaintenanceMode);
+			MaintenanceMode::<T>::put(false);
+
+			Self::deposit_event(Event::MaintenanceModeExited);
+
+			Ok(())
+		}
+	}
+}
+
+/// Let every call through, unless the runtime is in maintenance mode, in which case only the
+/// calls `Allowed` contains are let through.
+///
+/// The calls of this pallet must be allowed for a `MaintenanceOrigin` other than root to exit
+/// maintenance mode.
+pub struct MaintenanceFilter<T, Allowed>(sp_std::marker::PhantomData<(T, Allowed)>);
+
+impl<T: Config, Call, Allowed: frame_support::traits::Contains<Call>>
+	frame_support::traits::Contains<Call> for MaintenanceFilter<T, Allowed>
+{
+	fn contains(call: &Call) -> bool {
+		!Pallet::<T>::maintenance_mode() || Allowed::contains(call)
+	}
+}
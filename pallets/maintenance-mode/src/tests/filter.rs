@@ -0,0 +1,33 @@
+use super::mock::*;
+use frame_support::{assert_ok, traits::Contains};
+
+type BaseCallFilter = <Test as frame_system::Config>::BaseCallFilter;
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn exit() -> Call {
+	Call::MaintenanceMode(crate::Call::exit_maintenance_mode {})
+}
+
+#[test]
+fn every_call_is_let_through_outside_maintenance_mode() {
+	new_test_ext().execute_with(|| {
+		assert!(BaseCallFilter::contains(&remark()));
+		assert!(BaseCallFilter::contains(&exit()));
+	})
+}
+
+#[test]
+fn only_the_kept_calls_are_let_through_in_maintenance_mode() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MaintenanceMode::enter_maintenance_mode(Origin::root()));
+
+		assert!(!BaseCallFilter::contains(&remark()));
+		assert!(BaseCallFilter::contains(&exit()));
+
+		assert_ok!(MaintenanceMode::exit_maintenance_mode(Origin::root()));
+		assert!(BaseCallFilter::contains(&remark()));
+	})
+}
@@ -0,0 +1,34 @@
+use super::mock::*;
+use crate::{Error, Event as MaintenanceEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn root_enters_and_exits_maintenance_mode() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MaintenanceMode::enter_maintenance_mode(Origin::signed(ALICE)),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(MaintenanceMode::enter_maintenance_mode(Origin::root()));
+		assert!(MaintenanceMode::maintenance_mode());
+		System::assert_last_event(MaintenanceEvent::MaintenanceModeEntered.into());
+		assert_noop!(
+			MaintenanceMode::enter_maintenance_mode(Origin::root()),
+			Error::<Test>::AlreadyInMaintenanceMode
+		);
+
+		assert_noop!(
+			MaintenanceMode::exit_maintenance_mode(Origin::signed(ALICE)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(MaintenanceMode::exit_maintenance_mode(Origin::root()));
+		assert!(!MaintenanceMode::maintenance_mode());
+		System::assert_last_event(MaintenanceEvent::MaintenanceModeExited.into());
+		assert_noop!(
+			MaintenanceMode::exit_maintenance_mode(Origin::root()),
+			Error::<Test>::NotInMaintenanceMode
+		);
+	})
+}
@@ -0,0 +1,133 @@
+//! Benchmarks of the nft-rental pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{
+	tokens::{fungibles, nonfungibles},
+	Currency, Get,
+};
+use frame_system::RawOrigin;
+use rusty_node_support::OnUnlock;
+use sp_runtime::traits::One;
+
+const ASSET_ID: u32 = 1_000;
+const CLASS_ID: u32 = 1_000;
+const RENT: u32 = 100;
+
+/// Create the class of the benchmarks, owned by `lender`, and mint `n` items of it to them.
+fn mint_items<T: Config>(lender: &T::AccountId, n: u32)
+where
+	T: pallet_balances::Config,
+	T::Items: nonfungibles::Create<T::AccountId> + nonfungibles::Mutate<T::AccountId>,
+	T::ClassId: From<u32>,
+	T::InstanceId: From<u32>,
+{
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		lender,
+		T::ExistentialDeposit::get() * 1_000_000u32.into(),
+	);
+	<T::Items as nonfungibles::Create<_>>::create_class(&CLASS_ID.into(), lender, lender).unwrap();
+	for instance in 0..n {
+		<T::Items as nonfungibles::Mutate<_>>::mint_into(&CLASS_ID.into(), &instance.into(), lender)
+			.unwrap();
+	}
+}
+
+/// Have `lender` list the item `instance` for rent, one block per period.
+fn new_listing<T: Config>(lender: &T::AccountId, instance: u32)
+where
+	AssetIdOf<T>: From<u32>,
+	T::ClassId: From<u32>,
+	T::InstanceId: From<u32>,
+{
+	Pallet::<T>::list(
+		RawOrigin::Signed(lender.clone()).into(),
+		CLASS_ID.into(),
+		instance.into(),
+		ASSET_ID.into(),
+		RENT.into(),
+		One::one(),
+		10,
+	)
+	.unwrap();
+}
+
+/// Create the asset rent is paid in, funding `renter` with it.
+fn fund_renter<T: Config>(renter: &T::AccountId)
+where
+	T::Assets: fungibles::Create<T::AccountId> + fungibles::Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	let owner: T::AccountId = account("owner", 0, 0);
+	<T::Assets as fungibles::Create<_>>::create(ASSET_ID.into(), owner, true, One::one()).unwrap();
+	<T::Assets as fungibles::Mutate<_>>::mint_into(ASSET_ID.into(), renter, (RENT * 100).into())
+		.unwrap();
+}
+
+benchmarks! {
+	where_clause { where
+		T: pallet_balances::Config,
+		T::Assets: fungibles::Create<T::AccountId> + fungibles::Mutate<T::AccountId>,
+		T::Items: nonfungibles::Create<T::AccountId> + nonfungibles::Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+		T::ClassId: From<u32>,
+		T::InstanceId: From<u32>,
+	}
+
+	list {
+		let lender: T::AccountId = whitelisted_caller();
+		mint_items::<T>(&lender, 1);
+	}: _(
+		RawOrigin::Signed(lender),
+		CLASS_ID.into(),
+		0u32.into(),
+		ASSET_ID.into(),
+		RENT.into(),
+		One::one(),
+		10
+	)
+	verify {
+		assert!(Pallet::<T>::offer(T::ClassId::from(CLASS_ID), T::InstanceId::from(0)).is_some());
+	}
+
+	unlist {
+		let lender: T::AccountId = whitelisted_caller();
+		mint_items::<T>(&lender, 1);
+		new_listing::<T>(&lender, 0);
+	}: _(RawOrigin::Signed(lender), CLASS_ID.into(), 0u32.into())
+	verify {
+		assert!(Pallet::<T>::offer(T::ClassId::from(CLASS_ID), T::InstanceId::from(0)).is_none());
+	}
+
+	rent {
+		let lender: T::AccountId = account("lender", 0, 0);
+		let renter: T::AccountId = whitelisted_caller();
+		mint_items::<T>(&lender, 1);
+		new_listing::<T>(&lender, 0);
+		fund_renter::<T>(&renter);
+	}: _(RawOrigin::Signed(renter.clone()), CLASS_ID.into(), 0u32.into(), 10)
+	verify {
+		assert_eq!(
+			Pallet::<T>::lease(T::ClassId::from(CLASS_ID), T::InstanceId::from(0))
+				.map(|lease| lease.renter),
+			Some(renter)
+		);
+	}
+
+	// The item is returned to escrow.
+	end_lease {
+		let lender: T::AccountId = account("lender", 0, 0);
+		let renter: T::AccountId = account("renter", 0, 0);
+		mint_items::<T>(&lender, 1);
+		new_listing::<T>(&lender, 0);
+		fund_renter::<T>(&renter);
+		Pallet::<T>::rent(RawOrigin::Signed(renter).into(), CLASS_ID.into(), 0u32.into(), 1)?;
+	}: {
+		Pallet::<T>::on_unlock((CLASS_ID.into(), 0u32.into()));
+	}
+	verify {
+		assert!(Pallet::<T>::lease(T::ClassId::from(CLASS_ID), T::InstanceId::from(0)).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
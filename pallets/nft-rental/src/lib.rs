@@ -0,0 +1,320 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Rent out nonfungible items for a fee paid in a fungible asset.
+///
+/// The pallet only relies on the `nonfungibles` and `fungibles` traits, so any item pallet
+/// (uniques-style or the tutorial NFTs) can be plugged in through the runtime configuration.
+///
+/// A lender lists an item, which is escrowed in the pallet account. A renter pays the rent for
+/// a number of periods upfront and receives the item. The end of the lease is scheduled in the
+/// `Unlocks` queue, which pulls the item back into escrow when it comes, so the lender never
+/// depends on the renter's goodwill.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::tokens::{fungibles, nonfungibles},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::{OnUnlock, ScheduleUnlock};
+	use sp_runtime::traits::{AccountIdConversion, CheckedMul, Saturating, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets rent is paid with.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// Identifier of an item class.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its class.
+		type InstanceId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The nonfungible items that can be rented out.
+		type Items: nonfungibles::Transfer<
+			Self::AccountId,
+			ClassId = Self::ClassId,
+			InstanceId = Self::InstanceId,
+		>;
+
+		/// The queue the ends of the leases are scheduled in, which calls back `OnUnlock` of the
+		/// pallet with the item.
+		type Unlocks: ScheduleUnlock<Self::BlockNumber, (Self::ClassId, Self::InstanceId)>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The rental offer of each escrowed item.
+	#[pallet::storage]
+	#[pallet::getter(fn offer)]
+	pub type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		RentalOfferOf<T>,
+	>;
+
+	/// The running lease of each rented item.
+	#[pallet::storage]
+	#[pallet::getter(fn lease)]
+	pub type Leases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		LeaseOf<T>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An item has been put up for rent.
+		Listed {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			lender: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			rent_per_period: BalanceOf<T>,
+			period: T::BlockNumber,
+		},
+		/// An item has been withdrawn from rental.
+		Unlisted { class: T::ClassId, instance: T::InstanceId, lender: T::AccountId },
+		/// An item has been rented.
+		Rented {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			renter: T::AccountId,
+			rent: BalanceOf<T>,
+			ends_at: T::BlockNumber,
+		},
+		/// A rented item has been returned to escrow at the end of its lease.
+		Returned { class: T::ClassId, instance: T::InstanceId, renter: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The item does not exist.
+		UnknownItem,
+		/// The caller does not own the item.
+		NotOwner,
+		/// The item is not listed for rent.
+		NotListed,
+		/// The item is already listed for rent.
+		AlreadyListed,
+		/// The item is currently rented.
+		CurrentlyRented,
+		/// Rental periods must be non zero.
+		ZeroPeriod,
+		/// The requested number of periods exceeds the lender's limit.
+		TooManyPeriods,
+		/// Too many leases already end at the requested block.
+		TooManyLeasesInBlock,
+		/// The rent computation overflowed.
+		Overflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow an item and offer it for rent.
+		///
+		/// `max_periods` bounds how long a single lease can last.
+		#[pallet::weight(T::WeightInfo::list())]
+		pub fn list(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			instance: T::InstanceId,
+			asset_id: AssetIdOf<T>,
+			rent_per_period: BalanceOf<T>,
+			period: T::BlockNumber,
+			max_periods: u32,
+		) -> DispatchResult {
+			let lender = ensure_signed(origin)?;
+
+			let owner = <T::Items as nonfungibles::Inspect<T::AccountId>>::owner(&class, &instance)
+				.ok_or(Error::<T>::UnknownItem)?;
+			ensure!(owner == lender, Error::<T>::NotOwner);
+			ensure!(!Offers::<T>::contains_key(&class, &instance), Error::<T>::AlreadyListed);
+			ensure!(!period.is_zero() && max_periods > 0, Error::<T>::ZeroPeriod);
+
+			T::Items::transfer(&class, &instance, &Self::account_id())?;
+
+			Offers::<T>::insert(
+				&class,
+				&instance,
+				RentalOffer {
+					lender: lender.clone(),
+					asset_id,
+					rent_per_period,
+					period,
+					max_periods,
+				},
+			);
+
+			Self::deposit_event(Event::Listed {
+				class,
+				instance,
+				lender,
+				asset_id,
+				rent_per_period,
+				period,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw an item that is not currently rented and give it back to its lender.
+		#[pallet::weight(T::WeightInfo::unlist())]
+		pub fn unlist(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			instance: T::InstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let offer = Offers::<T>::get(&class, &instance).ok_or(Error::<T>::NotListed)?;
+			ensure!(offer.lender == who, Error::<T>::NotOwner);
+			ensure!(!Leases::<T>::contains_key(&class, &instance), Error::<T>::CurrentlyRented);
+
+			T::Items::transfer(&class, &instance, &who)?;
+			Offers::<T>::remove(&class, &instance);
+
+			Self::deposit_event(Event::Unlisted { class, instance, lender: who });
+
+			Ok(())
+		}
+
+		/// Rent a listed item for `periods` periods, paying the whole rent upfront.
+		#[pallet::weight(T::WeightInfo::rent())]
+		#[transactional]
+		pub fn rent(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			instance: T::InstanceId,
+			periods: u32,
+		) -> DispatchResult {
+			let renter = ensure_signed(origin)?;
+
+			let offer = Offers::<T>::get(&class, &instance).ok_or(Error::<T>::NotListed)?;
+			ensure!(!Leases::<T>::contains_key(&class, &instance), Error::<T>::CurrentlyRented);
+			ensure!(periods > 0, Error::<T>::ZeroPeriod);
+			ensure!(periods <= offer.max_periods, Error::<T>::TooManyPeriods);
+
+			let rent = offer
+				.rent_per_period
+				.checked_mul(&periods.into())
+				.ok_or(Error::<T>::Overflow)?;
+			let ends_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(offer.period.saturating_mul(periods.into()));
+
+			T::Unlocks::schedule(ends_at, (class, instance))
+				.map_err(|_| Error::<T>::TooManyLeasesInBlock)?;
+			T::Assets::transfer(offer.asset_id, &renter, &offer.lender, rent, false)?;
+			T::Items::transfer(&class, &instance, &renter)?;
+
+			Leases::<T>::insert(&class, &instance, Lease { renter: renter.clone(), ends_at });
+
+			Self::deposit_event(Event::Rented { class, instance, renter, rent, ends_at });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding escrowed items.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Pull a rented item back into escrow.
+		///
+		/// Runs from the unlock queue, so a failing transfer (e.g. the item got burned) is not fatal: the
+		/// lease is cleared either way.
+		pub(crate) fn end_lease(class: T::ClassId, instance: T::InstanceId) {
+			if let Some(lease) = Leases::<T>::take(&class, &instance) {
+				let _ = T::Items::transfer(&class, &instance, &Self::account_id());
+				Self::deposit_event(Event::Returned { class, instance, renter: lease.renter });
+			}
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let account = Self::account_id();
+			for (class, instance, _) in Offers::<T>::iter() {
+				let holder = Self::lease(&class, &instance)
+					.map_or_else(|| account.clone(), |lease| lease.renter);
+				// Burned items are not returned, but their lease is cleared anyway.
+				let owner =
+					<T::Items as nonfungibles::Inspect<T::AccountId>>::owner(&class, &instance);
+				ensure!(owner.map_or(true, |owner| owner == holder), "listed item not held");
+			}
+			ensure!(
+				Leases::<T>::iter_keys()
+					.all(|(class, instance)| Offers::<T>::contains_key(&class, &instance)),
+				"lease of an unlisted item"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// The ends of the leases, scheduled in `Unlocks`.
+	impl<T: Config> OnUnlock<(T::ClassId, T::InstanceId)> for Pallet<T> {
+		fn unlock_weight(_item: &(T::ClassId, T::InstanceId)) -> Weight {
+			T::WeightInfo::end_lease()
+		}
+
+		fn on_unlock((class, instance): (T::ClassId, T::InstanceId)) -> Weight {
+			Self::end_lease(class, instance);
+			T::WeightInfo::end_lease()
+		}
+	}
+}
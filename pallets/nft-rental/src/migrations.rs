@@ -0,0 +1,64 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 schedules the ends of the leases in `Unlocks`, instead of the `LeaseEnds` of the
+/// pallet.
+///
+/// A lease whose end no longer fits in its block of the queue ends right away, rather than never.
+pub mod v2 {
+	use super::*;
+	use frame_support::{sp_std::prelude::*, storage::migration::storage_key_iter, Twox64Concat};
+	use rusty_node_support::unlocks::schedule_or_unlock;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let lease_ends = storage_key_iter::<
+			T::BlockNumber,
+			Vec<(T::ClassId, T::InstanceId)>,
+			Twox64Concat,
+		>(Pallet::<T>::name().as_bytes(), b"LeaseEnds");
+
+		let mut blocks = 0u64;
+		let unlocks = lease_ends.drain().flat_map(|(ends_at, items)| {
+			blocks += 1;
+			items.into_iter().map(move |item| (ends_at, item))
+		});
+		let weight = schedule_or_unlock::<_, _, T::Unlocks, Pallet<T>>(unlocks, T::DbWeight::get());
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		weight.saturating_add(T::DbWeight::get().reads_writes(blocks, blocks + 1))
+	}
+}
@@ -0,0 +1,46 @@
+use super::mock::*;
+use crate::migrations;
+use frame_support::{
+	assert_ok,
+	storage::migration::{have_storage_value, put_storage_value},
+	traits::{GetStorageVersion, StorageVersion},
+	Hashable,
+};
+
+#[test]
+fn migrate_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<NftRental>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(NftRental::on_chain_storage_version(), NftRental::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_schedules_the_lease_ends_in_the_unlock_queue() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftRental::list(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			INSTANCE_ID,
+			ASSET_ID,
+			10,
+			5,
+			3
+		));
+		assert_ok!(NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 1));
+		// Version 1 kept the lease end in the storage of the pallet.
+		pallet_unlock_queue::Queue::<Test>::remove(6);
+		let ends_at = 6u64.twox_64_concat();
+		put_storage_value(b"NftRental", b"LeaseEnds", &ends_at, vec![(CLASS_ID, INSTANCE_ID)]);
+		StorageVersion::new(1).put::<NftRental>();
+
+		migrations::migrate::<Test>();
+
+		assert!(!have_storage_value(b"NftRental", b"LeaseEnds", &ends_at));
+		assert_eq!(UnlockQueue::queue(6).into_inner(), vec![(CLASS_ID, INSTANCE_ID)]);
+		assert_eq!(NftRental::on_chain_storage_version(), 2);
+	})
+}
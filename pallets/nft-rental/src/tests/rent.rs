@@ -0,0 +1,144 @@
+use super::mock::*;
+use crate::{Error, Event as RentalEvent};
+use frame_support::{assert_noop, assert_ok};
+use rusty_node_support::ScheduleUnlock;
+
+fn list_item() {
+	assert_ok!(NftRental::list(
+		Origin::signed(ALICE),
+		CLASS_ID,
+		INSTANCE_ID,
+		ASSET_ID,
+		10,
+		5,
+		3
+	));
+}
+
+#[test]
+fn list_escrows_the_item() {
+	new_test_ext().execute_with(|| {
+		list_item();
+
+		assert_eq!(Uniques::owner(CLASS_ID, INSTANCE_ID), Some(NftRental::account_id()));
+		assert!(NftRental::offer(CLASS_ID, INSTANCE_ID).is_some());
+	})
+}
+
+#[test]
+fn list_requires_ownership() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			NftRental::list(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, ASSET_ID, 10, 5, 3),
+			Error::<Test>::NotOwner
+		);
+	})
+}
+
+#[test]
+fn rent_pays_lender_and_hands_over_item() {
+	new_test_ext().execute_with(|| {
+		list_item();
+
+		assert_ok!(NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 2));
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 20);
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 980);
+		assert_eq!(Uniques::owner(CLASS_ID, INSTANCE_ID), Some(BOB));
+		System::assert_last_event(
+			RentalEvent::Rented {
+				class: CLASS_ID,
+				instance: INSTANCE_ID,
+				renter: BOB,
+				rent: 20,
+				ends_at: 11,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn failed_rent_rolls_back_the_payment() {
+	new_test_ext().execute_with(|| {
+		list_item();
+
+		// The renter pays, then the hand over of the item fails.
+		assert_ok!(Uniques::freeze_class(Origin::signed(ALICE), CLASS_ID));
+		assert_noop!(
+			NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 2),
+			pallet_uniques::Error::<Test>::Frozen
+		);
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 1_000);
+		assert!(NftRental::lease(CLASS_ID, INSTANCE_ID).is_none());
+	})
+}
+
+#[test]
+fn rent_is_bounded_by_max_periods() {
+	new_test_ext().execute_with(|| {
+		list_item();
+
+		assert_noop!(
+			NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 4),
+			Error::<Test>::TooManyPeriods
+		);
+	})
+}
+
+#[test]
+fn item_returns_to_escrow_at_lease_end() {
+	new_test_ext().execute_with(|| {
+		list_item();
+		assert_ok!(NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 1));
+
+		run_to_block(5);
+		assert_eq!(Uniques::owner(CLASS_ID, INSTANCE_ID), Some(BOB));
+
+		run_to_block(6);
+		assert_eq!(Uniques::owner(CLASS_ID, INSTANCE_ID), Some(NftRental::account_id()));
+		assert!(NftRental::lease(CLASS_ID, INSTANCE_ID).is_none());
+	})
+}
+
+#[test]
+fn lease_end_is_scheduled_in_the_unlock_queue() {
+	new_test_ext().execute_with(|| {
+		list_item();
+		assert_ok!(NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 1));
+
+		assert_eq!(UnlockQueue::queue(6).into_inner(), vec![(CLASS_ID, INSTANCE_ID)]);
+	})
+}
+
+#[test]
+fn rent_fails_when_too_many_leases_end_in_the_block() {
+	new_test_ext().execute_with(|| {
+		list_item();
+		for instance in 0..MaxUnlocksPerBlock::get() {
+			assert_ok!(<UnlockQueue as ScheduleUnlock<_, _>>::schedule(6, (CLASS_ID, instance)));
+		}
+
+		assert_noop!(
+			NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 1),
+			Error::<Test>::TooManyLeasesInBlock
+		);
+	})
+}
+
+#[test]
+fn cannot_unlist_while_rented() {
+	new_test_ext().execute_with(|| {
+		list_item();
+		assert_ok!(NftRental::rent(Origin::signed(BOB), CLASS_ID, INSTANCE_ID, 1));
+
+		assert_noop!(
+			NftRental::unlist(Origin::signed(ALICE), CLASS_ID, INSTANCE_ID),
+			Error::<Test>::CurrentlyRented
+		);
+
+		run_to_block(6);
+		assert_ok!(NftRental::unlist(Origin::signed(ALICE), CLASS_ID, INSTANCE_ID));
+		assert_eq!(Uniques::owner(CLASS_ID, INSTANCE_ID), Some(ALICE));
+	})
+}
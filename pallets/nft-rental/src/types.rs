@@ -0,0 +1,39 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type RentalOfferOf<T> = RentalOffer<
+	AccountIdOf<T>,
+	AssetIdOf<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub type LeaseOf<T> = Lease<AccountIdOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// The terms under which an escrowed item can be rented.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct RentalOffer<AccountId, AssetId, Balance, BlockNumber> {
+	/// The account the item goes back to when it is unlisted.
+	pub lender: AccountId,
+	/// The asset rent is paid in.
+	pub asset_id: AssetId,
+	/// The rent owed for each period.
+	pub rent_per_period: Balance,
+	/// The length of a period, in blocks.
+	pub period: BlockNumber,
+	/// The maximum number of periods a single lease can cover.
+	pub max_periods: u32,
+}
+
+/// A running lease.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Lease<AccountId, BlockNumber> {
+	/// The account currently holding the item.
+	pub renter: AccountId,
+	/// The block at which the item is pulled back into escrow.
+	pub ends_at: BlockNumber,
+}
@@ -0,0 +1,92 @@
+//! Benchmarks of the order-book pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{
+	tokens::fungibles::{Create, Mutate},
+	Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+
+const BASE: u32 = 1_000;
+const QUOTE: u32 = 1_001;
+const AMOUNT: u32 = 1_000_000;
+
+/// Create the assets of the pair.
+fn create_pair<T: Config>()
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	let owner: T::AccountId = account("owner", 0, 0);
+	for asset_id in [BASE, QUOTE] {
+		T::Assets::create(asset_id.into(), owner.clone(), true, One::one()).unwrap();
+	}
+}
+
+/// Fund `who` with both assets of the pair and have it place an order of one unit of base.
+fn resting_order<T: Config>(who: &T::AccountId, side: Side, price: u32)
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	for asset_id in [BASE, QUOTE] {
+		T::Assets::mint_into(asset_id.into(), who, AMOUNT.into()).unwrap();
+	}
+	Pallet::<T>::place_order(
+		RawOrigin::Signed(who.clone()).into(),
+		BASE.into(),
+		QUOTE.into(),
+		side,
+		price.into(),
+		One::one(),
+	)
+	.unwrap();
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	// A buy order filling `m` resting sell orders, its remainder resting in a book one order short
+	// of full.
+	place_order {
+		let m in 0 .. T::MaxOrdersPerSide::get();
+		create_pair::<T>();
+		for i in 0..m {
+			resting_order::<T>(&account("seller", i, 0), Side::Sell, 10);
+		}
+		for i in 1..T::MaxOrdersPerSide::get() {
+			resting_order::<T>(&account("buyer", i, 0), Side::Buy, 5);
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		T::Assets::mint_into(QUOTE.into(), &caller, AMOUNT.into())?;
+		let (base, quote): (AssetIdOf<T>, AssetIdOf<T>) = (BASE.into(), QUOTE.into());
+		let amount = m + 1;
+	}: _(RawOrigin::Signed(caller), base, quote, Side::Buy, 10u32.into(), amount.into())
+	verify {
+		assert_eq!(
+			Pallet::<T>::book((base, quote, Side::Buy)).len() as u32,
+			T::MaxOrdersPerSide::get()
+		);
+	}
+
+	// Canceling the last order of a full book.
+	cancel_order {
+		create_pair::<T>();
+		for i in 1..T::MaxOrdersPerSide::get() {
+			resting_order::<T>(&account("buyer", i, 0), Side::Buy, 10);
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		let order_id = Pallet::<T>::next_order_id();
+		resting_order::<T>(&caller, Side::Buy, 5);
+	}: _(RawOrigin::Signed(caller), order_id)
+	verify {
+		assert!(Pallet::<T>::order(order_id).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,352 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A limit-order exchange between pairs of fungible assets.
+///
+/// Each pair is made of a `base` asset, the one being traded, and a `quote` asset, the one prices
+/// are expressed in: a price is the amount of quote paid for one unit of base.
+///
+/// A new order is first matched against the opposite side of the book, best price first, and
+/// executed at the resting orders' prices. Whatever is left of it then rests in the book, with the
+/// maker's funds held in the pallet account until the order is filled or canceled.
+///
+/// Takers are charged the `TradeFee` on what they pay for each fill, out of what the maker
+/// receives, and the fee is reported to `OnFeeCharged`, so that referrers get their share.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::fungibles, transactional, PalletId};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::{fees::charge_fee, FeeKind, OnFeeCharged};
+	use sp_runtime::{
+		traits::{AccountIdConversion, CheckedMul, Zero},
+		Perbill,
+	};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets traded on the exchange.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The share of what a taker pays for each fill charged as a fee.
+		#[pallet::constant]
+		type TradeFee: Get<Perbill>;
+
+		/// The account collecting the fees, e.g. the treasury.
+		type FeeCollector: Get<Self::AccountId>;
+
+		/// Shares the fees with the referrers of the takers paying them.
+		type OnFeeCharged: OnFeeCharged<Self::AccountId, AssetIdOf<Self>, BalanceOf<Self>>;
+
+		/// The maximum number of resting orders on one side of a pair.
+		#[pallet::constant]
+		type MaxOrdersPerSide: Get<u32>;
+
+		/// The pallet id, used to derive the account holding resting orders' funds.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next order will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// The resting orders.
+	#[pallet::storage]
+	#[pallet::getter(fn order)]
+	pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, OrderOf<T>>;
+
+	/// The resting orders of each side of each pair, best price first.
+	///
+	/// Orders at the same price are kept in arrival order.
+	#[pallet::storage]
+	#[pallet::getter(fn book)]
+	pub type OrderBook<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(AssetIdOf<T>, AssetIdOf<T>, Side),
+		BoundedVec<OrderId, T::MaxOrdersPerSide>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An order, or what is left of it after matching, now rests in the book.
+		OrderPlaced {
+			order_id: OrderId,
+			owner: T::AccountId,
+			base: AssetIdOf<T>,
+			quote: AssetIdOf<T>,
+			side: Side,
+			price: BalanceOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// A resting order has been, at least partially, filled.
+		OrderFilled {
+			order_id: OrderId,
+			maker: T::AccountId,
+			taker: T::AccountId,
+			price: BalanceOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// A resting order has been canceled.
+		OrderCanceled { order_id: OrderId, owner: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The base and quote assets of a pair must differ.
+		SameAsset,
+		/// The order amount must be non zero.
+		ZeroAmount,
+		/// The order price must be non zero.
+		ZeroPrice,
+		/// The order does not exist.
+		UnknownOrder,
+		/// The caller does not own the order.
+		NotOrderOwner,
+		/// This side of the pair has too many resting orders.
+		BookFull,
+		/// The order value overflowed.
+		Overflow,
+		/// There is no order id left.
+		NoAvailableOrderId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Buy or sell `amount` of `base` for at most, or at least, `price` `quote` per unit.
+		///
+		/// The order is matched against the resting orders first. Its remainder, if any, is
+		/// added to the book and its funds are held until it is filled or canceled.
+		#[pallet::weight(T::WeightInfo::place_order(T::MaxOrdersPerSide::get()))]
+		#[transactional]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			base: AssetIdOf<T>,
+			quote: AssetIdOf<T>,
+			side: Side,
+			price: BalanceOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(base != quote, Error::<T>::SameAsset);
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let remaining = Self::match_order(&who, base, quote, side, price, amount)?;
+			if !remaining.is_zero() {
+				Self::rest_order(who, base, quote, side, price, remaining)?;
+			}
+
+			Ok(())
+		}
+
+		/// Cancel a resting order and release its held funds.
+		#[pallet::weight(T::WeightInfo::cancel_order())]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let order = Self::order(order_id).ok_or(Error::<T>::UnknownOrder)?;
+			ensure!(order.owner == who, Error::<T>::NotOrderOwner);
+
+			let (asset_id, held) = Self::held_funds(&order)?;
+			T::Assets::transfer(asset_id, &Self::account_id(), &who, held, false)?;
+
+			OrderBook::<T>::mutate((order.base, order.quote, order.side), |book| {
+				book.retain(|id| *id != order_id)
+			});
+			Orders::<T>::remove(order_id);
+
+			Self::deposit_event(Event::OrderCanceled { order_id, owner: who });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the funds of resting orders.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// The asset and amount held for a resting order.
+		fn held_funds(order: &OrderOf<T>) -> Result<(AssetIdOf<T>, BalanceOf<T>), DispatchError> {
+			Ok(match order.side {
+				Side::Sell => (order.base, order.amount),
+				Side::Buy => (
+					order.quote,
+					order.amount.checked_mul(&order.price).ok_or(Error::<T>::Overflow)?,
+				),
+			})
+		}
+
+		/// Fill as much as possible of a new order against the opposite side of the book.
+		///
+		/// Returns the amount left unfilled.
+		fn match_order(
+			taker: &T::AccountId,
+			base: AssetIdOf<T>,
+			quote: AssetIdOf<T>,
+			side: Side,
+			price: BalanceOf<T>,
+			mut amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let key = (base, quote, side.opposite());
+			let mut book = OrderBook::<T>::get(&key);
+			let pallet_account = Self::account_id();
+
+			while let Some(&maker_id) = book.first() {
+				if amount.is_zero() {
+					break
+				}
+				let mut maker = Orders::<T>::get(maker_id).ok_or(Error::<T>::UnknownOrder)?;
+				if !side.crosses(price, maker.price) {
+					break
+				}
+
+				let filled = amount.min(maker.amount);
+				let cost = filled.checked_mul(&maker.price).ok_or(Error::<T>::Overflow)?;
+				// The taker pays the maker, less the fee, and receives the held funds.
+				let (paid_asset, paid, held_asset, held) = match side {
+					Side::Buy => (quote, cost, base, filled),
+					Side::Sell => (base, filled, quote, cost),
+				};
+				let fee = T::TradeFee::get().mul_floor(paid);
+				charge_fee::<_, T::Assets, T::OnFeeCharged>(
+					FeeKind::Dex,
+					taker,
+					paid_asset,
+					fee,
+					&T::FeeCollector::get(),
+				)?;
+				T::Assets::transfer(paid_asset, taker, &maker.owner, paid - fee, false)?;
+				T::Assets::transfer(held_asset, &pallet_account, taker, held, false)?;
+
+				amount -= filled;
+				maker.amount -= filled;
+
+				Self::deposit_event(Event::OrderFilled {
+					order_id: maker_id,
+					maker: maker.owner.clone(),
+					taker: taker.clone(),
+					price: maker.price,
+					amount: filled,
+				});
+
+				if maker.amount.is_zero() {
+					Orders::<T>::remove(maker_id);
+					book.remove(0);
+				} else {
+					Orders::<T>::insert(maker_id, maker);
+				}
+			}
+
+			OrderBook::<T>::insert(&key, book);
+
+			Ok(amount)
+		}
+
+		/// Hold the funds of an order and add it to its side of the book.
+		fn rest_order(
+			owner: T::AccountId,
+			base: AssetIdOf<T>,
+			quote: AssetIdOf<T>,
+			side: Side,
+			price: BalanceOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let order_id = Self::next_order_id();
+			let next_id = order_id.checked_add(1).ok_or(Error::<T>::NoAvailableOrderId)?;
+
+			let order = Order { owner: owner.clone(), base, quote, side, price, amount };
+			let (asset_id, held) = Self::held_funds(&order)?;
+			T::Assets::transfer(asset_id, &owner, &Self::account_id(), held, false)?;
+
+			OrderBook::<T>::try_mutate((base, quote, side), |book| -> DispatchResult {
+				// Orders at the same price keep arrival order.
+				let position = book
+					.iter()
+					.position(|id| {
+						Orders::<T>::get(id)
+							.map_or(false, |resting| side.is_better(price, resting.price))
+					})
+					.unwrap_or_else(|| book.len());
+				book.try_insert(position, order_id).map_err(|_| Error::<T>::BookFull.into())
+			})?;
+			Orders::<T>::insert(order_id, order);
+			NextOrderId::<T>::put(next_id);
+
+			Self::deposit_event(Event::OrderPlaced { order_id, owner, base, quote, side, price, amount });
+
+			Ok(())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			for ((base, quote, side), book) in OrderBook::<T>::iter() {
+				for order_id in book {
+					let order = Self::order(order_id).ok_or("unknown order in the book")?;
+					ensure!(
+						order.base == base && order.quote == quote && order.side == side,
+						"order in the book of another pair or side"
+					);
+				}
+			}
+			let next_id = Self::next_order_id();
+			for (order_id, order) in Orders::<T>::iter() {
+				ensure!(order_id < next_id, "order id not allocated");
+				ensure!(!order.amount.is_zero(), "order of a zero amount");
+				ensure!(
+					Self::book((order.base, order.quote, order.side)).contains(&order_id),
+					"order missing from its book"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
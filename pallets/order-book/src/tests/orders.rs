@@ -0,0 +1,101 @@
+use super::mock::*;
+use crate::{Error, Event as OrderBookEvent, Side};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn unmatched_order_rests_and_holds_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Buy, 2, 100));
+
+		assert_eq!(Assets::balance(QUOTE, ALICE), 800);
+		assert_eq!(Assets::balance(QUOTE, OrderBook::account_id()), 200);
+		assert_eq!(OrderBook::book((BASE, QUOTE, Side::Buy)).into_inner(), vec![0]);
+		assert_eq!(OrderBook::order(0).unwrap().amount, 100);
+	})
+}
+
+#[test]
+fn orders_are_sorted_by_price_then_arrival() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Sell, 5, 10));
+		assert_ok!(OrderBook::place_order(Origin::signed(BOB), BASE, QUOTE, Side::Sell, 3, 10));
+		assert_ok!(OrderBook::place_order(Origin::signed(CHARLIE), BASE, QUOTE, Side::Sell, 5, 10));
+
+		assert_eq!(OrderBook::book((BASE, QUOTE, Side::Sell)).into_inner(), vec![1, 0, 2]);
+	})
+}
+
+#[test]
+fn crossing_order_is_filled_at_maker_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Sell, 2, 100));
+
+		assert_ok!(OrderBook::place_order(Origin::signed(BOB), BASE, QUOTE, Side::Buy, 3, 40));
+
+		assert_eq!(Assets::balance(BASE, BOB), 1_040);
+		assert_eq!(Assets::balance(QUOTE, BOB), 920);
+		assert_eq!(Assets::balance(QUOTE, ALICE), 1_080);
+		assert_eq!(OrderBook::order(0).unwrap().amount, 60);
+		assert!(OrderBook::book((BASE, QUOTE, Side::Buy)).is_empty());
+		System::assert_last_event(
+			OrderBookEvent::OrderFilled { order_id: 0, maker: ALICE, taker: BOB, price: 2, amount: 40 }
+				.into(),
+		);
+	})
+}
+
+#[test]
+fn remainder_of_a_partially_filled_order_rests() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Buy, 2, 30));
+
+		assert_ok!(OrderBook::place_order(Origin::signed(BOB), BASE, QUOTE, Side::Sell, 2, 50));
+
+		assert!(OrderBook::order(0).is_none());
+		assert!(OrderBook::book((BASE, QUOTE, Side::Buy)).is_empty());
+		assert_eq!(OrderBook::book((BASE, QUOTE, Side::Sell)).into_inner(), vec![1]);
+		assert_eq!(OrderBook::order(1).unwrap().amount, 20);
+		assert_eq!(Assets::balance(BASE, ALICE), 1_030);
+		assert_eq!(Assets::balance(QUOTE, BOB), 1_060);
+		assert_eq!(Assets::balance(BASE, OrderBook::account_id()), 20);
+	})
+}
+
+#[test]
+fn non_crossing_orders_do_not_match() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Sell, 5, 10));
+		assert_ok!(OrderBook::place_order(Origin::signed(BOB), BASE, QUOTE, Side::Buy, 4, 10));
+
+		assert_eq!(OrderBook::book((BASE, QUOTE, Side::Sell)).len(), 1);
+		assert_eq!(OrderBook::book((BASE, QUOTE, Side::Buy)).len(), 1);
+	})
+}
+
+#[test]
+fn cancel_order_releases_held_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Sell, 2, 100));
+
+		assert_noop!(OrderBook::cancel_order(Origin::signed(BOB), 0), Error::<Test>::NotOrderOwner);
+		assert_ok!(OrderBook::cancel_order(Origin::signed(ALICE), 0));
+
+		assert_eq!(Assets::balance(BASE, ALICE), 1_000);
+		assert!(OrderBook::order(0).is_none());
+		assert!(OrderBook::book((BASE, QUOTE, Side::Sell)).is_empty());
+	})
+}
+
+#[test]
+fn full_book_rejects_new_orders() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..4 {
+			assert_ok!(OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Sell, 2, 1));
+		}
+
+		assert_noop!(
+			OrderBook::place_order(Origin::signed(ALICE), BASE, QUOTE, Side::Sell, 2, 1),
+			Error::<Test>::BookFull
+		);
+	})
+}
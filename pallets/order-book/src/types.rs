@@ -0,0 +1,63 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type OrderId = u64;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type OrderOf<T> = Order<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>>;
+
+/// The side of an order.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum Side {
+	/// Buy base, paying quote.
+	Buy,
+	/// Sell base, receiving quote.
+	Sell,
+}
+
+impl Side {
+	/// The side an order is matched against.
+	pub fn opposite(self) -> Self {
+		match self {
+			Side::Buy => Side::Sell,
+			Side::Sell => Side::Buy,
+		}
+	}
+
+	/// Whether a new order at `price` can be filled by a resting order at `resting_price`.
+	pub fn crosses<Balance: PartialOrd>(self, price: Balance, resting_price: Balance) -> bool {
+		match self {
+			Side::Buy => price >= resting_price,
+			Side::Sell => price <= resting_price,
+		}
+	}
+
+	/// Whether `price` is strictly better than `other` for the owner of an order on this side.
+	pub fn is_better<Balance: PartialOrd>(self, price: Balance, other: Balance) -> bool {
+		match self {
+			Side::Buy => price > other,
+			Side::Sell => price < other,
+		}
+	}
+}
+
+/// A resting order.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Order<AccountId, AssetId, Balance> {
+	/// The account that placed the order.
+	pub owner: AccountId,
+	/// The asset being traded.
+	pub base: AssetId,
+	/// The asset prices are expressed in.
+	pub quote: AssetId,
+	/// Whether the order buys or sells base.
+	pub side: Side,
+	/// The amount of quote per unit of base.
+	pub price: Balance,
+	/// The amount of base left to fill.
+	pub amount: Balance,
+}
@@ -0,0 +1,143 @@
+//! Benchmarks of the otc-swap pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	traits::{
+		tokens::fungibles::{Create, Mutate},
+		Hooks,
+	},
+	weights::Weight,
+};
+use frame_system::RawOrigin;
+use rusty_node_support::OnUnlock;
+use sp_runtime::traits::One;
+
+const GIVE_ASSET: u32 = 1_000;
+const WANT_ASSET: u32 = 1_001;
+const AMOUNT: u32 = 1_000_000;
+
+/// Create both assets of the swap, funding `holders` with each.
+fn create_assets<T: Config>(holders: &[&T::AccountId])
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	let owner: T::AccountId = account("owner", 0, 0);
+	for asset_id in [GIVE_ASSET, WANT_ASSET] {
+		T::Assets::create(asset_id.into(), owner.clone(), true, One::one()).unwrap();
+		for who in holders {
+			T::Assets::mint_into(asset_id.into(), who, AMOUNT.into()).unwrap();
+		}
+	}
+}
+
+/// Have `maker` offer a swap to `taker`, expiring at block `expiry`.
+fn new_offer<T: Config>(
+	maker: &T::AccountId,
+	taker: &T::AccountId,
+	expiry: T::BlockNumber,
+) -> OfferId
+where
+	AssetIdOf<T>: From<u32>,
+{
+	let offer_id = Pallet::<T>::next_offer_id();
+	Pallet::<T>::create_offer(
+		RawOrigin::Signed(maker.clone()).into(),
+		GIVE_ASSET.into(),
+		AMOUNT.into(),
+		WANT_ASSET.into(),
+		AMOUNT.into(),
+		Some(taker.clone()),
+		expiry,
+	)
+	.unwrap();
+	offer_id
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	create_offer {
+		let maker: T::AccountId = whitelisted_caller();
+		let taker: T::AccountId = account("taker", 0, 0);
+		create_assets::<T>(&[&maker]);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+	}: _(
+		RawOrigin::Signed(maker),
+		GIVE_ASSET.into(),
+		AMOUNT.into(),
+		WANT_ASSET.into(),
+		AMOUNT.into(),
+		Some(taker),
+		expiry
+	)
+	verify {
+		assert!(Pallet::<T>::offer(0).is_some());
+	}
+
+	take_offer {
+		let maker: T::AccountId = account("maker", 0, 0);
+		let taker: T::AccountId = whitelisted_caller();
+		create_assets::<T>(&[&maker, &taker]);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		let offer_id = new_offer::<T>(&maker, &taker, expiry);
+	}: _(RawOrigin::Signed(taker), offer_id)
+	verify {
+		assert!(Pallet::<T>::offer(offer_id).is_none());
+	}
+
+	// Closing an expired offer, the caller not being its maker.
+	close_offer {
+		let maker: T::AccountId = account("maker", 0, 0);
+		let taker: T::AccountId = account("taker", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		create_assets::<T>(&[&maker]);
+		let expiry = frame_system::Pallet::<T>::block_number() + One::one();
+		let offer_id = new_offer::<T>(&maker, &taker, expiry);
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: _(RawOrigin::Signed(caller), offer_id)
+	verify {
+		assert!(Pallet::<T>::offer(offer_id).is_none());
+	}
+
+	// The escrow of the offer goes back to its maker.
+	expire_offer {
+		let maker: T::AccountId = account("maker", 0, 0);
+		let taker: T::AccountId = account("taker", 0, 0);
+		create_assets::<T>(&[&maker]);
+		let expiry = frame_system::Pallet::<T>::block_number() + One::one();
+		let offer_id = new_offer::<T>(&maker, &taker, expiry);
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: {
+		Pallet::<T>::on_unlock(offer_id);
+	}
+	verify {
+		assert!(Pallet::<T>::offer(offer_id).is_none());
+	}
+
+	// `n` expired offers are closed at the end of the block, each expiring at a block of its own
+	// so that their expiries fit in the unlock queue.
+	on_idle {
+		let n in 0 .. 100;
+		let maker: T::AccountId = account("maker", 0, 0);
+		let taker: T::AccountId = account("taker", 0, 0);
+		create_assets::<T>(&[&maker]);
+		T::Assets::mint_into(GIVE_ASSET.into(), &maker, (AMOUNT * n).into())?;
+		let mut expiry = frame_system::Pallet::<T>::block_number();
+		for _ in 0..n {
+			expiry += One::one();
+			new_offer::<T>(&maker, &taker, expiry);
+		}
+	}: {
+		Pallet::<T>::on_idle(expiry, Weight::max_value());
+	}
+	verify {
+		assert_eq!(Offers::<T>::iter().count(), 0);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,378 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Over-the-counter swaps between two accounts.
+///
+/// A maker offers to give some amount of an asset in exchange for some amount of another, possibly
+/// to a single counterparty only. The given side is held in escrow in the pallet account, so a
+/// taker filling the offer is guaranteed to receive it in the same extrinsic that pays the maker.
+///
+/// Takers are charged the `TradeFee` on what they pay, out of what the maker receives, and the fee
+/// is reported to `OnFeeCharged`, so that referrers get their share.
+///
+/// The expiry of each offer is scheduled in the `Unlocks` queue, which closes the offer if it is
+/// still open by then. The expired offers the queue could not close are closed with the weight
+/// left at the end of blocks, a bounded batch at a time.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*, storage::StoragePrefixedMap, traits::tokens::fungibles, transactional,
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::{
+		fees::charge_fee,
+		pagination::{decode_key, iter_page},
+		FeeKind, OnFeeCharged, OnUnlock, ScheduleUnlock,
+	};
+	use sp_runtime::{
+		traits::{AccountIdConversion, Zero},
+		Perbill,
+	};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets swapped.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The share of what a taker pays charged as a fee.
+		#[pallet::constant]
+		type TradeFee: Get<Perbill>;
+
+		/// The account collecting the fees, e.g. the treasury.
+		type FeeCollector: Get<Self::AccountId>;
+
+		/// Shares the fees with the referrers of the takers paying them.
+		type OnFeeCharged: OnFeeCharged<Self::AccountId, AssetIdOf<Self>, BalanceOf<Self>>;
+
+		/// The queue the expiries of the offers are scheduled in, which calls back `OnUnlock` of
+		/// the pallet with the offer.
+		type Unlocks: ScheduleUnlock<Self::BlockNumber, OfferId>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next offer will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_offer_id)]
+	pub type NextOfferId<T> = StorageValue<_, OfferId, ValueQuery>;
+
+	/// The open offers.
+	#[pallet::storage]
+	#[pallet::getter(fn offer)]
+	pub type Offers<T: Config> = StorageMap<_, Twox64Concat, OfferId, OfferOf<T>>;
+
+	/// The last offer inspected for expiry, which the next cleanup resumes after.
+	#[pallet::storage]
+	#[pallet::getter(fn cleanup_cursor)]
+	pub type CleanupCursor<T> = StorageValue<_, OfferId>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new offer has been posted.
+		OfferCreated {
+			offer_id: OfferId,
+			maker: T::AccountId,
+			give_asset: AssetIdOf<T>,
+			give_amount: BalanceOf<T>,
+			want_asset: AssetIdOf<T>,
+			want_amount: BalanceOf<T>,
+		},
+		/// An offer has been taken.
+		OfferTaken { offer_id: OfferId, maker: T::AccountId, taker: T::AccountId },
+		/// An offer has been canceled, or removed after expiring, and its escrow refunded.
+		OfferClosed { offer_id: OfferId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The offer does not exist.
+		UnknownOffer,
+		/// The two sides of an offer must use different assets.
+		SameAsset,
+		/// Both amounts must be non zero.
+		ZeroAmount,
+		/// The expiry block is already in the past.
+		ExpiryInThePast,
+		/// The offer has expired.
+		Expired,
+		/// The caller is not allowed to take or cancel this offer.
+		NoPermission,
+		/// There is no offer id left.
+		NoAvailableOfferId,
+		/// Too many expiries are already scheduled at the block.
+		TooManyExpiriesInBlock,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::close_expired(now, remaining_weight)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Offer `give_amount` of `give_asset` for `want_amount` of `want_asset`.
+		///
+		/// The given amount is escrowed until the offer is taken, canceled or expires.
+		#[pallet::weight(T::WeightInfo::create_offer())]
+		#[transactional]
+		pub fn create_offer(
+			origin: OriginFor<T>,
+			give_asset: AssetIdOf<T>,
+			give_amount: BalanceOf<T>,
+			want_asset: AssetIdOf<T>,
+			want_amount: BalanceOf<T>,
+			counterparty: Option<T::AccountId>,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let maker = ensure_signed(origin)?;
+
+			ensure!(give_asset != want_asset, Error::<T>::SameAsset);
+			ensure!(!give_amount.is_zero() && !want_amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ExpiryInThePast
+			);
+
+			let offer_id = Self::next_offer_id();
+			let next_id = offer_id.checked_add(1).ok_or(Error::<T>::NoAvailableOfferId)?;
+
+			T::Unlocks::schedule(expiry, offer_id)
+				.map_err(|_| Error::<T>::TooManyExpiriesInBlock)?;
+			T::Assets::transfer(give_asset, &maker, &Self::account_id(), give_amount, false)?;
+
+			Offers::<T>::insert(
+				offer_id,
+				Offer {
+					maker: maker.clone(),
+					give_asset,
+					give_amount,
+					want_asset,
+					want_amount,
+					counterparty,
+					expiry,
+				},
+			);
+			NextOfferId::<T>::put(next_id);
+
+			Self::deposit_event(Event::OfferCreated {
+				offer_id,
+				maker,
+				give_asset,
+				give_amount,
+				want_asset,
+				want_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Take an offer: pay the maker and receive the escrowed amount.
+		#[pallet::weight(T::WeightInfo::take_offer())]
+		#[transactional]
+		pub fn take_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+			let taker = ensure_signed(origin)?;
+
+			let offer = Self::offer(offer_id).ok_or(Error::<T>::UnknownOffer)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < offer.expiry,
+				Error::<T>::Expired
+			);
+			if let Some(counterparty) = &offer.counterparty {
+				ensure!(*counterparty == taker, Error::<T>::NoPermission);
+			}
+
+			let fee = T::TradeFee::get().mul_floor(offer.want_amount);
+			charge_fee::<_, T::Assets, T::OnFeeCharged>(
+				FeeKind::Dex,
+				&taker,
+				offer.want_asset,
+				fee,
+				&T::FeeCollector::get(),
+			)?;
+			T::Assets::transfer(
+				offer.want_asset,
+				&taker,
+				&offer.maker,
+				offer.want_amount - fee,
+				false,
+			)?;
+			T::Assets::transfer(
+				offer.give_asset,
+				&Self::account_id(),
+				&taker,
+				offer.give_amount,
+				false,
+			)?;
+			Offers::<T>::remove(offer_id);
+			T::Unlocks::cancel(offer.expiry, offer_id);
+
+			Self::deposit_event(Event::OfferTaken { offer_id, maker: offer.maker, taker });
+
+			Ok(())
+		}
+
+		/// Close an offer and refund its escrow to the maker.
+		///
+		/// The maker can cancel an offer at any time; anyone can close an expired offer.
+		#[pallet::weight(T::WeightInfo::close_offer())]
+		pub fn close_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let offer = Self::offer(offer_id).ok_or(Error::<T>::UnknownOffer)?;
+			ensure!(
+				who == offer.maker ||
+					frame_system::Pallet::<T>::block_number() >= offer.expiry,
+				Error::<T>::NoPermission
+			);
+
+			Self::close(offer_id, offer)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding escrowed offers.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Refund the escrow of an offer to its maker and remove it.
+		fn close(offer_id: OfferId, offer: OfferOf<T>) -> DispatchResult {
+			T::Assets::transfer(
+				offer.give_asset,
+				&Self::account_id(),
+				&offer.maker,
+				offer.give_amount,
+				false,
+			)?;
+			Offers::<T>::remove(offer_id);
+			T::Unlocks::cancel(offer.expiry, offer_id);
+
+			Self::deposit_event(Event::OfferClosed { offer_id });
+
+			Ok(())
+		}
+
+		/// Close an offer once it expired.
+		///
+		/// Runs from the unlock queue, so a failing refund is not fatal: the offer is left for its
+		/// maker, or anyone, to close.
+		pub(crate) fn expire_offer(offer_id: OfferId) {
+			if let Some(offer) = Self::offer(offer_id) {
+				if frame_system::Pallet::<T>::block_number() >= offer.expiry {
+					let _ = Self::close(offer_id, offer);
+				}
+			}
+		}
+
+		/// Close the expired offers among as many as `remaining_weight` allows inspecting.
+		///
+		/// Offers are inspected in storage order, from where the previous cleanup stopped, so
+		/// every offer is eventually inspected however little weight each block has left. Most
+		/// expired offers are closed by the unlock queue already: this closes those whose refund
+		/// failed then, or whose expiry could not be scheduled when they were migrated.
+		pub fn close_expired(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let base = T::WeightInfo::on_idle(0);
+			let per_offer = T::WeightInfo::on_idle(1).saturating_sub(base).max(1);
+			let max = remaining_weight.saturating_sub(base) / per_offer;
+			if max == 0 {
+				return 0
+			}
+
+			let page = iter_page(
+				&Offers::<T>::final_prefix(),
+				Self::cleanup_cursor().map(Offers::<T>::hashed_key_for),
+				max.min(u32::MAX.into()) as u32,
+				|key, value| {
+					let offer_id = decode_key::<Twox64Concat, OfferId>(&mut &key[..])?;
+					Some((offer_id, OfferOf::<T>::decode(&mut &value[..]).ok()?))
+				},
+			);
+			// Once the last offer is inspected, the next cleanup starts over from the first one.
+			let cursor = page.next_key.and(page.items.last().map(|(offer_id, _)| *offer_id));
+			CleanupCursor::<T>::set(cursor);
+
+			let expired = page.items.into_iter().filter(|(_, offer)| now >= offer.expiry);
+			for (offer_id, offer) in expired {
+				// An offer whose escrow cannot be refunded is left for its maker to close.
+				let _ = Self::close(offer_id, offer);
+			}
+
+			T::WeightInfo::on_idle(page.read)
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use rusty_node_support::escrow::ensure_escrowed;
+
+			let next_id = Self::next_offer_id();
+			ensure!(Offers::<T>::iter_keys().all(|id| id < next_id), "offer id not allocated");
+
+			// The escrow account holds what every open offer gives.
+			let account = Self::account_id();
+			ensure_escrowed::<_, T::Assets>(
+				&account,
+				Offers::<T>::iter_values().map(|offer| (offer.give_asset, offer.give_amount)),
+				"offers not escrowed",
+			)?;
+
+			Ok(())
+		}
+	}
+
+	/// The expiries of the offers, scheduled in `Unlocks`.
+	impl<T: Config> OnUnlock<OfferId> for Pallet<T> {
+		fn unlock_weight(_offer_id: &OfferId) -> Weight {
+			T::WeightInfo::expire_offer()
+		}
+
+		fn on_unlock(offer_id: OfferId) -> Weight {
+			Self::expire_offer(offer_id);
+			T::WeightInfo::expire_offer()
+		}
+	}
+}
@@ -0,0 +1,62 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 schedules the expiries of the offers in `Unlocks`, so that `on_idle` only has the
+/// offers the queue could not close left to scan for, and restarts its scan from the first offer.
+///
+/// An offer whose expiry is past, or no longer fits in its block of the queue, is closed right
+/// away if it expired. One that did not is left for `on_idle`, its maker, or anyone, to close.
+pub mod v2 {
+	use super::*;
+	use crate::{OfferId, Offers};
+	use frame_support::storage::migration::take_storage_value;
+	use rusty_node_support::unlocks::schedule_or_unlock;
+
+	pub fn migrate<T: Config>() -> Weight {
+		take_storage_value::<OfferId>(Pallet::<T>::name().as_bytes(), b"CleanupCursor", &[]);
+
+		let mut stored = 0u64;
+		let unlocks = Offers::<T>::iter().map(|(offer_id, offer)| {
+			stored += 1;
+			(offer.expiry, offer_id)
+		});
+		let weight = schedule_or_unlock::<_, _, T::Unlocks, Pallet<T>>(unlocks, T::DbWeight::get());
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		weight.saturating_add(T::DbWeight::get().reads_writes(stored, 2))
+	}
+}
@@ -0,0 +1,72 @@
+use super::mock::*;
+use crate::WeightInfo;
+use frame_support::{assert_ok, traits::Hooks, weights::Weight};
+
+/// Have ALICE make `n` offers expiring at block `expiry`, which the unlock queue then misses.
+fn create_unscheduled_offers(n: u32, expiry: u64) {
+	for _ in 0..n {
+		assert_ok!(OtcSwap::create_offer(
+			Origin::signed(ALICE),
+			ASSET_A,
+			100,
+			ASSET_B,
+			250,
+			None,
+			expiry
+		));
+	}
+	pallet_unlock_queue::Queue::<Test>::remove(expiry);
+}
+
+/// The weight of inspecting `n` offers for expiry.
+fn cleanup_weight(n: u32) -> Weight {
+	<() as WeightInfo>::on_idle(n)
+}
+
+fn open_offers() -> usize {
+	crate::Offers::<Test>::iter().count()
+}
+
+#[test]
+fn on_idle_closes_the_expired_offers_the_queue_missed() {
+	new_test_ext().execute_with(|| {
+		create_unscheduled_offers(2, 10);
+		create_unscheduled_offers(1, 20);
+
+		System::set_block_number(10);
+		assert_eq!(OtcSwap::on_idle(10, Weight::max_value()), cleanup_weight(3));
+
+		assert_eq!(open_offers(), 1);
+		assert!(OtcSwap::offer(2).is_some());
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 900);
+		assert_eq!(OtcSwap::cleanup_cursor(), None);
+	})
+}
+
+#[test]
+fn on_idle_resumes_where_it_stopped() {
+	new_test_ext().execute_with(|| {
+		create_unscheduled_offers(3, 10);
+		System::set_block_number(10);
+
+		assert_eq!(OtcSwap::on_idle(10, cleanup_weight(2)), cleanup_weight(2));
+		assert_eq!(open_offers(), 1);
+		assert!(OtcSwap::cleanup_cursor().is_some());
+
+		assert_eq!(OtcSwap::on_idle(11, cleanup_weight(2)), cleanup_weight(1));
+		assert_eq!(open_offers(), 0);
+		assert_eq!(OtcSwap::cleanup_cursor(), None);
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 1_000);
+	})
+}
+
+#[test]
+fn on_idle_does_nothing_without_enough_weight() {
+	new_test_ext().execute_with(|| {
+		create_unscheduled_offers(1, 10);
+		System::set_block_number(10);
+
+		assert_eq!(OtcSwap::on_idle(10, cleanup_weight(0)), 0);
+		assert_eq!(open_offers(), 1);
+	})
+}
@@ -0,0 +1,63 @@
+use super::mock::*;
+use crate::{Error, Event as SwapEvent};
+use frame_support::{assert_noop, assert_ok};
+use rusty_node_support::ScheduleUnlock;
+
+/// Have ALICE make `n` offers expiring at block `expiry`.
+fn create_offers(n: u32, expiry: u64) {
+	for _ in 0..n {
+		assert_ok!(OtcSwap::create_offer(
+			Origin::signed(ALICE),
+			ASSET_A,
+			100,
+			ASSET_B,
+			250,
+			None,
+			expiry
+		));
+	}
+}
+
+#[test]
+fn expired_offers_are_closed_at_their_expiry() {
+	new_test_ext().execute_with(|| {
+		create_offers(2, 10);
+		create_offers(1, 20);
+		assert_eq!(UnlockQueue::queue(10).into_inner(), vec![0, 1]);
+
+		run_to_block(9);
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 700);
+
+		run_to_block(10);
+		assert!(OtcSwap::offer(0).is_none() && OtcSwap::offer(1).is_none());
+		assert!(OtcSwap::offer(2).is_some());
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 900);
+		System::assert_has_event(SwapEvent::OfferClosed { offer_id: 1 }.into());
+	})
+}
+
+#[test]
+fn taken_and_closed_offers_are_no_longer_scheduled() {
+	new_test_ext().execute_with(|| {
+		create_offers(2, 10);
+
+		assert_ok!(OtcSwap::take_offer(Origin::signed(BOB), 0));
+		assert_ok!(OtcSwap::close_offer(Origin::signed(ALICE), 1));
+
+		assert!(UnlockQueue::queue(10).is_empty());
+	})
+}
+
+#[test]
+fn create_offer_fails_when_too_many_expiries_are_in_the_block() {
+	new_test_ext().execute_with(|| {
+		for offer_id in 100..100 + MaxUnlocksPerBlock::get() {
+			assert_ok!(<UnlockQueue as ScheduleUnlock<_, _>>::schedule(10, offer_id));
+		}
+
+		assert_noop!(
+			OtcSwap::create_offer(Origin::signed(ALICE), ASSET_A, 100, ASSET_B, 250, None, 10),
+			Error::<Test>::TooManyExpiriesInBlock
+		);
+	})
+}
@@ -0,0 +1,50 @@
+use super::mock::*;
+use crate::migrations;
+use frame_support::{
+	assert_ok,
+	storage::migration::{have_storage_value, put_storage_value},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+#[test]
+fn runtime_upgrade_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<OtcSwap>();
+
+		<OtcSwap as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(OtcSwap::on_chain_storage_version(), OtcSwap::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_schedules_the_expiries_in_the_unlock_queue() {
+	new_test_ext().execute_with(|| {
+		for expiry in [5, 10] {
+			assert_ok!(OtcSwap::create_offer(
+				Origin::signed(ALICE),
+				ASSET_A,
+				100,
+				ASSET_B,
+				250,
+				None,
+				expiry
+			));
+		}
+		// Version 1 scanned for the expired offers from a cursor of the pallet.
+		pallet_unlock_queue::Queue::<Test>::remove(5);
+		pallet_unlock_queue::Queue::<Test>::remove(10);
+		put_storage_value(b"OtcSwap", b"CleanupCursor", &[], 0u32);
+		StorageVersion::new(1).put::<OtcSwap>();
+		System::set_block_number(5);
+
+		migrations::migrate::<Test>();
+
+		assert!(!have_storage_value(b"OtcSwap", b"CleanupCursor", &[]));
+		// The offer past its expiry went back to its maker right away.
+		assert!(OtcSwap::offer(0).is_none());
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 900);
+		assert_eq!(UnlockQueue::queue(10).into_inner(), vec![1]);
+		assert_eq!(OtcSwap::on_chain_storage_version(), 2);
+	})
+}
@@ -0,0 +1,151 @@
+use crate as pallet_otc_swap;
+use frame_support::{parameter_types, weights::Weight, PalletId};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
+};
+use test_utils::ExtBuilder;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const FEE_COLLECTOR: u64 = 99;
+
+pub const ASSET_A: u32 = 0;
+pub const ASSET_B: u32 = 1;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		OtcSwap: pallet_otc_swap::{Pallet, Call, Storage, Event<T>},
+		UnlockQueue: pallet_unlock_queue::{Pallet, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type AccountData = pallet_balances::AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: u64 = 0;
+	pub const ApprovalDeposit: u64 = 0;
+	pub const MetadataDepositBase: u64 = 0;
+	pub const MetadataDepositPerByte: u64 = 0;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ApprovalDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = u32;
+	type Balance = u64;
+	type Currency = Balances;
+	type Event = Event;
+	type Extra = ();
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Freezer = ();
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const OtcSwapPalletId: PalletId = PalletId(*b"py/otcsw");
+	pub const FeeCollector: u64 = FEE_COLLECTOR;
+	pub static TradeFee: Perbill = Perbill::from_percent(0);
+}
+
+impl pallet_otc_swap::Config for Test {
+	type Assets = Assets;
+	type Event = Event;
+	type FeeCollector = FeeCollector;
+	type OnFeeCharged = ();
+	type PalletId = OtcSwapPalletId;
+	type TradeFee = TradeFee;
+	type Unlocks = UnlockQueue;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MaximumUnlockWeight: Weight = Weight::MAX;
+	pub const MaxUnlocksPerBlock: u32 = 4;
+}
+
+impl pallet_unlock_queue::Config for Test {
+	type Event = Event;
+	type Key = u32;
+	type MaxUnlocksPerBlock = MaxUnlocksPerBlock;
+	type MaximumWeight = MaximumUnlockWeight;
+	type OnUnlock = OtcSwap;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Test>::default()
+		.balances(vec![(ALICE, 100), (BOB, 100)])
+		.asset(ASSET_A, ALICE, vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 1_000)])
+		.asset(ASSET_B, ALICE, vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 1_000)])
+		.build()
+}
+
+pub fn run_to_block(n: u64) {
+	test_utils::run_to_block::<Test, UnlockQueue>(n)
+}
@@ -0,0 +1,128 @@
+use super::mock::*;
+use crate::{Error, Event as SwapEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Perbill;
+
+fn create_offer(counterparty: Option<u64>) {
+	assert_ok!(OtcSwap::create_offer(
+		Origin::signed(ALICE),
+		ASSET_A,
+		100,
+		ASSET_B,
+		250,
+		counterparty,
+		10
+	));
+}
+
+#[test]
+fn create_offer_escrows_the_given_side() {
+	new_test_ext().execute_with(|| {
+		create_offer(None);
+
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 900);
+		assert_eq!(Assets::balance(ASSET_A, OtcSwap::account_id()), 100);
+		assert_eq!(OtcSwap::next_offer_id(), 1);
+	})
+}
+
+#[test]
+fn create_offer_checks_its_terms() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			OtcSwap::create_offer(Origin::signed(ALICE), ASSET_A, 100, ASSET_A, 250, None, 10),
+			Error::<Test>::SameAsset
+		);
+		assert_noop!(
+			OtcSwap::create_offer(Origin::signed(ALICE), ASSET_A, 0, ASSET_B, 250, None, 10),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			OtcSwap::create_offer(Origin::signed(ALICE), ASSET_A, 100, ASSET_B, 250, None, 1),
+			Error::<Test>::ExpiryInThePast
+		);
+	})
+}
+
+#[test]
+fn take_offer_swaps_both_sides() {
+	new_test_ext().execute_with(|| {
+		create_offer(None);
+
+		assert_ok!(OtcSwap::take_offer(Origin::signed(BOB), 0));
+
+		assert_eq!(Assets::balance(ASSET_A, BOB), 1_100);
+		assert_eq!(Assets::balance(ASSET_B, BOB), 750);
+		assert_eq!(Assets::balance(ASSET_B, ALICE), 1_250);
+		assert!(OtcSwap::offer(0).is_none());
+		System::assert_last_event(
+			SwapEvent::OfferTaken { offer_id: 0, maker: ALICE, taker: BOB }.into(),
+		);
+	})
+}
+
+#[test]
+fn take_offer_charges_the_fee_out_of_the_payment() {
+	new_test_ext().execute_with(|| {
+		TradeFee::set(Perbill::from_percent(10));
+		create_offer(None);
+
+		assert_ok!(OtcSwap::take_offer(Origin::signed(BOB), 0));
+
+		assert_eq!(Assets::balance(ASSET_B, BOB), 750);
+		assert_eq!(Assets::balance(ASSET_B, ALICE), 1_225);
+		assert_eq!(Assets::balance(ASSET_B, FEE_COLLECTOR), 25);
+	})
+}
+
+#[test]
+fn failed_take_offer_rolls_back_the_payment() {
+	new_test_ext().execute_with(|| {
+		create_offer(None);
+
+		// The taker pays, then the release of the escrowed side fails.
+		assert_ok!(Assets::freeze_asset(Origin::signed(ALICE), ASSET_A));
+		assert_noop!(
+			OtcSwap::take_offer(Origin::signed(BOB), 0),
+			pallet_assets::Error::<Test>::Frozen
+		);
+		assert_eq!(Assets::balance(ASSET_B, BOB), 1_000);
+		assert!(OtcSwap::offer(0).is_some());
+	})
+}
+
+#[test]
+fn only_the_counterparty_can_take_a_private_offer() {
+	new_test_ext().execute_with(|| {
+		create_offer(Some(CHARLIE));
+
+		assert_noop!(OtcSwap::take_offer(Origin::signed(BOB), 0), Error::<Test>::NoPermission);
+		assert_ok!(OtcSwap::take_offer(Origin::signed(CHARLIE), 0));
+	})
+}
+
+#[test]
+fn expired_offer_cannot_be_taken_and_is_refunded() {
+	new_test_ext().execute_with(|| {
+		create_offer(None);
+
+		assert_noop!(OtcSwap::close_offer(Origin::signed(BOB), 0), Error::<Test>::NoPermission);
+
+		System::set_block_number(10);
+		assert_noop!(OtcSwap::take_offer(Origin::signed(BOB), 0), Error::<Test>::Expired);
+		assert_ok!(OtcSwap::close_offer(Origin::signed(BOB), 0));
+
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 1_000);
+		System::assert_last_event(SwapEvent::OfferClosed { offer_id: 0 }.into());
+	})
+}
+
+#[test]
+fn maker_can_cancel_before_expiry() {
+	new_test_ext().execute_with(|| {
+		create_offer(None);
+
+		assert_ok!(OtcSwap::close_offer(Origin::signed(ALICE), 0));
+		assert_eq!(Assets::balance(ASSET_A, ALICE), 1_000);
+	})
+}
@@ -0,0 +1,35 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type OfferId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type OfferOf<T> = Offer<
+	AccountIdOf<T>,
+	AssetIdOf<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// An offer to swap some amount of an asset for some amount of another.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Offer<AccountId, AssetId, Balance, BlockNumber> {
+	/// The account that posted the offer.
+	pub maker: AccountId,
+	/// The asset the maker gives, held in escrow.
+	pub give_asset: AssetId,
+	/// The amount the maker gives.
+	pub give_amount: Balance,
+	/// The asset the maker wants in return.
+	pub want_asset: AssetId,
+	/// The amount the maker wants in return.
+	pub want_amount: Balance,
+	/// The only account allowed to take the offer, if any.
+	pub counterparty: Option<AccountId>,
+	/// The block from which the offer can no longer be taken.
+	pub expiry: BlockNumber,
+}
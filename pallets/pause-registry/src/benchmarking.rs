@@ -0,0 +1,34 @@
+//! Benchmarks of the pause-registry pallet.
+
+use super::*;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{EnsureOrigin, Get};
+use sp_std::prelude::*;
+
+fn name(n: u32) -> Vec<u8> {
+	vec![b'a'; n as usize]
+}
+
+benchmarks! {
+	// `n` is the length of the name of the pallet.
+	pause {
+		let n in 1 .. T::MaxNameLength::get();
+		let origin = T::PauseOrigin::successful_origin();
+		let pallet = name(n);
+	}: _<T::Origin>(origin, pallet.clone())
+	verify {
+		assert!(Pallet::<T>::is_paused(&pallet));
+	}
+
+	unpause {
+		let n in 1 .. T::MaxNameLength::get();
+		let pallet = name(n);
+		Pallet::<T>::pause(T::PauseOrigin::successful_origin(), pallet.clone())?;
+		let origin = T::PauseOrigin::successful_origin();
+	}: _<T::Origin>(origin, pallet.clone())
+	verify {
+		assert!(!Pallet::<T>::is_paused(&pallet));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,148 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Pause the calls of other pallets, as the circuit breaker of the runtime.
+///
+/// A privileged origin pauses a pallet by its name in the runtime, among the `PausablePallets`,
+/// and unpauses it once it is safe again. The pallet implements [`Pausable`]: the runtime filters
+/// out the calls of the paused pallets with [`Pausable::is_paused`], and pallets implementing
+/// [`PauseGuard`](rusty_node_support::PauseGuard) over it fail their extrinsics with `Paused`
+/// while they are paused, in any runtime.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use rusty_node_support::Pausable;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::Contains};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::Pausable;
+	use sp_std::prelude::*;
+
+	/// The name of a pallet in the runtime.
+	pub type PalletNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to pause and unpause pallets.
+		type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of the name of a pallet.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// The names of the pallets that can be paused.
+		type PausablePallets: Contains<Vec<u8>>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The paused pallets, by name.
+	#[pallet::storage]
+	pub type Paused<T: Config> = StorageMap<_, Blake2_128Concat, PalletNameOf<T>, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event {
+		/// The calls of a pallet have been paused.
+		PalletPaused { pallet: Vec<u8> },
+		/// The calls of a pallet have been unpaused.
+		PalletUnpaused { pallet: Vec<u8> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The name of the pallet is longer than `MaxNameLength`.
+		NameTooLong,
+		/// The pallet is already paused.
+		AlreadyPaused,
+		/// The pallet is not paused.
+		NotPaused,
+		/// The pallet is not one of the `PausablePallets`.
+		NotPausable,
+		/// The calls of the pallet are paused.
+		Paused,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// There is no `try_state` to run after the upgrade: the paused pallets are independent
+		// entries, with no invariant between them.
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause the calls of the pallet named `pallet` in the runtime.
+		///
+		/// Operational, to get through even when blocks are full. Its weight grows with the length
+		/// of `pallet`, which is only checked against `MaxNameLength` once decoded.
+		#[pallet::weight((T::WeightInfo::pause(pallet.len() as u32), DispatchClass::Operational))]
+		pub fn pause(origin: OriginFor<T>, pallet: Vec<u8>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			let name = Self::name_of(&pallet)?;
+			ensure!(T::PausablePallets::contains(&pallet), Error::<T>::NotPausable);
+			ensure!(!Paused::<T>::contains_key(&name), Error::<T>::AlreadyPaused);
+			Paused::<T>::insert(&name, ());
+
+			Self::deposit_event(Event::PalletPaused { pallet });
+
+			Ok(())
+		}
+
+		/// Unpause the calls of the pallet named `pallet` in the runtime.
+		#[pallet::weight((T::WeightInfo::unpause(pallet.len() as u32), DispatchClass::Operational))]
+		pub fn unpause(origin: OriginFor<T>, pallet: Vec<u8>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			let name = Self::name_of(&pallet)?;
+			ensure!(Paused::<T>::contains_key(&name), Error::<T>::NotPaused);
+			Paused::<T>::remove(&name);
+
+			Self::deposit_event(Event::PalletUnpaused { pallet });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn name_of(pallet: &[u8]) -> Result<PalletNameOf<T>, Error<T>> {
+			pallet.to_vec().try_into().map_err(|_| Error::<T>::NameTooLong)
+		}
+	}
+
+	impl<T: Config> Pausable for Pallet<T> {
+		fn is_paused(pallet: &[u8]) -> bool {
+			Self::name_of(pallet).map_or(false, |name| Paused::<T>::contains_key(name))
+		}
+
+		fn ensure_not_paused(pallet: &[u8]) -> DispatchResult {
+			ensure!(!Self::is_paused(pallet), Error::<T>::Paused);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,76 @@
+use super::mock::*;
+use crate::{Error, Event as PauseEvent, Pausable};
+use frame_support::{
+	assert_noop, assert_ok,
+	weights::{DispatchClass, GetDispatchInfo},
+};
+use sp_runtime::DispatchError;
+
+const KITTIES: &[u8] = b"Kitties";
+
+#[test]
+fn root_pauses_and_unpauses_pallets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PauseRegistry::pause(Origin::signed(ALICE), KITTIES.to_vec()),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(PauseRegistry::pause(Origin::root(), KITTIES.to_vec()));
+		assert!(PauseRegistry::is_paused(KITTIES));
+		assert!(!PauseRegistry::is_paused(b"Assets"));
+		System::assert_last_event(PauseEvent::PalletPaused { pallet: KITTIES.to_vec() }.into());
+		assert_noop!(
+			PauseRegistry::pause(Origin::root(), KITTIES.to_vec()),
+			Error::<Test>::AlreadyPaused
+		);
+
+		assert_ok!(PauseRegistry::unpause(Origin::root(), KITTIES.to_vec()));
+		assert!(!PauseRegistry::is_paused(KITTIES));
+		System::assert_last_event(PauseEvent::PalletUnpaused { pallet: KITTIES.to_vec() }.into());
+		assert_noop!(
+			PauseRegistry::unpause(Origin::root(), KITTIES.to_vec()),
+			Error::<Test>::NotPaused
+		);
+	})
+}
+
+#[test]
+fn pallet_names_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PauseRegistry::pause(Origin::root(), vec![b'a'; 17]),
+			Error::<Test>::NameTooLong
+		);
+		assert!(!PauseRegistry::is_paused(&[b'a'; 17]));
+	})
+}
+
+#[test]
+fn only_pausable_pallets_are_paused() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PauseRegistry::pause(Origin::root(), b"System".to_vec()),
+			Error::<Test>::NotPausable
+		);
+		assert!(!PauseRegistry::is_paused(b"System"));
+	})
+}
+
+#[test]
+fn ensure_not_paused_fails_while_paused() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PauseRegistry::ensure_not_paused(KITTIES));
+
+		assert_ok!(PauseRegistry::pause(Origin::root(), KITTIES.to_vec()));
+		assert_noop!(PauseRegistry::ensure_not_paused(KITTIES), Error::<Test>::Paused);
+	})
+}
+
+#[test]
+fn pauses_are_operational_and_weighed_by_name_length() {
+	let pause = |len| crate::Call::<Test>::pause { pallet: vec![b'a'; len] }.get_dispatch_info();
+
+	assert_eq!(pause(8).class, DispatchClass::Operational);
+	assert!(pause(1_000).weight > pause(8).weight);
+}
@@ -0,0 +1,90 @@
+//! Benchmarks of the price-fetch pallet.
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	traits::{Get, UnfilteredDispatchable},
+	unsigned::ValidateUnsigned,
+	BoundedVec,
+};
+use frame_system::{offchain::AppCrypto, RawOrigin};
+use sp_runtime::{
+	traits::IdentifyAccount, transaction_validity::TransactionSource, RuntimeAppPublic,
+};
+use sp_std::prelude::*;
+
+const PRICE: Price = 42_000_000;
+
+/// Make `authority` the last of `a` authorities.
+fn set_authorities<T: Config>(a: u32, authority: &T::AccountId) {
+	let mut authorities: Vec<T::AccountId> = (1..a).map(|i| account("authority", i, 0)).collect();
+	authorities.push(authority.clone());
+	Authorities::<T>::put(BoundedVec::try_from(authorities).unwrap());
+}
+
+/// A new authority key, in the keystore.
+fn generate_key<T: Config>() -> T::Public {
+	let key =
+		<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+			None,
+		);
+	<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key).into()
+}
+
+benchmarks! {
+	submit_price {
+		let a in 1 .. T::MaxAuthorities::get();
+		let authority: T::AccountId = whitelisted_caller();
+		set_authorities::<T>(a, &authority);
+	}: _(RawOrigin::Signed(authority), PRICE)
+	verify {
+		assert_eq!(Pallet::<T>::price(), Some(PRICE));
+	}
+
+	// The validation of the transaction, which checks its signature, is part of the measure.
+	submit_price_unsigned {
+		let a in 1 .. T::MaxAuthorities::get();
+		let public = generate_key::<T>();
+		set_authorities::<T>(a, &public.clone().into_account());
+		let payload = PricePayload {
+			block_number: frame_system::Pallet::<T>::block_number(),
+			price: PRICE,
+			public: public.clone(),
+		};
+		let signature =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::sign(&payload.encode(), public)
+				.unwrap();
+		let call = Call::<T>::submit_price_unsigned { payload, signature };
+	}: {
+		Pallet::<T>::validate_unsigned(TransactionSource::External, &call)
+			.map_err(<&str>::from)?;
+		call.dispatch_bypass_filter(RawOrigin::None.into())?;
+	}
+	verify {
+		assert_eq!(Pallet::<T>::price(), Some(PRICE));
+	}
+
+	add_authority {
+		let a in 0 .. T::MaxAuthorities::get() - 1;
+		let authorities: Vec<T::AccountId> = (0..a).map(|i| account("authority", i, 0)).collect();
+		Authorities::<T>::put(BoundedVec::try_from(authorities).unwrap());
+		let who: T::AccountId = account("authority", a, 0);
+		let origin = T::AuthorityOrigin::successful_origin();
+	}: _<T::Origin>(origin, who.clone())
+	verify {
+		assert!(Pallet::<T>::authorities().contains(&who));
+	}
+
+	remove_authority {
+		let a in 1 .. T::MaxAuthorities::get();
+		let who: T::AccountId = account("authority", 0, 0);
+		set_authorities::<T>(a, &who);
+		let origin = T::AuthorityOrigin::successful_origin();
+	}: _<T::Origin>(origin, who.clone())
+	verify {
+		assert!(!Pallet::<T>::authorities().contains(&who));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext().0, crate::tests::mock::Test);
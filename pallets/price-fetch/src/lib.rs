@@ -0,0 +1,487 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Fetch a price from an HTTP API in an offchain worker, and bring it on chain.
+///
+/// Every `FetchInterval` blocks, the offchain worker of nodes holding an authority key fetches the
+/// DOT/USD price and submits it back, either in a signed transaction paying fees, or in an
+/// unsigned transaction whose payload is signed by the authority key. Only prices submitted by
+/// authorities, managed by the authority origin, are accepted.
+///
+/// The offchain worker keeps the last `RECENT_PRICES` prices it fetched in the offchain local
+/// storage of the node, and submits their average, smoothing out the spikes of the API.
+///
+/// Other pallets read the last price through the [`PriceOracle`] trait.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use sp_core::crypto::KeyTypeId;
+
+/// The key type of authority keys, to find them in the node keystore.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"pric");
+
+/// The API the price is fetched from.
+pub const PRICE_URL: &str = "https://min-api.cryptocompare.com/data/price?fsym=DOT&tsyms=USD";
+
+/// A price in USD, with `PRICE_DECIMALS` decimals.
+pub type Price = u64;
+
+/// The number of decimals of a `Price`, exposed in the metadata as `PriceDecimals`.
+pub const PRICE_DECIMALS: u32 = 6;
+
+/// The number of fetched prices the offchain worker averages.
+pub const RECENT_PRICES: usize = 4;
+
+/// The offchain local storage key of the recently fetched prices.
+const RECENT_PRICES_KEY: &[u8] = b"price-fetch::recent-prices";
+
+/// The authority crypto: sr25519 keys of type `KEY_TYPE`.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Signs transactions and payloads with authority keys.
+	pub struct AuthorityId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl
+		frame_system::offchain::AppCrypto<
+			<sp_core::sr25519::Signature as Verify>::Signer,
+			sp_core::sr25519::Signature,
+		> for AuthorityId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// Read access to the last known price, for other pallets.
+pub trait PriceOracle {
+	/// The last known price, if any.
+	fn price() -> Option<Price>;
+
+	/// Set the price, for the benchmarks of the pallets reading it.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_price(_price: Price) {}
+}
+
+/// No oracle: the price is never known.
+impl PriceOracle for () {
+	fn price() -> Option<Price> {
+		None
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{Price, PriceOracle, PRICE_DECIMALS, PRICE_URL};
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::{
+		offchain::{
+			AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendUnsignedTransaction,
+			SignedPayload, Signer,
+		},
+		pallet_prelude::*,
+	};
+	use lite_json::JsonValue;
+	use sp_runtime::{
+		offchain::{http, storage::StorageValueRef, Duration},
+		traits::{IdentifyAccount, Saturating, Zero},
+	};
+	use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The overarching call type.
+		type Call: From<Call<Self>>;
+
+		/// The crypto authorities sign prices with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The origin allowed to add and remove authorities.
+		type AuthorityOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum number of authorities.
+		#[pallet::constant]
+		type MaxAuthorities: Get<u32>;
+
+		/// The number of blocks between two price fetches.
+		#[pallet::constant]
+		type FetchInterval: Get<Self::BlockNumber>;
+
+		/// Whether offchain workers submit prices in signed transactions, rather than in unsigned
+		/// transactions carrying a signed payload.
+		#[pallet::constant]
+		type SignedSubmission: Get<bool>;
+
+		/// The priority of unsigned price submissions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The accounts allowed to submit prices.
+	#[pallet::storage]
+	#[pallet::getter(fn authorities)]
+	pub type Authorities<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxAuthorities>, ValueQuery>;
+
+	/// The last submitted price, and the block it was submitted at.
+	#[pallet::storage]
+	#[pallet::getter(fn current_price)]
+	pub type CurrentPrice<T: Config> = StorageValue<_, (Price, T::BlockNumber)>;
+
+	/// The block from which unsigned price submissions are accepted again.
+	#[pallet::storage]
+	#[pallet::getter(fn next_unsigned_at)]
+	pub type NextUnsignedAt<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub authorities: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { authorities: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			let authorities: BoundedVec<_, _> =
+				self.authorities.clone().try_into().expect("too many genesis authorities");
+			Authorities::<T>::put(authorities);
+		}
+	}
+
+	#[pallet::extra_constants]
+	impl<T: Config> Pallet<T> {
+		/// The number of decimals of the prices, so that clients display and parse them without
+		/// hard-coding it.
+		#[pallet::constant_name(PriceDecimals)]
+		fn price_decimals() -> u32 {
+			PRICE_DECIMALS
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An authority submitted a new price.
+		PriceSubmitted { authority: T::AccountId, price: Price },
+		/// An account has been made an authority.
+		AuthorityAdded { who: T::AccountId },
+		/// An account is no longer an authority.
+		AuthorityRemoved { who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not an authority.
+		NotAuthority,
+		/// The account is already an authority.
+		AlreadyAuthority,
+		/// There are too many authorities.
+		TooManyAuthorities,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(block_number: T::BlockNumber) {
+			if !(block_number % T::FetchInterval::get()).is_zero() {
+				return
+			}
+
+			let price = match Self::fetch_price() {
+				Ok(price) => Self::record_price(price),
+				Err(e) => {
+					log::warn!(target: "price-fetch", "Failed to fetch the price: {:?}", e);
+					return
+				},
+			};
+
+			let result = if T::SignedSubmission::get() {
+				Self::submit_signed(price)
+			} else {
+				Self::submit_unsigned(block_number, price)
+			};
+			if let Err(e) = result {
+				log::warn!(target: "price-fetch", "Failed to submit the price: {}", e);
+			}
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Submit a price, as an authority.
+		#[pallet::weight(T::WeightInfo::submit_price(T::MaxAuthorities::get()))]
+		pub fn submit_price(origin: OriginFor<T>, price: Price) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::authorities().contains(&who), Error::<T>::NotAuthority);
+			Self::store_price(who, price);
+
+			Ok(())
+		}
+
+		/// Submit a price signed by an authority key, in an unsigned transaction.
+		///
+		/// The signature and the authority are checked when validating the transaction.
+		#[pallet::weight(T::WeightInfo::submit_price_unsigned(T::MaxAuthorities::get()))]
+		pub fn submit_price_unsigned(
+			origin: OriginFor<T>,
+			payload: PricePayloadOf<T>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			NextUnsignedAt::<T>::put(now.saturating_add(T::FetchInterval::get()));
+			Self::store_price(payload.public.into_account(), payload.price);
+
+			Ok(())
+		}
+
+		/// Allow an account to submit prices.
+		#[pallet::weight(T::WeightInfo::add_authority(T::MaxAuthorities::get()))]
+		pub fn add_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Authorities::<T>::try_mutate(|authorities| -> DispatchResult {
+				ensure!(!authorities.contains(&who), Error::<T>::AlreadyAuthority);
+				authorities.try_push(who.clone()).map_err(|_| Error::<T>::TooManyAuthorities.into())
+			})?;
+
+			Self::deposit_event(Event::AuthorityAdded { who });
+
+			Ok(())
+		}
+
+		/// Revoke the right of an account to submit prices.
+		#[pallet::weight(T::WeightInfo::remove_authority(T::MaxAuthorities::get()))]
+		pub fn remove_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Authorities::<T>::try_mutate(|authorities| -> DispatchResult {
+				let index = authorities
+					.iter()
+					.position(|authority| *authority == who)
+					.ok_or(Error::<T>::NotAuthority)?;
+				authorities.remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AuthorityRemoved { who });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (payload, signature) = match call {
+				Call::submit_price_unsigned { payload, signature } => (payload, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into()
+			}
+			if !Self::authorities().contains(&payload.public.clone().into_account()) {
+				return InvalidTransaction::BadSigner.into()
+			}
+			if payload.block_number > frame_system::Pallet::<T>::block_number() {
+				return InvalidTransaction::Future.into()
+			}
+			if payload.block_number < Self::next_unsigned_at() {
+				return InvalidTransaction::Stale.into()
+			}
+
+			ValidTransaction::with_tag_prefix("PriceFetch")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(payload.block_number)
+				.longevity(5)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn store_price(authority: T::AccountId, price: Price) {
+			CurrentPrice::<T>::put((price, frame_system::Pallet::<T>::block_number()));
+
+			Self::deposit_event(Event::PriceSubmitted { authority, price });
+		}
+
+		/// Fetch the price from `PRICE_URL`.
+		fn fetch_price() -> Result<Price, http::Error> {
+			let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(2_000));
+			let pending = http::Request::get(PRICE_URL)
+				.deadline(deadline)
+				.send()
+				.map_err(|_| http::Error::IoError)?;
+			let response =
+				pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+			if response.code != 200 {
+				log::warn!(target: "price-fetch", "Unexpected status code: {}", response.code);
+				return Err(http::Error::Unknown)
+			}
+
+			let body = response.body().collect::<Vec<u8>>();
+			let body = sp_std::str::from_utf8(&body).map_err(|_| http::Error::Unknown)?;
+			Self::parse_price(body).ok_or(http::Error::Unknown)
+		}
+
+		/// Parse the price out of a `{"USD": 12.34}` JSON response.
+		pub fn parse_price(json: &str) -> Option<Price> {
+			let number = match lite_json::parse_json(json).ok()? {
+				JsonValue::Object(object) => match object
+					.into_iter()
+					.find(|(key, _)| key.iter().copied().eq("USD".chars()))?
+				{
+					(_, JsonValue::Number(number)) => number,
+					_ => return None,
+				},
+				_ => return None,
+			};
+			if number.negative || number.exponent != 0 {
+				return None
+			}
+
+			// A fraction too long to scale down is rejected rather than rounded to zero.
+			let fraction = if number.fraction_length > PRICE_DECIMALS {
+				number.fraction / 10u64.checked_pow(number.fraction_length - PRICE_DECIMALS)?
+			} else {
+				number.fraction * 10u64.pow(PRICE_DECIMALS - number.fraction_length)
+			};
+			u64::try_from(number.integer)
+				.ok()?
+				.checked_mul(10u64.pow(PRICE_DECIMALS))?
+				.checked_add(fraction)
+		}
+
+		/// Record a fetched price in the offchain local storage, returning the average of the
+		/// recently fetched prices.
+		fn record_price(price: Price) -> Price {
+			let storage = StorageValueRef::persistent(crate::RECENT_PRICES_KEY);
+			let recorded = storage.mutate(|stored: Result<Option<Vec<Price>>, _>| {
+				let mut prices = stored.ok().flatten().unwrap_or_default();
+				if prices.len() >= crate::RECENT_PRICES {
+					prices.remove(0);
+				}
+				prices.push(price);
+				Ok::<_, ()>(prices)
+			});
+			match recorded {
+				Ok(prices) => Self::average(&prices),
+				// Another worker is recording a price: use ours alone.
+				Err(_) => price,
+			}
+		}
+
+		/// The average of `prices`, zero if there are none.
+		pub fn average(prices: &[Price]) -> Price {
+			if prices.is_empty() {
+				return 0
+			}
+			let total = prices.iter().fold(0u128, |total, price| total + *price as u128);
+			(total / prices.len() as u128) as Price
+		}
+
+		fn submit_signed(price: Price) -> Result<(), &'static str> {
+			let signer = Signer::<T, T::AuthorityId>::any_account();
+			match signer.send_signed_transaction(|_account| Call::submit_price { price }) {
+				Some((_, Ok(()))) => Ok(()),
+				Some((_, Err(()))) => Err("the transaction was rejected"),
+				None => Err("no authority key in the keystore"),
+			}
+		}
+
+		fn submit_unsigned(block_number: T::BlockNumber, price: Price) -> Result<(), &'static str> {
+			let signer = Signer::<T, T::AuthorityId>::any_account();
+			let submitted = signer.send_unsigned_transaction(
+				|account| PricePayload { block_number, price, public: account.public.clone() },
+				|payload, signature| Call::submit_price_unsigned { payload, signature },
+			);
+			match submitted {
+				Some((_, Ok(()))) => Ok(()),
+				Some((_, Err(()))) => Err("the transaction was rejected"),
+				None => Err("no authority key in the keystore"),
+			}
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let authorities = Self::authorities();
+			ensure!(
+				authorities.iter().enumerate().all(|(i, who)| !authorities[..i].contains(who)),
+				"authority registered twice"
+			);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> PriceOracle for Pallet<T> {
+		fn price() -> Option<Price> {
+			Self::current_price().map(|(price, _)| price)
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn set_price(price: Price) {
+			CurrentPrice::<T>::put((price, frame_system::Pallet::<T>::block_number()));
+		}
+	}
+}
@@ -0,0 +1,162 @@
+use crate as pallet_price_fetch;
+use frame_support::parameter_types;
+use frame_system as system;
+use sp_core::{
+	offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt},
+	sr25519::{self, Signature},
+	H256,
+};
+use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStore};
+use sp_runtime::{
+	testing::{Header, TestXt},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup, Verify},
+};
+use std::sync::Arc;
+use test_utils::ExtBuilder;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+pub type Extrinsic = TestXt<Call, ()>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		PriceFetch: pallet_price_fetch::{
+			Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned
+		},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type AccountData = ();
+	type AccountId = AccountId;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	type Extrinsic = Extrinsic;
+	type OverarchingCall = Call;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		_public: <Signature as Verify>::Signer,
+		_account: AccountId,
+		nonce: u64,
+	) -> Option<(Call, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+parameter_types! {
+	pub const MaxAuthorities: u32 = 2;
+	pub const FetchInterval: u64 = 2;
+	pub const UnsignedPriority: u64 = 100;
+	pub static SignedSubmission: bool = true;
+}
+
+impl pallet_price_fetch::Config for Test {
+	type AuthorityId = crate::crypto::AuthorityId;
+	type AuthorityOrigin = frame_system::EnsureRoot<AccountId>;
+	type Call = Call;
+	type Event = Event;
+	type FetchInterval = FetchInterval;
+	type MaxAuthorities = MaxAuthorities;
+	type SignedSubmission = SignedSubmission;
+	type UnsignedPriority = UnsignedPriority;
+	type WeightInfo = ();
+}
+
+pub fn account(seed: u8) -> AccountId {
+	sr25519::Public::from_raw([seed; 32])
+}
+
+/// The state of the offchain extensions, to mock HTTP responses and inspect submitted
+/// transactions.
+pub struct OffchainState {
+	pub offchain: Arc<parking_lot::RwLock<testing::OffchainState>>,
+	pub pool: Arc<parking_lot::RwLock<testing::PoolState>>,
+	/// The authority key of the node.
+	pub authority: AccountId,
+}
+
+// Build genesis storage according to the mock runtime, with an authority key in the keystore.
+pub fn new_test_ext() -> (sp_io::TestExternalities, OffchainState) {
+	let keystore = KeyStore::new();
+	let authority =
+		SyncCryptoStore::sr25519_generate_new(&keystore, crate::KEY_TYPE, None).unwrap();
+
+	let mut ext = ExtBuilder::<Test>::default()
+		.genesis(pallet_price_fetch::GenesisConfig::<Test> { authorities: vec![authority] })
+		.build();
+
+	let (offchain, offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt(Arc::new(keystore)));
+
+	(ext, OffchainState { offchain: offchain_state, pool: pool_state, authority })
+}
+
+/// Expect a request to the price API, answering it with `body`.
+pub fn expect_price_request(state: &OffchainState, body: &[u8]) {
+	state.offchain.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: crate::PRICE_URL.into(),
+		response: Some(body.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+}
+
+/// The last transaction submitted to the pool.
+pub fn submitted_transaction(state: &OffchainState) -> Extrinsic {
+	let encoded = state.pool.write().transactions.pop().expect("a transaction was submitted");
+	codec::Decode::decode(&mut &*encoded).unwrap()
+}
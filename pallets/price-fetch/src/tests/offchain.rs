@@ -0,0 +1,158 @@
+use super::mock::*;
+use crate::{Error, Event as PriceEvent, PriceOracle, PricePayload};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, unsigned::ValidateUnsigned};
+use frame_system::offchain::SignedPayload;
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+#[test]
+fn parse_price_scales_to_price_decimals() {
+	assert_eq!(PriceFetch::parse_price(r#"{"USD": 25.51}"#), Some(25_510_000));
+	assert_eq!(PriceFetch::parse_price(r#"{"USD": 7}"#), Some(7_000_000));
+	assert_eq!(PriceFetch::parse_price(r#"{"USD": 0.123456789}"#), Some(123_456));
+	assert_eq!(PriceFetch::parse_price(r#"{"EUR": 25.51}"#), None);
+	assert_eq!(PriceFetch::parse_price(r#"{"USD": -1}"#), None);
+	assert_eq!(PriceFetch::parse_price(r#"{"USD": 1.00000000000000000000000001}"#), None);
+	assert_eq!(PriceFetch::parse_price("not json"), None);
+}
+
+#[test]
+fn offchain_worker_submits_signed_transactions() {
+	let (mut ext, state) = new_test_ext();
+	expect_price_request(&state, br#"{"USD": 25.51}"#);
+
+	ext.execute_with(|| {
+		SignedSubmission::set(true);
+		PriceFetch::offchain_worker(2);
+
+		let tx = submitted_transaction(&state);
+		assert_eq!(tx.signature, Some((0, ())));
+		assert_eq!(tx.call, Call::PriceFetch(crate::Call::submit_price { price: 25_510_000 }));
+	})
+}
+
+#[test]
+fn offchain_worker_submits_signed_payloads() {
+	let (mut ext, state) = new_test_ext();
+	expect_price_request(&state, br#"{"USD": 25.51}"#);
+
+	ext.execute_with(|| {
+		SignedSubmission::set(false);
+		System::set_block_number(2);
+		PriceFetch::offchain_worker(2);
+
+		let tx = submitted_transaction(&state);
+		assert_eq!(tx.signature, None);
+		let call = match tx.call {
+			Call::PriceFetch(call) => call,
+			_ => panic!("unexpected call"),
+		};
+		let (payload, signature) = match &call {
+			crate::Call::submit_price_unsigned { payload, signature } => (payload, signature),
+			_ => panic!("unexpected call"),
+		};
+		assert_eq!(
+			*payload,
+			PricePayload { block_number: 2, price: 25_510_000, public: state.authority }
+		);
+		assert!(SignedPayload::<Test>::verify::<crate::crypto::AuthorityId>(
+			payload,
+			signature.clone()
+		));
+		assert!(PriceFetch::validate_unsigned(TransactionSource::Local, &call).is_ok());
+
+		assert_ok!(PriceFetch::submit_price_unsigned(
+			Origin::none(),
+			payload.clone(),
+			signature.clone()
+		));
+		assert_eq!(PriceFetch::price(), Some(25_510_000));
+		assert_eq!(
+			PriceFetch::validate_unsigned(TransactionSource::Local, &call),
+			InvalidTransaction::Stale.into()
+		);
+	})
+}
+
+#[test]
+fn offchain_worker_submits_the_average_of_recent_prices() {
+	let (mut ext, state) = new_test_ext();
+	SignedSubmission::set(true);
+
+	for (block_number, body, average) in [
+		(2, br#"{"USD": 20}"#, 20_000_000),
+		(4, br#"{"USD": 30}"#, 25_000_000),
+		(6, br#"{"USD": 40}"#, 30_000_000),
+		(8, br#"{"USD": 50}"#, 35_000_000),
+		(10, br#"{"USD": 60}"#, 45_000_000),
+	] {
+		expect_price_request(&state, body);
+		ext.execute_with(|| {
+			PriceFetch::offchain_worker(block_number);
+
+			let tx = submitted_transaction(&state);
+			assert_eq!(tx.call, Call::PriceFetch(crate::Call::submit_price { price: average }));
+		});
+	}
+}
+
+#[test]
+fn average_of_prices() {
+	assert_eq!(PriceFetch::average(&[]), 0);
+	assert_eq!(PriceFetch::average(&[7]), 7);
+	assert_eq!(PriceFetch::average(&[1, 2]), 1);
+	assert_eq!(PriceFetch::average(&[u64::MAX, u64::MAX]), u64::MAX);
+}
+
+#[test]
+fn offchain_worker_waits_for_the_fetch_interval() {
+	let (mut ext, state) = new_test_ext();
+
+	ext.execute_with(|| {
+		PriceFetch::offchain_worker(3);
+		assert!(state.pool.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn only_authorities_submit_prices() {
+	let (mut ext, state) = new_test_ext();
+
+	ext.execute_with(|| {
+		assert_eq!(PriceFetch::price(), None);
+		assert_noop!(
+			PriceFetch::submit_price(Origin::signed(account(1)), 10),
+			Error::<Test>::NotAuthority
+		);
+
+		assert_ok!(PriceFetch::submit_price(Origin::signed(state.authority), 10));
+		assert_eq!(PriceFetch::current_price(), Some((10, 1)));
+		System::assert_last_event(
+			PriceEvent::PriceSubmitted { authority: state.authority, price: 10 }.into(),
+		);
+	})
+}
+
+#[test]
+fn authority_origin_manages_authorities() {
+	let (mut ext, state) = new_test_ext();
+
+	ext.execute_with(|| {
+		assert!(PriceFetch::add_authority(Origin::signed(state.authority), account(1)).is_err());
+		assert_noop!(
+			PriceFetch::add_authority(Origin::root(), state.authority),
+			Error::<Test>::AlreadyAuthority
+		);
+		assert_ok!(PriceFetch::add_authority(Origin::root(), account(1)));
+		assert_noop!(
+			PriceFetch::add_authority(Origin::root(), account(2)),
+			Error::<Test>::TooManyAuthorities
+		);
+
+		assert_ok!(PriceFetch::remove_authority(Origin::root(), state.authority));
+		assert_eq!(PriceFetch::authorities().into_inner(), vec![account(1)]);
+		assert_noop!(
+			PriceFetch::remove_authority(Origin::root(), state.authority),
+			Error::<Test>::NotAuthority
+		);
+	})
+}
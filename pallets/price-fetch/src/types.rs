@@ -0,0 +1,25 @@
+use crate::Price;
+use codec::{Decode, Encode};
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+pub type PricePayloadOf<T> =
+	PricePayload<<T as SigningTypes>::Public, <T as frame_system::Config>::BlockNumber>;
+
+/// A price submitted in an unsigned transaction, signed by an authority key.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PricePayload<Public, BlockNumber> {
+	/// The block the price was fetched at.
+	pub block_number: BlockNumber,
+	/// The fetched price, in USD with `PRICE_DECIMALS` decimals.
+	pub price: Price,
+	/// The key the payload is signed with.
+	pub public: Public,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for PricePayload<T::Public, T::BlockNumber> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
@@ -0,0 +1,79 @@
+//! Weights for pallet_price_fetch
+//!
+//! Estimated from the storage accesses of each call, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_price_fetch --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/price-fetch/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_price_fetch.
+pub trait WeightInfo {
+	fn submit_price(a: u32) -> Weight;
+	fn submit_price_unsigned(a: u32) -> Weight;
+	fn add_authority(a: u32) -> Weight;
+	fn remove_authority(a: u32) -> Weight;
+}
+
+/// Weights for pallet_price_fetch using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn submit_price(a: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn submit_price_unsigned(a: u32) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn add_authority(a: u32) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_authority(a: u32) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn submit_price(a: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn submit_price_unsigned(a: u32) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn add_authority(a: u32) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_authority(a: u32) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -0,0 +1,51 @@
+//! Benchmarks of the proof-of-existence pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+
+fn funded<T: Config>(who: &T::AccountId) {
+	let amount = T::Currency::minimum_balance() + T::ClaimDeposit::get() * 10u32.into();
+	T::Currency::make_free_balance_be(who, amount);
+}
+
+/// Have `owner` claim a document, returning its hash.
+fn new_claim<T: Config>(owner: &T::AccountId) -> T::Hash {
+	let hash = T::Hashing::hash_of(owner);
+	funded::<T>(owner);
+	Pallet::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), hash).unwrap();
+	hash
+}
+
+benchmarks! {
+	create_claim {
+		let owner: T::AccountId = whitelisted_caller();
+		funded::<T>(&owner);
+		let hash = T::Hashing::hash_of(&owner);
+	}: _(RawOrigin::Signed(owner.clone()), hash)
+	verify {
+		assert_eq!(Pallet::<T>::verify(&hash).map(|(owner, _)| owner), Some(owner));
+	}
+
+	transfer_claim {
+		let from: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("recipient", 0, 0);
+		let hash = new_claim::<T>(&from);
+		funded::<T>(&to);
+	}: _(RawOrigin::Signed(from), hash, to.clone())
+	verify {
+		assert_eq!(Pallet::<T>::verify(&hash).map(|(owner, _)| owner), Some(to));
+	}
+
+	revoke_claim {
+		let owner: T::AccountId = whitelisted_caller();
+		let hash = new_claim::<T>(&owner);
+	}: _(RawOrigin::Signed(owner), hash)
+	verify {
+		assert!(Pallet::<T>::verify(&hash).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,192 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Prove the existence of documents at some point in time.
+///
+/// An account claims a document by registering its hash, which records the time of the claim;
+/// the document itself never goes on chain. Anyone holding the document can then check who claimed
+/// it, and since when.
+///
+/// Each claim reserves a deposit from its owner, to pay for the storage it uses. Claims can be
+/// transferred, along with their deposit, or revoked to get the deposit back.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{ReservableCurrency, Time},
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::StorageDeposits;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency claim deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The source of claim timestamps.
+		type Time: Time;
+
+		/// The deposit reserved for each claim.
+		#[pallet::constant]
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The claims, by document hash.
+	#[pallet::storage]
+	#[pallet::getter(fn claim)]
+	pub type Claims<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, ClaimOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A document has been claimed.
+		ClaimCreated { hash: T::Hash, owner: T::AccountId, registered_at: MomentOf<T> },
+		/// A claim has been transferred.
+		ClaimTransferred { hash: T::Hash, from: T::AccountId, to: T::AccountId },
+		/// A claim has been revoked.
+		ClaimRevoked { hash: T::Hash, owner: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The document has already been claimed.
+		AlreadyClaimed,
+		/// The document has not been claimed.
+		NoSuchClaim,
+		/// The caller does not own the claim.
+		NotClaimOwner,
+		/// A claim cannot be transferred to its owner.
+		TransferToSelf,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Claim the document with the given hash, reserving `ClaimDeposit`.
+		#[pallet::weight(T::WeightInfo::create_claim())]
+		pub fn create_claim(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(!Claims::<T>::contains_key(&hash), Error::<T>::AlreadyClaimed);
+
+			let deposit = T::Currency::reserve_deposit(&owner, T::ClaimDeposit::get())?;
+
+			let registered_at = T::Time::now();
+			Claims::<T>::insert(&hash, Claim { owner: owner.clone(), deposit, registered_at });
+
+			Self::deposit_event(Event::ClaimCreated { hash, owner, registered_at });
+
+			Ok(())
+		}
+
+		/// Transfer a claim of the caller, along with its deposit.
+		///
+		/// The claim keeps its original timestamp.
+		#[pallet::weight(T::WeightInfo::transfer_claim())]
+		pub fn transfer_claim(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let mut claim = Self::claim(&hash).ok_or(Error::<T>::NoSuchClaim)?;
+			ensure!(claim.owner == from, Error::<T>::NotClaimOwner);
+			ensure!(from != to, Error::<T>::TransferToSelf);
+
+			T::Currency::transfer_deposit(&from, &to, claim.deposit)?;
+			claim.owner = to.clone();
+			Claims::<T>::insert(&hash, claim);
+
+			Self::deposit_event(Event::ClaimTransferred { hash, from, to });
+
+			Ok(())
+		}
+
+		/// Revoke a claim of the caller, and unreserve its deposit.
+		#[pallet::weight(T::WeightInfo::revoke_claim())]
+		pub fn revoke_claim(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let claim = Self::claim(&hash).ok_or(Error::<T>::NoSuchClaim)?;
+			ensure!(claim.owner == owner, Error::<T>::NotClaimOwner);
+
+			T::Currency::refund_deposit(&owner, claim.deposit);
+			Claims::<T>::remove(&hash);
+
+			Self::deposit_event(Event::ClaimRevoked { hash, owner });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The owner of a document, and the time it was claimed at, if it has been claimed.
+		pub fn verify(hash: &T::Hash) -> Option<(T::AccountId, MomentOf<T>)> {
+			Self::claim(hash).map(|claim| (claim.owner, claim.registered_at))
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use frame_support::sp_std::vec::Vec;
+			use sp_runtime::traits::Saturating;
+
+			// The deposit of every claim is reserved from its owner.
+			let mut deposits: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+			for claim in Claims::<T>::iter_values() {
+				match deposits.iter_mut().find(|(owner, _)| *owner == claim.owner) {
+					Some((_, total)) => *total = total.saturating_add(claim.deposit),
+					None => deposits.push((claim.owner, claim.deposit)),
+				}
+			}
+			for (owner, total) in deposits {
+				ensure!(
+					T::Currency::reserved_balance(&owner) >= total,
+					"claim deposit not reserved"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
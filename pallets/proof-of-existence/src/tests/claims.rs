@@ -0,0 +1,100 @@
+use super::mock::*;
+use crate::{Error, Event as PoeEvent};
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+use sp_runtime::TokenError;
+use sp_core::H256;
+
+const DOCUMENT: H256 = H256::repeat_byte(1);
+
+#[test]
+fn create_claim_records_owner_and_time() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ProofOfExistence::verify(&DOCUMENT), None);
+
+		assert_ok!(ProofOfExistence::create_claim(Origin::signed(ALICE), DOCUMENT));
+		assert_eq!(ProofOfExistence::verify(&DOCUMENT), Some((ALICE, 6_000)));
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		System::assert_last_event(
+			PoeEvent::ClaimCreated { hash: DOCUMENT, owner: ALICE, registered_at: 6_000 }.into(),
+		);
+
+		assert_noop!(
+			ProofOfExistence::create_claim(Origin::signed(BOB), DOCUMENT),
+			Error::<Test>::AlreadyClaimed
+		);
+	})
+}
+
+#[test]
+fn transfer_claim_moves_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProofOfExistence::create_claim(Origin::signed(ALICE), DOCUMENT));
+		Timestamp::set_timestamp(12_000);
+
+		assert_noop!(
+			ProofOfExistence::transfer_claim(Origin::signed(BOB), DOCUMENT, BOB),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			ProofOfExistence::transfer_claim(Origin::signed(ALICE), DOCUMENT, ALICE),
+			Error::<Test>::TransferToSelf
+		);
+		assert_ok!(ProofOfExistence::transfer_claim(Origin::signed(ALICE), DOCUMENT, BOB));
+
+		assert_eq!(ProofOfExistence::verify(&DOCUMENT), Some((BOB, 6_000)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 90);
+		assert_eq!(Balances::reserved_balance(BOB), 10);
+	})
+}
+
+#[test]
+fn transfer_claim_moves_the_whole_deposit_or_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProofOfExistence::create_claim(Origin::signed(ALICE), DOCUMENT));
+		Balances::unreserve(&ALICE, 4);
+
+		assert_noop!(
+			ProofOfExistence::transfer_claim(Origin::signed(ALICE), DOCUMENT, BOB),
+			TokenError::NoFunds
+		);
+		assert_eq!(ProofOfExistence::verify(&DOCUMENT), Some((ALICE, 6_000)));
+		assert_eq!(Balances::reserved_balance(ALICE), 6);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	})
+}
+
+#[test]
+fn revoke_claim_unreserves_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProofOfExistence::revoke_claim(Origin::signed(ALICE), DOCUMENT),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(ProofOfExistence::create_claim(Origin::signed(ALICE), DOCUMENT));
+
+		assert_noop!(
+			ProofOfExistence::revoke_claim(Origin::signed(BOB), DOCUMENT),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(ProofOfExistence::revoke_claim(Origin::signed(ALICE), DOCUMENT));
+
+		assert_eq!(ProofOfExistence::verify(&DOCUMENT), None);
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		System::assert_last_event(PoeEvent::ClaimRevoked { hash: DOCUMENT, owner: ALICE }.into());
+	})
+}
+
+#[test]
+fn revoke_claim_refunds_the_deposit_it_was_charged() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProofOfExistence::create_claim(Origin::signed(ALICE), DOCUMENT));
+		ClaimDeposit::set(20);
+		assert_ok!(ProofOfExistence::create_claim(Origin::signed(ALICE), H256::repeat_byte(2)));
+		assert_eq!(Balances::reserved_balance(ALICE), 30);
+
+		ClaimDeposit::set(5);
+		assert_ok!(ProofOfExistence::revoke_claim(Origin::signed(ALICE), DOCUMENT));
+		assert_eq!(Balances::reserved_balance(ALICE), 20);
+	})
+}
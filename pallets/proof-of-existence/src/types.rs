@@ -0,0 +1,20 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{Currency, Time};
+use scale_info::TypeInfo;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+pub type ClaimOf<T> = Claim<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+
+/// The claim of an account on a document.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Claim<AccountId, Balance, Moment> {
+	/// The account owning the claim.
+	pub owner: AccountId,
+	/// The deposit reserved from the owner.
+	pub deposit: Balance,
+	/// The time the document was first registered at.
+	pub registered_at: Moment,
+}
@@ -0,0 +1,121 @@
+//! Benchmarks of the proxy-permissions pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, Zero};
+use sp_std::prelude::*;
+
+/// Fund `who` for the deposits.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, T::Currency::minimum_balance() * 1_000_000u32.into());
+}
+
+/// Register `p` proxies of `real`, the last one being `delegate`.
+fn add_proxies<T: Config>(real: &T::AccountId, delegate: &T::AccountId, p: u32) {
+	fund::<T>(real);
+	for i in 0..p {
+		let proxy = if i + 1 == p { delegate.clone() } else { account("proxy", i, 0) };
+		Pallet::<T>::add_proxy(
+			RawOrigin::Signed(real.clone()).into(),
+			proxy,
+			T::ProxyType::default(),
+			Zero::zero(),
+		)
+		.unwrap();
+	}
+}
+
+/// Have `delegate` announce `a` calls of `real`, the last one being `call`.
+fn announce_calls<T: Config>(
+	real: &T::AccountId,
+	delegate: &T::AccountId,
+	call: &<T as Config>::Call,
+	a: u32,
+) {
+	fund::<T>(delegate);
+	for i in 0..a {
+		let call_hash =
+			if i + 1 == a { T::Hashing::hash_of(call) } else { T::Hashing::hash_of(&i) };
+		Pallet::<T>::announce(RawOrigin::Signed(delegate.clone()).into(), real.clone(), call_hash)
+			.unwrap();
+	}
+}
+
+fn remark<T: Config>() -> <T as Config>::Call {
+	frame_system::Call::<T>::remark { remark: Vec::new() }.into()
+}
+
+benchmarks! {
+	add_proxy {
+		let p in 0 .. T::MaxProxies::get() - 1;
+		let real: T::AccountId = whitelisted_caller();
+		add_proxies::<T>(&real, &account("proxy", p, 0), p);
+		let delegate: T::AccountId = account("delegate", 0, 0);
+	}: _(RawOrigin::Signed(real.clone()), delegate, T::ProxyType::default(), Zero::zero())
+	verify {
+		assert_eq!(Pallet::<T>::proxies(&real).len() as u32, p + 1);
+	}
+
+	remove_proxy {
+		let p in 1 .. T::MaxProxies::get();
+		let a in 0 .. T::MaxPending::get();
+		let real: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		add_proxies::<T>(&real, &delegate, p);
+		announce_calls::<T>(&real, &delegate, &remark::<T>(), a);
+	}: _(RawOrigin::Signed(real.clone()), delegate.clone(), T::ProxyType::default(), Zero::zero())
+	verify {
+		assert_eq!(Pallet::<T>::proxies(&real).len() as u32, p - 1);
+		assert!(Pallet::<T>::announcements(&delegate).is_empty());
+	}
+
+	proxy {
+		let p in 1 .. T::MaxProxies::get();
+		let real: T::AccountId = account("real", 0, 0);
+		let delegate: T::AccountId = whitelisted_caller();
+		add_proxies::<T>(&real, &delegate, p);
+		let call = Box::new(remark::<T>());
+	}: _(RawOrigin::Signed(delegate), real, None, call)
+
+	announce {
+		let a in 0 .. T::MaxPending::get() - 1;
+		let real: T::AccountId = account("real", 0, 0);
+		let delegate: T::AccountId = whitelisted_caller();
+		add_proxies::<T>(&real, &delegate, T::MaxProxies::get());
+		announce_calls::<T>(&real, &delegate, &remark::<T>(), a);
+		let call_hash = T::Hashing::hash_of(&a);
+	}: _(RawOrigin::Signed(delegate.clone()), real, call_hash)
+	verify {
+		assert_eq!(Pallet::<T>::announcements(&delegate).len() as u32, a + 1);
+	}
+
+	reject_announcement {
+		let a in 1 .. T::MaxPending::get();
+		let real: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		add_proxies::<T>(&real, &delegate, 1);
+		let call = remark::<T>();
+		announce_calls::<T>(&real, &delegate, &call, a);
+	}: _(RawOrigin::Signed(real), delegate.clone(), T::Hashing::hash_of(&call))
+	verify {
+		assert_eq!(Pallet::<T>::announcements(&delegate).len() as u32, a - 1);
+	}
+
+	proxy_announced {
+		let a in 1 .. T::MaxPending::get();
+		let p in 1 .. T::MaxProxies::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let real: T::AccountId = account("real", 0, 0);
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		add_proxies::<T>(&real, &delegate, p);
+		let call = remark::<T>();
+		announce_calls::<T>(&real, &delegate, &call, a);
+	}: _(RawOrigin::Signed(caller), delegate.clone(), real, None, Box::new(call))
+	verify {
+		assert_eq!(Pallet::<T>::announcements(&delegate).len() as u32, a - 1);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
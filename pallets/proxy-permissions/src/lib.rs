@@ -0,0 +1,483 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Let accounts delegate restricted classes of calls to proxy accounts.
+///
+/// A proxy is registered with a proxy type, defined by the runtime, whose `InstanceFilter`
+/// decides which calls it may dispatch on behalf of its delegator: e.g. "asset transfers only" or
+/// "NFT management only".
+///
+/// Each proxy reserves a deposit from its delegator, and each pending announcement one from its
+/// proxy, refunded when they are removed.
+///
+/// A proxy can also be registered with a delay: it must then announce the hash of a call and wait
+/// for the delay to elapse before dispatching it, which leaves the delegator time to reject the
+/// announcement if the proxy key got compromised.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+		traits::{InstanceFilter, IsSubType, OriginTrait, ReservableCurrency},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::StorageDeposits;
+	use sp_runtime::traits::{Hash, Saturating, Zero};
+	use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The calls proxies can dispatch.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ IsSubType<Call<Self>>
+			+ IsType<<Self as frame_system::Config>::Call>;
+
+		/// The kinds of proxies, and the calls each of them is allowed to dispatch.
+		type ProxyType: Parameter
+			+ Member
+			+ Ord
+			+ PartialOrd
+			+ InstanceFilter<<Self as Config>::Call>
+			+ Default
+			+ MaxEncodedLen;
+
+		/// The currency proxy and announcement deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from a delegator for each of its proxies.
+		#[pallet::constant]
+		type ProxyDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved from a proxy for each of its pending announcements.
+		#[pallet::constant]
+		type AnnouncementDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of proxies of an account.
+		#[pallet::constant]
+		type MaxProxies: Get<u32>;
+
+		/// The maximum number of pending announcements of a proxy.
+		#[pallet::constant]
+		type MaxPending: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The proxies of each delegator.
+	#[pallet::storage]
+	#[pallet::getter(fn proxies)]
+	pub type Proxies<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<ProxyDefinitionOf<T>, T::MaxProxies>,
+		ValueQuery,
+	>;
+
+	/// The calls announced by each proxy.
+	#[pallet::storage]
+	#[pallet::getter(fn announcements)]
+	pub type Announcements<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<AnnouncementOf<T>, T::MaxPending>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A proxy has been added.
+		ProxyAdded {
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+		},
+		/// A proxy has been removed.
+		ProxyRemoved { delegator: T::AccountId, delegate: T::AccountId, proxy_type: T::ProxyType },
+		/// A proxy announced a call.
+		Announced { real: T::AccountId, proxy: T::AccountId, call_hash: T::Hash },
+		/// A call has been dispatched through a proxy.
+		ProxyExecuted { real: T::AccountId, proxy: T::AccountId, result: DispatchResult },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account cannot be its own proxy.
+		NoSelfProxy,
+		/// This proxy is already registered.
+		Duplicate,
+		/// Too many proxies or announcements.
+		TooMany,
+		/// The proxy is not registered.
+		NotProxy,
+		/// The call is not allowed for this proxy type.
+		Unproxyable,
+		/// The announcement does not exist.
+		UnknownAnnouncement,
+		/// The announcement delay has not elapsed yet.
+		Unannounced,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register `delegate` as a proxy of the caller for calls allowed by `proxy_type`,
+		/// reserving `ProxyDeposit`.
+		///
+		/// With a non-zero `delay`, the proxy must announce its calls `delay` blocks in advance.
+		#[pallet::weight(T::WeightInfo::add_proxy(T::MaxProxies::get()))]
+		#[transactional]
+		pub fn add_proxy(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+
+			ensure!(delegator != delegate, Error::<T>::NoSelfProxy);
+
+			Proxies::<T>::try_mutate(&delegator, |proxies| -> DispatchResult {
+				let index = Self::search(proxies, &delegate, &proxy_type, delay)
+					.err()
+					.ok_or(Error::<T>::Duplicate)?;
+				let deposit = T::Currency::reserve_deposit(&delegator, T::ProxyDeposit::get())?;
+				let definition = ProxyDefinition {
+					delegate: delegate.clone(),
+					proxy_type: proxy_type.clone(),
+					delay,
+					deposit,
+				};
+				proxies.try_insert(index, definition).map_err(|_| Error::<T>::TooMany)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProxyAdded { delegator, delegate, proxy_type, delay });
+
+			Ok(())
+		}
+
+		/// Unregister a proxy of the caller, and unreserve its deposit.
+		///
+		/// Once `delegate` is no longer a proxy of the caller, its pending announcements of calls
+		/// of the caller are removed, and their deposits unreserved.
+		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get(), T::MaxPending::get()))]
+		pub fn remove_proxy(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+
+			let (deposit, still_proxy) = Proxies::<T>::try_mutate_exists(
+				&delegator,
+				|maybe_proxies| -> Result<_, DispatchError> {
+					let proxies = maybe_proxies.as_mut().ok_or(Error::<T>::NotProxy)?;
+					let index = Self::search(proxies, &delegate, &proxy_type, delay)
+						.map_err(|_| Error::<T>::NotProxy)?;
+					let definition = proxies.remove(index);
+					let still_proxy = proxies.iter().any(|d| d.delegate == delegate);
+					if proxies.is_empty() {
+						*maybe_proxies = None;
+					}
+					Ok((definition.deposit, still_proxy))
+				},
+			)?;
+			T::Currency::refund_deposit(&delegator, deposit);
+			if !still_proxy {
+				Self::remove_announcements(&delegate, &delegator);
+			}
+
+			Self::deposit_event(Event::ProxyRemoved { delegator, delegate, proxy_type });
+
+			Ok(())
+		}
+
+		/// Dispatch `call` on behalf of `real`, as a proxy without delay.
+		///
+		/// `force_proxy_type` selects which proxy definition to use when the caller has several.
+		#[pallet::weight({
+			let info = call.get_dispatch_info();
+			(T::WeightInfo::proxy(T::MaxProxies::get()).saturating_add(info.weight), info.class)
+		})]
+		pub fn proxy(
+			origin: OriginFor<T>,
+			real: T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let definition = Self::find_proxy(&real, &who, force_proxy_type)?;
+			ensure!(definition.delay.is_zero(), Error::<T>::Unannounced);
+
+			Self::do_proxy(definition, real, who, *call);
+
+			Ok(())
+		}
+
+		/// Announce, as a proxy of `real`, the hash of a call to dispatch later, reserving
+		/// `AnnouncementDeposit` until it is dispatched or rejected.
+		#[pallet::weight(T::WeightInfo::announce(T::MaxPending::get()))]
+		#[transactional]
+		pub fn announce(
+			origin: OriginFor<T>,
+			real: T::AccountId,
+			call_hash: T::Hash,
+		) -> DispatchResult {
+			let proxy = ensure_signed(origin)?;
+
+			ensure!(
+				Self::proxies(&real).iter().any(|definition| definition.delegate == proxy),
+				Error::<T>::NotProxy
+			);
+
+			Announcements::<T>::try_mutate(&proxy, |pending| -> DispatchResult {
+				let deposit = T::Currency::reserve_deposit(&proxy, T::AnnouncementDeposit::get())?;
+				let announcement = Announcement {
+					real: real.clone(),
+					call_hash,
+					height: frame_system::Pallet::<T>::block_number(),
+					deposit,
+				};
+				pending.try_push(announcement).map_err(|_| Error::<T>::TooMany)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Announced { real, proxy, call_hash });
+
+			Ok(())
+		}
+
+		/// Reject, as `real`, a call announced by one of its proxies.
+		#[pallet::weight(T::WeightInfo::reject_announcement(T::MaxPending::get()))]
+		pub fn reject_announcement(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			call_hash: T::Hash,
+		) -> DispatchResult {
+			let real = ensure_signed(origin)?;
+
+			Self::remove_announcement(&delegate, &real, call_hash)?;
+
+			Ok(())
+		}
+
+		/// Dispatch a call announced by `delegate` on behalf of `real`, once its delay elapsed.
+		///
+		/// Anyone can trigger the dispatch.
+		#[pallet::weight({
+			let info = call.get_dispatch_info();
+			let weight = T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get());
+			(weight.saturating_add(info.weight), info.class)
+		})]
+		pub fn proxy_announced(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			real: T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let definition = Self::find_proxy(&real, &delegate, force_proxy_type)?;
+			let call_hash = T::Hashing::hash_of(&call);
+			// The announcement is only consumed once it is due: dispatch is not transactional.
+			let height = Self::announcement_height(&delegate, &real, call_hash)
+				.ok_or(Error::<T>::UnknownAnnouncement)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >=
+					height.saturating_add(definition.delay),
+				Error::<T>::Unannounced
+			);
+			Self::remove_announcement(&delegate, &real, call_hash)?;
+
+			Self::do_proxy(definition, real, delegate, *call);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The index of the proxy of `delegate` with `proxy_type` and `delay` in `proxies`, or the
+		/// one to insert it at.
+		fn search(
+			proxies: &[ProxyDefinitionOf<T>],
+			delegate: &T::AccountId,
+			proxy_type: &T::ProxyType,
+			delay: T::BlockNumber,
+		) -> Result<usize, usize> {
+			proxies.binary_search_by(|definition| {
+				(&definition.delegate, &definition.proxy_type, definition.delay)
+					.cmp(&(delegate, proxy_type, delay))
+			})
+		}
+
+		/// The definition registering `delegate` as a proxy of `real`, the one with the shortest
+		/// delay if several match.
+		fn find_proxy(
+			real: &T::AccountId,
+			delegate: &T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+		) -> Result<ProxyDefinitionOf<T>, DispatchError> {
+			Self::proxies(real)
+				.into_iter()
+				.filter(|definition| {
+					&definition.delegate == delegate &&
+						force_proxy_type.as_ref().map_or(true, |t| &definition.proxy_type == t)
+				})
+				.min_by_key(|definition| definition.delay)
+				.ok_or_else(|| Error::<T>::NotProxy.into())
+		}
+
+		/// The block at which `delegate` announced `call_hash` on behalf of `real`, if it did.
+		fn announcement_height(
+			delegate: &T::AccountId,
+			real: &T::AccountId,
+			call_hash: T::Hash,
+		) -> Option<T::BlockNumber> {
+			Self::announcements(delegate)
+				.into_iter()
+				.find(|a| &a.real == real && a.call_hash == call_hash)
+				.map(|a| a.height)
+		}
+
+		/// Remove an announcement and unreserve its deposit, returning the block it was made at.
+		fn remove_announcement(
+			delegate: &T::AccountId,
+			real: &T::AccountId,
+			call_hash: T::Hash,
+		) -> Result<T::BlockNumber, DispatchError> {
+			Announcements::<T>::try_mutate_exists(
+				delegate,
+				|maybe_pending| -> Result<_, DispatchError> {
+					let pending = maybe_pending.as_mut().ok_or(Error::<T>::UnknownAnnouncement)?;
+					let index = pending
+						.iter()
+						.position(|a| &a.real == real && a.call_hash == call_hash)
+						.ok_or(Error::<T>::UnknownAnnouncement)?;
+					let announcement = pending.remove(index);
+					if pending.is_empty() {
+						*maybe_pending = None;
+					}
+					T::Currency::refund_deposit(delegate, announcement.deposit);
+					Ok(announcement.height)
+				},
+			)
+		}
+
+		/// Remove the announcements of `delegate` on behalf of `real`, and unreserve their
+		/// deposits.
+		fn remove_announcements(delegate: &T::AccountId, real: &T::AccountId) {
+			Announcements::<T>::mutate_exists(delegate, |maybe_pending| {
+				if let Some(pending) = maybe_pending {
+					let mut deposit = BalanceOf::<T>::zero();
+					pending.retain(|a| {
+						if &a.real == real {
+							deposit = deposit.saturating_add(a.deposit);
+						}
+						&a.real != real
+					});
+					T::Currency::refund_deposit(delegate, deposit);
+					if pending.is_empty() {
+						*maybe_pending = None;
+					}
+				}
+			});
+		}
+
+		/// Dispatch `call` as `real`, restricted to what the proxy type allows.
+		fn do_proxy(
+			definition: ProxyDefinitionOf<T>,
+			real: T::AccountId,
+			proxy: T::AccountId,
+			call: <T as Config>::Call,
+		) {
+			let mut origin: T::Origin = frame_system::RawOrigin::Signed(real.clone()).into();
+			origin.add_filter(move |c: &<T as frame_system::Config>::Call| {
+				let c = <T as Config>::Call::from_ref(c);
+				// A proxy must not be able to grant, or revoke, more than it is allowed itself.
+				match c.is_sub_type() {
+					Some(Call::add_proxy { ref proxy_type, .. }) |
+					Some(Call::remove_proxy { ref proxy_type, .. })
+						if !definition.proxy_type.is_superset(proxy_type) =>
+						false,
+					_ => definition.proxy_type.filter(c),
+				}
+			});
+
+			let result = call.dispatch(origin);
+
+			Self::deposit_event(Event::ProxyExecuted {
+				real,
+				proxy,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			for (delegator, proxies) in Proxies::<T>::iter() {
+				ensure!(!proxies.is_empty(), "empty proxies stored");
+				ensure!(proxies.windows(2).all(|pair| pair[0] < pair[1]), "proxies not sorted");
+				ensure!(
+					proxies.iter().all(|proxy| proxy.delegate != delegator),
+					"account proxy of itself"
+				);
+			}
+			ensure!(
+				Announcements::<T>::iter_values().all(|pending| !pending.is_empty()),
+				"empty announcements stored"
+			);
+
+			Ok(())
+		}
+	}
+}
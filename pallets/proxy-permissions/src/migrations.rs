@@ -0,0 +1,115 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 records the deposit of each proxy and announcement.
+///
+/// Nothing was reserved for the ones registered before, so they are migrated with no deposit.
+pub mod v2 {
+	use super::*;
+	use crate::{Announcement, Announcements, Proxies, ProxyDefinition};
+	use codec::{Decode, Encode};
+	use frame_support::BoundedVec;
+	use sp_runtime::traits::Zero;
+	use sp_std::prelude::*;
+
+	/// A proxy, as stored by version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldProxyDefinition<AccountId, ProxyType, BlockNumber> {
+		pub delegate: AccountId,
+		pub proxy_type: ProxyType,
+		pub delay: BlockNumber,
+	}
+
+	/// An announcement, as stored by version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldAnnouncement<AccountId, Hash, BlockNumber> {
+		pub real: AccountId,
+		pub call_hash: Hash,
+		pub height: BlockNumber,
+	}
+
+	type OldProxiesOf<T> = BoundedVec<
+		OldProxyDefinition<
+			<T as frame_system::Config>::AccountId,
+			<T as Config>::ProxyType,
+			<T as frame_system::Config>::BlockNumber,
+		>,
+		<T as Config>::MaxProxies,
+	>;
+	type OldAnnouncementsOf<T> = BoundedVec<
+		OldAnnouncement<
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::Hash,
+			<T as frame_system::Config>::BlockNumber,
+		>,
+		<T as Config>::MaxPending,
+	>;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let mut migrated = 0u64;
+		Proxies::<T>::translate::<OldProxiesOf<T>, _>(|_, old| {
+			migrated += 1;
+			let proxies = old
+				.into_iter()
+				.map(|old| ProxyDefinition {
+					delegate: old.delegate,
+					proxy_type: old.proxy_type,
+					delay: old.delay,
+					deposit: Zero::zero(),
+				})
+				.collect::<Vec<_>>();
+			// As many proxies as before fit the same bound.
+			proxies.try_into().ok()
+		});
+		Announcements::<T>::translate::<OldAnnouncementsOf<T>, _>(|_, old| {
+			migrated += 1;
+			let pending = old
+				.into_iter()
+				.map(|old| Announcement {
+					real: old.real,
+					call_hash: old.call_hash,
+					height: old.height,
+					deposit: Zero::zero(),
+				})
+				.collect::<Vec<_>>();
+			pending.try_into().ok()
+		});
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(migrated, migrated + 1)
+	}
+}
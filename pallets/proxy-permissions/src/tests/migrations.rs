@@ -0,0 +1,53 @@
+use super::mock::*;
+use crate::migrations::{
+	self,
+	v2::{OldAnnouncement, OldProxyDefinition},
+};
+use frame_support::{
+	assert_ok,
+	storage::migration::put_storage_value,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	Hashable,
+};
+use sp_core::H256;
+
+#[test]
+fn runtime_upgrade_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<ProxyPermissions>();
+
+		<ProxyPermissions as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(
+			ProxyPermissions::on_chain_storage_version(),
+			ProxyPermissions::current_storage_version()
+		);
+	})
+}
+
+#[test]
+fn migrate_records_no_deposit_for_existing_proxies() {
+	new_test_ext().execute_with(|| {
+		// Version 1 reserved no deposits.
+		let proxy = OldProxyDefinition { delegate: BOB, proxy_type: ProxyType::Any, delay: 5u64 };
+		let proxies = vec![proxy];
+		put_storage_value(b"ProxyPermissions", b"Proxies", &ALICE.blake2_128_concat(), proxies);
+		let call_hash = H256::repeat_byte(1);
+		let pending = vec![OldAnnouncement { real: ALICE, call_hash, height: 1u64 }];
+		put_storage_value(b"ProxyPermissions", b"Announcements", &BOB.blake2_128_concat(), pending);
+		StorageVersion::new(1).put::<ProxyPermissions>();
+
+		migrations::migrate::<Test>();
+
+		let proxies = ProxyPermissions::proxies(ALICE);
+		assert_eq!((proxies[0].delegate, proxies[0].delay, proxies[0].deposit), (BOB, 5, 0));
+		let pending = ProxyPermissions::announcements(BOB);
+		assert_eq!((pending[0].call_hash, pending[0].deposit), (call_hash, 0));
+		assert_eq!(ProxyPermissions::on_chain_storage_version(), 2);
+
+		// Removing them refunds nothing.
+		assert_ok!(ProxyPermissions::remove_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		assert!(ProxyPermissions::announcements(BOB).is_empty());
+		assert_eq!(Balances::free_balance(ALICE), 100);
+	})
+}
@@ -0,0 +1,4 @@
+pub mod mock;
+
+mod migrations;
+mod proxy;
@@ -0,0 +1,271 @@
+use super::mock::*;
+use crate::{Error, Event as ProxyEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn transfer(dest: u64, value: u64) -> Box<Call> {
+	Box::new(Call::Balances(pallet_balances::Call::transfer { dest, value }))
+}
+
+#[test]
+fn add_and_remove_proxy() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProxyPermissions::add_proxy(Origin::signed(ALICE), ALICE, ProxyType::Any, 0),
+			Error::<Test>::NoSelfProxy
+		);
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 0));
+		assert_noop!(
+			ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 0),
+			Error::<Test>::Duplicate
+		);
+		assert_ok!(ProxyPermissions::add_proxy(
+			Origin::signed(ALICE),
+			BOB,
+			ProxyType::Transfers,
+			0
+		));
+		assert_noop!(
+			ProxyPermissions::add_proxy(Origin::signed(ALICE), CHARLIE, ProxyType::Any, 0),
+			Error::<Test>::TooMany
+		);
+
+		assert_ok!(ProxyPermissions::remove_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 0));
+		System::assert_last_event(
+			ProxyEvent::ProxyRemoved { delegator: ALICE, delegate: BOB, proxy_type: ProxyType::Any }
+				.into(),
+		);
+		assert_noop!(
+			ProxyPermissions::remove_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 0),
+			Error::<Test>::NotProxy
+		);
+		assert_eq!(ProxyPermissions::proxies(ALICE).len(), 1);
+	})
+}
+
+#[test]
+fn proxy_dispatches_allowed_calls_as_the_delegator() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProxyPermissions::proxy(Origin::signed(BOB), ALICE, None, transfer(CHARLIE, 10)),
+			Error::<Test>::NotProxy
+		);
+		assert_ok!(ProxyPermissions::add_proxy(
+			Origin::signed(ALICE),
+			BOB,
+			ProxyType::Transfers,
+			0
+		));
+
+		assert_ok!(ProxyPermissions::proxy(Origin::signed(BOB), ALICE, None, transfer(CHARLIE, 10)));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { real: ALICE, proxy: BOB, result: Ok(()) }.into(),
+		);
+		// Alice also has the deposit of the proxy reserved.
+		assert_eq!(Balances::free_balance(ALICE), 89);
+		assert_eq!(Balances::free_balance(CHARLIE), 110);
+	})
+}
+
+#[test]
+fn proxy_prefers_definitions_without_delay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		assert_ok!(ProxyPermissions::add_proxy(
+			Origin::signed(ALICE),
+			BOB,
+			ProxyType::Transfers,
+			0
+		));
+
+		assert_ok!(ProxyPermissions::proxy(Origin::signed(BOB), ALICE, None, transfer(CHARLIE, 10)));
+		assert_eq!(Balances::free_balance(CHARLIE), 110);
+	})
+}
+
+#[test]
+fn proxy_type_filters_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProxyPermissions::add_proxy(
+			Origin::signed(ALICE),
+			BOB,
+			ProxyType::Transfers,
+			0
+		));
+
+		let remark = Box::new(Call::System(frame_system::Call::remark { remark: vec![] }));
+		assert_ok!(ProxyPermissions::proxy(Origin::signed(BOB), ALICE, None, remark));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted {
+				real: ALICE,
+				proxy: BOB,
+				result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+			}
+			.into(),
+		);
+
+		// A transfer proxy cannot grant itself more permissions.
+		let escalate = Box::new(Call::ProxyPermissions(crate::Call::add_proxy {
+			delegate: BOB,
+			proxy_type: ProxyType::Any,
+			delay: 0,
+		}));
+		assert_ok!(ProxyPermissions::proxy(Origin::signed(BOB), ALICE, None, escalate));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted {
+				real: ALICE,
+				proxy: BOB,
+				result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+			}
+			.into(),
+		);
+		assert_eq!(ProxyPermissions::proxies(ALICE).len(), 1);
+	})
+}
+
+#[test]
+fn delayed_proxy_must_announce_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		assert_noop!(
+			ProxyPermissions::proxy(Origin::signed(BOB), ALICE, None, transfer(CHARLIE, 10)),
+			Error::<Test>::Unannounced
+		);
+
+		let call_hash = BlakeTwo256::hash_of(&transfer(CHARLIE, 10));
+		assert_noop!(
+			ProxyPermissions::announce(Origin::signed(CHARLIE), ALICE, call_hash),
+			Error::<Test>::NotProxy
+		);
+		assert_ok!(ProxyPermissions::announce(Origin::signed(BOB), ALICE, call_hash));
+		System::assert_last_event(
+			ProxyEvent::Announced { real: ALICE, proxy: BOB, call_hash }.into(),
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			ProxyPermissions::proxy_announced(
+				Origin::signed(CHARLIE),
+				BOB,
+				ALICE,
+				None,
+				transfer(CHARLIE, 10)
+			),
+			Error::<Test>::Unannounced
+		);
+
+		System::set_block_number(6);
+		assert_ok!(ProxyPermissions::proxy_announced(
+			Origin::signed(CHARLIE),
+			BOB,
+			ALICE,
+			None,
+			transfer(CHARLIE, 10)
+		));
+		assert_eq!(Balances::free_balance(CHARLIE), 110);
+		assert!(ProxyPermissions::announcements(BOB).is_empty());
+	})
+}
+
+#[test]
+fn delegator_can_reject_announcements() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		let call_hash = BlakeTwo256::hash_of(&transfer(BOB, 100));
+		assert_ok!(ProxyPermissions::announce(Origin::signed(BOB), ALICE, call_hash));
+
+		assert_noop!(
+			ProxyPermissions::reject_announcement(Origin::signed(CHARLIE), BOB, call_hash),
+			Error::<Test>::UnknownAnnouncement
+		);
+		assert_ok!(ProxyPermissions::reject_announcement(Origin::signed(ALICE), BOB, call_hash));
+
+		System::set_block_number(6);
+		assert_noop!(
+			ProxyPermissions::proxy_announced(
+				Origin::signed(BOB),
+				BOB,
+				ALICE,
+				None,
+				transfer(BOB, 100)
+			),
+			Error::<Test>::UnknownAnnouncement
+		);
+	})
+}
+
+#[test]
+fn proxies_and_announcements_reserve_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		assert_eq!(Balances::reserved_balance(ALICE), 1);
+
+		let call_hash = BlakeTwo256::hash_of(&transfer(CHARLIE, 10));
+		assert_ok!(ProxyPermissions::announce(Origin::signed(BOB), ALICE, call_hash));
+		assert_eq!(Balances::reserved_balance(BOB), 2);
+
+		System::set_block_number(6);
+		assert_ok!(ProxyPermissions::proxy_announced(
+			Origin::signed(CHARLIE),
+			BOB,
+			ALICE,
+			None,
+			transfer(CHARLIE, 10)
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+
+		assert_ok!(ProxyPermissions::announce(Origin::signed(BOB), ALICE, call_hash));
+		assert_ok!(ProxyPermissions::reject_announcement(Origin::signed(ALICE), BOB, call_hash));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+
+		assert_ok!(ProxyPermissions::remove_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	})
+}
+
+#[test]
+fn removing_a_proxy_drops_its_announcements() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		assert_ok!(ProxyPermissions::add_proxy(
+			Origin::signed(ALICE),
+			BOB,
+			ProxyType::Transfers,
+			5
+		));
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(CHARLIE), BOB, ProxyType::Any, 5));
+		let call_hash = BlakeTwo256::hash_of(&transfer(BOB, 10));
+		assert_ok!(ProxyPermissions::announce(Origin::signed(BOB), ALICE, call_hash));
+		assert_ok!(ProxyPermissions::announce(Origin::signed(BOB), CHARLIE, call_hash));
+
+		// Bob is still a proxy of Alice: its announcement stays pending.
+		assert_ok!(ProxyPermissions::remove_proxy(
+			Origin::signed(ALICE),
+			BOB,
+			ProxyType::Transfers,
+			5
+		));
+		assert_eq!(ProxyPermissions::announcements(BOB).len(), 2);
+
+		// Only the announcement on behalf of Alice goes, along with its deposit.
+		assert_ok!(ProxyPermissions::remove_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		let pending = ProxyPermissions::announcements(BOB);
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].real, CHARLIE);
+		assert_eq!(Balances::reserved_balance(BOB), 2);
+
+		// Adding Bob back does not revive the announcement.
+		assert_ok!(ProxyPermissions::add_proxy(Origin::signed(ALICE), BOB, ProxyType::Any, 5));
+		System::set_block_number(6);
+		assert_noop!(
+			ProxyPermissions::proxy_announced(
+				Origin::signed(BOB),
+				BOB,
+				ALICE,
+				None,
+				transfer(BOB, 10)
+			),
+			Error::<Test>::UnknownAnnouncement
+		);
+	})
+}
@@ -0,0 +1,49 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Currency;
+use scale_info::TypeInfo;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type ProxyDefinitionOf<T> = ProxyDefinition<
+	AccountIdOf<T>,
+	<T as Config>::ProxyType,
+	<T as frame_system::Config>::BlockNumber,
+	BalanceOf<T>,
+>;
+pub type AnnouncementOf<T> = Announcement<
+	AccountIdOf<T>,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+	BalanceOf<T>,
+>;
+
+/// A proxy registered by a delegator.
+///
+/// The proxies of a delegator are sorted by delegate, proxy type and delay, which identify them.
+#[derive(
+	Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, MaxEncodedLen, Debug, TypeInfo,
+)]
+pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber, Balance> {
+	/// The proxy account.
+	pub delegate: AccountId,
+	/// The calls the proxy is allowed to dispatch.
+	pub proxy_type: ProxyType,
+	/// The number of blocks a call must be announced in advance.
+	pub delay: BlockNumber,
+	/// The deposit reserved from the delegator.
+	pub deposit: Balance,
+}
+
+/// A call announced by a proxy.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Announcement<AccountId, Hash, BlockNumber, Balance> {
+	/// The account the call will be dispatched for.
+	pub real: AccountId,
+	/// The hash of the announced call.
+	pub call_hash: Hash,
+	/// The block the call was announced at.
+	pub height: BlockNumber,
+	/// The deposit reserved from the proxy.
+	pub deposit: Balance,
+}
@@ -0,0 +1,107 @@
+//! Weights for pallet_proxy_permissions
+//!
+//! Estimated from the storage accesses of each call, until they are generated on the reference
+//! hardware with the benchmark CLI:
+//!
+//! ./target/release/rusty-node benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_proxy_permissions --extrinsic '*' --steps 50 --repeat 20
+//! --template .maintain/frame-weight-template.hbs --output pallets/proxy-permissions/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::{Weight, constants::RocksDbWeight}};
+
+/// Weight functions needed for pallet_proxy_permissions.
+pub trait WeightInfo {
+	fn add_proxy(p: u32) -> Weight;
+	fn remove_proxy(p: u32, a: u32) -> Weight;
+	fn proxy(p: u32) -> Weight;
+	fn announce(a: u32) -> Weight;
+	fn reject_announcement(a: u32) -> Weight;
+	fn proxy_announced(a: u32, p: u32) -> Weight;
+}
+
+/// Weights for pallet_proxy_permissions using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn add_proxy(p: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn remove_proxy(p: u32, a: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn proxy(p: u32) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+	fn announce(a: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn reject_announcement(a: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn proxy_announced(a: u32, p: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn add_proxy(p: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn remove_proxy(p: u32, a: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn proxy(p: u32) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
+	fn announce(a: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn reject_announcement(a: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn proxy_announced(a: u32, p: u32) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((300_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add((200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
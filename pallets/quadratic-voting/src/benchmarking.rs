@@ -0,0 +1,91 @@
+//! Benchmarks of the quadratic-voting pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{
+	tokens::fungibles::{Create, Inspect, Mutate},
+	Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, One, Zero};
+
+const AMOUNT: u32 = 1_000_000;
+const VOTES: u32 = 100;
+
+/// Create the voting asset unless it exists, and fund `holders` with it.
+fn fund<T: Config>(holders: &[&T::AccountId])
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let asset_id = T::VotingAsset::get();
+	if T::Assets::minimum_balance(asset_id).is_zero() {
+		T::Assets::create(asset_id, account("owner", 0, 0), true, One::one()).unwrap();
+	}
+	for who in holders {
+		let amount = T::ProposalDeposit::get() + AMOUNT.into();
+		T::Assets::mint_into(asset_id, who, amount).unwrap();
+	}
+}
+
+/// Have `proposer` make a proposal, returning its id and the block voting ends at.
+fn new_proposal<T: Config>(proposer: &T::AccountId) -> (ProposalId, T::BlockNumber) {
+	let proposal_id = Pallet::<T>::next_proposal_id();
+	let description = T::Hashing::hash_of(&proposal_id);
+	Pallet::<T>::propose(RawOrigin::Signed(proposer.clone()).into(), description).unwrap();
+	let end = Pallet::<T>::proposal(proposal_id).unwrap().end;
+	(proposal_id, end)
+}
+
+benchmarks! {
+	where_clause { where T::Assets: Create<T::AccountId> + Mutate<T::AccountId> }
+
+	propose {
+		let proposer: T::AccountId = whitelisted_caller();
+		fund::<T>(&[&proposer]);
+		let proposal_id = Pallet::<T>::next_proposal_id();
+		let description = T::Hashing::hash_of(&proposal_id);
+	}: _(RawOrigin::Signed(proposer), description)
+	verify {
+		assert!(Pallet::<T>::proposal(proposal_id).is_some());
+	}
+
+	// The caller replaces its previous votes, and is charged the difference.
+	vote {
+		let proposer: T::AccountId = account("proposer", 0, 0);
+		let voter: T::AccountId = whitelisted_caller();
+		fund::<T>(&[&proposer, &voter]);
+		let (proposal_id, _) = new_proposal::<T>(&proposer);
+		Pallet::<T>::vote(RawOrigin::Signed(voter.clone()).into(), proposal_id, false, VOTES)
+			.unwrap();
+	}: _(RawOrigin::Signed(voter.clone()), proposal_id, true, VOTES * 2)
+	verify {
+		let votes = Pallet::<T>::vote_of(proposal_id, &voter).map(|vote| vote.votes);
+		assert_eq!(votes, Some(VOTES * 2));
+	}
+
+	close {
+		let proposer: T::AccountId = account("proposer", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&[&proposer]);
+		let (proposal_id, end) = new_proposal::<T>(&proposer);
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: _(RawOrigin::Signed(caller), proposal_id)
+	verify {
+		assert!(Pallet::<T>::proposal(proposal_id).is_none());
+	}
+
+	unlock {
+		let proposer: T::AccountId = account("proposer", 0, 0);
+		let voter: T::AccountId = whitelisted_caller();
+		fund::<T>(&[&proposer, &voter]);
+		let (proposal_id, end) = new_proposal::<T>(&proposer);
+		Pallet::<T>::vote(RawOrigin::Signed(voter.clone()).into(), proposal_id, true, VOTES)
+			.unwrap();
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: _(RawOrigin::Signed(voter.clone()), proposal_id)
+	verify {
+		assert!(Pallet::<T>::vote_of(proposal_id, &voter).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,353 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Vote on proposals with quadratic costs.
+///
+/// Casting `n` votes on a proposal costs `n²` units of the voting asset, so an account must spend
+/// four times as much to double its influence: intensity of preference can be expressed, but
+/// large holders cannot simply outvote everyone else.
+///
+/// The cost of votes is escrowed in the pallet account, not burnt, and refunded to voters once
+/// voting ended. Proposers also escrow a deposit, refunded when their proposal is closed.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::fungibles, transactional, PalletId};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, CheckedMul, Saturating, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets votes and deposits are paid with.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The asset votes and deposits are paid with.
+		#[pallet::constant]
+		type VotingAsset: Get<AssetIdOf<Self>>;
+
+		/// The deposit escrowed from proposers.
+		#[pallet::constant]
+		type ProposalDeposit: Get<BalanceOf<Self>>;
+
+		/// The number of blocks proposals are open for voting.
+		#[pallet::constant]
+		type VotingPeriod: Get<Self::BlockNumber>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next proposal will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_proposal_id)]
+	pub type NextProposalId<T> = StorageValue<_, ProposalId, ValueQuery>;
+
+	/// The proposals that have not been closed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal)]
+	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, ProposalId, ProposalOf<T>>;
+
+	/// The votes of each account on each proposal, until their cost is refunded.
+	#[pallet::storage]
+	#[pallet::getter(fn vote_of)]
+	pub type Votes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ProposalId, Blake2_128Concat, T::AccountId, VoteOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new proposal has been made.
+		Proposed {
+			proposal_id: ProposalId,
+			proposer: T::AccountId,
+			description: T::Hash,
+			end: T::BlockNumber,
+		},
+		/// An account voted on a proposal, replacing its previous votes.
+		Voted {
+			proposal_id: ProposalId,
+			voter: T::AccountId,
+			aye: bool,
+			votes: u32,
+			cost: BalanceOf<T>,
+		},
+		/// A proposal has been closed, and its deposit refunded.
+		Closed { proposal_id: ProposalId, approved: bool, ayes: u64, nays: u64 },
+		/// The cost of an account's votes has been refunded.
+		Unlocked { proposal_id: ProposalId, voter: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The proposal does not exist.
+		UnknownProposal,
+		/// Voting on the proposal has ended.
+		VotingEnded,
+		/// Voting on the proposal has not ended yet.
+		VotingOngoing,
+		/// The number of votes must be non zero.
+		ZeroVotes,
+		/// The cost of the votes overflowed.
+		Overflow,
+		/// The account has no votes to unlock on the proposal.
+		NoVotes,
+		/// There is no proposal id left.
+		NoAvailableProposalId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Make a proposal, escrowing `ProposalDeposit`.
+		#[pallet::weight(T::WeightInfo::propose())]
+		pub fn propose(origin: OriginFor<T>, description: T::Hash) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+
+			let proposal_id = Self::next_proposal_id();
+			let next_id = proposal_id.checked_add(1).ok_or(Error::<T>::NoAvailableProposalId)?;
+
+			let deposit = T::ProposalDeposit::get();
+			let asset_id = T::VotingAsset::get();
+			T::Assets::transfer(asset_id, &proposer, &Self::account_id(), deposit, false)?;
+
+			let end =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::VotingPeriod::get());
+			let proposal = Proposal {
+				proposer: proposer.clone(),
+				deposit,
+				description,
+				end,
+				ayes: 0,
+				nays: 0,
+			};
+			Proposals::<T>::insert(proposal_id, proposal);
+			NextProposalId::<T>::put(next_id);
+
+			Self::deposit_event(Event::Proposed { proposal_id, proposer, description, end });
+
+			Ok(())
+		}
+
+		/// Cast `votes` votes on a proposal, escrowing `votes²` of the voting asset.
+		///
+		/// Voting again replaces the previous votes of the caller: only the difference in cost is
+		/// escrowed, or refunded.
+		#[pallet::weight(T::WeightInfo::vote())]
+		#[transactional]
+		pub fn vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			aye: bool,
+			votes: u32,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			ensure!(votes > 0, Error::<T>::ZeroVotes);
+			let mut proposal = Self::proposal(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < proposal.end,
+				Error::<T>::VotingEnded
+			);
+
+			let cost = Self::cost_of(votes)?;
+			let previous_cost = match Self::vote_of(proposal_id, &voter) {
+				Some(previous) => {
+					Self::untally(&mut proposal, &previous);
+					previous.cost
+				},
+				None => Zero::zero(),
+			};
+
+			let asset_id = T::VotingAsset::get();
+			if cost > previous_cost {
+				T::Assets::transfer(
+					asset_id,
+					&voter,
+					&Self::account_id(),
+					cost - previous_cost,
+					false,
+				)?;
+			} else if previous_cost > cost {
+				T::Assets::transfer(
+					asset_id,
+					&Self::account_id(),
+					&voter,
+					previous_cost - cost,
+					false,
+				)?;
+			}
+
+			let vote = Vote { aye, votes, cost };
+			if aye {
+				proposal.ayes = proposal.ayes.saturating_add(votes.into());
+			} else {
+				proposal.nays = proposal.nays.saturating_add(votes.into());
+			}
+			Votes::<T>::insert(proposal_id, &voter, vote);
+			Proposals::<T>::insert(proposal_id, proposal);
+
+			Self::deposit_event(Event::Voted { proposal_id, voter, aye, votes, cost });
+
+			Ok(())
+		}
+
+		/// Close a proposal once voting ended, and refund its deposit to the proposer.
+		///
+		/// The proposal is approved if it got more ayes than nays.
+		#[pallet::weight(T::WeightInfo::close())]
+		pub fn close(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let proposal = Self::proposal(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= proposal.end,
+				Error::<T>::VotingOngoing
+			);
+
+			T::Assets::transfer(
+				T::VotingAsset::get(),
+				&Self::account_id(),
+				&proposal.proposer,
+				proposal.deposit,
+				false,
+			)?;
+			Proposals::<T>::remove(proposal_id);
+
+			Self::deposit_event(Event::Closed {
+				proposal_id,
+				approved: proposal.ayes > proposal.nays,
+				ayes: proposal.ayes,
+				nays: proposal.nays,
+			});
+
+			Ok(())
+		}
+
+		/// Refund the cost of the caller's votes on a proposal once voting ended.
+		#[pallet::weight(T::WeightInfo::unlock())]
+		pub fn unlock(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			// Closed proposals are removed, and can only be closed once voting ended.
+			if let Some(proposal) = Self::proposal(proposal_id) {
+				ensure!(
+					frame_system::Pallet::<T>::block_number() >= proposal.end,
+					Error::<T>::VotingOngoing
+				);
+			}
+			let vote = Self::vote_of(proposal_id, &voter).ok_or(Error::<T>::NoVotes)?;
+
+			T::Assets::transfer(
+				T::VotingAsset::get(),
+				&Self::account_id(),
+				&voter,
+				vote.cost,
+				false,
+			)?;
+			Votes::<T>::remove(proposal_id, &voter);
+
+			Self::deposit_event(Event::Unlocked { proposal_id, voter, amount: vote.cost });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding deposits and the cost of votes.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// The cost of casting `votes` votes on a proposal.
+		pub fn cost_of(votes: u32) -> Result<BalanceOf<T>, DispatchError> {
+			let votes: BalanceOf<T> = votes.into();
+			votes.checked_mul(&votes).ok_or_else(|| Error::<T>::Overflow.into())
+		}
+
+		/// Remove previous votes from the tally of a proposal.
+		fn untally(proposal: &mut ProposalOf<T>, vote: &VoteOf<T>) {
+			if vote.aye {
+				proposal.ayes = proposal.ayes.saturating_sub(vote.votes.into());
+			} else {
+				proposal.nays = proposal.nays.saturating_sub(vote.votes.into());
+			}
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			let next_id = Self::next_proposal_id();
+			let mut escrowed = BalanceOf::<T>::zero();
+			for (proposal_id, proposal) in Proposals::<T>::iter() {
+				ensure!(proposal_id < next_id, "proposal id not allocated");
+				let (mut ayes, mut nays) = (0u64, 0u64);
+				for vote in Votes::<T>::iter_prefix_values(proposal_id) {
+					if vote.aye {
+						ayes = ayes.saturating_add(vote.votes.into());
+					} else {
+						nays = nays.saturating_add(vote.votes.into());
+					}
+				}
+				ensure!(
+					ayes == proposal.ayes && nays == proposal.nays,
+					"tally not matching the votes"
+				);
+				escrowed = escrowed.saturating_add(proposal.deposit);
+			}
+			for vote in Votes::<T>::iter_values() {
+				ensure!(
+					Self::cost_of(vote.votes).ok() == Some(vote.cost),
+					"vote cost not quadratic"
+				);
+				escrowed = escrowed.saturating_add(vote.cost);
+			}
+
+			// The escrow account holds the deposits and the cost of the votes not unlocked yet.
+			ensure!(
+				T::Assets::balance(T::VotingAsset::get(), &Self::account_id()) >= escrowed,
+				"deposits and votes not escrowed"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,100 @@
+use super::mock::*;
+use crate::{Error, Event as VotingEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+fn propose() {
+	assert_ok!(QuadraticVoting::propose(Origin::signed(ALICE), H256::repeat_byte(1)));
+}
+
+#[test]
+fn propose_escrows_the_deposit() {
+	new_test_ext().execute_with(|| {
+		propose();
+
+		let proposal = QuadraticVoting::proposal(0).unwrap();
+		assert_eq!(proposal.end, 11);
+		assert_eq!(Assets::balance(VOTING_ASSET, ALICE), 950);
+		assert_eq!(Assets::balance(VOTING_ASSET, QuadraticVoting::account_id()), 50);
+		System::assert_last_event(
+			VotingEvent::Proposed {
+				proposal_id: 0,
+				proposer: ALICE,
+				description: H256::repeat_byte(1),
+				end: 11,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn votes_cost_their_square() {
+	new_test_ext().execute_with(|| {
+		propose();
+
+		assert_noop!(
+			QuadraticVoting::vote(Origin::signed(BOB), 0, true, 0),
+			Error::<Test>::ZeroVotes
+		);
+		assert_noop!(
+			QuadraticVoting::vote(Origin::signed(BOB), 1, true, 1),
+			Error::<Test>::UnknownProposal
+		);
+
+		assert_ok!(QuadraticVoting::vote(Origin::signed(BOB), 0, true, 10));
+		assert_eq!(Assets::balance(VOTING_ASSET, BOB), 900);
+		assert_ok!(QuadraticVoting::vote(Origin::signed(CHARLIE), 0, false, 5));
+		assert_eq!(Assets::balance(VOTING_ASSET, CHARLIE), 975);
+
+		let proposal = QuadraticVoting::proposal(0).unwrap();
+		assert_eq!((proposal.ayes, proposal.nays), (10, 5));
+
+		// A single account cannot afford many more votes.
+		assert!(QuadraticVoting::vote(Origin::signed(CHARLIE), 0, false, 32).is_err());
+	})
+}
+
+#[test]
+fn voting_again_replaces_previous_votes() {
+	new_test_ext().execute_with(|| {
+		propose();
+		assert_ok!(QuadraticVoting::vote(Origin::signed(BOB), 0, true, 10));
+
+		assert_ok!(QuadraticVoting::vote(Origin::signed(BOB), 0, false, 3));
+		assert_eq!(Assets::balance(VOTING_ASSET, BOB), 991);
+		let proposal = QuadraticVoting::proposal(0).unwrap();
+		assert_eq!((proposal.ayes, proposal.nays), (0, 3));
+		System::assert_last_event(
+			VotingEvent::Voted { proposal_id: 0, voter: BOB, aye: false, votes: 3, cost: 9 }.into(),
+		);
+	})
+}
+
+#[test]
+fn close_and_unlock_after_voting_ended() {
+	new_test_ext().execute_with(|| {
+		propose();
+		assert_ok!(QuadraticVoting::vote(Origin::signed(BOB), 0, true, 4));
+		assert_ok!(QuadraticVoting::vote(Origin::signed(CHARLIE), 0, false, 3));
+
+		assert_noop!(QuadraticVoting::close(Origin::signed(BOB), 0), Error::<Test>::VotingOngoing);
+		assert_noop!(QuadraticVoting::unlock(Origin::signed(BOB), 0), Error::<Test>::VotingOngoing);
+
+		System::set_block_number(11);
+		assert_noop!(
+			QuadraticVoting::vote(Origin::signed(BOB), 0, true, 1),
+			Error::<Test>::VotingEnded
+		);
+		assert_ok!(QuadraticVoting::close(Origin::signed(BOB), 0));
+		System::assert_last_event(
+			VotingEvent::Closed { proposal_id: 0, approved: true, ayes: 4, nays: 3 }.into(),
+		);
+		assert_eq!(Assets::balance(VOTING_ASSET, ALICE), 1_000);
+		assert!(QuadraticVoting::proposal(0).is_none());
+
+		assert_ok!(QuadraticVoting::unlock(Origin::signed(BOB), 0));
+		assert_eq!(Assets::balance(VOTING_ASSET, BOB), 1_000);
+		assert_noop!(QuadraticVoting::unlock(Origin::signed(BOB), 0), Error::<Test>::NoVotes);
+	})
+}
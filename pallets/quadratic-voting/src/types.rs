@@ -0,0 +1,45 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type ProposalId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type ProposalOf<T> = Proposal<
+	AccountIdOf<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub type VoteOf<T> = Vote<BalanceOf<T>>;
+
+/// A proposal open for voting.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Proposal<AccountId, Balance, Hash, BlockNumber> {
+	/// The account that made the proposal.
+	pub proposer: AccountId,
+	/// The deposit escrowed from the proposer, refunded when the proposal is closed.
+	pub deposit: Balance,
+	/// The hash of the proposal description, stored off-chain.
+	pub description: Hash,
+	/// The block voting ends at.
+	pub end: BlockNumber,
+	/// The votes in favor of the proposal.
+	pub ayes: u64,
+	/// The votes against the proposal.
+	pub nays: u64,
+}
+
+/// The votes of an account on a proposal.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Vote<Balance> {
+	/// Whether the votes are in favor of the proposal.
+	pub aye: bool,
+	/// The number of votes cast.
+	pub votes: u32,
+	/// The amount escrowed for these votes, refunded once voting ended.
+	pub cost: Balance,
+}
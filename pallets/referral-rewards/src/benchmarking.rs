@@ -0,0 +1,82 @@
+//! Benchmarks of the referral-rewards pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	sp_std::{prelude::*, vec},
+	traits::{
+		tokens::fungibles::{Create, Mutate},
+		Currency, EnsureOrigin, Get,
+	},
+};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::One, Perbill};
+
+const ASSET_ID: u32 = 1_000;
+const AMOUNT: u32 = 1_000_000;
+
+/// The referral code of `seed`, of the maximum length.
+fn code<T: Config>(seed: u8) -> CodeOf<T> {
+	vec![seed; T::MaxCodeLength::get() as usize].try_into().unwrap()
+}
+
+/// Have `owner` register a referral code.
+fn register<T: Config>(owner: &T::AccountId) -> CodeOf<T> {
+	let code = code::<T>(0);
+	Pallet::<T>::register_code(RawOrigin::Signed(owner.clone()).into(), code.clone()).unwrap();
+	code
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	register_code {
+		let owner: T::AccountId = whitelisted_caller();
+		let code = code::<T>(0);
+	}: _(RawOrigin::Signed(owner.clone()), code.clone())
+	verify {
+		assert_eq!(Pallet::<T>::code_owner(code), Some(owner));
+	}
+
+	bind {
+		let referrer: T::AccountId = account("referrer", 0, 0);
+		let referee: T::AccountId = whitelisted_caller();
+		let code = register::<T>(&referrer);
+	}: _(RawOrigin::Signed(referee.clone()), code)
+	verify {
+		assert_eq!(Pallet::<T>::referral(referee), Some(Some(referrer)));
+	}
+
+	set_rate {
+		let origin = T::RateOrigin::successful_origin();
+		let rate = Perbill::from_percent(10);
+	}: _<T::Origin>(origin, FeeKind::Marketplace, rate)
+	verify {
+		assert_eq!(Pallet::<T>::rate(FeeKind::Marketplace), rate);
+	}
+
+	claim {
+		let referrer: T::AccountId = whitelisted_caller();
+		let asset_id: AssetIdOf<T> = ASSET_ID.into();
+		T::Assets::create(asset_id, account("owner", 0, 0), true, One::one()).unwrap();
+		T::Assets::mint_into(asset_id, &Pallet::<T>::account_id(), AMOUNT.into()).unwrap();
+		Accrued::<T>::insert(&referrer, asset_id, BalanceOf::<T>::from(AMOUNT));
+	}: _(RawOrigin::Signed(referrer.clone()), asset_id)
+	verify {
+		assert_eq!(Pallet::<T>::accrued(&referrer, asset_id), 0u32.into());
+	}
+
+	claim_native {
+		let referrer: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&Pallet::<T>::account_id(), AMOUNT.into());
+		AccruedNative::<T>::insert(&referrer, BalanceOf::<T>::from(AMOUNT));
+	}: _(RawOrigin::Signed(referrer.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::accrued_native(&referrer), 0u32.into());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
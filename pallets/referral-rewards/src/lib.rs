@@ -0,0 +1,397 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Referral rewards, paid out of the fees referred accounts are charged.
+///
+/// Users register referral codes. A new account binds to the owner of a code before its first
+/// fee is charged: once it has paid a fee, it can no longer be referred. The pallets charging fees,
+/// e.g. a marketplace or a DEX, report each fee through [`OnFeeCharged`], or [`OnNativeFeeCharged`]
+/// for the native currency, before collecting it, and a share of the fee, set per kind of
+/// operation, is set aside for the payer's referrer, who can later claim everything accrued. The
+/// fee of a claim is refunded once per `FeelessClaimInterval`, so that collecting small rewards
+/// does not cost more than they are worth. The fee is still withdrawn before the claim runs, so
+/// the referrer needs a native balance to pay it up front.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+pub use rusty_node_support::{OnFeeCharged, OnNativeFeeCharged};
+use sp_runtime::DispatchError;
+use types::{AssetIdOf, BalanceOf, FeeKind};
+
+impl<T: Config> OnFeeCharged<T::AccountId, AssetIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	fn on_fee_charged(
+		kind: FeeKind,
+		payer: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		fee: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::share_fee(kind, payer, Some(asset_id), fee)
+	}
+}
+
+impl<T: Config> OnNativeFeeCharged<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_native_fee_charged(
+		kind: FeeKind,
+		payer: &T::AccountId,
+		fee: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::share_fee(kind, payer, None, fee)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			tokens::fungibles,
+			Currency,
+			ExistenceRequirement::{AllowDeath, KeepAlive},
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, Saturating, Zero},
+		Perbill,
+	};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets fees are paid in.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The native currency fees are paid in. A share under the existential deposit fails
+		/// while the account holding the rewards is empty.
+		type Currency: Currency<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// The origin allowed to set the share of fees paid to referrers.
+		type RateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of a referral code.
+		#[pallet::constant]
+		type MaxCodeLength: Get<u32>;
+
+		/// The pallet id, used to derive the account holding accrued rewards.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The number of blocks after a feeless claim before a referrer's next claim is feeless.
+		#[pallet::constant]
+		type FeelessClaimInterval: Get<Self::BlockNumber>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The owner of each referral code.
+	#[pallet::storage]
+	#[pallet::getter(fn code_owner)]
+	pub type Codes<T: Config> = StorageMap<_, Blake2_128Concat, CodeOf<T>, T::AccountId>;
+
+	/// The referral code of each account that registered one.
+	#[pallet::storage]
+	#[pallet::getter(fn code_of)]
+	pub type AccountCodes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, CodeOf<T>>;
+
+	/// The referrer of each account that either bound to one or was charged a fee, `None` if it
+	/// was charged a fee without being referred.
+	#[pallet::storage]
+	#[pallet::getter(fn referral)]
+	pub type Referrals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Option<T::AccountId>>;
+
+	/// The share of fees paid to referrers, by kind of operation.
+	#[pallet::storage]
+	#[pallet::getter(fn rate)]
+	pub type Rates<T> = StorageMap<_, Twox64Concat, FeeKind, Perbill, ValueQuery>;
+
+	/// The rewards accrued by each referrer, by asset, and not claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn accrued)]
+	pub type Accrued<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The rewards accrued by each referrer in the native currency, and not claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn accrued_native)]
+	pub type AccruedNative<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The block of the last feeless claim of each referrer.
+	#[pallet::storage]
+	#[pallet::getter(fn last_feeless_claim)]
+	pub type LastFeelessClaim<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A referral code has been registered.
+		CodeRegistered { owner: T::AccountId, code: CodeOf<T> },
+		/// An account has bound to a referrer.
+		Referred { referee: T::AccountId, referrer: T::AccountId },
+		/// The share of fees paid to referrers has been set.
+		RateSet { kind: FeeKind, rate: Perbill },
+		/// A share of a fee has been set aside for a referrer.
+		RewardAccrued {
+			referrer: T::AccountId,
+			referee: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// A referrer has claimed its rewards.
+		Claimed { referrer: T::AccountId, asset_id: AssetIdOf<T>, amount: BalanceOf<T> },
+		/// A share of a fee in the native currency has been set aside for a referrer.
+		NativeRewardAccrued { referrer: T::AccountId, referee: T::AccountId, amount: BalanceOf<T> },
+		/// A referrer has claimed its rewards in the native currency.
+		NativeClaimed { referrer: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The code is already registered.
+		CodeTaken,
+		/// The caller already registered a code.
+		AlreadyHasCode,
+		/// The code is not registered.
+		UnknownCode,
+		/// An account cannot refer itself.
+		SelfReferral,
+		/// The caller already bound to a referrer or was charged a fee.
+		AlreadyActive,
+		/// The caller has no reward to claim.
+		NothingToClaim,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a referral code, the caller being its owner.
+		#[pallet::weight(T::WeightInfo::register_code())]
+		pub fn register_code(origin: OriginFor<T>, code: CodeOf<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(!AccountCodes::<T>::contains_key(&owner), Error::<T>::AlreadyHasCode);
+			ensure!(!Codes::<T>::contains_key(&code), Error::<T>::CodeTaken);
+
+			Codes::<T>::insert(&code, &owner);
+			AccountCodes::<T>::insert(&owner, &code);
+
+			Self::deposit_event(Event::CodeRegistered { owner, code });
+
+			Ok(())
+		}
+
+		/// Bind to the owner of `code` as referrer.
+		///
+		/// Must be done before the caller is charged any fee.
+		#[pallet::weight(T::WeightInfo::bind())]
+		pub fn bind(origin: OriginFor<T>, code: CodeOf<T>) -> DispatchResult {
+			let referee = ensure_signed(origin)?;
+
+			let referrer = Self::code_owner(&code).ok_or(Error::<T>::UnknownCode)?;
+			ensure!(referrer != referee, Error::<T>::SelfReferral);
+			ensure!(!Referrals::<T>::contains_key(&referee), Error::<T>::AlreadyActive);
+
+			Referrals::<T>::insert(&referee, Some(referrer.clone()));
+
+			Self::deposit_event(Event::Referred { referee, referrer });
+
+			Ok(())
+		}
+
+		/// Set the share of the fees of `kind` operations paid to referrers.
+		#[pallet::weight(T::WeightInfo::set_rate())]
+		pub fn set_rate(origin: OriginFor<T>, kind: FeeKind, rate: Perbill) -> DispatchResult {
+			T::RateOrigin::ensure_origin(origin)?;
+
+			Rates::<T>::insert(kind, rate);
+
+			Self::deposit_event(Event::RateSet { kind, rate });
+
+			Ok(())
+		}
+
+		/// Claim the rewards accrued in `asset_id`.
+		///
+		/// The fee is refunded if the claim succeeds and no other claim of the referrer was in the
+		/// last `FeelessClaimInterval` blocks. Failed claims always pay.
+		#[pallet::weight(T::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>, asset_id: AssetIdOf<T>) -> DispatchResultWithPostInfo {
+			let referrer = ensure_signed(origin)?;
+
+			let amount = Self::accrued(&referrer, asset_id);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			T::Assets::transfer(asset_id, &Self::account_id(), &referrer, amount, false)?;
+			Accrued::<T>::remove(&referrer, asset_id);
+			let pays = Self::note_claim(&referrer);
+
+			Self::deposit_event(Event::Claimed { referrer, asset_id, amount });
+
+			Ok(pays.into())
+		}
+
+		/// Claim the rewards accrued in the native currency.
+		///
+		/// Refunded as [`Pallet::claim`] is.
+		#[pallet::weight(T::WeightInfo::claim_native())]
+		pub fn claim_native(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let referrer = ensure_signed(origin)?;
+
+			let amount = Self::accrued_native(&referrer);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			T::Currency::transfer(&Self::account_id(), &referrer, amount, AllowDeath)?;
+			AccruedNative::<T>::remove(&referrer);
+			let pays = Self::note_claim(&referrer);
+
+			Self::deposit_event(Event::NativeClaimed { referrer, amount });
+
+			Ok(pays.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the rewards not claimed yet.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Whether the claim `referrer` just made is feeless, recording it if it is.
+		fn note_claim(referrer: &T::AccountId) -> Pays {
+			let now = frame_system::Pallet::<T>::block_number();
+			let feeless = Self::last_feeless_claim(referrer)
+				.map_or(true, |last| now >= last.saturating_add(T::FeelessClaimInterval::get()));
+			if feeless {
+				LastFeelessClaim::<T>::insert(referrer, now);
+				Pays::No
+			} else {
+				Pays::Yes
+			}
+		}
+
+		/// Set aside the share of a `fee` of `asset_id`, or of the native currency if `None`, owed
+		/// to the referrer of `payer`, returning the rest.
+		pub(crate) fn share_fee(
+			kind: FeeKind,
+			payer: &T::AccountId,
+			asset_id: Option<AssetIdOf<T>>,
+			fee: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let referrer = match Self::referral(payer) {
+				Some(Some(referrer)) => referrer,
+				Some(None) => return Ok(fee),
+				None => {
+					// The payer was not referred before its first fee: it never will be.
+					Referrals::<T>::insert(payer, None::<T::AccountId>);
+					return Ok(fee)
+				},
+			};
+
+			let share = Self::rate(kind).mul_floor(fee);
+			if share.is_zero() {
+				return Ok(fee)
+			}
+
+			let referee = payer.clone();
+			match asset_id {
+				Some(asset_id) => {
+					T::Assets::transfer(asset_id, payer, &Self::account_id(), share, false)?;
+					Accrued::<T>::mutate(&referrer, asset_id, |accrued| {
+						*accrued = accrued.saturating_add(share)
+					});
+					Self::deposit_event(Event::RewardAccrued {
+						referrer,
+						referee,
+						asset_id,
+						amount: share,
+					});
+				},
+				None => {
+					T::Currency::transfer(payer, &Self::account_id(), share, KeepAlive)?;
+					AccruedNative::<T>::mutate(&referrer, |accrued| {
+						*accrued = accrued.saturating_add(share)
+					});
+					Self::deposit_event(Event::NativeRewardAccrued {
+						referrer,
+						referee,
+						amount: share,
+					});
+				},
+			}
+
+			Ok(fee.saturating_sub(share))
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use rusty_node_support::escrow::ensure_escrowed;
+
+			for (owner, code) in AccountCodes::<T>::iter() {
+				ensure!(
+					Self::code_owner(&code) == Some(owner),
+					"code registered to another account"
+				);
+			}
+			for (code, owner) in Codes::<T>::iter() {
+				ensure!(Self::code_of(&owner) == Some(code), "code missing from its owner's");
+			}
+
+			// The reward account holds every reward not claimed yet.
+			let account = Self::account_id();
+			ensure_escrowed::<_, T::Assets>(
+				&account,
+				Accrued::<T>::iter().map(|(_, asset_id, amount)| (asset_id, amount)),
+				"rewards not held",
+			)?;
+			let native = AccruedNative::<T>::iter_values()
+				.fold(BalanceOf::<T>::zero(), |total, amount| total.saturating_add(amount));
+			ensure!(T::Currency::free_balance(&account) >= native, "native rewards not held");
+
+			Ok(())
+		}
+	}
+}
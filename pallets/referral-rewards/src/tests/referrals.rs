@@ -0,0 +1,169 @@
+use super::mock::*;
+use crate::{CodeOf, Error, Event as ReferralEvent, FeeKind, OnFeeCharged, OnNativeFeeCharged};
+use frame_support::{assert_noop, assert_ok, weights::Pays};
+use sp_runtime::{DispatchError, Perbill};
+
+fn code(bytes: &[u8]) -> CodeOf<Test> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+fn charge_fee(kind: FeeKind, payer: u64, fee: u64) -> Result<u64, DispatchError> {
+	<ReferralRewards as OnFeeCharged<u64, u32, u64>>::on_fee_charged(kind, &payer, ASSET_ID, fee)
+}
+
+#[test]
+fn codes_are_unique() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ReferralRewards::register_code(Origin::signed(ALICE), code(b"alice")));
+		System::assert_last_event(
+			ReferralEvent::CodeRegistered { owner: ALICE, code: code(b"alice") }.into(),
+		);
+
+		assert_noop!(
+			ReferralRewards::register_code(Origin::signed(ALICE), code(b"other")),
+			Error::<Test>::AlreadyHasCode
+		);
+		assert_noop!(
+			ReferralRewards::register_code(Origin::signed(BOB), code(b"alice")),
+			Error::<Test>::CodeTaken
+		);
+		assert_eq!(ReferralRewards::code_owner(code(b"alice")), Some(ALICE));
+	})
+}
+
+#[test]
+fn accounts_bind_before_their_first_fee() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ReferralRewards::bind(Origin::signed(BOB), code(b"alice")),
+			Error::<Test>::UnknownCode
+		);
+		assert_ok!(ReferralRewards::register_code(Origin::signed(ALICE), code(b"alice")));
+		assert_noop!(
+			ReferralRewards::bind(Origin::signed(ALICE), code(b"alice")),
+			Error::<Test>::SelfReferral
+		);
+
+		assert_ok!(ReferralRewards::bind(Origin::signed(BOB), code(b"alice")));
+		System::assert_last_event(ReferralEvent::Referred { referee: BOB, referrer: ALICE }.into());
+		assert_noop!(
+			ReferralRewards::bind(Origin::signed(BOB), code(b"alice")),
+			Error::<Test>::AlreadyActive
+		);
+
+		// Charlie was charged a fee before binding: too late.
+		assert_ok!(charge_fee(FeeKind::Dex, CHARLIE, 100));
+		assert_noop!(
+			ReferralRewards::bind(Origin::signed(CHARLIE), code(b"alice")),
+			Error::<Test>::AlreadyActive
+		);
+		assert_eq!(ReferralRewards::referral(CHARLIE), Some(None));
+	})
+}
+
+#[test]
+fn referrers_accrue_a_share_of_fees_and_claim_it() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ReferralRewards::set_rate(
+				Origin::signed(ALICE),
+				FeeKind::Marketplace,
+				Perbill::from_percent(10)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(ReferralRewards::set_rate(
+			Origin::root(),
+			FeeKind::Marketplace,
+			Perbill::from_percent(10)
+		));
+		assert_ok!(ReferralRewards::register_code(Origin::signed(ALICE), code(b"alice")));
+		assert_ok!(ReferralRewards::bind(Origin::signed(BOB), code(b"alice")));
+
+		assert_eq!(charge_fee(FeeKind::Marketplace, BOB, 100), Ok(90));
+		System::assert_last_event(
+			ReferralEvent::RewardAccrued {
+				referrer: ALICE,
+				referee: BOB,
+				asset_id: ASSET_ID,
+				amount: 10,
+			}
+			.into(),
+		);
+		// No share of DEX fees is set.
+		assert_eq!(charge_fee(FeeKind::Dex, BOB, 100), Ok(100));
+
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 990);
+		assert_eq!(Assets::balance(ASSET_ID, ReferralRewards::account_id()), 10);
+		assert_eq!(ReferralRewards::accrued(ALICE, ASSET_ID), 10);
+
+		assert_ok!(ReferralRewards::claim(Origin::signed(ALICE), ASSET_ID));
+		System::assert_last_event(
+			ReferralEvent::Claimed { referrer: ALICE, asset_id: ASSET_ID, amount: 10 }.into(),
+		);
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_010);
+		assert_noop!(
+			ReferralRewards::claim(Origin::signed(ALICE), ASSET_ID),
+			Error::<Test>::NothingToClaim
+		);
+	})
+}
+
+#[test]
+fn referrers_accrue_a_share_of_native_fees_and_claim_it() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ReferralRewards::set_rate(
+			Origin::root(),
+			FeeKind::Marketplace,
+			Perbill::from_percent(10)
+		));
+		assert_ok!(ReferralRewards::register_code(Origin::signed(ALICE), code(b"alice")));
+		assert_ok!(ReferralRewards::bind(Origin::signed(BOB), code(b"alice")));
+
+		assert_eq!(
+			<ReferralRewards as OnNativeFeeCharged<u64, u64>>::on_native_fee_charged(
+				FeeKind::Marketplace,
+				&BOB,
+				50
+			),
+			Ok(45)
+		);
+		System::assert_last_event(
+			ReferralEvent::NativeRewardAccrued { referrer: ALICE, referee: BOB, amount: 5 }.into(),
+		);
+		assert_eq!(Balances::free_balance(BOB), 95);
+		assert_eq!(ReferralRewards::accrued_native(ALICE), 5);
+
+		assert_ok!(ReferralRewards::claim_native(Origin::signed(ALICE)));
+		System::assert_last_event(ReferralEvent::NativeClaimed { referrer: ALICE, amount: 5 }.into());
+		assert_eq!(Balances::free_balance(ALICE), 105);
+		assert_noop!(
+			ReferralRewards::claim_native(Origin::signed(ALICE)),
+			Error::<Test>::NothingToClaim
+		);
+	})
+}
+
+#[test]
+fn claims_are_feeless_once_per_interval() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ReferralRewards::set_rate(
+			Origin::root(),
+			FeeKind::Marketplace,
+			Perbill::from_percent(10)
+		));
+		assert_ok!(ReferralRewards::register_code(Origin::signed(ALICE), code(b"alice")));
+		assert_ok!(ReferralRewards::bind(Origin::signed(BOB), code(b"alice")));
+		let claim = || {
+			assert_ok!(charge_fee(FeeKind::Marketplace, BOB, 100));
+			ReferralRewards::claim(Origin::signed(ALICE), ASSET_ID).unwrap().pays_fee
+		};
+
+		assert_eq!(claim(), Pays::No);
+		System::set_block_number(10);
+		assert_eq!(claim(), Pays::Yes);
+		System::set_block_number(11);
+		assert_eq!(claim(), Pays::No);
+		assert_eq!(ReferralRewards::last_feeless_claim(ALICE), Some(11));
+	})
+}
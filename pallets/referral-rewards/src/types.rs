@@ -0,0 +1,8 @@
+use crate::Config;
+use frame_support::{traits::tokens::fungibles::Inspect, BoundedVec};
+pub use rusty_node_support::FeeKind;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type CodeOf<T> = BoundedVec<u8, <T as Config>::MaxCodeLength>;
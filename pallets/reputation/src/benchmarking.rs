@@ -0,0 +1,29 @@
+//! Benchmarks of the reputation pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+
+const POINTS: ReputationPoints = 100;
+
+benchmarks! {
+	award {
+		let origin = T::AuthorityOrigin::successful_origin();
+		let who: T::AccountId = account("who", 0, 0);
+	}: _<T::Origin>(origin, who.clone(), POINTS)
+	verify {
+		assert_eq!(Pallet::<T>::reputation_of(&who), POINTS);
+	}
+
+	// The reputation of `who` remains, so that its entry is updated rather than removed.
+	slash {
+		let origin = T::AuthorityOrigin::successful_origin();
+		let who: T::AccountId = account("who", 0, 0);
+		Reputation::<T>::insert(&who, POINTS * 2);
+	}: _<T::Origin>(origin, who.clone(), POINTS)
+	verify {
+		assert_eq!(Pallet::<T>::reputation_of(&who), POINTS);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
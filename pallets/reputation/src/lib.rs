@@ -0,0 +1,163 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Non-transferable reputation points.
+///
+/// Reputation can only be awarded or slashed by the authority origin: accounts have no way to
+/// move it around. Other pallets read it through the [`ReputationProvider`] trait, e.g. to limit
+/// how many listings an account can open or to weight governance votes.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// The reputation of an account.
+pub type ReputationPoints = u64;
+
+/// Read access to the reputation of accounts, for other pallets.
+pub trait ReputationProvider<AccountId> {
+	/// The current reputation of `who`.
+	fn reputation(who: &AccountId) -> ReputationPoints;
+
+	/// Whether `who` has at least `threshold` reputation.
+	fn has_reputation(who: &AccountId, threshold: ReputationPoints) -> bool {
+		Self::reputation(who) >= threshold
+	}
+}
+
+/// No reputation system: everybody has zero reputation.
+impl<AccountId> ReputationProvider<AccountId> for () {
+	fn reputation(_who: &AccountId) -> ReputationPoints {
+		0
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{ReputationPoints, ReputationProvider};
+	use crate::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to award and slash reputation.
+		type AuthorityOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The reputation of each account.
+	#[pallet::storage]
+	#[pallet::getter(fn reputation_of)]
+	pub type Reputation<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ReputationPoints, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Reputation has been awarded to an account.
+		Awarded { who: T::AccountId, amount: ReputationPoints, reputation: ReputationPoints },
+		/// Reputation has been slashed from an account.
+		Slashed { who: T::AccountId, amount: ReputationPoints, reputation: ReputationPoints },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The amount must be non zero.
+		ZeroAmount,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Award `amount` reputation to `who`.
+		#[pallet::weight(T::WeightInfo::award())]
+		pub fn award(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			amount: ReputationPoints,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+			let reputation = Reputation::<T>::mutate(&who, |reputation| {
+				*reputation = reputation.saturating_add(amount);
+				*reputation
+			});
+
+			Self::deposit_event(Event::Awarded { who, amount, reputation });
+
+			Ok(())
+		}
+
+		/// Slash `amount` reputation from `who`, down to zero.
+		#[pallet::weight(T::WeightInfo::slash())]
+		pub fn slash(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			amount: ReputationPoints,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+			let reputation = Reputation::<T>::mutate_exists(&who, |maybe_reputation| {
+				let reputation = maybe_reputation.unwrap_or_default().saturating_sub(amount);
+				*maybe_reputation = if reputation == 0 { None } else { Some(reputation) };
+				reputation
+			});
+
+			Self::deposit_event(Event::Slashed { who, amount, reputation });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> ReputationProvider<T::AccountId> for Pallet<T> {
+		fn reputation(who: &T::AccountId) -> ReputationPoints {
+			Reputation::<T>::get(who)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			ensure!(
+				Reputation::<T>::iter_values().all(|reputation| reputation > 0),
+				"zero reputation stored"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,59 @@
+use super::mock::*;
+use crate::{Error, Event as ReputationEvent, ReputationProvider};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn authority_awards_reputation() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Reputation::award(Origin::signed(AUTHORITY), ALICE, 10));
+		assert_ok!(Reputation::award(Origin::signed(AUTHORITY), ALICE, 5));
+
+		assert_eq!(Reputation::reputation_of(ALICE), 15);
+		System::assert_last_event(
+			ReputationEvent::Awarded { who: ALICE, amount: 5, reputation: 15 }.into(),
+		);
+	})
+}
+
+#[test]
+fn only_authority_can_adjust_reputation() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Reputation::award(Origin::signed(ALICE), ALICE, 10), DispatchError::BadOrigin);
+		assert_noop!(Reputation::slash(Origin::signed(ALICE), ALICE, 10), DispatchError::BadOrigin);
+	})
+}
+
+#[test]
+fn zero_amounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Reputation::award(Origin::signed(AUTHORITY), ALICE, 0),
+			Error::<Test>::ZeroAmount
+		);
+	})
+}
+
+#[test]
+fn slash_saturates_at_zero() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Reputation::award(Origin::signed(AUTHORITY), ALICE, 10));
+		assert_ok!(Reputation::slash(Origin::signed(AUTHORITY), ALICE, 25));
+
+		assert_eq!(Reputation::reputation_of(ALICE), 0);
+		System::assert_last_event(
+			ReputationEvent::Slashed { who: ALICE, amount: 25, reputation: 0 }.into(),
+		);
+	})
+}
+
+#[test]
+fn provider_exposes_reputation() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Reputation::award(Origin::signed(AUTHORITY), ALICE, 10));
+
+		assert_eq!(<Reputation as ReputationProvider<u64>>::reputation(&ALICE), 10);
+		assert!(<Reputation as ReputationProvider<u64>>::has_reputation(&ALICE, 10));
+		assert!(!<Reputation as ReputationProvider<u64>>::has_reputation(&ALICE, 11));
+	})
+}
@@ -0,0 +1,58 @@
+//! Benchmarks of the royalty-registry pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{tokens::nonfungibles::Create, Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::Perbill;
+
+const CLASS_ID: u32 = 1_000;
+
+/// Create the class of the benchmarks, owned by `owner`.
+fn create_class<T: Config>(owner: &T::AccountId)
+where
+	T: pallet_balances::Config,
+	T::Items: Create<T::AccountId>,
+	T::ClassId: From<u32>,
+{
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		owner,
+		T::ExistentialDeposit::get() * 1_000_000u32.into(),
+	);
+	T::Items::create_class(&CLASS_ID.into(), owner, owner).unwrap();
+}
+
+benchmarks! {
+	where_clause { where
+		T: pallet_balances::Config,
+		T::Items: Create<T::AccountId>,
+		T::ClassId: From<u32>,
+	}
+
+	set_royalty {
+		let owner: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		create_class::<T>(&owner);
+		let rate = T::MaxRoyalty::get();
+	}: _(RawOrigin::Signed(owner), CLASS_ID.into(), beneficiary, rate)
+	verify {
+		assert!(Pallet::<T>::royalties(T::ClassId::from(CLASS_ID)).is_some());
+	}
+
+	remove_royalty {
+		let owner: T::AccountId = whitelisted_caller();
+		create_class::<T>(&owner);
+		Pallet::<T>::set_royalty(
+			RawOrigin::Signed(owner.clone()).into(),
+			CLASS_ID.into(),
+			owner.clone(),
+			Perbill::from_percent(1),
+		)
+		.unwrap();
+	}: _(RawOrigin::Signed(owner), CLASS_ID.into())
+	verify {
+		assert!(Pallet::<T>::royalties(T::ClassId::from(CLASS_ID)).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,174 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A registry of the royalties owed on item sales.
+///
+/// The owner of an item class registers the beneficiary and the rate of the royalty owed on every
+/// sale of its items. Trading venues do not store royalties themselves: they take a
+/// [`RoyaltyPolicy`] in their configuration, which this pallet implements, and pay what it
+/// computes out of each sale price.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use sp_runtime::traits::AtLeast32BitUnsigned;
+
+/// The royalty owed on the sale of an item.
+pub trait RoyaltyPolicy<AccountId, ClassId, Balance> {
+	/// The beneficiary and amount of the royalty owed on a sale of an item of `class` for `price`.
+	fn royalty(class: &ClassId, price: Balance) -> Option<(AccountId, Balance)>;
+}
+
+/// No royalty is ever owed.
+impl<AccountId, ClassId, Balance> RoyaltyPolicy<AccountId, ClassId, Balance> for () {
+	fn royalty(_class: &ClassId, _price: Balance) -> Option<(AccountId, Balance)> {
+		None
+	}
+}
+
+impl<T: Config, Balance: AtLeast32BitUnsigned> RoyaltyPolicy<T::AccountId, T::ClassId, Balance>
+	for Pallet<T>
+{
+	fn royalty(class: &T::ClassId, price: Balance) -> Option<(T::AccountId, Balance)> {
+		Self::royalties(class).map(|royalty| (royalty.beneficiary, royalty.rate.mul_floor(price)))
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::nonfungibles};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::Perbill;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Identifier of an item class.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The nonfungible items royalties are registered for.
+		type Items: nonfungibles::Inspect<Self::AccountId, ClassId = Self::ClassId>;
+
+		/// The highest royalty rate a class owner can register.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Perbill>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The royalty of each class.
+	#[pallet::storage]
+	#[pallet::getter(fn royalties)]
+	pub type Royalties<T: Config> = StorageMap<_, Blake2_128Concat, T::ClassId, RoyaltyOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The royalty of a class has been set.
+		RoyaltySet { class: T::ClassId, beneficiary: T::AccountId, rate: Perbill },
+		/// The royalty of a class has been removed.
+		RoyaltyRemoved { class: T::ClassId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The class does not exist.
+		UnknownClass,
+		/// The caller does not own the class.
+		NotClassOwner,
+		/// The rate exceeds the highest royalty rate.
+		RateTooHigh,
+		/// The class has no royalty.
+		NoRoyalty,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the royalty owed to `beneficiary` on sales of items of `class`.
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			beneficiary: T::AccountId,
+			rate: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_class_owner(&class, &who)?;
+			ensure!(rate <= T::MaxRoyalty::get(), Error::<T>::RateTooHigh);
+
+			Royalties::<T>::insert(&class, Royalty { beneficiary: beneficiary.clone(), rate });
+
+			Self::deposit_event(Event::RoyaltySet { class, beneficiary, rate });
+
+			Ok(())
+		}
+
+		/// Remove the royalty of `class`.
+		#[pallet::weight(T::WeightInfo::remove_royalty())]
+		pub fn remove_royalty(origin: OriginFor<T>, class: T::ClassId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_class_owner(&class, &who)?;
+			ensure!(Royalties::<T>::contains_key(&class), Error::<T>::NoRoyalty);
+			Royalties::<T>::remove(&class);
+
+			Self::deposit_event(Event::RoyaltyRemoved { class });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn ensure_class_owner(class: &T::ClassId, who: &T::AccountId) -> DispatchResult {
+			let owner = <T::Items as nonfungibles::Inspect<T::AccountId>>::class_owner(class)
+				.ok_or(Error::<T>::UnknownClass)?;
+			ensure!(owner == *who, Error::<T>::NotClassOwner);
+			Ok(())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			ensure!(
+				Royalties::<T>::iter_values().all(|royalty| royalty.rate <= T::MaxRoyalty::get()),
+				"royalty above the maximum"
+			);
+
+			Ok(())
+		}
+	}
+}
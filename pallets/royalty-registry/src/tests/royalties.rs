@@ -0,0 +1,78 @@
+use super::mock::*;
+use crate::{Error, Event as RoyaltyEvent, RoyaltyPolicy};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Perbill;
+
+fn royalty(price: u64) -> Option<(u64, u64)> {
+	<RoyaltyRegistry as RoyaltyPolicy<u64, u32, u64>>::royalty(&CLASS_ID, price)
+}
+
+#[test]
+fn class_owners_set_royalties() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RoyaltyRegistry::set_royalty(Origin::signed(ALICE), 1, BOB, Perbill::from_percent(5)),
+			Error::<Test>::UnknownClass
+		);
+		assert_noop!(
+			RoyaltyRegistry::set_royalty(
+				Origin::signed(BOB),
+				CLASS_ID,
+				BOB,
+				Perbill::from_percent(5)
+			),
+			Error::<Test>::NotClassOwner
+		);
+		assert_noop!(
+			RoyaltyRegistry::set_royalty(
+				Origin::signed(ALICE),
+				CLASS_ID,
+				BOB,
+				Perbill::from_percent(25)
+			),
+			Error::<Test>::RateTooHigh
+		);
+		assert_ok!(RoyaltyRegistry::set_royalty(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			BOB,
+			Perbill::from_percent(5)
+		));
+		System::assert_last_event(
+			RoyaltyEvent::RoyaltySet {
+				class: CLASS_ID,
+				beneficiary: BOB,
+				rate: Perbill::from_percent(5),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			RoyaltyRegistry::remove_royalty(Origin::signed(BOB), CLASS_ID),
+			Error::<Test>::NotClassOwner
+		);
+		assert_ok!(RoyaltyRegistry::remove_royalty(Origin::signed(ALICE), CLASS_ID));
+		assert_noop!(
+			RoyaltyRegistry::remove_royalty(Origin::signed(ALICE), CLASS_ID),
+			Error::<Test>::NoRoyalty
+		);
+	})
+}
+
+#[test]
+fn policy_computes_the_royalty_of_a_sale() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(royalty(1_000), None);
+
+		assert_ok!(RoyaltyRegistry::set_royalty(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			BOB,
+			Perbill::from_percent(5)
+		));
+		assert_eq!(royalty(1_000), Some((BOB, 50)));
+		// Royalties are rounded down.
+		assert_eq!(royalty(39), Some((BOB, 1)));
+		assert_eq!(<() as RoyaltyPolicy<u64, u32, u64>>::royalty(&CLASS_ID, 1_000), None);
+	})
+}
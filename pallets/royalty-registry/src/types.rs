@@ -0,0 +1,15 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::Perbill;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type RoyaltyOf<T> = Royalty<AccountIdOf<T>>;
+
+/// The royalty owed on every sale of an item of a class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Royalty<AccountId> {
+	/// The account royalties are paid to.
+	pub beneficiary: AccountId,
+	/// The share of the sale price paid as royalty.
+	pub rate: Perbill,
+}
@@ -0,0 +1,180 @@
+//! Benchmarks of the social-recovery pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	traits::{
+		tokens::{fungibles, nonfungibles},
+		Currency, Get,
+	},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{One, Zero};
+use sp_std::prelude::*;
+
+const ASSET_ID: u32 = 1_000;
+const CLASS_ID: u32 = 1_000;
+const INSTANCE_ID: u32 = 0;
+const AMOUNT: u32 = 1_000_000;
+
+/// `g` guardians, sorted.
+fn guardians<T: Config>(g: u32) -> BoundedVec<T::AccountId, T::MaxGuardians> {
+	let mut guardians: Vec<T::AccountId> = (0..g).map(|i| account("guardian", i, 0)).collect();
+	guardians.sort();
+	guardians.try_into().unwrap()
+}
+
+/// Fund `who` for the recovery deposit.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, T::RecoveryDeposit::get() * 10u32.into());
+}
+
+/// Have `lost` configure its recovery by `g` guardians, all of them needed, without delay.
+fn configure<T: Config>(lost: &T::AccountId, g: u32) {
+	Pallet::<T>::configure_recovery(
+		RawOrigin::Signed(lost.clone()).into(),
+		guardians::<T>(g),
+		g,
+		Zero::zero(),
+	)
+	.unwrap();
+}
+
+/// Start the recovery of `lost` by `rescuer`, already vouched for by `vouches`.
+fn initiate<T: Config>(lost: &T::AccountId, rescuer: &T::AccountId, vouches: &[T::AccountId]) {
+	fund::<T>(rescuer);
+	Pallet::<T>::initiate_recovery(RawOrigin::Signed(rescuer.clone()).into(), lost.clone())
+		.unwrap();
+	ActiveRecoveries::<T>::mutate(lost, rescuer, |recovery| {
+		recovery.as_mut().unwrap().vouches = vouches.to_vec().try_into().unwrap();
+	});
+}
+
+/// Set `rescuer` as the rescuer of `lost`.
+fn recover<T: Config>(lost: &T::AccountId, rescuer: &T::AccountId) {
+	configure::<T>(lost, 1);
+	initiate::<T>(lost, rescuer, &guardians::<T>(1));
+	Pallet::<T>::claim_recovery(RawOrigin::Signed(rescuer.clone()).into(), lost.clone())
+		.unwrap();
+}
+
+benchmarks! {
+	where_clause { where
+		T: pallet_balances::Config,
+		T::Assets: fungibles::Create<T::AccountId> + fungibles::Mutate<T::AccountId>,
+		T::Items: nonfungibles::Create<T::AccountId> + nonfungibles::Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+		T::ClassId: From<u32>,
+		T::InstanceId: From<u32>,
+	}
+
+	configure_recovery {
+		let g in 1 .. T::MaxGuardians::get();
+		let who: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(who.clone()), guardians::<T>(g), g, One::one())
+	verify {
+		assert!(Pallet::<T>::recovery_config(&who).is_some());
+	}
+
+	remove_recovery {
+		let who: T::AccountId = whitelisted_caller();
+		configure::<T>(&who, T::MaxGuardians::get());
+	}: _(RawOrigin::Signed(who.clone()))
+	verify {
+		assert!(Pallet::<T>::recovery_config(&who).is_none());
+	}
+
+	initiate_recovery {
+		let lost: T::AccountId = account("lost", 0, 0);
+		let rescuer: T::AccountId = whitelisted_caller();
+		configure::<T>(&lost, T::MaxGuardians::get());
+		fund::<T>(&rescuer);
+	}: _(RawOrigin::Signed(rescuer.clone()), lost.clone())
+	verify {
+		assert!(Pallet::<T>::active_recovery(&lost, &rescuer).is_some());
+	}
+
+	// The first guardian vouches, after `v` others.
+	vouch {
+		let v in 0 .. T::MaxGuardians::get() - 1;
+		let lost: T::AccountId = account("lost", 0, 0);
+		let rescuer: T::AccountId = account("rescuer", 0, 0);
+		configure::<T>(&lost, T::MaxGuardians::get());
+		let guardians = guardians::<T>(v + 1);
+		initiate::<T>(&lost, &rescuer, &guardians[1..]);
+		let guardian = guardians[0].clone();
+	}: _(RawOrigin::Signed(guardian), lost.clone(), rescuer.clone())
+	verify {
+		let recovery = Pallet::<T>::active_recovery(&lost, &rescuer).unwrap();
+		assert_eq!(recovery.vouches.len() as u32, v + 1);
+	}
+
+	cancel_recovery {
+		let lost: T::AccountId = whitelisted_caller();
+		let rescuer: T::AccountId = account("rescuer", 0, 0);
+		let g = T::MaxGuardians::get();
+		configure::<T>(&lost, g);
+		initiate::<T>(&lost, &rescuer, &guardians::<T>(g));
+	}: _(RawOrigin::Signed(lost.clone()), rescuer.clone())
+	verify {
+		assert!(Pallet::<T>::active_recovery(&lost, &rescuer).is_none());
+	}
+
+	// The rescuer claims, along `r - 1` other recoveries in progress.
+	claim_recovery {
+		let r in 1 .. T::MaxActiveRecoveries::get();
+		let lost: T::AccountId = account("lost", 0, 0);
+		let rescuer: T::AccountId = whitelisted_caller();
+		let g = T::MaxGuardians::get();
+		configure::<T>(&lost, g);
+		initiate::<T>(&lost, &rescuer, &guardians::<T>(g));
+		for i in 1 .. r {
+			initiate::<T>(&lost, &account("rescuer", i, 0), &[]);
+		}
+	}: _(RawOrigin::Signed(rescuer.clone()), lost.clone())
+	verify {
+		assert_eq!(Pallet::<T>::rescuer(&lost), Some(rescuer));
+		assert_eq!(Pallet::<T>::active_recovery_count(&lost), 0);
+	}
+
+	recover_asset {
+		let lost: T::AccountId = account("lost", 0, 0);
+		let rescuer: T::AccountId = whitelisted_caller();
+		recover::<T>(&lost, &rescuer);
+		<T::Assets as fungibles::Create<_>>::create(
+			ASSET_ID.into(),
+			account("owner", 0, 0),
+			true,
+			One::one(),
+		)
+		.unwrap();
+		<T::Assets as fungibles::Mutate<_>>::mint_into(ASSET_ID.into(), &lost, AMOUNT.into())
+			.unwrap();
+	}: _(RawOrigin::Signed(rescuer.clone()), lost.clone(), ASSET_ID.into())
+	verify {
+		let balance =
+			<T::Assets as fungibles::Inspect<_>>::balance(ASSET_ID.into(), &rescuer);
+		assert_eq!(balance, AMOUNT.into());
+	}
+
+	recover_item {
+		let lost: T::AccountId = account("lost", 0, 0);
+		let rescuer: T::AccountId = whitelisted_caller();
+		recover::<T>(&lost, &rescuer);
+		let owner: T::AccountId = account("owner", 0, 0);
+		pallet_balances::Pallet::<T>::make_free_balance_be(
+			&owner,
+			T::ExistentialDeposit::get() * 1_000_000u32.into(),
+		);
+		let (class, instance) = (CLASS_ID.into(), INSTANCE_ID.into());
+		<T::Items as nonfungibles::Create<_>>::create_class(&class, &owner, &owner).unwrap();
+		<T::Items as nonfungibles::Mutate<_>>::mint_into(&class, &instance, &lost).unwrap();
+	}: _(RawOrigin::Signed(rescuer.clone()), lost, class, instance)
+	verify {
+		let owner = <T::Items as nonfungibles::Inspect<_>>::owner(&class, &instance);
+		assert_eq!(owner, Some(rescuer));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,452 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Recover the fungible and nonfungible holdings of a lost account through its guardians.
+///
+/// An account designates a set of guardians, a threshold and a delay. If its keys are lost, a
+/// rescuer account opens a recovery, guardians vouch for it, and once enough guardians vouched and
+/// the delay elapsed the rescuer can close the recovery. From then on the rescuer can move every
+/// asset balance and item of the lost account to itself.
+///
+/// The delay gives the original owner, if its keys were not actually lost, time to cancel a
+/// malicious recovery. Opening a recovery reserves a deposit from the rescuer, slashed if the owner
+/// cancels it, and an account has at most `MaxActiveRecoveries` recoveries in progress, so that
+/// blocking the configuration of an account with recoveries is not free.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			tokens::{fungibles, nonfungibles},
+			Imbalance, ReservableCurrency,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::StorageDeposits;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets that can be recovered.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// Identifier of an item class.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its class.
+		type InstanceId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The nonfungible items that can be recovered.
+		type Items: nonfungibles::Transfer<
+			Self::AccountId,
+			ClassId = Self::ClassId,
+			InstanceId = Self::InstanceId,
+		>;
+
+		/// The maximum number of guardians of an account.
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+
+		/// The native currency, used for recovery deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from a rescuer opening a recovery, slashed if the account it
+		/// targets cancels it.
+		#[pallet::constant]
+		type RecoveryDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// The maximum number of recoveries of an account in progress at once.
+		#[pallet::constant]
+		type MaxActiveRecoveries: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The recovery configuration of each account.
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_config)]
+	pub type RecoveryConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RecoveryConfigOf<T>>;
+
+	/// The recoveries in progress, by lost account and rescuer.
+	#[pallet::storage]
+	#[pallet::getter(fn active_recovery)]
+	pub type ActiveRecoveries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		ActiveRecoveryOf<T>,
+	>;
+
+	/// The number of recoveries in progress of each lost account.
+	#[pallet::storage]
+	#[pallet::getter(fn active_recovery_count)]
+	pub type ActiveRecoveryCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The rescuer allowed to move the holdings of each recovered account.
+	#[pallet::storage]
+	#[pallet::getter(fn rescuer)]
+	pub type Recovered<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account set up its guardians.
+		RecoveryConfigured { who: T::AccountId, threshold: u32, delay: T::BlockNumber },
+		/// An account removed its recovery configuration.
+		RecoveryRemoved { who: T::AccountId },
+		/// A rescuer opened the recovery of a lost account.
+		RecoveryInitiated { lost: T::AccountId, rescuer: T::AccountId },
+		/// A guardian vouched for a recovery.
+		Vouched { lost: T::AccountId, rescuer: T::AccountId, guardian: T::AccountId },
+		/// A recovery has been canceled by the account it targeted, slashing its deposit.
+		RecoveryCanceled {
+			lost: T::AccountId,
+			rescuer: T::AccountId,
+			slashed: DepositBalanceOf<T>,
+		},
+		/// A rescuer gained control over the holdings of a lost account.
+		AccountRecovered { lost: T::AccountId, rescuer: T::AccountId },
+		/// An asset balance has been moved from a lost account to its rescuer.
+		AssetRecovered {
+			lost: T::AccountId,
+			rescuer: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// An item has been moved from a lost account to its rescuer.
+		ItemRecovered {
+			lost: T::AccountId,
+			rescuer: T::AccountId,
+			class: T::ClassId,
+			instance: T::InstanceId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account has no recovery configuration.
+		NotRecoverable,
+		/// The threshold must be between one and the number of guardians.
+		InvalidThreshold,
+		/// The same guardian appears twice.
+		DuplicateGuardian,
+		/// Too many guardians.
+		TooManyGuardians,
+		/// The configuration cannot change while recoveries are in progress.
+		RecoveryInProgress,
+		/// This recovery has already been initiated.
+		AlreadyInitiated,
+		/// This recovery has not been initiated.
+		NotInitiated,
+		/// The caller is not a guardian of the lost account.
+		NotGuardian,
+		/// The guardian already vouched for this recovery.
+		AlreadyVouched,
+		/// Not enough guardians vouched for this recovery.
+		ThresholdNotReached,
+		/// The recovery delay has not elapsed yet.
+		DelayNotElapsed,
+		/// The account has already been recovered.
+		AlreadyRecovered,
+		/// The caller is not the rescuer of the lost account.
+		NotRescuer,
+		/// The lost account does not own this item.
+		NotOwner,
+		/// The account already has the maximum number of recoveries in progress.
+		TooManyRecoveries,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Designate the guardians able to recover the caller's holdings.
+		#[pallet::weight(T::WeightInfo::configure_recovery(guardians.len() as u32))]
+		pub fn configure_recovery(
+			origin: OriginFor<T>,
+			guardians: BoundedVec<T::AccountId, T::MaxGuardians>,
+			threshold: u32,
+			delay: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::active_recovery_count(&who) == 0, Error::<T>::RecoveryInProgress);
+			ensure!(
+				threshold > 0 && threshold as usize <= guardians.len(),
+				Error::<T>::InvalidThreshold
+			);
+
+			let mut sorted = guardians.into_inner();
+			sorted.sort();
+			let count = sorted.len();
+			sorted.dedup();
+			ensure!(sorted.len() == count, Error::<T>::DuplicateGuardian);
+			let guardians: BoundedVec<_, _> =
+				sorted.try_into().map_err(|_| Error::<T>::TooManyGuardians)?;
+
+			RecoveryConfigs::<T>::insert(&who, RecoveryConfig { guardians, threshold, delay });
+
+			Self::deposit_event(Event::RecoveryConfigured { who, threshold, delay });
+
+			Ok(())
+		}
+
+		/// Remove the caller's recovery configuration.
+		#[pallet::weight(T::WeightInfo::remove_recovery())]
+		pub fn remove_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(RecoveryConfigs::<T>::contains_key(&who), Error::<T>::NotRecoverable);
+			ensure!(Self::active_recovery_count(&who) == 0, Error::<T>::RecoveryInProgress);
+
+			RecoveryConfigs::<T>::remove(&who);
+
+			Self::deposit_event(Event::RecoveryRemoved { who });
+
+			Ok(())
+		}
+
+		/// Open the recovery of `lost`, with the caller as rescuer, reserving the
+		/// `RecoveryDeposit` from them.
+		#[pallet::weight(T::WeightInfo::initiate_recovery())]
+		pub fn initiate_recovery(origin: OriginFor<T>, lost: T::AccountId) -> DispatchResult {
+			let rescuer = ensure_signed(origin)?;
+
+			ensure!(RecoveryConfigs::<T>::contains_key(&lost), Error::<T>::NotRecoverable);
+			ensure!(!Recovered::<T>::contains_key(&lost), Error::<T>::AlreadyRecovered);
+			ensure!(
+				!ActiveRecoveries::<T>::contains_key(&lost, &rescuer),
+				Error::<T>::AlreadyInitiated
+			);
+			let count = Self::active_recovery_count(&lost);
+			ensure!(count < T::MaxActiveRecoveries::get(), Error::<T>::TooManyRecoveries);
+
+			let deposit = T::Currency::reserve_deposit(&rescuer, T::RecoveryDeposit::get())?;
+			ActiveRecoveries::<T>::insert(
+				&lost,
+				&rescuer,
+				ActiveRecovery {
+					started: frame_system::Pallet::<T>::block_number(),
+					vouches: Default::default(),
+					deposit,
+				},
+			);
+			ActiveRecoveryCount::<T>::insert(&lost, count + 1);
+
+			Self::deposit_event(Event::RecoveryInitiated { lost, rescuer });
+
+			Ok(())
+		}
+
+		/// Vouch, as a guardian of `lost`, for the recovery opened by `rescuer`.
+		#[pallet::weight(T::WeightInfo::vouch(T::MaxGuardians::get()))]
+		pub fn vouch(
+			origin: OriginFor<T>,
+			lost: T::AccountId,
+			rescuer: T::AccountId,
+		) -> DispatchResult {
+			let guardian = ensure_signed(origin)?;
+
+			let config = Self::recovery_config(&lost).ok_or(Error::<T>::NotRecoverable)?;
+			ensure!(config.guardians.binary_search(&guardian).is_ok(), Error::<T>::NotGuardian);
+
+			ActiveRecoveries::<T>::try_mutate(&lost, &rescuer, |maybe_recovery| -> DispatchResult {
+				let recovery = maybe_recovery.as_mut().ok_or(Error::<T>::NotInitiated)?;
+				match recovery.vouches.binary_search(&guardian) {
+					Ok(_) => Err(Error::<T>::AlreadyVouched.into()),
+					Err(pos) => recovery
+						.vouches
+						.try_insert(pos, guardian.clone())
+						.map_err(|_| Error::<T>::TooManyGuardians.into()),
+				}
+			})?;
+
+			Self::deposit_event(Event::Vouched { lost, rescuer, guardian });
+
+			Ok(())
+		}
+
+		/// Cancel a recovery targeting the caller, slashing the deposit of its rescuer.
+		#[pallet::weight(T::WeightInfo::cancel_recovery())]
+		pub fn cancel_recovery(origin: OriginFor<T>, rescuer: T::AccountId) -> DispatchResult {
+			let lost = ensure_signed(origin)?;
+
+			let recovery =
+				ActiveRecoveries::<T>::take(&lost, &rescuer).ok_or(Error::<T>::NotInitiated)?;
+			ActiveRecoveryCount::<T>::mutate(&lost, |count| *count = count.saturating_sub(1));
+			let slashed = T::Currency::slash_deposit(&rescuer, recovery.deposit);
+
+			Self::deposit_event(Event::RecoveryCanceled {
+				lost,
+				rescuer,
+				slashed: slashed.peek(),
+			});
+
+			Ok(())
+		}
+
+		/// Close a recovery that gathered enough vouches, once its delay elapsed.
+		///
+		/// The caller becomes the rescuer of `lost` and every other pending recovery is dropped,
+		/// refunding the deposits of all of them. Refunds the weight of the recoveries the account
+		/// was assumed to have in progress.
+		#[pallet::weight(T::WeightInfo::claim_recovery(T::MaxActiveRecoveries::get()))]
+		pub fn claim_recovery(
+			origin: OriginFor<T>,
+			lost: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let rescuer = ensure_signed(origin)?;
+
+			let config = Self::recovery_config(&lost).ok_or(Error::<T>::NotRecoverable)?;
+			let recovery =
+				Self::active_recovery(&lost, &rescuer).ok_or(Error::<T>::NotInitiated)?;
+			ensure!(
+				recovery.vouches.len() as u32 >= config.threshold,
+				Error::<T>::ThresholdNotReached
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >=
+					recovery.started.saturating_add(config.delay),
+				Error::<T>::DelayNotElapsed
+			);
+
+			let mut removed = 0;
+			for (who, recovery) in ActiveRecoveries::<T>::drain_prefix(&lost) {
+				T::Currency::refund_deposit(&who, recovery.deposit);
+				removed += 1;
+			}
+			ActiveRecoveryCount::<T>::remove(&lost);
+			Recovered::<T>::insert(&lost, &rescuer);
+
+			Self::deposit_event(Event::AccountRecovered { lost, rescuer });
+
+			Ok(Some(T::WeightInfo::claim_recovery(removed)).into())
+		}
+
+		/// Move the whole `asset_id` balance of a recovered account to its rescuer.
+		#[pallet::weight(T::WeightInfo::recover_asset())]
+		pub fn recover_asset(
+			origin: OriginFor<T>,
+			lost: T::AccountId,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let rescuer = Self::ensure_rescuer(origin, &lost)?;
+
+			let balance =
+				<T::Assets as fungibles::Inspect<T::AccountId>>::balance(asset_id, &lost);
+			let amount = if balance.is_zero() {
+				balance
+			} else {
+				T::Assets::transfer(asset_id, &lost, &rescuer, balance, false)?
+			};
+
+			Self::deposit_event(Event::AssetRecovered { lost, rescuer, asset_id, amount });
+
+			Ok(())
+		}
+
+		/// Move an item owned by a recovered account to its rescuer.
+		#[pallet::weight(T::WeightInfo::recover_item())]
+		pub fn recover_item(
+			origin: OriginFor<T>,
+			lost: T::AccountId,
+			class: T::ClassId,
+			instance: T::InstanceId,
+		) -> DispatchResult {
+			let rescuer = Self::ensure_rescuer(origin, &lost)?;
+
+			ensure!(
+				<T::Items as nonfungibles::Inspect<T::AccountId>>::owner(&class, &instance) ==
+					Some(lost.clone()),
+				Error::<T>::NotOwner
+			);
+			T::Items::transfer(&class, &instance, &rescuer)?;
+
+			Self::deposit_event(Event::ItemRecovered { lost, rescuer, class, instance });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn ensure_rescuer(
+			origin: OriginFor<T>,
+			lost: &T::AccountId,
+		) -> Result<T::AccountId, DispatchError> {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::rescuer(lost) == Some(who.clone()), Error::<T>::NotRescuer);
+			Ok(who)
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			for config in RecoveryConfigs::<T>::iter_values() {
+				let guardians = &config.guardians;
+				ensure!(guardians.windows(2).all(|pair| pair[0] < pair[1]), "guardians not sorted");
+				ensure!(
+					config.threshold > 0 && config.threshold as usize <= guardians.len(),
+					"threshold out of the guardians"
+				);
+			}
+			for (lost, _, recovery) in ActiveRecoveries::<T>::iter() {
+				let config =
+					Self::recovery_config(&lost).ok_or("recovery of an unrecoverable account")?;
+				ensure!(!Recovered::<T>::contains_key(&lost), "recovery of a recovered account");
+				ensure!(
+					recovery.vouches.iter().all(|who| config.guardians.binary_search(who).is_ok()),
+					"vouch of a non-guardian"
+				);
+			}
+			for (lost, count) in ActiveRecoveryCount::<T>::iter() {
+				ensure!(
+					ActiveRecoveries::<T>::iter_prefix(&lost).count() as u32 == count,
+					"recovery count out of sync"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
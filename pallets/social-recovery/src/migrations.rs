@@ -0,0 +1,67 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 records the deposit of each recovery in progress, and counts the recoveries of each
+/// lost account.
+///
+/// The recoveries opened before version 2 reserved no deposit, so they record none.
+pub mod v2 {
+	use super::*;
+	use crate::{AccountIdOf, ActiveRecoveries, ActiveRecovery, ActiveRecoveryCount};
+	use frame_support::BoundedVec;
+	use sp_runtime::traits::Zero;
+
+	/// A recovery in progress as version 1 stored it, with its start and vouches.
+	type OldActiveRecovery<T> = (
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<AccountIdOf<T>, <T as Config>::MaxGuardians>,
+	);
+
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = 0;
+		ActiveRecoveries::<T>::translate::<OldActiveRecovery<T>, _>(
+			|lost, _rescuer, (started, vouches)| {
+				ActiveRecoveryCount::<T>::mutate(&lost, |count| *count += 1);
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+				Some(ActiveRecovery { started, vouches, deposit: Zero::zero() })
+			},
+		);
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		weight.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,46 @@
+use super::mock::*;
+use crate::migrations;
+use frame_support::{
+	assert_ok,
+	storage::migration::put_storage_value,
+	traits::{GetStorageVersion, StorageVersion},
+	Hashable,
+};
+
+#[test]
+fn migrate_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<SocialRecovery>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(
+			SocialRecovery::on_chain_storage_version(),
+			SocialRecovery::current_storage_version()
+		);
+	})
+}
+
+#[test]
+fn migrate_records_the_recovery_deposits_and_counts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SocialRecovery::configure_recovery(
+			Origin::signed(ALICE),
+			vec![CHARLIE].try_into().unwrap(),
+			1,
+			10
+		));
+		// Version 1 kept the start and the vouches of a recovery, and no count.
+		let key = [ALICE.blake2_128_concat(), BOB.blake2_128_concat()].concat();
+		put_storage_value(b"SocialRecovery", b"ActiveRecoveries", &key, (5u64, vec![CHARLIE]));
+		StorageVersion::new(1).put::<SocialRecovery>();
+
+		migrations::migrate::<Test>();
+
+		let recovery = SocialRecovery::active_recovery(ALICE, BOB).unwrap();
+		assert_eq!((recovery.started, recovery.deposit), (5, 0));
+		assert_eq!(recovery.vouches.into_inner(), vec![CHARLIE]);
+		assert_eq!(SocialRecovery::active_recovery_count(ALICE), 1);
+		assert_eq!(SocialRecovery::on_chain_storage_version(), 2);
+	})
+}
@@ -0,0 +1,196 @@
+use super::mock::*;
+use crate::{Error, Event as RecoveryEvent, WeightInfo};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+fn guardians(accounts: Vec<u64>) -> BoundedVec<u64, MaxGuardians> {
+	accounts.try_into().unwrap()
+}
+
+fn configure_alice() {
+	assert_ok!(SocialRecovery::configure_recovery(
+		Origin::signed(ALICE),
+		guardians(vec![DAVE, CHARLIE, EVE]),
+		2,
+		10
+	));
+}
+
+fn recover_alice_to_bob() {
+	configure_alice();
+	assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(BOB), ALICE));
+	assert_ok!(SocialRecovery::vouch(Origin::signed(CHARLIE), ALICE, BOB));
+	assert_ok!(SocialRecovery::vouch(Origin::signed(DAVE), ALICE, BOB));
+	System::set_block_number(11);
+	assert_ok!(SocialRecovery::claim_recovery(Origin::signed(BOB), ALICE));
+}
+
+#[test]
+fn configure_recovery_sorts_guardians() {
+	new_test_ext().execute_with(|| {
+		configure_alice();
+
+		let config = SocialRecovery::recovery_config(ALICE).unwrap();
+		assert_eq!(config.guardians.into_inner(), vec![CHARLIE, DAVE, EVE]);
+	})
+}
+
+#[test]
+fn configure_recovery_checks_guardians() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SocialRecovery::configure_recovery(
+				Origin::signed(ALICE),
+				guardians(vec![CHARLIE]),
+				2,
+				10
+			),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			SocialRecovery::configure_recovery(
+				Origin::signed(ALICE),
+				guardians(vec![CHARLIE, CHARLIE]),
+				1,
+				10
+			),
+			Error::<Test>::DuplicateGuardian
+		);
+		// Too many guardians do not even make a call.
+		assert!(BoundedVec::<u64, MaxGuardians>::try_from(vec![BOB, CHARLIE, DAVE, EVE]).is_err());
+	})
+}
+
+#[test]
+fn only_guardians_can_vouch_once() {
+	new_test_ext().execute_with(|| {
+		configure_alice();
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(BOB), ALICE));
+
+		assert_noop!(
+			SocialRecovery::vouch(Origin::signed(BOB), ALICE, BOB),
+			Error::<Test>::NotGuardian
+		);
+		assert_ok!(SocialRecovery::vouch(Origin::signed(CHARLIE), ALICE, BOB));
+		assert_noop!(
+			SocialRecovery::vouch(Origin::signed(CHARLIE), ALICE, BOB),
+			Error::<Test>::AlreadyVouched
+		);
+	})
+}
+
+#[test]
+fn claim_requires_threshold_and_delay() {
+	new_test_ext().execute_with(|| {
+		configure_alice();
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(BOB), ALICE));
+		assert_ok!(SocialRecovery::vouch(Origin::signed(CHARLIE), ALICE, BOB));
+
+		assert_noop!(
+			SocialRecovery::claim_recovery(Origin::signed(BOB), ALICE),
+			Error::<Test>::ThresholdNotReached
+		);
+
+		assert_ok!(SocialRecovery::vouch(Origin::signed(EVE), ALICE, BOB));
+		assert_noop!(
+			SocialRecovery::claim_recovery(Origin::signed(BOB), ALICE),
+			Error::<Test>::DelayNotElapsed
+		);
+
+		System::set_block_number(11);
+		assert_ok!(SocialRecovery::claim_recovery(Origin::signed(BOB), ALICE));
+		assert_eq!(SocialRecovery::rescuer(ALICE), Some(BOB));
+	})
+}
+
+#[test]
+fn owner_can_cancel_a_recovery() {
+	new_test_ext().execute_with(|| {
+		configure_alice();
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(BOB), ALICE));
+
+		assert_ok!(SocialRecovery::cancel_recovery(Origin::signed(ALICE), BOB));
+		assert!(SocialRecovery::active_recovery(ALICE, BOB).is_none());
+	})
+}
+
+#[test]
+fn cancel_slashes_the_deposit_of_the_rescuer() {
+	new_test_ext().execute_with(|| {
+		configure_alice();
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(BOB), ALICE));
+		assert_eq!(Balances::reserved_balance(BOB), 10);
+
+		assert_ok!(SocialRecovery::cancel_recovery(Origin::signed(ALICE), BOB));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 90);
+		System::assert_last_event(
+			RecoveryEvent::RecoveryCanceled { lost: ALICE, rescuer: BOB, slashed: 10 }.into(),
+		);
+		assert_ok!(SocialRecovery::remove_recovery(Origin::signed(ALICE)));
+	})
+}
+
+#[test]
+fn recoveries_in_progress_are_bounded() {
+	new_test_ext().execute_with(|| {
+		configure_alice();
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(BOB), ALICE));
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(CHARLIE), ALICE));
+
+		assert_noop!(
+			SocialRecovery::initiate_recovery(Origin::signed(DAVE), ALICE),
+			Error::<Test>::TooManyRecoveries
+		);
+		assert_noop!(
+			SocialRecovery::remove_recovery(Origin::signed(ALICE)),
+			Error::<Test>::RecoveryInProgress
+		);
+	})
+}
+
+#[test]
+fn claim_refunds_the_deposits_of_every_recovery() {
+	new_test_ext().execute_with(|| {
+		configure_alice();
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(BOB), ALICE));
+		assert_ok!(SocialRecovery::initiate_recovery(Origin::signed(DAVE), ALICE));
+		assert_ok!(SocialRecovery::vouch(Origin::signed(CHARLIE), ALICE, BOB));
+		assert_ok!(SocialRecovery::vouch(Origin::signed(EVE), ALICE, BOB));
+		System::set_block_number(11);
+
+		let post_info = SocialRecovery::claim_recovery(Origin::signed(BOB), ALICE).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::claim_recovery(2)));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(DAVE), 0);
+		assert_eq!(SocialRecovery::active_recovery_count(ALICE), 0);
+		assert!(SocialRecovery::active_recovery(ALICE, DAVE).is_none());
+	})
+}
+
+#[test]
+fn rescuer_moves_holdings() {
+	new_test_ext().execute_with(|| {
+		recover_alice_to_bob();
+
+		assert_noop!(
+			SocialRecovery::recover_asset(Origin::signed(CHARLIE), ALICE, ASSET_ID),
+			Error::<Test>::NotRescuer
+		);
+
+		assert_ok!(SocialRecovery::recover_asset(Origin::signed(BOB), ALICE, ASSET_ID));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 1_000);
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 0);
+
+		assert_ok!(SocialRecovery::recover_item(Origin::signed(BOB), ALICE, CLASS_ID, INSTANCE_ID));
+		assert_eq!(Uniques::owner(CLASS_ID, INSTANCE_ID), Some(BOB));
+		System::assert_last_event(
+			RecoveryEvent::ItemRecovered {
+				lost: ALICE,
+				rescuer: BOB,
+				class: CLASS_ID,
+				instance: INSTANCE_ID,
+			}
+			.into(),
+		);
+	})
+}
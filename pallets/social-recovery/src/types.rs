@@ -0,0 +1,78 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{tokens::fungibles::Inspect, Currency, Get},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type DepositBalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type RecoveryConfigOf<T> = RecoveryConfig<
+	AccountIdOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxGuardians,
+>;
+pub type ActiveRecoveryOf<T> = ActiveRecovery<
+	AccountIdOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	DepositBalanceOf<T>,
+	<T as Config>::MaxGuardians,
+>;
+
+/// The guardians of an account and how many of them are needed to recover it.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxGuardians))]
+pub struct RecoveryConfig<AccountId, BlockNumber, MaxGuardians>
+where
+	AccountId: Clone + Eq + core::fmt::Debug,
+	BlockNumber: Clone + Eq + core::fmt::Debug,
+	MaxGuardians: Get<u32>,
+{
+	/// The guardians, sorted.
+	pub guardians: BoundedVec<AccountId, MaxGuardians>,
+	/// The number of vouches needed to recover the account.
+	pub threshold: u32,
+	/// The number of blocks between the opening of a recovery and its completion.
+	pub delay: BlockNumber,
+}
+
+/// A recovery in progress.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen, Balance: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxGuardians))]
+pub struct ActiveRecovery<AccountId, BlockNumber, Balance, MaxGuardians>
+where
+	AccountId: Clone + Eq + core::fmt::Debug,
+	BlockNumber: Clone + Eq + core::fmt::Debug,
+	Balance: Clone + Eq + core::fmt::Debug,
+	MaxGuardians: Get<u32>,
+{
+	/// The block the recovery was opened at.
+	pub started: BlockNumber,
+	/// The guardians that vouched for the recovery, sorted.
+	pub vouches: BoundedVec<AccountId, MaxGuardians>,
+	/// The deposit reserved from the rescuer.
+	pub deposit: Balance,
+}
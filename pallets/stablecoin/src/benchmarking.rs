@@ -0,0 +1,120 @@
+//! Benchmarks of the stablecoin pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use crate::migrations::v2::{OldVault, OldVaultOf};
+use frame_support::{
+	storage::unhashed,
+	traits::{
+		tokens::fungibles::{Create, Inspect, Mutate},
+		Get,
+	},
+	weights::Weight,
+};
+use frame_system::RawOrigin;
+use pallet_price_fetch::{Price, PriceOracle, PRICE_DECIMALS};
+use sp_runtime::traits::{One, Zero};
+
+const COLLATERAL: u32 = 1_000_000;
+/// The price of the collateral: 2 stablecoins.
+const PRICE: Price = 2 * 10u64.pow(PRICE_DECIMALS);
+
+/// Create the collateral and the stablecoin unless they exist, fund `holders` with collateral,
+/// and set the price of the collateral.
+fn setup<T: Config>(holders: &[&T::AccountId])
+where
+	T::Assets: Create<T::AccountId>,
+{
+	let collateral = T::CollateralAsset::get();
+	if T::Assets::minimum_balance(collateral).is_zero() {
+		T::Assets::create(collateral, account("owner", 0, 0), true, One::one()).unwrap();
+	}
+	let stable = T::StableAsset::get();
+	if T::Assets::minimum_balance(stable).is_zero() {
+		T::Assets::create(stable, Pallet::<T>::account_id(), true, One::one()).unwrap();
+	}
+	for who in holders {
+		T::Assets::mint_into(collateral, who, COLLATERAL.into()).unwrap();
+	}
+	T::Oracle::set_price(PRICE);
+}
+
+/// Have `who` lock all its collateral in its vault, and mint `debt` against it.
+fn open_vault<T: Config>(who: &T::AccountId, debt: u32) {
+	Pallet::<T>::deposit_collateral(RawOrigin::Signed(who.clone()).into(), COLLATERAL.into())
+		.unwrap();
+	Pallet::<T>::mint(RawOrigin::Signed(who.clone()).into(), debt.into()).unwrap();
+}
+
+benchmarks! {
+	where_clause { where T::Assets: Create<T::AccountId> }
+
+	deposit_collateral {
+		let who: T::AccountId = whitelisted_caller();
+		setup::<T>(&[&who]);
+	}: _(RawOrigin::Signed(who.clone()), COLLATERAL.into())
+	verify {
+		assert_eq!(Pallet::<T>::vault(&who).unwrap().collateral, COLLATERAL.into());
+	}
+
+	withdraw_collateral {
+		let who: T::AccountId = whitelisted_caller();
+		setup::<T>(&[&who]);
+		open_vault::<T>(&who, COLLATERAL / 10);
+	}: _(RawOrigin::Signed(who.clone()), (COLLATERAL / 2).into())
+	verify {
+		assert_eq!(Pallet::<T>::vault(&who).unwrap().collateral, (COLLATERAL / 2).into());
+	}
+
+	mint {
+		let who: T::AccountId = whitelisted_caller();
+		setup::<T>(&[&who]);
+		open_vault::<T>(&who, COLLATERAL / 10);
+	}: _(RawOrigin::Signed(who.clone()), (COLLATERAL / 10).into())
+	verify {
+		assert_eq!(Pallet::<T>::vault(&who).unwrap().debt, (COLLATERAL / 5).into());
+	}
+
+	repay {
+		let who: T::AccountId = whitelisted_caller();
+		setup::<T>(&[&who]);
+		open_vault::<T>(&who, COLLATERAL / 5);
+	}: _(RawOrigin::Signed(who.clone()), (COLLATERAL / 10).into())
+	verify {
+		assert_eq!(Pallet::<T>::vault(&who).unwrap().debt, (COLLATERAL / 10).into());
+	}
+
+	// The price of the collateral is divided by 4, taking the vault from 400% down to 100%.
+	liquidate {
+		let owner: T::AccountId = account("borrower", 0, 0);
+		let liquidator: T::AccountId = whitelisted_caller();
+		setup::<T>(&[&owner]);
+		open_vault::<T>(&owner, COLLATERAL / 2);
+		T::Assets::mint_into(T::StableAsset::get(), &liquidator, (COLLATERAL / 2).into())
+			.unwrap();
+		T::Oracle::set_price(PRICE / 4);
+	}: _(RawOrigin::Signed(liquidator), owner.clone())
+	verify {
+		assert!(Pallet::<T>::vault(&owner).is_none());
+	}
+
+	// `n` vaults stored in the version 1 layout are migrated in one step.
+	migrate_vaults {
+		let n in 0 .. 100;
+		for i in 0..n {
+			let who: T::AccountId = account("borrower", i, 0);
+			let old: OldVaultOf<T> =
+				OldVault { collateral: COLLATERAL.into(), debt: COLLATERAL.into() };
+			unhashed::put(&Vaults::<T>::hashed_key_for(&who), &old);
+		}
+		VaultsMigrationCursor::<T>::put(VaultsMigration::Started);
+	}: {
+		crate::migrations::v2::step::<T>(Zero::zero(), Weight::max_value());
+	}
+	verify {
+		assert!(Pallet::<T>::vaults_migration().is_none());
+		assert_eq!(Vaults::<T>::iter_values().count(), n as usize);
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
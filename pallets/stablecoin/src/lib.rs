@@ -0,0 +1,373 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A stablecoin minted against over-collateralized vaults.
+///
+/// Accounts lock a collateral asset in their vault and mint the stablecoin against it, as long as
+/// the value of their collateral, according to the price oracle, stays above
+/// `MinimumCollateralRatio` percent of their debt. Repaying the debt burns the stablecoin and
+/// unlocks the collateral.
+///
+/// When the price of the collateral drops and a vault falls below `LiquidationRatio` percent,
+/// anyone can liquidate it: the liquidator repays the whole debt and takes the whole collateral,
+/// the surplus being their reward.
+///
+/// The collateral and the stablecoin are expected to have the same number of decimals, the price
+/// being the value of one unit of collateral in stablecoins.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::tokens::fungibles, transactional, PalletId};
+	use frame_system::pallet_prelude::*;
+	use pallet_price_fetch::{PriceOracle, PRICE_DECIMALS};
+	use rusty_node_support::topics::{account_topic, asset_topic};
+	use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Saturating, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets used as collateral and as stablecoin.
+		type Assets: fungibles::Mutate<Self::AccountId> + fungibles::Transfer<Self::AccountId>;
+
+		/// The price of the collateral, in stablecoins.
+		type Oracle: PriceOracle;
+
+		/// The asset locked as collateral.
+		#[pallet::constant]
+		type CollateralAsset: Get<AssetIdOf<Self>>;
+
+		/// The stablecoin asset, minted by this pallet.
+		#[pallet::constant]
+		type StableAsset: Get<AssetIdOf<Self>>;
+
+		/// The collateral value, as a percentage of the debt, vaults must keep when minting or
+		/// withdrawing collateral.
+		#[pallet::constant]
+		type MinimumCollateralRatio: Get<u32>;
+
+		/// The collateral value, as a percentage of the debt, under which vaults can be
+		/// liquidated.
+		#[pallet::constant]
+		type LiquidationRatio: Get<u32>;
+
+		/// The pallet id, used to derive the account holding collateral.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The weight each block may spend migrating vaults, while a multi-block migration runs.
+		#[pallet::constant]
+		type MaxMigrationWeight: Get<Weight>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The vault of each account.
+	#[pallet::storage]
+	#[pallet::getter(fn vault)]
+	pub type Vaults<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, VaultOf<T>>;
+
+	/// How far the ongoing migration of the vaults went, if one is ongoing.
+	#[pallet::storage]
+	#[pallet::getter(fn vaults_migration)]
+	pub type VaultsMigrationCursor<T> = StorageValue<_, VaultsMigrationOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Collateral has been locked in a vault.
+		CollateralDeposited { who: T::AccountId, amount: BalanceOf<T> },
+		/// Collateral has been withdrawn from a vault.
+		CollateralWithdrawn { who: T::AccountId, amount: BalanceOf<T> },
+		/// Stablecoins have been minted against a vault, leaving `balance` stablecoins to `who`.
+		///
+		/// Indexed by the stablecoin asset and by `who`.
+		Minted { who: T::AccountId, amount: BalanceOf<T>, balance: BalanceOf<T> },
+		/// Stablecoins have been burnt to repay a vault's debt, leaving `balance` stablecoins to
+		/// `who`.
+		///
+		/// Indexed by the stablecoin asset and by `who`.
+		Repaid { who: T::AccountId, amount: BalanceOf<T>, balance: BalanceOf<T> },
+		/// An undercollateralized vault has been liquidated.
+		Liquidated {
+			owner: T::AccountId,
+			liquidator: T::AccountId,
+			debt: BalanceOf<T>,
+			collateral: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The amount must be non zero.
+		ZeroAmount,
+		/// The account has no vault.
+		NoVault,
+		/// The vault does not have that much collateral.
+		InsufficientCollateral,
+		/// The vault does not owe that much.
+		ExcessiveRepayment,
+		/// The operation would bring the vault under the minimum collateral ratio.
+		Undercollateralized,
+		/// The vault is above the liquidation ratio.
+		NotLiquidatable,
+		/// The oracle does not know the price of the collateral.
+		PriceUnavailable,
+		/// The account does not have enough stablecoins.
+		InsufficientBalance,
+		/// The vaults are being migrated, the calls are paused until the migration completes.
+		MigrationOngoing,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			crate::migrations::v2::step::<T>(now, T::MaxMigrationWeight::get())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock `amount` of collateral in the caller's vault.
+		#[pallet::weight(T::WeightInfo::deposit_collateral())]
+		pub fn deposit_collateral(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let asset_id = T::CollateralAsset::get();
+			T::Assets::transfer(asset_id, &who, &Self::account_id(), amount, false)?;
+			Vaults::<T>::mutate(&who, |maybe_vault| {
+				let vault = maybe_vault.get_or_insert_with(|| Vault {
+					opened_at: frame_system::Pallet::<T>::block_number(),
+					..Default::default()
+				});
+				vault.collateral = vault.collateral.saturating_add(amount);
+			});
+
+			Self::deposit_event(Event::CollateralDeposited { who, amount });
+
+			Ok(())
+		}
+
+		/// Unlock `amount` of collateral from the caller's vault.
+		#[pallet::weight(T::WeightInfo::withdraw_collateral())]
+		pub fn withdraw_collateral(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut vault = Self::vault(&who).ok_or(Error::<T>::NoVault)?;
+			ensure!(vault.collateral >= amount, Error::<T>::InsufficientCollateral);
+			vault.collateral -= amount;
+			Self::ensure_ratio(&vault, T::MinimumCollateralRatio::get())?;
+
+			let asset_id = T::CollateralAsset::get();
+			T::Assets::transfer(asset_id, &Self::account_id(), &who, amount, false)?;
+			Self::store_vault(&who, vault);
+
+			Self::deposit_event(Event::CollateralWithdrawn { who, amount });
+
+			Ok(())
+		}
+
+		/// Mint `amount` of stablecoins against the caller's vault.
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut vault = Self::vault(&who).ok_or(Error::<T>::NoVault)?;
+			vault.debt = vault.debt.saturating_add(amount);
+			Self::ensure_ratio(&vault, T::MinimumCollateralRatio::get())?;
+
+			T::Assets::mint_into(T::StableAsset::get(), &who, amount)?;
+			Vaults::<T>::insert(&who, vault);
+
+			let balance = T::Assets::balance(T::StableAsset::get(), &who);
+			let topics = Self::topics(&who);
+			Self::deposit_indexed(Event::Minted { who, amount, balance }, &topics);
+
+			Ok(())
+		}
+
+		/// Burn `amount` of the caller's stablecoins to repay the debt of their vault.
+		#[pallet::weight(T::WeightInfo::repay())]
+		pub fn repay(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut vault = Self::vault(&who).ok_or(Error::<T>::NoVault)?;
+			ensure!(vault.debt >= amount, Error::<T>::ExcessiveRepayment);
+			vault.debt -= amount;
+
+			Self::burn_exactly(&who, amount)?;
+			Self::store_vault(&who, vault);
+
+			let balance = T::Assets::balance(T::StableAsset::get(), &who);
+			let topics = Self::topics(&who);
+			Self::deposit_indexed(Event::Repaid { who, amount, balance }, &topics);
+
+			Ok(())
+		}
+
+		/// Liquidate a vault below the liquidation ratio: repay its whole debt with the caller's
+		/// stablecoins, and take its whole collateral.
+		#[pallet::weight(T::WeightInfo::liquidate())]
+		#[transactional]
+		pub fn liquidate(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			let liquidator = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let vault = Self::vault(&owner).ok_or(Error::<T>::NoVault)?;
+			let ratio = Self::collateral_ratio(&vault)?.ok_or(Error::<T>::NotLiquidatable)?;
+			ensure!(ratio < T::LiquidationRatio::get().into(), Error::<T>::NotLiquidatable);
+
+			Self::burn_exactly(&liquidator, vault.debt)?;
+			T::Assets::transfer(
+				T::CollateralAsset::get(),
+				&Self::account_id(),
+				&liquidator,
+				vault.collateral,
+				false,
+			)?;
+			Vaults::<T>::remove(&owner);
+
+			Self::deposit_event(Event::Liquidated {
+				owner,
+				liquidator,
+				debt: vault.debt,
+				collateral: vault.collateral,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the collateral of all vaults.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// The value of a vault's collateral as a percentage of its debt, if it has debt.
+		pub fn collateral_ratio(vault: &VaultOf<T>) -> Result<Option<u128>, DispatchError> {
+			if vault.debt.is_zero() {
+				return Ok(None)
+			}
+
+			let price: u128 = T::Oracle::price().ok_or(Error::<T>::PriceUnavailable)?.into();
+			let collateral: u128 = vault.collateral.saturated_into();
+			let debt: u128 = vault.debt.saturated_into();
+			let value = collateral.saturating_mul(price) / 10u128.pow(PRICE_DECIMALS);
+
+			Ok(Some(value.saturating_mul(100) / debt))
+		}
+
+		/// Ensure no migration of the vaults is ongoing, the vaults being stored in two layouts
+		/// until it completes.
+		fn ensure_not_migrating() -> DispatchResult {
+			ensure!(!VaultsMigrationCursor::<T>::exists(), Error::<T>::MigrationOngoing);
+			Ok(())
+		}
+
+		/// Ensure a vault is at, or above, `ratio` percent.
+		fn ensure_ratio(vault: &VaultOf<T>, ratio: u32) -> DispatchResult {
+			match Self::collateral_ratio(vault)? {
+				Some(current) if current < ratio.into() =>
+					Err(Error::<T>::Undercollateralized.into()),
+				_ => Ok(()),
+			}
+		}
+
+		fn burn_exactly(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			let asset_id = T::StableAsset::get();
+			ensure!(
+				T::Assets::reducible_balance(asset_id, who, false) >= amount,
+				Error::<T>::InsufficientBalance
+			);
+			T::Assets::burn_from(asset_id, who, amount)?;
+			Ok(())
+		}
+
+		/// The topics of the events about the stablecoin of `who`.
+		fn topics(who: &T::AccountId) -> [T::Hash; 2] {
+			[
+				asset_topic::<T::Hashing, _>(&T::StableAsset::get()),
+				account_topic::<T::Hashing, _>(who),
+			]
+		}
+
+		fn deposit_indexed(event: Event<T>, topics: &[T::Hash]) {
+			let event = <T as Config>::Event::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(topics, event.into());
+		}
+
+		fn store_vault(who: &T::AccountId, vault: VaultOf<T>) {
+			if vault.collateral.is_zero() && vault.debt.is_zero() {
+				Vaults::<T>::remove(who);
+			} else {
+				Vaults::<T>::insert(who, vault);
+			}
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			// The vaults not migrated yet do not decode.
+			if VaultsMigrationCursor::<T>::exists() {
+				return Ok(())
+			}
+
+			let mut collateral = BalanceOf::<T>::zero();
+			for vault in Vaults::<T>::iter_values() {
+				ensure!(!vault.collateral.is_zero() || !vault.debt.is_zero(), "empty vault stored");
+				collateral = collateral.saturating_add(vault.collateral);
+			}
+
+			// The escrow account holds the collateral of every vault.
+			ensure!(
+				T::Assets::balance(T::CollateralAsset::get(), &Self::account_id()) >= collateral,
+				"collateral not escrowed"
+			);
+
+			Ok(())
+		}
+	}
+}
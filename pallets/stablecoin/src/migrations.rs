@@ -0,0 +1,110 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 records the block each vault was opened at.
+///
+/// There may be too many vaults to migrate them all within the upgrade block, so the migration
+/// only starts there: `step` then migrates as many vaults as `MaxMigrationWeight` allows in each
+/// block, in storage order, from where the previous step stopped. The calls of the pallet are
+/// paused until the last vault is migrated.
+pub mod v2 {
+	use super::*;
+	use crate::{Vault, Vaults, VaultsMigration, VaultsMigrationCursor, WeightInfo};
+	use codec::{Decode, Encode};
+	use frame_support::storage::unhashed;
+
+	/// A vault, as stored by version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldVault<Balance> {
+		pub collateral: Balance,
+		pub debt: Balance,
+	}
+
+	pub type OldVaultOf<T> = OldVault<crate::BalanceOf<T>>;
+
+	pub fn migrate<T: Config>() -> Weight {
+		VaultsMigrationCursor::<T>::put(VaultsMigration::Started);
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().writes(2)
+	}
+
+	/// Migrate the vaults among as many as `limit` allows, if the migration is ongoing. The
+	/// migrated vaults are recorded as opened at `now`.
+	pub fn step<T: Config>(now: T::BlockNumber, limit: Weight) -> Weight {
+		let cursor = match VaultsMigrationCursor::<T>::get() {
+			Some(cursor) => cursor,
+			None => return T::DbWeight::get().reads(1),
+		};
+
+		let base = T::WeightInfo::migrate_vaults(0);
+		let per_vault = T::WeightInfo::migrate_vaults(1).saturating_sub(base).max(1);
+		let max = limit.saturating_sub(base) / per_vault;
+		if max == 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		// Only the keys are decoded, the values of the vaults left are still in the old layout.
+		let mut accounts = match cursor {
+			VaultsMigration::Started => Vaults::<T>::iter_keys(),
+			VaultsMigration::MigratedUpTo(who) =>
+				Vaults::<T>::iter_keys_from(Vaults::<T>::hashed_key_for(who)),
+		};
+		let mut migrated = 0;
+		let mut cursor = None;
+		while (migrated as Weight) < max {
+			match accounts.next() {
+				Some(who) => {
+					let key = Vaults::<T>::hashed_key_for(&who);
+					if let Some(old) = unhashed::get::<OldVaultOf<T>>(&key) {
+						let OldVault { collateral, debt } = old;
+						Vaults::<T>::insert(&who, Vault { collateral, debt, opened_at: now });
+					}
+					migrated += 1;
+					cursor = Some(VaultsMigration::MigratedUpTo(who));
+				},
+				// Every vault has been migrated.
+				None => {
+					cursor = None;
+					break
+				},
+			}
+		}
+		VaultsMigrationCursor::<T>::set(cursor);
+
+		T::WeightInfo::migrate_vaults(migrated)
+	}
+}
@@ -0,0 +1,76 @@
+use super::mock::*;
+use crate::{
+	migrations::{self, v2::OldVault},
+	Error, Vault, Vaults, VaultsMigration,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+#[test]
+fn migrate_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Stablecoin>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(Stablecoin::on_chain_storage_version(), Stablecoin::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_v2_records_when_vaults_are_opened_over_several_blocks() {
+	new_test_ext().execute_with(|| {
+		for who in [ALICE, BOB, 3] {
+			let old = OldVault { collateral: 300u64, debt: who * 100 };
+			unhashed::put(&Vaults::<Test>::hashed_key_for(who), &old);
+		}
+		StorageVersion::new(1).put::<Stablecoin>();
+
+		migrations::migrate::<Test>();
+		assert_eq!(Stablecoin::on_chain_storage_version(), Stablecoin::current_storage_version());
+		assert_eq!(Stablecoin::vaults_migration(), Some(VaultsMigration::Started));
+		assert_noop!(
+			Stablecoin::deposit_collateral(Origin::signed(ALICE), 100),
+			Error::<Test>::MigrationOngoing
+		);
+
+		// Two vaults are migrated in the first block, the last one in the next.
+		run_to_block(2);
+		assert!(matches!(
+			Stablecoin::vaults_migration(),
+			Some(VaultsMigration::MigratedUpTo(_))
+		));
+		assert_eq!(Vaults::<Test>::iter_values().count(), 2);
+
+		run_to_block(3);
+		assert_eq!(Stablecoin::vaults_migration(), None);
+		let mut opened_at: Vec<_> =
+			Vaults::<Test>::iter_values().map(|vault| vault.opened_at).collect();
+		opened_at.sort();
+		assert_eq!(opened_at, vec![2, 2, 3]);
+		assert_eq!(
+			Stablecoin::vault(BOB).map(|vault| (vault.collateral, vault.debt)),
+			Some((300, 200))
+		);
+
+		assert_ok!(Stablecoin::deposit_collateral(Origin::signed(ALICE), 100));
+		assert_eq!(Stablecoin::vault(ALICE).map(|vault| vault.collateral), Some(400));
+	})
+}
+
+#[test]
+fn new_vaults_record_their_opening_block() {
+	new_test_ext().execute_with(|| {
+		run_to_block(5);
+
+		assert_ok!(Stablecoin::deposit_collateral(Origin::signed(ALICE), 100));
+
+		assert_eq!(
+			Stablecoin::vault(ALICE),
+			Some(Vault { collateral: 100, debt: 0, opened_at: 5 })
+		);
+	})
+}
@@ -0,0 +1,123 @@
+use super::mock::*;
+use crate::{Error, Event as StablecoinEvent, Vault};
+use frame_support::{assert_noop, assert_ok};
+use rusty_node_support::topics::{account_topic, asset_topic};
+use sp_runtime::traits::BlakeTwo256;
+
+fn open_vault(who: u64, collateral: u64, debt: u64) {
+	assert_ok!(Stablecoin::deposit_collateral(Origin::signed(who), collateral));
+	assert_ok!(Stablecoin::mint(Origin::signed(who), debt));
+}
+
+#[test]
+fn mint_up_to_the_minimum_ratio() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Stablecoin::mint(Origin::signed(ALICE), 100), Error::<Test>::NoVault);
+		assert_ok!(Stablecoin::deposit_collateral(Origin::signed(ALICE), 300));
+		assert_eq!(Assets::balance(COLLATERAL, Stablecoin::account_id()), 300);
+
+		// 300 collateral are worth 600 stablecoins, enough for 400 at 150%.
+		assert_noop!(
+			Stablecoin::mint(Origin::signed(ALICE), 401),
+			Error::<Test>::Undercollateralized
+		);
+		assert_ok!(Stablecoin::mint(Origin::signed(ALICE), 400));
+
+		assert_eq!(Assets::balance(STABLE, ALICE), 400);
+		assert_eq!(
+			Stablecoin::vault(ALICE),
+			Some(Vault { collateral: 300, debt: 400, opened_at: 1 })
+		);
+		System::assert_last_event(
+			StablecoinEvent::Minted { who: ALICE, amount: 400, balance: 400 }.into(),
+		);
+	})
+}
+
+#[test]
+fn mint_and_repay_events_are_indexed_by_asset_and_account() {
+	new_test_ext().execute_with(|| {
+		open_vault(ALICE, 300, 400);
+		assert_ok!(Stablecoin::repay(Origin::signed(ALICE), 100));
+
+		let events = |topic| System::event_topics(topic).len();
+		assert_eq!(events(asset_topic::<BlakeTwo256, _>(&STABLE)), 2);
+		assert_eq!(events(account_topic::<BlakeTwo256, _>(&ALICE)), 2);
+		assert_eq!(events(account_topic::<BlakeTwo256, _>(&BOB)), 0);
+	})
+}
+
+#[test]
+fn mint_needs_a_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Stablecoin::deposit_collateral(Origin::signed(ALICE), 300));
+
+		CollateralPrice::set(None);
+		assert_noop!(Stablecoin::mint(Origin::signed(ALICE), 1), Error::<Test>::PriceUnavailable);
+	})
+}
+
+#[test]
+fn withdraw_keeps_the_minimum_ratio() {
+	new_test_ext().execute_with(|| {
+		open_vault(ALICE, 300, 200);
+
+		assert_noop!(
+			Stablecoin::withdraw_collateral(Origin::signed(ALICE), 151),
+			Error::<Test>::Undercollateralized
+		);
+		assert_ok!(Stablecoin::withdraw_collateral(Origin::signed(ALICE), 150));
+		assert_eq!(Assets::balance(COLLATERAL, ALICE), 850);
+	})
+}
+
+#[test]
+fn repay_unlocks_the_collateral() {
+	new_test_ext().execute_with(|| {
+		open_vault(ALICE, 300, 400);
+
+		assert_noop!(
+			Stablecoin::repay(Origin::signed(ALICE), 401),
+			Error::<Test>::ExcessiveRepayment
+		);
+		assert_ok!(Stablecoin::repay(Origin::signed(ALICE), 400));
+		assert_eq!(Assets::balance(STABLE, ALICE), 0);
+		System::assert_last_event(
+			StablecoinEvent::Repaid { who: ALICE, amount: 400, balance: 0 }.into(),
+		);
+
+		assert_ok!(Stablecoin::withdraw_collateral(Origin::signed(ALICE), 300));
+		assert_eq!(Assets::balance(COLLATERAL, ALICE), 1_000);
+		assert_eq!(Stablecoin::vault(ALICE), None);
+	})
+}
+
+#[test]
+fn liquidate_undercollateralized_vaults() {
+	new_test_ext().execute_with(|| {
+		open_vault(ALICE, 300, 400);
+		open_vault(BOB, 1_000, 500);
+
+		assert_noop!(
+			Stablecoin::liquidate(Origin::signed(BOB), ALICE),
+			Error::<Test>::NotLiquidatable
+		);
+
+		// 300 collateral are now worth 450 stablecoins, 112% of the debt.
+		CollateralPrice::set(Some(1_500_000));
+		assert_ok!(Stablecoin::liquidate(Origin::signed(BOB), ALICE));
+
+		assert_eq!(Stablecoin::vault(ALICE), None);
+		assert_eq!(Assets::balance(STABLE, BOB), 100);
+		assert_eq!(Assets::balance(COLLATERAL, BOB), 300);
+		System::assert_last_event(
+			StablecoinEvent::Liquidated {
+				owner: ALICE,
+				liquidator: BOB,
+				debt: 400,
+				collateral: 300,
+			}
+			.into(),
+		);
+	})
+}
@@ -0,0 +1,31 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::tokens::fungibles::Inspect;
+use scale_info::TypeInfo;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type VaultOf<T> = Vault<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub type VaultsMigrationOf<T> = VaultsMigration<AccountIdOf<T>>;
+
+/// The collateral locked and the stablecoin minted by an account.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Vault<Balance, BlockNumber> {
+	/// The amount of collateral held in the pallet account.
+	pub collateral: Balance,
+	/// The amount of stablecoin minted, to be repaid to unlock the collateral.
+	pub debt: Balance,
+	/// The block the vault was opened at, or migrated at for the vaults opened before it was
+	/// recorded.
+	pub opened_at: BlockNumber,
+}
+
+/// How far the migration of the vaults to their version 2 layout went.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum VaultsMigration<AccountId> {
+	/// No vault has been migrated yet.
+	Started,
+	/// The vaults up to the one of this account, in storage order, have been migrated.
+	MigratedUpTo(AccountId),
+}
@@ -0,0 +1,111 @@
+//! Benchmarks of the storage-repair pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{
+	tokens::{fungibles, nonfungibles},
+	Currency, EnsureOrigin, Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{One, StaticLookup};
+
+const ASSET_ID: u32 = 1_000;
+const CLASS_ID: u32 = 1_000;
+const INSTANCE_ID: u32 = 0;
+const AMOUNT: u32 = 1_000;
+
+/// Create the asset, held by `a` accounts, and an item of the class.
+fn setup<T: Config>(a: u32) -> T::AccountId
+where
+	T: pallet_balances::Config,
+	T::Assets: fungibles::Create<T::AccountId>,
+	T::Items: nonfungibles::Create<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+	T::ClassId: From<u32>,
+	T::InstanceId: From<u32>,
+{
+	let admin: T::AccountId = account("admin", 0, 0);
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		&admin,
+		T::ExistentialDeposit::get() * 1_000_000u32.into(),
+	);
+	<T::Assets as fungibles::Create<_>>::create(ASSET_ID.into(), admin.clone(), true, One::one())
+		.unwrap();
+	for i in 0..a {
+		let holder: T::AccountId = account("holder", i, 0);
+		<T::Assets as fungibles::Mutate<_>>::mint_into(ASSET_ID.into(), &holder, AMOUNT.into())
+			.unwrap();
+	}
+	<T::Items as nonfungibles::Create<_>>::create_class(&CLASS_ID.into(), &admin, &admin).unwrap();
+	<T::Items as nonfungibles::Mutate<_>>::mint_into(&CLASS_ID.into(), &INSTANCE_ID.into(), &admin)
+		.unwrap();
+	admin
+}
+
+/// Have the `admin` of the asset approve `p` delegates.
+fn approve<T>(admin: &T::AccountId, p: u32)
+where
+	T: Config + pallet_assets::Config<AssetId = AssetIdOf<T>>,
+	AssetIdOf<T>: From<u32>,
+{
+	let deposit = <T as pallet_assets::Config>::ApprovalDeposit::get();
+	let _ = <T as pallet_assets::Config>::Currency::deposit_creating(admin, deposit * p.into());
+	for i in 0..p {
+		let delegate: T::AccountId = account("delegate", i, 0);
+		pallet_assets::Pallet::<T>::approve_transfer(
+			RawOrigin::Signed(admin.clone()).into(),
+			ASSET_ID.into(),
+			T::Lookup::unlookup(delegate),
+			AMOUNT.into(),
+		)
+		.unwrap();
+	}
+}
+
+benchmarks! {
+	where_clause { where
+		T: pallet_assets::Config<AssetId = AssetIdOf<T>> + pallet_balances::Config,
+		T::Assets: fungibles::Create<T::AccountId>,
+		T::Items: nonfungibles::Create<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+		T::ClassId: From<u32>,
+		T::InstanceId: From<u32>,
+	}
+
+	// `a` is the number of accounts of the asset, and `p` the number of its approvals.
+	force_remove_asset {
+		let a in 0 .. 1_000;
+		let p in 0 .. 1_000;
+		let admin = setup::<T>(a);
+		approve::<T>(&admin, p);
+		let origin = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(origin, ASSET_ID.into(), a, p)
+	verify {
+		assert!(
+			<T::Assets as fungibles::Destroy<_>>::get_destroy_witness(&ASSET_ID.into()).is_none()
+		);
+	}
+
+	// Burning the whole balance, which reaps the account.
+	force_set_balance {
+		setup::<T>(1);
+		let holder: T::AccountId = account("holder", 0, 0);
+		let origin = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(origin, ASSET_ID.into(), holder.clone(), 0u32.into())
+	verify {
+		let balance = <T::Assets as fungibles::Inspect<_>>::balance(ASSET_ID.into(), &holder);
+		assert_eq!(balance, 0u32.into());
+	}
+
+	force_remove_unique_asset {
+		setup::<T>(0);
+		let origin = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(origin, CLASS_ID.into(), INSTANCE_ID.into())
+	verify {
+		let owner =
+			<T::Items as nonfungibles::Inspect<_>>::owner(&CLASS_ID.into(), &INSTANCE_ID.into());
+		assert!(owner.is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,200 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Repair the state of the assets and uniques pallets on long-lived test networks.
+///
+/// Test networks outlive the accounts and the bugs that left assets half destroyed, balances no
+/// one can move, or items in the way of a class to destroy. `ForceOrigin` removes an asset along
+/// with all its accounts, sets the balance of an account to any amount, and removes an item from
+/// its owner, through the `fungibles` and `nonfungibles` traits rather than raw storage writes,
+/// so the supplies and deposits of the pallets stay consistent.
+///
+/// Each repair deposits an event recording the state it replaced, for audits.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use codec::Compact;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::tokens::{fungibles, nonfungibles},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Saturating;
+
+	pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+	pub type BalanceOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to repair the state of assets and items.
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The fungible assets repaired.
+		type Assets: fungibles::Mutate<Self::AccountId>
+			+ fungibles::Destroy<Self::AccountId, DestroyWitness = Self::DestroyWitness>;
+
+		/// The witness of what an asset holds before it is destroyed, whose encoding starts with
+		/// the compact numbers of accounts, sufficient accounts and approvals of the asset, as the
+		/// witness of the assets pallet does.
+		type DestroyWitness: Encode;
+
+		/// Identifier of an item class.
+		type ClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its class.
+		type InstanceId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The nonfungible items repaired.
+		type Items: nonfungibles::Mutate<
+			Self::AccountId,
+			ClassId = Self::ClassId,
+			InstanceId = Self::InstanceId,
+		>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An asset has been removed, along with all its accounts.
+		AssetRemoved { asset_id: AssetIdOf<T> },
+		/// The balance of an account has been set from `old` to `new`.
+		BalanceSet {
+			asset_id: AssetIdOf<T>,
+			who: T::AccountId,
+			old: BalanceOf<T>,
+			new: BalanceOf<T>,
+		},
+		/// An item has been removed from its owner.
+		UniqueAssetRemoved { class: T::ClassId, instance: T::InstanceId, owner: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The asset does not exist.
+		UnknownAsset,
+		/// The item does not exist.
+		UnknownItem,
+		/// The asset has more accounts, or approvals, than the call was given.
+		BadWitness,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Remove `asset_id`, along with all its accounts, approvals and metadata.
+		///
+		/// `accounts` and `approvals` are the numbers of accounts and approvals of the asset, which
+		/// the weight grows with. The call fails if the asset has more of either, so that it never
+		/// removes more than it paid for.
+		#[pallet::weight(T::WeightInfo::force_remove_asset(*accounts, *approvals))]
+		pub fn force_remove_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			accounts: u32,
+			approvals: u32,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let witness = <T::Assets as fungibles::Destroy<_>>::get_destroy_witness(&asset_id)
+				.ok_or(Error::<T>::UnknownAsset)?;
+			let (witnessed_accounts, witnessed_approvals) = Self::witnessed(&witness);
+			ensure!(
+				witnessed_accounts <= accounts && witnessed_approvals <= approvals,
+				Error::<T>::BadWitness
+			);
+			<T::Assets as fungibles::Destroy<_>>::destroy(asset_id, witness, None)?;
+
+			Self::deposit_event(Event::AssetRemoved { asset_id });
+
+			Ok(())
+		}
+
+		/// Set the balance of `who` in `asset_id` to `balance`, minting or burning the difference.
+		///
+		/// As for any burn, a balance left below the minimum balance of the asset is burned too.
+		#[pallet::weight(T::WeightInfo::force_set_balance())]
+		pub fn force_set_balance(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			who: T::AccountId,
+			balance: BalanceOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let old = <T::Assets as fungibles::Inspect<_>>::balance(asset_id, &who);
+			if balance > old {
+				<T::Assets as fungibles::Mutate<_>>::mint_into(
+					asset_id,
+					&who,
+					balance.saturating_sub(old),
+				)?;
+			} else if balance < old {
+				<T::Assets as fungibles::Mutate<_>>::burn_from(
+					asset_id,
+					&who,
+					old.saturating_sub(balance),
+				)?;
+			}
+			let new = <T::Assets as fungibles::Inspect<_>>::balance(asset_id, &who);
+
+			Self::deposit_event(Event::BalanceSet { asset_id, who, old, new });
+
+			Ok(())
+		}
+
+		/// Remove the item `instance` of `class` from its owner.
+		#[pallet::weight(T::WeightInfo::force_remove_unique_asset())]
+		pub fn force_remove_unique_asset(
+			origin: OriginFor<T>,
+			class: T::ClassId,
+			instance: T::InstanceId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let owner = <T::Items as nonfungibles::Inspect<_>>::owner(&class, &instance)
+				.ok_or(Error::<T>::UnknownItem)?;
+			<T::Items as nonfungibles::Mutate<_>>::burn_from(&class, &instance)?;
+
+			Self::deposit_event(Event::UniqueAssetRemoved { class, instance, owner });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The numbers of accounts and approvals `witness` records, or `u32::MAX` for each if it
+		/// does not decode.
+		fn witnessed(witness: &T::DestroyWitness) -> (u32, u32) {
+			let encoded = witness.encode();
+			// The number of sufficient accounts is in between, and is not weighed.
+			match <(Compact<u32>, Compact<u32>, Compact<u32>)>::decode(&mut &encoded[..]) {
+				Ok((accounts, _, approvals)) => (accounts.0, approvals.0),
+				Err(_) => (u32::MAX, u32::MAX),
+			}
+		}
+	}
+}
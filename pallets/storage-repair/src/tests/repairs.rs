@@ -0,0 +1,94 @@
+use super::mock::*;
+use crate::{Error, Event as RepairEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn only_the_force_origin_repairs() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			StorageRepair::force_remove_asset(Origin::signed(ALICE), ASSET_ID, 2, 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			StorageRepair::force_set_balance(Origin::signed(ALICE), ASSET_ID, BOB, 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			StorageRepair::force_remove_unique_asset(Origin::signed(ALICE), CLASS_ID, INSTANCE_ID),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn force_remove_asset_removes_its_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			StorageRepair::force_remove_asset(Origin::root(), ASSET_ID, 1, 0),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(StorageRepair::force_remove_asset(Origin::root(), ASSET_ID, 2, 0));
+		assert_eq!(Assets::total_supply(ASSET_ID), 0);
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 0);
+		System::assert_last_event(RepairEvent::AssetRemoved { asset_id: ASSET_ID }.into());
+
+		assert_noop!(
+			StorageRepair::force_remove_asset(Origin::root(), ASSET_ID, 0, 0),
+			Error::<Test>::UnknownAsset
+		);
+	})
+}
+
+#[test]
+fn force_remove_asset_checks_the_witnessed_approvals() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::approve_transfer(Origin::signed(BOB), ASSET_ID, CHARLIE, 10));
+
+		assert_noop!(
+			StorageRepair::force_remove_asset(Origin::root(), ASSET_ID, 2, 0),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(StorageRepair::force_remove_asset(Origin::root(), ASSET_ID, 2, 1));
+		assert_eq!(Assets::total_supply(ASSET_ID), 0);
+	})
+}
+
+#[test]
+fn force_set_balance_mints_or_burns_the_difference() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StorageRepair::force_set_balance(Origin::root(), ASSET_ID, ALICE, 30));
+		System::assert_last_event(
+			RepairEvent::BalanceSet { asset_id: ASSET_ID, who: ALICE, old: 0, new: 30 }.into(),
+		);
+
+		assert_ok!(StorageRepair::force_set_balance(Origin::root(), ASSET_ID, BOB, 20));
+		System::assert_last_event(
+			RepairEvent::BalanceSet { asset_id: ASSET_ID, who: BOB, old: 50, new: 20 }.into(),
+		);
+
+		assert_ok!(StorageRepair::force_set_balance(Origin::root(), ASSET_ID, CHARLIE, 0));
+		assert_eq!(Assets::total_supply(ASSET_ID), 50);
+	})
+}
+
+#[test]
+fn force_remove_unique_asset_burns_the_item() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StorageRepair::force_remove_unique_asset(Origin::root(), CLASS_ID, INSTANCE_ID));
+		assert_eq!(Uniques::owner(CLASS_ID, INSTANCE_ID), None);
+		System::assert_last_event(
+			RepairEvent::UniqueAssetRemoved {
+				class: CLASS_ID,
+				instance: INSTANCE_ID,
+				owner: BOB,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			StorageRepair::force_remove_unique_asset(Origin::root(), CLASS_ID, INSTANCE_ID),
+			Error::<Test>::UnknownItem
+		);
+	})
+}
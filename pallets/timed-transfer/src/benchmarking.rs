@@ -0,0 +1,85 @@
+//! Benchmarks of the timed-transfer pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{
+	tokens::fungibles::{Create, Mutate},
+	Get, Time,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+
+const ASSET_ID: u32 = 1_000;
+const AMOUNT: u32 = 1_000_000;
+
+/// Create the asset of the transfers, funding `from` with it.
+fn create_asset<T: Config>(from: &T::AccountId)
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	let owner: T::AccountId = account("owner", 0, 0);
+	T::Assets::create(ASSET_ID.into(), owner, true, One::one()).unwrap();
+	T::Assets::mint_into(ASSET_ID.into(), from, AMOUNT.into()).unwrap();
+}
+
+/// Have `from` make `n` transfers to distinct recipients, unlocking at `unlock_at`.
+fn new_transfers<T: Config>(from: &T::AccountId, n: u32, unlock_at: MomentOf<T>)
+where
+	AssetIdOf<T>: From<u32>,
+{
+	for i in 0..n {
+		Pallet::<T>::transfer_at(
+			RawOrigin::Signed(from.clone()).into(),
+			account("to", i, 0),
+			ASSET_ID.into(),
+			One::one(),
+			unlock_at,
+		)
+		.unwrap();
+	}
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	transfer_at {
+		let p in 0 .. T::MaxPending::get() - 1;
+		let from: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, 0);
+		create_asset::<T>(&from);
+		// The new transfer unlocks first, so it is inserted in front of all the pending ones.
+		let unlock_at = T::Time::now() + One::one();
+		new_transfers::<T>(&from, p, unlock_at + One::one());
+	}: _(RawOrigin::Signed(from), to, ASSET_ID.into(), AMOUNT.into(), unlock_at)
+	verify {
+		assert!(Pallet::<T>::transfer(p).is_some());
+	}
+
+	cancel {
+		let p in 1 .. T::MaxPending::get();
+		let from: T::AccountId = whitelisted_caller();
+		create_asset::<T>(&from);
+		new_transfers::<T>(&from, p, T::Time::now() + One::one());
+		// The last transfer of the queue is the slowest to remove.
+		let transfer_id = p - 1;
+	}: _(RawOrigin::Signed(from), transfer_id)
+	verify {
+		assert!(Pallet::<T>::transfer(transfer_id).is_none());
+	}
+
+	release_due {
+		let r in 0 .. T::MaxReleasesPerBlock::get();
+		let from: T::AccountId = account("from", 0, 0);
+		create_asset::<T>(&from);
+		new_transfers::<T>(&from, r, T::Time::now());
+	}: _(RawOrigin::None)
+	verify {
+		assert!(Pallet::<T>::queue().is_empty());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
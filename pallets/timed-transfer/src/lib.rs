@@ -0,0 +1,421 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Asset transfers released at a timestamp.
+///
+/// A sender escrows some amount of an asset for a recipient until an unlock time. The unlock time
+/// is a timestamp rather than a block number, as block times drift while the dates users agree on
+/// do not. The sender can cancel the transfer until it is released.
+///
+/// Due transfers are released by an inherent: the block author reads the timestamp of the block
+/// from its inherent data, and includes a `release_due` call when transfers are due by then. The
+/// call releases the transfers due by the time the timestamp pallet set, at most
+/// `MaxReleasesPerBlock` of them, once per block.
+///
+/// The calls are encoded by their index, their order of declaration. New calls are appended, and a
+/// call whose parameters change is appended under a new name, the legacy one forwarding to it at
+/// its index, so that transactions signed against a previous version still decode, and do what
+/// they did.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{tokens::fungibles, Time},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::topics::{account_topic, asset_topic};
+	use sp_inherents::MakeFatalError;
+	use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Zero};
+
+	/// The identifier of the inherent of the pallet.
+	pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"timedtrf";
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The fungible assets transferred.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// The time of the chain, set by the timestamp inherent of each block.
+		type Time: Time;
+
+		/// The maximum number of transfers pending release.
+		#[pallet::constant]
+		type MaxPending: Get<u32>;
+
+		/// The maximum number of transfers released in a block.
+		#[pallet::constant]
+		type MaxReleasesPerBlock: Get<u32>;
+
+		/// The pallet id, used to derive the escrow account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next transfer will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_transfer_id)]
+	pub type NextTransferId<T> = StorageValue<_, TransferId, ValueQuery>;
+
+	/// The transfers that have not been released nor canceled.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer)]
+	pub type Transfers<T: Config> = StorageMap<_, Twox64Concat, TransferId, TimedTransferOf<T>>;
+
+	/// The pending transfers, sorted by unlock time.
+	#[pallet::storage]
+	#[pallet::getter(fn queue)]
+	pub type Queue<T: Config> =
+		StorageValue<_, BoundedVec<(MomentOf<T>, TransferId), T::MaxPending>, ValueQuery>;
+
+	/// Whether due transfers have been released in this block.
+	#[pallet::storage]
+	pub type Released<T> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A transfer has been escrowed until `unlock_at`.
+		///
+		/// Indexed by the asset, by `from` and by `to`.
+		Scheduled {
+			transfer_id: TransferId,
+			from: T::AccountId,
+			to: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			unlock_at: MomentOf<T>,
+		},
+		/// A due transfer has been paid out. Indexed by the asset and by `to`.
+		Released { transfer_id: TransferId, to: T::AccountId, amount: BalanceOf<T> },
+		/// A due transfer could not be paid out, and went back to its sender. Indexed by the asset
+		/// and by `from`.
+		Refunded { transfer_id: TransferId, from: T::AccountId, amount: BalanceOf<T> },
+		/// A due transfer could neither be paid out nor go back to its sender, e.g. as the asset is
+		/// frozen. It stays escrowed until its sender cancels it. Indexed by the asset and by
+		/// `from`.
+		ReleaseFailed { transfer_id: TransferId, from: T::AccountId },
+		/// A transfer has been canceled by its sender.
+		Canceled { transfer_id: TransferId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The amount must be non zero.
+		ZeroAmount,
+		/// The transfer does not exist.
+		UnknownTransfer,
+		/// Only the sender of a transfer can cancel it.
+		NotSender,
+		/// Too many transfers are pending release.
+		TooManyPending,
+		/// There is no transfer id left.
+		NoAvailableTransferId,
+		/// Due transfers have already been released in this block.
+		AlreadyReleased,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			// The flag is killed in `on_finalize`.
+			T::DbWeight::get().writes(1)
+		}
+
+		fn on_finalize(_now: T::BlockNumber) {
+			Released::<T>::kill();
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow `amount` of `asset_id` for `to`, until the timestamp `unlock_at`.
+		///
+		/// Legacy version of `schedule_transfer`, kept at its call index: the escrow may take the
+		/// whole balance of the caller.
+		#[pallet::weight(T::WeightInfo::transfer_at(T::MaxPending::get()))]
+		#[transactional]
+		pub fn transfer_at(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			unlock_at: MomentOf<T>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			Self::do_schedule_transfer(from, to, asset_id, amount, unlock_at, false)
+		}
+
+		/// Cancel a transfer made by the caller, which has not been released yet.
+		///
+		/// Refunds the weight of the pending transfers the queue was assumed to hold.
+		#[pallet::weight(T::WeightInfo::cancel(T::MaxPending::get()))]
+		pub fn cancel(origin: OriginFor<T>, transfer_id: TransferId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let transfer = Self::transfer(transfer_id).ok_or(Error::<T>::UnknownTransfer)?;
+			ensure!(transfer.from == who, Error::<T>::NotSender);
+
+			T::Assets::transfer(
+				transfer.asset_id,
+				&Self::account_id(),
+				&who,
+				transfer.amount,
+				false,
+			)?;
+			Transfers::<T>::remove(transfer_id);
+			let pending = Queue::<T>::mutate(|queue| {
+				let pending = queue.len() as u32;
+				queue.retain(|(_, id)| *id != transfer_id);
+				pending
+			});
+
+			Self::deposit_event(Event::Canceled { transfer_id });
+
+			Ok(Some(T::WeightInfo::cancel(pending)).into())
+		}
+
+		/// Release the transfers due by the timestamp of the block.
+		///
+		/// This is an inherent, included by the block author when transfers are due. Refunds the
+		/// weight of the releases it was assumed to pay out, but did not.
+		#[pallet::weight((
+			T::WeightInfo::release_due(T::MaxReleasesPerBlock::get()),
+			DispatchClass::Mandatory
+		))]
+		pub fn release_due(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			ensure!(!Released::<T>::get(), Error::<T>::AlreadyReleased);
+			Released::<T>::put(true);
+
+			let now = T::Time::now();
+			let mut queue = Queue::<T>::get().into_inner();
+			let due = queue
+				.iter()
+				.take(T::MaxReleasesPerBlock::get() as usize)
+				.take_while(|(unlock_at, _)| *unlock_at <= now)
+				.count();
+			for (_, transfer_id) in queue.drain(..due) {
+				Self::release(transfer_id);
+			}
+			let queue: BoundedVec<_, T::MaxPending> =
+				queue.try_into().expect("the queue only shrank");
+			Queue::<T>::put(queue);
+
+			Ok(Some(T::WeightInfo::release_due(due as u32)).into())
+		}
+
+		/// Escrow `amount` of `asset_id` for `to`, until the timestamp `unlock_at`.
+		///
+		/// With `keep_alive`, the escrow fails rather than take the caller's balance of the asset
+		/// below its minimum balance.
+		#[pallet::weight(T::WeightInfo::transfer_at(T::MaxPending::get()))]
+		#[transactional]
+		pub fn schedule_transfer(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			unlock_at: MomentOf<T>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			Self::do_schedule_transfer(from, to, asset_id, amount, unlock_at, keep_alive)
+		}
+	}
+
+	#[pallet::inherent]
+	impl<T: Config> ProvideInherent for Pallet<T> {
+		type Call = Call<T>;
+		type Error = MakeFatalError<()>;
+
+		const INHERENT_IDENTIFIER: InherentIdentifier = INHERENT_IDENTIFIER;
+
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			let now = Self::timestamp(data)?;
+			let first = Queue::<T>::get().first().map(|(unlock_at, _)| *unlock_at)?;
+			(first <= now).then(|| Call::release_due {})
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::release_due { .. })
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the escrowed transfers.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// The timestamp of the block being built, from the inherent data of the timestamp
+		/// pallet.
+		fn timestamp(data: &InherentData) -> Option<MomentOf<T>> {
+			let timestamp = data
+				.get_data::<sp_timestamp::InherentType>(&sp_timestamp::INHERENT_IDENTIFIER)
+				.ok()??;
+			Some((*timestamp).saturated_into())
+		}
+
+		fn do_schedule_transfer(
+			from: T::AccountId,
+			to: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			unlock_at: MomentOf<T>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let transfer_id = Self::next_transfer_id();
+			let next_id = transfer_id.checked_add(1).ok_or(Error::<T>::NoAvailableTransferId)?;
+
+			Queue::<T>::try_mutate(|queue| {
+				// Transfers unlocking at the same time are released in the order they were made.
+				let index = queue.partition_point(|(at, _)| *at <= unlock_at);
+				queue.try_insert(index, (unlock_at, transfer_id))
+			})
+			.map_err(|_| Error::<T>::TooManyPending)?;
+			T::Assets::transfer(asset_id, &from, &Self::account_id(), amount, keep_alive)?;
+
+			Transfers::<T>::insert(
+				transfer_id,
+				TimedTransfer { from: from.clone(), to: to.clone(), asset_id, amount, unlock_at },
+			);
+			NextTransferId::<T>::put(next_id);
+
+			let topics = [
+				asset_topic::<T::Hashing, _>(&asset_id),
+				account_topic::<T::Hashing, _>(&from),
+				account_topic::<T::Hashing, _>(&to),
+			];
+			Self::deposit_indexed(
+				Event::Scheduled { transfer_id, from, to, asset_id, amount, unlock_at },
+				&topics,
+			);
+
+			Ok(())
+		}
+
+		fn deposit_indexed(event: Event<T>, topics: &[T::Hash]) {
+			let event = <T as Config>::Event::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(topics, event.into());
+		}
+
+		/// Pay out a due transfer, or refund its sender if it cannot be paid out.
+		///
+		/// A transfer that cannot be refunded either is kept, for its sender to cancel.
+		fn release(transfer_id: TransferId) {
+			let transfer = match Self::transfer(transfer_id) {
+				Some(transfer) => transfer,
+				None => return,
+			};
+			let escrow = Self::account_id();
+			let asset = asset_topic::<T::Hashing, _>(&transfer.asset_id);
+
+			let paid = T::Assets::transfer(
+				transfer.asset_id,
+				&escrow,
+				&transfer.to,
+				transfer.amount,
+				false,
+			);
+			if paid.is_ok() {
+				Transfers::<T>::remove(transfer_id);
+				let topics = [asset, account_topic::<T::Hashing, _>(&transfer.to)];
+				Self::deposit_indexed(
+					Event::Released { transfer_id, to: transfer.to, amount: transfer.amount },
+					&topics,
+				);
+				return
+			}
+
+			// E.g. the amount is below the minimum balance of the asset, for a new holder.
+			let refunded = T::Assets::transfer(
+				transfer.asset_id,
+				&escrow,
+				&transfer.from,
+				transfer.amount,
+				false,
+			);
+			let topics = [asset, account_topic::<T::Hashing, _>(&transfer.from)];
+			let TimedTransfer { from, amount, .. } = transfer;
+			if refunded.is_ok() {
+				Transfers::<T>::remove(transfer_id);
+				Self::deposit_indexed(Event::Refunded { transfer_id, from, amount }, &topics);
+			} else {
+				Self::deposit_indexed(Event::ReleaseFailed { transfer_id, from }, &topics);
+			}
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use rusty_node_support::escrow::ensure_escrowed;
+
+			let next_id = Self::next_transfer_id();
+			ensure!(
+				Transfers::<T>::iter_keys().all(|id| id < next_id),
+				"transfer id not allocated"
+			);
+
+			// A transfer that failed to release leaves the queue, but stays stored.
+			let queue = Self::queue();
+			ensure!(queue.windows(2).all(|pair| pair[0].0 <= pair[1].0), "queue not sorted");
+			ensure!(
+				queue.iter().all(|(unlock_at, id)| {
+					Self::transfer(id).map_or(false, |transfer| transfer.unlock_at == *unlock_at)
+				}),
+				"queued transfer not stored"
+			);
+
+			ensure_escrowed::<_, T::Assets>(
+				&Self::account_id(),
+				Transfers::<T>::iter_values().map(|transfer| (transfer.asset_id, transfer.amount)),
+				"transfers not escrowed",
+			)?;
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,46 @@
+use super::mock::*;
+use crate::Call as TimedTransferCall;
+use frame_support::{assert_ok, inherent::ProvideInherent};
+use sp_inherents::InherentData;
+
+/// The inherent data of a block authored at `timestamp`.
+fn inherent_data(timestamp: u64) -> InherentData {
+	let mut data = InherentData::new();
+	data.put_data(sp_timestamp::INHERENT_IDENTIFIER, &timestamp).unwrap();
+	data
+}
+
+#[test]
+fn create_inherent_when_transfers_are_due() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TimedTransfer::create_inherent(&inherent_data(6_000)), None);
+
+		assert_ok!(TimedTransfer::transfer_at(Origin::signed(ALICE), BOB, ASSET_ID, 100, 12_000));
+		assert_eq!(TimedTransfer::create_inherent(&inherent_data(11_999)), None);
+		assert_eq!(
+			TimedTransfer::create_inherent(&inherent_data(12_000)),
+			Some(TimedTransferCall::release_due {})
+		);
+		assert_eq!(TimedTransfer::create_inherent(&InherentData::new()), None);
+	})
+}
+
+#[test]
+fn release_due_is_an_inherent() {
+	assert!(TimedTransfer::is_inherent(&TimedTransferCall::release_due {}));
+	assert!(!TimedTransfer::is_inherent(&TimedTransferCall::cancel { transfer_id: 0 }));
+}
+
+#[test]
+fn release_due_uses_the_timestamp_of_the_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TimedTransfer::transfer_at(Origin::signed(ALICE), BOB, ASSET_ID, 100, 12_000));
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 0);
+
+		run_to_block(2);
+		Timestamp::set_timestamp(12_000);
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+	})
+}
@@ -0,0 +1,250 @@
+use super::mock::*;
+use crate::{Call as TimedTransferCall, Error, Event as TimedTransferEvent, WeightInfo};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use rusty_node_support::topics::{account_topic, asset_topic};
+use sp_runtime::{traits::BlakeTwo256, DispatchError};
+
+fn transfer_at(to: u64, amount: u64, unlock_at: u64) {
+	assert_ok!(TimedTransfer::transfer_at(Origin::signed(ALICE), to, ASSET_ID, amount, unlock_at));
+}
+
+#[test]
+fn transfer_at_escrows_the_amount() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TimedTransfer::transfer_at(Origin::signed(ALICE), BOB, ASSET_ID, 0, 12_000),
+			Error::<Test>::ZeroAmount
+		);
+		transfer_at(BOB, 100, 12_000);
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 900);
+		assert_eq!(Assets::balance(ASSET_ID, TimedTransfer::account_id()), 100);
+		System::assert_last_event(
+			TimedTransferEvent::Scheduled {
+				transfer_id: 0,
+				from: ALICE,
+				to: BOB,
+				asset_id: ASSET_ID,
+				amount: 100,
+				unlock_at: 12_000,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn queue_is_sorted_by_unlock_time() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 100, 12_000);
+		transfer_at(BOB, 100, 9_000);
+		transfer_at(BOB, 100, 12_000);
+		transfer_at(BOB, 100, 10_000);
+
+		assert_eq!(
+			TimedTransfer::queue().into_inner(),
+			vec![(9_000, 1), (10_000, 3), (12_000, 0), (12_000, 2)]
+		);
+		assert_noop!(
+			TimedTransfer::transfer_at(Origin::signed(ALICE), BOB, ASSET_ID, 100, 6_000),
+			Error::<Test>::TooManyPending
+		);
+	})
+}
+
+#[test]
+fn cancel_refunds_the_sender() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 100, 12_000);
+
+		assert_noop!(
+			TimedTransfer::cancel(Origin::signed(ALICE), 1),
+			Error::<Test>::UnknownTransfer
+		);
+		assert_noop!(TimedTransfer::cancel(Origin::signed(BOB), 0), Error::<Test>::NotSender);
+		assert_ok!(TimedTransfer::cancel(Origin::signed(ALICE), 0));
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000);
+		assert!(TimedTransfer::transfer(0).is_none());
+		assert!(TimedTransfer::queue().is_empty());
+		System::assert_last_event(TimedTransferEvent::Canceled { transfer_id: 0 }.into());
+	})
+}
+
+#[test]
+fn release_due_pays_out_due_transfers() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 100, 6_000);
+		transfer_at(CHARLIE, 200, 12_000);
+
+		assert_noop!(TimedTransfer::release_due(Origin::signed(ALICE)), DispatchError::BadOrigin);
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 100);
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), 0);
+		assert_eq!(TimedTransfer::queue().into_inner(), vec![(12_000, 1)]);
+		System::assert_last_event(
+			TimedTransferEvent::Released { transfer_id: 0, to: BOB, amount: 100 }.into(),
+		);
+	})
+}
+
+#[test]
+fn transfer_events_are_indexed_by_asset_and_account() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 100, 6_000);
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+
+		let events = |topic| System::event_topics(topic).len();
+		// Scheduled, then Released.
+		assert_eq!(events(asset_topic::<BlakeTwo256, _>(&ASSET_ID)), 2);
+		assert_eq!(events(account_topic::<BlakeTwo256, _>(&BOB)), 2);
+		assert_eq!(events(account_topic::<BlakeTwo256, _>(&ALICE)), 1);
+		assert_eq!(events(account_topic::<BlakeTwo256, _>(&CHARLIE)), 0);
+	})
+}
+
+#[test]
+fn release_due_runs_once_per_block() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 100, 6_000);
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+		assert_noop!(TimedTransfer::release_due(Origin::none()), Error::<Test>::AlreadyReleased);
+
+		run_to_block(2);
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+	})
+}
+
+#[test]
+fn release_due_is_bounded_per_block() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			transfer_at(BOB, 100, 6_000);
+		}
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 200);
+		assert_eq!(TimedTransfer::queue().into_inner(), vec![(6_000, 2)]);
+
+		run_to_block(2);
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 300);
+		assert!(TimedTransfer::queue().is_empty());
+	})
+}
+
+#[test]
+fn unused_weight_is_refunded() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 100, 6_000);
+		transfer_at(CHARLIE, 200, 12_000);
+
+		let post_info = TimedTransfer::release_due(Origin::none()).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::release_due(1)));
+
+		let post_info = TimedTransfer::cancel(Origin::signed(ALICE), 1).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::cancel(1)));
+	})
+}
+
+#[test]
+fn release_due_refunds_transfers_that_cannot_be_paid_out() {
+	new_test_ext().execute_with(|| {
+		// An insufficient asset, which CHARLIE cannot hold without a native balance.
+		assert_ok!(Assets::force_create(Origin::root(), 1, ALICE, false, 1));
+		assert_ok!(Assets::mint(Origin::signed(ALICE), 1, ALICE, 1_000));
+		Balances::make_free_balance_be(&TimedTransfer::account_id(), 1);
+		assert_ok!(TimedTransfer::transfer_at(Origin::signed(ALICE), CHARLIE, 1, 100, 6_000));
+
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+
+		assert_eq!(Assets::balance(1, ALICE), 1_000);
+		assert_eq!(Assets::balance(1, CHARLIE), 0);
+		assert!(TimedTransfer::transfer(0).is_none());
+		System::assert_last_event(
+			TimedTransferEvent::Refunded { transfer_id: 0, from: ALICE, amount: 100 }.into(),
+		);
+	})
+}
+
+#[test]
+fn release_due_keeps_transfers_that_cannot_be_refunded() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 100, 6_000);
+		assert_ok!(Assets::freeze_asset(Origin::signed(ALICE), ASSET_ID));
+
+		assert_ok!(TimedTransfer::release_due(Origin::none()));
+
+		assert_eq!(Assets::balance(ASSET_ID, TimedTransfer::account_id()), 100);
+		assert!(TimedTransfer::transfer(0).is_some());
+		assert!(TimedTransfer::queue().is_empty());
+		System::assert_last_event(
+			TimedTransferEvent::ReleaseFailed { transfer_id: 0, from: ALICE }.into(),
+		);
+
+		// The sender gets the transfer back once the asset is thawed.
+		assert_ok!(Assets::thaw_asset(Origin::signed(ALICE), ASSET_ID));
+		assert_ok!(TimedTransfer::cancel(Origin::signed(ALICE), 0));
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 1_000);
+	})
+}
+
+#[test]
+fn schedule_transfer_keeps_the_sender_alive() {
+	new_test_ext().execute_with(|| {
+		let schedule = |keep_alive| {
+			TimedTransfer::schedule_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				ASSET_ID,
+				1_000,
+				6_000,
+				keep_alive,
+			)
+		};
+
+		assert!(schedule(true).is_err());
+		assert!(TimedTransfer::queue().is_empty());
+
+		assert_ok!(schedule(false));
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 0);
+	})
+}
+
+#[test]
+fn legacy_transfer_at_may_empty_the_sender() {
+	new_test_ext().execute_with(|| {
+		transfer_at(BOB, 1_000, 6_000);
+
+		assert_eq!(Assets::balance(ASSET_ID, ALICE), 0);
+		assert_eq!(TimedTransfer::transfer(0).map(|transfer| transfer.amount), Some(1_000));
+	})
+}
+
+#[test]
+fn call_indices_are_stable() {
+	let index = |call: TimedTransferCall<Test>| call.encode()[0];
+
+	assert_eq!(
+		index(TimedTransferCall::transfer_at {
+			to: BOB,
+			asset_id: ASSET_ID,
+			amount: 1,
+			unlock_at: 1,
+		}),
+		0
+	);
+	assert_eq!(index(TimedTransferCall::cancel { transfer_id: 0 }), 1);
+	assert_eq!(index(TimedTransferCall::release_due {}), 2);
+	assert_eq!(
+		index(TimedTransferCall::schedule_transfer {
+			to: BOB,
+			asset_id: ASSET_ID,
+			amount: 1,
+			unlock_at: 1,
+			keep_alive: true,
+		}),
+		3
+	);
+}
@@ -0,0 +1,26 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{tokens::fungibles::Inspect, Time};
+use scale_info::TypeInfo;
+
+pub type TransferId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type BalanceOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::Balance;
+pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+pub type TimedTransferOf<T> =
+	TimedTransfer<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+
+/// An asset transfer, escrowed until its unlock time.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct TimedTransfer<AccountId, AssetId, Balance, Moment> {
+	/// The account that funded the transfer, and can cancel it.
+	pub from: AccountId,
+	/// The account the transfer pays out to.
+	pub to: AccountId,
+	pub asset_id: AssetId,
+	pub amount: Balance,
+	/// The timestamp from which the transfer is released.
+	pub unlock_at: Moment,
+}
@@ -0,0 +1,68 @@
+//! Benchmarks of the timelock pallet.
+//!
+//! The scheduled calls are remarks: `on_initialize` is benchmarked without the weight of the
+//! calls it dispatches.
+
+use super::*;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, Get, Hooks};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+fn remark<T: Config>() -> <T as Config>::Call {
+	frame_system::Call::<T>::remark { remark: Vec::new() }.into()
+}
+
+/// The earliest block calls can be scheduled at.
+fn earliest<T: Config>() -> T::BlockNumber {
+	frame_system::Pallet::<T>::block_number() + T::MinDelay::get()
+}
+
+/// Fund `owner` for `s` scheduling deposits.
+fn fund<T: Config>(owner: &T::AccountId, s: u32) {
+	T::Currency::make_free_balance_be(owner, T::ScheduleDeposit::get() * (s + 10).into());
+}
+
+/// Have `owner` schedule `s` remarks at block `when`.
+fn schedule_remarks<T: Config>(owner: &T::AccountId, when: T::BlockNumber, s: u32) {
+	fund::<T>(owner, s + 1);
+	for _ in 0..s {
+		let call = Box::new(remark::<T>());
+		Pallet::<T>::schedule(RawOrigin::Signed(owner.clone()).into(), when, call).unwrap();
+	}
+}
+
+benchmarks! {
+	schedule {
+		let s in 0 .. T::MaxScheduledPerBlock::get() - 1;
+		let owner: T::AccountId = whitelisted_caller();
+		let when = earliest::<T>();
+		schedule_remarks::<T>(&owner, when, s);
+	}: _(RawOrigin::Signed(owner), when, Box::new(remark::<T>()))
+	verify {
+		assert_eq!(Pallet::<T>::agenda(when).len() as u32, s + 1);
+	}
+
+	cancel {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let owner: T::AccountId = whitelisted_caller();
+		let when = earliest::<T>();
+		schedule_remarks::<T>(&owner, when, s);
+	}: _(RawOrigin::Signed(owner), when, s - 1)
+	verify {
+		assert!(Pallet::<T>::agenda(when)[s as usize - 1].is_none());
+	}
+
+	on_initialize {
+		let s in 0 .. T::MaxScheduledPerBlock::get();
+		let owner: T::AccountId = whitelisted_caller();
+		let when = earliest::<T>();
+		schedule_remarks::<T>(&owner, when, s);
+		frame_system::Pallet::<T>::set_block_number(when);
+	}: { Pallet::<T>::on_initialize(when); }
+	verify {
+		assert!(Pallet::<T>::agenda(when).is_empty());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
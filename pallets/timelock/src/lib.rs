@@ -0,0 +1,320 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Schedule runtime calls for execution at a future block.
+///
+/// A scheduled call is dispatched with the signed origin of the account that scheduled it. Calls
+/// must be scheduled at least `MinDelay` blocks ahead, which gives a window during which they can
+/// be canceled: the basic building block of a timelock.
+///
+/// Calls are stored encoded, in at most `MaxCallSize` bytes, so that the agenda of a block has a
+/// bounded size.
+///
+/// Calls are dispatched in `on_initialize`, which has to report the weight it consumed. Tasks that
+/// would push the block over `MaximumWeight` are postponed to the next block instead, and calls
+/// too heavy to ever fit in a block are rejected when scheduled.
+///
+/// Each scheduled call reserves a deposit from its owner until it is dispatched, canceled or
+/// dropped, so that filling the agendas is not free.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+		traits::{Currency, ReservableCurrency},
+		transactional,
+		weights::extract_actual_weight,
+		CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::StorageDeposits;
+	use sp_runtime::traits::Saturating;
+	use sp_std::prelude::*;
+
+	/// The location of a task: the block it is scheduled at and its index in that block's agenda.
+	pub type TaskAddress<BlockNumber> = (BlockNumber, u32);
+
+	/// A call scheduled by an account.
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		MaxEncodedLen,
+		TypeInfo,
+	)]
+	#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+	#[scale_info(skip_type_params(MaxCallSize))]
+	pub struct Scheduled<AccountId, Balance, MaxCallSize>
+	where
+		AccountId: Clone + Eq + core::fmt::Debug,
+		Balance: Clone + Eq + core::fmt::Debug,
+		MaxCallSize: Get<u32>,
+	{
+		/// The account the call is dispatched as.
+		pub owner: AccountId,
+		/// The scheduled call, encoded so that its size is bounded.
+		pub call: BoundedVec<u8, MaxCallSize>,
+		/// The deposit reserved from the owner.
+		pub deposit: Balance,
+	}
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type ScheduledOf<T> = Scheduled<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as Config>::MaxCallSize,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The calls that can be scheduled.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// The minimum number of blocks between scheduling a call and its execution.
+		#[pallet::constant]
+		type MinDelay: Get<Self::BlockNumber>;
+
+		/// The maximum weight scheduled calls may consume in a block.
+		#[pallet::constant]
+		type MaximumWeight: Get<Weight>;
+
+		/// The maximum number of calls scheduled at the same block.
+		#[pallet::constant]
+		type MaxScheduledPerBlock: Get<u32>;
+
+		/// The maximum size of a scheduled call, encoded.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
+		/// The native currency, used for scheduling deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the owner of each scheduled call, until the call leaves the
+		/// agenda.
+		#[pallet::constant]
+		type ScheduleDeposit: Get<BalanceOf<Self>>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The calls scheduled at each block. Canceled calls leave a `None` so that addresses of the
+	/// other calls stay valid.
+	#[pallet::storage]
+	#[pallet::getter(fn agenda)]
+	pub type Agenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<Option<ScheduledOf<T>>, T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A call has been scheduled.
+		Scheduled { task: TaskAddress<T::BlockNumber>, owner: T::AccountId },
+		/// A scheduled call has been canceled.
+		Canceled { task: TaskAddress<T::BlockNumber> },
+		/// A scheduled call has been dispatched.
+		Dispatched { task: TaskAddress<T::BlockNumber>, result: DispatchResult },
+		/// A scheduled call did not fit in its block and has been moved to the next one.
+		Postponed { from: TaskAddress<T::BlockNumber>, to: TaskAddress<T::BlockNumber> },
+		/// A scheduled call has been dropped, refunding its deposit: the next block is full, or it
+		/// no longer decodes.
+		Dropped { task: TaskAddress<T::BlockNumber> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The call must be scheduled at least `MinDelay` blocks ahead.
+		TooEarly,
+		/// Too many calls are already scheduled at this block.
+		AgendaFull,
+		/// There is no scheduled call at this address.
+		UnknownTask,
+		/// The caller did not schedule this call.
+		NotOwner,
+		/// The call is larger than `MaxCallSize` once encoded.
+		CallTooLarge,
+		/// The call is too heavy to ever fit in the `MaximumWeight` of a block.
+		CallTooHeavy,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let limit = T::MaximumWeight::get();
+			let agenda = Agenda::<T>::take(now);
+			let mut consumed = T::WeightInfo::on_initialize(agenda.len() as u32);
+
+			for (index, maybe_task) in agenda.into_iter().enumerate() {
+				let task = match maybe_task {
+					Some(task) => task,
+					None => continue,
+				};
+				let address = (now, index as u32);
+
+				let call = match <T as Config>::Call::decode(&mut &task.call[..]) {
+					Ok(call) => call,
+					Err(_) => {
+						T::Currency::refund_deposit(&task.owner, task.deposit);
+						Self::deposit_event(Event::Dropped { task: address });
+						continue
+					},
+				};
+				let info = call.get_dispatch_info();
+				if consumed.saturating_add(info.weight) > limit {
+					consumed = consumed.saturating_add(Self::postpone(address, task));
+					continue
+				}
+
+				T::Currency::refund_deposit(&task.owner, task.deposit);
+				let origin = frame_system::RawOrigin::Signed(task.owner).into();
+				let result = call.dispatch(origin);
+				consumed = consumed.saturating_add(extract_actual_weight(&result, &info));
+
+				Self::deposit_event(Event::Dispatched {
+					task: address,
+					result: result.map(|_| ()).map_err(|e| e.error),
+				});
+			}
+
+			consumed
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Schedule `call` to be dispatched, as the caller, at block `when`, reserving the
+		/// `ScheduleDeposit` from them.
+		///
+		/// The call must fit in the `MaximumWeight` of a block on its own.
+		#[pallet::weight(T::WeightInfo::schedule(T::MaxScheduledPerBlock::get()))]
+		#[transactional]
+		pub fn schedule(
+			origin: OriginFor<T>,
+			when: T::BlockNumber,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let earliest =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::MinDelay::get());
+			ensure!(when >= earliest, Error::<T>::TooEarly);
+			let weight = call.get_dispatch_info().weight;
+			ensure!(
+				weight.saturating_add(T::WeightInfo::on_initialize(1)) <= T::MaximumWeight::get(),
+				Error::<T>::CallTooHeavy
+			);
+			let call = call.encode().try_into().map_err(|_| Error::<T>::CallTooLarge)?;
+
+			let index = Agenda::<T>::try_mutate(when, |agenda| -> Result<u32, DispatchError> {
+				let deposit = T::Currency::reserve_deposit(&owner, T::ScheduleDeposit::get())?;
+				agenda
+					.try_push(Some(Scheduled { owner: owner.clone(), call, deposit }))
+					.map_err(|_| Error::<T>::AgendaFull)?;
+				Ok(agenda.len() as u32 - 1)
+			})?;
+
+			Self::deposit_event(Event::Scheduled { task: (when, index), owner });
+
+			Ok(())
+		}
+
+		/// Cancel a call scheduled by the caller, refunding its deposit.
+		#[pallet::weight(T::WeightInfo::cancel(T::MaxScheduledPerBlock::get()))]
+		pub fn cancel(origin: OriginFor<T>, when: T::BlockNumber, index: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let deposit = Agenda::<T>::try_mutate(when, |agenda| -> Result<_, DispatchError> {
+				let slot = agenda.get_mut(index as usize).ok_or(Error::<T>::UnknownTask)?;
+				let task = slot.as_ref().ok_or(Error::<T>::UnknownTask)?;
+				ensure!(task.owner == who, Error::<T>::NotOwner);
+				let deposit = task.deposit;
+				*slot = None;
+				Ok(deposit)
+			})?;
+			T::Currency::refund_deposit(&who, deposit);
+
+			Self::deposit_event(Event::Canceled { task: (when, index) });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Move a task to the end of the next block's agenda, returning the weight consumed.
+		///
+		/// A task that does not fit in the next agenda is dropped, refunding its deposit.
+		fn postpone(from: TaskAddress<T::BlockNumber>, task: ScheduledOf<T>) -> Weight {
+			let next = from.0.saturating_add(1u32.into());
+			let pushed = Agenda::<T>::mutate(next, |agenda| {
+				agenda.try_push(Some(task)).map(|_| agenda.len() as u32 - 1)
+			});
+
+			match pushed {
+				Ok(index) => Self::deposit_event(Event::Postponed { from, to: (next, index) }),
+				Err(task) => {
+					if let Some(task) = task {
+						T::Currency::refund_deposit(&task.owner, task.deposit);
+					}
+					Self::deposit_event(Event::Dropped { task: from });
+				},
+			}
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			// The agenda of a block is taken when it is initialized.
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(Agenda::<T>::iter_keys().all(|when| when >= now), "agenda left in the past");
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,118 @@
+//! Storage migrations of the pallet, run by `on_runtime_upgrade`.
+//!
+//! Each version has its own module, migrating the storage from the previous version. `migrate`
+//! runs the ones the on-chain storage version is behind of, so each runs once.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage from its on-chain version to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+	if on_chain < 3 {
+		weight = weight.saturating_add(v3::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 leaves the storage items unchanged, and records the version on the chains started
+/// before the pallet had one.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().writes(1)
+	}
+}
+
+/// Version 2 stores the scheduled calls encoded, in at most `MaxCallSize` bytes, so that the
+/// size of the agenda is bounded. The calls larger than that are canceled.
+pub mod v2 {
+	use super::*;
+	use crate::Agenda;
+	use codec::{Decode, Encode};
+	use frame_support::{storage::unhashed, BoundedVec};
+	use sp_std::prelude::*;
+
+	/// A scheduled call, as stored by version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldScheduled<Call, AccountId> {
+		pub owner: AccountId,
+		pub call: Call,
+	}
+
+	pub type OldScheduledOf<T> =
+		OldScheduled<<T as Config>::Call, <T as frame_system::Config>::AccountId>;
+	pub type OldAgendaOf<T> =
+		BoundedVec<Option<OldScheduledOf<T>>, <T as Config>::MaxScheduledPerBlock>;
+
+	/// A scheduled call, as stored by version 2: its owner and the call, encoded.
+	pub type ScheduledOf<T> = (
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<u8, <T as Config>::MaxCallSize>,
+	);
+
+	pub fn migrate<T: Config>() -> Weight {
+		// Written in the layout of version 2, which later versions migrate from.
+		let whens = Agenda::<T>::iter_keys().collect::<Vec<_>>();
+		let translated = whens.len() as u64;
+		for when in whens {
+			let key = Agenda::<T>::hashed_key_for(when);
+			let agenda = match unhashed::get::<OldAgendaOf<T>>(&key) {
+				Some(agenda) => agenda,
+				None => continue,
+			};
+			let agenda = agenda.into_iter().map(|maybe_task| {
+				maybe_task.and_then(|task| {
+					let call = task.call.encode().try_into().ok()?;
+					Some((task.owner, call))
+				})
+			});
+			unhashed::put(&key, &agenda.collect::<Vec<Option<ScheduledOf<T>>>>());
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
+
+/// Version 3 records the deposit of each scheduled call.
+///
+/// The calls scheduled before version 3 reserved no deposit, so they record none.
+pub mod v3 {
+	use super::*;
+	use crate::{Agenda, Scheduled};
+	use frame_support::BoundedVec;
+	use sp_runtime::traits::Zero;
+	use sp_std::prelude::*;
+
+	pub type OldAgendaOf<T> =
+		BoundedVec<Option<super::v2::ScheduledOf<T>>, <T as Config>::MaxScheduledPerBlock>;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated = 0u64;
+		Agenda::<T>::translate::<OldAgendaOf<T>, _>(|_, agenda| {
+			translated += 1;
+			let agenda = agenda.into_iter().map(|maybe_task| {
+				maybe_task.map(|(owner, call)| Scheduled { owner, call, deposit: Zero::zero() })
+			});
+			Some(agenda.collect::<Vec<_>>().try_into().expect("the agenda keeps its length"))
+		});
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(translated, translated + 1)
+	}
+}
@@ -0,0 +1,67 @@
+use super::mock::*;
+use crate::{
+	migrations::{
+		self,
+		v2::{self, OldScheduled, OldScheduledOf},
+	},
+	Agenda,
+};
+use codec::Encode;
+use frame_support::{
+	storage::unhashed,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+#[test]
+fn migrate_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Timelock>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(Timelock::on_chain_storage_version(), Timelock::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_v2_encodes_the_scheduled_calls() {
+	new_test_ext().execute_with(|| {
+		let remark = |len| Call::System(frame_system::Call::remark { remark: vec![0; len] });
+		let old: Vec<Option<OldScheduledOf<Test>>> = vec![
+			Some(OldScheduled { owner: ALICE, call: remark(8) }),
+			None,
+			Some(OldScheduled { owner: BOB, call: remark(100) }),
+		];
+		unhashed::put(&Agenda::<Test>::hashed_key_for(6), &old);
+		StorageVersion::new(1).put::<Timelock>();
+
+		migrations::migrate::<Test>();
+
+		let agenda = Timelock::agenda(6);
+		assert_eq!(agenda.len(), 3);
+		assert_eq!(agenda[0].as_ref().map(|task| task.call.to_vec()), Some(remark(8).encode()));
+		assert!(agenda[1].is_none());
+		// Too large to be kept.
+		assert!(agenda[2].is_none());
+		assert_eq!(Timelock::on_chain_storage_version(), Timelock::current_storage_version());
+	})
+}
+
+#[test]
+fn migrate_v3_records_the_deposits() {
+	new_test_ext().execute_with(|| {
+		let call = Call::System(frame_system::Call::remark { remark: vec![0; 8] }).encode();
+		let old: Vec<Option<v2::ScheduledOf<Test>>> =
+			vec![Some((ALICE, call.clone().try_into().unwrap())), None];
+		unhashed::put(&Agenda::<Test>::hashed_key_for(6), &old);
+		StorageVersion::new(2).put::<Timelock>();
+
+		migrations::migrate::<Test>();
+
+		let agenda = Timelock::agenda(6);
+		let task = agenda[0].as_ref().unwrap();
+		assert_eq!((task.owner, task.call.to_vec(), task.deposit), (ALICE, call, 0));
+		assert!(agenda[1].is_none());
+		assert_eq!(Timelock::on_chain_storage_version(), 3);
+	})
+}
@@ -0,0 +1,129 @@
+use super::mock::*;
+use crate::{Error, Event as TimelockEvent, WeightInfo};
+use frame_support::{assert_noop, assert_ok, weights::GetDispatchInfo};
+use test_utils::assert_events;
+
+fn remark() -> Box<Call> {
+	Box::new(Call::System(frame_system::Call::remark_with_event { remark: b"hello".to_vec() }))
+}
+
+#[test]
+fn schedule_respects_the_min_delay() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Timelock::schedule(Origin::signed(ALICE), 5, remark()),
+			Error::<Test>::TooEarly
+		);
+		assert_ok!(Timelock::schedule(Origin::signed(ALICE), 6, remark()));
+
+		assert_eq!(Timelock::agenda(6).len(), 1);
+		System::assert_last_event(TimelockEvent::Scheduled { task: (6, 0), owner: ALICE }.into());
+	})
+}
+
+#[test]
+fn scheduled_call_is_dispatched_at_its_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Timelock::schedule(Origin::signed(ALICE), 6, remark()));
+		assert_events!(Test, [
+			Event::Balances(pallet_balances::Event::Reserved { who: ALICE, amount: 10 }),
+			Event::Timelock(TimelockEvent::Scheduled { .. }),
+		]);
+
+		run_to_block(5);
+		assert_events!(Test, []);
+
+		run_to_block(6);
+		assert_events!(Test, [
+			Event::Balances(pallet_balances::Event::Unreserved { who: ALICE, amount: 10 }),
+			Event::System(frame_system::Event::Remarked { sender: ALICE, .. }),
+			Event::Timelock(TimelockEvent::Dispatched { task: (6, 0), result: Ok(()) }),
+		]);
+		assert!(Timelock::agenda(6).is_empty());
+	})
+}
+
+#[test]
+fn owner_can_cancel_before_execution() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Timelock::schedule(Origin::signed(ALICE), 6, remark()));
+
+		assert_noop!(Timelock::cancel(Origin::signed(BOB), 6, 0), Error::<Test>::NotOwner);
+		assert_ok!(Timelock::cancel(Origin::signed(ALICE), 6, 0));
+		assert_noop!(Timelock::cancel(Origin::signed(ALICE), 6, 0), Error::<Test>::UnknownTask);
+		assert_events!(Test, [
+			Event::Balances(pallet_balances::Event::Reserved { .. }),
+			Event::Timelock(TimelockEvent::Scheduled { .. }),
+			Event::Balances(pallet_balances::Event::Unreserved { who: ALICE, amount: 10 }),
+			Event::Timelock(TimelockEvent::Canceled { .. }),
+		]);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+		run_to_block(6);
+		assert_events!(Test, []);
+	})
+}
+
+#[test]
+fn scheduled_call_size_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(Call::System(frame_system::Call::remark { remark: vec![0; 64] }));
+
+		assert_noop!(
+			Timelock::schedule(Origin::signed(ALICE), 6, call),
+			Error::<Test>::CallTooLarge
+		);
+	})
+}
+
+#[test]
+fn agenda_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Timelock::schedule(Origin::signed(ALICE), 6, remark()));
+		assert_ok!(Timelock::schedule(Origin::signed(BOB), 6, remark()));
+
+		assert_noop!(
+			Timelock::schedule(Origin::signed(ALICE), 6, remark()),
+			Error::<Test>::AgendaFull
+		);
+	})
+}
+
+#[test]
+fn scheduling_reserves_a_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Timelock::schedule(Origin::signed(ALICE), 6, remark()));
+		assert_ok!(Timelock::schedule(Origin::signed(ALICE), 7, remark()));
+		assert_eq!(Balances::reserved_balance(ALICE), 20);
+
+		run_to_block(6);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(Timelock::agenda(7)[0].as_ref().map(|task| task.deposit), Some(10));
+	})
+}
+
+#[test]
+fn scheduling_requires_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Timelock::schedule(Origin::signed(3), 6, remark()),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	})
+}
+
+#[test]
+fn schedule_rejects_calls_heavier_than_a_block() {
+	new_test_ext().execute_with(|| {
+		let weight = remark().get_dispatch_info().weight;
+		MaximumWeight::set(weight + <() as WeightInfo>::on_initialize(1) - 1);
+
+		assert_noop!(
+			Timelock::schedule(Origin::signed(ALICE), 6, remark()),
+			Error::<Test>::CallTooHeavy
+		);
+
+		MaximumWeight::set(weight + <() as WeightInfo>::on_initialize(1));
+		assert_ok!(Timelock::schedule(Origin::signed(ALICE), 6, remark()));
+	})
+}
@@ -0,0 +1,61 @@
+//! Benchmarks of the treasury pallet.
+//!
+//! Proposals spend the native currency.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance() * 1_000_000u32.into()
+}
+
+fn funded<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, T::ProposalBond::get() + amount::<T>());
+}
+
+/// Have `proposer` propose to spend from the funded treasury, returning the proposal id.
+fn new_proposal<T: Config>(proposer: &T::AccountId) -> ProposalId {
+	funded::<T>(proposer);
+	funded::<T>(&Pallet::<T>::account_id());
+	let proposal_id = Pallet::<T>::next_proposal_id();
+	Pallet::<T>::propose_spend(
+		RawOrigin::Signed(proposer.clone()).into(),
+		Funds::Native,
+		amount::<T>(),
+		account("beneficiary", 0, 0),
+	)
+	.unwrap();
+	proposal_id
+}
+
+benchmarks! {
+	propose_spend {
+		let proposer: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		funded::<T>(&proposer);
+		let proposal_id = Pallet::<T>::next_proposal_id();
+	}: _(RawOrigin::Signed(proposer), Funds::Native, amount::<T>(), beneficiary)
+	verify {
+		assert!(Pallet::<T>::proposal(proposal_id).is_some());
+	}
+
+	approve_proposal {
+		let proposal_id = new_proposal::<T>(&account("proposer", 0, 0));
+		let origin = T::ApproveOrigin::successful_origin();
+	}: _<T::Origin>(origin, proposal_id)
+	verify {
+		assert!(Pallet::<T>::proposal(proposal_id).is_none());
+	}
+
+	reject_proposal {
+		let proposal_id = new_proposal::<T>(&account("proposer", 0, 0));
+		let origin = T::ApproveOrigin::successful_origin();
+	}: _<T::Origin>(origin, proposal_id)
+	verify {
+		assert!(Pallet::<T>::proposal(proposal_id).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
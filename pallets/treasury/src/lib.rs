@@ -0,0 +1,282 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A treasury collecting the fees of the tutorial pallets.
+///
+/// The treasury is a keyless account derived from the pallet id. Fee-charging pallets send their
+/// native or asset fees to [`Pallet::account_id`], or hand their native fees over to the pallet,
+/// as the `OnUnbalanced` handler of their imbalances. Anyone can propose to spend part of those funds
+/// by putting up a bond; the approve origin then either pays the proposal out or rejects it, in
+/// which case the bond is slashed into the treasury.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	pub use crate::types::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			tokens::fungibles, Currency, ExistenceRequirement::AllowDeath, Imbalance,
+			OnUnbalanced, ReservableCurrency,
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::StorageDeposits;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The native currency, used for bonds and native spends.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The fungible assets the treasury can hold and spend.
+		type Assets: fungibles::Transfer<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// The origin allowed to approve or reject spend proposals.
+		type ApproveOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The bond reserved from a proposer until their proposal is decided.
+		#[pallet::constant]
+		type ProposalBond: Get<BalanceOf<Self>>;
+
+		/// The pallet id, used to derive the treasury account.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The id the next proposal will get.
+	#[pallet::storage]
+	#[pallet::getter(fn next_proposal_id)]
+	pub type NextProposalId<T> = StorageValue<_, ProposalId, ValueQuery>;
+
+	/// The spend proposals awaiting a decision.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal)]
+	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, ProposalId, ProposalOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A spend has been proposed.
+		Proposed {
+			proposal_id: ProposalId,
+			proposer: T::AccountId,
+			beneficiary: T::AccountId,
+			funds: FundsOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// A proposal has been approved and paid out.
+		Spent {
+			proposal_id: ProposalId,
+			beneficiary: T::AccountId,
+			funds: FundsOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// A proposal has been rejected and its bond slashed.
+		Rejected { proposal_id: ProposalId, slashed: BalanceOf<T> },
+		/// Fees have been deposited into the treasury.
+		Deposited { amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The proposal does not exist.
+		UnknownProposal,
+		/// The spent amount must be non zero.
+		ZeroAmount,
+		/// The proposer cannot afford the bond.
+		InsufficientBond,
+		/// There is no proposal id left.
+		NoAvailableProposalId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose to spend `amount` of `funds` from the treasury to `beneficiary`.
+		///
+		/// Reserves `ProposalBond` from the caller until the proposal is decided.
+		#[pallet::weight(T::WeightInfo::propose_spend())]
+		pub fn propose_spend(
+			origin: OriginFor<T>,
+			funds: FundsOf<T>,
+			amount: BalanceOf<T>,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let proposal_id = Self::next_proposal_id();
+			let next_id = proposal_id.checked_add(1).ok_or(Error::<T>::NoAvailableProposalId)?;
+
+			let bond = T::Currency::reserve_deposit(&proposer, T::ProposalBond::get())
+				.map_err(|_| Error::<T>::InsufficientBond)?;
+
+			Proposals::<T>::insert(
+				proposal_id,
+				Proposal {
+					proposer: proposer.clone(),
+					beneficiary: beneficiary.clone(),
+					funds,
+					amount,
+					bond,
+				},
+			);
+			NextProposalId::<T>::put(next_id);
+
+			Self::deposit_event(Event::Proposed {
+				proposal_id,
+				proposer,
+				beneficiary,
+				funds,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Approve a proposal: pay it out and give the bond back to the proposer.
+		#[pallet::weight(T::WeightInfo::approve_proposal())]
+		pub fn approve_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let proposal = Self::proposal(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+
+			let treasury = Self::account_id();
+			match proposal.funds {
+				Funds::Native => T::Currency::transfer(
+					&treasury,
+					&proposal.beneficiary,
+					proposal.amount,
+					AllowDeath,
+				)?,
+				Funds::Asset(asset_id) => {
+					T::Assets::transfer(
+						asset_id,
+						&treasury,
+						&proposal.beneficiary,
+						proposal.amount,
+						false,
+					)?;
+				},
+			}
+
+			T::Currency::refund_deposit(&proposal.proposer, proposal.bond);
+			Proposals::<T>::remove(proposal_id);
+
+			Self::deposit_event(Event::Spent {
+				proposal_id,
+				beneficiary: proposal.beneficiary,
+				funds: proposal.funds,
+				amount: proposal.amount,
+			});
+
+			Ok(())
+		}
+
+		/// Reject a proposal, slashing its bond into the treasury.
+		#[pallet::weight(T::WeightInfo::reject_proposal())]
+		pub fn reject_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let proposal = Proposals::<T>::take(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+
+			let imbalance = T::Currency::slash_deposit(&proposal.proposer, proposal.bond);
+			let slashed = imbalance.peek();
+			T::Currency::resolve_creating(&Self::account_id(), imbalance);
+
+			Self::deposit_event(Event::Rejected { proposal_id, slashed });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
+		fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+			let deposited = amount.peek();
+			T::Currency::resolve_creating(&Self::account_id(), amount);
+
+			Self::deposit_event(Event::Deposited { amount: deposited });
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The treasury account, where fees are collected.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// The native balance available for spending.
+		pub fn pot() -> BalanceOf<T> {
+			T::Currency::free_balance(&Self::account_id())
+				.saturating_sub(T::Currency::minimum_balance())
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			use frame_support::sp_std::vec::Vec;
+
+			let next_id = Self::next_proposal_id();
+			ensure!(
+				Proposals::<T>::iter_keys().all(|id| id < next_id),
+				"proposal id not allocated"
+			);
+
+			// The bond of every proposal is reserved from its proposer.
+			let mut bonds: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+			for proposal in Proposals::<T>::iter_values() {
+				match bonds.iter_mut().find(|(proposer, _)| *proposer == proposal.proposer) {
+					Some((_, total)) => *total = total.saturating_add(proposal.bond),
+					None => bonds.push((proposal.proposer, proposal.bond)),
+				}
+			}
+			for (proposer, total) in bonds {
+				ensure!(
+					T::Currency::reserved_balance(&proposer) >= total,
+					"proposal bond not reserved"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,23 @@
+use super::mock::*;
+use crate::Event as TreasuryEvent;
+use frame_support::traits::{Currency, ExistenceRequirement, OnUnbalanced, WithdrawReasons};
+
+#[test]
+fn unbalanced_fees_are_deposited_into_the_treasury() {
+	new_test_ext().execute_with(|| {
+		let fee = Balances::withdraw(
+			&ALICE,
+			30,
+			WithdrawReasons::FEE,
+			ExistenceRequirement::KeepAlive,
+		)
+		.unwrap();
+
+		Treasury::on_unbalanced(fee);
+
+		assert_eq!(Balances::free_balance(ALICE), 70);
+		assert_eq!(Balances::free_balance(Treasury::account_id()), 530);
+		assert_eq!(Balances::total_issuance(), 700);
+		System::assert_last_event(TreasuryEvent::Deposited { amount: 30 }.into());
+	})
+}
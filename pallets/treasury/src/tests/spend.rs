@@ -0,0 +1,78 @@
+use super::mock::*;
+use crate::{Error, Event as TreasuryEvent, Funds};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn propose_spend_reserves_the_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Treasury::propose_spend(Origin::signed(ALICE), Funds::Native, 50, BOB));
+
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert!(Treasury::proposal(0).is_some());
+		assert_eq!(Treasury::next_proposal_id(), 1);
+	})
+}
+
+#[test]
+fn propose_spend_requires_the_bond() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Treasury::propose_spend(Origin::signed(42), Funds::Native, 50, BOB),
+			Error::<Test>::InsufficientBond
+		);
+	})
+}
+
+#[test]
+fn approved_native_spend_is_paid_out() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Treasury::propose_spend(Origin::signed(ALICE), Funds::Native, 50, BOB));
+
+		assert_noop!(Treasury::approve_proposal(Origin::signed(ALICE), 0), DispatchError::BadOrigin);
+		assert_ok!(Treasury::approve_proposal(Origin::root(), 0));
+
+		assert_eq!(Balances::free_balance(BOB), 150);
+		assert_eq!(Treasury::pot(), 449);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert!(Treasury::proposal(0).is_none());
+	})
+}
+
+#[test]
+fn approved_asset_spend_is_paid_out() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Treasury::propose_spend(
+			Origin::signed(ALICE),
+			Funds::Asset(ASSET_ID),
+			300,
+			BOB
+		));
+		assert_ok!(Treasury::approve_proposal(Origin::root(), 0));
+
+		assert_eq!(Assets::balance(ASSET_ID, BOB), 300);
+		assert_eq!(Assets::balance(ASSET_ID, Treasury::account_id()), 700);
+		System::assert_last_event(
+			TreasuryEvent::Spent {
+				proposal_id: 0,
+				beneficiary: BOB,
+				funds: Funds::Asset(ASSET_ID),
+				amount: 300,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn rejected_proposal_bond_goes_to_the_treasury() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Treasury::propose_spend(Origin::signed(ALICE), Funds::Native, 50, BOB));
+		assert_ok!(Treasury::reject_proposal(Origin::root(), 0));
+
+		assert_eq!(Balances::free_balance(ALICE), 90);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(Treasury::account_id()), 510);
+		System::assert_last_event(TreasuryEvent::Rejected { proposal_id: 0, slashed: 10 }.into());
+	})
+}
@@ -0,0 +1,38 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{tokens::fungibles::Inspect, Currency};
+use scale_info::TypeInfo;
+
+pub type ProposalId = u32;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+pub type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<AccountIdOf<T>>>::AssetId;
+pub type FundsOf<T> = Funds<AssetIdOf<T>>;
+pub type ProposalOf<T> = Proposal<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>>;
+
+/// The kind of funds a proposal spends.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum Funds<AssetId> {
+	/// The native currency.
+	Native,
+	/// A fungible asset.
+	Asset(AssetId),
+}
+
+/// A spend awaiting a decision.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub struct Proposal<AccountId, AssetId, Balance> {
+	/// The account that proposed the spend and put up the bond.
+	pub proposer: AccountId,
+	/// The account receiving the funds.
+	pub beneficiary: AccountId,
+	/// The kind of funds spent.
+	pub funds: Funds<AssetId>,
+	/// The amount spent.
+	pub amount: Balance,
+	/// The bond reserved from the proposer.
+	pub bond: Balance,
+}
@@ -0,0 +1,44 @@
+//! Benchmarks of the unlock-queue pallet.
+
+use super::*;
+use codec::Decode;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{Get, Hooks};
+use sp_runtime::traits::{One, TrailingZeroInput};
+
+/// The key decoded from zeroes: any key does, as the weight of the unlocks themselves is the
+/// business of `OnUnlock`.
+fn key<T: Config>() -> T::Key {
+	T::Key::decode(&mut TrailingZeroInput::new(&[][..])).unwrap()
+}
+
+benchmarks! {
+	// No unlock is due.
+	on_initialize {
+		let now = frame_system::Pallet::<T>::block_number();
+		Head::<T>::put(now);
+	}: {
+		Pallet::<T>::on_initialize(now);
+	}
+	verify {
+		assert_eq!(Pallet::<T>::head(), Some(now + One::one()));
+	}
+
+	// `n` unlocks are due in the block.
+	service_block {
+		let n in 0 .. T::MaxUnlocksPerBlock::get();
+		let now = frame_system::Pallet::<T>::block_number();
+		let at = now + One::one();
+		for _ in 0..n {
+			<Pallet<T> as ScheduleUnlock<_, _>>::schedule(at, key::<T>())?;
+		}
+		Head::<T>::put(at);
+	}: {
+		Pallet::<T>::on_initialize(at);
+	}
+	verify {
+		assert_eq!(Pallet::<T>::head(), Some(at + One::one()));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
@@ -0,0 +1,183 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Run the unlocks other pallets schedule at a block, such as the end of a lease.
+///
+/// Pallets schedule unlocks through [`ScheduleUnlock`], under a key of their own which the runtime
+/// converts into its `Key`. When the block comes, `on_initialize` hands the key to `OnUnlock`,
+/// which the runtime routes back to the pallet that scheduled it. So the pallets do not each scan
+/// for what expires in their own hook, and all of them share a single weight limit.
+///
+/// Unlocks run in the order they were scheduled in, as long as they fit in `MaximumWeight`. The
+/// ones that do not fit stay queued, and run first in the next block.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use rusty_node_support::{OnUnlock, ScheduleUnlock};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::{OnUnlock, ScheduleUnlock};
+	use sp_runtime::traits::{One, Saturating};
+	use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The key of an unlock, telling which pallet scheduled it and what to unlock.
+		type Key: Member + Parameter + MaxEncodedLen;
+
+		/// Run an unlock once it is due.
+		type OnUnlock: OnUnlock<Self::Key>;
+
+		/// The maximum weight unlocks may consume in a block.
+		#[pallet::constant]
+		type MaximumWeight: Get<Weight>;
+
+		/// The maximum number of unlocks scheduled at the same block.
+		#[pallet::constant]
+		type MaxUnlocksPerBlock: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The unlocks scheduled at each block, in the order they were scheduled in.
+	#[pallet::storage]
+	#[pallet::getter(fn queue)]
+	pub type Queue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::Key, T::MaxUnlocksPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The first block whose unlocks may not all have run.
+	#[pallet::storage]
+	#[pallet::getter(fn head)]
+	pub type Head<T: Config> = StorageValue<_, T::BlockNumber>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An unlock scheduled at the block `at` has run.
+		Unlocked { at: T::BlockNumber, key: T::Key },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Unlocks must be scheduled at a future block.
+		InThePast,
+		/// Too many unlocks are already scheduled at this block.
+		QueueFull,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let limit = T::MaximumWeight::get();
+			let mut consumed = T::WeightInfo::on_initialize();
+			let mut ran = 0u32;
+			let mut head = Self::head().unwrap_or(now);
+
+			while head <= now && consumed < limit {
+				let mut due = Queue::<T>::take(head).into_inner();
+				consumed = consumed.saturating_add(T::WeightInfo::service_block(due.len() as u32));
+
+				let mut index = 0;
+				while index < due.len() {
+					// The first unlock always runs, so that one heavier than the limit does not
+					// stall the queue.
+					let weight = T::OnUnlock::unlock_weight(&due[index]);
+					if ran > 0 && consumed.saturating_add(weight) > limit {
+						break
+					}
+					let key = due[index].clone();
+					consumed = consumed.saturating_add(T::OnUnlock::on_unlock(key.clone()));
+					Self::deposit_event(Event::Unlocked { at: head, key });
+					ran += 1;
+					index += 1;
+				}
+
+				if index < due.len() {
+					// Fewer keys than were taken out of the queue, so they fit back in.
+					if let Ok(rest) = BoundedVec::try_from(due.split_off(index)) {
+						Queue::<T>::insert(head, rest);
+					}
+					break
+				}
+				head = head.saturating_add(One::one());
+			}
+
+			Head::<T>::put(head);
+			consumed
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	impl<T: Config, K: Into<T::Key>> ScheduleUnlock<T::BlockNumber, K> for Pallet<T> {
+		fn schedule(at: T::BlockNumber, key: K) -> DispatchResult {
+			ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::InThePast);
+			Queue::<T>::try_mutate(at, |queue| queue.try_push(key.into()))
+				.map_err(|_| Error::<T>::QueueFull)?;
+			Ok(())
+		}
+
+		fn cancel(at: T::BlockNumber, key: K) {
+			let key = key.into();
+			Queue::<T>::mutate_exists(at, |maybe_queue| {
+				if let Some(queue) = maybe_queue {
+					queue.retain(|queued| queued != &key);
+				}
+				if maybe_queue.as_ref().map_or(false, |queue| queue.is_empty()) {
+					*maybe_queue = None;
+				}
+			});
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			ensure!(Queue::<T>::iter_values().all(|queue| !queue.is_empty()), "empty queue stored");
+			if let Some(head) = Self::head() {
+				ensure!(
+					Queue::<T>::iter_keys().all(|at| at >= head),
+					"unlock queued before the head"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,98 @@
+use crate as pallet_unlock_queue;
+use frame_support::{parameter_types, weights::Weight};
+use frame_system as system;
+use rusty_node_support::OnUnlock;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use test_utils::ExtBuilder;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		UnlockQueue: pallet_unlock_queue::{Pallet, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type AccountData = ();
+	type AccountId = u64;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	/// Room for two unlocks in a block, along with the queue's own accesses.
+	pub const MaximumWeight: Weight = 2_700_000_000;
+	pub const MaxUnlocksPerBlock: u32 = 4;
+	pub const UnlockWeight: Weight = 1_000_000_000;
+	/// The keys unlocked so far, in order.
+	pub static Unlocked: Vec<u32> = vec![];
+}
+
+/// Record the unlocked keys.
+pub struct RecordUnlocks;
+impl OnUnlock<u32> for RecordUnlocks {
+	fn unlock_weight(_key: &u32) -> Weight {
+		UnlockWeight::get()
+	}
+
+	fn on_unlock(key: u32) -> Weight {
+		let mut unlocked = Unlocked::get();
+		unlocked.push(key);
+		Unlocked::set(unlocked);
+		UnlockWeight::get()
+	}
+}
+
+impl pallet_unlock_queue::Config for Test {
+	type Event = Event;
+	type Key = u32;
+	type MaxUnlocksPerBlock = MaxUnlocksPerBlock;
+	type MaximumWeight = MaximumWeight;
+	type OnUnlock = RecordUnlocks;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Test>::default().build()
+}
+
+pub fn run_to_block(n: u64) {
+	test_utils::run_to_block::<Test, UnlockQueue>(n)
+}
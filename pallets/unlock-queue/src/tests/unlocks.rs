@@ -0,0 +1,80 @@
+use super::mock::*;
+use crate::{Error, Event as UnlockEvent, ScheduleUnlock};
+use frame_support::{assert_noop, assert_ok};
+
+fn schedule(at: u64, key: u32) -> frame_support::dispatch::DispatchResult {
+	<UnlockQueue as ScheduleUnlock<u64, u32>>::schedule(at, key)
+}
+
+#[test]
+fn unlocks_run_at_their_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(schedule(3, 1));
+		assert_ok!(schedule(2, 2));
+		assert_ok!(schedule(3, 3));
+
+		run_to_block(2);
+		assert_eq!(Unlocked::get(), vec![2]);
+		System::assert_last_event(UnlockEvent::Unlocked { at: 2, key: 2 }.into());
+
+		run_to_block(3);
+		assert_eq!(Unlocked::get(), vec![2, 1, 3]);
+		assert!(UnlockQueue::queue(3).is_empty());
+		assert_eq!(UnlockQueue::head(), Some(4));
+	})
+}
+
+#[test]
+fn unlocks_are_scheduled_in_the_future() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(schedule(1, 1), Error::<Test>::InThePast);
+		assert_noop!(schedule(0, 1), Error::<Test>::InThePast);
+	})
+}
+
+#[test]
+fn unlocks_per_block_are_bounded() {
+	new_test_ext().execute_with(|| {
+		for key in 0..MaxUnlocksPerBlock::get() {
+			assert_ok!(schedule(2, key));
+		}
+		assert_noop!(schedule(2, 42), Error::<Test>::QueueFull);
+		assert_ok!(schedule(3, 42));
+	})
+}
+
+#[test]
+fn canceled_unlocks_do_not_run() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(schedule(2, 1));
+		assert_ok!(schedule(2, 2));
+
+		<UnlockQueue as ScheduleUnlock<u64, u32>>::cancel(2, 1);
+		assert_eq!(UnlockQueue::queue(2).into_inner(), vec![2]);
+		<UnlockQueue as ScheduleUnlock<u64, u32>>::cancel(2, 2);
+		assert!(!crate::Queue::<Test>::contains_key(2));
+
+		run_to_block(2);
+		assert!(Unlocked::get().is_empty());
+	})
+}
+
+#[test]
+fn unlocks_over_the_weight_limit_run_in_the_next_block() {
+	new_test_ext().execute_with(|| {
+		for key in 1..=3 {
+			assert_ok!(schedule(2, key));
+		}
+		assert_ok!(schedule(3, 4));
+
+		run_to_block(2);
+		assert_eq!(Unlocked::get(), vec![1, 2]);
+		assert_eq!(UnlockQueue::queue(2).into_inner(), vec![3]);
+		assert_eq!(UnlockQueue::head(), Some(2));
+
+		// The late unlock runs first.
+		run_to_block(3);
+		assert_eq!(Unlocked::get(), vec![1, 2, 3, 4]);
+		assert_eq!(UnlockQueue::head(), Some(4));
+	})
+}
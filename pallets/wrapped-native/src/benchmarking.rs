@@ -0,0 +1,64 @@
+//! Benchmarks of the wrapped-native pallet.
+
+use super::*;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{
+	tokens::fungibles::{approvals, metadata, Create, Inspect},
+	Currency, Get,
+};
+use frame_system::RawOrigin;
+use rusty_node_support::benchmarking::helpers::{populate_asset, AssetShape};
+use sp_runtime::traits::Zero;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance() * 1_000u32.into()
+}
+
+/// Create the wrapped asset, in its worst-case shape, unless it exists, and fund `who` with the
+/// native currency.
+fn setup<T: Config>(who: &T::AccountId)
+where
+	T::Assets: Create<T::AccountId>
+		+ approvals::Mutate<T::AccountId>
+		+ metadata::Mutate<T::AccountId>,
+{
+	let asset_id = T::WrappedAssetId::get();
+	if T::Assets::minimum_balance(asset_id).is_zero() {
+		populate_asset::<_, T::Currency, T::Assets>(
+			asset_id,
+			&Pallet::<T>::account_id(),
+			amount::<T>(),
+			AssetShape::WORST_CASE,
+		)
+		.unwrap();
+	}
+	T::Currency::make_free_balance_be(who, amount::<T>() * 10u32.into());
+}
+
+benchmarks! {
+	where_clause { where
+		T::Assets: Create<T::AccountId>
+			+ approvals::Mutate<T::AccountId>
+			+ metadata::Mutate<T::AccountId>,
+	}
+
+	wrap {
+		let who: T::AccountId = whitelisted_caller();
+		setup::<T>(&who);
+	}: _(RawOrigin::Signed(who.clone()), amount::<T>())
+	verify {
+		assert_eq!(T::Assets::balance(T::WrappedAssetId::get(), &who), amount::<T>());
+	}
+
+	unwrap {
+		let who: T::AccountId = whitelisted_caller();
+		setup::<T>(&who);
+		Pallet::<T>::wrap(RawOrigin::Signed(who.clone()).into(), amount::<T>() * 2u32.into())
+			.unwrap();
+	}: _(RawOrigin::Signed(who.clone()), amount::<T>())
+	verify {
+		assert_eq!(T::Assets::balance(T::WrappedAssetId::get(), &who), amount::<T>());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::tests::mock::new_test_ext(), crate::tests::mock::Test);
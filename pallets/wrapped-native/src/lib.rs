@@ -0,0 +1,166 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Wrap the native currency into a fungible asset, 1:1.
+///
+/// Wrapping moves native currency into the pallet account and mints the same amount of the
+/// wrapped asset; unwrapping burns it and releases the native currency. Pallets working with
+/// fungible assets, like the DEX or the marketplace, can then handle the native currency as just
+/// another asset.
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			tokens::fungibles, Currency,
+			ExistenceRequirement::{AllowDeath, KeepAlive},
+		},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use rusty_node_support::{Pausable, PauseGuard};
+	use sp_runtime::traits::{AccountIdConversion, Zero};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The native currency.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The fungible assets the wrapped asset is minted in.
+		type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// The id of the wrapped native asset.
+		#[pallet::constant]
+		type WrappedAssetId: Get<AssetIdOf<Self>>;
+
+		/// The pallet id, used to derive the account holding the wrapped native currency.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The registry pausing the calls of the pallet.
+		type Pauses: Pausable;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The storage version of the pallet, bumped by each of its migrations.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Native currency has been wrapped, leaving `balance` of the wrapped asset to `who`.
+		Wrapped { who: T::AccountId, amount: BalanceOf<T>, balance: BalanceOf<T> },
+		/// Wrapped asset has been turned back into native currency, leaving `balance` of the
+		/// wrapped asset to `who`.
+		Unwrapped { who: T::AccountId, amount: BalanceOf<T>, balance: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The amount must be non zero.
+		ZeroAmount,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			rusty_node_support::migrations::init_storage_version::<Self>(T::DbWeight::get())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock `amount` of native currency and mint as much wrapped asset to the caller.
+		#[pallet::weight(T::WeightInfo::wrap())]
+		#[transactional]
+		pub fn wrap(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::transfer(&who, &Self::account_id(), amount, KeepAlive)?;
+			T::Assets::mint_into(T::WrappedAssetId::get(), &who, amount)?;
+
+			let balance = T::Assets::balance(T::WrappedAssetId::get(), &who);
+			Self::deposit_event(Event::Wrapped { who, amount, balance });
+
+			Ok(())
+		}
+
+		/// Burn `amount` of the caller's wrapped asset and release as much native currency.
+		#[pallet::weight(T::WeightInfo::unwrap())]
+		#[transactional]
+		pub fn unwrap(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let amount = T::Assets::burn_from(T::WrappedAssetId::get(), &who, amount)?;
+			T::Currency::transfer(&Self::account_id(), &who, amount, AllowDeath)?;
+
+			let balance = T::Assets::balance(T::WrappedAssetId::get(), &who);
+			Self::deposit_event(Event::Unwrapped { who, amount, balance });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> PauseGuard for Pallet<T> {
+		type Pauses = T::Pauses;
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the wrapped native currency.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Check the invariants of the pallet storage.
+		#[cfg(feature = "try-runtime")]
+		pub fn try_state() -> Result<(), &'static str> {
+			// Every unit of the wrapped asset is backed by a unit of the locked currency.
+			ensure!(
+				T::Currency::free_balance(&Self::account_id()) >=
+					T::Assets::total_issuance(T::WrappedAssetId::get()),
+				"wrapped asset not backed"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,73 @@
+use super::mock::*;
+use crate::{Error, Event as WrappedEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn wrap_locks_native_and_mints_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WrappedNative::wrap(Origin::signed(ALICE), 40));
+
+		assert_eq!(Balances::free_balance(ALICE), 60);
+		assert_eq!(Balances::free_balance(WrappedNative::account_id()), 40);
+		assert_eq!(Assets::balance(WRAPPED, ALICE), 40);
+		System::assert_last_event(
+			WrappedEvent::Wrapped { who: ALICE, amount: 40, balance: 40 }.into(),
+		);
+	})
+}
+
+#[test]
+fn wrap_keeps_the_caller_alive() {
+	new_test_ext().execute_with(|| {
+		assert!(WrappedNative::wrap(Origin::signed(ALICE), 100).is_err());
+		assert_eq!(Assets::balance(WRAPPED, ALICE), 0);
+	})
+}
+
+#[test]
+fn unwrap_burns_asset_and_releases_native() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WrappedNative::wrap(Origin::signed(ALICE), 40));
+		assert_ok!(Assets::transfer(Origin::signed(ALICE), WRAPPED, BOB, 15));
+
+		assert_ok!(WrappedNative::unwrap(Origin::signed(BOB), 15));
+
+		assert_eq!(Balances::free_balance(BOB), 115);
+		assert_eq!(Balances::free_balance(WrappedNative::account_id()), 25);
+		assert_eq!(Assets::balance(WRAPPED, BOB), 0);
+		System::assert_last_event(
+			WrappedEvent::Unwrapped { who: BOB, amount: 15, balance: 0 }.into(),
+		);
+	})
+}
+
+#[test]
+fn unwrap_cannot_exceed_wrapped_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WrappedNative::wrap(Origin::signed(ALICE), 40));
+
+		assert!(WrappedNative::unwrap(Origin::signed(BOB), 10).is_err());
+		assert_noop!(WrappedNative::unwrap(Origin::signed(ALICE), 0), Error::<Test>::ZeroAmount);
+	})
+}
+
+#[test]
+fn paused_calls_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(WrappedNative::wrap(Origin::signed(ALICE), 40));
+		Paused::set(true);
+
+		assert_noop!(
+			WrappedNative::wrap(Origin::signed(ALICE), 10),
+			DispatchError::Other("paused")
+		);
+		assert_noop!(
+			WrappedNative::unwrap(Origin::signed(ALICE), 10),
+			DispatchError::Other("paused")
+		);
+
+		Paused::set(false);
+		assert_ok!(WrappedNative::unwrap(Origin::signed(ALICE), 10));
+	})
+}
@@ -0,0 +1,217 @@
+use cumulus_primitives_core::ParaId;
+use rusty_parachain_runtime::{
+	AccountId, AssetId, AssetsConfig, AuraConfig, BalancesConfig, GenesisConfig,
+	ParachainInfoConfig, PolkadotXcmConfig, Signature, SudoConfig, SystemConfig, WASM_BINARY,
+};
+use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
+use sc_service::{ChainType, Properties};
+use serde::{Deserialize, Serialize};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_core::{sr25519, Pair, Public};
+use sp_runtime::traits::{IdentifyAccount, Verify};
+
+/// Specialized `ChainSpec` for the parachain, with the [`Extensions`] of a parachain.
+pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig, Extensions>;
+
+/// The XCM version the parachain speaks to chains that did not advertise theirs.
+const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
+
+/// The demo token, created by the presets.
+pub const DEMO_ASSET_ID: AssetId = 1;
+
+/// Where a parachain runs: the relay chain, and its id there.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ChainSpecGroup, ChainSpecExtension)]
+#[serde(deny_unknown_fields)]
+pub struct Extensions {
+	/// The id of the relay chain, e.g. `rococo-local`.
+	pub relay_chain: String,
+	/// The id of the parachain on the relay chain.
+	pub para_id: u32,
+}
+
+impl Extensions {
+	/// The extensions of a chain spec.
+	pub fn try_get(chain_spec: &dyn sc_service::ChainSpec) -> Option<&Self> {
+		sc_chain_spec::get_extension(chain_spec.extensions())
+	}
+}
+
+/// Generate a crypto pair from seed.
+pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
+	TPublic::Pair::from_string(&format!("//{}", seed), None)
+		.expect("static values are valid; qed")
+		.public()
+}
+
+type AccountPublic = <Signature as Verify>::Signer;
+
+/// Generate an account ID from seed.
+pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
+where
+	AccountPublic: From<<TPublic::Pair as Pair>::Public>,
+{
+	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+}
+
+/// The well-known development accounts, `//Alice` to `//Ferdie` and their stashes.
+pub fn dev_accounts() -> Vec<AccountId> {
+	["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"]
+		.iter()
+		.flat_map(|name| [name.to_string(), format!("{}//stash", name)])
+		.map(|seed| get_account_id_from_seed::<sr25519::Public>(&seed))
+		.collect()
+}
+
+/// The token symbol, decimals and address format polkadot-js displays.
+fn chain_properties() -> Properties {
+	let mut properties = Properties::new();
+	properties.insert("tokenSymbol".into(), "UNIT".into());
+	properties.insert("tokenDecimals".into(), 12.into());
+	properties.insert("ss58Format".into(), 42.into());
+	properties
+}
+
+/// The named genesis presets of the parachain, each running on a local relay chain. They are
+/// selected by id with `--chain`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Preset {
+	/// A single collator, Alice, with the id 2000 on `rococo-local`.
+	Development,
+	/// Two collators, Alice and Bob, with the id 2000 on `rococo-local`.
+	LocalTestnet,
+	/// Two collators, Charlie and Dave, with the id 2001 on `rococo-local`: a sibling of the
+	/// other presets, to transfer assets to over XCM.
+	Sibling,
+}
+
+impl Preset {
+	/// The preset of a chain id.
+	pub fn from_id(id: &str) -> Option<Self> {
+		match id {
+			"dev" => Some(Self::Development),
+			"" | "local" => Some(Self::LocalTestnet),
+			"sibling" => Some(Self::Sibling),
+			_ => None,
+		}
+	}
+
+	fn name(self) -> &'static str {
+		match self {
+			Self::Development => "Development Parachain",
+			Self::LocalTestnet => "Local Parachain",
+			Self::Sibling => "Sibling Parachain",
+		}
+	}
+
+	fn id(self) -> &'static str {
+		match self {
+			Self::Development => "dev",
+			Self::LocalTestnet => "local_testnet",
+			Self::Sibling => "sibling",
+		}
+	}
+
+	fn chain_type(self) -> ChainType {
+		match self {
+			Self::Development => ChainType::Development,
+			Self::LocalTestnet | Self::Sibling => ChainType::Local,
+		}
+	}
+
+	/// The seeds of the initial collators.
+	fn collators(self) -> &'static [&'static str] {
+		match self {
+			Self::Development => &["Alice"],
+			Self::LocalTestnet => &["Alice", "Bob"],
+			Self::Sibling => &["Charlie", "Dave"],
+		}
+	}
+
+	fn para_id(self) -> ParaId {
+		match self {
+			Self::Development | Self::LocalTestnet => 2000.into(),
+			Self::Sibling => 2001.into(),
+		}
+	}
+
+	fn genesis(self, wasm_binary: &[u8]) -> GenesisConfig {
+		let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+
+		parachain_genesis(
+			wasm_binary,
+			self.para_id(),
+			// Initial collators
+			self.collators().iter().map(|seed| get_from_seed::<AuraId>(seed)).collect(),
+			// Sudo account
+			alice.clone(),
+			// Pre-funded accounts
+			dev_accounts(),
+			// Demo asset owner
+			alice,
+		)
+	}
+}
+
+/// The chain spec of a genesis preset.
+pub fn preset_config(preset: Preset) -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+	Ok(ChainSpec::from_genesis(
+		// Name
+		preset.name(),
+		// ID
+		preset.id(),
+		preset.chain_type(),
+		move || preset.genesis(wasm_binary),
+		// Bootnodes
+		vec![],
+		// Telemetry
+		None,
+		// Protocol ID
+		None,
+		// Properties
+		Some(chain_properties()),
+		// Extensions
+		Extensions { relay_chain: "rococo-local".into(), para_id: preset.para_id().into() },
+	))
+}
+
+/// Configure initial storage state for FRAME modules.
+fn parachain_genesis(
+	wasm_binary: &[u8],
+	para_id: ParaId,
+	collators: Vec<AuraId>,
+	root_key: AccountId,
+	endowed_accounts: Vec<AccountId>,
+	demo_asset_owner: AccountId,
+) -> GenesisConfig {
+	GenesisConfig {
+		system: SystemConfig {
+			// Add Wasm runtime to storage.
+			code: wasm_binary.to_vec(),
+		},
+		parachain_system: Default::default(),
+		parachain_info: ParachainInfoConfig { parachain_id: para_id },
+		balances: BalancesConfig {
+			// Configure endowed accounts with initial balance of 1 << 60.
+			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
+		},
+		transaction_payment: Default::default(),
+		sudo: SudoConfig {
+			// Assign network admin rights.
+			key: root_key,
+		},
+		aura: AuraConfig { authorities: collators },
+		aura_ext: Default::default(),
+		polkadot_xcm: PolkadotXcmConfig { safe_xcm_version: Some(SAFE_XCM_VERSION) },
+		assets: AssetsConfig {
+			// A demo token, held by every development account, to transfer to the siblings.
+			assets: vec![(DEMO_ASSET_ID, demo_asset_owner, true, 1)],
+			metadata: vec![(DEMO_ASSET_ID, "Demo".into(), "DEMO".into(), 12)],
+			accounts: endowed_accounts
+				.into_iter()
+				.map(|who| (DEMO_ASSET_ID, who, 1 << 50))
+				.collect(),
+		},
+	}
+}
@@ -0,0 +1,279 @@
+//! The XCM configuration of the parachain: where its assets are located, and how they are
+//! transacted.
+//!
+//! It follows the one of the mock parachains of the `xcm-tests` crate. The parachain holds its own
+//! assets in its assets pallet, located at `PalletInstance(ASSETS_PALLET_INDEX)/GeneralIndex(id)`,
+//! and its nonfungible items in its uniques pallet, located at
+//! `PalletInstance(UNIQUES_PALLET_INDEX)/GeneralIndex(class)` and identified by `Index(instance)`.
+//! The assets and items a sibling sends it are held in the same pallets, as derivatives whose
+//! asset id, or class, packs the sibling's para id with the original one, see [`AssetLocation`]:
+//! the derivative asset, or class, must be created before it is received.
+
+use crate::{
+	AccountId, AssetId, Assets, Balance, Call, ClassId, Event, InstanceId, Origin, ParachainInfo,
+	ParachainSystem, PolkadotXcm, Runtime, Uniques, XcmpQueue,
+};
+use frame_support::{
+	match_type, parameter_types,
+	traits::{Everything, Get, Nothing},
+	weights::Weight,
+};
+use pallet_xcm::XcmPassthrough;
+use polkadot_parachain::primitives::{Id as ParaId, Sibling};
+use sp_std::marker::PhantomData;
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
+	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, ConvertedConcreteAssetId,
+	EnsureXcmOrigin, FixedWeightBounds, FungiblesAdapter, LocationInverter, NonFungiblesAdapter,
+	ParentAsSuperuser, ParentIsDefault, RelayChainAsNative, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
+	SovereignSignedViaLocation, TakeWeightCredit,
+};
+use xcm_executor::{
+	traits::{Convert, FilterAssetLocation, WeightTrader},
+	XcmExecutor,
+};
+
+/// The index of the assets pallet in the runtime.
+pub const ASSETS_PALLET_INDEX: u8 = 40;
+
+/// The index of the uniques pallet in the runtime.
+pub const UNIQUES_PALLET_INDEX: u8 = 41;
+
+/// Native asset ids, and classes, are under `1 << DERIVATIVE_SHIFT`, the ones of derivatives are
+/// `para_id << DERIVATIVE_SHIFT | id`.
+pub const DERIVATIVE_SHIFT: u32 = 16;
+
+/// The id of the derivative of the asset, or class, `id` of the sibling `para_id`.
+pub fn derivative_id(para_id: u32, id: u32) -> u32 {
+	para_id << DERIVATIVE_SHIFT | id
+}
+
+/// Convert between the location of an asset, or class, of the pallet at `PALLET_INDEX` and its
+/// id, in the parachain `SelfParaId`.
+pub struct AssetLocation<SelfParaId, const PALLET_INDEX: u8>(PhantomData<SelfParaId>);
+
+impl<SelfParaId: Get<ParaId>, const PALLET_INDEX: u8> AssetLocation<SelfParaId, PALLET_INDEX> {
+	fn native_id(index: u128) -> Option<u32> {
+		u32::try_from(index).ok().filter(|id| *id < 1 << DERIVATIVE_SHIFT)
+	}
+
+	fn id(location: &MultiLocation) -> Option<u32> {
+		match (location.parents, &location.interior) {
+			(0, X2(PalletInstance(pallet_index), GeneralIndex(index)))
+				if *pallet_index == PALLET_INDEX =>
+				Self::native_id(*index),
+			(1, X3(Parachain(para_id), PalletInstance(pallet_index), GeneralIndex(index)))
+				if *pallet_index == PALLET_INDEX =>
+			{
+				let id = Self::native_id(*index)?;
+				if *para_id == u32::from(SelfParaId::get()) {
+					// One of our assets, located from a sibling.
+					Some(id)
+				} else if *para_id > 0 && *para_id < 1 << (32 - DERIVATIVE_SHIFT) {
+					Some(derivative_id(*para_id, id))
+				} else {
+					None
+				}
+			},
+			_ => None,
+		}
+	}
+}
+
+impl<SelfParaId: Get<ParaId>, const PALLET_INDEX: u8> Convert<MultiLocation, u32>
+	for AssetLocation<SelfParaId, PALLET_INDEX>
+{
+	fn convert(location: MultiLocation) -> Result<u32, MultiLocation> {
+		Self::id(&location).ok_or(location)
+	}
+
+	fn reverse(id: u32) -> Result<MultiLocation, u32> {
+		let para_id = id >> DERIVATIVE_SHIFT;
+		let index = GeneralIndex((id & ((1 << DERIVATIVE_SHIFT) - 1)).into());
+		let pallet = PalletInstance(PALLET_INDEX);
+		Ok(if para_id == 0 {
+			MultiLocation::new(0, X2(pallet, index))
+		} else {
+			MultiLocation::new(1, X3(Parachain(para_id), pallet, index))
+		})
+	}
+}
+
+/// Convert between the `Index` of a nonfungible item and its instance id.
+pub struct InstanceIndex;
+
+impl Convert<AssetInstance, u32> for InstanceIndex {
+	fn convert(instance: AssetInstance) -> Result<u32, AssetInstance> {
+		match instance {
+			AssetInstance::Index(index) => u32::try_from(index).map_err(|_| instance),
+			_ => Err(instance),
+		}
+	}
+
+	fn reverse(instance: u32) -> Result<AssetInstance, u32> {
+		Ok(AssetInstance::Index(instance.into()))
+	}
+}
+
+/// Trust the chain an asset is located in as its reserve.
+pub struct ReserveLocation;
+
+impl FilterAssetLocation for ReserveLocation {
+	fn filter_asset_location(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		matches!(&asset.id, Concrete(location) if location.starts_with(origin))
+	}
+}
+
+/// Execution is free, as on the mock parachains: the fees bought are left in the holding
+/// register, and deposited with the rest of the assets.
+pub struct FreeExecution;
+
+impl WeightTrader for FreeExecution {
+	fn new() -> Self {
+		Self
+	}
+
+	fn buy_weight(
+		&mut self,
+		_weight: Weight,
+		payment: xcm_executor::Assets,
+	) -> Result<xcm_executor::Assets, XcmError> {
+		Ok(payment)
+	}
+}
+
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Any;
+	pub RelayChainOrigin: Origin = cumulus_pallet_xcm::Origin::Relay.into();
+	pub Ancestry: MultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
+	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+	pub const UnitWeightCost: Weight = 1_000_000_000;
+	pub const MaxInstructions: u32 = 100;
+}
+
+/// Convert the location of an account, or of a chain, to the account controlled by it.
+pub type LocationToAccountId = (
+	ParentIsDefault<AccountId>,
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	AccountId32Aliases<RelayNetwork, AccountId>,
+);
+
+/// Transact the assets of the assets pallet, native and derivatives alike.
+pub type FungiblesTransactor = FungiblesAdapter<
+	Assets,
+	ConvertedConcreteAssetId<
+		AssetId,
+		Balance,
+		AssetLocation<ParachainInfo, ASSETS_PALLET_INDEX>,
+		xcm_executor::traits::JustTry,
+	>,
+	LocationToAccountId,
+	AccountId,
+	// No asset is teleported, none is checked.
+	Nothing,
+	CheckingAccount,
+>;
+
+/// Transact the items of the uniques pallet, native and derivatives alike.
+pub type NonFungiblesTransactor = NonFungiblesAdapter<
+	Uniques,
+	ConvertedConcreteAssetId<
+		ClassId,
+		InstanceId,
+		AssetLocation<ParachainInfo, UNIQUES_PALLET_INDEX>,
+		InstanceIndex,
+	>,
+	LocationToAccountId,
+	AccountId,
+	Nothing,
+	CheckingAccount,
+>;
+
+pub type AssetTransactor = (FungiblesTransactor, NonFungiblesTransactor);
+
+/// Convert the origin of a message to the origin of the calls it transacts.
+pub type XcmOriginToCallOrigin = (
+	SovereignSignedViaLocation<LocationToAccountId, Origin>,
+	RelayChainAsNative<RelayChainOrigin, Origin>,
+	SiblingParachainAsNative<cumulus_pallet_xcm::Origin, Origin>,
+	ParentAsSuperuser<Origin>,
+	SignedAccountId32AsNative<RelayNetwork, Origin>,
+	XcmPassthrough<Origin>,
+);
+
+/// Send messages to the relay chain as upward messages, and to siblings through the XCMP queue.
+pub type XcmRouter = (cumulus_primitives_utility::ParentAsUmp<ParachainSystem, ()>, XcmpQueue);
+
+match_type! {
+	pub type ParentOrParentsExecutivePlurality: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: Here } |
+		MultiLocation { parents: 1, interior: X1(Plurality { id: BodyId::Executive, .. }) }
+	};
+}
+
+pub type Barrier = (
+	TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom<Everything>,
+	AllowUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
+	AllowKnownQueryResponses<PolkadotXcm>,
+	AllowSubscriptionsFrom<Everything>,
+);
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type AssetClaims = PolkadotXcm;
+	type AssetTransactor = AssetTransactor;
+	type AssetTrap = PolkadotXcm;
+	type Barrier = Barrier;
+	type Call = Call;
+	type IsReserve = ReserveLocation;
+	type IsTeleporter = ();
+	type LocationInverter = LocationInverter<Ancestry>;
+	type OriginConverter = XcmOriginToCallOrigin;
+	type ResponseHandler = PolkadotXcm;
+	type SubscriptionService = PolkadotXcm;
+	type Trader = FreeExecution;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type XcmSender = XcmRouter;
+}
+
+/// Convert a signed origin to the location of its account.
+pub type LocalOriginToLocation = SignedToAccountId32<Origin, AccountId, RelayNetwork>;
+
+impl pallet_xcm::Config for Runtime {
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Call = Call;
+	type Event = Event;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type LocationInverter = LocationInverter<Ancestry>;
+	type Origin = Origin;
+	type SendXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmReserveTransferFilter = Everything;
+	type XcmRouter = XcmRouter;
+	type XcmTeleportFilter = Nothing;
+}
+
+impl cumulus_pallet_xcm::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl cumulus_pallet_xcmp_queue::Config for Runtime {
+	type ChannelInfo = ParachainSystem;
+	type Event = Event;
+	type VersionWrapper = PolkadotXcm;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl cumulus_pallet_dmp_queue::Config for Runtime {
+	type Event = Event;
+	type ExecuteOverweightOrigin = frame_system::EnsureRoot<AccountId>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
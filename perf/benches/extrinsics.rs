@@ -0,0 +1,107 @@
+//! Time the extrinsics of the pallets against states of growing sizes.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rusty_node_perf::{
+	execute, Call, Origin, State, ALICE, ASSET, BOB, CLASS, INITIAL_BALANCE, OTHER_ASSET,
+};
+
+/// The numbers of holders, or items, the states are built with.
+const SIZES: [u32; 3] = [100, 1_000, 10_000];
+
+/// The numbers of kitties owned by the caller, up to `MaxKittiesOwned`.
+const KITTIES: [u32; 3] = [1, 100, 1_000];
+
+/// Time the call `call` makes for the size of each of `states`, from `origin`, after checking it
+/// succeeds.
+fn bench_call(
+	c: &mut Criterion,
+	name: &str,
+	states: impl IntoIterator<Item = (u32, State)>,
+	origin: Origin,
+	call: impl Fn(u32) -> Call,
+) {
+	let mut group = c.benchmark_group(name);
+	for (size, state) in states {
+		let mut ext = state.build();
+		let call = call(size);
+		execute(&mut ext, origin.clone(), call.clone()).expect("the call succeeds");
+		group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+			b.iter(|| execute(&mut ext, origin.clone(), call.clone()))
+		});
+	}
+	group.finish();
+}
+
+fn holders() -> impl Iterator<Item = (u32, State)> {
+	SIZES.into_iter().map(|holders| (holders, State { holders, ..Default::default() }))
+}
+
+fn items() -> impl Iterator<Item = (u32, State)> {
+	SIZES.into_iter().map(|items| (items, State { items, ..Default::default() }))
+}
+
+fn kitties() -> impl Iterator<Item = (u32, State)> {
+	KITTIES.into_iter().map(|kitties| (kitties, State { kitties, ..Default::default() }))
+}
+
+fn assets(c: &mut Criterion) {
+	bench_call(
+		c,
+		"assets/transfer",
+		holders(),
+		Origin::signed(ALICE),
+		|_| Call::Assets(pallet_assets::Call::transfer { id: ASSET, target: BOB, amount: 1 }),
+	);
+}
+
+fn uniques(c: &mut Criterion) {
+	bench_call(
+		c,
+		"uniques/transfer",
+		items(),
+		Origin::signed(ALICE),
+		|_| Call::Uniques(pallet_uniques::Call::transfer { class: CLASS, instance: 0, dest: BOB }),
+	);
+}
+
+fn kitties_transfer(c: &mut Criterion) {
+	bench_call(
+		c,
+		"kitties/transfer",
+		kitties(),
+		Origin::signed(ALICE),
+		|_| Call::Kitties(pallet_kitties::Call::transfer { to: BOB, kitty_id: 0 }),
+	);
+}
+
+fn otc_swap(c: &mut Criterion) {
+	bench_call(
+		c,
+		"otc_swap/create_offer",
+		holders(),
+		Origin::signed(ALICE),
+		|_| {
+			Call::OtcSwap(pallet_otc_swap::Call::create_offer {
+				give_asset: ASSET,
+				give_amount: INITIAL_BALANCE / 2,
+				want_asset: OTHER_ASSET,
+				want_amount: INITIAL_BALANCE / 2,
+				counterparty: None,
+				expiry: 10,
+			})
+		},
+	);
+}
+
+fn storage_repair(c: &mut Criterion) {
+	bench_call(c, "storage_repair/force_remove_asset", holders(), Origin::root(), |holders| {
+		Call::StorageRepair(pallet_storage_repair::Call::force_remove_asset {
+			asset_id: ASSET,
+			accounts: holders,
+			approvals: 0,
+		})
+	});
+}
+
+criterion_group!(benches, assets, uniques, kitties_transfer, otc_swap, storage_repair);
+criterion_main!(benches);
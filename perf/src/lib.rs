@@ -0,0 +1,256 @@
+//! Mock runtime and states the extrinsics of the pallets are timed against.
+//!
+//! The benchmarks of the runtime measure weights on the reference hardware, once per release.
+//! These track the execution time of the extrinsics from commit to commit instead, so a change
+//! to the storage layout of a pallet, or to how it iterates, shows in the time of its calls. Each
+//! state is built once, with as many holders, items or kitties as the benchmark asks for, and
+//! [`execute`] rolls back the changes of each run, so every run starts from the same state.
+
+use frame_support::{
+	assert_ok,
+	dispatch::DispatchResultWithPostInfo,
+	parameter_types,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Everything, Randomness},
+	PalletId,
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Dispatchable, IdentityLookup},
+	Perbill,
+};
+use test_utils::ExtBuilder;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+/// The account the calls are made from, owning the asset, the class and the kitties.
+pub const ALICE: u64 = 1;
+
+/// The account receiving the transfers.
+pub const BOB: u64 = 2;
+
+/// The asset held by all the accounts.
+pub const ASSET: u32 = 0;
+
+/// The other asset of the OTC offers.
+pub const OTHER_ASSET: u32 = 1;
+
+/// The class of the items.
+pub const CLASS: u32 = 0;
+
+/// The balance of each account, in the native currency and in each asset.
+pub const INITIAL_BALANCE: u64 = 1_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
+		OtcSwap: pallet_otc_swap::{Pallet, Call, Storage, Event<T>},
+		Kitties: pallet_kitties::{Pallet, Call, Storage, Event<T>},
+		StorageRepair: pallet_storage_repair::{Pallet, Call, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<u64>;
+	type AccountId = u64;
+	type BaseCallFilter = Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: u64 = 0;
+	pub const ApprovalDeposit: u64 = 0;
+	pub const MetadataDepositBase: u64 = 0;
+	pub const MetadataDepositPerByte: u64 = 0;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Runtime {
+	type ApprovalDeposit = ApprovalDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = u32;
+	type Balance = u64;
+	type Currency = Balances;
+	type Event = Event;
+	type Extra = ();
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Freezer = ();
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ClassDeposit: u64 = 0;
+	pub const InstanceDeposit: u64 = 0;
+	pub const AttributeDepositBase: u64 = 0;
+	pub const DepositPerByte: u64 = 0;
+	pub const KeyLimit: u32 = 32;
+	pub const ValueLimit: u32 = 64;
+}
+
+impl pallet_uniques::Config for Runtime {
+	type AttributeDepositBase = AttributeDepositBase;
+	type ClassDeposit = ClassDeposit;
+	type ClassId = u32;
+	type Currency = Balances;
+	type DepositPerByte = DepositPerByte;
+	type Event = Event;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type InstanceDeposit = InstanceDeposit;
+	type InstanceId = u32;
+	type KeyLimit = KeyLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type StringLimit = StringLimit;
+	type ValueLimit = ValueLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const OtcSwapPalletId: PalletId = PalletId(*b"py/otcsw");
+	/// No fee is charged on trades, so that balances only move between the traders.
+	pub const TradeFee: Perbill = Perbill::from_percent(0);
+	pub const FeeCollector: u64 = 0;
+}
+
+impl pallet_otc_swap::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type FeeCollector = FeeCollector;
+	type OnFeeCharged = ();
+	type PalletId = OtcSwapPalletId;
+	type TradeFee = TradeFee;
+	type WeightInfo = ();
+}
+
+/// Randomness derived from the subject and the block number, so the states are reproducible.
+pub struct PerfRandomness;
+
+impl Randomness<H256, u64> for PerfRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block_number = System::block_number();
+		let seed = [subject, &block_number.to_le_bytes()[..]].concat();
+		(H256::from(sp_io::hashing::blake2_256(&seed)), block_number)
+	}
+}
+
+parameter_types! {
+	pub const MaxKittiesOwned: u32 = 1_000;
+}
+
+impl pallet_kitties::Config for Runtime {
+	type Currency = Balances;
+	type Event = Event;
+	type FeeCollector = FeeCollector;
+	type MaxKittiesOwned = MaxKittiesOwned;
+	type OnFeeCharged = ();
+	type Pauses = ();
+	type Randomness = PerfRandomness;
+	type TradeFee = TradeFee;
+	type WeightInfo = ();
+}
+
+impl pallet_storage_repair::Config for Runtime {
+	type Assets = Assets;
+	type ClassId = u32;
+	type Event = Event;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type InstanceId = u32;
+	type Items = Uniques;
+	type WeightInfo = ();
+}
+
+/// The sizes of a state.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct State {
+	/// The number of accounts holding both assets, `ALICE` and `BOB` included.
+	pub holders: u32,
+	/// The number of items of the class, all owned by `ALICE`.
+	pub items: u32,
+	/// The number of kitties owned by `ALICE`, at most `MaxKittiesOwned`.
+	pub kitties: u32,
+}
+
+impl State {
+	/// Build the externalities of the state.
+	pub fn build(self) -> sp_io::TestExternalities {
+		let accounts: Vec<(u64, u64)> =
+			(1..=u64::from(self.holders.max(2))).map(|who| (who, INITIAL_BALANCE)).collect();
+		let mut builder = ExtBuilder::<Runtime>::default()
+			.balances(accounts.clone())
+			.asset(ASSET, ALICE, accounts.clone())
+			.asset(OTHER_ASSET, ALICE, accounts)
+			.class(CLASS, ALICE);
+		for instance in 0..self.items {
+			builder = builder.item(CLASS, instance, ALICE);
+		}
+		let kitties = self.kitties;
+		builder
+			.with(move || {
+				for _ in 0..kitties {
+					assert_ok!(Kitties::create_kitty(Origin::signed(ALICE)));
+				}
+			})
+			.build()
+	}
+}
+
+/// Dispatch `call` from `origin` in `ext`, and roll back its changes.
+pub fn execute(
+	ext: &mut sp_io::TestExternalities,
+	origin: Origin,
+	call: Call,
+) -> DispatchResultWithPostInfo {
+	ext.execute_with(|| with_transaction(|| TransactionOutcome::Rollback(call.dispatch(origin))))
+}
@@ -0,0 +1,92 @@
+//! The identifiers of the assets of the assets pallet, and of the classes and items of the uniques
+//! pallet.
+//!
+//! Each wraps a `u32`, and encodes as one, compact or not, so that the calls and storage of the
+//! pallets keep their encoding: contracts and clients encoding a `u32` are unaffected. Converting
+//! from and to a `u32` is explicit, and arithmetic only adds, or subtracts, a number to an
+//! identifier: adding an asset id to a class is a type error.
+
+use codec::{CompactAs, Decode, Encode, MaxEncodedLen};
+use core::ops::{Add, Sub};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+macro_rules! id {
+	($(#[$attr:meta])* $name:ident) => {
+		$(#[$attr])*
+		#[derive(
+			Clone,
+			Copy,
+			Default,
+			Encode,
+			Decode,
+			CompactAs,
+			MaxEncodedLen,
+			Eq,
+			PartialEq,
+			Ord,
+			PartialOrd,
+			Hash,
+			Debug,
+			TypeInfo,
+		)]
+		#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(transparent))]
+		pub struct $name(pub u32);
+
+		impl $name {
+			/// The identifier `n` after this one, if it does not overflow.
+			pub fn checked_add(self, n: u32) -> Option<Self> {
+				self.0.checked_add(n).map(Self)
+			}
+
+			/// The identifier `n` before this one, if it does not underflow.
+			pub fn checked_sub(self, n: u32) -> Option<Self> {
+				self.0.checked_sub(n).map(Self)
+			}
+		}
+
+		impl Add<u32> for $name {
+			type Output = Self;
+
+			fn add(self, n: u32) -> Self {
+				Self(self.0 + n)
+			}
+		}
+
+		impl Sub<u32> for $name {
+			type Output = Self;
+
+			fn sub(self, n: u32) -> Self {
+				Self(self.0 - n)
+			}
+		}
+
+		impl From<u32> for $name {
+			fn from(id: u32) -> Self {
+				Self(id)
+			}
+		}
+
+		impl From<$name> for u32 {
+			fn from(id: $name) -> u32 {
+				id.0
+			}
+		}
+	};
+}
+
+id! {
+	/// Identifier of a fungible asset.
+	AssetId
+}
+
+id! {
+	/// Identifier of a class of nonfungible items.
+	ClassId
+}
+
+id! {
+	/// Identifier of a nonfungible item within its class.
+	InstanceId
+}
@@ -0,0 +1,124 @@
+//! Types shared by the runtime, its APIs and their clients.
+//!
+//! The identifiers of the assets, classes and items are [`ids`] of their own, so that one is not
+//! passed where another is expected.
+//!
+//! The assets and uniques pallets keep the fields of their details private, and do not serialize
+//! them. The types here mirror their layout, so that the values stored by the pallets decode into
+//! them, and derive `Serialize` and `Deserialize` in `std` builds, so that RPC responses and
+//! genesis configs carry them as JSON:
+//! - [`AssetDetails`] mirrors the details of an asset of the assets pallet
+//! - [`AssetMetadata`] mirrors the metadata of an asset of the assets pallet
+//! - [`ClassDetails`] mirrors the details of a class of the uniques pallet
+//! - [`ClassMetadata`] mirrors the metadata of a class of the uniques pallet
+//! - [`UniqueAssetDetails`] mirrors the details of an item of the uniques pallet
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod ids;
+
+pub use ids::{AssetId, ClassId, InstanceId};
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// The details of a fungible asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetDetails<Balance, AccountId, DepositBalance> {
+	/// Can change `owner`, `issuer`, `freezer` and `admin`.
+	pub owner: AccountId,
+	/// Can mint tokens.
+	pub issuer: AccountId,
+	/// Can thaw tokens, force transfers and burn tokens from any account.
+	pub admin: AccountId,
+	/// Can freeze tokens.
+	pub freezer: AccountId,
+	/// The total supply across all accounts.
+	pub supply: Balance,
+	/// The balance deposited for this asset, returned when it is destroyed.
+	pub deposit: DepositBalance,
+	/// The balance under which an account is reaped.
+	pub min_balance: Balance,
+	/// Whether holding the asset is enough for an account to exist.
+	pub is_sufficient: bool,
+	/// The number of accounts holding the asset.
+	pub accounts: u32,
+	/// The number of accounts existing because they hold the asset.
+	pub sufficients: u32,
+	/// The number of approvals of transfers.
+	pub approvals: u32,
+	/// Whether the asset is frozen for non-admin transfers.
+	pub is_frozen: bool,
+}
+
+/// The metadata of a fungible asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetMetadata<DepositBalance> {
+	/// The balance deposited for the metadata, returned when it is cleared.
+	pub deposit: DepositBalance,
+	/// The user friendly name of the asset.
+	pub name: Vec<u8>,
+	/// The ticker symbol of the asset.
+	pub symbol: Vec<u8>,
+	/// The number of decimals the balances are displayed with.
+	pub decimals: u8,
+	/// Whether the metadata may be changed by a non-forced call.
+	pub is_frozen: bool,
+}
+
+/// The details of a class of nonfungible items.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ClassDetails<AccountId, DepositBalance> {
+	/// Can change `owner`, `issuer`, `freezer` and `admin`.
+	pub owner: AccountId,
+	/// Can mint items.
+	pub issuer: AccountId,
+	/// Can thaw items, force transfers and burn items from any account.
+	pub admin: AccountId,
+	/// Can freeze items.
+	pub freezer: AccountId,
+	/// The balance deposited for the class and its items, metadata and attributes.
+	pub total_deposit: DepositBalance,
+	/// Whether the items of the class are minted without a deposit.
+	pub free_holding: bool,
+	/// The number of items of the class.
+	pub instances: u32,
+	/// The number of items of the class with metadata.
+	pub instance_metadatas: u32,
+	/// The number of attributes of the class and its items.
+	pub attributes: u32,
+	/// Whether the class is frozen for non-admin transfers.
+	pub is_frozen: bool,
+}
+
+/// The metadata of a class of nonfungible items.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ClassMetadata<DepositBalance> {
+	/// The balance deposited for the metadata, returned when it is cleared.
+	pub deposit: DepositBalance,
+	/// The metadata of the class, usually the IPFS hash of a JSON file.
+	pub data: Vec<u8>,
+	/// Whether the metadata may be changed by a non-forced call.
+	pub is_frozen: bool,
+}
+
+/// The details of a nonfungible item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct UniqueAssetDetails<AccountId, DepositBalance> {
+	/// The owner of the item.
+	pub owner: AccountId,
+	/// The account approved to transfer the item, if any.
+	pub approved: Option<AccountId>,
+	/// Whether the item can be transferred.
+	pub is_frozen: bool,
+	/// The balance deposited for the item, returned when it is burnt.
+	pub deposit: DepositBalance,
+}
@@ -0,0 +1,84 @@
+//! Verify the holdings of accounts from storage proofs, without trusting the node serving them.
+//!
+//! The `proofs_balance` and `proofs_item` methods of the node return the trie nodes proving the
+//! storage of a balance, or of the details of an item, at a block. A service gating access on
+//! holdings, e.g. a ticket gate, checks them against the state root of a block header it trusts,
+//! such as a finalized header followed by its light client:
+//! - [`verify_balance`] proves the balance of an account in an asset
+//! - [`verify_owner`] proves the owner of an item
+//!
+//! The storage keys are computed here from the ids, rather than taken from the node, so a proof of
+//! another account, or another item, fails to verify.
+
+use codec::{Decode, Encode};
+use sp_core::{
+	hashing::{blake2_128, twox_128},
+	Blake2Hasher, H256,
+};
+use sp_state_machine::{read_proof_check, StorageProof};
+
+/// The name of the assets pallet in the runtime.
+pub const ASSETS: &str = "Assets";
+
+/// The name of the uniques pallet in the runtime.
+pub const UNIQUES: &str = "Uniques";
+
+/// Why a proof failed to verify.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Error {
+	/// The proof does not prove the key against the state root.
+	InvalidProof,
+	/// The proven value does not decode.
+	InvalidValue,
+}
+
+/// The storage key of the `storage` map of `pallet` at `keys`, hashed with `Blake2_128Concat`.
+pub fn storage_key(pallet: &str, storage: &str, keys: &[&[u8]]) -> Vec<u8> {
+	let mut key = twox_128(pallet.as_bytes()).to_vec();
+	key.extend(twox_128(storage.as_bytes()));
+	for k in keys {
+		key.extend(blake2_128(k));
+		key.extend(*k);
+	}
+	key
+}
+
+/// The balance of `who` in `asset_id` at the block of `state_root`, zero if `who` holds none.
+pub fn verify_balance<Balance: Decode + Default>(
+	state_root: H256,
+	proof: Vec<Vec<u8>>,
+	asset_id: impl Encode,
+	who: impl Encode,
+) -> Result<Balance, Error> {
+	let key = storage_key(ASSETS, "Account", &[&asset_id.encode(), &who.encode()]);
+	// The account of an asset starts with its balance.
+	Ok(read(state_root, proof, key)?.unwrap_or_default())
+}
+
+/// The owner of an item at the block of `state_root`, `None` if the item does not exist.
+pub fn verify_owner<AccountId: Decode>(
+	state_root: H256,
+	proof: Vec<Vec<u8>>,
+	class: impl Encode,
+	instance: impl Encode,
+) -> Result<Option<AccountId>, Error> {
+	let key = storage_key(UNIQUES, "Asset", &[&class.encode(), &instance.encode()]);
+	// The details of an item start with its owner.
+	read(state_root, proof, key)
+}
+
+/// Decode the start of the value proven at `key`, `None` if the proof shows it is not stored.
+fn read<T: Decode>(
+	state_root: H256,
+	proof: Vec<Vec<u8>>,
+	key: Vec<u8>,
+) -> Result<Option<T>, Error> {
+	let proof = StorageProof::new(proof);
+	let mut values = read_proof_check::<Blake2Hasher, _>(state_root, proof, [&key])
+		.map_err(|_| Error::InvalidProof)?;
+	values
+		.remove(&key)
+		.flatten()
+		.map(|value| T::decode(&mut &value[..]).map_err(|_| Error::InvalidValue))
+		.transpose()
+}
@@ -0,0 +1,57 @@
+//! Run the runtime upgrade against the state of a live chain, or of a snapshot of it.
+//!
+//! The state is loaded with remote-externalities, then the `on_runtime_upgrade` hooks of every
+//! pallet run on it, followed by their `post_upgrade` hooks, which check the invariants of their
+//! storage. So the migrations of a new runtime are validated before it is deployed.
+//!
+//! The state is downloaded from the node at the `WS` uri, and saved in the snapshot at the `SNAP`
+//! path if set. Once the snapshot exists, it is loaded instead, without a node.
+#![cfg(feature = "try-runtime")]
+
+#[cfg(test)]
+mod tests;
+
+use frame_support::weights::Weight;
+use remote_externalities::{Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig};
+use rusty_node_runtime::{Block, Executive};
+use sp_io::TestExternalities;
+use std::{env, path::Path};
+
+/// The environment variable holding the uri of the node to download the state from.
+pub const WS: &str = "WS";
+
+/// The environment variable holding the path of the snapshot of the state.
+pub const SNAP: &str = "SNAP";
+
+/// Where to load the state from, following the `WS` and `SNAP` environment variables. `None`
+/// when neither is set.
+pub fn mode() -> Option<Mode<Block>> {
+	let snapshot = env::var(SNAP).ok();
+	if let Some(path) = snapshot.as_ref().filter(|path| Path::new(path).exists()) {
+		let state_snapshot = SnapshotConfig::new(path);
+		return Some(Mode::Offline(OfflineConfig { state_snapshot }))
+	}
+
+	let uri = env::var(WS).ok()?;
+	Some(Mode::Online(OnlineConfig {
+		transport: uri.into(),
+		state_snapshot: snapshot.map(SnapshotConfig::new),
+		..Default::default()
+	}))
+}
+
+/// Load the state, from a node or a snapshot.
+pub async fn remote_ext(mode: Mode<Block>) -> TestExternalities {
+	Builder::<Block>::new()
+		.mode(mode)
+		.build()
+		.await
+		.expect("the state could not be loaded")
+}
+
+/// Run the migrations of the runtime on `ext`, then check the invariants of every pallet.
+///
+/// Returns the weight of the upgrade, or the invariant that does not hold.
+pub fn try_runtime_upgrade(ext: &mut TestExternalities) -> Result<Weight, &'static str> {
+	ext.execute_with(Executive::try_runtime_upgrade)
+}
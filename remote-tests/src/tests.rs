@@ -0,0 +1,13 @@
+use crate::{mode, remote_ext, try_runtime_upgrade, SNAP, WS};
+use rusty_node_runtime::BlockWeights;
+
+#[tokio::test]
+#[ignore = "needs the state of a live chain, from the WS or SNAP environment variables"]
+async fn runtime_upgrade_on_the_remote_state() {
+	let mode = mode().unwrap_or_else(|| panic!("neither {} nor {} is set", WS, SNAP));
+	let mut ext = remote_ext(mode).await;
+
+	let weight = try_runtime_upgrade(&mut ext).unwrap();
+
+	assert!(weight <= BlockWeights::get().max_block, "the upgrade does not fit in a block");
+}
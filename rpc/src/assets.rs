@@ -0,0 +1,137 @@
+use crate::runtime_error;
+use codec::Codec;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use rusty_node_runtime_api::{AssetEntry, AssetsApi as AssetsRuntimeApi, Page};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+
+#[rpc]
+pub trait AssetsApi<BlockHash, AssetId, AccountId, Balance> {
+	/// The balance of `who` in `asset_id`.
+	#[rpc(name = "assets_balance")]
+	fn balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<NumberOrHex>;
+
+	/// The total supply of `asset_id`.
+	#[rpc(name = "assets_totalSupply")]
+	fn total_supply(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// The minimum balance of `asset_id`, zero if the asset does not exist.
+	#[rpc(name = "assets_minimumBalance")]
+	fn minimum_balance(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Up to `limit` of the accounts holding `asset_id`, with their balance, from `start_key` of
+	/// the previous page, or from the first one.
+	#[rpc(name = "assets_holders")]
+	fn holders(
+		&self,
+		asset_id: AssetId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Page<(AccountId, NumberOrHex)>>;
+
+	/// Up to `limit` of the assets, with their details and metadata, after `start`, the last
+	/// asset of the previous page, or from the first one.
+	#[rpc(name = "assets_list")]
+	fn list(
+		&self,
+		start: Option<AssetId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<AssetEntry<AssetId, AccountId, Balance>>>;
+}
+
+/// Serve the `assets_*` methods, for a runtime with `Balance` balances.
+pub struct Assets<C, Block, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> Assets<C, Block, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+impl<C, Block, AssetId, AccountId, Balance> AssetsApi<Block::Hash, AssetId, AccountId, Balance>
+	for Assets<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AssetsRuntimeApi<Block, AssetId, AccountId, Balance>,
+	AssetId: Codec,
+	AccountId: Codec,
+	Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+{
+	fn balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> Result<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.balance(&at, asset_id, who)
+			.map(Into::into)
+			.map_err(|e| runtime_error("Unable to query the balance.", e))
+	}
+
+	fn total_supply(&self, asset_id: AssetId, at: Option<Block::Hash>) -> Result<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.total_supply(&at, asset_id)
+			.map(Into::into)
+			.map_err(|e| runtime_error("Unable to query the total supply.", e))
+	}
+
+	fn minimum_balance(&self, asset_id: AssetId, at: Option<Block::Hash>) -> Result<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.minimum_balance(&at, asset_id)
+			.map(Into::into)
+			.map_err(|e| runtime_error("Unable to query the minimum balance.", e))
+	}
+
+	fn holders(
+		&self,
+		asset_id: AssetId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Page<(AccountId, NumberOrHex)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.holders(&at, asset_id, start_key, limit)
+			.map(|page| Page {
+				items: page.items.into_iter().map(|(who, balance)| (who, balance.into())).collect(),
+				next_key: page.next_key,
+			})
+			.map_err(|e| runtime_error("Unable to query the holders.", e))
+	}
+
+	fn list(
+		&self,
+		start: Option<AssetId>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Vec<AssetEntry<AssetId, AccountId, Balance>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.list_assets(&at, start, limit)
+			.map_err(|e| runtime_error("Unable to list the assets.", e))
+	}
+}
@@ -0,0 +1,31 @@
+//! JSON-RPC handlers over the custom runtime APIs of [`rusty_node_runtime_api`].
+//!
+//! Each handler queries the runtime at the given block, or at the best block by default:
+//! - [`Assets`] serves the `assets_*` methods
+//! - [`Nfts`] serves the `nfts_*` methods
+//! - [`Marketplace`] serves the `marketplace_*` methods
+//! - [`Proofs`] serves the `proofs_*` methods, proving the storage of balances and items
+
+mod assets;
+mod marketplace;
+mod nfts;
+mod proofs;
+
+pub use assets::{Assets, AssetsApiServer};
+pub use marketplace::{Marketplace, MarketplaceApiServer};
+pub use nfts::{Nfts, NftsApiServer};
+pub use proofs::{Proofs, ProofsApiServer};
+
+use jsonrpc_core::{Error as RpcError, ErrorCode};
+
+/// The error code of runtime API calls failing.
+const RUNTIME_ERROR: i64 = 1;
+
+/// Report a runtime API call failing.
+fn runtime_error(message: &str, error: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: message.into(),
+		data: Some(format!("{:?}", error).into()),
+	}
+}
@@ -0,0 +1,109 @@
+use crate::runtime_error;
+use codec::Codec;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use rusty_node_runtime_api::{
+	AuctionListing, KittyListing, MarketplaceApi as MarketplaceRuntimeApi, OfferListing, Page,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+
+#[rpc]
+pub trait MarketplaceApi<BlockHash, AccountId, AssetId, Balance, ClassId, InstanceId> {
+	/// The kitties on sale, among up to `limit` kitties from `start_key` of the previous page, or
+	/// from the first one.
+	#[rpc(name = "marketplace_kittiesForSale")]
+	fn kitties_for_sale(
+		&self,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Page<KittyListing<AccountId, Balance>>>;
+
+	/// Up to `limit` of the items in a running Dutch auction, at their current price, from
+	/// `start_key` of the previous page, or from the first one.
+	#[rpc(name = "marketplace_auctions")]
+	fn auctions(
+		&self,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Page<AuctionListing<AccountId, AssetId, Balance, ClassId, InstanceId>>>;
+
+	/// The OTC offers that have not expired, among up to `limit` offers from `start_key` of the
+	/// previous page, or from the first one.
+	#[rpc(name = "marketplace_otcOffers")]
+	fn otc_offers(
+		&self,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Page<OfferListing<AccountId, AssetId, Balance>>>;
+}
+
+/// Serve the `marketplace_*` methods.
+pub struct Marketplace<C, Block> {
+	client: Arc<C>,
+	_block: PhantomData<Block>,
+}
+
+impl<C, Block> Marketplace<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _block: PhantomData }
+	}
+}
+
+impl<C, Block, AccountId, AssetId, Balance, ClassId, InstanceId>
+	MarketplaceApi<Block::Hash, AccountId, AssetId, Balance, ClassId, InstanceId>
+	for Marketplace<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: MarketplaceRuntimeApi<Block, AccountId, AssetId, Balance, ClassId, InstanceId>,
+	AccountId: Codec,
+	AssetId: Codec,
+	Balance: Codec,
+	ClassId: Codec,
+	InstanceId: Codec,
+{
+	fn kitties_for_sale(
+		&self,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Page<KittyListing<AccountId, Balance>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.kitties_for_sale(&at, start_key, limit)
+			.map_err(|e| runtime_error("Unable to query the kitties for sale.", e))
+	}
+
+	fn auctions(
+		&self,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Page<AuctionListing<AccountId, AssetId, Balance, ClassId, InstanceId>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.auctions(&at, start_key, limit)
+			.map_err(|e| runtime_error("Unable to query the auctions.", e))
+	}
+
+	fn otc_offers(
+		&self,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Page<OfferListing<AccountId, AssetId, Balance>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.otc_offers(&at, start_key, limit)
+			.map_err(|e| runtime_error("Unable to query the OTC offers.", e))
+	}
+}
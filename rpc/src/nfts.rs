@@ -0,0 +1,118 @@
+use crate::runtime_error;
+use codec::Codec;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use rusty_node_runtime_api::{ClassEntry, KittyId, NftsApi as NftsRuntimeApi, Page};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+
+#[rpc]
+pub trait NftsApi<BlockHash, ClassId, InstanceId, AccountId, Balance> {
+	/// The owner of an item, if it exists.
+	#[rpc(name = "nfts_owner")]
+	fn owner(
+		&self,
+		class: ClassId,
+		instance: InstanceId,
+		at: Option<BlockHash>,
+	) -> Result<Option<AccountId>>;
+
+	/// Up to `limit` of the items owned by `who`, from `start_key` of the previous page, or from
+	/// the first one.
+	#[rpc(name = "nfts_owned")]
+	fn owned(
+		&self,
+		who: AccountId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Page<(ClassId, InstanceId)>>;
+
+	/// The kitties owned by `who`.
+	#[rpc(name = "nfts_kittiesOwned")]
+	fn kitties_owned(&self, who: AccountId, at: Option<BlockHash>) -> Result<Vec<KittyId>>;
+
+	/// Up to `limit` of the classes, with their details and metadata, after `start`, the last
+	/// class of the previous page, or from the first one.
+	#[rpc(name = "nfts_listClasses")]
+	fn list_classes(
+		&self,
+		start: Option<ClassId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<ClassEntry<ClassId, AccountId, Balance>>>;
+}
+
+/// Serve the `nfts_*` methods, for a runtime with `Balance` deposits.
+pub struct Nfts<C, Block, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> Nfts<C, Block, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+impl<C, Block, ClassId, InstanceId, AccountId, Balance>
+	NftsApi<Block::Hash, ClassId, InstanceId, AccountId, Balance> for Nfts<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: NftsRuntimeApi<Block, ClassId, InstanceId, AccountId, Balance>,
+	ClassId: Codec,
+	InstanceId: Codec,
+	AccountId: Codec,
+	Balance: Codec + Send + Sync + 'static,
+{
+	fn owner(
+		&self,
+		class: ClassId,
+		instance: InstanceId,
+		at: Option<Block::Hash>,
+	) -> Result<Option<AccountId>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.owner(&at, class, instance)
+			.map_err(|e| runtime_error("Unable to query the owner.", e))
+	}
+
+	fn owned(
+		&self,
+		who: AccountId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Page<(ClassId, InstanceId)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.owned(&at, who, start_key, limit)
+			.map_err(|e| runtime_error("Unable to query the owned items.", e))
+	}
+
+	fn kitties_owned(&self, who: AccountId, at: Option<Block::Hash>) -> Result<Vec<KittyId>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.kitties_owned(&at, who)
+			.map_err(|e| runtime_error("Unable to query the owned kitties.", e))
+	}
+
+	fn list_classes(
+		&self,
+		start: Option<ClassId>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Vec<ClassEntry<ClassId, AccountId, Balance>>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.list_classes(&at, start, limit)
+			.map_err(|e| runtime_error("Unable to list the classes.", e))
+	}
+}
@@ -0,0 +1,101 @@
+use crate::runtime_error;
+use codec::Codec;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use rusty_node_runtime_api::ProofsApi as ProofsRuntimeApi;
+use sc_client_api::ProofProvider;
+use sc_rpc_api::state::ReadProof;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{iter, marker::PhantomData, sync::Arc};
+
+#[rpc]
+pub trait ProofsApi<BlockHash, AssetId, ClassId, InstanceId, AccountId> {
+	/// A storage proof of the balance of `who` in `asset_id`.
+	#[rpc(name = "proofs_balance")]
+	fn balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<ReadProof<BlockHash>>;
+
+	/// A storage proof of the owner of an item.
+	#[rpc(name = "proofs_item")]
+	fn item(
+		&self,
+		class: ClassId,
+		instance: InstanceId,
+		at: Option<BlockHash>,
+	) -> Result<ReadProof<BlockHash>>;
+}
+
+/// Serve the `proofs_*` methods.
+pub struct Proofs<C, Block> {
+	client: Arc<C>,
+	_block: PhantomData<Block>,
+}
+
+impl<C, Block> Proofs<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _block: PhantomData }
+	}
+}
+
+impl<C, Block> Proofs<C, Block>
+where
+	Block: BlockT,
+	C: ProofProvider<Block>,
+{
+	/// Prove the value stored at `key` in the state of the block `at`.
+	fn prove(&self, at: Block::Hash, key: Vec<u8>) -> Result<ReadProof<Block::Hash>> {
+		self.client
+			.read_proof(&BlockId::hash(at), &mut iter::once(&key[..]))
+			.map(|proof| ReadProof { at, proof: proof.iter_nodes().map(Into::into).collect() })
+			.map_err(|e| runtime_error("Unable to prove the storage.", e))
+	}
+}
+
+impl<C, Block, AssetId, ClassId, InstanceId, AccountId>
+	ProofsApi<Block::Hash, AssetId, ClassId, InstanceId, AccountId> for Proofs<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + ProofProvider<Block>,
+	C: Send + Sync + 'static,
+	C::Api: ProofsRuntimeApi<Block, AssetId, ClassId, InstanceId, AccountId>,
+	AssetId: Codec,
+	ClassId: Codec,
+	InstanceId: Codec,
+	AccountId: Codec,
+{
+	fn balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> Result<ReadProof<Block::Hash>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let key = self
+			.client
+			.runtime_api()
+			.balance_key(&BlockId::hash(at), asset_id, who)
+			.map_err(|e| runtime_error("Unable to query the balance key.", e))?;
+		self.prove(at, key)
+	}
+
+	fn item(
+		&self,
+		class: ClassId,
+		instance: InstanceId,
+		at: Option<Block::Hash>,
+	) -> Result<ReadProof<Block::Hash>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let key = self
+			.client
+			.runtime_api()
+			.item_key(&BlockId::hash(at), class, instance)
+			.map_err(|e| runtime_error("Unable to query the item key.", e))?;
+		self.prove(at, key)
+	}
+}
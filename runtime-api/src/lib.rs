@@ -0,0 +1,287 @@
+//! The custom runtime APIs of the workspace pallets, declared in one place.
+//!
+//! The runtime implements them, so RPC crates and clients have a single interface to target:
+//! - [`AssetsApi`] queries balances, supplies and holders of the fungible assets, and lists them
+//! - [`NftsApi`] queries who owns nonfungible items and kitties, and lists the classes
+//! - [`MarketplaceApi`] lists what is on sale in the marketplace pallets
+//! - [`DryRunApi`] simulates calls, so wallets report their errors and events before they are
+//!   submitted
+//! - [`ErrorCodesApi`] gives errors codes and names that stay the same across upgrades
+//! - [`ExplorerApi`] gives the aggregate counters of the chain, and the creation timestamps of
+//!   assets and items, for block explorers
+//! - [`ProofsApi`] gives the storage keys of balances and items, for the services verifying
+//!   holdings against a storage proof
+//!
+//! The queries walking large storage maps return a [`Page`] at a time.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use frame_support::dispatch::{DispatchError, DispatchResultWithPostInfo};
+pub use rusty_node_primitives::{AssetDetails, AssetMetadata, ClassDetails, ClassMetadata};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// The id of a kitty.
+pub type KittyId = u32;
+
+/// The id of an OTC offer.
+pub type OfferId = u32;
+
+/// The outcome of a dry run of a call.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct CallSimulation<Event> {
+	/// The result of the call, with its actual weight.
+	pub result: DispatchResultWithPostInfo,
+	/// The events deposited by the call, none of which is kept.
+	pub events: Vec<Event>,
+}
+
+/// A kitty on sale.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct KittyListing<AccountId, Balance> {
+	pub kitty_id: KittyId,
+	pub owner: AccountId,
+	/// The price, in the native currency.
+	pub price: Balance,
+}
+
+/// An item in a running Dutch auction.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AuctionListing<AccountId, AssetId, Balance, ClassId, InstanceId> {
+	pub class: ClassId,
+	pub instance: InstanceId,
+	pub seller: AccountId,
+	/// The asset the item is paid in, `None` for the native currency.
+	pub asset_id: Option<AssetId>,
+	/// The price at the current block.
+	pub price: Balance,
+}
+
+/// An OTC offer that can still be taken.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OfferListing<AccountId, AssetId, Balance> {
+	pub offer_id: OfferId,
+	pub maker: AccountId,
+	pub give_asset: AssetId,
+	pub give_amount: Balance,
+	pub want_asset: AssetId,
+	pub want_amount: Balance,
+	/// The only account allowed to take the offer, if any.
+	pub counterparty: Option<AccountId>,
+}
+
+/// An asset, with its details and its metadata, if it has any.
+pub type AssetEntry<AssetId, AccountId, Balance> =
+	(AssetId, AssetDetails<Balance, AccountId, Balance>, Option<AssetMetadata<Balance>>);
+
+/// A class of nonfungible items, with its details and its metadata, if it has any.
+pub type ClassEntry<ClassId, AccountId, Balance> =
+	(ClassId, ClassDetails<AccountId, Balance>, Option<ClassMetadata<Balance>>);
+
+/// A page of the results of a query, and where the next one starts.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Page<T> {
+	pub items: Vec<T>,
+	/// The key to query the next page from, `None` on the last page.
+	pub next_key: Option<Vec<u8>>,
+}
+
+/// The code and name of an error, which stay the same across runtime upgrades.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ErrorCode {
+	/// The upper 16 bits select the pallet, and the lower 16 bits an error of the pallet.
+	pub code: u32,
+	/// The name of the error, `<pallet>.<error>` in snake case, e.g. `assets.balance_low`.
+	pub name: Vec<u8>,
+}
+
+/// The aggregate counters of the chain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ChainCounters {
+	/// The number of assets created since genesis.
+	pub assets_created: u64,
+	/// The number of nonfungible items, and kitties, in existence.
+	pub nfts: u64,
+	/// The number of accounts holding each asset, summed over the assets.
+	pub holders: u64,
+	/// The index of the current session.
+	pub session: u32,
+	/// The number of transfers of the native currency, assets and nonfungible items, in the
+	/// current session.
+	pub session_transfers: u64,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Queries of the fungible assets.
+	pub trait AssetsApi<AssetId, AccountId, Balance>
+	where
+		AssetId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The balance of `who` in `asset_id`.
+		fn balance(asset_id: AssetId, who: AccountId) -> Balance;
+
+		/// The total supply of `asset_id`.
+		fn total_supply(asset_id: AssetId) -> Balance;
+
+		/// The minimum balance of `asset_id`, zero if the asset does not exist.
+		fn minimum_balance(asset_id: AssetId) -> Balance;
+
+		/// Up to `limit` of the accounts holding `asset_id`, with their balance, from `start_key`
+		/// of the previous page, or from the first one.
+		fn holders(
+			asset_id: AssetId,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<(AccountId, Balance)>;
+
+		/// Up to `limit` of the assets, with their details and metadata, after `start`, the last
+		/// asset of the previous page, or from the first one.
+		///
+		/// The assets come in storage order, which is the order of the hashes of their ids.
+		fn list_assets(
+			start: Option<AssetId>,
+			limit: u32,
+		) -> Vec<AssetEntry<AssetId, AccountId, Balance>>;
+	}
+
+	/// Queries of the nonfungible items and kitties.
+	pub trait NftsApi<ClassId, InstanceId, AccountId, Balance>
+	where
+		ClassId: Codec,
+		InstanceId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The owner of an item, if it exists.
+		fn owner(class: ClassId, instance: InstanceId) -> Option<AccountId>;
+
+		/// Up to `limit` of the items owned by `who`, from `start_key` of the previous page, or
+		/// from the first one.
+		fn owned(
+			who: AccountId,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<(ClassId, InstanceId)>;
+
+		/// The kitties owned by `who`.
+		fn kitties_owned(who: AccountId) -> Vec<KittyId>;
+
+		/// Up to `limit` of the classes, with their details and metadata, after `start`, the last
+		/// class of the previous page, or from the first one.
+		///
+		/// The classes come in storage order, which is the order of the hashes of their ids.
+		fn list_classes(
+			start: Option<ClassId>,
+			limit: u32,
+		) -> Vec<ClassEntry<ClassId, AccountId, Balance>>;
+	}
+
+	/// The listings of the marketplace pallets.
+	pub trait MarketplaceApi<AccountId, AssetId, Balance, ClassId, InstanceId>
+	where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		ClassId: Codec,
+		InstanceId: Codec,
+	{
+		/// The kitties on sale, among up to `limit` kitties from `start_key` of the previous page,
+		/// or from the first one.
+		///
+		/// The kitties read that are not on sale count towards `limit`, so a page may hold fewer
+		/// listings, and only the one without a `next_key` is the last.
+		fn kitties_for_sale(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<KittyListing<AccountId, Balance>>;
+
+		/// Up to `limit` of the items in a running Dutch auction, from `start_key` of the previous
+		/// page, or from the first one.
+		fn auctions(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<AuctionListing<AccountId, AssetId, Balance, ClassId, InstanceId>>;
+
+		/// The OTC offers that have not expired, among up to `limit` offers from `start_key` of
+		/// the previous page, or from the first one.
+		///
+		/// As for [`Self::kitties_for_sale`], the expired offers read count towards `limit`.
+		fn otc_offers(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<OfferListing<AccountId, AssetId, Balance>>;
+	}
+
+	/// Dry runs of calls.
+	pub trait DryRunApi<AccountId, Call, Event>
+	where
+		AccountId: Codec,
+		Call: Codec,
+		Event: Codec,
+	{
+		/// Dispatch `call` signed by `origin` against the current state, without committing it.
+		///
+		/// The call is dispatched as is, without the checks and fees of the transaction carrying
+		/// it: the result and events are the ones the call would have if it were included in the
+		/// next block.
+		fn simulate_call(origin: AccountId, call: Call) -> CallSimulation<Event>;
+	}
+
+	/// The stable codes of the errors.
+	pub trait ErrorCodesApi {
+		/// The code and name of `error`, `None` if it has none, e.g. an error of a pallet which
+		/// is not in the registry of the runtime.
+		fn error_code(error: DispatchError) -> Option<ErrorCode>;
+
+		/// The codes and names of all the errors of the registry.
+		fn error_codes() -> Vec<ErrorCode>;
+	}
+
+	/// The counters of block explorers, and the creation timestamps of assets and items.
+	pub trait ExplorerApi<AssetId, ClassId, InstanceId, Moment>
+	where
+		AssetId: Codec,
+		ClassId: Codec,
+		InstanceId: Codec,
+		Moment: Codec,
+	{
+		/// The aggregate counters of the chain, as of the end of the block.
+		fn counters() -> ChainCounters;
+
+		/// When `asset_id` was created, if it was created since the timestamps are recorded.
+		fn asset_created_at(asset_id: AssetId) -> Option<Moment>;
+
+		/// When an item in existence was created, if it was created since the timestamps are
+		/// recorded.
+		fn item_created_at(class: ClassId, instance: InstanceId) -> Option<Moment>;
+	}
+
+	/// The storage keys holding the balances of accounts and the owners of items.
+	///
+	/// A storage proof of a key, against the state root of a block, proves the holding to a
+	/// service which does not trust the node serving it.
+	pub trait ProofsApi<AssetId, ClassId, InstanceId, AccountId>
+	where
+		AssetId: Codec,
+		ClassId: Codec,
+		InstanceId: Codec,
+		AccountId: Codec,
+	{
+		/// The storage key of the balance of `who` in `asset_id`.
+		fn balance_key(asset_id: AssetId, who: AccountId) -> Vec<u8>;
+
+		/// The storage key of the details of an item, which start with its owner.
+		fn item_key(class: ClassId, instance: InstanceId) -> Vec<u8>;
+	}
+}
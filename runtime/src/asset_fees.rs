@@ -0,0 +1,120 @@
+//! Transaction fees paid with assets, by the accounts lacking the native currency.
+//!
+//! [`AssetFeeAdapter`] charges the fees in the native currency whenever the account can pay them.
+//! Otherwise it burns their value in the first whitelisted asset the account holds enough of,
+//! converted at the asset's rate and rounded up, and mints back the part refunded after dispatch.
+//! Either way, the fees are then split between the treasury and the burn, see [`crate::fees`].
+
+use crate::{
+	fees::{deposit_asset_fee, DealWithFees},
+	AccountId, AssetId, Assets, Balance, Balances, Call, Runtime,
+};
+use frame_support::traits::{
+	tokens::{
+		fungibles::{Inspect, Mutate},
+		WithdrawConsequence,
+	},
+	Get,
+};
+use pallet_transaction_payment::{CurrencyAdapter, OnChargeTransaction};
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	FixedPointNumber, FixedU128,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Fees in the native currency.
+type NativeAdapter = CurrencyAdapter<Balances, DealWithFees>;
+
+/// How the fee of a transaction was paid, until it is corrected after dispatch.
+pub enum FeePayment {
+	Native(<NativeAdapter as OnChargeTransaction<Runtime>>::LiquidityInfo),
+	Asset { asset_id: AssetId, rate: FixedU128, paid: Balance },
+}
+
+impl Default for FeePayment {
+	fn default() -> Self {
+		Self::Native(Default::default())
+	}
+}
+
+/// The amount of an asset worth `fee` at `rate`, rounded up, so that a fraction of a unit owed is
+/// paid rather than forgiven.
+fn asset_fee(rate: FixedU128, fee: Balance) -> Balance {
+	let floor = rate.saturating_mul_int(fee);
+	let exact = rate.saturating_mul(FixedU128::saturating_from_integer(fee));
+	if FixedU128::saturating_from_integer(floor) < exact {
+		floor.saturating_add(1)
+	} else {
+		floor
+	}
+}
+
+/// Charge the fees in the native currency, or else in one of the assets of `Rates`, which gives
+/// how many units of each pay for a unit of the native currency.
+pub struct AssetFeeAdapter<Rates>(PhantomData<Rates>);
+
+impl<Rates: Get<Vec<(AssetId, FixedU128)>>> OnChargeTransaction<Runtime>
+	for AssetFeeAdapter<Rates>
+{
+	type Balance = Balance;
+	type LiquidityInfo = FeePayment;
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &Call,
+		info: &DispatchInfoOf<Call>,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<FeePayment, TransactionValidityError> {
+		let native_error = match NativeAdapter::withdraw_fee(who, call, info, fee, tip) {
+			Ok(imbalance) => return Ok(FeePayment::Native(imbalance)),
+			Err(error) => error,
+		};
+
+		for (asset_id, rate) in Rates::get() {
+			// A fee, however small, is never free, whatever the rate of the asset.
+			let amount = asset_fee(rate, fee).max(1);
+			// The account must stay alive: it may hold nothing else keeping its nonce.
+			if let WithdrawConsequence::Success = Assets::can_withdraw(asset_id, who, amount) {
+				let paid = Assets::burn_from(asset_id, who, amount)
+					.map_err(|_| InvalidTransaction::Payment)?;
+				return Ok(FeePayment::Asset { asset_id, rate, paid })
+			}
+		}
+
+		Err(native_error)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		info: &DispatchInfoOf<Call>,
+		post_info: &PostDispatchInfoOf<Call>,
+		corrected_fee: Balance,
+		tip: Balance,
+		already_withdrawn: FeePayment,
+	) -> Result<(), TransactionValidityError> {
+		match already_withdrawn {
+			FeePayment::Native(imbalance) => NativeAdapter::correct_and_deposit_fee(
+				who,
+				info,
+				post_info,
+				corrected_fee,
+				tip,
+				imbalance,
+			),
+			FeePayment::Asset { asset_id, rate, paid } => {
+				// The corrected fee includes the tip.
+				let fee = asset_fee(rate, corrected_fee).min(paid);
+				let refund = paid.saturating_sub(fee);
+				if !refund.is_zero() {
+					Assets::mint_into(asset_id, who, refund)
+						.map_err(|_| InvalidTransaction::Payment)?;
+				}
+				deposit_asset_fee(asset_id, fee);
+				Ok(())
+			},
+		}
+	}
+}
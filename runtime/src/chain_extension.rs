@@ -0,0 +1,220 @@
+//! The chain extension letting ink! smart contracts use the assets and uniques pallets.
+//!
+//! A contract calls a function of [`RuntimeExtension`] by id, with its SCALE encoded arguments as
+//! input. The upper 16 bits of the id select a set of functions, and the lower 16 bits a function
+//! of the set. The functions of the assets pallet are:
+//!
+//! | id            | function          | input                                      | output    |
+//! |---------------|-------------------|--------------------------------------------|-----------|
+//! | `0x0001_0001` | `balance_of`      | `(AssetId, AccountId)`                     | `Balance` |
+//! | `0x0001_0002` | `total_supply`    | `AssetId`                                  | `Balance` |
+//! | `0x0001_0003` | `transfer`        | `(AssetId, AccountId, Balance)`            | status    |
+//! | `0x0001_0004` | `mint`            | `(AssetId, AccountId, Balance)`            | status    |
+//! | `0x0001_0005` | `allowance`       | `(AssetId, AccountId, AccountId)`          | `Balance` |
+//! | `0x0001_0006` | `approve`         | `(AssetId, AccountId, Balance)`            | status    |
+//! | `0x0001_0007` | `cancel_approval` | `(AssetId, AccountId)`                     | status    |
+//! | `0x0001_0008` | `transfer_from`   | `(AssetId, AccountId, AccountId, Balance)` | status    |
+//!
+//! The functions of the uniques pallet are:
+//!
+//! | id            | function      | input                              | output              |
+//! |---------------|---------------|------------------------------------|---------------------|
+//! | `0x0002_0001` | `owner`       | `(ClassId, InstanceId)`            | `Option<AccountId>` |
+//! | `0x0002_0002` | `is_approved` | `(ClassId, InstanceId, AccountId)` | `bool`              |
+//! | `0x0002_0003` | `transfer`    | `(ClassId, InstanceId, AccountId)` | status              |
+//!
+//! Transfers and mints are dispatched as calls of their pallet signed by the contract, through
+//! the call filter of the runtime: a contract transfers its own assets, and only mints the assets
+//! it is the issuer of, while they are not frozen nor their pallet paused. It transfers the items
+//! it owns, and the ones their owner approved it to transfer, e.g. to sell them as a marketplace.
+//! The same goes for the approvals of the assets pallet: `allowance` is what an owner approved a
+//! delegate to spend, `approve` and `cancel_approval` approve a delegate to spend the assets of the
+//! contract, which reserves the approval deposit from its balance, and `transfer_from` spends what
+//! an owner approved the contract to, e.g. to settle trades as an exchange.
+//! A failed call is reverted and returns the [`FAILED`] status rather than trapping, so that the
+//! contract can handle it.
+
+use crate::{
+	roles::details, AccountId, AssetId, Assets, Balance, Call, ClassId, InstanceId, ItemRoles,
+	Origin, Runtime, Uniques,
+};
+use codec::Encode;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{tokens::nonfungibles, Get},
+};
+use pallet_assets::WeightInfo as _;
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RetVal, SysConfig, UncheckedFrom,
+};
+use pallet_uniques::WeightInfo as _;
+use rusty_node_support::{Permissions, Roles};
+use sp_runtime::{
+	traits::{Dispatchable, StaticLookup},
+	DispatchError,
+};
+
+/// The set of functions of the assets pallet.
+pub const ASSETS: u32 = 0x0001;
+
+/// The set of functions of the uniques pallet.
+pub const ITEMS: u32 = 0x0002;
+
+/// The status of a successful call.
+pub const SUCCESS: u32 = 0;
+
+/// The status of a failed call.
+pub const FAILED: u32 = 1;
+
+/// The chain extension of the runtime, dispatching each set of functions to its pallet.
+pub struct RuntimeExtension;
+
+impl ChainExtension<Runtime> for RuntimeExtension {
+	fn call<E>(func_id: u32, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		match func_id >> 16 {
+			ASSETS => assets(func_id & 0xffff, env),
+			ITEMS => items(func_id & 0xffff, env),
+			_ => Err(DispatchError::Other("unknown chain extension function")),
+		}
+	}
+}
+
+/// The functions of the assets pallet.
+fn assets<E>(function: u32, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+where
+	E: Ext<T = Runtime>,
+	<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+{
+	let mut env = env.buf_in_buf_out();
+	let read = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+	let lookup = <Runtime as frame_system::Config>::Lookup::unlookup;
+
+	match function {
+		// balance_of
+		0x0001 => {
+			env.charge_weight(read)?;
+			let (asset_id, who): (AssetId, AccountId) = env.read_as()?;
+			env.write(&Assets::balance(asset_id, who).encode(), false, None)?;
+			Ok(RetVal::Converging(SUCCESS))
+		},
+		// total_supply
+		0x0002 => {
+			env.charge_weight(read)?;
+			let asset_id: AssetId = env.read_as()?;
+			env.write(&Assets::total_supply(asset_id).encode(), false, None)?;
+			Ok(RetVal::Converging(SUCCESS))
+		},
+		// transfer
+		0x0003 => {
+			env.charge_weight(<Runtime as pallet_assets::Config>::WeightInfo::transfer())?;
+			let (asset_id, to, amount): (AssetId, AccountId, Balance) = env.read_as()?;
+			let call = pallet_assets::Call::transfer { id: asset_id, target: lookup(to), amount };
+			Ok(dispatch(env.ext().address().clone(), Call::Assets(call)))
+		},
+		// mint
+		0x0004 => {
+			env.charge_weight(<Runtime as pallet_assets::Config>::WeightInfo::mint())?;
+			let (asset_id, to, amount): (AssetId, AccountId, Balance) = env.read_as()?;
+			let call = pallet_assets::Call::mint { id: asset_id, beneficiary: lookup(to), amount };
+			Ok(dispatch(env.ext().address().clone(), Call::Assets(call)))
+		},
+		// allowance
+		0x0005 => {
+			env.charge_weight(read)?;
+			let (asset_id, owner, delegate): (AssetId, AccountId, AccountId) = env.read_as()?;
+			// An approval starts with the amount the delegate may still spend.
+			let allowance: Balance = details::<Assets, _>(
+				b"Approvals",
+				&[asset_id.encode(), owner.encode(), delegate.encode()],
+			)
+			.unwrap_or_default();
+			env.write(&allowance.encode(), false, None)?;
+			Ok(RetVal::Converging(SUCCESS))
+		},
+		// approve
+		0x0006 => {
+			env.charge_weight(<Runtime as pallet_assets::Config>::WeightInfo::approve_transfer())?;
+			let (asset_id, delegate, amount): (AssetId, AccountId, Balance) = env.read_as()?;
+			let delegate = lookup(delegate);
+			let call = pallet_assets::Call::approve_transfer { id: asset_id, delegate, amount };
+			Ok(dispatch(env.ext().address().clone(), Call::Assets(call)))
+		},
+		// cancel_approval
+		0x0007 => {
+			env.charge_weight(<Runtime as pallet_assets::Config>::WeightInfo::cancel_approval())?;
+			let (asset_id, delegate): (AssetId, AccountId) = env.read_as()?;
+			let delegate = lookup(delegate);
+			let call = pallet_assets::Call::cancel_approval { id: asset_id, delegate };
+			Ok(dispatch(env.ext().address().clone(), Call::Assets(call)))
+		},
+		// transfer_from
+		0x0008 => {
+			env.charge_weight(<Runtime as pallet_assets::Config>::WeightInfo::transfer_approved())?;
+			let (asset_id, owner, to, amount): (AssetId, AccountId, AccountId, Balance) =
+				env.read_as()?;
+			let call = pallet_assets::Call::transfer_approved {
+				id: asset_id,
+				owner: lookup(owner),
+				destination: lookup(to),
+				amount,
+			};
+			Ok(dispatch(env.ext().address().clone(), Call::Assets(call)))
+		},
+		_ => Err(DispatchError::Other("unknown assets function")),
+	}
+}
+
+/// The functions of the uniques pallet.
+fn items<E>(function: u32, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+where
+	E: Ext<T = Runtime>,
+	<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+{
+	let mut env = env.buf_in_buf_out();
+	let reads = |n| <Runtime as frame_system::Config>::DbWeight::get().reads(n);
+	let lookup = <Runtime as frame_system::Config>::Lookup::unlookup;
+
+	match function {
+		// owner
+		0x0001 => {
+			env.charge_weight(reads(1))?;
+			let (class, instance): (ClassId, InstanceId) = env.read_as()?;
+			let owner = <Uniques as nonfungibles::Inspect<AccountId>>::owner(&class, &instance);
+			env.write(&owner.encode(), false, None)?;
+			Ok(RetVal::Converging(SUCCESS))
+		},
+		// is_approved
+		0x0002 => {
+			env.charge_weight(reads(2))?;
+			let (class, instance, who): (ClassId, InstanceId, AccountId) = env.read_as()?;
+			let approved =
+				ItemRoles::permissions(&(class, instance), &who).contains(Permissions::DELEGATE);
+			env.write(&approved.encode(), false, None)?;
+			Ok(RetVal::Converging(SUCCESS))
+		},
+		// transfer
+		0x0003 => {
+			env.charge_weight(<Runtime as pallet_uniques::Config>::WeightInfo::transfer())?;
+			let (class, instance, to): (ClassId, InstanceId, AccountId) = env.read_as()?;
+			let call = pallet_uniques::Call::transfer { class, instance, dest: lookup(to) };
+			Ok(dispatch(env.ext().address().clone(), Call::Uniques(call)))
+		},
+		_ => Err(DispatchError::Other("unknown items function")),
+	}
+}
+
+/// Dispatch `call` signed by `contract`, reverting it if it fails.
+///
+/// The signed origin applies the `BaseCallFilter` of the runtime, as for any transaction: the
+/// calls of paused pallets, the mints of frozen assets and the calls filtered out in maintenance
+/// mode fail.
+fn dispatch(contract: AccountId, call: Call) -> RetVal {
+	with_transaction(|| match call.dispatch(Origin::signed(contract)) {
+		Ok(_) => TransactionOutcome::Commit(RetVal::Converging(SUCCESS)),
+		Err(_) => TransactionOutcome::Rollback(RetVal::Converging(FAILED)),
+	})
+}
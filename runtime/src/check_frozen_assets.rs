@@ -0,0 +1,185 @@
+//! A signed extension rejecting transfers of frozen assets, before they are dispatched.
+//!
+//! The assets pallet fails transfers and burns out of a frozen account, or of a frozen asset, but
+//! only once dispatched: the transaction is included and its fees are paid. This extension checks
+//! them when transactions are validated instead, so the pool drops them right away. It rejects the
+//! mints of frozen assets too, which the `BaseCallFilter` of the runtime fails once dispatched, so
+//! that freezing an asset pauses all of its transfers, mints and burns.
+//!
+//! The calls wrapped in other calls, and dispatched in the same transaction, are checked as well,
+//! as the account they are dispatched as: the calls batched with the utility, or batch utility,
+//! pallets as the signer, the calls of a proxy as the account it acts for, the calls of a multisig
+//! as its account, the calls of `as_derivative` as the derivative account, and the calls of the
+//! sudo pallet as root, or as the account of `sudo_as`. The calls scheduled, or proposed, to be
+//! dispatched later are not: the asset may be thawed, or frozen, by then.
+
+use crate::{
+	multisig_account, roles::details, AccountId, AssetId, Assets, Balance, Call, Runtime, Utility,
+};
+use codec::{Decode, DecodeLimit, Encode};
+use rusty_node_primitives::AssetDetails;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension, StaticLookup},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+/// The custom `InvalidTransaction` code of transfers of frozen assets.
+pub const FROZEN_ASSET: u8 = 1;
+
+/// The depth of nested calls decoded from the opaque call of a multisig.
+const MAX_CALL_DEPTH: u32 = 256;
+
+/// Whether `asset_id` is frozen by its freezer.
+pub fn is_frozen_asset(asset_id: AssetId) -> bool {
+	details::<Assets, AssetDetails<Balance, AccountId, Balance>>(b"Asset", &[asset_id.encode()])
+		.map_or(false, |details| details.is_frozen)
+}
+
+/// Whether the account `who` is frozen for `asset_id` by the freezer of the asset.
+fn is_frozen_account(asset_id: AssetId, who: &AccountId) -> bool {
+	// The balance of an account starts with the amount held, then whether it is frozen.
+	details::<Assets, (Balance, bool)>(b"Account", &[asset_id.encode(), who.encode()])
+		.map_or(false, |(_, is_frozen)| is_frozen)
+}
+
+/// The asset minted by `call`, if it is a mint of assets.
+pub(crate) fn mint_of(call: &Call) -> Option<AssetId> {
+	match call {
+		Call::Assets(pallet_assets::Call::mint { id, .. }) => Some(*id),
+		_ => None,
+	}
+}
+
+/// Reject transfers and burns out of frozen accounts, or of frozen assets, and mints of frozen
+/// assets, at the transaction pool level.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct CheckFrozenAssets;
+
+impl CheckFrozenAssets {
+	pub fn new() -> Self {
+		Self
+	}
+
+	/// The asset and account debited of a transfer, or a burn, of assets, dispatched as `who`, or
+	/// as root for `None`.
+	fn transfer_of(who: Option<&AccountId>, call: &Call) -> Option<(AssetId, AccountId)> {
+		let lookup = <Runtime as frame_system::Config>::Lookup::lookup;
+		match call {
+			Call::Assets(pallet_assets::Call::transfer { id, .. }) |
+			Call::Assets(pallet_assets::Call::transfer_keep_alive { id, .. }) =>
+				Some((*id, who?.clone())),
+			Call::Assets(pallet_assets::Call::force_transfer { id, source, .. }) =>
+				Some((*id, lookup(source.clone()).ok()?)),
+			Call::Assets(pallet_assets::Call::transfer_approved { id, owner, .. }) =>
+				Some((*id, lookup(owner.clone()).ok()?)),
+			Call::Assets(pallet_assets::Call::burn { id, who, .. }) =>
+				Some((*id, lookup(who.clone()).ok()?)),
+			_ => None,
+		}
+	}
+
+	/// Whether `call`, dispatched as `who`, or as root for `None`, or one of the calls it wraps,
+	/// transfers a frozen asset out of the account it debits, or mints a frozen asset.
+	fn transfers_frozen(who: Option<&AccountId>, call: &Call) -> bool {
+		// Whatever the amount: other failures, such as a lack of funds, are left to the dispatch.
+		let frozen = Self::transfer_of(who, call).map_or(false, |(asset_id, debited)| {
+			is_frozen_asset(asset_id) || is_frozen_account(asset_id, &debited)
+		}) || mint_of(call).map_or(false, is_frozen_asset);
+
+		frozen || Self::wraps_frozen(who, call)
+	}
+
+	/// Whether one of the calls `call` wraps, dispatched as `who`, or as root for `None`,
+	/// transfers or mints a frozen asset, as the account it dispatches them as.
+	fn wraps_frozen(who: Option<&AccountId>, call: &Call) -> bool {
+		let lookup = <Runtime as frame_system::Config>::Lookup::lookup;
+		match call {
+			Call::Utility(pallet_utility::Call::batch { calls }) |
+			Call::Utility(pallet_utility::Call::batch_all { calls }) |
+			Call::BatchUtility(pallet_batch_utility::Call::batch { calls }) |
+			Call::BatchUtility(pallet_batch_utility::Call::batch_all { calls }) =>
+				calls.iter().any(|call| Self::transfers_frozen(who, call)),
+			Call::Utility(pallet_utility::Call::as_derivative { index, call }) => {
+				let derivative = who.map(|who| Utility::derivative_account_id(who.clone(), *index));
+				Self::transfers_frozen(derivative.as_ref(), call)
+			},
+			Call::ProxyPermissions(pallet_proxy_permissions::Call::proxy { real, call, .. }) |
+			Call::ProxyPermissions(pallet_proxy_permissions::Call::proxy_announced {
+				real,
+				call,
+				..
+			}) => Self::transfers_frozen(Some(real), call),
+			Call::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+				other_signatories,
+				call,
+			}) => who.map_or(false, |who| {
+				let shared = Self::multisig_of(who, other_signatories, 1);
+				Self::transfers_frozen(Some(&shared), call)
+			}),
+			Call::Multisig(pallet_multisig::Call::as_multi {
+				threshold,
+				other_signatories,
+				call,
+				..
+			}) => who.map_or(false, |who| {
+				let shared = Self::multisig_of(who, other_signatories, *threshold);
+				// A call that does not decode fails the multisig itself.
+				Call::decode_with_depth_limit(MAX_CALL_DEPTH, &mut &call[..])
+					.map_or(false, |call| Self::transfers_frozen(Some(&shared), &call))
+			}),
+			Call::Sudo(pallet_sudo::Call::sudo { call }) |
+			Call::Sudo(pallet_sudo::Call::sudo_unchecked_weight { call, .. }) =>
+				Self::transfers_frozen(None, call),
+			Call::Sudo(pallet_sudo::Call::sudo_as { who, call }) => lookup(who.clone())
+				.map_or(false, |who| Self::transfers_frozen(Some(&who), call)),
+			_ => false,
+		}
+	}
+
+	/// The account of the multisig of `who` and `others`, with `threshold`.
+	fn multisig_of(who: &AccountId, others: &[AccountId], threshold: u16) -> AccountId {
+		let signatories: Vec<_> = others.iter().chain([who]).cloned().collect();
+		multisig_account(&signatories, threshold)
+	}
+}
+
+impl SignedExtension for CheckFrozenAssets {
+	const IDENTIFIER: &'static str = "CheckFrozenAssets";
+	type AccountId = AccountId;
+	type Call = Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if Self::transfers_frozen(Some(who), call) {
+			return Err(InvalidTransaction::Custom(FROZEN_ASSET).into())
+		}
+
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
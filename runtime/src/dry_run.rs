@@ -0,0 +1,20 @@
+//! Dry runs of calls, for wallets to report their outcome before they are submitted.
+
+use crate::{AccountId, Call, Event, Origin, System};
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use rusty_node_runtime_api::CallSimulation;
+use sp_runtime::traits::Dispatchable;
+
+/// Dispatch `call` signed by `origin`, and report its result and the events it deposited, then
+/// roll back all it changed.
+pub fn simulate_call(origin: AccountId, call: Call) -> CallSimulation<Event> {
+	// Runtime API calls are never committed anyway: rolling back makes it explicit, and holds if
+	// the API is ever called from within a block.
+	with_transaction(|| {
+		let deposited = System::events().len();
+		let result = call.dispatch(Origin::signed(origin));
+		let events =
+			System::events().into_iter().skip(deposited).map(|record| record.event).collect();
+		TransactionOutcome::Rollback(CallSimulation { result, events })
+	})
+}
@@ -0,0 +1,45 @@
+//! An origin restricted to the owner of an asset.
+//!
+//! [`EnsureAssetOwner`] lets a pallet require, in its `Config`, that some of its calls are signed
+//! by the current owner of an asset of the assets pallet, rather than by root: whoever the asset
+//! is transferred to takes over these calls along with it.
+
+use crate::{roles::asset_roles, AccountId, AssetId, AssetRoles, Origin};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use rusty_node_support::{Permissions, Roles};
+use sp_std::marker::PhantomData;
+
+/// The owner of the asset `id`, if it exists.
+pub fn asset_owner(id: AssetId) -> Option<AccountId> {
+	asset_roles(id).map(|(owner, ..)| owner)
+}
+
+/// Ensure the origin is signed by the owner of the asset `Id`, yielding their account.
+pub struct EnsureAssetOwner<Id>(PhantomData<Id>);
+
+impl<Id: Get<AssetId>> EnsureOrigin<Origin> for EnsureAssetOwner<Id> {
+	type Success = AccountId;
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who)
+				if AssetRoles::permissions(&Id::get(), &who).contains(Permissions::OWNER) =>
+				Ok(who),
+			r => Err(Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		use crate::Assets;
+		use frame_support::traits::tokens::fungibles::Create;
+
+		let owner = asset_owner(Id::get()).unwrap_or_else(|| {
+			let owner: AccountId = frame_benchmarking::account("owner", 0, 0);
+			Assets::create(Id::get(), owner.clone(), true, 1).expect("the asset is created");
+			owner
+		});
+		Origin::from(RawOrigin::Signed(owner))
+	}
+}
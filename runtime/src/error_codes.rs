@@ -0,0 +1,448 @@
+//! The registry of the codes of the errors of the runtime, which stay the same across upgrades.
+//!
+//! The errors that are not errors of a pallet have the pallet code `0x0000`. Then each pallet has
+//! a code, and each of its errors a code within the pallet, following the order they were added
+//! in. A new error, or a new pallet, takes the next free code: the codes of the ones removed are
+//! never reused, so that their code keeps pointing to the same message.
+//!
+//! The errors of the pallets missing from the table, such as contracts and grandpa, have no code:
+//! clients fall back to the metadata for them.
+
+use crate::{CouncilCollective, Runtime};
+use frame_support::{
+	dispatch::DispatchError,
+	sp_runtime::{ArithmeticError, TokenError},
+};
+use rusty_node_support::ErrorCodes;
+use sp_std::vec::Vec;
+
+/// The index of the pallet and of the error in the pallet, if `error` is an error of a pallet.
+fn module_error(error: &DispatchError) -> Option<(u8, u8)> {
+	match error {
+		DispatchError::Module { index, error, .. } => Some((*index, *error)),
+		_ => None,
+	}
+}
+
+macro_rules! error_codes {
+	($(
+		$pallet:ty = $pallet_code:literal {
+			$($error:ident = $code:literal $name:literal,)*
+		}
+	)*) => {
+		/// The errors of the pallets, by pallet.
+		fn pallet_error_code(error: &DispatchError) -> Option<(u32, &'static str)> {
+			let error = module_error(error)?;
+			$($(
+				if module_error(&<$pallet>::$error.into()) == Some(error) {
+					return Some((($pallet_code << 16) | $code, $name))
+				}
+			)*)*
+			None
+		}
+
+		/// All the errors of the pallets.
+		const PALLET_ERROR_CODES: &[(u32, &str)] = &[
+			$($((($pallet_code << 16) | $code, $name),)*)*
+		];
+	};
+}
+
+/// The errors that are not errors of a pallet.
+const DISPATCH_ERROR_CODES: &[(u32, &str)] = &[
+	(0x0000_0001, "dispatch.other"),
+	(0x0000_0002, "dispatch.cannot_lookup"),
+	(0x0000_0003, "dispatch.bad_origin"),
+	(0x0000_0004, "dispatch.consumer_remaining"),
+	(0x0000_0005, "dispatch.no_providers"),
+	(0x0000_0006, "token.no_funds"),
+	(0x0000_0007, "token.would_die"),
+	(0x0000_0008, "token.below_minimum"),
+	(0x0000_0009, "token.cannot_create"),
+	(0x0000_000a, "token.unknown_asset"),
+	(0x0000_000b, "token.frozen"),
+	(0x0000_000c, "token.unsupported"),
+	(0x0000_000d, "arithmetic.underflow"),
+	(0x0000_000e, "arithmetic.overflow"),
+	(0x0000_000f, "arithmetic.division_by_zero"),
+];
+
+/// The errors that are not errors of a pallet, by variant.
+fn dispatch_error_code(error: &DispatchError) -> Option<(u32, &'static str)> {
+	let index = match error {
+		DispatchError::Other(_) => 0,
+		DispatchError::CannotLookup => 1,
+		DispatchError::BadOrigin => 2,
+		DispatchError::ConsumerRemaining => 3,
+		DispatchError::NoProviders => 4,
+		DispatchError::Token(TokenError::NoFunds) => 5,
+		DispatchError::Token(TokenError::WouldDie) => 6,
+		DispatchError::Token(TokenError::BelowMinimum) => 7,
+		DispatchError::Token(TokenError::CannotCreate) => 8,
+		DispatchError::Token(TokenError::UnknownAsset) => 9,
+		DispatchError::Token(TokenError::Frozen) => 10,
+		DispatchError::Token(TokenError::Unsupported) => 11,
+		DispatchError::Arithmetic(ArithmeticError::Underflow) => 12,
+		DispatchError::Arithmetic(ArithmeticError::Overflow) => 13,
+		DispatchError::Arithmetic(ArithmeticError::DivisionByZero) => 14,
+		_ => return None,
+	};
+	Some(DISPATCH_ERROR_CODES[index])
+}
+
+error_codes! {
+	frame_system::Error<Runtime> = 0x0001 {
+		InvalidSpecName = 0x0001 "system.invalid_spec_name",
+		SpecVersionNeedsToIncrease = 0x0002 "system.spec_version_needs_to_increase",
+		FailedToExtractRuntimeVersion = 0x0003 "system.failed_to_extract_runtime_version",
+		NonDefaultComposite = 0x0004 "system.non_default_composite",
+		NonZeroRefCount = 0x0005 "system.non_zero_ref_count",
+		CallFiltered = 0x0006 "system.call_filtered",
+	}
+	pallet_balances::Error<Runtime> = 0x0002 {
+		VestingBalance = 0x0001 "balances.vesting_balance",
+		LiquidityRestrictions = 0x0002 "balances.liquidity_restrictions",
+		InsufficientBalance = 0x0003 "balances.insufficient_balance",
+		ExistentialDeposit = 0x0004 "balances.existential_deposit",
+		KeepAlive = 0x0005 "balances.keep_alive",
+		ExistingVestingSchedule = 0x0006 "balances.existing_vesting_schedule",
+		DeadAccount = 0x0007 "balances.dead_account",
+		TooManyReserves = 0x0008 "balances.too_many_reserves",
+	}
+	pallet_sudo::Error<Runtime> = 0x0003 {
+		RequireSudo = 0x0001 "sudo.require_sudo",
+	}
+	pallet_assets::Error<Runtime> = 0x0004 {
+		BalanceLow = 0x0001 "assets.balance_low",
+		BalanceZero = 0x0002 "assets.balance_zero",
+		NoPermission = 0x0003 "assets.no_permission",
+		Unknown = 0x0004 "assets.unknown",
+		Frozen = 0x0005 "assets.frozen",
+		InUse = 0x0006 "assets.in_use",
+		BadWitness = 0x0007 "assets.bad_witness",
+		MinBalanceZero = 0x0008 "assets.min_balance_zero",
+		NoProvider = 0x0009 "assets.no_provider",
+		BadMetadata = 0x000a "assets.bad_metadata",
+		Unapproved = 0x000b "assets.unapproved",
+		WouldDie = 0x000c "assets.would_die",
+	}
+	pallet_uniques::Error<Runtime> = 0x0005 {
+		NoPermission = 0x0001 "uniques.no_permission",
+		Unknown = 0x0002 "uniques.unknown",
+		AlreadyExists = 0x0003 "uniques.already_exists",
+		WrongOwner = 0x0004 "uniques.wrong_owner",
+		BadWitness = 0x0005 "uniques.bad_witness",
+		InUse = 0x0006 "uniques.in_use",
+		Frozen = 0x0007 "uniques.frozen",
+		WrongDelegate = 0x0008 "uniques.wrong_delegate",
+		NoDelegate = 0x0009 "uniques.no_delegate",
+		Unapproved = 0x000a "uniques.unapproved",
+	}
+	pallet_session::Error<Runtime> = 0x0006 {
+		InvalidProof = 0x0001 "session.invalid_proof",
+		NoAssociatedValidatorId = 0x0002 "session.no_associated_validator_id",
+		DuplicatedKey = 0x0003 "session.duplicated_key",
+		NoKeys = 0x0004 "session.no_keys",
+		NoAccount = 0x0005 "session.no_account",
+	}
+	pallet_collective::Error<Runtime, CouncilCollective> = 0x0007 {
+		NotMember = 0x0001 "council.not_member",
+		DuplicateProposal = 0x0002 "council.duplicate_proposal",
+		ProposalMissing = 0x0003 "council.proposal_missing",
+		WrongIndex = 0x0004 "council.wrong_index",
+		DuplicateVote = 0x0005 "council.duplicate_vote",
+		AlreadyInitialized = 0x0006 "council.already_initialized",
+		TooEarly = 0x0007 "council.too_early",
+		TooManyProposals = 0x0008 "council.too_many_proposals",
+		WrongProposalWeight = 0x0009 "council.wrong_proposal_weight",
+		WrongProposalLength = 0x000a "council.wrong_proposal_length",
+	}
+	pallet_scheduler::Error<Runtime> = 0x0008 {
+		FailedToSchedule = 0x0001 "scheduler.failed_to_schedule",
+		NotFound = 0x0002 "scheduler.not_found",
+		TargetBlockNumberInPast = 0x0003 "scheduler.target_block_number_in_past",
+		RescheduleNoChange = 0x0004 "scheduler.reschedule_no_change",
+	}
+	pallet_utility::Error<Runtime> = 0x0009 {
+		TooManyCalls = 0x0001 "utility.too_many_calls",
+	}
+	pallet_multisig::Error<Runtime> = 0x000a {
+		MinimumThreshold = 0x0001 "multisig.minimum_threshold",
+		AlreadyApproved = 0x0002 "multisig.already_approved",
+		NoApprovalsNeeded = 0x0003 "multisig.no_approvals_needed",
+		TooFewSignatories = 0x0004 "multisig.too_few_signatories",
+		TooManySignatories = 0x0005 "multisig.too_many_signatories",
+		SignatoriesOutOfOrder = 0x0006 "multisig.signatories_out_of_order",
+		SenderInSignatories = 0x0007 "multisig.sender_in_signatories",
+		NotFound = 0x0008 "multisig.not_found",
+		NotOwner = 0x0009 "multisig.not_owner",
+		NoTimepoint = 0x000a "multisig.no_timepoint",
+		WrongTimepoint = 0x000b "multisig.wrong_timepoint",
+		UnexpectedTimepoint = 0x000c "multisig.unexpected_timepoint",
+		MaxWeightTooLow = 0x000d "multisig.max_weight_too_low",
+		AlreadyStored = 0x000e "multisig.already_stored",
+	}
+	pallet_attestation::Error<Runtime> = 0x0010 {
+		UnknownSchema = 0x0001 "attestation.unknown_schema",
+		NotIssuer = 0x0002 "attestation.not_issuer",
+		AlreadyIssued = 0x0003 "attestation.already_issued",
+		UnknownCredential = 0x0004 "attestation.unknown_credential",
+		AlreadyRevoked = 0x0005 "attestation.already_revoked",
+		ExpiryInThePast = 0x0006 "attestation.expiry_in_the_past",
+		NoAvailableSchemaId = 0x0007 "attestation.no_available_schema_id",
+	}
+	pallet_batch_utility::Error<Runtime> = 0x0011 {
+		TooManyCalls = 0x0001 "batch_utility.too_many_calls",
+	}
+	pallet_bounty::Error<Runtime> = 0x0012 {
+		UnknownBounty = 0x0001 "bounty.unknown_bounty",
+		ZeroAmount = 0x0002 "bounty.zero_amount",
+		ExpiryInThePast = 0x0003 "bounty.expiry_in_the_past",
+		Expired = 0x0004 "bounty.expired",
+		NotExpired = 0x0005 "bounty.not_expired",
+		NoPermission = 0x0006 "bounty.no_permission",
+		NoSubmission = 0x0007 "bounty.no_submission",
+		NoAvailableBountyId = 0x0008 "bounty.no_available_bounty_id",
+		TooManySubmissions = 0x0009 "bounty.too_many_submissions",
+	}
+	pallet_club::Error<Runtime> = 0x0013 {
+		NotMember = 0x0001 "club.not_member",
+		AlreadyMember = 0x0002 "club.already_member",
+		AlreadyApplied = 0x0003 "club.already_applied",
+		NotApplied = 0x0004 "club.not_applied",
+		AlreadySponsored = 0x0005 "club.already_sponsored",
+		AlreadyVoted = 0x0006 "club.already_voted",
+		SelfKick = 0x0007 "club.self_kick",
+		TooManyMembers = 0x0008 "club.too_many_members",
+		InsufficientDeposit = 0x0009 "club.insufficient_deposit",
+	}
+	pallet_donation_matching::Error<Runtime> = 0x0014 {
+		UnknownCampaign = 0x0001 "donation_matching.unknown_campaign",
+		ZeroAmount = 0x0002 "donation_matching.zero_amount",
+		InvalidWindow = 0x0003 "donation_matching.invalid_window",
+		WindowClosed = 0x0004 "donation_matching.window_closed",
+		CampaignOngoing = 0x0005 "donation_matching.campaign_ongoing",
+		NoAvailableCampaignId = 0x0006 "donation_matching.no_available_campaign_id",
+	}
+	pallet_dutch_auction::Error<Runtime> = 0x0015 {
+		UnknownItem = 0x0001 "dutch_auction.unknown_item",
+		NotOwner = 0x0002 "dutch_auction.not_owner",
+		NotAuctioned = 0x0003 "dutch_auction.not_auctioned",
+		FloorAboveStart = 0x0004 "dutch_auction.floor_above_start",
+		PriceTooHigh = 0x0005 "dutch_auction.price_too_high",
+	}
+	pallet_game_inventory::Error<Runtime> = 0x0016 {
+		AlreadyRegistered = 0x0001 "game_inventory.already_registered",
+		TooManyResourceKinds = 0x0002 "game_inventory.too_many_resource_kinds",
+		InvalidIngredients = 0x0003 "game_inventory.invalid_ingredients",
+		UnknownRecipe = 0x0004 "game_inventory.unknown_recipe",
+		NoAvailableId = 0x0005 "game_inventory.no_available_id",
+	}
+	pallet_gift_voucher::Error<Runtime> = 0x0017 {
+		UnknownVoucher = 0x0001 "gift_voucher.unknown_voucher",
+		ZeroAmount = 0x0002 "gift_voucher.zero_amount",
+		ExpiryInThePast = 0x0003 "gift_voucher.expiry_in_the_past",
+		Expired = 0x0004 "gift_voucher.expired",
+		NotExpired = 0x0005 "gift_voucher.not_expired",
+		WrongSecret = 0x0006 "gift_voucher.wrong_secret",
+		NoAvailableVoucherId = 0x0007 "gift_voucher.no_available_voucher_id",
+		NotCommitted = 0x0008 "gift_voucher.not_committed",
+		AlreadyCommitted = 0x0009 "gift_voucher.already_committed",
+	}
+	pallet_invoice::Error<Runtime> = 0x0018 {
+		UnknownInvoice = 0x0001 "invoice.unknown_invoice",
+		ZeroAmount = 0x0002 "invoice.zero_amount",
+		ExpiryInThePast = 0x0003 "invoice.expiry_in_the_past",
+		Expired = 0x0004 "invoice.expired",
+		NoPermission = 0x0005 "invoice.no_permission",
+		NoAvailableInvoiceId = 0x0006 "invoice.no_available_invoice_id",
+	}
+	pallet_kitties::Error<Runtime> = 0x0019 {
+		UnknownKitty = 0x0001 "kitties.unknown_kitty",
+		NotOwner = 0x0002 "kitties.not_owner",
+		TooManyOwned = 0x0003 "kitties.too_many_owned",
+		TransferToSelf = 0x0004 "kitties.transfer_to_self",
+		SameParent = 0x0005 "kitties.same_parent",
+		SameGender = 0x0006 "kitties.same_gender",
+		NotForSale = 0x0007 "kitties.not_for_sale",
+		BidTooLow = 0x0008 "kitties.bid_too_low",
+		NoAvailableKittyId = 0x0009 "kitties.no_available_kitty_id",
+	}
+	pallet_liquidity_mining::Error<Runtime> = 0x001a {
+		UnknownFarm = 0x0001 "liquidity_mining.unknown_farm",
+		ZeroAmount = 0x0002 "liquidity_mining.zero_amount",
+		InsufficientStake = 0x0003 "liquidity_mining.insufficient_stake",
+		NoAvailableFarmId = 0x0004 "liquidity_mining.no_available_farm_id",
+	}
+	pallet_loyalty::Error<Runtime> = 0x001b {
+		NotMerchant = 0x0001 "loyalty.not_merchant",
+		CapExceeded = 0x0002 "loyalty.cap_exceeded",
+		TooManyBatches = 0x0003 "loyalty.too_many_batches",
+		UnknownReward = 0x0004 "loyalty.unknown_reward",
+		InsufficientPoints = 0x0005 "loyalty.insufficient_points",
+		UnknownVoucher = 0x0006 "loyalty.unknown_voucher",
+		NotVoucherMerchant = 0x0007 "loyalty.not_voucher_merchant",
+		NoAvailableId = 0x0008 "loyalty.no_available_id",
+	}
+	pallet_maintenance_mode::Error<Runtime> = 0x001c {
+		AlreadyInMaintenanceMode = 0x0001 "maintenance_mode.already_in_maintenance_mode",
+		NotInMaintenanceMode = 0x0002 "maintenance_mode.not_in_maintenance_mode",
+	}
+	pallet_nft_rental::Error<Runtime> = 0x001d {
+		UnknownItem = 0x0001 "nft_rental.unknown_item",
+		NotOwner = 0x0002 "nft_rental.not_owner",
+		NotListed = 0x0003 "nft_rental.not_listed",
+		AlreadyListed = 0x0004 "nft_rental.already_listed",
+		CurrentlyRented = 0x0005 "nft_rental.currently_rented",
+		ZeroPeriod = 0x0006 "nft_rental.zero_period",
+		TooManyPeriods = 0x0007 "nft_rental.too_many_periods",
+		TooManyLeasesInBlock = 0x0008 "nft_rental.too_many_leases_in_block",
+		Overflow = 0x0009 "nft_rental.overflow",
+	}
+	pallet_order_book::Error<Runtime> = 0x001e {
+		SameAsset = 0x0001 "order_book.same_asset",
+		ZeroAmount = 0x0002 "order_book.zero_amount",
+		ZeroPrice = 0x0003 "order_book.zero_price",
+		UnknownOrder = 0x0004 "order_book.unknown_order",
+		NotOrderOwner = 0x0005 "order_book.not_order_owner",
+		BookFull = 0x0006 "order_book.book_full",
+		Overflow = 0x0007 "order_book.overflow",
+		NoAvailableOrderId = 0x0008 "order_book.no_available_order_id",
+	}
+	pallet_otc_swap::Error<Runtime> = 0x001f {
+		UnknownOffer = 0x0001 "otc_swap.unknown_offer",
+		SameAsset = 0x0002 "otc_swap.same_asset",
+		ZeroAmount = 0x0003 "otc_swap.zero_amount",
+		ExpiryInThePast = 0x0004 "otc_swap.expiry_in_the_past",
+		Expired = 0x0005 "otc_swap.expired",
+		NoPermission = 0x0006 "otc_swap.no_permission",
+		NoAvailableOfferId = 0x0007 "otc_swap.no_available_offer_id",
+	}
+	pallet_pause_registry::Error<Runtime> = 0x0020 {
+		NameTooLong = 0x0001 "pause_registry.name_too_long",
+		AlreadyPaused = 0x0002 "pause_registry.already_paused",
+		NotPaused = 0x0003 "pause_registry.not_paused",
+		Paused = 0x0004 "pause_registry.paused",
+	}
+	pallet_price_fetch::Error<Runtime> = 0x0021 {
+		NotAuthority = 0x0001 "price_fetch.not_authority",
+		AlreadyAuthority = 0x0002 "price_fetch.already_authority",
+		TooManyAuthorities = 0x0003 "price_fetch.too_many_authorities",
+	}
+	pallet_proof_of_existence::Error<Runtime> = 0x0022 {
+		AlreadyClaimed = 0x0001 "proof_of_existence.already_claimed",
+		NoSuchClaim = 0x0002 "proof_of_existence.no_such_claim",
+		NotClaimOwner = 0x0003 "proof_of_existence.not_claim_owner",
+		TransferToSelf = 0x0004 "proof_of_existence.transfer_to_self",
+	}
+	pallet_proxy_permissions::Error<Runtime> = 0x0023 {
+		NoSelfProxy = 0x0001 "proxy_permissions.no_self_proxy",
+		Duplicate = 0x0002 "proxy_permissions.duplicate",
+		TooMany = 0x0003 "proxy_permissions.too_many",
+		NotProxy = 0x0004 "proxy_permissions.not_proxy",
+		Unproxyable = 0x0005 "proxy_permissions.unproxyable",
+		UnknownAnnouncement = 0x0006 "proxy_permissions.unknown_announcement",
+		Unannounced = 0x0007 "proxy_permissions.unannounced",
+	}
+	pallet_quadratic_voting::Error<Runtime> = 0x0024 {
+		UnknownProposal = 0x0001 "quadratic_voting.unknown_proposal",
+		VotingEnded = 0x0002 "quadratic_voting.voting_ended",
+		VotingOngoing = 0x0003 "quadratic_voting.voting_ongoing",
+		ZeroVotes = 0x0004 "quadratic_voting.zero_votes",
+		Overflow = 0x0005 "quadratic_voting.overflow",
+		NoVotes = 0x0006 "quadratic_voting.no_votes",
+		NoAvailableProposalId = 0x0007 "quadratic_voting.no_available_proposal_id",
+	}
+	pallet_referral_rewards::Error<Runtime> = 0x0025 {
+		CodeTaken = 0x0001 "referral_rewards.code_taken",
+		AlreadyHasCode = 0x0002 "referral_rewards.already_has_code",
+		UnknownCode = 0x0003 "referral_rewards.unknown_code",
+		SelfReferral = 0x0004 "referral_rewards.self_referral",
+		AlreadyActive = 0x0005 "referral_rewards.already_active",
+		NothingToClaim = 0x0006 "referral_rewards.nothing_to_claim",
+	}
+	pallet_reputation::Error<Runtime> = 0x0026 {
+		ZeroAmount = 0x0001 "reputation.zero_amount",
+	}
+	pallet_royalty_registry::Error<Runtime> = 0x0027 {
+		UnknownClass = 0x0001 "royalty_registry.unknown_class",
+		NotClassOwner = 0x0002 "royalty_registry.not_class_owner",
+		RateTooHigh = 0x0003 "royalty_registry.rate_too_high",
+		NoRoyalty = 0x0004 "royalty_registry.no_royalty",
+	}
+	pallet_social_recovery::Error<Runtime> = 0x0028 {
+		NotRecoverable = 0x0001 "social_recovery.not_recoverable",
+		InvalidThreshold = 0x0002 "social_recovery.invalid_threshold",
+		DuplicateGuardian = 0x0003 "social_recovery.duplicate_guardian",
+		TooManyGuardians = 0x0004 "social_recovery.too_many_guardians",
+		RecoveryInProgress = 0x0005 "social_recovery.recovery_in_progress",
+		AlreadyInitiated = 0x0006 "social_recovery.already_initiated",
+		NotInitiated = 0x0007 "social_recovery.not_initiated",
+		NotGuardian = 0x0008 "social_recovery.not_guardian",
+		AlreadyVouched = 0x0009 "social_recovery.already_vouched",
+		ThresholdNotReached = 0x000a "social_recovery.threshold_not_reached",
+		DelayNotElapsed = 0x000b "social_recovery.delay_not_elapsed",
+		AlreadyRecovered = 0x000c "social_recovery.already_recovered",
+		NotRescuer = 0x000d "social_recovery.not_rescuer",
+		NotOwner = 0x000e "social_recovery.not_owner",
+		TooManyRecoveries = 0x000f "social_recovery.too_many_recoveries",
+	}
+	pallet_stablecoin::Error<Runtime> = 0x0029 {
+		ZeroAmount = 0x0001 "stablecoin.zero_amount",
+		NoVault = 0x0002 "stablecoin.no_vault",
+		InsufficientCollateral = 0x0003 "stablecoin.insufficient_collateral",
+		ExcessiveRepayment = 0x0004 "stablecoin.excessive_repayment",
+		Undercollateralized = 0x0005 "stablecoin.undercollateralized",
+		NotLiquidatable = 0x0006 "stablecoin.not_liquidatable",
+		PriceUnavailable = 0x0007 "stablecoin.price_unavailable",
+		InsufficientBalance = 0x0008 "stablecoin.insufficient_balance",
+		MigrationOngoing = 0x0009 "stablecoin.migration_ongoing",
+	}
+	pallet_timed_transfer::Error<Runtime> = 0x002a {
+		ZeroAmount = 0x0001 "timed_transfer.zero_amount",
+		UnknownTransfer = 0x0002 "timed_transfer.unknown_transfer",
+		NotSender = 0x0003 "timed_transfer.not_sender",
+		TooManyPending = 0x0004 "timed_transfer.too_many_pending",
+		NoAvailableTransferId = 0x0005 "timed_transfer.no_available_transfer_id",
+		AlreadyReleased = 0x0006 "timed_transfer.already_released",
+	}
+	pallet_timelock::Error<Runtime> = 0x002b {
+		TooEarly = 0x0001 "timelock.too_early",
+		AgendaFull = 0x0002 "timelock.agenda_full",
+		UnknownTask = 0x0003 "timelock.unknown_task",
+		NotOwner = 0x0004 "timelock.not_owner",
+		CallTooLarge = 0x0005 "timelock.call_too_large",
+		CallTooHeavy = 0x0006 "timelock.call_too_heavy",
+	}
+	pallet_treasury::Error<Runtime> = 0x002c {
+		UnknownProposal = 0x0001 "treasury.unknown_proposal",
+		ZeroAmount = 0x0002 "treasury.zero_amount",
+		InsufficientBond = 0x0003 "treasury.insufficient_bond",
+		NoAvailableProposalId = 0x0004 "treasury.no_available_proposal_id",
+	}
+	pallet_wrapped_native::Error<Runtime> = 0x002d {
+		ZeroAmount = 0x0001 "wrapped_native.zero_amount",
+	}
+	pallet_unlock_queue::Error<Runtime> = 0x002e {
+		InThePast = 0x0001 "unlock_queue.in_the_past",
+		QueueFull = 0x0002 "unlock_queue.queue_full",
+	}
+	pallet_storage_repair::Error<Runtime> = 0x002f {
+		UnknownAsset = 0x0001 "storage_repair.unknown_asset",
+		UnknownItem = 0x0002 "storage_repair.unknown_item",
+		BadWitness = 0x0003 "storage_repair.bad_witness",
+	}
+}
+
+/// The registry of the runtime.
+pub struct RuntimeErrorCodes;
+
+impl ErrorCodes for RuntimeErrorCodes {
+	fn error_code(error: &DispatchError) -> Option<(u32, &'static str)> {
+		dispatch_error_code(error).or_else(|| pallet_error_code(error))
+	}
+
+	fn error_codes() -> Vec<(u32, &'static str)> {
+		DISPATCH_ERROR_CODES.iter().chain(PALLET_ERROR_CODES).copied().collect()
+	}
+}
@@ -0,0 +1,56 @@
+//! What the events of the runtime change in the counters of the explorer-counters pallet.
+//!
+//! Assets are created by the assets pallet, and nonfungible items are the items of the uniques
+//! pallet and the kitties. Transfers are the ones of the native currency, of assets and of
+//! nonfungible items. The holders of an asset are read from its details in the assets pallet.
+//! The creation of the items of the uniques pallet is timestamped, the kitties have no item id.
+//! Destroying an asset drops its creation timestamp, and destroying a class of the uniques pallet
+//! burns its items without an event for each of them.
+
+use crate::{roles::details, AccountId, AssetId, Assets, Balance, ClassId, Event, InstanceId};
+use codec::Encode;
+use pallet_explorer_counters::{Observation, Observe};
+use rusty_node_primitives::AssetDetails;
+
+/// The observer of the events of the runtime.
+pub struct ExplorerObserver;
+
+impl Observe<Event, AssetId, ClassId, InstanceId> for ExplorerObserver {
+	fn observe(event: &Event) -> Option<Observation<AssetId, ClassId, InstanceId>> {
+		match event {
+			Event::Assets(
+				pallet_assets::Event::Created { asset_id, .. } |
+				pallet_assets::Event::ForceCreated { asset_id, .. },
+			) => Some(Observation::AssetCreated(*asset_id)),
+			Event::Assets(
+				pallet_assets::Event::Issued { asset_id, .. } |
+				pallet_assets::Event::Burned { asset_id, .. },
+			) => Some(Observation::Holdings(*asset_id)),
+			Event::Assets(pallet_assets::Event::Destroyed { asset_id }) =>
+				Some(Observation::AssetDestroyed(*asset_id)),
+			Event::Assets(
+				pallet_assets::Event::Transferred { asset_id, .. } |
+				pallet_assets::Event::TransferredApproved { asset_id, .. },
+			) => Some(Observation::Transfer(Some(*asset_id))),
+			Event::Uniques(pallet_uniques::Event::Issued { class, instance, .. }) =>
+				Some(Observation::ItemIssued(Some((*class, *instance)))),
+			Event::Kitties(pallet_kitties::Event::Created { .. }) =>
+				Some(Observation::ItemIssued(None)),
+			Event::Uniques(pallet_uniques::Event::Burned { class, instance, .. }) =>
+				Some(Observation::ItemBurned(Some((*class, *instance)))),
+			Event::Uniques(pallet_uniques::Event::Destroyed { class }) =>
+				Some(Observation::ClassDestroyed(*class)),
+			Event::Balances(pallet_balances::Event::Transfer { .. }) |
+			Event::Uniques(pallet_uniques::Event::Transferred { .. }) |
+			Event::Kitties(
+				pallet_kitties::Event::Transferred { .. } | pallet_kitties::Event::Sold { .. },
+			) => Some(Observation::Transfer(None)),
+			_ => None,
+		}
+	}
+
+	fn holders(asset: &AssetId) -> u32 {
+		details::<Assets, AssetDetails<Balance, AccountId, Balance>>(b"Asset", &[asset.encode()])
+			.map_or(0, |details| details.accounts)
+	}
+}
@@ -0,0 +1,48 @@
+//! Where the fees of the runtime go: a share to the treasury, the rest burned.
+//!
+//! [`DealWithFees`] splits the fees and tips of the transactions paid in the native currency, and
+//! [`deposit_asset_fee`] the ones paid with assets, both along the `TreasuryFeeShare` of the
+//! runtime, the single place the split is configured.
+
+use crate::{AccountId, AssetId, Assets, Balance, Balances, Treasury, TreasuryFeeShare};
+use frame_support::traits::{tokens::fungibles::Mutate, Currency, Get, Imbalance, OnUnbalanced};
+use sp_runtime::{traits::Zero, PerThing};
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// The parts of the treasury and of the burn in the fees.
+fn split() -> (u32, u32) {
+	let share = TreasuryFeeShare::get();
+	(share.deconstruct(), share.left_from_one().deconstruct())
+}
+
+/// Send the `TreasuryFeeShare` of the native fees, and tips, to the treasury, and burn the rest.
+pub struct DealWithFees;
+
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
+		if let Some(fees) = fees_then_tips.next() {
+			let (treasury, burn) = split();
+			let mut split = fees.ration(treasury, burn);
+			if let Some(tips) = fees_then_tips.next() {
+				tips.ration_merge_into(treasury, burn, &mut split);
+			}
+
+			Treasury::on_unbalanced(split.0);
+			// Dropping the rest burns it.
+		}
+	}
+}
+
+/// Mint the `TreasuryFeeShare` of the `fee` burned from the payer of a transaction in the asset
+/// `asset_id` into the treasury, the rest staying burned.
+pub fn deposit_asset_fee(asset_id: AssetId, fee: Balance) {
+	let share = TreasuryFeeShare::get().mul_floor(fee);
+	if share.is_zero() {
+		return
+	}
+	// A share the treasury cannot take, e.g. under the minimum balance of the asset, stays burned
+	// along with the rest. The events of the assets pallet already tell: the whole fee was burned
+	// when it was withdrawn, and the share is only issued to the treasury if it is minted.
+	let _ = Assets::mint_into(asset_id, &Treasury::account_id(), share);
+}
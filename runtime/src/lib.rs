@@ -9,14 +9,35 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	storage::storage_prefix,
+	traits::{
+		tokens::{fungibles, nonfungibles},
+		Contains, EqualPrivilegeOnly, GetCallMetadata, InstanceFilter, PalletInfoAccess,
+		SortedMembers,
+	},
+	Blake2_128Concat, PalletId, StorageHasher, Twox64Concat,
+};
+use frame_system::{EnsureOneOf, EnsureRoot, EnsureSignedBy};
+use pallet_maintenance_mode::MaintenanceFilter;
+use pallet_pause_registry::Pausable;
+use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_core::{
+	crypto::KeyTypeId,
+	u32_trait::{_2, _3},
+	OpaqueMetadata,
+};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
-	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	traits::{
+		self, AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, IdentifyAccount,
+		NumberFor, OpaqueKeys, SaturatedConversion, StaticLookup, Verify,
+	},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
+	ApplyExtrinsicResult, DispatchError, FixedPointNumber, MultiSignature, RuntimeDebug,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -35,14 +56,46 @@ pub use frame_support::{
 };
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::CurrencyAdapter;
+use rusty_node_runtime_api::{
+	AssetEntry, AuctionListing, CallSimulation, ChainCounters, ClassEntry, ErrorCode, KittyId,
+	KittyListing, OfferId, OfferListing, Page,
+};
+use rusty_node_support::{
+	pagination::{decode_key, iter_page},
+	ErrorCodes,
+};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{FixedU128, Perbill, Permill};
 
 /// Import the template pallet.
 pub use pallet_template;
 
+mod asset_fees;
+pub use asset_fees::{AssetFeeAdapter, FeePayment};
+pub mod chain_extension;
+pub use chain_extension::RuntimeExtension;
+pub mod fees;
+mod check_frozen_assets;
+pub use check_frozen_assets::{is_frozen_asset, CheckFrozenAssets, FROZEN_ASSET};
+pub mod dry_run;
+mod ensure_asset_owner;
+pub use ensure_asset_owner::{asset_owner, EnsureAssetOwner};
+pub mod error_codes;
+pub use error_codes::RuntimeErrorCodes;
+mod explorer;
+pub use explorer::ExplorerObserver;
+mod listings;
+mod roles;
+pub use roles::{AssetRoles, ItemRoles};
+mod unlocks;
+pub use unlocks::{RuntimeUnlocks, UnlockKey};
+
+#[cfg(feature = "upgrade-test")]
+pub mod upgrade_test;
+#[cfg(feature = "upgrade-test")]
+pub use upgrade_test::VERSION;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -62,6 +115,13 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// A timestamp: milliseconds since the unix epoch.
+pub type Moment = u64;
+
+/// Identifiers of the fungible assets, of the classes of nonfungible items and of the items
+/// within their class.
+pub use rusty_node_primitives::{AssetId, ClassId, InstanceId};
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -88,6 +148,7 @@ pub mod opaque {
 
 // To learn more about runtime versioning and what each of the following value means:
 //   https://docs.substrate.io/v3/runtime/upgrades#runtime-versioning
+#[cfg(not(feature = "upgrade-test"))]
 #[sp_version::runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("rusty-node"),
@@ -121,6 +182,18 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+/// The asset wrapping the native currency, also used as stablecoin collateral and to vote.
+pub const WRAPPED_NATIVE_ASSET_ID: AssetId = AssetId(0);
+
+/// The stablecoin, minted against collateral.
+pub const STABLE_ASSET_ID: AssetId = AssetId(1);
+
+/// The demo token, created by the development and local chain specs.
+pub const DEMO_ASSET_ID: AssetId = AssetId(2);
+
+/// The most entries a paginated runtime API query returns at once.
+pub const MAX_PAGE_SIZE: u32 = 1_000;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -150,8 +223,9 @@ impl frame_system::Config for Runtime {
 	type AccountData = pallet_balances::AccountData<Balance>;
 	/// The identifier used to distinguish between accounts.
 	type AccountId = AccountId;
-	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	/// The basic call filter to use in dispatchable: every call, outside of maintenance mode, but
+	/// the ones of the paused pallets.
+	type BaseCallFilter = BaseCallFilter;
 	/// Maximum number of block number to block hash mappings to keep (oldest pruned first).
 	type BlockHashCount = BlockHashCount;
 	/// The maximum length of a block (in bytes).
@@ -223,14 +297,34 @@ impl pallet_grandpa::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const SessionPeriod: BlockNumber = HOURS;
+	pub const SessionOffset: BlockNumber = 0;
+	pub const DisabledValidatorsThreshold: Perbill = Perbill::from_percent(33);
+}
+
+/// The authorities are the validators set at genesis: a new session only applies the keys they
+/// set with `set_keys`, so that they rotate their Aura and Grandpa keys without a runtime upgrade.
+impl pallet_session::Config for Runtime {
+	type Event = Event;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type SessionManager = ();
+	type SessionHandler = <opaque::SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = opaque::SessionKeys;
+	type DisabledValidatorsThreshold = DisabledValidatorsThreshold;
+	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
 }
 
 impl pallet_timestamp::Config for Runtime {
 	type MinimumPeriod = MinimumPeriod;
-	/// A timestamp: milliseconds since the unix epoch.
-	type Moment = u64;
+	type Moment = Moment;
 	type OnTimestampSet = Aura;
 	type WeightInfo = ();
 }
@@ -244,7 +338,8 @@ impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
 	/// The type for recording an account's balance.
 	type Balance = Balance;
-	type DustRemoval = ();
+	/// The dust of the accounts reaped goes to the treasury.
+	type DustRemoval = Treasury;
 	/// The ubiquitous event type.
 	type Event = Event;
 	type ExistentialDeposit = ExistentialDeposit;
@@ -255,13 +350,21 @@ impl pallet_balances::Config for Runtime {
 }
 
 parameter_types! {
+	/// The share of the fees going to the treasury, the rest being burned.
+	pub const TreasuryFeeShare: Perbill = Perbill::from_percent(80);
 	pub const TransactionByteFee: Balance = 1;
 	pub OperationalFeeMultiplier: u8 = 5;
+	/// The assets fees can be paid with, by accounts lacking the native currency, and how many of
+	/// their units pay for a unit of the native currency.
+	pub FeeAssetRates: Vec<(AssetId, FixedU128)> = vec![
+		(WRAPPED_NATIVE_ASSET_ID, FixedU128::saturating_from_integer(1)),
+		(DEMO_ASSET_ID, FixedU128::saturating_from_integer(2)),
+	];
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = ();
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = AssetFeeAdapter<FeeAssetRates>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = IdentityFee<Balance>;
@@ -272,11 +375,864 @@ impl pallet_sudo::Config for Runtime {
 	type Event = Event;
 }
 
+// Let offchain workers, such as the price-fetch one, submit transactions.
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(Call, <UncheckedExtrinsic as traits::Extrinsic>::SignaturePayload)> {
+		let tip = 0;
+		// Mortal for about half of the block hashes kept.
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			CheckFrozenAssets::new(),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (address, signature, extra)))
+	}
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	Call: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = Call;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	/// The accounts allowed to dispatch the privileged calls of the pallets, along with root. Kept
+	/// in storage, so that root can appoint them with `system.setStorage`.
+	pub storage Operators: Vec<AccountId> = Vec::new();
+}
+
+/// The [`Operators`], sorted.
+pub struct SortedOperators;
+
+impl SortedMembers<AccountId> for SortedOperators {
+	fn sorted_members() -> Vec<AccountId> {
+		let mut operators = Operators::get();
+		operators.sort();
+		operators
+	}
+}
+
+/// Root, through sudo, or one of the [`Operators`]: the origin of the privileged calls of the
+/// pallets, such as the `force_*` calls of the assets and uniques pallets.
+pub type EnsureRootOrOperator =
+	EnsureOneOf<AccountId, EnsureRoot<AccountId>, EnsureSignedBy<SortedOperators, AccountId>>;
+
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub const CouncilMaxProposals: u32 = 100;
+	pub const CouncilMaxMembers: u32 = 100;
+}
+
+/// The instance of the collective pallet of the council.
+pub type CouncilCollective = pallet_collective::Instance1;
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type Event = Event;
+	type MaxMembers = CouncilMaxMembers;
+	type MaxProposals = CouncilMaxProposals;
+	type MotionDuration = CouncilMotionDuration;
+	type Origin = Origin;
+	type Proposal = Call;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+}
+
+/// Two thirds of the council, through a motion.
+pub type EnsureCouncilTwoThirds =
+	pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, CouncilCollective>;
+
+/// Root, an operator, or two thirds of the council: the origin of the admin operations, such as
+/// freezing assets, destroying classes of items, and the maintenance mode.
+pub type EnsureAdminOrCouncil =
+	EnsureOneOf<AccountId, EnsureRootOrOperator, EnsureCouncilTwoThirds>;
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * ExistentialDeposit::get();
+	pub const ApprovalDeposit: Balance = ExistentialDeposit::get();
+	pub const MetadataDepositBase: Balance = 10 * ExistentialDeposit::get();
+	pub const MetadataDepositPerByte: Balance = ExistentialDeposit::get();
+	pub const StringLimit: u32 = 50;
+}
+
+/// The fungible assets the tutorial pallets trade, escrow and mint.
+impl pallet_assets::Config for Runtime {
+	type ApprovalDeposit = ApprovalDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type Currency = Balances;
+	type Event = Event;
+	type Extra = ();
+	type ForceOrigin = EnsureAdminOrCouncil;
+	type Freezer = ();
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type StringLimit = StringLimit;
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ClassDeposit: Balance = 100 * ExistentialDeposit::get();
+	pub const InstanceDeposit: Balance = ExistentialDeposit::get();
+	pub const AttributeDepositBase: Balance = 10 * ExistentialDeposit::get();
+	pub const DepositPerByte: Balance = ExistentialDeposit::get();
+	pub const KeyLimit: u32 = 32;
+	pub const ValueLimit: u32 = 256;
+}
+
+/// The nonfungible items the tutorial pallets trade, escrow and mint.
+impl pallet_uniques::Config for Runtime {
+	type AttributeDepositBase = AttributeDepositBase;
+	type ClassDeposit = ClassDeposit;
+	type ClassId = ClassId;
+	type Currency = Balances;
+	type DepositPerByte = DepositPerByte;
+	type Event = Event;
+	type ForceOrigin = EnsureAdminOrCouncil;
+	type InstanceDeposit = InstanceDeposit;
+	type InstanceId = InstanceId;
+	type KeyLimit = KeyLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type StringLimit = StringLimit;
+	type ValueLimit = ValueLimit;
+	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ContractDepositPerItem: Balance = 10 * ExistentialDeposit::get();
+	pub const ContractDepositPerByte: Balance = ExistentialDeposit::get();
+	pub const DeletionQueueDepth: u32 = 128;
+	pub DeletionWeightLimit: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+}
+
+/// Smart contracts, which use the assets pallet through the [`RuntimeExtension`].
+impl pallet_contracts::Config for Runtime {
+	type Call = Call;
+	type CallFilter = frame_support::traits::Nothing;
+	type CallStack = [pallet_contracts::Frame<Self>; 31];
+	type ChainExtension = RuntimeExtension;
+	type Currency = Balances;
+	type DeletionQueueDepth = DeletionQueueDepth;
+	type DeletionWeightLimit = DeletionWeightLimit;
+	type DepositPerByte = ContractDepositPerByte;
+	type DepositPerItem = ContractDepositPerItem;
+	type Event = Event;
+	type Randomness = RandomnessCollectiveFlip;
+	type Schedule = Schedule;
+	type Time = Timestamp;
+	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Runtime>;
+	type WeightPrice = pallet_transaction_payment::Pallet<Runtime>;
+}
+
+impl pallet_attestation::Config for Runtime {
+	type Event = Event;
+	type WeightInfo = pallet_attestation::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxCalls: u32 = 16;
+}
+
+impl pallet_batch_utility::Config for Runtime {
+	type Call = Call;
+	type Event = Event;
+	type MaxCalls = MaxCalls;
+	type WeightInfo = pallet_batch_utility::weights::SubstrateWeight<Runtime>;
+}
+
+/// Batches of calls dispatched with the origin of the batch, next to the ones of the batch
+/// utility pallet, so that wallets batch with the pallet they know.
+impl pallet_utility::Config for Runtime {
+	type Call = Call;
+	type Event = Event;
+	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MultisigDepositBase: Balance = 10 * ExistentialDeposit::get();
+	pub const MultisigDepositFactor: Balance = ExistentialDeposit::get();
+	pub const MaxSignatories: u16 = 100;
+}
+
+/// Accounts shared by several signatories, dispatching the calls a threshold of them approved.
+impl pallet_multisig::Config for Runtime {
+	type Call = Call;
+	type Currency = Balances;
+	type DepositBase = MultisigDepositBase;
+	type DepositFactor = MultisigDepositFactor;
+	type Event = Event;
+	type MaxSignatories = MaxSignatories;
+	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+/// The account of the multisig of `signatories` and `threshold`, in any order: the account to
+/// make the owner of an asset, or of a class of items, shared by the signatories.
+pub fn multisig_account(signatories: &[AccountId], threshold: u16) -> AccountId {
+	let mut signatories = signatories.to_vec();
+	signatories.sort();
+	Multisig::multi_account_id(&signatories, threshold)
+}
+
+parameter_types! {
+	pub const BountyPalletId: PalletId = PalletId(*b"py/bount");
+	pub const BountySubmissionDeposit: Balance = 10 * ExistentialDeposit::get();
+	pub const MaxBountySubmissions: u32 = 100;
+}
+
+impl pallet_bounty::Config for Runtime {
+	type ArbiterOrigin = EnsureRootOrOperator;
+	type Assets = Assets;
+	type Currency = Balances;
+	type Event = Event;
+	type MaxSubmissions = MaxBountySubmissions;
+	type PalletId = BountyPalletId;
+	type SubmissionDeposit = BountySubmissionDeposit;
+	type WeightInfo = pallet_bounty::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const JoinDeposit: Balance = 100 * ExistentialDeposit::get();
+	pub const SponsorsNeeded: u32 = 2;
+	pub const MaxMembers: u32 = 100;
+}
+
+impl pallet_club::Config for Runtime {
+	type Currency = Balances;
+	type Event = Event;
+	type JoinDeposit = JoinDeposit;
+	type MaxMembers = MaxMembers;
+	type SponsorsNeeded = SponsorsNeeded;
+	type WeightInfo = pallet_club::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const DonationMatchingPalletId: PalletId = PalletId(*b"py/match");
+}
+
+impl pallet_donation_matching::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type PalletId = DonationMatchingPalletId;
+	type WeightInfo = pallet_donation_matching::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	/// The share of the price of the items sold on the marketplaces charged as a fee.
+	pub const MarketplaceFee: Perbill = Perbill::from_percent(1);
+	/// The share of what takers pay on the exchanges charged as a fee: 0.3%.
+	pub const DexFee: Perbill = Perbill::from_parts(3_000_000);
+	/// The fees of the trades go to the treasury, but the share of the referrers of the payers.
+	pub TreasuryAccount: AccountId = Treasury::account_id();
+}
+
+parameter_types! {
+	pub const DutchAuctionPalletId: PalletId = PalletId(*b"py/dutch");
+}
+
+impl pallet_dutch_auction::Config for Runtime {
+	type Assets = Assets;
+	type ClassId = ClassId;
+	type Currency = Balances;
+	type Event = Event;
+	type FeeCollector = TreasuryAccount;
+	type InstanceId = InstanceId;
+	type Items = Uniques;
+	type OnFeeCharged = ReferralRewards;
+	type PalletId = DutchAuctionPalletId;
+	type Royalties = RoyaltyRegistry;
+	type TradeFee = MarketplaceFee;
+	type WeightInfo = pallet_dutch_auction::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxResourceKinds: u32 = 16;
+	pub const MaxIngredients: u32 = 8;
+	/// The game's currency, whose owner manages its resources and recipes.
+	pub const GameAssetId: AssetId = DEMO_ASSET_ID;
+}
+
+impl pallet_game_inventory::Config for Runtime {
+	type ClassId = ClassId;
+	type Event = Event;
+	type InstanceId = InstanceId;
+	type Items = Uniques;
+	type MaxIngredients = MaxIngredients;
+	type MaxResourceKinds = MaxResourceKinds;
+	type RecipeOrigin = EnsureAssetOwner<GameAssetId>;
+	type Resources = Assets;
+	type WeightInfo = pallet_game_inventory::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const VoucherCodeLength: u32 = 32;
+	pub const GiftVoucherPalletId: PalletId = PalletId(*b"py/vouch");
+	pub const VoucherUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 4;
+	pub const VoucherCommitmentDeposit: Balance = 10 * ExistentialDeposit::get();
+	pub const VoucherCommitmentLifetime: BlockNumber = DAYS;
+}
+
+impl pallet_gift_voucher::Config for Runtime {
+	type Assets = Assets;
+	type CommitmentDeposit = VoucherCommitmentDeposit;
+	type CommitmentLifetime = VoucherCommitmentLifetime;
+	type Currency = Balances;
+	type Event = Event;
+	type MaxCodeLength = VoucherCodeLength;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type OffchainSignature = Signature;
+	type PalletId = GiftVoucherPalletId;
+	type Unlocks = UnlockQueue;
+	type UnsignedPriority = VoucherUnsignedPriority;
+	type WeightInfo = pallet_gift_voucher::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_invoice::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type WeightInfo = pallet_invoice::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxKittiesOwned: u32 = 100;
+}
+
+impl pallet_kitties::Config for Runtime {
+	type Currency = Balances;
+	type Event = Event;
+	type FeeCollector = TreasuryAccount;
+	type MaxKittiesOwned = MaxKittiesOwned;
+	type OnFeeCharged = ReferralRewards;
+	type Pauses = PauseRegistry;
+	type Randomness = RandomnessCollectiveFlip;
+	type TradeFee = MarketplaceFee;
+	type WeightInfo = pallet_kitties::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const LiquidityMiningPalletId: PalletId = PalletId(*b"py/farms");
+}
+
+impl pallet_liquidity_mining::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type FarmOrigin = EnsureRootOrOperator;
+	type PalletId = LiquidityMiningPalletId;
+	type WeightInfo = pallet_liquidity_mining::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxBatches: u32 = 16;
+	pub const MaxRewards: u32 = 100;
+}
+
+impl pallet_loyalty::Config for Runtime {
+	type ClassId = ClassId;
+	type Event = Event;
+	type InstanceId = InstanceId;
+	type Items = Uniques;
+	type MaxBatches = MaxBatches;
+	type MaxRewards = MaxRewards;
+	type MerchantOrigin = EnsureRootOrOperator;
+	type WeightInfo = pallet_loyalty::weights::SubstrateWeight<Runtime>;
+}
+
+/// The calls kept in maintenance mode: the ones of the pallets producing and finalizing blocks,
+/// the mandatory inherents, which a block fails without, of sudo, of the council, so that it can
+/// vote to exit maintenance mode, and the ones exiting maintenance mode.
+pub struct MaintenanceCalls;
+
+impl Contains<Call> for MaintenanceCalls {
+	fn contains(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::System(_) |
+				Call::Timestamp(_) |
+				Call::TimedTransfer(pallet_timed_transfer::Call::release_due { .. }) |
+				Call::Grandpa(_) |
+				Call::Sudo(_) |
+				Call::Council(_) |
+				Call::MaintenanceMode(_)
+		)
+	}
+}
+
+impl pallet_maintenance_mode::Config for Runtime {
+	type Event = Event;
+	type MaintenanceOrigin = EnsureAdminOrCouncil;
+	type WeightInfo = pallet_maintenance_mode::weights::SubstrateWeight<Runtime>;
+}
+
+/// Every call outside of maintenance mode, but the ones of the paused pallets, by the name of
+/// their pallet, whether or not the pallet guards its calls with the pause registry itself. The
+/// mints of frozen assets are filtered too, as the assets pallet only fails their transfers and
+/// burns.
+pub struct BaseCallFilter;
+
+impl Contains<Call> for BaseCallFilter {
+	fn contains(call: &Call) -> bool {
+		let paused = PauseRegistry::is_paused(call.get_call_metadata().pallet_name.as_bytes());
+		let frozen = check_frozen_assets::mint_of(call).map_or(false, is_frozen_asset);
+
+		!paused && !frozen && MaintenanceFilter::<Runtime, MaintenanceCalls>::contains(call)
+	}
+}
+
+parameter_types! {
+	pub const NftRentalPalletId: PalletId = PalletId(*b"py/rentl");
+}
+
+impl pallet_nft_rental::Config for Runtime {
+	type Assets = Assets;
+	type ClassId = ClassId;
+	type Event = Event;
+	type InstanceId = InstanceId;
+	type Items = Uniques;
+	type PalletId = NftRentalPalletId;
+	type Unlocks = UnlockQueue;
+	type WeightInfo = pallet_nft_rental::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxOrdersPerSide: u32 = 64;
+	pub const OrderBookPalletId: PalletId = PalletId(*b"py/order");
+}
+
+impl pallet_order_book::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type FeeCollector = TreasuryAccount;
+	type MaxOrdersPerSide = MaxOrdersPerSide;
+	type OnFeeCharged = ReferralRewards;
+	type PalletId = OrderBookPalletId;
+	type TradeFee = DexFee;
+	type WeightInfo = pallet_order_book::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const OtcSwapPalletId: PalletId = PalletId(*b"py/otcsw");
+}
+
+impl pallet_otc_swap::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type FeeCollector = TreasuryAccount;
+	type OnFeeCharged = ReferralRewards;
+	type PalletId = OtcSwapPalletId;
+	type TradeFee = DexFee;
+	type Unlocks = UnlockQueue;
+	type WeightInfo = pallet_otc_swap::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxPalletNameLength: u32 = 32;
+}
+
+/// Every pallet with calls, but the ones keeping the chain running and governed, which stay
+/// available in maintenance mode too, and the pause registry itself.
+pub struct PausablePallets;
+
+impl Contains<Vec<u8>> for PausablePallets {
+	fn contains(pallet: &Vec<u8>) -> bool {
+		// The benchmarks pause names of every length.
+		if cfg!(feature = "runtime-benchmarks") {
+			return true
+		}
+		let unpausable = [
+			<System as PalletInfoAccess>::name(),
+			<Timestamp as PalletInfoAccess>::name(),
+			<Grandpa as PalletInfoAccess>::name(),
+			<Sudo as PalletInfoAccess>::name(),
+			<Council as PalletInfoAccess>::name(),
+			<MaintenanceMode as PalletInfoAccess>::name(),
+			<PauseRegistry as PalletInfoAccess>::name(),
+		];
+		let named = |name: &&str| name.as_bytes() == pallet.as_slice();
+		Call::get_module_names().iter().any(named) && !unpausable.iter().any(named)
+	}
+}
+
+impl pallet_pause_registry::Config for Runtime {
+	type Event = Event;
+	type MaxNameLength = MaxPalletNameLength;
+	type PausablePallets = PausablePallets;
+	type PauseOrigin = EnsureRootOrOperator;
+	type WeightInfo = pallet_pause_registry::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxPriceAuthorities: u32 = 8;
+	pub const FetchInterval: BlockNumber = 10;
+	pub const SignedSubmission: bool = false;
+	pub const UnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
+impl pallet_price_fetch::Config for Runtime {
+	type AuthorityId = pallet_price_fetch::crypto::AuthorityId;
+	type AuthorityOrigin = EnsureRootOrOperator;
+	type Call = Call;
+	type Event = Event;
+	type FetchInterval = FetchInterval;
+	type MaxAuthorities = MaxPriceAuthorities;
+	type SignedSubmission = SignedSubmission;
+	type UnsignedPriority = UnsignedPriority;
+	type WeightInfo = pallet_price_fetch::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ClaimDeposit: Balance = 10 * ExistentialDeposit::get();
+}
+
+impl pallet_proof_of_existence::Config for Runtime {
+	type ClaimDeposit = ClaimDeposit;
+	type Currency = Balances;
+	type Event = Event;
+	type Time = Timestamp;
+	type WeightInfo = pallet_proof_of_existence::weights::SubstrateWeight<Runtime>;
+}
+
+/// The kinds of calls a proxy can make on behalf of its delegator.
+#[derive(
+	Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, MaxEncodedLen, RuntimeDebug, TypeInfo,
+)]
+pub enum ProxyType {
+	/// Any call.
+	Any,
+	/// Balance transfers only.
+	Transfers,
+	/// The issuer operations of the assets pallet: minting, burning, freezing and thawing, and
+	/// setting the metadata, for the hot keys acting on behalf of an issuer.
+	AssetManager,
+	/// The issuer operations of the uniques pallet, as for [`ProxyType::AssetManager`].
+	NftManager,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<Call> for ProxyType {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::Transfers => matches!(
+				c,
+				Call::Balances(pallet_balances::Call::transfer { .. }) |
+					Call::Balances(pallet_balances::Call::transfer_keep_alive { .. })
+			),
+			ProxyType::AssetManager => matches!(
+				c,
+				Call::Assets(pallet_assets::Call::mint { .. }) |
+					Call::Assets(pallet_assets::Call::burn { .. }) |
+					Call::Assets(pallet_assets::Call::freeze { .. }) |
+					Call::Assets(pallet_assets::Call::thaw { .. }) |
+					Call::Assets(pallet_assets::Call::freeze_asset { .. }) |
+					Call::Assets(pallet_assets::Call::thaw_asset { .. }) |
+					Call::Assets(pallet_assets::Call::set_metadata { .. }) |
+					Call::Assets(pallet_assets::Call::clear_metadata { .. })
+			),
+			ProxyType::NftManager => matches!(
+				c,
+				Call::Uniques(pallet_uniques::Call::mint { .. }) |
+					Call::Uniques(pallet_uniques::Call::burn { .. }) |
+					Call::Uniques(pallet_uniques::Call::freeze { .. }) |
+					Call::Uniques(pallet_uniques::Call::thaw { .. }) |
+					Call::Uniques(pallet_uniques::Call::freeze_class { .. }) |
+					Call::Uniques(pallet_uniques::Call::thaw_class { .. }) |
+					Call::Uniques(pallet_uniques::Call::set_metadata { .. }) |
+					Call::Uniques(pallet_uniques::Call::clear_metadata { .. }) |
+					Call::Uniques(pallet_uniques::Call::set_class_metadata { .. }) |
+					Call::Uniques(pallet_uniques::Call::clear_class_metadata { .. }) |
+					Call::Uniques(pallet_uniques::Call::set_attribute { .. }) |
+					Call::Uniques(pallet_uniques::Call::clear_attribute { .. })
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		self == &ProxyType::Any || self == o
+	}
+}
+
+parameter_types! {
+	pub const ProxyDeposit: Balance = 10 * ExistentialDeposit::get();
+	pub const ProxyAnnouncementDeposit: Balance = 10 * ExistentialDeposit::get();
+	pub const MaxProxies: u32 = 16;
+	pub const MaxPending: u32 = 16;
+}
+
+impl pallet_proxy_permissions::Config for Runtime {
+	type AnnouncementDeposit = ProxyAnnouncementDeposit;
+	type Call = Call;
+	type Currency = Balances;
+	type Event = Event;
+	type MaxPending = MaxPending;
+	type MaxProxies = MaxProxies;
+	type ProxyDeposit = ProxyDeposit;
+	type ProxyType = ProxyType;
+	type WeightInfo = pallet_proxy_permissions::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const VotingAsset: AssetId = WRAPPED_NATIVE_ASSET_ID;
+	pub const ProposalDeposit: Balance = 100 * ExistentialDeposit::get();
+	pub const VotingPeriod: BlockNumber = DAYS;
+	pub const QuadraticVotingPalletId: PalletId = PalletId(*b"py/qvote");
+}
+
+impl pallet_quadratic_voting::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type PalletId = QuadraticVotingPalletId;
+	type ProposalDeposit = ProposalDeposit;
+	type VotingAsset = VotingAsset;
+	type VotingPeriod = VotingPeriod;
+	type WeightInfo = pallet_quadratic_voting::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const FeelessReferralClaimInterval: BlockNumber = DAYS;
+	pub const ReferralCodeLength: u32 = 16;
+	pub const ReferralRewardsPalletId: PalletId = PalletId(*b"py/refer");
+}
+
+impl pallet_referral_rewards::Config for Runtime {
+	type Assets = Assets;
+	type Currency = Balances;
+	type Event = Event;
+	type FeelessClaimInterval = FeelessReferralClaimInterval;
+	type MaxCodeLength = ReferralCodeLength;
+	type PalletId = ReferralRewardsPalletId;
+	type RateOrigin = EnsureRootOrOperator;
+	type WeightInfo = pallet_referral_rewards::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_reputation::Config for Runtime {
+	type AuthorityOrigin = EnsureRootOrOperator;
+	type Event = Event;
+	type WeightInfo = pallet_reputation::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(20);
+}
+
+impl pallet_royalty_registry::Config for Runtime {
+	type ClassId = ClassId;
+	type Event = Event;
+	type Items = Uniques;
+	type MaxRoyalty = MaxRoyalty;
+	type WeightInfo = pallet_royalty_registry::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxGuardians: u32 = 10;
+	pub const RecoveryDeposit: Balance = 100 * ExistentialDeposit::get();
+	pub const MaxActiveRecoveries: u32 = 10;
+}
+
+impl pallet_social_recovery::Config for Runtime {
+	type Assets = Assets;
+	type ClassId = ClassId;
+	type Currency = Balances;
+	type Event = Event;
+	type InstanceId = InstanceId;
+	type Items = Uniques;
+	type MaxActiveRecoveries = MaxActiveRecoveries;
+	type MaxGuardians = MaxGuardians;
+	type RecoveryDeposit = RecoveryDeposit;
+	type WeightInfo = pallet_social_recovery::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const CollateralAsset: AssetId = WRAPPED_NATIVE_ASSET_ID;
+	pub const StableAsset: AssetId = STABLE_ASSET_ID;
+	pub const MinimumCollateralRatio: u32 = 150;
+	pub const LiquidationRatio: u32 = 120;
+	pub const StablecoinPalletId: PalletId = PalletId(*b"py/stabl");
+	pub StablecoinMigrationWeight: Weight = Perbill::from_percent(10) *
+		BlockWeights::get().max_block;
+}
+
+impl pallet_stablecoin::Config for Runtime {
+	type Assets = Assets;
+	type CollateralAsset = CollateralAsset;
+	type Event = Event;
+	type LiquidationRatio = LiquidationRatio;
+	type MaxMigrationWeight = StablecoinMigrationWeight;
+	type MinimumCollateralRatio = MinimumCollateralRatio;
+	type Oracle = PriceFetch;
+	type PalletId = StablecoinPalletId;
+	type StableAsset = StableAsset;
+	type WeightInfo = pallet_stablecoin::weights::SubstrateWeight<Runtime>;
+}
+
+/// Repairs of the assets and items left in a broken state, by the origin forcing the assets and
+/// uniques pallets.
+impl pallet_storage_repair::Config for Runtime {
+	type Assets = Assets;
+	type ClassId = ClassId;
+	type DestroyWitness = pallet_assets::DestroyWitness;
+	type Event = Event;
+	type ForceOrigin = EnsureAdminOrCouncil;
+	type InstanceId = InstanceId;
+	type Items = Uniques;
+	type WeightInfo = pallet_storage_repair::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxPendingTimedTransfers: u32 = 256;
+	pub const MaxTimedReleasesPerBlock: u32 = 16;
+	pub const TimedTransferPalletId: PalletId = PalletId(*b"py/timtr");
+}
+
+impl pallet_timed_transfer::Config for Runtime {
+	type Assets = Assets;
+	type Event = Event;
+	type MaxPending = MaxPendingTimedTransfers;
+	type MaxReleasesPerBlock = MaxTimedReleasesPerBlock;
+	type PalletId = TimedTransferPalletId;
+	type Time = Timestamp;
+	type WeightInfo = pallet_timed_transfer::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinDelay: BlockNumber = 10 * MINUTES;
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+		BlockWeights::get().max_block;
+	pub const MaxScheduledPerBlock: u32 = 50;
+	pub const MaxScheduledCallSize: u32 = 4 * 1024;
+	pub const ScheduleDeposit: Balance = 10 * ExistentialDeposit::get();
+}
+
+impl pallet_timelock::Config for Runtime {
+	type Call = Call;
+	type Currency = Balances;
+	type Event = Event;
+	type MaxCallSize = MaxScheduledCallSize;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type MinDelay = MinDelay;
+	type ScheduleDeposit = ScheduleDeposit;
+	type WeightInfo = pallet_timelock::weights::SubstrateWeight<Runtime>;
+}
+
+/// Calls dispatched at a later block by root, an operator or the council, with their own origin,
+/// such as a future mint by an operator issuing the asset, or a timed freeze of an asset. Named
+/// schedules are cancelled with `cancel_named` until they run.
+impl pallet_scheduler::Config for Runtime {
+	type Call = Call;
+	type Event = Event;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type Origin = Origin;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type PalletsOrigin = OriginCaller;
+	type ScheduleOrigin = EnsureAdminOrCouncil;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ProposalBond: Balance = 100 * ExistentialDeposit::get();
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+}
+
+impl pallet_treasury::Config for Runtime {
+	type ApproveOrigin = EnsureRootOrOperator;
+	type Assets = Assets;
+	type Currency = Balances;
+	type Event = Event;
+	type PalletId = TreasuryPalletId;
+	type ProposalBond = ProposalBond;
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub MaximumUnlockWeight: Weight = Perbill::from_percent(10) *
+		BlockWeights::get().max_block;
+	pub const MaxUnlocksPerBlock: u32 = 64;
+}
+
+/// The leases ending, and the other unlocks the pallets schedule, run at their block in a shared
+/// queue rather than in a hook of each pallet.
+impl pallet_unlock_queue::Config for Runtime {
+	type Event = Event;
+	type Key = UnlockKey;
+	type MaxUnlocksPerBlock = MaxUnlocksPerBlock;
+	type MaximumWeight = MaximumUnlockWeight;
+	type OnUnlock = RuntimeUnlocks;
+	type WeightInfo = pallet_unlock_queue::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const WrappedAssetId: AssetId = WRAPPED_NATIVE_ASSET_ID;
+	pub const WrappedNativePalletId: PalletId = PalletId(*b"py/wrapn");
+}
+
+impl pallet_wrapped_native::Config for Runtime {
+	type Assets = Assets;
+	type Currency = Balances;
+	type Event = Event;
+	type PalletId = WrappedNativePalletId;
+	type Pauses = PauseRegistry;
+	type WeightInfo = pallet_wrapped_native::weights::SubstrateWeight<Runtime>;
+	type WrappedAssetId = WrappedAssetId;
+}
+
+parameter_types! {
+	pub CurrentSession: u32 = Session::current_index();
+	pub const MaxAssetsPerBlock: u32 = 50;
+	pub const MaxEventsPerBlock: u32 = 1_000;
+}
+
+impl pallet_explorer_counters::Config for Runtime {
+	type AssetId = AssetId;
+	type ClassId = ClassId;
+	type CurrentSession = CurrentSession;
+	type Event = Event;
+	type InstanceId = InstanceId;
+	type MaxAssetsPerBlock = MaxAssetsPerBlock;
+	type MaxEventsPerBlock = MaxEventsPerBlock;
+	type Observer = ExplorerObserver;
+	type Time = Timestamp;
+	type WeightInfo = pallet_explorer_counters::weights::SubstrateWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime where
@@ -284,16 +1240,61 @@ construct_runtime!(
 		NodeBlock = opaque::Block,
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
-		System: frame_system,
-		RandomnessCollectiveFlip: pallet_randomness_collective_flip,
-		Timestamp: pallet_timestamp,
-		Aura: pallet_aura,
-		Grandpa: pallet_grandpa,
-		Balances: pallet_balances,
-		TransactionPayment: pallet_transaction_payment,
-		Sudo: pallet_sudo,
+		System: frame_system = 0,
+		RandomnessCollectiveFlip: pallet_randomness_collective_flip = 1,
+		Timestamp: pallet_timestamp = 2,
+		Aura: pallet_aura = 3,
+		Grandpa: pallet_grandpa = 4,
+		Balances: pallet_balances = 5,
+		TransactionPayment: pallet_transaction_payment = 6,
+		Sudo: pallet_sudo = 7,
 		// Include the custom logic from the pallet-template in the runtime.
-		TemplateModule: pallet_template,
+		TemplateModule: pallet_template = 8,
+		// The pallets added since, each appended with the next index, so that the indices of the
+		// pallets before it are unchanged.
+		Assets: pallet_assets = 9,
+		Uniques: pallet_uniques = 10,
+		// The tutorial pallets.
+		Attestation: pallet_attestation = 11,
+		BatchUtility: pallet_batch_utility = 12,
+		Bounty: pallet_bounty = 13,
+		Club: pallet_club = 14,
+		DonationMatching: pallet_donation_matching = 15,
+		DutchAuction: pallet_dutch_auction = 16,
+		GameInventory: pallet_game_inventory = 17,
+		GiftVoucher: pallet_gift_voucher = 18,
+		Invoice: pallet_invoice = 19,
+		Kitties: pallet_kitties = 20,
+		LiquidityMining: pallet_liquidity_mining = 21,
+		Loyalty: pallet_loyalty = 22,
+		NftRental: pallet_nft_rental = 23,
+		OrderBook: pallet_order_book = 24,
+		OtcSwap: pallet_otc_swap = 25,
+		PriceFetch: pallet_price_fetch = 26,
+		ProofOfExistence: pallet_proof_of_existence = 27,
+		ProxyPermissions: pallet_proxy_permissions = 28,
+		QuadraticVoting: pallet_quadratic_voting = 29,
+		ReferralRewards: pallet_referral_rewards = 30,
+		Reputation: pallet_reputation = 31,
+		RoyaltyRegistry: pallet_royalty_registry = 32,
+		SocialRecovery: pallet_social_recovery = 33,
+		Stablecoin: pallet_stablecoin = 34,
+		Timelock: pallet_timelock = 35,
+		Treasury: pallet_treasury = 36,
+		WrappedNative: pallet_wrapped_native = 37,
+		MaintenanceMode: pallet_maintenance_mode = 38,
+		PauseRegistry: pallet_pause_registry = 39,
+		TimedTransfer: pallet_timed_transfer = 40,
+		Contracts: pallet_contracts = 41,
+		Session: pallet_session = 42,
+		// After all the pallets whose events it counts.
+		ExplorerCounters: pallet_explorer_counters = 43,
+		Council: pallet_collective::<Instance1> = 44,
+		Scheduler: pallet_scheduler = 45,
+		Utility: pallet_utility = 46,
+		Multisig: pallet_multisig = 47,
+		UnlockQueue: pallet_unlock_queue = 48,
+		StorageRepair: pallet_storage_repair = 49,
 	}
 );
 
@@ -312,9 +1313,17 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	CheckFrozenAssets,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
+/// The payload being signed in transactions.
+pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
+/// The migrations run on runtime upgrades, before the ones of the pallets.
+#[cfg(not(feature = "upgrade-test"))]
+pub type Migrations = ();
+#[cfg(feature = "upgrade-test")]
+pub type Migrations = upgrade_test::MarkUpgrade;
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -322,6 +1331,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
+	Migrations,
 >;
 
 impl_runtime_apis! {
@@ -455,6 +1465,210 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl rusty_node_runtime_api::AssetsApi<Block, AssetId, AccountId, Balance> for Runtime {
+		fn balance(asset_id: AssetId, who: AccountId) -> Balance {
+			<Assets as fungibles::Inspect<AccountId>>::balance(asset_id, &who)
+		}
+
+		fn total_supply(asset_id: AssetId) -> Balance {
+			<Assets as fungibles::Inspect<AccountId>>::total_issuance(asset_id)
+		}
+
+		fn minimum_balance(asset_id: AssetId) -> Balance {
+			<Assets as fungibles::Inspect<AccountId>>::minimum_balance(asset_id)
+		}
+
+		fn holders(
+			asset_id: AssetId,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<(AccountId, Balance)> {
+			let pallet = <Assets as PalletInfoAccess>::name();
+			let mut prefix = storage_prefix(pallet.as_bytes(), b"Account").to_vec();
+			prefix.extend(Blake2_128Concat::hash(&asset_id.encode()));
+			let page = iter_page(&prefix, start_key, limit.min(MAX_PAGE_SIZE), |key, _| {
+				decode_key::<Blake2_128Concat, AccountId>(&mut &key[..])
+			});
+			Page {
+				items: page
+					.items
+					.into_iter()
+					.map(|who| {
+						let balance =
+							<Assets as fungibles::Inspect<AccountId>>::balance(asset_id, &who);
+						(who, balance)
+					})
+					.collect(),
+				next_key: page.next_key,
+			}
+		}
+
+		fn list_assets(
+			start: Option<AssetId>,
+			limit: u32,
+		) -> Vec<AssetEntry<AssetId, AccountId, Balance>> {
+			listings::assets(start, limit)
+		}
+	}
+
+	impl rusty_node_runtime_api::NftsApi<Block, ClassId, InstanceId, AccountId, Balance>
+		for Runtime
+	{
+		fn owner(class: ClassId, instance: InstanceId) -> Option<AccountId> {
+			<Uniques as nonfungibles::Inspect<AccountId>>::owner(&class, &instance)
+		}
+
+		fn owned(
+			who: AccountId,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<(ClassId, InstanceId)> {
+			let pallet = <Uniques as PalletInfoAccess>::name();
+			let mut prefix = storage_prefix(pallet.as_bytes(), b"Account").to_vec();
+			prefix.extend(Blake2_128Concat::hash(&who.encode()));
+			let page = iter_page(&prefix, start_key, limit.min(MAX_PAGE_SIZE), |key, _| {
+				let mut key = key;
+				let class = decode_key::<Blake2_128Concat, ClassId>(&mut key)?;
+				let instance = decode_key::<Blake2_128Concat, InstanceId>(&mut key)?;
+				Some((class, instance))
+			});
+			Page { items: page.items, next_key: page.next_key }
+		}
+
+		fn kitties_owned(who: AccountId) -> Vec<KittyId> {
+			Kitties::kitties_owned(&who).into_inner()
+		}
+
+		fn list_classes(
+			start: Option<ClassId>,
+			limit: u32,
+		) -> Vec<ClassEntry<ClassId, AccountId, Balance>> {
+			listings::classes(start, limit)
+		}
+	}
+
+	impl rusty_node_runtime_api::MarketplaceApi<Block, AccountId, AssetId, Balance, ClassId, InstanceId>
+		for Runtime
+	{
+		fn kitties_for_sale(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<KittyListing<AccountId, Balance>> {
+			let pallet = <Kitties as PalletInfoAccess>::name();
+			let prefix = storage_prefix(pallet.as_bytes(), b"Kitties");
+			let page = iter_page(&prefix, start_key, limit.min(MAX_PAGE_SIZE), |key, value| {
+				let kitty_id = decode_key::<Twox64Concat, KittyId>(&mut &key[..])?;
+				let kitty = pallet_kitties::KittyOf::<Runtime>::decode(&mut &value[..]).ok()?;
+				kitty.price.map(|price| KittyListing { kitty_id, owner: kitty.owner, price })
+			});
+			Page { items: page.items, next_key: page.next_key }
+		}
+
+		fn auctions(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<AuctionListing<AccountId, AssetId, Balance, ClassId, InstanceId>> {
+			let pallet = <DutchAuction as PalletInfoAccess>::name();
+			let prefix = storage_prefix(pallet.as_bytes(), b"Auctions");
+			let page = iter_page(&prefix, start_key, limit.min(MAX_PAGE_SIZE), |key, value| {
+				let mut key = key;
+				let class = decode_key::<Blake2_128Concat, ClassId>(&mut key)?;
+				let instance = decode_key::<Blake2_128Concat, InstanceId>(&mut key)?;
+				let auction =
+					pallet_dutch_auction::AuctionOf::<Runtime>::decode(&mut &value[..]).ok()?;
+				Some(AuctionListing {
+					class,
+					instance,
+					price: DutchAuction::current_price(&auction),
+					asset_id: match auction.funds {
+						pallet_dutch_auction::Funds::Native => None,
+						pallet_dutch_auction::Funds::Asset(asset_id) => Some(asset_id),
+					},
+					seller: auction.seller,
+				})
+			});
+			Page { items: page.items, next_key: page.next_key }
+		}
+
+		fn otc_offers(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> Page<OfferListing<AccountId, AssetId, Balance>> {
+			let now = System::block_number();
+			let pallet = <OtcSwap as PalletInfoAccess>::name();
+			let prefix = storage_prefix(pallet.as_bytes(), b"Offers");
+			let page = iter_page(&prefix, start_key, limit.min(MAX_PAGE_SIZE), |key, value| {
+				let offer_id = decode_key::<Twox64Concat, OfferId>(&mut &key[..])?;
+				let offer = pallet_otc_swap::OfferOf::<Runtime>::decode(&mut &value[..]).ok()?;
+				(now < offer.expiry).then(|| OfferListing {
+					offer_id,
+					maker: offer.maker,
+					give_asset: offer.give_asset,
+					give_amount: offer.give_amount,
+					want_asset: offer.want_asset,
+					want_amount: offer.want_amount,
+					counterparty: offer.counterparty,
+				})
+			});
+			Page { items: page.items, next_key: page.next_key }
+		}
+	}
+
+	impl rusty_node_runtime_api::DryRunApi<Block, AccountId, Call, Event> for Runtime {
+		fn simulate_call(origin: AccountId, call: Call) -> CallSimulation<Event> {
+			dry_run::simulate_call(origin, call)
+		}
+	}
+
+	impl rusty_node_runtime_api::ErrorCodesApi<Block> for Runtime {
+		fn error_code(error: DispatchError) -> Option<ErrorCode> {
+			RuntimeErrorCodes::error_code(&error)
+				.map(|(code, name)| ErrorCode { code, name: name.as_bytes().to_vec() })
+		}
+
+		fn error_codes() -> Vec<ErrorCode> {
+			RuntimeErrorCodes::error_codes()
+				.into_iter()
+				.map(|(code, name)| ErrorCode { code, name: name.as_bytes().to_vec() })
+				.collect()
+		}
+	}
+
+	impl rusty_node_runtime_api::ExplorerApi<Block, AssetId, ClassId, InstanceId, Moment>
+		for Runtime
+	{
+		fn counters() -> ChainCounters {
+			let counters = ExplorerCounters::counters();
+			ChainCounters {
+				assets_created: counters.assets_created,
+				nfts: counters.items,
+				holders: counters.holders,
+				session: counters.session,
+				session_transfers: counters.session_transfers,
+			}
+		}
+
+		fn asset_created_at(asset_id: AssetId) -> Option<Moment> {
+			ExplorerCounters::asset_created_at(asset_id)
+		}
+
+		fn item_created_at(class: ClassId, instance: InstanceId) -> Option<Moment> {
+			ExplorerCounters::item_created_at(class, instance)
+		}
+	}
+
+	impl rusty_node_runtime_api::ProofsApi<Block, AssetId, ClassId, InstanceId, AccountId>
+		for Runtime
+	{
+		fn balance_key(asset_id: AssetId, who: AccountId) -> Vec<u8> {
+			roles::storage_key::<Assets>(b"Account", &[asset_id.encode(), who.encode()])
+		}
+
+		fn item_key(class: ClassId, instance: InstanceId) -> Vec<u8> {
+			roles::storage_key::<Uniques>(b"Asset", &[class.encode(), instance.encode()])
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -472,7 +1686,46 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, frame_system, SystemBench::<Runtime>);
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
+			list_benchmark!(list, extra, pallet_scheduler, Scheduler);
+			list_benchmark!(list, extra, pallet_utility, Utility);
+			list_benchmark!(list, extra, pallet_multisig, Multisig);
 			list_benchmark!(list, extra, pallet_template, TemplateModule);
+			list_benchmark!(list, extra, pallet_assets, Assets);
+			list_benchmark!(list, extra, pallet_uniques, Uniques);
+			list_benchmark!(list, extra, pallet_contracts, Contracts);
+			list_benchmark!(list, extra, pallet_attestation, Attestation);
+			list_benchmark!(list, extra, pallet_batch_utility, BatchUtility);
+			list_benchmark!(list, extra, pallet_bounty, Bounty);
+			list_benchmark!(list, extra, pallet_club, Club);
+			list_benchmark!(list, extra, pallet_donation_matching, DonationMatching);
+			list_benchmark!(list, extra, pallet_dutch_auction, DutchAuction);
+			list_benchmark!(list, extra, pallet_explorer_counters, ExplorerCounters);
+			list_benchmark!(list, extra, pallet_game_inventory, GameInventory);
+			list_benchmark!(list, extra, pallet_gift_voucher, GiftVoucher);
+			list_benchmark!(list, extra, pallet_invoice, Invoice);
+			list_benchmark!(list, extra, pallet_kitties, Kitties);
+			list_benchmark!(list, extra, pallet_liquidity_mining, LiquidityMining);
+			list_benchmark!(list, extra, pallet_loyalty, Loyalty);
+			list_benchmark!(list, extra, pallet_maintenance_mode, MaintenanceMode);
+			list_benchmark!(list, extra, pallet_nft_rental, NftRental);
+			list_benchmark!(list, extra, pallet_order_book, OrderBook);
+			list_benchmark!(list, extra, pallet_otc_swap, OtcSwap);
+			list_benchmark!(list, extra, pallet_pause_registry, PauseRegistry);
+			list_benchmark!(list, extra, pallet_price_fetch, PriceFetch);
+			list_benchmark!(list, extra, pallet_proof_of_existence, ProofOfExistence);
+			list_benchmark!(list, extra, pallet_proxy_permissions, ProxyPermissions);
+			list_benchmark!(list, extra, pallet_quadratic_voting, QuadraticVoting);
+			list_benchmark!(list, extra, pallet_referral_rewards, ReferralRewards);
+			list_benchmark!(list, extra, pallet_reputation, Reputation);
+			list_benchmark!(list, extra, pallet_royalty_registry, RoyaltyRegistry);
+			list_benchmark!(list, extra, pallet_social_recovery, SocialRecovery);
+			list_benchmark!(list, extra, pallet_stablecoin, Stablecoin);
+			list_benchmark!(list, extra, pallet_storage_repair, StorageRepair);
+			list_benchmark!(list, extra, pallet_timed_transfer, TimedTransfer);
+			list_benchmark!(list, extra, pallet_timelock, Timelock);
+			list_benchmark!(list, extra, pallet_treasury, Treasury);
+			list_benchmark!(list, extra, pallet_unlock_queue, UnlockQueue);
+			list_benchmark!(list, extra, pallet_wrapped_native, WrappedNative);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -510,9 +1763,62 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, pallet_scheduler, Scheduler);
+			add_benchmark!(params, batches, pallet_utility, Utility);
+			add_benchmark!(params, batches, pallet_multisig, Multisig);
 			add_benchmark!(params, batches, pallet_template, TemplateModule);
+			add_benchmark!(params, batches, pallet_assets, Assets);
+			add_benchmark!(params, batches, pallet_uniques, Uniques);
+			add_benchmark!(params, batches, pallet_contracts, Contracts);
+			add_benchmark!(params, batches, pallet_attestation, Attestation);
+			add_benchmark!(params, batches, pallet_batch_utility, BatchUtility);
+			add_benchmark!(params, batches, pallet_bounty, Bounty);
+			add_benchmark!(params, batches, pallet_club, Club);
+			add_benchmark!(params, batches, pallet_donation_matching, DonationMatching);
+			add_benchmark!(params, batches, pallet_dutch_auction, DutchAuction);
+			add_benchmark!(params, batches, pallet_explorer_counters, ExplorerCounters);
+			add_benchmark!(params, batches, pallet_game_inventory, GameInventory);
+			add_benchmark!(params, batches, pallet_gift_voucher, GiftVoucher);
+			add_benchmark!(params, batches, pallet_invoice, Invoice);
+			add_benchmark!(params, batches, pallet_kitties, Kitties);
+			add_benchmark!(params, batches, pallet_liquidity_mining, LiquidityMining);
+			add_benchmark!(params, batches, pallet_loyalty, Loyalty);
+			add_benchmark!(params, batches, pallet_maintenance_mode, MaintenanceMode);
+			add_benchmark!(params, batches, pallet_nft_rental, NftRental);
+			add_benchmark!(params, batches, pallet_order_book, OrderBook);
+			add_benchmark!(params, batches, pallet_otc_swap, OtcSwap);
+			add_benchmark!(params, batches, pallet_pause_registry, PauseRegistry);
+			add_benchmark!(params, batches, pallet_price_fetch, PriceFetch);
+			add_benchmark!(params, batches, pallet_proof_of_existence, ProofOfExistence);
+			add_benchmark!(params, batches, pallet_proxy_permissions, ProxyPermissions);
+			add_benchmark!(params, batches, pallet_quadratic_voting, QuadraticVoting);
+			add_benchmark!(params, batches, pallet_referral_rewards, ReferralRewards);
+			add_benchmark!(params, batches, pallet_reputation, Reputation);
+			add_benchmark!(params, batches, pallet_royalty_registry, RoyaltyRegistry);
+			add_benchmark!(params, batches, pallet_social_recovery, SocialRecovery);
+			add_benchmark!(params, batches, pallet_stablecoin, Stablecoin);
+			add_benchmark!(params, batches, pallet_storage_repair, StorageRepair);
+			add_benchmark!(params, batches, pallet_timed_transfer, TimedTransfer);
+			add_benchmark!(params, batches, pallet_timelock, Timelock);
+			add_benchmark!(params, batches, pallet_treasury, Treasury);
+			add_benchmark!(params, batches, pallet_unlock_queue, UnlockQueue);
+			add_benchmark!(params, batches, pallet_wrapped_native, WrappedNative);
 
 			Ok(batches)
 		}
 	}
+
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade() -> (Weight, Weight) {
+			// Runs the `post_upgrade` hooks, checking the invariants of every pallet. Panics on
+			// failure, so the whole upgrade is reported.
+			let weight = Executive::try_runtime_upgrade().unwrap();
+			(weight, BlockWeights::get().max_block)
+		}
+
+		fn execute_block_no_check(block: Block) -> Weight {
+			Executive::execute_block_no_check(block)
+		}
+	}
 }
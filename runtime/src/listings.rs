@@ -0,0 +1,32 @@
+//! The registries of assets and classes, a page at a time, for block explorers.
+//!
+//! The details and metadata of the assets and classes are private to the assets and uniques
+//! pallets, so they are read from their storage into the types of the primitives crate, which
+//! mirror their layout.
+
+use crate::{roles::details, AccountId, AssetId, Assets, Balance, ClassId, Uniques, MAX_PAGE_SIZE};
+use codec::Encode;
+use frame_support::{storage::storage_prefix, traits::PalletInfoAccess, Blake2_128Concat};
+use rusty_node_runtime_api::{AssetEntry, ClassEntry};
+use rusty_node_support::pagination::map_page;
+use sp_std::prelude::*;
+
+/// Up to `limit`, capped at `MAX_PAGE_SIZE`, of the assets, with their details and metadata,
+/// after `start`.
+pub fn assets(start: Option<AssetId>, limit: u32) -> Vec<AssetEntry<AssetId, AccountId, Balance>> {
+	let prefix = storage_prefix(<Assets as PalletInfoAccess>::name().as_bytes(), b"Asset");
+	map_page::<Blake2_128Concat, _, _>(&prefix, start, limit.min(MAX_PAGE_SIZE))
+		.into_iter()
+		.map(|(id, asset)| (id, asset, details::<Assets, _>(b"Metadata", &[id.encode()])))
+		.collect()
+}
+
+/// Up to `limit`, capped at `MAX_PAGE_SIZE`, of the classes, with their details and metadata,
+/// after `start`.
+pub fn classes(start: Option<ClassId>, limit: u32) -> Vec<ClassEntry<ClassId, AccountId, Balance>> {
+	let prefix = storage_prefix(<Uniques as PalletInfoAccess>::name().as_bytes(), b"Class");
+	map_page::<Blake2_128Concat, _, _>(&prefix, start, limit.min(MAX_PAGE_SIZE))
+		.into_iter()
+		.map(|(id, class)| (id, class, details::<Uniques, _>(b"ClassMetadataOf", &[id.encode()])))
+		.collect()
+}
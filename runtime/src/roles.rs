@@ -0,0 +1,97 @@
+//! The roles of accounts over the assets and the nonfungible items of the runtime.
+//!
+//! The assets and uniques pallets keep the details of their assets, classes and items private, so
+//! the accounts holding a role are read from their storage. Both pallets start the details of an
+//! asset, or a class, with its owner, issuer, admin and freezer, and the details of an item with
+//! its owner and the account approved to transfer it.
+
+use crate::{AccountId, AssetId, Assets, ClassId, InstanceId, Uniques};
+use codec::{Decode, Encode};
+use frame_support::{
+	storage::{storage_prefix, unhashed},
+	traits::PalletInfoAccess,
+	Blake2_128Concat, StorageHasher,
+};
+use rusty_node_support::{Permissions, Roles};
+use sp_std::prelude::*;
+
+/// The owner, issuer, admin and freezer of an asset, or a class.
+type RoleAccounts = (AccountId, AccountId, AccountId, AccountId);
+
+/// The storage key of the `storage` map of `Pallet` at the encoded keys `keys`, hashed with
+/// `Blake2_128Concat`.
+pub(crate) fn storage_key<Pallet: PalletInfoAccess>(storage: &[u8], keys: &[Vec<u8>]) -> Vec<u8> {
+	let mut key = storage_prefix(Pallet::name().as_bytes(), storage).to_vec();
+	for k in keys {
+		key.extend(Blake2_128Concat::hash(k));
+	}
+	key
+}
+
+/// Decode the start of the value of the `storage` map of `Pallet` at the encoded keys `keys`,
+/// hashed with `Blake2_128Concat`.
+pub(crate) fn details<Pallet: PalletInfoAccess, D: Decode>(
+	storage: &[u8],
+	keys: &[Vec<u8>],
+) -> Option<D> {
+	unhashed::get_raw(&storage_key::<Pallet>(storage, keys))
+		.and_then(|details| D::decode(&mut &details[..]).ok())
+}
+
+/// The owner, issuer, admin and freezer of the asset `id`, if it exists.
+pub fn asset_roles(id: AssetId) -> Option<RoleAccounts> {
+	details::<Assets, _>(b"Asset", &[id.encode()])
+}
+
+fn permissions_of(roles: RoleAccounts, who: &AccountId) -> Permissions {
+	let (owner, issuer, admin, freezer) = roles;
+	[
+		(owner, Permissions::OWNER),
+		(issuer, Permissions::ISSUER),
+		(admin, Permissions::ADMIN),
+		(freezer, Permissions::FREEZER),
+	]
+	.into_iter()
+	.filter(|(account, _)| account == who)
+	.fold(Permissions::empty(), |granted, (_, role)| granted | role)
+}
+
+/// The roles over the assets of the assets pallet.
+pub struct AssetRoles;
+
+impl Roles<AccountId> for AssetRoles {
+	type Target = AssetId;
+
+	fn permissions(id: &AssetId, who: &AccountId) -> Permissions {
+		asset_roles(*id).map_or(Permissions::empty(), |roles| permissions_of(roles, who))
+	}
+}
+
+/// The roles over the items of the uniques pallet: the issuer, admin and freezer of their class,
+/// their owner, and the account approved to transfer them.
+pub struct ItemRoles;
+
+impl Roles<AccountId> for ItemRoles {
+	type Target = (ClassId, InstanceId);
+
+	fn permissions((class, instance): &(ClassId, InstanceId), who: &AccountId) -> Permissions {
+		let item: Option<(AccountId, Option<AccountId>)> =
+			details::<Uniques, _>(b"Asset", &[class.encode(), instance.encode()]);
+		let (owner, approved) = match item {
+			Some(item) => item,
+			None => return Permissions::empty(),
+		};
+
+		let class_roles: Option<RoleAccounts> = details::<Uniques, _>(b"Class", &[class.encode()]);
+		// The owner of the class does not own its items.
+		let mut granted = class_roles
+			.map_or(Permissions::empty(), |roles| permissions_of(roles, who) - Permissions::OWNER);
+		if owner == *who {
+			granted |= Permissions::OWNER;
+		}
+		if approved.as_ref() == Some(who) {
+			granted |= Permissions::DELEGATE;
+		}
+		granted
+	}
+}
@@ -0,0 +1,64 @@
+//! The unlocks the pallets schedule in the unlock-queue pallet, and the pallet each goes back to
+//! once it is due.
+//!
+//! The ends of the leases of the nft-rental pallet, the expiries of the vouchers and commitments
+//! of the gift-voucher pallet, and the expiries of the offers of the otc-swap pallet are scheduled
+//! so far.
+
+use crate::{ClassId, GiftVoucher, Hash, InstanceId, NftRental, OtcSwap};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{weights::Weight, RuntimeDebug};
+use pallet_gift_voucher::Expiry;
+use pallet_otc_swap::OfferId;
+use rusty_node_support::OnUnlock;
+use scale_info::TypeInfo;
+
+/// The key of an unlock: the pallet that scheduled it, and what it unlocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum UnlockKey {
+	/// The end of the lease of an item.
+	Lease(ClassId, InstanceId),
+	/// The expiry of a voucher, or of a commitment to redeem one.
+	Voucher(Expiry<Hash>),
+	/// The expiry of an offer.
+	Offer(OfferId),
+}
+
+impl From<(ClassId, InstanceId)> for UnlockKey {
+	fn from((class, instance): (ClassId, InstanceId)) -> Self {
+		Self::Lease(class, instance)
+	}
+}
+
+impl From<Expiry<Hash>> for UnlockKey {
+	fn from(expiry: Expiry<Hash>) -> Self {
+		Self::Voucher(expiry)
+	}
+}
+
+impl From<OfferId> for UnlockKey {
+	fn from(offer_id: OfferId) -> Self {
+		Self::Offer(offer_id)
+	}
+}
+
+/// Hand the unlocks back to the pallets that scheduled them.
+pub struct RuntimeUnlocks;
+
+impl OnUnlock<UnlockKey> for RuntimeUnlocks {
+	fn unlock_weight(key: &UnlockKey) -> Weight {
+		match key {
+			UnlockKey::Lease(class, instance) => NftRental::unlock_weight(&(*class, *instance)),
+			UnlockKey::Voucher(expiry) => GiftVoucher::unlock_weight(expiry),
+			UnlockKey::Offer(offer_id) => OtcSwap::unlock_weight(offer_id),
+		}
+	}
+
+	fn on_unlock(key: UnlockKey) -> Weight {
+		match key {
+			UnlockKey::Lease(class, instance) => NftRental::on_unlock((class, instance)),
+			UnlockKey::Voucher(expiry) => GiftVoucher::on_unlock(expiry),
+			UnlockKey::Offer(offer_id) => OtcSwap::on_unlock(offer_id),
+		}
+	}
+}
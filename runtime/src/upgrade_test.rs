@@ -0,0 +1,41 @@
+//! The runtime the end-to-end tests upgrade a running chain to, built with the `upgrade-test`
+//! feature.
+//!
+//! It bumps the `spec_version`, as `set_code` requires, and migrates the storage with
+//! [`MarkUpgrade`], which records the version the chain was upgraded to under [`UPGRADED_KEY`].
+//! The tests check the mark to tell the migration ran.
+
+use crate::{Runtime, RUNTIME_API_VERSIONS};
+use frame_support::{
+	storage::unhashed,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::create_runtime_str;
+use sp_version::RuntimeVersion;
+
+/// The version of the upgraded runtime: the one of the runtime built without the feature, with
+/// the next `spec_version`.
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+	spec_name: create_runtime_str!("rusty-node"),
+	impl_name: create_runtime_str!("rusty-node"),
+	authoring_version: 1,
+	spec_version: 101,
+	impl_version: 1,
+	apis: RUNTIME_API_VERSIONS,
+	transaction_version: 1,
+};
+
+/// The unhashed storage key of the `spec_version` the chain was upgraded to.
+pub const UPGRADED_KEY: &[u8] = b":rusty-node:upgraded";
+
+/// Record the `spec_version` the chain was upgraded to.
+pub struct MarkUpgrade;
+
+impl OnRuntimeUpgrade for MarkUpgrade {
+	fn on_runtime_upgrade() -> Weight {
+		unhashed::put(UPGRADED_KEY, &VERSION.spec_version);
+		<Runtime as frame_system::Config>::DbWeight::get().writes(1)
+	}
+}
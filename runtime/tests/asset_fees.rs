@@ -0,0 +1,135 @@
+//! The fees of the accounts lacking the native currency are burned in the first whitelisted asset
+//! they hold enough of, converted at its rate and rounded up, and the unused part is minted back.
+
+use frame_support::{
+	assert_ok, parameter_types,
+	traits::Get,
+	weights::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
+};
+use pallet_transaction_payment::OnChargeTransaction;
+use rusty_node_runtime::{
+	AccountId, AssetFeeAdapter, AssetId, Assets, Call, FeeAssetRates, FeePayment, Runtime,
+	Treasury, DEMO_ASSET_ID,
+};
+use sp_runtime::{
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	FixedPointNumber, FixedU128,
+};
+use test_utils::ExtBuilder;
+
+const UNSUPPORTED_ASSET: AssetId = AssetId(100);
+
+parameter_types! {
+	pub HalfRate: Vec<(AssetId, FixedU128)> =
+		vec![(DEMO_ASSET_ID, FixedU128::saturating_from_rational(1, 2))];
+}
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Bob holds no native currency, but 1_000 of the demo asset and of an unsupported asset.
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.asset(DEMO_ASSET_ID, account(1), vec![(account(2), 1_000)])
+		.asset(UNSUPPORTED_ASSET, account(1), vec![(account(2), 1_000), (account(3), 1_000)])
+		.build()
+}
+
+fn call() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn info() -> DispatchInfo {
+	call().get_dispatch_info()
+}
+
+fn post_info() -> PostDispatchInfo {
+	PostDispatchInfo { actual_weight: None, pays_fee: Default::default() }
+}
+
+/// Charge `who` a fee of `fee`, with the assets and rates of `Rates`.
+fn withdraw<Rates: Get<Vec<(AssetId, FixedU128)>>>(
+	who: &AccountId,
+	fee: u128,
+) -> Result<FeePayment, TransactionValidityError> {
+	AssetFeeAdapter::<Rates>::withdraw_fee(who, &call(), &info(), fee, 0)
+}
+
+fn paid(payment: &FeePayment) -> u128 {
+	match payment {
+		FeePayment::Asset { asset_id, paid, .. } if *asset_id == DEMO_ASSET_ID => *paid,
+		_ => panic!("the fee was not paid with the demo asset"),
+	}
+}
+
+#[test]
+fn fees_are_converted_at_the_rate_of_the_asset() {
+	new_test_ext().execute_with(|| {
+		let bob = account(2);
+
+		// Two units of the demo asset pay for a unit of the native currency.
+		let payment = withdraw::<FeeAssetRates>(&bob, 100).unwrap();
+
+		assert_eq!(paid(&payment), 200);
+		assert_eq!(Assets::balance(DEMO_ASSET_ID, &bob), 800);
+	});
+}
+
+#[test]
+fn unsupported_assets_cannot_pay_fees() {
+	new_test_ext().execute_with(|| {
+		let charlie = account(3);
+
+		assert_eq!(
+			withdraw::<FeeAssetRates>(&charlie, 100).map(|_| ()),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_eq!(Assets::balance(UNSUPPORTED_ASSET, &charlie), 1_000);
+	});
+}
+
+#[test]
+fn unused_fees_are_refunded_in_the_asset() {
+	new_test_ext().execute_with(|| {
+		let bob = account(2);
+		let payment = withdraw::<FeeAssetRates>(&bob, 100).unwrap();
+
+		assert_ok!(AssetFeeAdapter::<FeeAssetRates>::correct_and_deposit_fee(
+			&bob,
+			&info(),
+			&post_info(),
+			40,
+			0,
+			payment
+		));
+
+		// 80 of the 200 paid are due, 64 of which go to the treasury.
+		assert_eq!(Assets::balance(DEMO_ASSET_ID, &bob), 920);
+		assert_eq!(Assets::balance(DEMO_ASSET_ID, &Treasury::account_id()), 64);
+	});
+}
+
+#[test]
+fn fractional_fees_are_rounded_up() {
+	new_test_ext().execute_with(|| {
+		let bob = account(2);
+
+		// Half a unit of the asset is owed for a unit of the native currency.
+		assert_eq!(paid(&withdraw::<HalfRate>(&bob, 1).unwrap()), 1);
+		assert_eq!(paid(&withdraw::<HalfRate>(&bob, 3).unwrap()), 2);
+
+		let payment = withdraw::<HalfRate>(&bob, 5).unwrap();
+		assert_eq!(paid(&payment), 3);
+		assert_ok!(AssetFeeAdapter::<HalfRate>::correct_and_deposit_fee(
+			&bob,
+			&info(),
+			&post_info(),
+			1,
+			0,
+			payment
+		));
+		// The corrected fee rounds up too: 1 of the 3 paid is kept.
+		assert_eq!(Assets::balance(DEMO_ASSET_ID, &bob), 1_000 - 1 - 2 - 1);
+	});
+}
@@ -0,0 +1,66 @@
+//! Transferring the ownership of an asset hands its owner-gated calls, like the recipes of the
+//! game inventory, to the new owner.
+
+use frame_support::{assert_noop, assert_ok};
+use rusty_node_runtime::{
+	asset_owner, AccountId, Assets, GameInventory, Origin, Runtime, System, DEMO_ASSET_ID,
+};
+use sp_runtime::DispatchError;
+use test_utils::ExtBuilder;
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice owns the game asset.
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::<Runtime>::default()
+		.balances(vec![(account(1), 1_000_000), (account(2), 1_000_000)])
+		.asset(DEMO_ASSET_ID, account(1), vec![])
+		.build();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+#[test]
+fn new_owner_manages_the_recipes() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (account(1), account(2));
+		assert_noop!(
+			GameInventory::remove_recipe(Origin::signed(alice.clone()), 0),
+			pallet_game_inventory::Error::<Runtime>::UnknownRecipe
+		);
+		assert_noop!(
+			GameInventory::remove_recipe(Origin::signed(bob.clone()), 0),
+			DispatchError::BadOrigin
+		);
+
+		assert_noop!(
+			Assets::transfer_ownership(
+				Origin::signed(bob.clone()),
+				DEMO_ASSET_ID,
+				bob.clone().into()
+			),
+			pallet_assets::Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Assets::transfer_ownership(
+			Origin::signed(alice.clone()),
+			DEMO_ASSET_ID,
+			bob.clone().into()
+		));
+		System::assert_last_event(
+			pallet_assets::Event::OwnerChanged { asset_id: DEMO_ASSET_ID, owner: bob.clone() }
+				.into(),
+		);
+		assert_eq!(asset_owner(DEMO_ASSET_ID), Some(bob.clone()));
+
+		assert_noop!(
+			GameInventory::remove_recipe(Origin::signed(alice), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			GameInventory::remove_recipe(Origin::signed(bob), 0),
+			pallet_game_inventory::Error::<Runtime>::UnknownRecipe
+		);
+	});
+}
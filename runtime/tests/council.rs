@@ -0,0 +1,123 @@
+//! The admin operations the council passes through motions: two thirds of its members can freeze
+//! assets, destroy classes of items and toggle maintenance mode, a single one cannot.
+
+use codec::Encode;
+use frame_support::{assert_err_ignore_postinfo, assert_ok};
+use pallet_uniques::DestroyWitness;
+use rusty_node_runtime::{
+	AccountId, AssetId, Assets, Call, ClassId, Council, CouncilCollective, InstanceId,
+	MaintenanceMode, Origin, Runtime, Uniques,
+};
+use sp_runtime::traits::{BlakeTwo256, Dispatchable, Hash};
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+const CLASS: ClassId = ClassId(100);
+const ITEM: InstanceId = InstanceId(0);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let (alice, bob, charlie, dave) = (account(1), account(2), account(3), account(4));
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(alice.clone(), 1_000_000), (dave.clone(), 1_000_000)])
+		.genesis(pallet_collective::GenesisConfig::<Runtime, CouncilCollective> {
+			phantom: Default::default(),
+			members: vec![alice, bob, charlie],
+		})
+		.asset(ASSET, dave.clone(), vec![(dave.clone(), 100)])
+		.class(CLASS, dave.clone())
+		.item(CLASS, ITEM, dave)
+		.build()
+}
+
+/// Propose `call` from the first member with the given threshold, have the other members of
+/// `ayes` vote for it, and close the motion.
+fn motion(call: Call, threshold: u32, ayes: &[u8]) {
+	let hash = BlakeTwo256::hash_of(&call);
+	let length = call.encode().len() as u32;
+	let index = Council::proposal_count();
+
+	let proposer = Origin::signed(account(ayes[0]));
+	assert_ok!(Council::propose(proposer, threshold, Box::new(call), length));
+	if threshold < 2 {
+		// The motion is executed as soon as it is proposed.
+		return
+	}
+	for &member in &ayes[1..] {
+		assert_ok!(Council::vote(Origin::signed(account(member)), hash, index, true));
+	}
+	assert_ok!(Council::close(Origin::signed(account(ayes[0])), hash, index, u64::MAX, length));
+}
+
+#[test]
+fn council_freezes_an_asset() {
+	new_test_ext().execute_with(|| {
+		let dave = account(4);
+		let freeze = Call::Assets(pallet_assets::Call::force_asset_status {
+			id: ASSET,
+			owner: dave.clone().into(),
+			issuer: dave.clone().into(),
+			admin: dave.clone().into(),
+			freezer: dave.clone().into(),
+			min_balance: 1,
+			is_sufficient: true,
+			is_frozen: true,
+		});
+		motion(freeze, 2, &[1, 2]);
+
+		assert_err_ignore_postinfo!(
+			Assets::transfer(Origin::signed(dave), ASSET, account(1).into(), 10),
+			pallet_assets::Error::<Runtime>::Frozen
+		);
+	});
+}
+
+#[test]
+fn council_destroys_a_class() {
+	new_test_ext().execute_with(|| {
+		let destroy = Call::Uniques(pallet_uniques::Call::destroy {
+			class: CLASS,
+			witness: DestroyWitness { instances: 1, instance_metadatas: 0, attributes: 0 },
+		});
+		motion(destroy, 2, &[1, 2]);
+
+		assert_eq!(Uniques::owner(CLASS, ITEM), None);
+	});
+}
+
+#[test]
+fn council_toggles_maintenance_mode() {
+	new_test_ext().execute_with(|| {
+		let enter = Call::MaintenanceMode(pallet_maintenance_mode::Call::enter_maintenance_mode {});
+		motion(enter, 2, &[1, 2]);
+		assert!(MaintenanceMode::maintenance_mode());
+
+		let transfer = Call::Assets(pallet_assets::Call::transfer {
+			id: ASSET,
+			target: account(1).into(),
+			amount: 10,
+		});
+		assert_err_ignore_postinfo!(
+			transfer.dispatch(Origin::signed(account(4))),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+
+		// The council still votes in maintenance mode, and can exit it.
+		let exit = Call::MaintenanceMode(pallet_maintenance_mode::Call::exit_maintenance_mode {});
+		motion(exit, 2, &[2, 3]);
+		assert!(!MaintenanceMode::maintenance_mode());
+	});
+}
+
+#[test]
+fn a_single_member_is_not_enough() {
+	new_test_ext().execute_with(|| {
+		let enter = Call::MaintenanceMode(pallet_maintenance_mode::Call::enter_maintenance_mode {});
+		motion(enter, 1, &[1]);
+
+		assert!(!MaintenanceMode::maintenance_mode());
+	});
+}
@@ -0,0 +1,74 @@
+//! Dry runs report the result of a call and the events it deposits, and leave the state as it
+//! was, whether the call succeeds or fails.
+
+use rusty_node_runtime::{
+	dry_run::simulate_call, AccountId, AssetId, Assets, Call, Event, Runtime, System,
+};
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice owns the asset, holding 100 of it.
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(account(1), 1_000_000)])
+		.asset(ASSET, account(1), vec![(account(1), 100)])
+		.build()
+}
+
+fn transfer(to: &AccountId, amount: u128) -> Call {
+	Call::Assets(pallet_assets::Call::transfer { id: ASSET, target: to.clone().into(), amount })
+}
+
+/// Check that the simulations of `check` leave the state as it was.
+fn unchanged(check: impl FnOnce()) {
+	let (alice, bob) = (account(1), account(2));
+	let events = System::events().len();
+
+	check();
+
+	assert_eq!(Assets::balance(ASSET, &alice), 100);
+	assert_eq!(Assets::balance(ASSET, &bob), 0);
+	assert_eq!(System::account(&bob).sufficients, 0);
+	assert_eq!(System::events().len(), events);
+}
+
+#[test]
+fn simulations_report_the_result_and_events_of_calls() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (account(1), account(2));
+
+		unchanged(|| {
+			let simulation = simulate_call(alice.clone(), transfer(&bob, 10));
+
+			assert!(simulation.result.is_ok());
+			assert!(simulation.events.contains(&Event::Assets(pallet_assets::Event::Transferred {
+				asset_id: ASSET,
+				from: alice,
+				to: bob,
+				amount: 10,
+			})));
+		});
+	});
+}
+
+#[test]
+fn simulations_report_the_errors_of_failing_calls() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (account(1), account(2));
+
+		unchanged(|| {
+			let simulation = simulate_call(alice, transfer(&bob, 1_000));
+
+			assert_eq!(
+				simulation.result.map_err(|error| error.error),
+				Err(pallet_assets::Error::<Runtime>::BalanceLow.into())
+			);
+			assert_eq!(simulation.events, vec![]);
+		});
+	});
+}
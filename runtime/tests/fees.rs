@@ -0,0 +1,76 @@
+//! The `TreasuryFeeShare` of the fees goes to the treasury and the rest is burned, whether they
+//! are paid in the native currency or with an asset.
+
+use frame_support::traits::{Currency, ExistenceRequirement, OnUnbalanced, WithdrawReasons};
+use rusty_node_runtime::{
+	fees::{deposit_asset_fee, DealWithFees},
+	AccountId, AssetId, Assets, Balances, Origin, Runtime, System, Treasury,
+};
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+/// An asset with a minimum balance of 1_000.
+const COSTLY_ASSET: AssetId = AssetId(101);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice holds 1_000_000 of the native currency, and owns both assets.
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(account(1), 1_000_000)])
+		.asset(ASSET, account(1), vec![])
+		.with(|| {
+			Assets::force_create(Origin::root(), COSTLY_ASSET, account(1).into(), true, 1_000)
+				.unwrap();
+		})
+		.build()
+}
+
+#[test]
+fn native_fees_and_tips_are_split() {
+	new_test_ext().execute_with(|| {
+		let alice = account(1);
+		let withdraw = |amount| {
+			Balances::withdraw(
+				&alice,
+				amount,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::KeepAlive,
+			)
+			.unwrap()
+		};
+		let issuance = Balances::total_issuance();
+
+		DealWithFees::on_unbalanceds(vec![withdraw(10_000), withdraw(1_000)].into_iter());
+
+		assert_eq!(Balances::free_balance(&Treasury::account_id()), 8_800);
+		assert_eq!(Balances::total_issuance(), issuance - 2_200);
+	});
+}
+
+#[test]
+fn asset_fees_are_split() {
+	new_test_ext().execute_with(|| {
+		// The fee was burned from Bob when it was withdrawn.
+		deposit_asset_fee(ASSET, 1_000);
+
+		assert_eq!(Assets::balance(ASSET, &Treasury::account_id()), 800);
+		assert_eq!(Assets::total_issuance(ASSET), 800);
+	});
+}
+
+#[test]
+fn asset_shares_the_treasury_cannot_take_stay_burned() {
+	new_test_ext().execute_with(|| {
+		let events = System::events().len();
+
+		deposit_asset_fee(COSTLY_ASSET, 1_000);
+
+		assert_eq!(Assets::balance(COSTLY_ASSET, &Treasury::account_id()), 0);
+		assert_eq!(Assets::total_issuance(COSTLY_ASSET), 0);
+		// The assets pallet reported the whole fee burned when it was withdrawn.
+		assert_eq!(System::events().len(), events);
+	});
+}
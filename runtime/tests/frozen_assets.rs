@@ -0,0 +1,270 @@
+//! The transaction pool rejects the transfers and burns out of frozen accounts, which the assets
+//! pallet would fail once dispatched, and the mints of frozen assets, which the call filter of the
+//! runtime fails, including the ones dispatched by a proxy or a multisig.
+
+use codec::Encode;
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok, weights::GetDispatchInfo};
+use rusty_node_runtime::{
+	multisig_account, AccountId, AssetId, Assets, Call, CheckFrozenAssets, Origin, Runtime,
+	Utility, FROZEN_ASSET,
+};
+use sp_runtime::{
+	traits::{Dispatchable, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice owns the asset, and Bob holds 100 of it.
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(account(1), 1_000_000), (account(2), 1_000_000)])
+		.asset(ASSET, account(1), vec![(account(2), 100)])
+		.build()
+}
+
+fn validate(who: &AccountId, call: &Call) -> Result<(), TransactionValidityError> {
+	let info = call.get_dispatch_info();
+	CheckFrozenAssets::new().validate(who, call, &info, 0).map(|_| ())
+}
+
+fn frozen() -> Result<(), TransactionValidityError> {
+	Err(InvalidTransaction::Custom(FROZEN_ASSET).into())
+}
+
+/// A transfer of 10 of the asset to `target`, out of the account it is dispatched as.
+fn transfer_to(target: &AccountId) -> Call {
+	Call::Assets(pallet_assets::Call::transfer {
+		id: ASSET,
+		target: target.clone().into(),
+		amount: 10,
+	})
+}
+
+#[test]
+fn transfers_and_burns_out_of_frozen_accounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (account(1), account(2));
+		let transfer = Call::Assets(pallet_assets::Call::transfer {
+			id: ASSET,
+			target: alice.clone().into(),
+			amount: 10,
+		});
+		let burn = Call::Assets(pallet_assets::Call::burn {
+			id: ASSET,
+			who: bob.clone().into(),
+			amount: 10,
+		});
+		assert_ok!(validate(&bob, &transfer));
+		assert_ok!(validate(&alice, &burn));
+
+		assert_ok!(Assets::freeze(Origin::signed(alice.clone()), ASSET, bob.clone().into()));
+		let frozen: Result<(), TransactionValidityError> =
+			Err(InvalidTransaction::Custom(FROZEN_ASSET).into());
+		assert_eq!(validate(&bob, &transfer), frozen);
+		assert_eq!(validate(&alice, &burn), frozen);
+		assert_noop!(
+			Assets::burn(Origin::signed(alice.clone()), ASSET, bob.clone().into(), 10),
+			pallet_assets::Error::<Runtime>::Frozen
+		);
+
+		assert_ok!(Assets::thaw(Origin::signed(alice.clone()), ASSET, bob.clone().into()));
+		assert_ok!(validate(&bob, &transfer));
+		assert_ok!(validate(&alice, &burn));
+	});
+}
+
+#[test]
+fn frozen_assets_are_not_minted() {
+	new_test_ext().execute_with(|| {
+		let alice = account(1);
+		let mint = Call::Assets(pallet_assets::Call::mint {
+			id: ASSET,
+			beneficiary: alice.clone().into(),
+			amount: 10,
+		});
+
+		assert_ok!(Assets::freeze_asset(Origin::signed(alice.clone()), ASSET));
+		assert_eq!(validate(&alice, &mint), Err(InvalidTransaction::Custom(FROZEN_ASSET).into()));
+		assert_err_ignore_postinfo!(
+			mint.clone().dispatch(Origin::signed(alice.clone())),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+
+		assert_ok!(Assets::thaw_asset(Origin::signed(alice.clone()), ASSET));
+		assert_ok!(validate(&alice, &mint));
+		assert_ok!(mint.dispatch(Origin::signed(alice.clone())));
+		assert_eq!(Assets::balance(ASSET, alice), 10);
+	});
+}
+
+#[test]
+fn transfers_out_of_frozen_accounts_are_rejected_whatever_the_amount() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (account(1), account(2));
+		let transfer = Call::Assets(pallet_assets::Call::transfer {
+			id: ASSET,
+			target: alice.clone().into(),
+			amount: 0,
+		});
+		// More than Bob holds.
+		let burn = Call::Assets(pallet_assets::Call::burn {
+			id: ASSET,
+			who: bob.clone().into(),
+			amount: u128::MAX,
+		});
+
+		assert_ok!(Assets::freeze(Origin::signed(alice.clone()), ASSET, bob.clone().into()));
+		let frozen: Result<(), TransactionValidityError> =
+			Err(InvalidTransaction::Custom(FROZEN_ASSET).into());
+		assert_eq!(validate(&bob, &transfer), frozen);
+		assert_eq!(validate(&alice, &burn), frozen);
+
+		assert_ok!(Assets::thaw(Origin::signed(alice.clone()), ASSET, bob.clone().into()));
+		assert_ok!(Assets::freeze_asset(Origin::signed(alice.clone()), ASSET));
+		assert_eq!(validate(&bob, &transfer), frozen);
+		assert_eq!(validate(&alice, &burn), frozen);
+	});
+}
+
+#[test]
+fn transfers_of_a_proxy_out_of_frozen_accounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob, charlie) = (account(1), account(2), account(3));
+		let proxy = |real: &AccountId| {
+			Call::ProxyPermissions(pallet_proxy_permissions::Call::proxy {
+				real: real.clone(),
+				force_proxy_type: None,
+				call: Box::new(transfer_to(&charlie)),
+			})
+		};
+		let announced = Call::ProxyPermissions(pallet_proxy_permissions::Call::proxy_announced {
+			delegate: charlie.clone(),
+			real: bob.clone(),
+			force_proxy_type: None,
+			call: Box::new(transfer_to(&charlie)),
+		});
+		assert_ok!(validate(&charlie, &proxy(&bob)));
+		assert_ok!(validate(&charlie, &announced));
+
+		// The proxied transfers debit Bob, not the proxy.
+		assert_ok!(Assets::freeze(Origin::signed(alice.clone()), ASSET, bob.clone().into()));
+		assert_eq!(validate(&charlie, &proxy(&bob)), frozen());
+		assert_eq!(validate(&charlie, &announced), frozen());
+		assert_ok!(validate(&bob, &proxy(&charlie)));
+	});
+}
+
+#[test]
+fn transfers_of_a_multisig_out_of_frozen_accounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob, charlie) = (account(1), account(2), account(3));
+		let shared = multisig_account(&[alice.clone(), bob.clone(), charlie.clone()], 2);
+		let single = multisig_account(&[alice.clone(), bob.clone()], 1);
+		for holder in [&shared, &single] {
+			assert_ok!(Assets::transfer(
+				Origin::signed(bob.clone()),
+				ASSET,
+				holder.clone().into(),
+				20
+			));
+		}
+		let as_multi = Call::Multisig(pallet_multisig::Call::as_multi {
+			threshold: 2,
+			other_signatories: vec![bob.clone(), charlie.clone()],
+			maybe_timepoint: None,
+			call: transfer_to(&bob).encode(),
+			store_call: false,
+			max_weight: 1_000_000_000_000,
+		});
+		let as_multi_threshold_1 = Call::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+			other_signatories: vec![bob.clone()],
+			call: Box::new(transfer_to(&bob)),
+		});
+		assert_ok!(validate(&alice, &as_multi));
+		assert_ok!(validate(&alice, &as_multi_threshold_1));
+
+		// The transfers debit the account of the multisig, not its signatory.
+		assert_ok!(Assets::freeze(Origin::signed(alice.clone()), ASSET, shared.into()));
+		assert_eq!(validate(&alice, &as_multi), frozen());
+		assert_ok!(validate(&alice, &as_multi_threshold_1));
+
+		assert_ok!(Assets::freeze(Origin::signed(alice.clone()), ASSET, single.into()));
+		assert_eq!(validate(&alice, &as_multi_threshold_1), frozen());
+	});
+}
+
+#[test]
+fn nested_transfers_and_burns_out_of_frozen_accounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob, charlie) = (account(1), account(2), account(3));
+		let burn = Call::Assets(pallet_assets::Call::burn {
+			id: ASSET,
+			who: bob.clone().into(),
+			amount: 10,
+		});
+		// Charlie, as a proxy of Bob, batches a transfer of his.
+		let proxied_batch = Call::Utility(pallet_utility::Call::batch_all {
+			calls: vec![Call::ProxyPermissions(pallet_proxy_permissions::Call::proxy {
+				real: bob.clone(),
+				force_proxy_type: None,
+				call: Box::new(transfer_to(&charlie)),
+			})],
+		});
+		// Sudo, as Bob, proxies a batch of his transfers.
+		let sudo_batch = Call::Sudo(pallet_sudo::Call::sudo_as {
+			who: bob.clone().into(),
+			call: Box::new(Call::BatchUtility(pallet_batch_utility::Call::batch {
+				calls: vec![transfer_to(&charlie)],
+			})),
+		});
+		// Sudo batches the burn of the issuer, as root.
+		let root_burn = Call::Sudo(pallet_sudo::Call::sudo {
+			call: Box::new(Call::Utility(pallet_utility::Call::batch { calls: vec![burn] })),
+		});
+		let nested = [proxied_batch, sudo_batch, root_burn];
+		for call in &nested {
+			assert_ok!(validate(&charlie, call));
+		}
+
+		assert_ok!(Assets::freeze(Origin::signed(alice.clone()), ASSET, bob.clone().into()));
+		for call in &nested {
+			assert_eq!(validate(&charlie, call), frozen());
+		}
+
+		assert_ok!(Assets::thaw(Origin::signed(alice), ASSET, bob.into()));
+		for call in &nested {
+			assert_ok!(validate(&charlie, call));
+		}
+	});
+}
+
+#[test]
+fn transfers_of_a_derivative_account_are_checked_as_that_account() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (account(1), account(2));
+		let derivative = Utility::derivative_account_id(bob.clone(), 0);
+		assert_ok!(Assets::transfer(
+			Origin::signed(bob.clone()),
+			ASSET,
+			derivative.clone().into(),
+			20
+		));
+		let as_derivative = |index| {
+			Call::Utility(pallet_utility::Call::as_derivative {
+				index,
+				call: Box::new(transfer_to(&alice)),
+			})
+		};
+
+		assert_ok!(Assets::freeze(Origin::signed(alice.clone()), ASSET, derivative.into()));
+		assert_eq!(validate(&bob, &as_derivative(0)), frozen());
+		assert_ok!(validate(&bob, &as_derivative(1)));
+		assert_ok!(validate(&bob, &transfer_to(&alice)));
+	});
+}
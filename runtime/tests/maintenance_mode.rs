@@ -0,0 +1,60 @@
+//! Blocks are still built in maintenance mode: the inherents they must include are not filtered
+//! out, e.g. the releases of the due timed transfers.
+
+use frame_support::assert_ok;
+use rusty_node_runtime::{
+	AccountId, AssetId, Assets, Call, Executive, Header, MaintenanceMode, Origin, Runtime,
+	TimedTransfer, UncheckedExtrinsic,
+};
+use sp_runtime::traits::Header as _;
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice owns the asset, holding 1_000 of it.
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(account(1), 1_000_000)])
+		.asset(ASSET, account(1), vec![(account(1), 1_000)])
+		.build()
+}
+
+fn inherent(call: Call) -> UncheckedExtrinsic {
+	UncheckedExtrinsic::new_unsigned(call)
+}
+
+#[test]
+fn blocks_release_due_transfers_in_maintenance_mode() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (account(1), account(2));
+		assert_ok!(TimedTransfer::transfer_at(
+			Origin::signed(alice),
+			bob.clone(),
+			ASSET,
+			100,
+			1_000
+		));
+		assert_ok!(MaintenanceMode::enter_maintenance_mode(Origin::root()));
+
+		// Within the first slot, as no slot is set by a digest.
+		Executive::initialize_block(&Header::new(
+			2,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		));
+		let set = Call::Timestamp(pallet_timestamp::Call::set { now: 1_000 });
+		assert_eq!(Executive::apply_extrinsic(inherent(set)), Ok(Ok(())));
+		let release = Call::TimedTransfer(pallet_timed_transfer::Call::release_due {});
+		assert_eq!(Executive::apply_extrinsic(inherent(release)), Ok(Ok(())));
+		Executive::finalize_block();
+
+		assert!(MaintenanceMode::maintenance_mode());
+		assert_eq!(Assets::balance(ASSET, &bob), 100);
+	});
+}
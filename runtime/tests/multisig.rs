@@ -0,0 +1,110 @@
+//! An asset owned by a 2-of-3 multisig: the multisig account is derived from the signatories to
+//! create the asset with, then two of them approve its mints and metadata.
+
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, weights::Weight};
+use rusty_node_runtime::{multisig_account, AccountId, AssetId, Assets, Call, Multisig, Origin, Runtime};
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+const THRESHOLD: u16 = 2;
+const MAX_WEIGHT: Weight = 1_000_000_000_000;
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+fn signatories() -> Vec<AccountId> {
+	vec![account(1), account(2), account(3)]
+}
+
+fn shared() -> AccountId {
+	multisig_account(&signatories(), THRESHOLD)
+}
+
+/// The signatories, and the multisig account, are funded for their deposits.
+fn new_test_ext() -> sp_io::TestExternalities {
+	let funded = signatories().into_iter().chain([shared()]).map(|who| (who, 1_000_000)).collect();
+	ExtBuilder::<Runtime>::default().balances(funded).build()
+}
+
+/// `who` approves `call` as one of the signatories, dispatching it if the threshold is met.
+fn approve(who: u8, call: &Call, first: bool) {
+	let who = account(who);
+	let others = signatories().into_iter().filter(|other| *other != who).collect();
+	let timepoint = if first { None } else { Some(Multisig::timepoint()) };
+	assert_ok!(Multisig::as_multi(
+		Origin::signed(who),
+		THRESHOLD,
+		others,
+		timepoint,
+		call.encode(),
+		false,
+		MAX_WEIGHT,
+	));
+}
+
+/// Two of the signatories create the asset, with the multisig account as its owner.
+fn create_asset() {
+	let create = Call::Assets(pallet_assets::Call::create {
+		id: ASSET,
+		admin: shared().into(),
+		min_balance: 1,
+	});
+	approve(1, &create, true);
+	approve(3, &create, false);
+}
+
+#[test]
+fn multisig_account_does_not_depend_on_the_order() {
+	let reversed: Vec<_> = signatories().into_iter().rev().collect();
+	assert_eq!(multisig_account(&reversed, THRESHOLD), shared());
+	assert_ne!(multisig_account(&signatories(), 3), shared());
+}
+
+#[test]
+fn multisig_creates_and_mints() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+
+		let mint = Call::Assets(pallet_assets::Call::mint {
+			id: ASSET,
+			beneficiary: account(4).into(),
+			amount: 50,
+		});
+		approve(2, &mint, true);
+		assert_eq!(Assets::balance(ASSET, account(4)), 0);
+
+		approve(1, &mint, false);
+		assert_eq!(Assets::balance(ASSET, account(4)), 50);
+	});
+}
+
+#[test]
+fn multisig_sets_metadata() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+
+		let metadata = Call::Assets(pallet_assets::Call::set_metadata {
+			id: ASSET,
+			name: b"Shared".to_vec(),
+			symbol: b"SHRD".to_vec(),
+			decimals: 12,
+		});
+		approve(2, &metadata, true);
+		approve(3, &metadata, false);
+
+		// The metadata is set: clearing it takes the multisig, not a single signatory.
+		assert_noop!(
+			Assets::clear_metadata(Origin::signed(account(1)), ASSET),
+			pallet_assets::Error::<Runtime>::NoPermission
+		);
+		let clear = Call::Assets(pallet_assets::Call::clear_metadata { id: ASSET });
+		approve(1, &clear, true);
+		approve(2, &clear, false);
+		assert_noop!(
+			Assets::clear_metadata(Origin::signed(shared()), ASSET),
+			pallet_assets::Error::<Runtime>::Unknown
+		);
+	});
+}
@@ -0,0 +1,55 @@
+//! The pause registry pauses any pallet of the runtime by its name, whether or not the pallet
+//! guards its calls with the registry itself, but the ones keeping the chain running.
+
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok};
+use rusty_node_runtime::{AccountId, Call, Hash, Origin, PauseRegistry, Runtime};
+use sp_runtime::traits::Dispatchable;
+use test_utils::ExtBuilder;
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Runtime>::default().balances(vec![(account(1), 1_000_000)]).build()
+}
+
+fn claim(n: u8) -> Call {
+	Call::ProofOfExistence(pallet_proof_of_existence::Call::create_claim {
+		hash: Hash::repeat_byte(n),
+	})
+}
+
+#[test]
+fn calls_of_paused_pallets_are_filtered() {
+	new_test_ext().execute_with(|| {
+		let alice = Origin::signed(account(1));
+		assert_ok!(PauseRegistry::pause(Origin::root(), b"ProofOfExistence".to_vec()));
+
+		assert_err_ignore_postinfo!(
+			claim(1).dispatch(alice.clone()),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+		// Batched, or not.
+		let batch = Call::Utility(pallet_utility::Call::batch_all { calls: vec![claim(2)] });
+		assert_err_ignore_postinfo!(
+			batch.dispatch(alice.clone()),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+
+		assert_ok!(PauseRegistry::unpause(Origin::root(), b"ProofOfExistence".to_vec()));
+		assert_ok!(claim(1).dispatch(alice));
+	});
+}
+
+#[test]
+fn only_pallets_of_the_runtime_are_paused() {
+	new_test_ext().execute_with(|| {
+		for pallet in [&b"System"[..], b"Sudo", b"PauseRegistry", b"MaintenanceMode", b"Unknown"] {
+			assert_noop!(
+				PauseRegistry::pause(Origin::root(), pallet.to_vec()),
+				pallet_pause_registry::Error::<Runtime>::NotPausable
+			);
+		}
+	});
+}
@@ -0,0 +1,169 @@
+//! The proxies an issuer delegates its day-to-day token operations to: an asset manager only
+//! dispatches the issuer operations of the assets pallet, an NFT manager the ones of the uniques
+//! pallet, and neither can move tokens, hand over the team or give away what it manages.
+
+use frame_support::{assert_noop, assert_ok};
+use rusty_node_runtime::{
+	AccountId, AssetId, Assets, Balances, Call, ClassId, InstanceId, Origin, ProxyPermissions,
+	ProxyType, Runtime, Uniques,
+};
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+const CLASS: ClassId = ClassId(100);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice owns the asset and the class, and delegates them to Bob with `proxy_type`.
+fn new_test_ext(proxy_type: ProxyType) -> sp_io::TestExternalities {
+	let (alice, bob) = (account(1), account(2));
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(alice.clone(), 1_000_000), (bob.clone(), 1_000_000)])
+		.asset(ASSET, alice.clone(), vec![(alice.clone(), 100)])
+		.class(CLASS, alice.clone())
+		.with(move || {
+			assert_ok!(ProxyPermissions::add_proxy(Origin::signed(alice), bob, proxy_type, 0));
+		})
+		.build()
+}
+
+/// Bob dispatches `call` on behalf of Alice.
+fn proxy(call: Call) {
+	let (bob, alice) = (Origin::signed(account(2)), account(1));
+	assert_ok!(ProxyPermissions::proxy(bob, alice, None, Box::new(call)));
+}
+
+fn mint_asset() -> Call {
+	Call::Assets(pallet_assets::Call::mint {
+		id: ASSET,
+		beneficiary: account(3).into(),
+		amount: 50,
+	})
+}
+
+fn mint_item() -> Call {
+	Call::Uniques(pallet_uniques::Call::mint {
+		class: CLASS,
+		instance: InstanceId(0),
+		owner: account(3).into(),
+	})
+}
+
+fn transfer() -> Call {
+	Call::Balances(pallet_balances::Call::transfer { dest: account(3).into(), value: 1_000 })
+}
+
+#[test]
+fn asset_manager_dispatches_the_calls_of_the_assets_pallet() {
+	new_test_ext(ProxyType::AssetManager).execute_with(|| {
+		proxy(mint_asset());
+		proxy(mint_item());
+		proxy(transfer());
+
+		assert_eq!(Assets::balance(ASSET, account(3)), 50);
+		assert_eq!(Uniques::owner(CLASS, InstanceId(0)), None);
+		assert_eq!(Balances::free_balance(account(3)), 0);
+	});
+}
+
+#[test]
+fn nft_manager_dispatches_the_calls_of_the_uniques_pallet() {
+	new_test_ext(ProxyType::NftManager).execute_with(|| {
+		proxy(mint_asset());
+		proxy(mint_item());
+		proxy(transfer());
+
+		assert_eq!(Assets::balance(ASSET, account(3)), 0);
+		assert_eq!(Uniques::owner(CLASS, InstanceId(0)), Some(account(3)));
+		assert_eq!(Balances::free_balance(account(3)), 0);
+	});
+}
+
+#[test]
+fn managers_cannot_give_away_what_they_manage() {
+	new_test_ext(ProxyType::AssetManager).execute_with(|| {
+		proxy(Call::Assets(pallet_assets::Call::transfer_ownership {
+			id: ASSET,
+			owner: account(2).into(),
+		}));
+
+		// Alice still owns the asset.
+		let owner = account(3).into();
+		assert_ok!(Assets::transfer_ownership(Origin::signed(account(1)), ASSET, owner));
+	});
+	new_test_ext(ProxyType::NftManager).execute_with(|| {
+		proxy(Call::Uniques(pallet_uniques::Call::transfer_ownership {
+			class: CLASS,
+			owner: account(2).into(),
+		}));
+
+		let owner = account(3).into();
+		assert_ok!(Uniques::transfer_ownership(Origin::signed(account(1)), CLASS, owner));
+	});
+}
+
+#[test]
+fn managers_cannot_set_the_team() {
+	new_test_ext(ProxyType::AssetManager).execute_with(|| {
+		let bob = account(2);
+		proxy(Call::Assets(pallet_assets::Call::set_team {
+			id: ASSET,
+			issuer: bob.clone().into(),
+			admin: bob.clone().into(),
+			freezer: bob.clone().into(),
+		}));
+
+		// Bob did not become the issuer.
+		assert_noop!(
+			Assets::mint(Origin::signed(bob.clone()), ASSET, bob.into(), 50),
+			pallet_assets::Error::<Runtime>::NoPermission
+		);
+	});
+	new_test_ext(ProxyType::NftManager).execute_with(|| {
+		let bob = account(2);
+		proxy(Call::Uniques(pallet_uniques::Call::set_team {
+			class: CLASS,
+			issuer: bob.clone().into(),
+			admin: bob.clone().into(),
+			freezer: bob.clone().into(),
+		}));
+
+		assert_noop!(
+			Uniques::mint(Origin::signed(bob.clone()), CLASS, InstanceId(0), bob.into()),
+			pallet_uniques::Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn managers_cannot_transfer_the_tokens_of_their_delegator() {
+	new_test_ext(ProxyType::AssetManager).execute_with(|| {
+		proxy(Call::Assets(pallet_assets::Call::transfer {
+			id: ASSET,
+			target: account(2).into(),
+			amount: 50,
+		}));
+
+		assert_eq!(Assets::balance(ASSET, account(1)), 100);
+		assert_eq!(Assets::balance(ASSET, account(2)), 0);
+	});
+	new_test_ext(ProxyType::NftManager).execute_with(|| {
+		let alice = account(1);
+		assert_ok!(Uniques::mint(
+			Origin::signed(alice.clone()),
+			CLASS,
+			InstanceId(0),
+			alice.clone().into()
+		));
+
+		proxy(Call::Uniques(pallet_uniques::Call::transfer {
+			class: CLASS,
+			instance: InstanceId(0),
+			dest: account(2).into(),
+		}));
+
+		assert_eq!(Uniques::owner(CLASS, InstanceId(0)), Some(alice));
+	});
+}
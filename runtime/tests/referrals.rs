@@ -0,0 +1,99 @@
+//! The marketplaces and exchanges report the fees of the trades to the referral-rewards pallet,
+//! so that the referrer of a trader accrues its share, and the treasury gets the rest.
+
+use frame_support::assert_ok;
+use pallet_referral_rewards::FeeKind;
+use rusty_node_runtime::{
+	AccountId, AssetId, Assets, Balances, Kitties, Origin, OtcSwap, ReferralRewards, Runtime,
+	System, Treasury,
+};
+use sp_runtime::Perbill;
+use test_utils::ExtBuilder;
+
+const ASSET_A: AssetId = AssetId(100);
+const ASSET_B: AssetId = AssetId(101);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice refers Bob, with a tenth of the fees of both kinds of trades, and Charlie trades with
+/// him.
+fn new_test_ext() -> sp_io::TestExternalities {
+	let (alice, bob, charlie) = (account(1), account(2), account(3));
+	let holders = vec![(bob.clone(), 10_000_000), (charlie.clone(), 10_000_000)];
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![
+			(alice.clone(), 10_000_000),
+			(bob.clone(), 10_000_000),
+			(charlie.clone(), 10_000_000),
+		])
+		.asset(ASSET_A, alice.clone(), holders.clone())
+		.asset(ASSET_B, alice.clone(), holders)
+		.with(move || {
+			for kind in [FeeKind::Marketplace, FeeKind::Dex] {
+				assert_ok!(ReferralRewards::set_rate(
+					Origin::root(),
+					kind,
+					Perbill::from_percent(10)
+				));
+			}
+			let code = b"alice".to_vec().try_into().unwrap();
+			assert_ok!(ReferralRewards::register_code(Origin::signed(alice), code));
+			let code = b"alice".to_vec().try_into().unwrap();
+			assert_ok!(ReferralRewards::bind(Origin::signed(bob), code));
+		})
+		.build()
+}
+
+#[test]
+fn buying_a_kitty_credits_the_referrer_of_the_buyer() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob, charlie) = (account(1), account(2), account(3));
+		assert_ok!(Kitties::create_kitty(Origin::signed(charlie.clone())));
+		assert_ok!(Kitties::set_price(Origin::signed(charlie.clone()), 0, Some(1_000_000)));
+
+		assert_ok!(Kitties::buy_kitty(Origin::signed(bob.clone()), 0, 1_000_000));
+
+		// The 1% fee is 10_000: a tenth of it for Alice, the rest for the treasury.
+		assert_eq!(ReferralRewards::accrued_native(&alice), 1_000);
+		assert_eq!(Balances::free_balance(&Treasury::account_id()), 9_000);
+		assert_eq!(Balances::free_balance(&charlie), 10_990_000);
+		assert_eq!(Balances::free_balance(&bob), 9_000_000);
+		System::assert_has_event(
+			pallet_referral_rewards::Event::NativeRewardAccrued {
+				referrer: alice.clone(),
+				referee: bob,
+				amount: 1_000,
+			}
+			.into(),
+		);
+
+		assert_ok!(ReferralRewards::claim_native(Origin::signed(alice.clone())));
+		assert_eq!(Balances::free_balance(&alice), 10_001_000);
+	})
+}
+
+#[test]
+fn taking_an_offer_credits_the_referrer_of_the_taker() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob, charlie) = (account(1), account(2), account(3));
+		assert_ok!(OtcSwap::create_offer(
+			Origin::signed(charlie.clone()),
+			ASSET_A,
+			1_000_000,
+			ASSET_B,
+			1_000_000,
+			None,
+			10
+		));
+
+		assert_ok!(OtcSwap::take_offer(Origin::signed(bob.clone()), 0));
+
+		// The 0.3% fee is 3_000: a tenth of it for Alice, the rest for the treasury.
+		assert_eq!(ReferralRewards::accrued(&alice, ASSET_B), 300);
+		assert_eq!(Assets::balance(ASSET_B, &Treasury::account_id()), 2_700);
+		assert_eq!(Assets::balance(ASSET_B, &charlie), 10_997_000);
+		assert_eq!(Assets::balance(ASSET_B, &bob), 9_000_000);
+	})
+}
@@ -0,0 +1,123 @@
+//! The token operations scheduled for a later block: a future mint by an operator issuing the
+//! asset, a metadata freeze and a timed sunset of the asset by root, cancelled until they run.
+
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok};
+use rusty_node_runtime::{AccountId, AssetId, Assets, Call, Operators, Origin, Runtime, Scheduler};
+use sp_runtime::DispatchError;
+use test_utils::{run_to_block, ExtBuilder};
+
+const ASSET: AssetId = AssetId(100);
+const SUNSET: &[u8] = b"sunset";
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Dave owns, and issues, the asset.
+fn new_test_ext() -> sp_io::TestExternalities {
+	let dave = account(4);
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(account(1), 1_000_000), (dave.clone(), 1_000_000)])
+		.asset(ASSET, dave.clone(), vec![(dave, 100)])
+		.build()
+}
+
+fn freeze_asset() -> Call {
+	let dave = account(4);
+	Call::Assets(pallet_assets::Call::force_asset_status {
+		id: ASSET,
+		owner: dave.clone().into(),
+		issuer: dave.clone().into(),
+		admin: dave.clone().into(),
+		freezer: dave.into(),
+		min_balance: 1,
+		is_sufficient: true,
+		is_frozen: true,
+	})
+}
+
+#[test]
+fn operator_schedules_a_mint() {
+	new_test_ext().execute_with(|| {
+		let dave = account(4);
+		Operators::set(&vec![dave.clone()]);
+		let mint = Call::Assets(pallet_assets::Call::mint {
+			id: ASSET,
+			beneficiary: account(1).into(),
+			amount: 50,
+		});
+		assert_ok!(Scheduler::schedule(Origin::signed(dave), 5, None, 0, Box::new(mint)));
+
+		run_to_block::<Runtime, Scheduler>(4);
+		assert_eq!(Assets::balance(ASSET, account(1)), 0);
+
+		// The mint is dispatched by the operator, who issues the asset.
+		run_to_block::<Runtime, Scheduler>(5);
+		assert_eq!(Assets::balance(ASSET, account(1)), 50);
+	});
+}
+
+#[test]
+fn root_schedules_a_metadata_freeze() {
+	new_test_ext().execute_with(|| {
+		let freeze = Call::Assets(pallet_assets::Call::force_set_metadata {
+			id: ASSET,
+			name: b"Demo".to_vec(),
+			symbol: b"DEMO".to_vec(),
+			decimals: 12,
+			is_frozen: true,
+		});
+		assert_ok!(Scheduler::schedule(Origin::root(), 3, None, 0, Box::new(freeze)));
+
+		let rename = |name: &[u8]| {
+			let (name, symbol) = (name.to_vec(), b"DEMO".to_vec());
+			Assets::set_metadata(Origin::signed(account(4)), ASSET, name, symbol, 12)
+		};
+		assert_ok!(rename(b"Before"));
+
+		run_to_block::<Runtime, Scheduler>(3);
+		assert_noop!(rename(b"After"), pallet_assets::Error::<Runtime>::NoPermission);
+	});
+}
+
+#[test]
+fn scheduled_sunset_freezes_the_asset() {
+	new_test_ext().execute_with(|| {
+		let sunset = Box::new(freeze_asset());
+		assert_ok!(Scheduler::schedule_named(Origin::root(), SUNSET.to_vec(), 10, None, 0, sunset));
+
+		run_to_block::<Runtime, Scheduler>(10);
+		assert_err_ignore_postinfo!(
+			Assets::transfer(Origin::signed(account(4)), ASSET, account(1).into(), 10),
+			pallet_assets::Error::<Runtime>::Frozen
+		);
+	});
+}
+
+#[test]
+fn sunset_is_cancelled_before_it_runs() {
+	new_test_ext().execute_with(|| {
+		let sunset = Box::new(freeze_asset());
+		assert_ok!(Scheduler::schedule_named(Origin::root(), SUNSET.to_vec(), 10, None, 0, sunset));
+
+		run_to_block::<Runtime, Scheduler>(5);
+		assert_ok!(Scheduler::cancel_named(Origin::root(), SUNSET.to_vec()));
+
+		run_to_block::<Runtime, Scheduler>(10);
+		assert_ok!(Assets::transfer(Origin::signed(account(4)), ASSET, account(1).into(), 10));
+		assert_noop!(
+			Scheduler::cancel_named(Origin::root(), SUNSET.to_vec()),
+			pallet_scheduler::Error::<Runtime>::NotFound
+		);
+	});
+}
+
+#[test]
+fn accounts_cannot_schedule() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Scheduler::schedule(Origin::signed(account(4)), 5, None, 0, Box::new(freeze_asset())),
+			DispatchError::BadOrigin
+		);
+	});
+}
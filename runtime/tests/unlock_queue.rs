@@ -0,0 +1,46 @@
+//! The ends of the leases of the nft-rental pallet, scheduled in the unlock queue and handed back
+//! to the pallet when they are due.
+
+use frame_support::assert_ok;
+use rusty_node_runtime::{
+	AccountId, AssetId, ClassId, InstanceId, NftRental, Origin, Runtime, Uniques, UnlockKey,
+	UnlockQueue,
+};
+use test_utils::{run_to_block, ExtBuilder};
+
+const ASSET: AssetId = AssetId(100);
+const CLASS: ClassId = ClassId(100);
+const INSTANCE: InstanceId = InstanceId(1);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice lists her item for 10 of the asset per period of 5 blocks, and Bob rents it.
+fn new_test_ext() -> sp_io::TestExternalities {
+	let (alice, bob) = (account(1), account(2));
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(alice.clone(), 1_000_000), (bob.clone(), 1_000_000)])
+		.asset(ASSET, alice.clone(), vec![(alice.clone(), 100), (bob.clone(), 100)])
+		.class(CLASS, alice.clone())
+		.item(CLASS, INSTANCE, alice.clone())
+		.with(move || {
+			assert_ok!(NftRental::list(Origin::signed(alice), CLASS, INSTANCE, ASSET, 10, 5, 3));
+			assert_ok!(NftRental::rent(Origin::signed(bob), CLASS, INSTANCE, 1));
+		})
+		.build()
+}
+
+#[test]
+fn lease_ends_through_the_unlock_queue() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(UnlockQueue::queue(6).into_inner(), vec![UnlockKey::Lease(CLASS, INSTANCE)]);
+
+		run_to_block::<Runtime, UnlockQueue>(5);
+		assert_eq!(Uniques::owner(CLASS, INSTANCE), Some(account(2)));
+
+		run_to_block::<Runtime, UnlockQueue>(6);
+		assert_eq!(Uniques::owner(CLASS, INSTANCE), Some(NftRental::account_id()));
+		assert!(NftRental::lease(CLASS, INSTANCE).is_none());
+	});
+}
@@ -0,0 +1,94 @@
+//! Batches of calls across the assets and uniques pallets: `batch` keeps the calls before the
+//! first failing one and stops there, `batch_all` reverts them all, and the transaction pool
+//! rejects batches transferring frozen assets.
+
+use frame_support::{assert_err_ignore_postinfo, assert_ok, weights::GetDispatchInfo};
+use rusty_node_runtime::{
+	AccountId, AssetId, Assets, Call, CheckFrozenAssets, ClassId, InstanceId, Origin, Runtime,
+	Uniques, Utility, FROZEN_ASSET,
+};
+use sp_runtime::{
+	traits::SignedExtension,
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use test_utils::ExtBuilder;
+
+const ASSET: AssetId = AssetId(100);
+const CLASS: ClassId = ClassId(100);
+
+fn account(n: u8) -> AccountId {
+	AccountId::from([n; 32])
+}
+
+/// Alice owns the asset, holding 100 of it, and the class.
+fn new_test_ext() -> sp_io::TestExternalities {
+	let alice = account(1);
+	ExtBuilder::<Runtime>::default()
+		.balances(vec![(alice.clone(), 1_000_000)])
+		.asset(ASSET, alice.clone(), vec![(alice.clone(), 100)])
+		.class(CLASS, alice)
+		.build()
+}
+
+fn mint_item(instance: u32) -> Call {
+	Call::Uniques(pallet_uniques::Call::mint {
+		class: CLASS,
+		instance: InstanceId(instance),
+		owner: account(3).into(),
+	})
+}
+
+fn transfer(amount: u128) -> Call {
+	Call::Assets(pallet_assets::Call::transfer { id: ASSET, target: account(3).into(), amount })
+}
+
+/// Mints an item, transfers more of the asset than Alice holds, then mints another item.
+fn failing_batch() -> Vec<Call> {
+	vec![mint_item(0), transfer(40), transfer(1_000), mint_item(1)]
+}
+
+#[test]
+fn batch_stops_at_the_first_failure() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Utility::batch(Origin::signed(account(1)), failing_batch()));
+
+		assert_eq!(Uniques::owner(CLASS, InstanceId(0)), Some(account(3)));
+		assert_eq!(Assets::balance(ASSET, account(3)), 40);
+		assert_eq!(Uniques::owner(CLASS, InstanceId(1)), None);
+	});
+}
+
+#[test]
+fn batch_all_reverts_every_call() {
+	new_test_ext().execute_with(|| {
+		assert_err_ignore_postinfo!(
+			Utility::batch_all(Origin::signed(account(1)), failing_batch()),
+			pallet_assets::Error::<Runtime>::BalanceLow
+		);
+
+		assert_eq!(Uniques::owner(CLASS, InstanceId(0)), None);
+		assert_eq!(Assets::balance(ASSET, account(1)), 100);
+		assert_eq!(Assets::balance(ASSET, account(3)), 0);
+	});
+}
+
+#[test]
+fn batches_of_transfers_of_frozen_assets_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let alice = account(1);
+		assert_ok!(Assets::freeze_asset(Origin::signed(alice.clone()), ASSET));
+
+		let batch = Call::Utility(pallet_utility::Call::batch {
+			calls: vec![mint_item(0), transfer(40)],
+		});
+		let info = batch.get_dispatch_info();
+		assert_eq!(
+			CheckFrozenAssets::new().validate(&alice, &batch, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(FROZEN_ASSET)))
+		);
+
+		let batch = Call::Utility(pallet_utility::Call::batch { calls: vec![mint_item(0)] });
+		let info = batch.get_dispatch_info();
+		assert_ok!(CheckFrozenAssets::new().validate(&alice, &batch, &info, 0));
+	});
+}
@@ -0,0 +1,109 @@
+//! Populate the storage of an asset before measuring a call over it.
+//!
+//! A fresh asset has a single holder, no approvals and no metadata: a call measured against it
+//! reads and writes smaller, and fewer, storage items than against an asset in use. The helpers
+//! give it many holders, approvals from its owner and metadata of the maximal length.
+
+use frame_benchmarking::account;
+use frame_support::{
+	codec::Decode,
+	dispatch::DispatchResult,
+	sp_runtime::traits::{Bounded, One},
+	sp_std::vec,
+	traits::{
+		tokens::fungibles::{approvals, metadata, Create, Mutate},
+		Currency,
+	},
+};
+
+const SEED: u32 = 0;
+
+/// The storage shape of an asset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AssetShape {
+	/// The number of accounts holding the asset, besides its owner.
+	pub holders: u32,
+	/// The number of approvals from the owner of the asset.
+	pub approvals: u32,
+	/// The length of the name, and of the symbol, of the asset.
+	pub metadata_len: u32,
+}
+
+impl AssetShape {
+	/// The shape the benchmarks measure against: the length of the metadata is the `StringLimit`
+	/// of the runtime, and of the mocks.
+	pub const WORST_CASE: Self = Self { holders: 100, approvals: 16, metadata_len: 50 };
+}
+
+/// The `index`-th holder, or delegate, of the assets populated by the helpers.
+pub fn holder<AccountId: Decode>(index: u32) -> AccountId {
+	account("holder", index, SEED)
+}
+
+/// Create `asset`, owned by `owner`, and populate it up to `shape`: the holders each hold
+/// `amount`, and are each approved to spend `amount` of the owner until the approvals run out.
+///
+/// The owner is funded with `C`, the native currency, for the deposits of the approvals and of
+/// the metadata.
+pub fn populate_asset<AccountId, C, Assets>(
+	asset: Assets::AssetId,
+	owner: &AccountId,
+	amount: Assets::Balance,
+	shape: AssetShape,
+) -> DispatchResult
+where
+	AccountId: Clone + Decode,
+	C: Currency<AccountId>,
+	Assets: Create<AccountId>
+		+ Mutate<AccountId>
+		+ approvals::Mutate<AccountId>
+		+ metadata::Mutate<AccountId>,
+{
+	C::make_free_balance_be(owner, C::Balance::max_value() / 2u32.into());
+	Assets::create(asset, owner.clone(), true, One::one())?;
+	add_holders::<AccountId, Assets>(asset, amount, shape.holders)?;
+	add_approvals::<AccountId, Assets>(asset, owner, amount, shape.approvals)?;
+	set_metadata::<AccountId, Assets>(asset, owner, shape.metadata_len)
+}
+
+/// Mint `amount` of `asset` to `count` new holders.
+pub fn add_holders<AccountId, Assets>(
+	asset: Assets::AssetId,
+	amount: Assets::Balance,
+	count: u32,
+) -> DispatchResult
+where
+	AccountId: Decode,
+	Assets: Mutate<AccountId>,
+{
+	(0..count).try_for_each(|index| Assets::mint_into(asset, &holder(index), amount))
+}
+
+/// Approve `count` delegates to spend `amount` of `asset` on behalf of `owner`, who must be
+/// funded for the deposits.
+pub fn add_approvals<AccountId, Assets>(
+	asset: Assets::AssetId,
+	owner: &AccountId,
+	amount: Assets::Balance,
+	count: u32,
+) -> DispatchResult
+where
+	AccountId: Decode,
+	Assets: approvals::Mutate<AccountId>,
+{
+	(0..count).try_for_each(|index| Assets::approve(asset, owner, &holder(index), amount))
+}
+
+/// Set a name and a symbol of `len` bytes to `asset`, as its `owner`, who must be funded for the
+/// deposit.
+pub fn set_metadata<AccountId, Assets>(
+	asset: Assets::AssetId,
+	owner: &AccountId,
+	len: u32,
+) -> DispatchResult
+where
+	Assets: metadata::Mutate<AccountId>,
+{
+	let text = vec![b'x'; len as usize];
+	Assets::set(asset, owner, text.clone(), text, 12)
+}
@@ -0,0 +1,6 @@
+//! Setup shared by the benchmarks of the workspace pallets.
+//!
+//! The [`helpers`] fill the storage the benchmarked calls touch up to its worst-case shape, so that
+//! the weights are measured against full storage rather than an empty chain.
+
+pub mod helpers;
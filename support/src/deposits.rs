@@ -0,0 +1,70 @@
+//! Deposits reserved for the storage items an account adds, such as a claim or an application.
+//!
+//! The item records the amount reserved for it, and the deposit is moved and refunded from that
+//! record rather than from the deposit the runtime currently charges, so that an account always
+//! gets back exactly what it was charged.
+
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		TokenError, TransactionOutcome,
+	},
+	storage::with_transaction,
+	traits::{BalanceStatus, ReservableCurrency},
+};
+
+/// Reserve, move and refund the deposits of storage items in a reservable currency.
+pub trait StorageDeposits<AccountId>: ReservableCurrency<AccountId> {
+	/// Reserve the deposit `amount` of a new item from `depositor`, returning the amount reserved,
+	/// to record in the item.
+	///
+	/// Fails, reserving nothing, unless the whole `amount` is reserved.
+	fn reserve_deposit(
+		depositor: &AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Refund the deposit `amount` recorded in a removed item to its `depositor`, returning the
+	/// part of it that was no longer reserved.
+	fn refund_deposit(depositor: &AccountId, amount: Self::Balance) -> Self::Balance;
+
+	/// Move the deposit `amount` recorded in an item from its owner `from` to its new owner `to`,
+	/// still reserved.
+	///
+	/// Fails, moving nothing, unless the whole `amount` moves, so that the item never records more
+	/// than its new owner has reserved for it.
+	fn transfer_deposit(from: &AccountId, to: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Slash the deposit `amount` recorded in a removed item from its `depositor`.
+	fn slash_deposit(depositor: &AccountId, amount: Self::Balance) -> Self::NegativeImbalance;
+}
+
+impl<AccountId, C: ReservableCurrency<AccountId>> StorageDeposits<AccountId> for C {
+	fn reserve_deposit(
+		depositor: &AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		let before = C::reserved_balance(depositor);
+		C::reserve(depositor, amount)?;
+		Ok(C::reserved_balance(depositor).saturating_sub(before))
+	}
+
+	fn refund_deposit(depositor: &AccountId, amount: Self::Balance) -> Self::Balance {
+		C::unreserve(depositor, amount)
+	}
+
+	fn transfer_deposit(from: &AccountId, to: &AccountId, amount: Self::Balance) -> DispatchResult {
+		with_transaction(|| {
+			match C::repatriate_reserved(from, to, amount, BalanceStatus::Reserved) {
+				Ok(remainder) if remainder.is_zero() => TransactionOutcome::Commit(Ok(())),
+				Ok(_) => TransactionOutcome::Rollback(Err(TokenError::NoFunds.into())),
+				Err(error) => TransactionOutcome::Rollback(Err(error)),
+			}
+		})
+	}
+
+	fn slash_deposit(depositor: &AccountId, amount: Self::Balance) -> Self::NegativeImbalance {
+		C::slash_reserved(depositor, amount).0
+	}
+}
@@ -0,0 +1,20 @@
+//! Codes of the errors of the runtime that stay the same across runtime upgrades.
+//!
+//! The index of a pallet in the runtime and the index of an error in its pallet change when
+//! pallets or errors are added, removed or reordered. A registry gives each error a code and a
+//! name of its own instead, so that wallets and indexers show the same message for the same
+//! error whatever the version of the runtime. A code is never reused once it is released.
+
+use frame_support::{dispatch::DispatchError, sp_std::vec::Vec};
+
+/// A registry of the codes and names of errors.
+///
+/// The upper 16 bits of a code select the pallet, and the lower 16 bits an error of the pallet.
+/// A name is `<pallet>.<error>`, in snake case, e.g. `assets.balance_low`.
+pub trait ErrorCodes {
+	/// The code and name of `error`, `None` if the registry does not know it.
+	fn error_code(error: &DispatchError) -> Option<(u32, &'static str)>;
+
+	/// The codes and names of all the errors of the registry.
+	fn error_codes() -> Vec<(u32, &'static str)>;
+}
@@ -0,0 +1,26 @@
+//! Checks of the escrow accounts of the pallets, for their `try_state`.
+
+use frame_support::{
+	ensure, sp_runtime::traits::Saturating, sp_std::vec::Vec, traits::tokens::fungibles::Inspect,
+};
+
+/// Check that `account` holds at least the total of `amounts` in each of their assets, failing
+/// with `error` otherwise.
+pub fn ensure_escrowed<AccountId, Assets: Inspect<AccountId>>(
+	account: &AccountId,
+	amounts: impl IntoIterator<Item = (Assets::AssetId, Assets::Balance)>,
+	error: &'static str,
+) -> Result<(), &'static str> {
+	let mut totals: Vec<(Assets::AssetId, Assets::Balance)> = Vec::new();
+	for (asset_id, amount) in amounts {
+		match totals.iter_mut().find(|(id, _)| *id == asset_id) {
+			Some((_, total)) => *total = total.saturating_add(amount),
+			None => totals.push((asset_id, amount)),
+		}
+	}
+	for (asset_id, total) in totals {
+		ensure!(Assets::balance(asset_id, account) >= total, error);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,118 @@
+//! Fees charged by the pallets on trades, shared with the referrers of the accounts paying them.
+//!
+//! A pallet charging a fee calls [`charge_fee`], or [`charge_native_fee`], which first reports it
+//! through [`OnFeeCharged`], or [`OnNativeFeeCharged`], and then collects whatever is left of it.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResult,
+	sp_runtime::{traits::Zero, DispatchError},
+	traits::{tokens::fungibles, Currency, ExistenceRequirement::KeepAlive, WithdrawReasons},
+};
+use scale_info::TypeInfo;
+
+/// The operations charging fees referrers get a share of.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
+pub enum FeeKind {
+	/// A sale on a marketplace.
+	Marketplace,
+	/// A swap on a DEX.
+	Dex,
+}
+
+/// Share the fees charged to referred accounts in fungible assets.
+pub trait OnFeeCharged<AccountId, AssetId, Balance> {
+	/// Called before `payer` is charged `fee` of `asset_id` for a `kind` operation.
+	///
+	/// Pays the share of the fee owed to the payer's referrer out of the payer's account and
+	/// returns the rest, left for the caller to collect. Callers should be transactional, as the
+	/// share stays paid if they fail afterwards.
+	fn on_fee_charged(
+		kind: FeeKind,
+		payer: &AccountId,
+		asset_id: AssetId,
+		fee: Balance,
+	) -> Result<Balance, DispatchError>;
+}
+
+/// No fee is shared.
+impl<AccountId, AssetId, Balance> OnFeeCharged<AccountId, AssetId, Balance> for () {
+	fn on_fee_charged(
+		_kind: FeeKind,
+		_payer: &AccountId,
+		_asset_id: AssetId,
+		fee: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(fee)
+	}
+}
+
+/// Share the fees charged to referred accounts in the native currency.
+pub trait OnNativeFeeCharged<AccountId, Balance> {
+	/// Called before `payer` is charged `fee` of the native currency for a `kind` operation.
+	///
+	/// As [`OnFeeCharged::on_fee_charged`], for the native currency.
+	fn on_native_fee_charged(
+		kind: FeeKind,
+		payer: &AccountId,
+		fee: Balance,
+	) -> Result<Balance, DispatchError>;
+}
+
+/// No fee is shared.
+impl<AccountId, Balance> OnNativeFeeCharged<AccountId, Balance> for () {
+	fn on_native_fee_charged(
+		_kind: FeeKind,
+		_payer: &AccountId,
+		fee: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(fee)
+	}
+}
+
+/// Charge `payer` a `fee` of `asset_id` for a `kind` operation: `Shares` pays the share of its
+/// referrer, and the rest goes to `collector`.
+pub fn charge_fee<AccountId, Assets, Shares>(
+	kind: FeeKind,
+	payer: &AccountId,
+	asset_id: Assets::AssetId,
+	fee: Assets::Balance,
+	collector: &AccountId,
+) -> DispatchResult
+where
+	Assets: fungibles::Transfer<AccountId>,
+	Shares: OnFeeCharged<AccountId, Assets::AssetId, Assets::Balance>,
+{
+	if fee.is_zero() {
+		return Ok(())
+	}
+	let rest = Shares::on_fee_charged(kind, payer, asset_id, fee)?;
+	if !rest.is_zero() {
+		Assets::transfer(asset_id, payer, collector, rest, false)?;
+	}
+	Ok(())
+}
+
+/// Charge `payer` a `fee` of the native currency for a `kind` operation: `Shares` pays the share
+/// of its referrer, and the rest goes to `collector`. The payer is kept alive, and the rest is
+/// burned if it would leave `collector` under the existential deposit.
+pub fn charge_native_fee<AccountId, C, Shares>(
+	kind: FeeKind,
+	payer: &AccountId,
+	fee: C::Balance,
+	collector: &AccountId,
+) -> DispatchResult
+where
+	C: Currency<AccountId>,
+	Shares: OnNativeFeeCharged<AccountId, C::Balance>,
+{
+	if fee.is_zero() {
+		return Ok(())
+	}
+	let rest = Shares::on_native_fee_charged(kind, payer, fee)?;
+	if !rest.is_zero() {
+		let rest = C::withdraw(payer, rest, WithdrawReasons::FEE, KeepAlive)?;
+		C::resolve_creating(collector, rest);
+	}
+	Ok(())
+}
@@ -0,0 +1,85 @@
+//! Traits shared by the workspace pallets.
+//!
+//! [`Pausable`] is the circuit breaker of the runtime: a registry of paused pallets, such as the
+//! pause-registry pallet. Pallets implement [`PauseGuard`] over it, and call
+//! [`PauseGuard::ensure_not_paused`] at the top of each of their extrinsics, so that they are all
+//! paused the same way.
+//!
+//! [`Permissions`] are the roles of accounts over assets and nonfungible items, which the
+//! [`Roles`] of each pallet grant, so that they mean the same for both.
+//!
+//! The [`topics`] index the events by asset and by account.
+//!
+//! Pallets charging fees on trades [`fees::charge_fee`], reporting each fee through
+//! [`OnFeeCharged`] so that the referrer of the payer gets its share.
+//!
+//! The [`escrow`] checks verify that the accounts of the pallets hold what they escrow.
+//!
+//! The [`migrations`] shared by the pallets record their storage version on upgrade.
+//!
+//! The [`pagination`] helpers walk large storage maps a bounded page at a time.
+//!
+//! Pallets [`ScheduleUnlock`] what falls due at a block, such as the end of a lease, in a queue
+//! shared by the runtime, which calls back [`OnUnlock`] when it is due. Their migrations move what
+//! they used to track themselves into the queue with [`unlocks::schedule_or_unlock`].
+//!
+//! [`StorageDeposits`] reserve the deposits of the storage items accounts add, and move and
+//! refund them in full, from the amount recorded in the item.
+//!
+//! [`ErrorCodes`] give the errors of the runtime codes that stay the same across upgrades.
+//!
+//! With the `runtime-benchmarks` feature, the [`benchmarking`] helpers populate storage up to its
+//! worst-case shape before a benchmark measures a call.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod deposits;
+pub mod errors;
+pub mod escrow;
+pub mod fees;
+pub mod migrations;
+pub mod pagination;
+pub mod permissions;
+pub mod topics;
+pub mod unlocks;
+
+pub use deposits::StorageDeposits;
+pub use errors::ErrorCodes;
+pub use fees::{FeeKind, OnFeeCharged, OnNativeFeeCharged};
+pub use permissions::{ensure_has_permission, Permissions, Roles};
+pub use unlocks::{OnUnlock, ScheduleUnlock};
+
+use frame_support::{dispatch::DispatchResult, traits::PalletInfoAccess};
+
+/// A registry of paused pallets.
+pub trait Pausable {
+	/// Whether the calls of the pallet named `pallet` are paused.
+	fn is_paused(pallet: &[u8]) -> bool;
+
+	/// Fail if the calls of the pallet named `pallet` are paused.
+	fn ensure_not_paused(pallet: &[u8]) -> DispatchResult;
+}
+
+/// No pallet is ever paused.
+impl Pausable for () {
+	fn is_paused(_pallet: &[u8]) -> bool {
+		false
+	}
+
+	fn ensure_not_paused(_pallet: &[u8]) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// A pallet whose calls are paused by the registry `Pauses`, under the name of the pallet in the
+/// runtime.
+pub trait PauseGuard: PalletInfoAccess {
+	type Pauses: Pausable;
+
+	/// Fail if the calls of the pallet are paused.
+	fn ensure_not_paused() -> DispatchResult {
+		Self::Pauses::ensure_not_paused(Self::name().as_bytes())
+	}
+}
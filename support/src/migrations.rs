@@ -0,0 +1,115 @@
+//! Storage migrations shared by the pallets, for their `on_runtime_upgrade`.
+
+use frame_support::{
+	traits::{GetStorageVersion, PalletInfoAccess},
+	weights::{RuntimeDbWeight, Weight},
+};
+
+/// Record the current storage version of the pallet `P` on the chains started before it had one.
+///
+/// Only for the pallets whose storage items never changed: it migrates nothing, so the pallets
+/// with a real migration run their own instead.
+pub fn init_storage_version<P: GetStorageVersion + PalletInfoAccess>(
+	db_weight: RuntimeDbWeight,
+) -> Weight {
+	let current = P::current_storage_version();
+	if P::on_chain_storage_version() < current {
+		current.put::<P>();
+		db_weight.reads_writes(1, 1)
+	} else {
+		db_weight.reads(1)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::H256;
+	use sp_runtime::{
+		testing::Header,
+		traits::{BlakeTwo256, IdentityLookup},
+	};
+
+	const DB_WEIGHT: RuntimeDbWeight = RuntimeDbWeight {
+		read: 1,
+		write: 100,
+	};
+
+	/// A pallet at storage version 2.
+	#[frame_support::pallet]
+	pub mod pallet {
+		use frame_support::pallet_prelude::*;
+		use frame_system::pallet_prelude::*;
+
+		const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+		#[pallet::config]
+		pub trait Config: frame_system::Config {}
+
+		#[pallet::pallet]
+		#[pallet::storage_version(STORAGE_VERSION)]
+		pub struct Pallet<T>(_);
+
+		#[pallet::hooks]
+		impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+		#[pallet::call]
+		impl<T: Config> Pallet<T> {}
+	}
+
+	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+	type Block = frame_system::mocking::MockBlock<Test>;
+
+	frame_support::construct_runtime!(
+		pub enum Test where
+			Block = Block,
+			NodeBlock = Block,
+			UncheckedExtrinsic = UncheckedExtrinsic,
+		{
+			System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+			Versioned: pallet::{Pallet},
+		}
+	);
+
+	impl frame_system::Config for Test {
+		type AccountData = ();
+		type AccountId = u64;
+		type BaseCallFilter = frame_support::traits::Everything;
+		type BlockHashCount = ();
+		type BlockLength = ();
+		type BlockNumber = u64;
+		type BlockWeights = ();
+		type Call = Call;
+		type DbWeight = ();
+		type Event = Event;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type Header = Header;
+		type Index = u64;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type OnKilledAccount = ();
+		type OnNewAccount = ();
+		type OnSetCode = ();
+		type Origin = Origin;
+		type PalletInfo = PalletInfo;
+		type SS58Prefix = ();
+		type SystemWeightInfo = ();
+		type Version = ();
+	}
+
+	impl pallet::Config for Test {}
+
+	#[test]
+	fn init_storage_version_records_the_current_version_once() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_eq!(Versioned::on_chain_storage_version(), 0);
+
+			assert_eq!(init_storage_version::<Versioned>(DB_WEIGHT), DB_WEIGHT.reads_writes(1, 1));
+			assert_eq!(Versioned::on_chain_storage_version(), 2);
+
+			// Once recorded, the version is only read.
+			assert_eq!(init_storage_version::<Versioned>(DB_WEIGHT), DB_WEIGHT.reads(1));
+			assert_eq!(Versioned::on_chain_storage_version(), 2);
+		})
+	}
+}
@@ -0,0 +1,84 @@
+//! Walk storage maps a page at a time, so that runtime APIs and cleanups read a bounded number of
+//! entries however large the map grows.
+//!
+//! A page resumes from the raw storage key the previous page stopped at, so the walk goes on in
+//! storage order across calls, or across blocks. The pages of a map can also resume after the key
+//! of its last entry, for the clients paging by id.
+
+use frame_support::{
+	codec::{Decode, Encode},
+	sp_io,
+	sp_std::prelude::*,
+	storage::unhashed,
+	ReversibleStorageHasher,
+};
+
+/// The entries of a page, and where the next page starts.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Page<T> {
+	/// The entries of the page, in storage order.
+	pub items: Vec<T>,
+	/// The number of entries read, including the ones that failed to decode.
+	pub read: u32,
+	/// The raw storage key of the last entry read, to start the next page after. `None` once the
+	/// whole map has been read.
+	pub next_key: Option<Vec<u8>>,
+}
+
+/// Read up to `limit` entries stored under `prefix`, after the raw storage key `start_key`, or
+/// from the first entry.
+///
+/// `decode` gets the key of each entry without `prefix`, and its value. Entries it fails to decode
+/// are skipped, but still count towards `limit`, so that the reads stay bounded.
+pub fn iter_page<T>(
+	prefix: &[u8],
+	start_key: Option<Vec<u8>>,
+	limit: u32,
+	decode: impl Fn(&[u8], &[u8]) -> Option<T>,
+) -> Page<T> {
+	let mut key = start_key.unwrap_or_else(|| prefix.to_vec());
+	let mut items = Vec::new();
+
+	for read in 0..limit {
+		let next = match sp_io::storage::next_key(&key) {
+			Some(next) if next.starts_with(prefix) => next,
+			_ => return Page { items, read, next_key: None },
+		};
+		if let Some(item) =
+			unhashed::get_raw(&next).and_then(|value| decode(&next[prefix.len()..], &value))
+		{
+			items.push(item);
+		}
+		key = next;
+	}
+
+	Page { items, read: limit, next_key: Some(key) }
+}
+
+/// Decode a key hashed with `H` from the front of `key`, leaving the rest of it in `key`.
+pub fn decode_key<H: ReversibleStorageHasher, K: Decode>(key: &mut &[u8]) -> Option<K> {
+	*key = H::reverse(key);
+	K::decode(key).ok()
+}
+
+/// Read up to `limit` entries of the map stored under `prefix`, with its keys hashed with `H`,
+/// after the entry of `start`, or from the first entry.
+///
+/// Entries which fail to decode are skipped, as in [`iter_page`].
+pub fn map_page<H: ReversibleStorageHasher, K: Encode + Decode, V: Decode>(
+	prefix: &[u8],
+	start: Option<K>,
+	limit: u32,
+) -> Vec<(K, V)> {
+	let start_key = start.map(|start| {
+		let mut key = prefix.to_vec();
+		key.extend(H::hash(&start.encode()).as_ref());
+		key
+	});
+	iter_page(prefix, start_key, limit, |mut key, value| {
+		let id = decode_key::<H, K>(&mut key)?;
+		let value = V::decode(&mut &value[..]).ok()?;
+		Some((id, value))
+	})
+	.items
+}
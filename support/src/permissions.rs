@@ -0,0 +1,46 @@
+//! The roles of accounts over assets and nonfungible items.
+
+use frame_support::dispatch::{DispatchError, DispatchResult};
+
+bitflags::bitflags! {
+	/// The roles an account holds over an asset, or a nonfungible item.
+	pub struct Permissions: u8 {
+		/// Owns the asset, or the item, and transfers its ownership.
+		const OWNER = 0b0000_0001;
+		/// Mints the asset, or the items of the class.
+		const ISSUER = 0b0000_0010;
+		/// Burns from any account, and forces transfers.
+		const ADMIN = 0b0000_0100;
+		/// Freezes and thaws the accounts, or the items.
+		const FREEZER = 0b0000_1000;
+		/// Transfers the item on behalf of its owner.
+		const DELEGATE = 0b0001_0000;
+	}
+}
+
+/// The permissions of accounts over the assets, or items, `Target` of a pallet.
+pub trait Roles<AccountId> {
+	/// The id of an asset, or of an item.
+	type Target;
+
+	/// The permissions of `who` over `target`, empty if it does not exist.
+	fn permissions(target: &Self::Target, who: &AccountId) -> Permissions;
+
+	/// Fail unless `who` holds one of the `allowed` roles over `target`.
+	fn ensure_has_permission(
+		target: &Self::Target,
+		who: &AccountId,
+		allowed: Permissions,
+	) -> DispatchResult {
+		ensure_has_permission(Self::permissions(target, who), allowed)
+	}
+}
+
+/// Fail with `BadOrigin` unless the `granted` permissions hold one of the `allowed` roles.
+pub fn ensure_has_permission(granted: Permissions, allowed: Permissions) -> DispatchResult {
+	if granted.intersects(allowed) {
+		Ok(())
+	} else {
+		Err(DispatchError::BadOrigin)
+	}
+}
@@ -0,0 +1,17 @@
+//! Topics indexing the events of the pallets, so that clients can look up the events about an
+//! asset, or an account, with `System::EventTopics` instead of scanning every block's events.
+//!
+//! A topic is the hash of a tag and of the encoded asset id, or account, so that an asset and an
+//! account encoding the same way do not share their topic.
+
+use frame_support::{codec::Encode, sp_runtime::traits::Hash};
+
+/// The topic of the events about the asset `asset_id`.
+pub fn asset_topic<Hashing: Hash, AssetId: Encode>(asset_id: &AssetId) -> Hashing::Output {
+	Hashing::hash_of(&(b"asset", asset_id))
+}
+
+/// The topic of the events about the account `who`.
+pub fn account_topic<Hashing: Hash, AccountId: Encode>(who: &AccountId) -> Hashing::Output {
+	Hashing::hash_of(&(b"account", who))
+}
@@ -0,0 +1,146 @@
+//! Unlocks scheduled at a block, such as the end of a lease.
+
+use frame_support::{
+	dispatch::DispatchResult,
+	weights::{RuntimeDbWeight, Weight},
+};
+
+/// A queue of unlocks, each identified by a key and scheduled at a block.
+pub trait ScheduleUnlock<BlockNumber, Key> {
+	/// Schedule the unlock of `key` at the block `at`, which must be in the future.
+	fn schedule(at: BlockNumber, key: Key) -> DispatchResult;
+
+	/// Cancel the unlock of `key` scheduled at the block `at`, if any.
+	fn cancel(at: BlockNumber, key: Key);
+}
+
+/// What to do when an unlock is due.
+pub trait OnUnlock<Key> {
+	/// The most weight the unlock of `key` may consume.
+	fn unlock_weight(key: &Key) -> Weight;
+
+	/// Unlock `key`, returning the weight consumed.
+	fn on_unlock(key: Key) -> Weight;
+}
+
+/// Unlocks do nothing.
+impl<Key> OnUnlock<Key> for () {
+	fn unlock_weight(_key: &Key) -> Weight {
+		0
+	}
+
+	fn on_unlock(_key: Key) -> Weight {
+		0
+	}
+}
+
+/// Schedule each of `unlocks` in `Unlocks` at its block, for the migrations moving what the
+/// pallets used to track themselves into the queue.
+///
+/// An unlock that can no longer be scheduled, as its block is past or full, goes to `On` right
+/// away rather than never. Returns the weight of the scheduling and of those unlocks.
+pub fn schedule_or_unlock<BlockNumber, Key, Unlocks, On>(
+	unlocks: impl IntoIterator<Item = (BlockNumber, Key)>,
+	db_weight: RuntimeDbWeight,
+) -> Weight
+where
+	Key: Clone,
+	Unlocks: ScheduleUnlock<BlockNumber, Key>,
+	On: OnUnlock<Key>,
+{
+	let mut weight: Weight = 0;
+	for (at, key) in unlocks {
+		if Unlocks::schedule(at, key.clone()).is_err() {
+			weight = weight.saturating_add(On::on_unlock(key));
+		}
+		weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+	}
+	weight
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::sp_runtime::DispatchError;
+	use std::cell::RefCell;
+
+	const NOW: u64 = 10;
+	const MAX_PER_BLOCK: usize = 2;
+	const UNLOCK_WEIGHT: Weight = 1_000;
+	const DB_WEIGHT: RuntimeDbWeight = RuntimeDbWeight {
+		read: 1,
+		write: 100,
+	};
+
+	thread_local! {
+		static QUEUE: RefCell<Vec<(u64, u32)>> = RefCell::new(Vec::new());
+		static UNLOCKED: RefCell<Vec<u32>> = RefCell::new(Vec::new());
+	}
+
+	/// A queue of at most `MAX_PER_BLOCK` unlocks per block, after `NOW`.
+	struct Queue;
+
+	impl ScheduleUnlock<u64, u32> for Queue {
+		fn schedule(at: u64, key: u32) -> DispatchResult {
+			QUEUE.with(|queue| {
+				let mut queue = queue.borrow_mut();
+				let in_block = queue.iter().filter(|(queued_at, _)| *queued_at == at).count();
+				if at <= NOW || in_block >= MAX_PER_BLOCK {
+					return Err(DispatchError::Other("cannot schedule"))
+				}
+				queue.push((at, key));
+				Ok(())
+			})
+		}
+
+		fn cancel(at: u64, key: u32) {
+			QUEUE.with(|queue| queue.borrow_mut().retain(|queued| *queued != (at, key)));
+		}
+	}
+
+	/// Record the keys unlocked.
+	struct Recorder;
+
+	impl OnUnlock<u32> for Recorder {
+		fn unlock_weight(_key: &u32) -> Weight {
+			UNLOCK_WEIGHT
+		}
+
+		fn on_unlock(key: u32) -> Weight {
+			UNLOCKED.with(|unlocked| unlocked.borrow_mut().push(key));
+			UNLOCK_WEIGHT
+		}
+	}
+
+	#[test]
+	fn schedule_or_unlock_schedules_what_fits_and_unlocks_the_rest() {
+		let unlocks = vec![
+			(NOW + 1, 0),
+			(NOW, 1),
+			(NOW + 1, 2),
+			(NOW + 1, 3),
+			(NOW + 5, 4),
+		];
+
+		let weight = schedule_or_unlock::<_, _, Queue, Recorder>(unlocks, DB_WEIGHT);
+
+		// The unlock past and the one over the bound of its block run right away.
+		assert_eq!(
+			QUEUE.with(|queue| queue.borrow().clone()),
+			vec![(11, 0), (11, 2), (15, 4)]
+		);
+		assert_eq!(
+			UNLOCKED.with(|unlocked| unlocked.borrow().clone()),
+			vec![1, 3]
+		);
+		assert_eq!(weight, 5 * DB_WEIGHT.reads_writes(1, 1) + 2 * UNLOCK_WEIGHT);
+	}
+
+	#[test]
+	fn schedule_or_unlock_does_nothing_without_unlocks() {
+		let weight = schedule_or_unlock::<u64, u32, Queue, Recorder>(Vec::new(), DB_WEIGHT);
+
+		assert_eq!(weight, 0);
+		assert!(QUEUE.with(|queue| queue.borrow().is_empty()));
+	}
+}
@@ -0,0 +1,176 @@
+//! Helpers shared by the tests of the workspace pallets.
+//!
+//! [`ExtBuilder`] builds the test externalities of any mock runtime: funded accounts, pallet
+//! genesis configs, pre-created assets and nonfungible items. [`run_to_block`] advances blocks,
+//! running the hooks of the given pallets. [`assert_events!`] matches the events deposited since
+//! the previous assertion against patterns.
+
+use frame_support::traits::{
+	tokens::{fungibles::Mutate as _, nonfungibles::Mutate as _},
+	GenesisBuild, OnFinalize, OnInitialize,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{One, StaticLookup},
+	Storage,
+};
+use std::marker::PhantomData;
+
+/// Build the test externalities of the mock runtime `T`.
+///
+/// The block number is set to 1, then the setup steps run in the order they were added.
+pub struct ExtBuilder<T: frame_system::Config> {
+	storage: Storage,
+	setup: Vec<Box<dyn FnOnce()>>,
+	_runtime: PhantomData<T>,
+}
+
+impl<T: frame_system::Config> Default for ExtBuilder<T> {
+	fn default() -> Self {
+		Self {
+			storage: frame_system::GenesisConfig::default().build_storage::<T>().unwrap(),
+			setup: Vec::new(),
+			_runtime: Default::default(),
+		}
+	}
+}
+
+impl<T: frame_system::Config> ExtBuilder<T> {
+	/// Add the genesis config of a pallet.
+	pub fn genesis<I>(mut self, config: impl GenesisBuild<T, I>) -> Self {
+		config.assimilate_storage(&mut self.storage).unwrap();
+		self
+	}
+
+	/// Fund accounts with the native currency.
+	pub fn balances(self, balances: Vec<(T::AccountId, T::Balance)>) -> Self
+	where
+		T: pallet_balances::Config,
+	{
+		self.genesis(pallet_balances::GenesisConfig::<T> { balances })
+	}
+
+	/// Create a sufficient asset with a minimum balance of 1, and mint it to `holders`.
+	pub fn asset(
+		self,
+		asset_id: T::AssetId,
+		owner: T::AccountId,
+		holders: Vec<(T::AccountId, <T as pallet_assets::Config>::Balance)>,
+	) -> Self
+	where
+		T: pallet_assets::Config,
+	{
+		self.with(move || {
+			pallet_assets::Pallet::<T>::force_create(
+				RawOrigin::Root.into(),
+				asset_id,
+				T::Lookup::unlookup(owner),
+				true,
+				One::one(),
+			)
+			.unwrap();
+			for (who, amount) in holders {
+				pallet_assets::Pallet::<T>::mint_into(asset_id, &who, amount).unwrap();
+			}
+		})
+	}
+
+	/// Create a class of nonfungible items, free of deposits.
+	pub fn class(self, class: T::ClassId, owner: T::AccountId) -> Self
+	where
+		T: pallet_uniques::Config,
+	{
+		self.with(move || {
+			pallet_uniques::Pallet::<T>::force_create(
+				RawOrigin::Root.into(),
+				class,
+				T::Lookup::unlookup(owner),
+				true,
+			)
+			.unwrap();
+		})
+	}
+
+	/// Mint a nonfungible item of an existing class to `owner`.
+	pub fn item(self, class: T::ClassId, instance: T::InstanceId, owner: T::AccountId) -> Self
+	where
+		T: pallet_uniques::Config,
+	{
+		self.with(move || {
+			pallet_uniques::Pallet::<T>::mint_into(&class, &instance, &owner).unwrap();
+		})
+	}
+
+	/// Add a setup step, run in the externalities once built.
+	pub fn with(mut self, step: impl FnOnce() + 'static) -> Self {
+		self.setup.push(Box::new(step));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let setup = self.setup;
+		let mut ext: sp_io::TestExternalities = self.storage.into();
+		ext.execute_with(|| {
+			frame_system::Pallet::<T>::set_block_number(One::one());
+			for step in setup {
+				step();
+			}
+		});
+		ext
+	}
+}
+
+/// Advance blocks up to `n`, running the hooks of `Pallets`, e.g. a single pallet or a tuple.
+pub fn run_to_block<T, Pallets>(n: T::BlockNumber)
+where
+	T: frame_system::Config,
+	Pallets: OnInitialize<T::BlockNumber> + OnFinalize<T::BlockNumber>,
+{
+	while frame_system::Pallet::<T>::block_number() < n {
+		let now = frame_system::Pallet::<T>::block_number();
+		Pallets::on_finalize(now);
+		frame_system::Pallet::<T>::set_block_number(now + One::one());
+		Pallets::on_initialize(now + One::one());
+	}
+}
+
+/// The events deposited since the previous call, which are cleared.
+pub fn take_events<T: frame_system::Config>() -> Vec<<T as frame_system::Config>::Event> {
+	let events = frame_system::Pallet::<T>::events();
+	frame_system::Pallet::<T>::reset_events();
+	events.into_iter().map(|record| record.event).collect()
+}
+
+/// Assert that the events deposited in the mock runtime `$runtime` since the previous assertion
+/// match the patterns, one for one and in order, then clear them.
+///
+/// ```ignore
+/// assert_events!(Test, [
+/// 	Event::Assets(pallet_assets::Event::Transferred { to: BOB, amount: 5, .. }),
+/// 	Event::Uniques(pallet_uniques::Event::Issued { .. }),
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! assert_events {
+	($runtime:ty, [$($pattern:pat),* $(,)?]) => {{
+		let events = $crate::take_events::<$runtime>();
+		let patterns: &[&str] = &[$(stringify!($pattern)),*];
+		assert_eq!(
+			events.len(),
+			patterns.len(),
+			"expected events matching {:#?}, got {:#?}",
+			patterns,
+			events,
+		);
+		let mut events = events.into_iter();
+		$(
+			let event = events.next().unwrap();
+			assert!(
+				matches!(event, $pattern),
+				"expected an event matching `{}`, got {:?}",
+				stringify!($pattern),
+				event,
+			);
+		)*
+	}};
+}
@@ -0,0 +1,98 @@
+//! Cross-chain transfers of assets and nonfungible items, between mock parachains run in
+//! xcm-simulator.
+//!
+//! Two sibling parachains, [`ParaA`] and [`ParaB`], hold assets in their assets pallet, and items
+//! in their uniques pallet, and reserve-transfer them to each other, following the XCM
+//! configuration of [`xcm_config`]. The mock relay chain only routes their messages.
+
+pub mod parachain;
+pub mod relay_chain;
+pub mod xcm_config;
+
+#[cfg(test)]
+mod tests;
+
+use parachain::{AccountId, AssetId, Balance, ClassId, InstanceId};
+use polkadot_parachain::primitives::{Id as ParaId, Sibling};
+use sp_runtime::traits::AccountIdConversion;
+use test_utils::ExtBuilder;
+use xcm_config::derivative_id;
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+
+pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const BOB: AccountId = AccountId::new([2; 32]);
+
+pub const PARA_A: u32 = 1;
+pub const PARA_B: u32 = 2;
+
+/// The asset each parachain creates, Alice holding all of it.
+pub const ASSET_ID: AssetId = 1;
+
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+
+/// The class each parachain creates, with a single item owned by Alice.
+pub const CLASS_ID: ClassId = 1;
+pub const INSTANCE_ID: InstanceId = 7;
+
+decl_test_parachain! {
+	pub struct ParaA {
+		Runtime = parachain::Runtime,
+		XcmpMessageHandler = parachain::MsgQueue,
+		DmpMessageHandler = parachain::MsgQueue,
+		new_ext = para_ext(PARA_A),
+	}
+}
+
+decl_test_parachain! {
+	pub struct ParaB {
+		Runtime = parachain::Runtime,
+		XcmpMessageHandler = parachain::MsgQueue,
+		DmpMessageHandler = parachain::MsgQueue,
+		new_ext = para_ext(PARA_B),
+	}
+}
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay_chain::Runtime,
+		XcmConfig = relay_chain::XcmConfig,
+		new_ext = relay_ext(),
+	}
+}
+
+decl_test_network! {
+	pub struct MockNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(1, ParaA),
+			(2, ParaB),
+		],
+	}
+}
+
+/// The account of the sibling `para_id` on the parachains.
+pub fn sibling_account(para_id: u32) -> AccountId {
+	Sibling::from(para_id).into_account()
+}
+
+/// Build the externalities of the parachain `para_id`: its asset and class, held by Alice, and
+/// the derivatives of its sibling's.
+pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
+	let sibling = if para_id == PARA_A { PARA_B } else { PARA_A };
+
+	ExtBuilder::<parachain::Runtime>::default()
+		.balances(vec![(ALICE, INITIAL_BALANCE)])
+		.asset(ASSET_ID, ALICE, vec![(ALICE, INITIAL_BALANCE)])
+		.asset(derivative_id(sibling, ASSET_ID), ALICE, vec![])
+		.class(CLASS_ID, ALICE)
+		.item(CLASS_ID, INSTANCE_ID, ALICE)
+		.class(derivative_id(sibling, CLASS_ID), ALICE)
+		.with(move || parachain::MsgQueue::set_para_id(ParaId::from(para_id)))
+		.build()
+}
+
+pub fn relay_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<relay_chain::Runtime>::default()
+		.balances(vec![(ALICE, INITIAL_BALANCE)])
+		.build()
+}
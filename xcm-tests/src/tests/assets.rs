@@ -0,0 +1,132 @@
+use crate::{
+	parachain::{self, Assets, PolkadotXcm, MAX_WEIGHT},
+	sibling_account,
+	xcm_config::{derivative_id, AssetLocation, ASSETS_PALLET_INDEX},
+	MockNet, ParaA, ParaB, ALICE, ASSET_ID, BOB, INITIAL_BALANCE, PARA_A, PARA_B,
+};
+use frame_support::{assert_ok, traits::Get};
+use polkadot_parachain::primitives::Id as ParaId;
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_executor::traits::Convert;
+use xcm_simulator::TestExt;
+
+const AMOUNT: u128 = 1_000;
+
+fn beneficiary(who: &parachain::AccountId) -> MultiLocation {
+	AccountId32 { network: Any, id: who.clone().into() }.into()
+}
+
+/// The asset of `para_id`, located from a sibling.
+fn sibling_asset(para_id: u32, amount: u128) -> MultiAsset {
+	let location = MultiLocation::new(
+		1,
+		X3(Parachain(para_id), PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(ASSET_ID.into())),
+	);
+	(location, amount).into()
+}
+
+/// Reserve-transfer `AMOUNT` of the asset of parachain A from Alice, to Bob on parachain B.
+fn transfer_to_para_b() {
+	ParaA::execute_with(|| {
+		let location = X2(PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(ASSET_ID.into()));
+		let asset: MultiAsset = (location, AMOUNT).into();
+		assert_ok!(PolkadotXcm::reserve_transfer_assets(
+			parachain::Origin::signed(ALICE),
+			Box::new(MultiLocation::new(1, X1(Parachain(PARA_B))).into()),
+			Box::new(beneficiary(&BOB).into()),
+			Box::new(MultiAssets::from(vec![asset]).into()),
+			0,
+		));
+	});
+}
+
+struct ParaAId;
+impl Get<ParaId> for ParaAId {
+	fn get() -> ParaId {
+		PARA_A.into()
+	}
+}
+
+#[test]
+fn asset_locations_convert_to_asset_ids() {
+	type Location = AssetLocation<ParaAId, ASSETS_PALLET_INDEX>;
+
+	let native = MultiLocation::new(0, X2(PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(1)));
+	assert_eq!(Location::convert(native.clone()), Ok(1));
+	assert_eq!(Location::reverse(1), Ok(native));
+
+	// Our own asset, located from a sibling, is still native.
+	let relative = MultiLocation::new(
+		1,
+		X3(Parachain(PARA_A), PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(1)),
+	);
+	assert_eq!(Location::convert(relative), Ok(1));
+
+	let foreign = MultiLocation::new(
+		1,
+		X3(Parachain(PARA_B), PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(1)),
+	);
+	assert_eq!(Location::convert(foreign.clone()), Ok(derivative_id(PARA_B, 1)));
+	assert_eq!(Location::reverse(derivative_id(PARA_B, 1)), Ok(foreign));
+
+	let unknown = MultiLocation::new(0, X2(PalletInstance(0), GeneralIndex(1)));
+	assert_eq!(Location::convert(unknown.clone()), Err(unknown));
+	let too_large = MultiLocation::new(
+		0,
+		X2(PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(1 << 16)),
+	);
+	assert_eq!(Location::convert(too_large.clone()), Err(too_large));
+}
+
+#[test]
+fn reserve_transfer_to_sibling() {
+	MockNet::reset();
+
+	transfer_to_para_b();
+
+	ParaA::execute_with(|| {
+		assert_eq!(Assets::balance(ASSET_ID, &ALICE), INITIAL_BALANCE - AMOUNT);
+		// The reserve holds the transferred assets in the sovereign account of the sibling.
+		assert_eq!(Assets::balance(ASSET_ID, &sibling_account(PARA_B)), AMOUNT);
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(Assets::balance(derivative_id(PARA_A, ASSET_ID), &BOB), AMOUNT);
+		assert_eq!(Assets::total_supply(derivative_id(PARA_A, ASSET_ID)), AMOUNT);
+	});
+}
+
+#[test]
+fn reserve_withdraw_back_to_the_reserve() {
+	MockNet::reset();
+
+	transfer_to_para_b();
+
+	ParaB::execute_with(|| {
+		let message = Xcm(vec![
+			WithdrawAsset(sibling_asset(PARA_A, AMOUNT / 2).into()),
+			InitiateReserveWithdraw {
+				assets: All.into(),
+				reserve: MultiLocation::new(1, X1(Parachain(PARA_A))),
+				xcm: Xcm(vec![DepositAsset {
+					assets: All.into(),
+					max_assets: 1,
+					beneficiary: beneficiary(&ALICE),
+				}]),
+			},
+		]);
+		assert_ok!(PolkadotXcm::execute(
+			parachain::Origin::signed(BOB),
+			Box::new(VersionedXcm::V2(message)),
+			MAX_WEIGHT,
+		));
+
+		assert_eq!(Assets::balance(derivative_id(PARA_A, ASSET_ID), &BOB), AMOUNT / 2);
+		assert_eq!(Assets::total_supply(derivative_id(PARA_A, ASSET_ID)), AMOUNT / 2);
+	});
+
+	ParaA::execute_with(|| {
+		assert_eq!(Assets::balance(ASSET_ID, &ALICE), INITIAL_BALANCE - AMOUNT / 2);
+		assert_eq!(Assets::balance(ASSET_ID, &sibling_account(PARA_B)), AMOUNT / 2);
+	});
+}
@@ -0,0 +1,2 @@
+mod assets;
+mod nfts;
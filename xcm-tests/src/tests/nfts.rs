@@ -0,0 +1,98 @@
+use crate::{
+	parachain::{self, PolkadotXcm, Uniques, MAX_WEIGHT},
+	sibling_account,
+	xcm_config::{derivative_id, InstanceIndex, UNIQUES_PALLET_INDEX},
+	MockNet, ParaA, ParaB, ALICE, BOB, CLASS_ID, INSTANCE_ID, PARA_A, PARA_B,
+};
+use frame_support::{assert_ok, traits::tokens::nonfungibles::Inspect};
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_executor::traits::Convert;
+use xcm_simulator::TestExt;
+
+fn beneficiary(who: &parachain::AccountId) -> MultiLocation {
+	AccountId32 { network: Any, id: who.clone().into() }.into()
+}
+
+/// The item of the class of `para_id`, located from `parents` up.
+fn item(parents: u8, para_id: Option<u32>) -> MultiAsset {
+	let class = (PalletInstance(UNIQUES_PALLET_INDEX), GeneralIndex(CLASS_ID.into()));
+	let location = match para_id {
+		Some(para_id) => MultiLocation::new(parents, X3(Parachain(para_id), class.0, class.1)),
+		None => MultiLocation::new(parents, X2(class.0, class.1)),
+	};
+	(location, Index(INSTANCE_ID.into())).into()
+}
+
+/// Reserve-transfer the item of parachain A from Alice, to Bob on parachain B.
+fn transfer_to_para_b() {
+	ParaA::execute_with(|| {
+		assert_ok!(PolkadotXcm::reserve_transfer_assets(
+			parachain::Origin::signed(ALICE),
+			Box::new(MultiLocation::new(1, X1(Parachain(PARA_B))).into()),
+			Box::new(beneficiary(&BOB).into()),
+			Box::new(MultiAssets::from(vec![item(0, None)]).into()),
+			0,
+		));
+	});
+}
+
+#[test]
+fn instance_indexes_convert_to_instance_ids() {
+	assert_eq!(InstanceIndex::convert(Index(7)), Ok(7));
+	assert_eq!(InstanceIndex::reverse(7), Ok(Index(7)));
+	assert_eq!(InstanceIndex::convert(Index(1 << 32)), Err(Index(1 << 32)));
+	assert_eq!(InstanceIndex::convert(Array4([0; 4])), Err(Array4([0; 4])));
+}
+
+#[test]
+fn reserve_transfer_to_sibling() {
+	MockNet::reset();
+
+	transfer_to_para_b();
+
+	ParaA::execute_with(|| {
+		// The reserve holds the transferred item in the sovereign account of the sibling.
+		assert_eq!(Uniques::owner(&CLASS_ID, &INSTANCE_ID), Some(sibling_account(PARA_B)));
+	});
+
+	ParaB::execute_with(|| {
+		// The derivative is minted on the destination, with the same instance id.
+		assert_eq!(Uniques::owner(&derivative_id(PARA_A, CLASS_ID), &INSTANCE_ID), Some(BOB));
+		// Parachain B's own item is left alone.
+		assert_eq!(Uniques::owner(&CLASS_ID, &INSTANCE_ID), Some(ALICE));
+	});
+}
+
+#[test]
+fn reserve_withdraw_back_to_the_reserve() {
+	MockNet::reset();
+
+	transfer_to_para_b();
+
+	ParaB::execute_with(|| {
+		let message = Xcm(vec![
+			WithdrawAsset(item(1, Some(PARA_A)).into()),
+			InitiateReserveWithdraw {
+				assets: All.into(),
+				reserve: MultiLocation::new(1, X1(Parachain(PARA_A))),
+				xcm: Xcm(vec![DepositAsset {
+					assets: All.into(),
+					max_assets: 1,
+					beneficiary: beneficiary(&ALICE),
+				}]),
+			},
+		]);
+		assert_ok!(PolkadotXcm::execute(
+			parachain::Origin::signed(BOB),
+			Box::new(VersionedXcm::V2(message)),
+			MAX_WEIGHT,
+		));
+
+		// The derivative is burnt.
+		assert_eq!(Uniques::owner(&derivative_id(PARA_A, CLASS_ID), &INSTANCE_ID), None);
+	});
+
+	ParaA::execute_with(|| {
+		assert_eq!(Uniques::owner(&CLASS_ID, &INSTANCE_ID), Some(ALICE));
+	});
+}
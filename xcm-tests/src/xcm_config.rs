@@ -0,0 +1,125 @@
+//! The XCM configuration of the mock parachains: where their assets are located, and how they are
+//! transacted.
+//!
+//! A parachain holds its own assets in its assets pallet, located at
+//! `PalletInstance(ASSETS_PALLET_INDEX)/GeneralIndex(asset_id)`, and its nonfungible items in its
+//! uniques pallet, located at `PalletInstance(UNIQUES_PALLET_INDEX)/GeneralIndex(class)` and
+//! identified by `Index(instance)`. The assets and items a sibling sends it are held in the same
+//! pallets, as derivatives whose asset id, or class, packs the sibling's para id with the original
+//! one, see [`AssetLocation`]: the derivative asset, or class, must be created before it is
+//! received.
+
+use frame_support::{traits::Get, weights::Weight};
+use polkadot_parachain::primitives::Id as ParaId;
+use sp_std::marker::PhantomData;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{Convert, FilterAssetLocation, WeightTrader};
+
+/// The index of the assets pallet in the mock parachains.
+pub const ASSETS_PALLET_INDEX: u8 = 2;
+
+/// The index of the uniques pallet in the mock parachains.
+pub const UNIQUES_PALLET_INDEX: u8 = 5;
+
+/// Native asset ids, and classes, are under `1 << DERIVATIVE_SHIFT`, the ones of derivatives are
+/// `para_id << DERIVATIVE_SHIFT | id`.
+pub const DERIVATIVE_SHIFT: u32 = 16;
+
+/// The id of the derivative of the asset, or class, `id` of the sibling `para_id`.
+pub fn derivative_id(para_id: u32, id: u32) -> u32 {
+	para_id << DERIVATIVE_SHIFT | id
+}
+
+/// Convert between the location of an asset, or class, of the pallet at `PALLET_INDEX` and its
+/// id, in the parachain `SelfParaId`.
+pub struct AssetLocation<SelfParaId, const PALLET_INDEX: u8>(PhantomData<SelfParaId>);
+
+impl<SelfParaId: Get<ParaId>, const PALLET_INDEX: u8> AssetLocation<SelfParaId, PALLET_INDEX> {
+	fn native_id(index: u128) -> Option<u32> {
+		u32::try_from(index).ok().filter(|id| *id < 1 << DERIVATIVE_SHIFT)
+	}
+
+	fn id(location: &MultiLocation) -> Option<u32> {
+		match (location.parents, &location.interior) {
+			(0, X2(PalletInstance(pallet_index), GeneralIndex(index)))
+				if *pallet_index == PALLET_INDEX =>
+				Self::native_id(*index),
+			(1, X3(Parachain(para_id), PalletInstance(pallet_index), GeneralIndex(index)))
+				if *pallet_index == PALLET_INDEX =>
+			{
+				let id = Self::native_id(*index)?;
+				if *para_id == u32::from(SelfParaId::get()) {
+					// One of our assets, located from a sibling.
+					Some(id)
+				} else if *para_id > 0 && *para_id < 1 << (32 - DERIVATIVE_SHIFT) {
+					Some(derivative_id(*para_id, id))
+				} else {
+					None
+				}
+			},
+			_ => None,
+		}
+	}
+}
+
+impl<SelfParaId: Get<ParaId>, const PALLET_INDEX: u8> Convert<MultiLocation, u32>
+	for AssetLocation<SelfParaId, PALLET_INDEX>
+{
+	fn convert(location: MultiLocation) -> Result<u32, MultiLocation> {
+		Self::id(&location).ok_or(location)
+	}
+
+	fn reverse(id: u32) -> Result<MultiLocation, u32> {
+		let para_id = id >> DERIVATIVE_SHIFT;
+		let index = GeneralIndex((id & ((1 << DERIVATIVE_SHIFT) - 1)).into());
+		let pallet = PalletInstance(PALLET_INDEX);
+		Ok(if para_id == 0 {
+			MultiLocation::new(0, X2(pallet, index))
+		} else {
+			MultiLocation::new(1, X3(Parachain(para_id), pallet, index))
+		})
+	}
+}
+
+/// Convert between the `Index` of a nonfungible item and its instance id.
+pub struct InstanceIndex;
+
+impl Convert<AssetInstance, u32> for InstanceIndex {
+	fn convert(instance: AssetInstance) -> Result<u32, AssetInstance> {
+		match instance {
+			AssetInstance::Index(index) => u32::try_from(index).map_err(|_| instance),
+			_ => Err(instance),
+		}
+	}
+
+	fn reverse(instance: u32) -> Result<AssetInstance, u32> {
+		Ok(AssetInstance::Index(instance.into()))
+	}
+}
+
+/// Trust the chain an asset is located in as its reserve.
+pub struct ReserveLocation;
+
+impl FilterAssetLocation for ReserveLocation {
+	fn filter_asset_location(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		matches!(&asset.id, Concrete(location) if location.starts_with(origin))
+	}
+}
+
+/// Execution is free on the mock parachains: the fees are left in the holding register, and
+/// deposited with the rest of the assets.
+pub struct FreeExecution;
+
+impl WeightTrader for FreeExecution {
+	fn new() -> Self {
+		Self
+	}
+
+	fn buy_weight(
+		&mut self,
+		_weight: Weight,
+		payment: xcm_executor::Assets,
+	) -> Result<xcm_executor::Assets, XcmError> {
+		Ok(payment)
+	}
+}
@@ -0,0 +1,66 @@
+//! Multi-node tests of the node: the tests launch a local network of validators with zombienet,
+//! submit extrinsics to different nodes with [`rusty_node_client`], and check every node
+//! finalizes the same state.
+//!
+//! The network runs the node built in the target directory of the workspace, or the one at the
+//! `RUSTY_NODE` environment variable.
+
+#[cfg(test)]
+mod tests;
+
+use rusty_node_client::{Client, Error, Hash};
+use std::{env, future::Future, time::Duration};
+use zombienet_sdk::{LocalFileSystem, Network, NetworkConfigBuilder, NetworkConfigExt};
+
+/// The environment variable overriding the path of the node binary.
+pub const RUSTY_NODE: &str = "RUSTY_NODE";
+
+/// The validators of the network, named after their session keys.
+pub const VALIDATORS: [&str; 2] = ["alice", "bob"];
+
+/// How long to wait for a node to finalize a state.
+const FINALITY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Launch a network of [`VALIDATORS`] on the `local` chain.
+pub async fn spawn_network() -> Network<LocalFileSystem> {
+	let command = env::var(RUSTY_NODE).unwrap_or_else(|_| {
+		concat!(env!("CARGO_MANIFEST_DIR"), "/../target/release/rusty-node").into()
+	});
+	NetworkConfigBuilder::new()
+		.with_relaychain(|relaychain| {
+			let relaychain = relaychain.with_chain("local").with_default_command(command.as_str());
+			let relaychain = relaychain.with_node(|node| node.with_name(VALIDATORS[0]));
+			relaychain.with_node(|node| node.with_name(VALIDATORS[1]))
+		})
+		.build()
+		.expect("the network configuration is valid")
+		.spawn_native()
+		.await
+		.expect("the network could not be launched")
+}
+
+/// Connect to the validator `name` of `network`.
+pub async fn connect(network: &Network<LocalFileSystem>, name: &str) -> Client {
+	let node = network.get_node(name).expect("the validator is part of the network");
+	Client::new(node.ws_uri()).await.expect("the validator serves its websocket")
+}
+
+/// Wait for the node of `client` to finalize a state where `query` returns `expected`.
+pub async fn finalized<T, F, Fut>(client: &Client, expected: T, query: F)
+where
+	T: PartialEq + std::fmt::Debug,
+	F: Fn(Hash) -> Fut,
+	Fut: Future<Output = Result<T, Error>>,
+{
+	let mut waited = Duration::ZERO;
+	loop {
+		let head = client.finalized_head().await.unwrap();
+		let value = query(head).await.unwrap();
+		if value == expected {
+			return
+		}
+		assert!(waited < FINALITY_TIMEOUT, "finalized {:?} instead of {:?}", value, expected);
+		tokio::time::sleep(Duration::from_secs(1)).await;
+		waited += Duration::from_secs(1);
+	}
+}
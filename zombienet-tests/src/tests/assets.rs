@@ -0,0 +1,42 @@
+use crate::{connect, finalized, spawn_network, VALIDATORS};
+use rusty_node_client::{AssetId, Signer};
+use sp_keyring::AccountKeyring::{Alice, Bob, Charlie};
+
+/// An asset not created at genesis.
+const ASSET_ID: AssetId = AssetId(100);
+
+/// Alice mints to Bob and Charlie through different validators at once: the nonces of her two
+/// extrinsics are set upfront, as the validators can't know of the extrinsic the other received.
+#[tokio::test]
+async fn concurrent_mints_through_different_validators_are_finalized() {
+	let network = spawn_network().await;
+	let alice_node = connect(&network, VALIDATORS[0]).await;
+	let bob_node = connect(&network, VALIDATORS[1]).await;
+
+	let mut alice = Signer::new(Alice.pair());
+	alice_node.create_asset(&alice, ASSET_ID, Alice.to_account_id(), 1).await.unwrap();
+
+	let nonce = alice_node
+		.api()
+		.client
+		.rpc()
+		.system_account_next_index(&Alice.to_account_id())
+		.await
+		.unwrap();
+	alice.set_nonce(nonce);
+	let mut next = alice.clone();
+	next.set_nonce(nonce + 1);
+	let (to_bob, to_charlie) = tokio::join!(
+		alice_node.mint_asset(&alice, ASSET_ID, Bob.to_account_id(), 300),
+		bob_node.mint_asset(&next, ASSET_ID, Charlie.to_account_id(), 200),
+	);
+	to_bob.unwrap();
+	to_charlie.unwrap();
+
+	for node in [&alice_node, &bob_node] {
+		let (bob, charlie) = (Bob.to_account_id(), Charlie.to_account_id());
+		finalized(node, 300, move |at| node.asset_balance(ASSET_ID, bob.clone(), Some(at))).await;
+		finalized(node, 200, move |at| node.asset_balance(ASSET_ID, charlie.clone(), Some(at)))
+			.await;
+	}
+}
@@ -0,0 +1,34 @@
+use crate::{connect, finalized, spawn_network, VALIDATORS};
+use rusty_node_client::{ClassId, InstanceId, Signer};
+use sp_keyring::AccountKeyring::{Alice, Bob, Charlie};
+
+/// A class not created at genesis.
+const CLASS: ClassId = ClassId(100);
+const INSTANCE: InstanceId = InstanceId(7);
+
+/// An item minted through one validator is transferred, by its new owner, through the other.
+#[tokio::test]
+async fn items_move_across_validators() {
+	let network = spawn_network().await;
+	let alice_node = connect(&network, VALIDATORS[0]).await;
+	let bob_node = connect(&network, VALIDATORS[1]).await;
+
+	let alice = Signer::new(Alice.pair());
+	alice_node.create_class(&alice, CLASS, Alice.to_account_id()).await.unwrap();
+	alice_node.mint_nft(&alice, CLASS, INSTANCE, Bob.to_account_id()).await.unwrap();
+
+	// Bob's validator may not have imported the mint yet when Bob signs.
+	finalized(&bob_node, Some(Bob.to_account_id()), |at| {
+		bob_node.nft_owner(CLASS, INSTANCE, Some(at))
+	})
+	.await;
+	let bob = Signer::new(Bob.pair());
+	bob_node.transfer_nft(&bob, CLASS, INSTANCE, Charlie.to_account_id()).await.unwrap();
+
+	for node in [&alice_node, &bob_node] {
+		finalized(node, Some(Charlie.to_account_id()), |at| {
+			node.nft_owner(CLASS, INSTANCE, Some(at))
+		})
+		.await;
+	}
+}